@@ -0,0 +1,93 @@
+import {
+  Context,
+  publicKey,
+  PublicKey,
+  publicKeyBytes,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  findValidatorAttesterV1Pda,
+  respondValidationAttestationV1,
+  RespondValidationAttestationV1InstructionAccounts,
+  RespondValidationAttestationV1InstructionArgs,
+} from './generated/validation';
+
+/** The Ed25519 signature verification program. */
+export const ED25519_PROGRAM_ID = publicKey(
+  'Ed25519SigVerify111111111111111111111111111'
+);
+
+/**
+ * Build the statement an attester signs to back a validation response:
+ * the validation request address, the validator, the work hash of the
+ * request and the result byte.
+ */
+export function attestationMessage(
+  validationRequest: PublicKey,
+  validator: PublicKey,
+  workHash: Uint8Array,
+  result: number
+): Uint8Array {
+  const message = new Uint8Array(97);
+  message.set(publicKeyBytes(validationRequest), 0);
+  message.set(publicKeyBytes(validator), 32);
+  message.set(workHash, 64);
+  message[96] = result;
+  return message;
+}
+
+export type RespondWithAttestationInput = Omit<
+  RespondValidationAttestationV1InstructionAccounts,
+  'validatorAttester'
+> &
+  RespondValidationAttestationV1InstructionArgs & {
+    /** The attester key of the validator that signed the statement. */
+    attester: PublicKey;
+    /** Signature of the attester over `attestationMessage`. */
+    signature: Uint8Array;
+    /** The work hash of the request. */
+    workHash: Uint8Array;
+  };
+
+/**
+ * Respond to a validation request with an attestation, preceded by the
+ * Ed25519 program instruction verifying the attester's signature.
+ */
+export function respondWithAttestation(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RespondWithAttestationInput
+): TransactionBuilder {
+  const message = attestationMessage(
+    publicKey(input.validationRequest, false),
+    input.validator.publicKey,
+    input.workHash,
+    input.result
+  );
+
+  // One signature with its key, signature and message in the instruction
+  // itself: a 2-byte header, 14 bytes of offsets, then the data.
+  const data = new Uint8Array(112 + message.length);
+  const view = new DataView(data.buffer);
+  data[0] = 1;
+  const offsets = [48, 0xffff, 16, 0xffff, 112, message.length, 0xffff];
+  offsets.forEach((offset, i) => view.setUint16(2 + i * 2, offset, true));
+  data.set(publicKeyBytes(input.attester), 16);
+  data.set(input.signature, 48);
+  data.set(message, 112);
+
+  const validatorAttester = findValidatorAttesterV1Pda(context, {
+    validator: input.validator.publicKey,
+    attester: input.attester,
+  });
+
+  return transactionBuilder()
+    .add({
+      instruction: { programId: ED25519_PROGRAM_ID, keys: [], data },
+      signers: [],
+      bytesCreatedOnChain: 0,
+    })
+    .add(
+      respondValidationAttestationV1(context, { ...input, validatorAttester })
+    );
+}
@@ -0,0 +1,74 @@
+import {
+  AccountMeta,
+  Context,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  findAgentIdentityV2Pda,
+  registerIdentitiesV1,
+} from './generated/identity';
+
+/**
+ * Prefix of the program log written when registering an entry of a
+ * `RegisterIdentitiesV1` batch fails. It is followed by the index of the
+ * entry and the asset address.
+ */
+export const REGISTRATION_FAILURE_LOG_PREFIX =
+  'Failed to register identity at index ';
+
+export type RegisterIdentitiesInput = {
+  /** The collection of every asset. */
+  collection: PublicKey;
+  /** The assets to register. */
+  assets: PublicKey[];
+  /** One URI shared by every asset, or one URI per asset. */
+  agentRegistrationUris: string[];
+  /** Bitmask of the lifecycle checks. Defaults to the program's policy. */
+  lifecycleChecks?: number;
+  /** Update authority of the collection. Defaults to the payer. */
+  authority?: Signer;
+  payer?: Signer;
+};
+
+/**
+ * Register the identities of several assets of a collection with a single
+ * `RegisterIdentitiesV1` instruction, passing each (agent identity PDA,
+ * asset) pair as remaining accounts.
+ */
+export function registerIdentities(
+  context: Pick<Context, 'payer' | 'programs' | 'eddsa'>,
+  input: RegisterIdentitiesInput
+): TransactionBuilder {
+  const remainingAccounts: AccountMeta[] = input.assets.flatMap((asset) => [
+    {
+      pubkey: findAgentIdentityV2Pda(context, { asset })[0],
+      isSigner: false,
+      isWritable: true,
+    },
+    { pubkey: asset, isSigner: false, isWritable: true },
+  ]);
+
+  return registerIdentitiesV1(context, {
+    collection: input.collection,
+    payer: input.payer,
+    authority: input.authority,
+    lifecycleChecks: input.lifecycleChecks,
+    agentRegistrationUris: input.agentRegistrationUris,
+  }).addRemainingAccounts(remainingAccounts);
+}
+
+/**
+ * Find the index of the batch entry that made `RegisterIdentitiesV1` fail in
+ * the transaction logs.
+ */
+export function findFailedRegistrationIndex(logs: string[]): number | null {
+  const prefix = `Program log: ${REGISTRATION_FAILURE_LOG_PREFIX}`;
+  const log = logs.find((line) => line.startsWith(prefix));
+  if (!log) {
+    return null;
+  }
+  const index = Number.parseInt(log.slice(prefix.length), 10);
+  return Number.isNaN(index) ? null : index;
+}
@@ -0,0 +1,47 @@
+import { fetchAsset, fetchCollection } from '@metaplex-foundation/mpl-core';
+import { PublicKey, Umi } from '@metaplex-foundation/umi';
+import { safeFetchAgentIdentityV2FromSeeds } from './generated/identity';
+
+/**
+ * The Agent Identity that applies to an asset: either its own AgentIdentity
+ * plugin, or the plugin of its collection when the asset inherits it.
+ */
+export type EffectiveIdentity =
+  | { source: 'asset'; registrationUri: string }
+  | { source: 'collection'; collection: PublicKey; registrationUri: string };
+
+/**
+ * Resolve the effective identity of an asset. Returns `null` if the asset
+ * has no agent identity registered.
+ */
+export async function resolveEffectiveIdentity(
+  umi: Pick<Umi, 'rpc' | 'programs' | 'eddsa'>,
+  asset: PublicKey
+): Promise<EffectiveIdentity | null> {
+  const agentIdentity = await safeFetchAgentIdentityV2FromSeeds(umi, {
+    asset,
+  });
+  if (!agentIdentity) {
+    return null;
+  }
+
+  const assetData = await fetchAsset(umi, asset);
+
+  if (!agentIdentity.inheritsCollectionIdentity) {
+    const plugin = assetData.agentIdentities?.[0];
+    return plugin ? { source: 'asset', registrationUri: plugin.uri } : null;
+  }
+
+  if (
+    assetData.updateAuthority.type !== 'Collection' ||
+    !assetData.updateAuthority.address
+  ) {
+    throw new Error('Inherited agent identity on an asset without a collection');
+  }
+
+  const collection = assetData.updateAuthority.address;
+  const plugin = (await fetchCollection(umi, collection)).agentIdentities?.[0];
+  return plugin
+    ? { source: 'collection', collection, registrationUri: plugin.uri }
+    : null;
+}
@@ -27,14 +27,22 @@ import {
 import {
   Serializer,
   array,
-  bytes,
+  bool,
   mapSerializer,
   publicKey as publicKeySerializer,
   string,
   struct,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { Key, KeyArgs, getKeySerializer } from '../types';
+import {
+  AgentTokenSource,
+  AgentTokenSourceArgs,
+  Key,
+  KeyArgs,
+  getAgentTokenSourceSerializer,
+  getKeySerializer,
+} from '../types';
 
 export type AgentIdentityV2 = Account<AgentIdentityV2AccountData>;
 
@@ -44,7 +52,11 @@ export type AgentIdentityV2AccountData = {
   padding: Array<number>;
   asset: PublicKey;
   agentToken: Option<PublicKey>;
-  reserved: Uint8Array;
+  agentTokenSource: AgentTokenSource;
+  padding1: Array<number>;
+  agentTokenTimelock: number;
+  inheritsCollectionIdentity: boolean;
+  reserved: Array<number>;
 };
 
 export type AgentIdentityV2AccountDataArgs = {
@@ -52,6 +64,9 @@ export type AgentIdentityV2AccountDataArgs = {
   bump: number;
   asset: PublicKey;
   agentToken: OptionOrNullable<PublicKey>;
+  agentTokenSource: AgentTokenSourceArgs;
+  agentTokenTimelock: number;
+  inheritsCollectionIdentity: boolean;
 };
 
 export function getAgentIdentityV2AccountDataSerializer(): Serializer<
@@ -124,16 +139,20 @@ export function getAgentIdentityV2AccountDataSerializer(): Serializer<
             } as Serializer<OptionOrNullable<PublicKey>, Option<PublicKey>>;
           })(),
         ],
-        ['reserved', bytes({ size: 32 })],
+        ['agentTokenSource', getAgentTokenSourceSerializer()],
+        ['padding1', array(u8(), { size: 3 })],
+        ['agentTokenTimelock', u32()],
+        ['inheritsCollectionIdentity', bool()],
+        ['reserved', array(u8(), { size: 23 })],
       ],
       { description: 'AgentIdentityV2AccountData' }
     ),
     (value) => ({
       ...value,
       padding: [0, 0, 0, 0, 0, 0],
+      padding1: [0, 0, 0],
       reserved: [
-        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-        0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
       ],
     })
   ) as Serializer<AgentIdentityV2AccountDataArgs, AgentIdentityV2AccountData>;
@@ -218,7 +237,11 @@ export function getAgentIdentityV2GpaBuilder(
       padding: Array<number>;
       asset: PublicKey;
       agentToken: OptionOrNullable<PublicKey>;
-      reserved: Uint8Array;
+      agentTokenSource: AgentTokenSourceArgs;
+      padding1: Array<number>;
+      agentTokenTimelock: number;
+      inheritsCollectionIdentity: boolean;
+      reserved: Array<number>;
     }>({
       key: [0, getKeySerializer()],
       bump: [1, u8()],
@@ -277,7 +300,11 @@ export function getAgentIdentityV2GpaBuilder(
           } as Serializer<OptionOrNullable<PublicKey>, Option<PublicKey>>;
         })(),
       ],
-      reserved: [72, bytes({ size: 32 })],
+      agentTokenSource: [72, getAgentTokenSourceSerializer()],
+      padding1: [73, array(u8(), { size: 3 })],
+      agentTokenTimelock: [76, u32()],
+      inheritsCollectionIdentity: [80, bool()],
+      reserved: [81, array(u8(), { size: 23 })],
     })
     .deserializeUsing<AgentIdentityV2>((account) =>
       deserializeAgentIdentityV2(account)
@@ -0,0 +1,353 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Option,
+  OptionOrNullable,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  isOption,
+  none,
+  publicKey as toPublicKey,
+  some,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  i64,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  AgentTokenChange,
+  AgentTokenChangeArgs,
+  Key,
+  KeyArgs,
+  getAgentTokenChangeSerializer,
+  getKeySerializer,
+} from '../types';
+
+export type AgentTokenChangeV1 = Account<AgentTokenChangeV1AccountData>;
+
+export type AgentTokenChangeV1AccountData = {
+  key: Key;
+  bump: number;
+  change: AgentTokenChange;
+  padding: Array<number>;
+  asset: PublicKey;
+  newAgentToken: Option<PublicKey>;
+  unlockAt: bigint;
+  reserved: Uint8Array;
+};
+
+export type AgentTokenChangeV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  change: AgentTokenChangeArgs;
+  asset: PublicKey;
+  newAgentToken: OptionOrNullable<PublicKey>;
+  unlockAt: number | bigint;
+};
+
+export function getAgentTokenChangeV1AccountDataSerializer(): Serializer<
+  AgentTokenChangeV1AccountDataArgs,
+  AgentTokenChangeV1AccountData
+> {
+  return mapSerializer<
+    AgentTokenChangeV1AccountDataArgs,
+    any,
+    AgentTokenChangeV1AccountData
+  >(
+    struct<AgentTokenChangeV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['change', getAgentTokenChangeSerializer()],
+        ['padding', array(u8(), { size: 5 })],
+        ['asset', publicKeySerializer()],
+        [
+          'newAgentToken',
+          (() => {
+            const inner = publicKeySerializer();
+            const sentinel = new Uint8Array([
+              0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+              0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            ]);
+            if (inner.fixedSize == null) {
+              throw new Error(
+                'Fixed-size options require an inner serializer with a fixed size.'
+              );
+            }
+            if (inner.fixedSize !== sentinel.length) {
+              throw new Error(
+                'Fixed-size option sentinel length must match the inner serializer fixed size.'
+              );
+            }
+            const { fixedSize } = inner;
+            const normalize = (input: OptionOrNullable<PublicKey>) => {
+              if (input == null) {
+                return null;
+              }
+              if (isOption(input)) {
+                return input.__option === 'None' ? null : input.value;
+              }
+              return input;
+            };
+            return {
+              description: 'Option<PublicKey>',
+              fixedSize,
+              maxSize: fixedSize,
+              serialize: (value: OptionOrNullable<PublicKey>) => {
+                const normalized = normalize(value);
+                return normalized == null
+                  ? sentinel.slice()
+                  : inner.serialize(normalized);
+              },
+              deserialize: (bytes: Uint8Array, offset = 0) => {
+                const slice = bytes.slice(offset, offset + fixedSize);
+                const isSentinel = slice.every(
+                  (byte, i) => byte === sentinel[i]
+                );
+                if (isSentinel) {
+                  return [none<PublicKey>(), offset + fixedSize] as [
+                    Option<PublicKey>,
+                    number,
+                  ];
+                }
+                const [value, newOffset] = inner.deserialize(bytes, offset);
+                return [some(value), newOffset] as [Option<PublicKey>, number];
+              },
+            } as Serializer<OptionOrNullable<PublicKey>, Option<PublicKey>>;
+          })(),
+        ],
+        ['unlockAt', i64()],
+        ['reserved', bytes({ size: 32 })],
+      ],
+      { description: 'AgentTokenChangeV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0, 0, 0, 0],
+      reserved: [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+      ],
+    })
+  ) as Serializer<
+    AgentTokenChangeV1AccountDataArgs,
+    AgentTokenChangeV1AccountData
+  >;
+}
+
+export function deserializeAgentTokenChangeV1(
+  rawAccount: RpcAccount
+): AgentTokenChangeV1 {
+  return deserializeAccount(
+    rawAccount,
+    getAgentTokenChangeV1AccountDataSerializer()
+  );
+}
+
+export async function fetchAgentTokenChangeV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AgentTokenChangeV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AgentTokenChangeV1');
+  return deserializeAgentTokenChangeV1(maybeAccount);
+}
+
+export async function safeFetchAgentTokenChangeV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AgentTokenChangeV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeAgentTokenChangeV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllAgentTokenChangeV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AgentTokenChangeV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AgentTokenChangeV1');
+    return deserializeAgentTokenChangeV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAgentTokenChangeV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AgentTokenChangeV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAgentTokenChangeV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAgentTokenChangeV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      change: AgentTokenChangeArgs;
+      padding: Array<number>;
+      asset: PublicKey;
+      newAgentToken: OptionOrNullable<PublicKey>;
+      unlockAt: number | bigint;
+      reserved: Uint8Array;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      change: [2, getAgentTokenChangeSerializer()],
+      padding: [3, array(u8(), { size: 5 })],
+      asset: [8, publicKeySerializer()],
+      newAgentToken: [
+        40,
+        (() => {
+          const inner = publicKeySerializer();
+          const sentinel = new Uint8Array([
+            0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+            0, 0, 0, 0, 0, 0, 0, 0, 0,
+          ]);
+          if (inner.fixedSize == null) {
+            throw new Error(
+              'Fixed-size options require an inner serializer with a fixed size.'
+            );
+          }
+          if (inner.fixedSize !== sentinel.length) {
+            throw new Error(
+              'Fixed-size option sentinel length must match the inner serializer fixed size.'
+            );
+          }
+          const { fixedSize } = inner;
+          const normalize = (input: OptionOrNullable<PublicKey>) => {
+            if (input == null) {
+              return null;
+            }
+            if (isOption(input)) {
+              return input.__option === 'None' ? null : input.value;
+            }
+            return input;
+          };
+          return {
+            description: 'Option<PublicKey>',
+            fixedSize,
+            maxSize: fixedSize,
+            serialize: (value: OptionOrNullable<PublicKey>) => {
+              const normalized = normalize(value);
+              return normalized == null
+                ? sentinel.slice()
+                : inner.serialize(normalized);
+            },
+            deserialize: (bytes: Uint8Array, offset = 0) => {
+              const slice = bytes.slice(offset, offset + fixedSize);
+              const isSentinel = slice.every((byte, i) => byte === sentinel[i]);
+              if (isSentinel) {
+                return [none<PublicKey>(), offset + fixedSize] as [
+                  Option<PublicKey>,
+                  number,
+                ];
+              }
+              const [value, newOffset] = inner.deserialize(bytes, offset);
+              return [some(value), newOffset] as [Option<PublicKey>, number];
+            },
+          } as Serializer<OptionOrNullable<PublicKey>, Option<PublicKey>>;
+        })(),
+      ],
+      unlockAt: [72, i64()],
+      reserved: [80, bytes({ size: 32 })],
+    })
+    .deserializeUsing<AgentTokenChangeV1>((account) =>
+      deserializeAgentTokenChangeV1(account)
+    );
+}
+
+export function getAgentTokenChangeV1Size(): number {
+  return 112;
+}
+
+export function findAgentTokenChangeV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('agent_token_change'),
+    publicKeySerializer().serialize(seeds.asset),
+  ]);
+}
+
+export async function fetchAgentTokenChangeV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAgentTokenChangeV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AgentTokenChangeV1> {
+  return fetchAgentTokenChangeV1(
+    context,
+    findAgentTokenChangeV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchAgentTokenChangeV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAgentTokenChangeV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AgentTokenChangeV1 | null> {
+  return safeFetchAgentTokenChangeV1(
+    context,
+    findAgentTokenChangeV1Pda(context, seeds),
+    options
+  );
+}
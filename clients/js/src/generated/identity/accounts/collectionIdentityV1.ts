@@ -0,0 +1,215 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type CollectionIdentityV1 = Account<CollectionIdentityV1AccountData>;
+
+export type CollectionIdentityV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  collection: PublicKey;
+  reserved: Array<number>;
+};
+
+export type CollectionIdentityV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  collection: PublicKey;
+};
+
+export function getCollectionIdentityV1AccountDataSerializer(): Serializer<
+  CollectionIdentityV1AccountDataArgs,
+  CollectionIdentityV1AccountData
+> {
+  return mapSerializer<
+    CollectionIdentityV1AccountDataArgs,
+    any,
+    CollectionIdentityV1AccountData
+  >(
+    struct<CollectionIdentityV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['collection', publicKeySerializer()],
+        ['reserved', array(u8(), { size: 16 })],
+      ],
+      { description: 'CollectionIdentityV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0, 0, 0, 0, 0],
+      reserved: [0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0],
+    })
+  ) as Serializer<
+    CollectionIdentityV1AccountDataArgs,
+    CollectionIdentityV1AccountData
+  >;
+}
+
+export function deserializeCollectionIdentityV1(
+  rawAccount: RpcAccount
+): CollectionIdentityV1 {
+  return deserializeAccount(
+    rawAccount,
+    getCollectionIdentityV1AccountDataSerializer()
+  );
+}
+
+export async function fetchCollectionIdentityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<CollectionIdentityV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'CollectionIdentityV1');
+  return deserializeCollectionIdentityV1(maybeAccount);
+}
+
+export async function safeFetchCollectionIdentityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<CollectionIdentityV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeCollectionIdentityV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllCollectionIdentityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<CollectionIdentityV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'CollectionIdentityV1');
+    return deserializeCollectionIdentityV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllCollectionIdentityV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<CollectionIdentityV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeCollectionIdentityV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getCollectionIdentityV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      collection: PublicKey;
+      reserved: Array<number>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      collection: [8, publicKeySerializer()],
+      reserved: [40, array(u8(), { size: 16 })],
+    })
+    .deserializeUsing<CollectionIdentityV1>((account) =>
+      deserializeCollectionIdentityV1(account)
+    );
+}
+
+export function getCollectionIdentityV1Size(): number {
+  return 56;
+}
+
+export function findCollectionIdentityV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the collection */
+    collection: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('agent_identity'),
+    publicKeySerializer().serialize(seeds.collection),
+  ]);
+}
+
+export async function fetchCollectionIdentityV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findCollectionIdentityV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<CollectionIdentityV1> {
+  return fetchCollectionIdentityV1(
+    context,
+    findCollectionIdentityV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchCollectionIdentityV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findCollectionIdentityV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<CollectionIdentityV1 | null> {
+  return safeFetchCollectionIdentityV1(
+    context,
+    findCollectionIdentityV1Pda(context, seeds),
+    options
+  );
+}
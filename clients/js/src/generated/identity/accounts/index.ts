@@ -8,3 +8,5 @@
 
 export * from './agentIdentityV1';
 export * from './agentIdentityV2';
+export * from './agentTokenChangeV1';
+export * from './collectionIdentityV1';
@@ -177,6 +177,403 @@ export class GenesisNotMintFundedError extends ProgramError {
 codeToErrorMap.set(0xb, GenesisNotMintFundedError);
 nameToErrorMap.set('GenesisNotMintFunded', GenesisNotMintFundedError);
 
+/** OnlyAssetOwnerOrSignerCanUpdateUri: Only the asset owner or Asset Signer can update the registration URI */
+export class OnlyAssetOwnerOrSignerCanUpdateUriError extends ProgramError {
+  override readonly name: string = 'OnlyAssetOwnerOrSignerCanUpdateUri';
+
+  readonly code: number = 0xc; // 12
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the asset owner or Asset Signer can update the registration URI',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0xc, OnlyAssetOwnerOrSignerCanUpdateUriError);
+nameToErrorMap.set(
+  'OnlyAssetOwnerOrSignerCanUpdateUri',
+  OnlyAssetOwnerOrSignerCanUpdateUriError
+);
+
+/** RegistrationUriEmpty: Registration URI is empty */
+export class RegistrationUriEmptyError extends ProgramError {
+  override readonly name: string = 'RegistrationUriEmpty';
+
+  readonly code: number = 0xd; // 13
+
+  constructor(program: Program, cause?: Error) {
+    super('Registration URI is empty', program, cause);
+  }
+}
+codeToErrorMap.set(0xd, RegistrationUriEmptyError);
+nameToErrorMap.set('RegistrationUriEmpty', RegistrationUriEmptyError);
+
+/** RegistrationUriTooLong: Registration URI exceeds the maximum length */
+export class RegistrationUriTooLongError extends ProgramError {
+  override readonly name: string = 'RegistrationUriTooLong';
+
+  readonly code: number = 0xe; // 14
+
+  constructor(program: Program, cause?: Error) {
+    super('Registration URI exceeds the maximum length', program, cause);
+  }
+}
+codeToErrorMap.set(0xe, RegistrationUriTooLongError);
+nameToErrorMap.set('RegistrationUriTooLong', RegistrationUriTooLongError);
+
+/** RegistrationUriNotUtf8: Registration URI is not valid UTF-8 */
+export class RegistrationUriNotUtf8Error extends ProgramError {
+  override readonly name: string = 'RegistrationUriNotUtf8';
+
+  readonly code: number = 0xf; // 15
+
+  constructor(program: Program, cause?: Error) {
+    super('Registration URI is not valid UTF-8', program, cause);
+  }
+}
+codeToErrorMap.set(0xf, RegistrationUriNotUtf8Error);
+nameToErrorMap.set('RegistrationUriNotUtf8', RegistrationUriNotUtf8Error);
+
+/** RegistrationUriSchemeNotAllowed: Registration URI scheme is not allowed */
+export class RegistrationUriSchemeNotAllowedError extends ProgramError {
+  override readonly name: string = 'RegistrationUriSchemeNotAllowed';
+
+  readonly code: number = 0x10; // 16
+
+  constructor(program: Program, cause?: Error) {
+    super('Registration URI scheme is not allowed', program, cause);
+  }
+}
+codeToErrorMap.set(0x10, RegistrationUriSchemeNotAllowedError);
+nameToErrorMap.set(
+  'RegistrationUriSchemeNotAllowed',
+  RegistrationUriSchemeNotAllowedError
+);
+
+/** OnlyAssetOwnerCanDeregister: Only the asset owner can deregister the agent identity */
+export class OnlyAssetOwnerCanDeregisterError extends ProgramError {
+  override readonly name: string = 'OnlyAssetOwnerCanDeregister';
+
+  readonly code: number = 0x11; // 17
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the asset owner can deregister the agent identity',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x11, OnlyAssetOwnerCanDeregisterError);
+nameToErrorMap.set(
+  'OnlyAssetOwnerCanDeregister',
+  OnlyAssetOwnerCanDeregisterError
+);
+
+/** InvalidExecutionDelegateCount: Invalid Execution Delegate Count account */
+export class InvalidExecutionDelegateCountError extends ProgramError {
+  override readonly name: string = 'InvalidExecutionDelegateCount';
+
+  readonly code: number = 0x12; // 18
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Execution Delegate Count account', program, cause);
+  }
+}
+codeToErrorMap.set(0x12, InvalidExecutionDelegateCountError);
+nameToErrorMap.set(
+  'InvalidExecutionDelegateCount',
+  InvalidExecutionDelegateCountError
+);
+
+/** ExecutionDelegatesStillActive: Agent still has active execution delegates */
+export class ExecutionDelegatesStillActiveError extends ProgramError {
+  override readonly name: string = 'ExecutionDelegatesStillActive';
+
+  readonly code: number = 0x13; // 19
+
+  constructor(program: Program, cause?: Error) {
+    super('Agent still has active execution delegates', program, cause);
+  }
+}
+codeToErrorMap.set(0x13, ExecutionDelegatesStillActiveError);
+nameToErrorMap.set(
+  'ExecutionDelegatesStillActive',
+  ExecutionDelegatesStillActiveError
+);
+
+/** AgentIdentityAlreadyMigrated: Agent Identity is already an AgentIdentityV2 */
+export class AgentIdentityAlreadyMigratedError extends ProgramError {
+  override readonly name: string = 'AgentIdentityAlreadyMigrated';
+
+  readonly code: number = 0x14; // 20
+
+  constructor(program: Program, cause?: Error) {
+    super('Agent Identity is already an AgentIdentityV2', program, cause);
+  }
+}
+codeToErrorMap.set(0x14, AgentIdentityAlreadyMigratedError);
+nameToErrorMap.set(
+  'AgentIdentityAlreadyMigrated',
+  AgentIdentityAlreadyMigratedError
+);
+
+/** InvalidMint: Invalid SPL Token or Token-2022 mint */
+export class InvalidMintError extends ProgramError {
+  override readonly name: string = 'InvalidMint';
+
+  readonly code: number = 0x15; // 21
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid SPL Token or Token-2022 mint', program, cause);
+  }
+}
+codeToErrorMap.set(0x15, InvalidMintError);
+nameToErrorMap.set('InvalidMint', InvalidMintError);
+
+/** MintAuthorityMismatch: Mint authority must be the asset signer or sign the instruction */
+export class MintAuthorityMismatchError extends ProgramError {
+  override readonly name: string = 'MintAuthorityMismatch';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Mint authority must be the asset signer or sign the instruction',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x16, MintAuthorityMismatchError);
+nameToErrorMap.set('MintAuthorityMismatch', MintAuthorityMismatchError);
+
+/** AgentTokenNotSet: Agent token is not set */
+export class AgentTokenNotSetError extends ProgramError {
+  override readonly name: string = 'AgentTokenNotSet';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super('Agent token is not set', program, cause);
+  }
+}
+codeToErrorMap.set(0x17, AgentTokenNotSetError);
+nameToErrorMap.set('AgentTokenNotSet', AgentTokenNotSetError);
+
+/** AgentTokenChangeLocked: Agent token change is still time-locked */
+export class AgentTokenChangeLockedError extends ProgramError {
+  override readonly name: string = 'AgentTokenChangeLocked';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super('Agent token change is still time-locked', program, cause);
+  }
+}
+codeToErrorMap.set(0x18, AgentTokenChangeLockedError);
+nameToErrorMap.set('AgentTokenChangeLocked', AgentTokenChangeLockedError);
+
+/** AgentTokenTimelockDecrease: Agent token timelock cannot be shortened while an agent token is set */
+export class AgentTokenTimelockDecreaseError extends ProgramError {
+  override readonly name: string = 'AgentTokenTimelockDecrease';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Agent token timelock cannot be shortened while an agent token is set',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x19, AgentTokenTimelockDecreaseError);
+nameToErrorMap.set(
+  'AgentTokenTimelockDecrease',
+  AgentTokenTimelockDecreaseError
+);
+
+/** InvalidLifecycleChecks: Invalid lifecycle checks */
+export class InvalidLifecycleChecksError extends ProgramError {
+  override readonly name: string = 'InvalidLifecycleChecks';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid lifecycle checks', program, cause);
+  }
+}
+codeToErrorMap.set(0x1a, InvalidLifecycleChecksError);
+nameToErrorMap.set('InvalidLifecycleChecks', InvalidLifecycleChecksError);
+
+/** OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks: Only the asset owner or Asset Signer can update the lifecycle checks */
+export class OnlyAssetOwnerOrSignerCanUpdateLifecycleChecksError extends ProgramError {
+  override readonly name: string =
+    'OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the asset owner or Asset Signer can update the lifecycle checks',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1b, OnlyAssetOwnerOrSignerCanUpdateLifecycleChecksError);
+nameToErrorMap.set(
+  'OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks',
+  OnlyAssetOwnerOrSignerCanUpdateLifecycleChecksError
+);
+
+/** InvalidCoreCollection: Invalid Core Collection */
+export class InvalidCoreCollectionError extends ProgramError {
+  override readonly name: string = 'InvalidCoreCollection';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Core Collection', program, cause);
+  }
+}
+codeToErrorMap.set(0x1c, InvalidCoreCollectionError);
+nameToErrorMap.set('InvalidCoreCollection', InvalidCoreCollectionError);
+
+/** CollectionIdentityNotRegistered: Collection identity is not registered */
+export class CollectionIdentityNotRegisteredError extends ProgramError {
+  override readonly name: string = 'CollectionIdentityNotRegistered';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super('Collection identity is not registered', program, cause);
+  }
+}
+codeToErrorMap.set(0x1d, CollectionIdentityNotRegisteredError);
+nameToErrorMap.set(
+  'CollectionIdentityNotRegistered',
+  CollectionIdentityNotRegisteredError
+);
+
+/** AssetNotInCollection: Asset does not belong to the collection */
+export class AssetNotInCollectionError extends ProgramError {
+  override readonly name: string = 'AssetNotInCollection';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset does not belong to the collection', program, cause);
+  }
+}
+codeToErrorMap.set(0x1e, AssetNotInCollectionError);
+nameToErrorMap.set('AssetNotInCollection', AssetNotInCollectionError);
+
+/** OnlyAssetOwnerOrSignerCanInheritIdentity: Only the asset owner or Asset Signer can inherit the collection identity */
+export class OnlyAssetOwnerOrSignerCanInheritIdentityError extends ProgramError {
+  override readonly name: string = 'OnlyAssetOwnerOrSignerCanInheritIdentity';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the asset owner or Asset Signer can inherit the collection identity',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1f, OnlyAssetOwnerOrSignerCanInheritIdentityError);
+nameToErrorMap.set(
+  'OnlyAssetOwnerOrSignerCanInheritIdentity',
+  OnlyAssetOwnerOrSignerCanInheritIdentityError
+);
+
+/** AgentIdentityInheritedFromCollection: Agent identity is inherited from the collection */
+export class AgentIdentityInheritedFromCollectionError extends ProgramError {
+  override readonly name: string = 'AgentIdentityInheritedFromCollection';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super('Agent identity is inherited from the collection', program, cause);
+  }
+}
+codeToErrorMap.set(0x20, AgentIdentityInheritedFromCollectionError);
+nameToErrorMap.set(
+  'AgentIdentityInheritedFromCollection',
+  AgentIdentityInheritedFromCollectionError
+);
+
+/** InvalidBatchAccounts: Batch accounts must be non-empty (agent identity, asset) pairs */
+export class InvalidBatchAccountsError extends ProgramError {
+  override readonly name: string = 'InvalidBatchAccounts';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Batch accounts must be non-empty (agent identity, asset) pairs',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x21, InvalidBatchAccountsError);
+nameToErrorMap.set('InvalidBatchAccounts', InvalidBatchAccountsError);
+
+/** InvalidRegistrationUriTable: Registration URI table must have one URI or one per asset */
+export class InvalidRegistrationUriTableError extends ProgramError {
+  override readonly name: string = 'InvalidRegistrationUriTable';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Registration URI table must have one URI or one per asset',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x22, InvalidRegistrationUriTableError);
+nameToErrorMap.set(
+  'InvalidRegistrationUriTable',
+  InvalidRegistrationUriTableError
+);
+
+/** ExecutionDelegateMigrationIncomplete: Execution delegates are not all counted yet */
+export class ExecutionDelegateMigrationIncompleteError extends ProgramError {
+  override readonly name: string = 'ExecutionDelegateMigrationIncomplete';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super('Execution delegates are not all counted yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x23, ExecutionDelegateMigrationIncompleteError);
+nameToErrorMap.set(
+  'ExecutionDelegateMigrationIncomplete',
+  ExecutionDelegateMigrationIncompleteError
+);
+
+/** InvalidAgentTokenChange: Invalid Agent Token Change account */
+export class InvalidAgentTokenChangeError extends ProgramError {
+  override readonly name: string = 'InvalidAgentTokenChange';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Agent Token Change account', program, cause);
+  }
+}
+codeToErrorMap.set(0x24, InvalidAgentTokenChangeError);
+nameToErrorMap.set('InvalidAgentTokenChange', InvalidAgentTokenChangeError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
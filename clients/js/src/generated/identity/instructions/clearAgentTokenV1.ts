@@ -0,0 +1,171 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda, findAgentTokenChangeV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ClearAgentTokenV1InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV2. */
+  agentIdentity?: PublicKey | Pda;
+  /** The agent token change PDA holding the scheduled change */
+  agentTokenChange?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The payer for the agent token change account */
+  payer?: Signer;
+  /** Authority must be the asset signer. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ClearAgentTokenV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type ClearAgentTokenV1InstructionDataArgs = {};
+
+export function getClearAgentTokenV1InstructionDataSerializer(): Serializer<
+  ClearAgentTokenV1InstructionDataArgs,
+  ClearAgentTokenV1InstructionData
+> {
+  return mapSerializer<
+    ClearAgentTokenV1InstructionDataArgs,
+    any,
+    ClearAgentTokenV1InstructionData
+  >(
+    struct<ClearAgentTokenV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'ClearAgentTokenV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 7, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ClearAgentTokenV1InstructionDataArgs,
+    ClearAgentTokenV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const clearAgentTokenV1InstructionDiscriminator = 7;
+
+// Instruction.
+export function clearAgentTokenV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: ClearAgentTokenV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    agentTokenChange: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.agentTokenChange ?? null,
+    },
+    asset: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.agentTokenChange.value) {
+    resolvedAccounts.agentTokenChange.value = findAgentTokenChangeV1Pda(
+      context,
+      { asset: expectPublicKey(resolvedAccounts.asset.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getClearAgentTokenV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,200 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type DeregisterIdentityV1InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2. */
+  agentIdentity?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The mpl-agent-tools execution delegate count PDA for the asset */
+  executionDelegateCount: PublicKey | Pda;
+  /** The mpl-agent-tools execution delegate migration PDA */
+  executionDelegateMigration: PublicKey | Pda;
+  /** The destination for the reclaimed rent */
+  destination: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Authority must be the asset owner. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type DeregisterIdentityV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type DeregisterIdentityV1InstructionDataArgs = {};
+
+export function getDeregisterIdentityV1InstructionDataSerializer(): Serializer<
+  DeregisterIdentityV1InstructionDataArgs,
+  DeregisterIdentityV1InstructionData
+> {
+  return mapSerializer<
+    DeregisterIdentityV1InstructionDataArgs,
+    any,
+    DeregisterIdentityV1InstructionData
+  >(
+    struct<DeregisterIdentityV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'DeregisterIdentityV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 3, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    DeregisterIdentityV1InstructionDataArgs,
+    DeregisterIdentityV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const deregisterIdentityV1InstructionDiscriminator = 3;
+
+// Instruction.
+export function deregisterIdentityV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: DeregisterIdentityV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    executionDelegateCount: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.executionDelegateCount ?? null,
+    },
+    executionDelegateMigration: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.executionDelegateMigration ?? null,
+    },
+    destination: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+    payer: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getDeregisterIdentityV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
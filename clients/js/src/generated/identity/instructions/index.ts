@@ -6,5 +6,16 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './clearAgentTokenV1';
+export * from './deregisterIdentityV1';
+export * from './inheritCollectionIdentityV1';
+export * from './migrateIdentityV1ToV2';
+export * from './registerCollectionIdentityV1';
+export * from './registerIdentitiesV1';
 export * from './registerIdentityV1';
+export * from './replaceAgentTokenV1';
+export * from './setAgentTokenFromMintV1';
+export * from './setAgentTokenTimelockV1';
 export * from './setAgentTokenV1';
+export * from './updateAgentRegistrationUriV1';
+export * from './updateIdentityLifecycleChecksV1';
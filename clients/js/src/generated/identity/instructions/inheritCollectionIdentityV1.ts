@@ -0,0 +1,182 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findAgentIdentityV2Pda,
+  findCollectionIdentityV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InheritCollectionIdentityV1InstructionAccounts = {
+  /** The agent identity PDA */
+  agentIdentity?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The collection of the asset */
+  collection: PublicKey | Pda;
+  /** The collection identity PDA */
+  collectionIdentity?: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Authority must be the asset owner or the asset signer. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InheritCollectionIdentityV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type InheritCollectionIdentityV1InstructionDataArgs = {};
+
+export function getInheritCollectionIdentityV1InstructionDataSerializer(): Serializer<
+  InheritCollectionIdentityV1InstructionDataArgs,
+  InheritCollectionIdentityV1InstructionData
+> {
+  return mapSerializer<
+    InheritCollectionIdentityV1InstructionDataArgs,
+    any,
+    InheritCollectionIdentityV1InstructionData
+  >(
+    struct<InheritCollectionIdentityV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'InheritCollectionIdentityV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 11, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    InheritCollectionIdentityV1InstructionDataArgs,
+    InheritCollectionIdentityV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const inheritCollectionIdentityV1InstructionDiscriminator = 11;
+
+// Instruction.
+export function inheritCollectionIdentityV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: InheritCollectionIdentityV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.collection ?? null,
+    },
+    collectionIdentity: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.collectionIdentity ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.collectionIdentity.value) {
+    resolvedAccounts.collectionIdentity.value = findCollectionIdentityV1Pda(
+      context,
+      { collection: expectPublicKey(resolvedAccounts.collection.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getInheritCollectionIdentityV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,153 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MigrateIdentityV1ToV2InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV1. */
+  agentIdentity?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MigrateIdentityV1ToV2InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type MigrateIdentityV1ToV2InstructionDataArgs = {};
+
+export function getMigrateIdentityV1ToV2InstructionDataSerializer(): Serializer<
+  MigrateIdentityV1ToV2InstructionDataArgs,
+  MigrateIdentityV1ToV2InstructionData
+> {
+  return mapSerializer<
+    MigrateIdentityV1ToV2InstructionDataArgs,
+    any,
+    MigrateIdentityV1ToV2InstructionData
+  >(
+    struct<MigrateIdentityV1ToV2InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'MigrateIdentityV1ToV2InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 4, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    MigrateIdentityV1ToV2InstructionDataArgs,
+    MigrateIdentityV1ToV2InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const migrateIdentityV1ToV2InstructionDiscriminator = 4;
+
+// Instruction.
+export function migrateIdentityV1ToV2(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: MigrateIdentityV1ToV2InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMigrateIdentityV1ToV2InstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,200 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findCollectionIdentityV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RegisterCollectionIdentityV1InstructionAccounts = {
+  /** The collection identity PDA */
+  collectionIdentity?: PublicKey | Pda;
+  /** The address of the Core collection */
+  collection: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Update authority of the collection. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RegisterCollectionIdentityV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  lifecycleChecks: number;
+  agentRegistrationUri: string;
+};
+
+export type RegisterCollectionIdentityV1InstructionDataArgs = {
+  lifecycleChecks?: number;
+  agentRegistrationUri: string;
+};
+
+export function getRegisterCollectionIdentityV1InstructionDataSerializer(): Serializer<
+  RegisterCollectionIdentityV1InstructionDataArgs,
+  RegisterCollectionIdentityV1InstructionData
+> {
+  return mapSerializer<
+    RegisterCollectionIdentityV1InstructionDataArgs,
+    any,
+    RegisterCollectionIdentityV1InstructionData
+  >(
+    struct<RegisterCollectionIdentityV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 3 })],
+        ['lifecycleChecks', u32()],
+        ['agentRegistrationUri', string()],
+      ],
+      { description: 'RegisterCollectionIdentityV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 10,
+      padding: [0, 0, 0],
+      lifecycleChecks: value.lifecycleChecks ?? 0,
+    })
+  ) as Serializer<
+    RegisterCollectionIdentityV1InstructionDataArgs,
+    RegisterCollectionIdentityV1InstructionData
+  >;
+}
+
+// Args.
+export type RegisterCollectionIdentityV1InstructionArgs =
+  RegisterCollectionIdentityV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const registerCollectionIdentityV1InstructionDiscriminator = 10;
+
+// Instruction.
+export function registerCollectionIdentityV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RegisterCollectionIdentityV1InstructionAccounts &
+    RegisterCollectionIdentityV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    collectionIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.collectionIdentity ?? null,
+    },
+    collection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RegisterCollectionIdentityV1InstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.collectionIdentity.value) {
+    resolvedAccounts.collectionIdentity.value = findCollectionIdentityV1Pda(
+      context,
+      { collection: expectPublicKey(resolvedAccounts.collection.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getRegisterCollectionIdentityV1InstructionDataSerializer().serialize(
+      resolvedArgs as RegisterCollectionIdentityV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
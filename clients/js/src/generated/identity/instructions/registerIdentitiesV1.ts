@@ -0,0 +1,182 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  string,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RegisterIdentitiesV1InstructionAccounts = {
+  /** The address of the collection of every asset */
+  collection: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Update authority of the collection. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RegisterIdentitiesV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  lifecycleChecks: number;
+  agentRegistrationUris: Array<string>;
+};
+
+export type RegisterIdentitiesV1InstructionDataArgs = {
+  lifecycleChecks?: number;
+  agentRegistrationUris: Array<string>;
+};
+
+export function getRegisterIdentitiesV1InstructionDataSerializer(): Serializer<
+  RegisterIdentitiesV1InstructionDataArgs,
+  RegisterIdentitiesV1InstructionData
+> {
+  return mapSerializer<
+    RegisterIdentitiesV1InstructionDataArgs,
+    any,
+    RegisterIdentitiesV1InstructionData
+  >(
+    struct<RegisterIdentitiesV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 3 })],
+        ['lifecycleChecks', u32()],
+        ['agentRegistrationUris', array(string())],
+      ],
+      { description: 'RegisterIdentitiesV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 12,
+      padding: [0, 0, 0],
+      lifecycleChecks: value.lifecycleChecks ?? 0,
+    })
+  ) as Serializer<
+    RegisterIdentitiesV1InstructionDataArgs,
+    RegisterIdentitiesV1InstructionData
+  >;
+}
+
+// Args.
+export type RegisterIdentitiesV1InstructionArgs =
+  RegisterIdentitiesV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const registerIdentitiesV1InstructionDiscriminator = 12;
+
+// Instruction.
+export function registerIdentitiesV1(
+  context: Pick<Context, 'payer' | 'programs'>,
+  input: RegisterIdentitiesV1InstructionAccounts &
+    RegisterIdentitiesV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    collection: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    payer: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RegisterIdentitiesV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRegisterIdentitiesV1InstructionDataSerializer().serialize(
+    resolvedArgs as RegisterIdentitiesV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -20,6 +20,7 @@ import {
   mapSerializer,
   string,
   struct,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import { findAgentIdentityV2Pda } from '../accounts';
@@ -52,10 +53,12 @@ export type RegisterIdentityV1InstructionAccounts = {
 export type RegisterIdentityV1InstructionData = {
   discriminator: number;
   padding: Array<number>;
+  lifecycleChecks: number;
   agentRegistrationUri: string;
 };
 
 export type RegisterIdentityV1InstructionDataArgs = {
+  lifecycleChecks?: number;
   agentRegistrationUri: string;
 };
 
@@ -71,12 +74,18 @@ export function getRegisterIdentityV1InstructionDataSerializer(): Serializer<
     struct<RegisterIdentityV1InstructionData>(
       [
         ['discriminator', u8()],
-        ['padding', array(u8(), { size: 7 })],
+        ['padding', array(u8(), { size: 3 })],
+        ['lifecycleChecks', u32()],
         ['agentRegistrationUri', string()],
       ],
       { description: 'RegisterIdentityV1InstructionData' }
     ),
-    (value) => ({ ...value, discriminator: 0, padding: [0, 0, 0, 0, 0, 0, 0] })
+    (value) => ({
+      ...value,
+      discriminator: 0,
+      padding: [0, 0, 0],
+      lifecycleChecks: value.lifecycleChecks ?? 0,
+    })
   ) as Serializer<
     RegisterIdentityV1InstructionDataArgs,
     RegisterIdentityV1InstructionData
@@ -0,0 +1,185 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda, findAgentTokenChangeV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReplaceAgentTokenV1InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV2. */
+  agentIdentity?: PublicKey | Pda;
+  /** The agent token change PDA holding the scheduled change */
+  agentTokenChange?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The SPL Token or Token-2022 mint of the new agent token */
+  mint: PublicKey | Pda;
+  /** The mint authority of the mint. Not required if the asset signer is the mint authority. */
+  mintAuthority?: Signer;
+  /** The payer for the agent token change account */
+  payer?: Signer;
+  /** Authority must be the asset signer. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type ReplaceAgentTokenV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type ReplaceAgentTokenV1InstructionDataArgs = {};
+
+export function getReplaceAgentTokenV1InstructionDataSerializer(): Serializer<
+  ReplaceAgentTokenV1InstructionDataArgs,
+  ReplaceAgentTokenV1InstructionData
+> {
+  return mapSerializer<
+    ReplaceAgentTokenV1InstructionDataArgs,
+    any,
+    ReplaceAgentTokenV1InstructionData
+  >(
+    struct<ReplaceAgentTokenV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'ReplaceAgentTokenV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 8, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ReplaceAgentTokenV1InstructionDataArgs,
+    ReplaceAgentTokenV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const replaceAgentTokenV1InstructionDiscriminator = 8;
+
+// Instruction.
+export function replaceAgentTokenV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: ReplaceAgentTokenV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    agentTokenChange: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.agentTokenChange ?? null,
+    },
+    asset: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    mint: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mint ?? null,
+    },
+    mintAuthority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.mintAuthority ?? null,
+    },
+    payer: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.agentTokenChange.value) {
+    resolvedAccounts.agentTokenChange.value = findAgentTokenChangeV1Pda(
+      context,
+      { asset: expectPublicKey(resolvedAccounts.asset.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getReplaceAgentTokenV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
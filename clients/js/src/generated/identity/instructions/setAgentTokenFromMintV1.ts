@@ -0,0 +1,174 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetAgentTokenFromMintV1InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2. */
+  agentIdentity?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The SPL Token or Token-2022 mint of the agent token */
+  mint: PublicKey | Pda;
+  /** The mint authority of the mint. Not required if the asset signer is the mint authority. */
+  mintAuthority?: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Authority must be the asset signer. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetAgentTokenFromMintV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type SetAgentTokenFromMintV1InstructionDataArgs = {};
+
+export function getSetAgentTokenFromMintV1InstructionDataSerializer(): Serializer<
+  SetAgentTokenFromMintV1InstructionDataArgs,
+  SetAgentTokenFromMintV1InstructionData
+> {
+  return mapSerializer<
+    SetAgentTokenFromMintV1InstructionDataArgs,
+    any,
+    SetAgentTokenFromMintV1InstructionData
+  >(
+    struct<SetAgentTokenFromMintV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'SetAgentTokenFromMintV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 5, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    SetAgentTokenFromMintV1InstructionDataArgs,
+    SetAgentTokenFromMintV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const setAgentTokenFromMintV1InstructionDiscriminator = 5;
+
+// Instruction.
+export function setAgentTokenFromMintV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SetAgentTokenFromMintV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    mint: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.mint ?? null,
+    },
+    mintAuthority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mintAuthority ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetAgentTokenFromMintV1InstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
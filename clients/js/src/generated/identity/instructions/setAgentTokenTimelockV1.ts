@@ -0,0 +1,173 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetAgentTokenTimelockV1InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2. */
+  agentIdentity?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Authority must be the asset signer. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetAgentTokenTimelockV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  timelock: number;
+};
+
+export type SetAgentTokenTimelockV1InstructionDataArgs = {
+  timelock: number;
+};
+
+export function getSetAgentTokenTimelockV1InstructionDataSerializer(): Serializer<
+  SetAgentTokenTimelockV1InstructionDataArgs,
+  SetAgentTokenTimelockV1InstructionData
+> {
+  return mapSerializer<
+    SetAgentTokenTimelockV1InstructionDataArgs,
+    any,
+    SetAgentTokenTimelockV1InstructionData
+  >(
+    struct<SetAgentTokenTimelockV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 3 })],
+        ['timelock', u32()],
+      ],
+      { description: 'SetAgentTokenTimelockV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 6, padding: [0, 0, 0] })
+  ) as Serializer<
+    SetAgentTokenTimelockV1InstructionDataArgs,
+    SetAgentTokenTimelockV1InstructionData
+  >;
+}
+
+// Args.
+export type SetAgentTokenTimelockV1InstructionArgs =
+  SetAgentTokenTimelockV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const setAgentTokenTimelockV1InstructionDiscriminator = 6;
+
+// Instruction.
+export function setAgentTokenTimelockV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SetAgentTokenTimelockV1InstructionAccounts &
+    SetAgentTokenTimelockV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetAgentTokenTimelockV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetAgentTokenTimelockV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetAgentTokenTimelockV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
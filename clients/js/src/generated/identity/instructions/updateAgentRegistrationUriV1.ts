@@ -0,0 +1,197 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateAgentRegistrationUriV1InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2. */
+  agentIdentity?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Authority must be the asset owner or the asset signer. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateAgentRegistrationUriV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  agentRegistrationUri: string;
+};
+
+export type UpdateAgentRegistrationUriV1InstructionDataArgs = {
+  agentRegistrationUri: string;
+};
+
+export function getUpdateAgentRegistrationUriV1InstructionDataSerializer(): Serializer<
+  UpdateAgentRegistrationUriV1InstructionDataArgs,
+  UpdateAgentRegistrationUriV1InstructionData
+> {
+  return mapSerializer<
+    UpdateAgentRegistrationUriV1InstructionDataArgs,
+    any,
+    UpdateAgentRegistrationUriV1InstructionData
+  >(
+    struct<UpdateAgentRegistrationUriV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['agentRegistrationUri', string()],
+      ],
+      { description: 'UpdateAgentRegistrationUriV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 2, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    UpdateAgentRegistrationUriV1InstructionDataArgs,
+    UpdateAgentRegistrationUriV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdateAgentRegistrationUriV1InstructionArgs =
+  UpdateAgentRegistrationUriV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const updateAgentRegistrationUriV1InstructionDiscriminator = 2;
+
+// Instruction.
+export function updateAgentRegistrationUriV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: UpdateAgentRegistrationUriV1InstructionAccounts &
+    UpdateAgentRegistrationUriV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateAgentRegistrationUriV1InstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateAgentRegistrationUriV1InstructionDataSerializer().serialize(
+      resolvedArgs as UpdateAgentRegistrationUriV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,202 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentIdentityV2Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateIdentityLifecycleChecksV1InstructionAccounts = {
+  /** The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2. */
+  agentIdentity?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** Authority must be the asset owner or the asset signer. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateIdentityLifecycleChecksV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  lifecycleChecks: number;
+};
+
+export type UpdateIdentityLifecycleChecksV1InstructionDataArgs = {
+  lifecycleChecks?: number;
+};
+
+export function getUpdateIdentityLifecycleChecksV1InstructionDataSerializer(): Serializer<
+  UpdateIdentityLifecycleChecksV1InstructionDataArgs,
+  UpdateIdentityLifecycleChecksV1InstructionData
+> {
+  return mapSerializer<
+    UpdateIdentityLifecycleChecksV1InstructionDataArgs,
+    any,
+    UpdateIdentityLifecycleChecksV1InstructionData
+  >(
+    struct<UpdateIdentityLifecycleChecksV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 3 })],
+        ['lifecycleChecks', u32()],
+      ],
+      { description: 'UpdateIdentityLifecycleChecksV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 9,
+      padding: [0, 0, 0],
+      lifecycleChecks: value.lifecycleChecks ?? 0,
+    })
+  ) as Serializer<
+    UpdateIdentityLifecycleChecksV1InstructionDataArgs,
+    UpdateIdentityLifecycleChecksV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdateIdentityLifecycleChecksV1InstructionArgs =
+  UpdateIdentityLifecycleChecksV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const updateIdentityLifecycleChecksV1InstructionDiscriminator = 9;
+
+// Instruction.
+export function updateIdentityLifecycleChecksV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: UpdateIdentityLifecycleChecksV1InstructionAccounts &
+    UpdateIdentityLifecycleChecksV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentIdentity',
+    '1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentIdentity: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateIdentityLifecycleChecksV1InstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.agentIdentity.value) {
+    resolvedAccounts.agentIdentity.value = findAgentIdentityV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getUpdateIdentityLifecycleChecksV1InstructionDataSerializer().serialize(
+      resolvedArgs as UpdateIdentityLifecycleChecksV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
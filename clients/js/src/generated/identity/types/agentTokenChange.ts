@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum AgentTokenChange {
+  None,
+  Clear,
+  Replace,
+}
+
+export type AgentTokenChangeArgs = AgentTokenChange;
+
+export function getAgentTokenChangeSerializer(): Serializer<
+  AgentTokenChangeArgs,
+  AgentTokenChange
+> {
+  return scalarEnum<AgentTokenChange>(AgentTokenChange, {
+    description: 'AgentTokenChange',
+  }) as Serializer<AgentTokenChangeArgs, AgentTokenChange>;
+}
@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum AgentTokenSource {
+  Genesis,
+  MintAuthority,
+  AssetSigner,
+}
+
+export type AgentTokenSourceArgs = AgentTokenSource;
+
+export function getAgentTokenSourceSerializer(): Serializer<
+  AgentTokenSourceArgs,
+  AgentTokenSource
+> {
+  return scalarEnum<AgentTokenSource>(AgentTokenSource, {
+    description: 'AgentTokenSource',
+  }) as Serializer<AgentTokenSourceArgs, AgentTokenSource>;
+}
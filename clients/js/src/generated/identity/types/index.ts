@@ -6,4 +6,7 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './agentTokenChange';
+export * from './agentTokenSource';
 export * from './key';
+export * from './mplAgentIdentityEvent';
@@ -12,6 +12,8 @@ export enum Key {
   Uninitialized,
   AgentIdentityV1,
   AgentIdentityV2,
+  CollectionIdentityV1,
+  AgentTokenChangeV1,
 }
 
 export type KeyArgs = Key;
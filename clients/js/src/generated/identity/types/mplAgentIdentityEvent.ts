@@ -0,0 +1,375 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  Option,
+  OptionOrNullable,
+  PublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  dataEnum,
+  i64,
+  option,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  AgentTokenChange,
+  AgentTokenChangeArgs,
+  AgentTokenSource,
+  AgentTokenSourceArgs,
+  getAgentTokenChangeSerializer,
+  getAgentTokenSourceSerializer,
+} from '.';
+
+export type MplAgentIdentityEvent =
+  | {
+      __kind: 'IdentityRegisteredV1';
+      asset: PublicKey;
+      collection: Option<PublicKey>;
+      registrationUri: string;
+    }
+  | {
+      __kind: 'RegistrationUriUpdatedV1';
+      asset: PublicKey;
+      registrationUri: string;
+    }
+  | { __kind: 'IdentityDeregisteredV1'; asset: PublicKey }
+  | { __kind: 'IdentityMigratedV1'; asset: PublicKey }
+  | {
+      __kind: 'AgentTokenSetV1';
+      asset: PublicKey;
+      agentToken: PublicKey;
+      source: AgentTokenSource;
+    }
+  | { __kind: 'AgentTokenTimelockSetV1'; asset: PublicKey; timelock: number }
+  | {
+      __kind: 'AgentTokenChangeScheduledV1';
+      asset: PublicKey;
+      agentToken: PublicKey;
+      change: AgentTokenChange;
+      newAgentToken: Option<PublicKey>;
+      unlockAt: bigint;
+      expiresAt: bigint;
+    }
+  | { __kind: 'AgentTokenClearedV1'; asset: PublicKey; agentToken: PublicKey }
+  | {
+      __kind: 'AgentTokenReplacedV1';
+      asset: PublicKey;
+      oldAgentToken: PublicKey;
+      newAgentToken: PublicKey;
+      source: AgentTokenSource;
+    }
+  | {
+      __kind: 'LifecycleChecksUpdatedV1';
+      asset: PublicKey;
+      lifecycleChecks: number;
+    }
+  | {
+      __kind: 'CollectionIdentityRegisteredV1';
+      collection: PublicKey;
+      registrationUri: string;
+    }
+  | {
+      __kind: 'CollectionIdentityInheritedV1';
+      asset: PublicKey;
+      collection: PublicKey;
+    };
+
+export type MplAgentIdentityEventArgs =
+  | {
+      __kind: 'IdentityRegisteredV1';
+      asset: PublicKey;
+      collection: OptionOrNullable<PublicKey>;
+      registrationUri: string;
+    }
+  | {
+      __kind: 'RegistrationUriUpdatedV1';
+      asset: PublicKey;
+      registrationUri: string;
+    }
+  | { __kind: 'IdentityDeregisteredV1'; asset: PublicKey }
+  | { __kind: 'IdentityMigratedV1'; asset: PublicKey }
+  | {
+      __kind: 'AgentTokenSetV1';
+      asset: PublicKey;
+      agentToken: PublicKey;
+      source: AgentTokenSourceArgs;
+    }
+  | { __kind: 'AgentTokenTimelockSetV1'; asset: PublicKey; timelock: number }
+  | {
+      __kind: 'AgentTokenChangeScheduledV1';
+      asset: PublicKey;
+      agentToken: PublicKey;
+      change: AgentTokenChangeArgs;
+      newAgentToken: OptionOrNullable<PublicKey>;
+      unlockAt: number | bigint;
+      expiresAt: number | bigint;
+    }
+  | { __kind: 'AgentTokenClearedV1'; asset: PublicKey; agentToken: PublicKey }
+  | {
+      __kind: 'AgentTokenReplacedV1';
+      asset: PublicKey;
+      oldAgentToken: PublicKey;
+      newAgentToken: PublicKey;
+      source: AgentTokenSourceArgs;
+    }
+  | {
+      __kind: 'LifecycleChecksUpdatedV1';
+      asset: PublicKey;
+      lifecycleChecks: number;
+    }
+  | {
+      __kind: 'CollectionIdentityRegisteredV1';
+      collection: PublicKey;
+      registrationUri: string;
+    }
+  | {
+      __kind: 'CollectionIdentityInheritedV1';
+      asset: PublicKey;
+      collection: PublicKey;
+    };
+
+export function getMplAgentIdentityEventSerializer(): Serializer<
+  MplAgentIdentityEventArgs,
+  MplAgentIdentityEvent
+> {
+  return dataEnum<MplAgentIdentityEvent>(
+    [
+      [
+        'IdentityRegisteredV1',
+        struct<
+          GetDataEnumKindContent<MplAgentIdentityEvent, 'IdentityRegisteredV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['collection', option(publicKeySerializer())],
+          ['registrationUri', string()],
+        ]),
+      ],
+      [
+        'RegistrationUriUpdatedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentIdentityEvent,
+            'RegistrationUriUpdatedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['registrationUri', string()],
+        ]),
+      ],
+      [
+        'IdentityDeregisteredV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentIdentityEvent,
+            'IdentityDeregisteredV1'
+          >
+        >([['asset', publicKeySerializer()]]),
+      ],
+      [
+        'IdentityMigratedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentIdentityEvent, 'IdentityMigratedV1'>
+        >([['asset', publicKeySerializer()]]),
+      ],
+      [
+        'AgentTokenSetV1',
+        struct<
+          GetDataEnumKindContent<MplAgentIdentityEvent, 'AgentTokenSetV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['agentToken', publicKeySerializer()],
+          ['source', getAgentTokenSourceSerializer()],
+        ]),
+      ],
+      [
+        'AgentTokenTimelockSetV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentIdentityEvent,
+            'AgentTokenTimelockSetV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['timelock', u32()],
+        ]),
+      ],
+      [
+        'AgentTokenChangeScheduledV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentIdentityEvent,
+            'AgentTokenChangeScheduledV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['agentToken', publicKeySerializer()],
+          ['change', getAgentTokenChangeSerializer()],
+          ['newAgentToken', option(publicKeySerializer())],
+          ['unlockAt', i64()],
+          ['expiresAt', i64()],
+        ]),
+      ],
+      [
+        'AgentTokenClearedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentIdentityEvent, 'AgentTokenClearedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['agentToken', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'AgentTokenReplacedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentIdentityEvent, 'AgentTokenReplacedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['oldAgentToken', publicKeySerializer()],
+          ['newAgentToken', publicKeySerializer()],
+          ['source', getAgentTokenSourceSerializer()],
+        ]),
+      ],
+      [
+        'LifecycleChecksUpdatedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentIdentityEvent,
+            'LifecycleChecksUpdatedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['lifecycleChecks', u32()],
+        ]),
+      ],
+      [
+        'CollectionIdentityRegisteredV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentIdentityEvent,
+            'CollectionIdentityRegisteredV1'
+          >
+        >([
+          ['collection', publicKeySerializer()],
+          ['registrationUri', string()],
+        ]),
+      ],
+      [
+        'CollectionIdentityInheritedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentIdentityEvent,
+            'CollectionIdentityInheritedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['collection', publicKeySerializer()],
+        ]),
+      ],
+    ],
+    { description: 'MplAgentIdentityEvent' }
+  ) as Serializer<MplAgentIdentityEventArgs, MplAgentIdentityEvent>;
+}
+
+// Data Enum Helpers.
+export function mplAgentIdentityEvent(
+  kind: 'IdentityRegisteredV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'IdentityRegisteredV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'IdentityRegisteredV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'RegistrationUriUpdatedV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'RegistrationUriUpdatedV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'RegistrationUriUpdatedV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'IdentityDeregisteredV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'IdentityDeregisteredV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'IdentityDeregisteredV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'IdentityMigratedV1',
+  data: GetDataEnumKindContent<MplAgentIdentityEventArgs, 'IdentityMigratedV1'>
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'IdentityMigratedV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'AgentTokenSetV1',
+  data: GetDataEnumKindContent<MplAgentIdentityEventArgs, 'AgentTokenSetV1'>
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'AgentTokenSetV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'AgentTokenTimelockSetV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'AgentTokenTimelockSetV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'AgentTokenTimelockSetV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'AgentTokenChangeScheduledV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'AgentTokenChangeScheduledV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'AgentTokenChangeScheduledV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'AgentTokenClearedV1',
+  data: GetDataEnumKindContent<MplAgentIdentityEventArgs, 'AgentTokenClearedV1'>
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'AgentTokenClearedV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'AgentTokenReplacedV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'AgentTokenReplacedV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'AgentTokenReplacedV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'LifecycleChecksUpdatedV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'LifecycleChecksUpdatedV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'LifecycleChecksUpdatedV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'CollectionIdentityRegisteredV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'CollectionIdentityRegisteredV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'CollectionIdentityRegisteredV1'>;
+export function mplAgentIdentityEvent(
+  kind: 'CollectionIdentityInheritedV1',
+  data: GetDataEnumKindContent<
+    MplAgentIdentityEventArgs,
+    'CollectionIdentityInheritedV1'
+  >
+): GetDataEnumKind<MplAgentIdentityEventArgs, 'CollectionIdentityInheritedV1'>;
+export function mplAgentIdentityEvent<
+  K extends MplAgentIdentityEventArgs['__kind'],
+>(kind: K, data?: any): Extract<MplAgentIdentityEventArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isMplAgentIdentityEvent<
+  K extends MplAgentIdentityEvent['__kind'],
+>(
+  kind: K,
+  value: MplAgentIdentityEvent
+): value is MplAgentIdentityEvent & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,283 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type AgentReputationV2 = Account<AgentReputationV2AccountData>;
+
+export type AgentReputationV2AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  asset: PublicKey;
+  feedbackCount: bigint;
+  scoreSum: bigint;
+  scoreSumSquares: bigint;
+  lastFeedbackSlot: bigint;
+  decayedScore: bigint;
+  decayedCount: bigint;
+  decaySlot: bigint;
+  weightSum: bigint;
+  weightedScoreSum: bigint;
+  disputedCount: bigint;
+  disputedScoreSum: bigint;
+  disputedWeightSum: bigint;
+  disputedWeightedScoreSum: bigint;
+  reserved: Array<number>;
+};
+
+export type AgentReputationV2AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  asset: PublicKey;
+  feedbackCount: number | bigint;
+  scoreSum: number | bigint;
+  scoreSumSquares: number | bigint;
+  lastFeedbackSlot: number | bigint;
+  decayedScore: number | bigint;
+  decayedCount: number | bigint;
+  decaySlot: number | bigint;
+  weightSum: number | bigint;
+  weightedScoreSum: number | bigint;
+  disputedCount: number | bigint;
+  disputedScoreSum: number | bigint;
+  disputedWeightSum: number | bigint;
+  disputedWeightedScoreSum: number | bigint;
+};
+
+export function getAgentReputationV2AccountDataSerializer(): Serializer<
+  AgentReputationV2AccountDataArgs,
+  AgentReputationV2AccountData
+> {
+  return mapSerializer<
+    AgentReputationV2AccountDataArgs,
+    any,
+    AgentReputationV2AccountData
+  >(
+    struct<AgentReputationV2AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['asset', publicKeySerializer()],
+        ['feedbackCount', u64()],
+        ['scoreSum', u64()],
+        ['scoreSumSquares', u64()],
+        ['lastFeedbackSlot', u64()],
+        ['decayedScore', u64()],
+        ['decayedCount', u64()],
+        ['decaySlot', u64()],
+        ['weightSum', u64()],
+        ['weightedScoreSum', u64()],
+        ['disputedCount', u64()],
+        ['disputedScoreSum', u64()],
+        ['disputedWeightSum', u64()],
+        ['disputedWeightedScoreSum', u64()],
+        ['reserved', array(u8(), { size: 24 })],
+      ],
+      { description: 'AgentReputationV2AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0, 0, 0, 0, 0],
+      reserved: [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      ],
+    })
+  ) as Serializer<
+    AgentReputationV2AccountDataArgs,
+    AgentReputationV2AccountData
+  >;
+}
+
+export function deserializeAgentReputationV2(
+  rawAccount: RpcAccount
+): AgentReputationV2 {
+  return deserializeAccount(
+    rawAccount,
+    getAgentReputationV2AccountDataSerializer()
+  );
+}
+
+export async function fetchAgentReputationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AgentReputationV2> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AgentReputationV2');
+  return deserializeAgentReputationV2(maybeAccount);
+}
+
+export async function safeFetchAgentReputationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AgentReputationV2 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeAgentReputationV2(maybeAccount)
+    : null;
+}
+
+export async function fetchAllAgentReputationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AgentReputationV2[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AgentReputationV2');
+    return deserializeAgentReputationV2(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAgentReputationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AgentReputationV2[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAgentReputationV2(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAgentReputationV2GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      asset: PublicKey;
+      feedbackCount: number | bigint;
+      scoreSum: number | bigint;
+      scoreSumSquares: number | bigint;
+      lastFeedbackSlot: number | bigint;
+      decayedScore: number | bigint;
+      decayedCount: number | bigint;
+      decaySlot: number | bigint;
+      weightSum: number | bigint;
+      weightedScoreSum: number | bigint;
+      disputedCount: number | bigint;
+      disputedScoreSum: number | bigint;
+      disputedWeightSum: number | bigint;
+      disputedWeightedScoreSum: number | bigint;
+      reserved: Array<number>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      asset: [8, publicKeySerializer()],
+      feedbackCount: [40, u64()],
+      scoreSum: [48, u64()],
+      scoreSumSquares: [56, u64()],
+      lastFeedbackSlot: [64, u64()],
+      decayedScore: [72, u64()],
+      decayedCount: [80, u64()],
+      decaySlot: [88, u64()],
+      weightSum: [96, u64()],
+      weightedScoreSum: [104, u64()],
+      disputedCount: [112, u64()],
+      disputedScoreSum: [120, u64()],
+      disputedWeightSum: [128, u64()],
+      disputedWeightedScoreSum: [136, u64()],
+      reserved: [144, array(u8(), { size: 24 })],
+    })
+    .deserializeUsing<AgentReputationV2>((account) =>
+      deserializeAgentReputationV2(account)
+    );
+}
+
+export function getAgentReputationV2Size(): number {
+  return 168;
+}
+
+export function findAgentReputationV2Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('agent_reputation'),
+    publicKeySerializer().serialize(seeds.asset),
+  ]);
+}
+
+export async function fetchAgentReputationV2FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAgentReputationV2Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AgentReputationV2> {
+  return fetchAgentReputationV2(
+    context,
+    findAgentReputationV2Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchAgentReputationV2FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAgentReputationV2Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AgentReputationV2 | null> {
+  return safeFetchAgentReputationV2(
+    context,
+    findAgentReputationV2Pda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,248 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  FeedbackStatus,
+  FeedbackStatusArgs,
+  Key,
+  KeyArgs,
+  getFeedbackStatusSerializer,
+  getKeySerializer,
+} from '../types';
+
+export type FeedbackV1 = Account<FeedbackV1AccountData>;
+
+export type FeedbackV1AccountData = {
+  key: Key;
+  bump: number;
+  score: number;
+  status: FeedbackStatus;
+  weight: number;
+  asset: PublicKey;
+  reviewer: PublicKey;
+  index: bigint;
+  treeIndex: bigint;
+  nonce: bigint;
+  slot: bigint;
+  responseCount: bigint;
+  responseSlot: bigint;
+  responseHash: Uint8Array;
+};
+
+export type FeedbackV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  score: number;
+  status: FeedbackStatusArgs;
+  weight: number;
+  asset: PublicKey;
+  reviewer: PublicKey;
+  index: number | bigint;
+  treeIndex: number | bigint;
+  nonce: number | bigint;
+  slot: number | bigint;
+  responseCount: number | bigint;
+  responseSlot: number | bigint;
+  responseHash: Uint8Array;
+};
+
+export function getFeedbackV1AccountDataSerializer(): Serializer<
+  FeedbackV1AccountDataArgs,
+  FeedbackV1AccountData
+> {
+  return struct<FeedbackV1AccountData>(
+    [
+      ['key', getKeySerializer()],
+      ['bump', u8()],
+      ['score', u8()],
+      ['status', getFeedbackStatusSerializer()],
+      ['weight', u32()],
+      ['asset', publicKeySerializer()],
+      ['reviewer', publicKeySerializer()],
+      ['index', u64()],
+      ['treeIndex', u64()],
+      ['nonce', u64()],
+      ['slot', u64()],
+      ['responseCount', u64()],
+      ['responseSlot', u64()],
+      ['responseHash', bytes({ size: 32 })],
+    ],
+    { description: 'FeedbackV1AccountData' }
+  ) as Serializer<FeedbackV1AccountDataArgs, FeedbackV1AccountData>;
+}
+
+export function deserializeFeedbackV1(rawAccount: RpcAccount): FeedbackV1 {
+  return deserializeAccount(rawAccount, getFeedbackV1AccountDataSerializer());
+}
+
+export async function fetchFeedbackV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<FeedbackV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'FeedbackV1');
+  return deserializeFeedbackV1(maybeAccount);
+}
+
+export async function safeFetchFeedbackV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<FeedbackV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeFeedbackV1(maybeAccount) : null;
+}
+
+export async function fetchAllFeedbackV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<FeedbackV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'FeedbackV1');
+    return deserializeFeedbackV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllFeedbackV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<FeedbackV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeFeedbackV1(maybeAccount as RpcAccount));
+}
+
+export function getFeedbackV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      score: number;
+      status: FeedbackStatusArgs;
+      weight: number;
+      asset: PublicKey;
+      reviewer: PublicKey;
+      index: number | bigint;
+      treeIndex: number | bigint;
+      nonce: number | bigint;
+      slot: number | bigint;
+      responseCount: number | bigint;
+      responseSlot: number | bigint;
+      responseHash: Uint8Array;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      score: [2, u8()],
+      status: [3, getFeedbackStatusSerializer()],
+      weight: [4, u32()],
+      asset: [8, publicKeySerializer()],
+      reviewer: [40, publicKeySerializer()],
+      index: [72, u64()],
+      treeIndex: [80, u64()],
+      nonce: [88, u64()],
+      slot: [96, u64()],
+      responseCount: [104, u64()],
+      responseSlot: [112, u64()],
+      responseHash: [120, bytes({ size: 32 })],
+    })
+    .deserializeUsing<FeedbackV1>((account) => deserializeFeedbackV1(account));
+}
+
+export function getFeedbackV1Size(): number {
+  return 152;
+}
+
+export function findFeedbackV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+    /** The address of the reviewer */
+    reviewer: PublicKey;
+    /** Index of the feedback among the reviewer's feedback for the asset */
+    index: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('feedback'),
+    publicKeySerializer().serialize(seeds.asset),
+    publicKeySerializer().serialize(seeds.reviewer),
+    u64().serialize(seeds.index),
+  ]);
+}
+
+export async function fetchFeedbackV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findFeedbackV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<FeedbackV1> {
+  return fetchFeedbackV1(context, findFeedbackV1Pda(context, seeds), options);
+}
+
+export async function safeFetchFeedbackV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findFeedbackV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<FeedbackV1 | null> {
+  return safeFetchFeedbackV1(
+    context,
+    findFeedbackV1Pda(context, seeds),
+    options
+  );
+}
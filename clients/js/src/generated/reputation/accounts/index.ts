@@ -7,3 +7,11 @@
  */
 
 export * from './agentReputationV1';
+export * from './agentReputationV2';
+export * from './feedbackV1';
+export * from './receiptNullifierV1';
+export * from './reputationConfigV1';
+export * from './reputationSnapshotV1';
+export * from './reviewerHistoryV1';
+export * from './tokenLockV1';
+export * from './weightingPolicyV1';
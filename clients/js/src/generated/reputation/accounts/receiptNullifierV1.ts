@@ -0,0 +1,226 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ReceiptNullifierV1 = Account<ReceiptNullifierV1AccountData>;
+
+export type ReceiptNullifierV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  treeIndex: bigint;
+  nonce: bigint;
+  asset: PublicKey;
+  reviewer: PublicKey;
+};
+
+export type ReceiptNullifierV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  treeIndex: number | bigint;
+  nonce: number | bigint;
+  asset: PublicKey;
+  reviewer: PublicKey;
+};
+
+export function getReceiptNullifierV1AccountDataSerializer(): Serializer<
+  ReceiptNullifierV1AccountDataArgs,
+  ReceiptNullifierV1AccountData
+> {
+  return mapSerializer<
+    ReceiptNullifierV1AccountDataArgs,
+    any,
+    ReceiptNullifierV1AccountData
+  >(
+    struct<ReceiptNullifierV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['treeIndex', u64()],
+        ['nonce', u64()],
+        ['asset', publicKeySerializer()],
+        ['reviewer', publicKeySerializer()],
+      ],
+      { description: 'ReceiptNullifierV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ReceiptNullifierV1AccountDataArgs,
+    ReceiptNullifierV1AccountData
+  >;
+}
+
+export function deserializeReceiptNullifierV1(
+  rawAccount: RpcAccount
+): ReceiptNullifierV1 {
+  return deserializeAccount(
+    rawAccount,
+    getReceiptNullifierV1AccountDataSerializer()
+  );
+}
+
+export async function fetchReceiptNullifierV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReceiptNullifierV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ReceiptNullifierV1');
+  return deserializeReceiptNullifierV1(maybeAccount);
+}
+
+export async function safeFetchReceiptNullifierV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReceiptNullifierV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeReceiptNullifierV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllReceiptNullifierV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReceiptNullifierV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ReceiptNullifierV1');
+    return deserializeReceiptNullifierV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllReceiptNullifierV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReceiptNullifierV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeReceiptNullifierV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getReceiptNullifierV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      treeIndex: number | bigint;
+      nonce: number | bigint;
+      asset: PublicKey;
+      reviewer: PublicKey;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      treeIndex: [8, u64()],
+      nonce: [16, u64()],
+      asset: [24, publicKeySerializer()],
+      reviewer: [56, publicKeySerializer()],
+    })
+    .deserializeUsing<ReceiptNullifierV1>((account) =>
+      deserializeReceiptNullifierV1(account)
+    );
+}
+
+export function getReceiptNullifierV1Size(): number {
+  return 88;
+}
+
+export function findReceiptNullifierV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** Index of the receipts tree */
+    treeIndex: number | bigint;
+    /** Nonce of the receipt leaf */
+    nonce: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('receipt_nullifier'),
+    u64().serialize(seeds.treeIndex),
+    u64().serialize(seeds.nonce),
+  ]);
+}
+
+export async function fetchReceiptNullifierV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReceiptNullifierV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReceiptNullifierV1> {
+  return fetchReceiptNullifierV1(
+    context,
+    findReceiptNullifierV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchReceiptNullifierV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReceiptNullifierV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReceiptNullifierV1 | null> {
+  return safeFetchReceiptNullifierV1(
+    context,
+    findReceiptNullifierV1Pda(context, seeds),
+    options
+  );
+}
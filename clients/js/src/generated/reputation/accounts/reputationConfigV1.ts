@@ -0,0 +1,218 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ReputationConfigV1 = Account<ReputationConfigV1AccountData>;
+
+export type ReputationConfigV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  authority: PublicKey;
+  halfLifeSlots: bigint;
+  reserved: Array<number>;
+};
+
+export type ReputationConfigV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  authority: PublicKey;
+  halfLifeSlots: number | bigint;
+};
+
+export function getReputationConfigV1AccountDataSerializer(): Serializer<
+  ReputationConfigV1AccountDataArgs,
+  ReputationConfigV1AccountData
+> {
+  return mapSerializer<
+    ReputationConfigV1AccountDataArgs,
+    any,
+    ReputationConfigV1AccountData
+  >(
+    struct<ReputationConfigV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['authority', publicKeySerializer()],
+        ['halfLifeSlots', u64()],
+        ['reserved', array(u8(), { size: 64 })],
+      ],
+      { description: 'ReputationConfigV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0, 0, 0, 0, 0],
+      reserved: [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      ],
+    })
+  ) as Serializer<
+    ReputationConfigV1AccountDataArgs,
+    ReputationConfigV1AccountData
+  >;
+}
+
+export function deserializeReputationConfigV1(
+  rawAccount: RpcAccount
+): ReputationConfigV1 {
+  return deserializeAccount(
+    rawAccount,
+    getReputationConfigV1AccountDataSerializer()
+  );
+}
+
+export async function fetchReputationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReputationConfigV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ReputationConfigV1');
+  return deserializeReputationConfigV1(maybeAccount);
+}
+
+export async function safeFetchReputationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReputationConfigV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeReputationConfigV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllReputationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReputationConfigV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ReputationConfigV1');
+    return deserializeReputationConfigV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllReputationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReputationConfigV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeReputationConfigV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getReputationConfigV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      authority: PublicKey;
+      halfLifeSlots: number | bigint;
+      reserved: Array<number>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      authority: [8, publicKeySerializer()],
+      halfLifeSlots: [40, u64()],
+      reserved: [48, array(u8(), { size: 64 })],
+    })
+    .deserializeUsing<ReputationConfigV1>((account) =>
+      deserializeReputationConfigV1(account)
+    );
+}
+
+export function getReputationConfigV1Size(): number {
+  return 112;
+}
+
+export function findReputationConfigV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('reputation_config'),
+  ]);
+}
+
+export async function fetchReputationConfigV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ReputationConfigV1> {
+  return fetchReputationConfigV1(
+    context,
+    findReputationConfigV1Pda(context),
+    options
+  );
+}
+
+export async function safeFetchReputationConfigV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ReputationConfigV1 | null> {
+  return safeFetchReputationConfigV1(
+    context,
+    findReputationConfigV1Pda(context),
+    options
+  );
+}
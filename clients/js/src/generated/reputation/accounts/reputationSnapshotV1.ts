@@ -0,0 +1,281 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ReputationSnapshotV1 = Account<ReputationSnapshotV1AccountData>;
+
+export type ReputationSnapshotV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  asset: PublicKey;
+  epoch: bigint;
+  slot: bigint;
+  feedbackCount: bigint;
+  scoreSum: bigint;
+  scoreSumSquares: bigint;
+  lastFeedbackSlot: bigint;
+  decayedScore: bigint;
+  decayedCount: bigint;
+  weightSum: bigint;
+  weightedScoreSum: bigint;
+  disputedCount: bigint;
+  disputedScoreSum: bigint;
+  disputedWeightSum: bigint;
+  disputedWeightedScoreSum: bigint;
+};
+
+export type ReputationSnapshotV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  asset: PublicKey;
+  epoch: number | bigint;
+  slot: number | bigint;
+  feedbackCount: number | bigint;
+  scoreSum: number | bigint;
+  scoreSumSquares: number | bigint;
+  lastFeedbackSlot: number | bigint;
+  decayedScore: number | bigint;
+  decayedCount: number | bigint;
+  weightSum: number | bigint;
+  weightedScoreSum: number | bigint;
+  disputedCount: number | bigint;
+  disputedScoreSum: number | bigint;
+  disputedWeightSum: number | bigint;
+  disputedWeightedScoreSum: number | bigint;
+};
+
+export function getReputationSnapshotV1AccountDataSerializer(): Serializer<
+  ReputationSnapshotV1AccountDataArgs,
+  ReputationSnapshotV1AccountData
+> {
+  return mapSerializer<
+    ReputationSnapshotV1AccountDataArgs,
+    any,
+    ReputationSnapshotV1AccountData
+  >(
+    struct<ReputationSnapshotV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['asset', publicKeySerializer()],
+        ['epoch', u64()],
+        ['slot', u64()],
+        ['feedbackCount', u64()],
+        ['scoreSum', u64()],
+        ['scoreSumSquares', u64()],
+        ['lastFeedbackSlot', u64()],
+        ['decayedScore', u64()],
+        ['decayedCount', u64()],
+        ['weightSum', u64()],
+        ['weightedScoreSum', u64()],
+        ['disputedCount', u64()],
+        ['disputedScoreSum', u64()],
+        ['disputedWeightSum', u64()],
+        ['disputedWeightedScoreSum', u64()],
+      ],
+      { description: 'ReputationSnapshotV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ReputationSnapshotV1AccountDataArgs,
+    ReputationSnapshotV1AccountData
+  >;
+}
+
+export function deserializeReputationSnapshotV1(
+  rawAccount: RpcAccount
+): ReputationSnapshotV1 {
+  return deserializeAccount(
+    rawAccount,
+    getReputationSnapshotV1AccountDataSerializer()
+  );
+}
+
+export async function fetchReputationSnapshotV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReputationSnapshotV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ReputationSnapshotV1');
+  return deserializeReputationSnapshotV1(maybeAccount);
+}
+
+export async function safeFetchReputationSnapshotV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReputationSnapshotV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeReputationSnapshotV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllReputationSnapshotV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReputationSnapshotV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ReputationSnapshotV1');
+    return deserializeReputationSnapshotV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllReputationSnapshotV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReputationSnapshotV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeReputationSnapshotV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getReputationSnapshotV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      asset: PublicKey;
+      epoch: number | bigint;
+      slot: number | bigint;
+      feedbackCount: number | bigint;
+      scoreSum: number | bigint;
+      scoreSumSquares: number | bigint;
+      lastFeedbackSlot: number | bigint;
+      decayedScore: number | bigint;
+      decayedCount: number | bigint;
+      weightSum: number | bigint;
+      weightedScoreSum: number | bigint;
+      disputedCount: number | bigint;
+      disputedScoreSum: number | bigint;
+      disputedWeightSum: number | bigint;
+      disputedWeightedScoreSum: number | bigint;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      asset: [8, publicKeySerializer()],
+      epoch: [40, u64()],
+      slot: [48, u64()],
+      feedbackCount: [56, u64()],
+      scoreSum: [64, u64()],
+      scoreSumSquares: [72, u64()],
+      lastFeedbackSlot: [80, u64()],
+      decayedScore: [88, u64()],
+      decayedCount: [96, u64()],
+      weightSum: [104, u64()],
+      weightedScoreSum: [112, u64()],
+      disputedCount: [120, u64()],
+      disputedScoreSum: [128, u64()],
+      disputedWeightSum: [136, u64()],
+      disputedWeightedScoreSum: [144, u64()],
+    })
+    .deserializeUsing<ReputationSnapshotV1>((account) =>
+      deserializeReputationSnapshotV1(account)
+    );
+}
+
+export function getReputationSnapshotV1Size(): number {
+  return 152;
+}
+
+export function findReputationSnapshotV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+    /** Epoch the snapshot was taken in */
+    epoch: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('reputation_snapshot'),
+    publicKeySerializer().serialize(seeds.asset),
+    u64().serialize(seeds.epoch),
+  ]);
+}
+
+export async function fetchReputationSnapshotV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReputationSnapshotV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReputationSnapshotV1> {
+  return fetchReputationSnapshotV1(
+    context,
+    findReputationSnapshotV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchReputationSnapshotV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReputationSnapshotV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReputationSnapshotV1 | null> {
+  return safeFetchReputationSnapshotV1(
+    context,
+    findReputationSnapshotV1Pda(context, seeds),
+    options
+  );
+}
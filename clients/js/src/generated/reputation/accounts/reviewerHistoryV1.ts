@@ -0,0 +1,221 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ReviewerHistoryV1 = Account<ReviewerHistoryV1AccountData>;
+
+export type ReviewerHistoryV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  asset: PublicKey;
+  reviewer: PublicKey;
+  receiptCount: bigint;
+};
+
+export type ReviewerHistoryV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  asset: PublicKey;
+  reviewer: PublicKey;
+  receiptCount: number | bigint;
+};
+
+export function getReviewerHistoryV1AccountDataSerializer(): Serializer<
+  ReviewerHistoryV1AccountDataArgs,
+  ReviewerHistoryV1AccountData
+> {
+  return mapSerializer<
+    ReviewerHistoryV1AccountDataArgs,
+    any,
+    ReviewerHistoryV1AccountData
+  >(
+    struct<ReviewerHistoryV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['asset', publicKeySerializer()],
+        ['reviewer', publicKeySerializer()],
+        ['receiptCount', u64()],
+      ],
+      { description: 'ReviewerHistoryV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ReviewerHistoryV1AccountDataArgs,
+    ReviewerHistoryV1AccountData
+  >;
+}
+
+export function deserializeReviewerHistoryV1(
+  rawAccount: RpcAccount
+): ReviewerHistoryV1 {
+  return deserializeAccount(
+    rawAccount,
+    getReviewerHistoryV1AccountDataSerializer()
+  );
+}
+
+export async function fetchReviewerHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReviewerHistoryV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ReviewerHistoryV1');
+  return deserializeReviewerHistoryV1(maybeAccount);
+}
+
+export async function safeFetchReviewerHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReviewerHistoryV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeReviewerHistoryV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllReviewerHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReviewerHistoryV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ReviewerHistoryV1');
+    return deserializeReviewerHistoryV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllReviewerHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReviewerHistoryV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeReviewerHistoryV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getReviewerHistoryV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      asset: PublicKey;
+      reviewer: PublicKey;
+      receiptCount: number | bigint;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      asset: [8, publicKeySerializer()],
+      reviewer: [40, publicKeySerializer()],
+      receiptCount: [72, u64()],
+    })
+    .deserializeUsing<ReviewerHistoryV1>((account) =>
+      deserializeReviewerHistoryV1(account)
+    );
+}
+
+export function getReviewerHistoryV1Size(): number {
+  return 80;
+}
+
+export function findReviewerHistoryV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+    /** The address of the reviewer */
+    reviewer: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('reviewer_history'),
+    publicKeySerializer().serialize(seeds.asset),
+    publicKeySerializer().serialize(seeds.reviewer),
+  ]);
+}
+
+export async function fetchReviewerHistoryV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReviewerHistoryV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReviewerHistoryV1> {
+  return fetchReviewerHistoryV1(
+    context,
+    findReviewerHistoryV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchReviewerHistoryV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReviewerHistoryV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReviewerHistoryV1 | null> {
+  return safeFetchReviewerHistoryV1(
+    context,
+    findReviewerHistoryV1Pda(context, seeds),
+    options
+  );
+}
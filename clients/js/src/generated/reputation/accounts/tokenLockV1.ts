@@ -0,0 +1,201 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type TokenLockV1 = Account<TokenLockV1AccountData>;
+
+export type TokenLockV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  reviewer: PublicKey;
+  mint: PublicKey;
+  unlockSlot: bigint;
+};
+
+export type TokenLockV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  reviewer: PublicKey;
+  mint: PublicKey;
+  unlockSlot: number | bigint;
+};
+
+export function getTokenLockV1AccountDataSerializer(): Serializer<
+  TokenLockV1AccountDataArgs,
+  TokenLockV1AccountData
+> {
+  return mapSerializer<TokenLockV1AccountDataArgs, any, TokenLockV1AccountData>(
+    struct<TokenLockV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['reviewer', publicKeySerializer()],
+        ['mint', publicKeySerializer()],
+        ['unlockSlot', u64()],
+      ],
+      { description: 'TokenLockV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<TokenLockV1AccountDataArgs, TokenLockV1AccountData>;
+}
+
+export function deserializeTokenLockV1(rawAccount: RpcAccount): TokenLockV1 {
+  return deserializeAccount(rawAccount, getTokenLockV1AccountDataSerializer());
+}
+
+export async function fetchTokenLockV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TokenLockV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'TokenLockV1');
+  return deserializeTokenLockV1(maybeAccount);
+}
+
+export async function safeFetchTokenLockV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<TokenLockV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeTokenLockV1(maybeAccount) : null;
+}
+
+export async function fetchAllTokenLockV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TokenLockV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'TokenLockV1');
+    return deserializeTokenLockV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllTokenLockV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<TokenLockV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) => deserializeTokenLockV1(maybeAccount as RpcAccount));
+}
+
+export function getTokenLockV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      reviewer: PublicKey;
+      mint: PublicKey;
+      unlockSlot: number | bigint;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      reviewer: [8, publicKeySerializer()],
+      mint: [40, publicKeySerializer()],
+      unlockSlot: [72, u64()],
+    })
+    .deserializeUsing<TokenLockV1>((account) =>
+      deserializeTokenLockV1(account)
+    );
+}
+
+export function getTokenLockV1Size(): number {
+  return 80;
+}
+
+export function findTokenLockV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the reviewer */
+    reviewer: PublicKey;
+    /** The mint of the locked tokens */
+    mint: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('token_lock'),
+    publicKeySerializer().serialize(seeds.reviewer),
+    publicKeySerializer().serialize(seeds.mint),
+  ]);
+}
+
+export async function fetchTokenLockV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTokenLockV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TokenLockV1> {
+  return fetchTokenLockV1(context, findTokenLockV1Pda(context, seeds), options);
+}
+
+export async function safeFetchTokenLockV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findTokenLockV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<TokenLockV1 | null> {
+  return safeFetchTokenLockV1(
+    context,
+    findTokenLockV1Pda(context, seeds),
+    options
+  );
+}
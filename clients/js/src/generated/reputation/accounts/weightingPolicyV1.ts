@@ -0,0 +1,257 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type WeightingPolicyV1 = Account<WeightingPolicyV1AccountData>;
+
+export type WeightingPolicyV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  baseWeight: number;
+  receiptWeight: number;
+  maxReceipts: number;
+  identityWeight: number;
+  tokenWeight: number;
+  padding1: Array<number>;
+  tokenMint: PublicKey;
+  minTokenBalance: bigint;
+  minIdentityFeedback: bigint;
+  tokenLockSlots: bigint;
+  reserved: Array<number>;
+};
+
+export type WeightingPolicyV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  baseWeight: number;
+  receiptWeight: number;
+  maxReceipts: number;
+  identityWeight: number;
+  tokenWeight: number;
+  tokenMint: PublicKey;
+  minTokenBalance: number | bigint;
+  minIdentityFeedback: number | bigint;
+  tokenLockSlots: number | bigint;
+};
+
+export function getWeightingPolicyV1AccountDataSerializer(): Serializer<
+  WeightingPolicyV1AccountDataArgs,
+  WeightingPolicyV1AccountData
+> {
+  return mapSerializer<
+    WeightingPolicyV1AccountDataArgs,
+    any,
+    WeightingPolicyV1AccountData
+  >(
+    struct<WeightingPolicyV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['baseWeight', u32()],
+        ['receiptWeight', u32()],
+        ['maxReceipts', u32()],
+        ['identityWeight', u32()],
+        ['tokenWeight', u32()],
+        ['padding1', array(u8(), { size: 4 })],
+        ['tokenMint', publicKeySerializer()],
+        ['minTokenBalance', u64()],
+        ['minIdentityFeedback', u64()],
+        ['tokenLockSlots', u64()],
+        ['reserved', array(u8(), { size: 24 })],
+      ],
+      { description: 'WeightingPolicyV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0, 0, 0, 0, 0],
+      padding1: [0, 0, 0, 0],
+      reserved: [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      ],
+    })
+  ) as Serializer<
+    WeightingPolicyV1AccountDataArgs,
+    WeightingPolicyV1AccountData
+  >;
+}
+
+export function deserializeWeightingPolicyV1(
+  rawAccount: RpcAccount
+): WeightingPolicyV1 {
+  return deserializeAccount(
+    rawAccount,
+    getWeightingPolicyV1AccountDataSerializer()
+  );
+}
+
+export async function fetchWeightingPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<WeightingPolicyV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'WeightingPolicyV1');
+  return deserializeWeightingPolicyV1(maybeAccount);
+}
+
+export async function safeFetchWeightingPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<WeightingPolicyV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeWeightingPolicyV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllWeightingPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<WeightingPolicyV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'WeightingPolicyV1');
+    return deserializeWeightingPolicyV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllWeightingPolicyV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<WeightingPolicyV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeWeightingPolicyV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getWeightingPolicyV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      baseWeight: number;
+      receiptWeight: number;
+      maxReceipts: number;
+      identityWeight: number;
+      tokenWeight: number;
+      padding1: Array<number>;
+      tokenMint: PublicKey;
+      minTokenBalance: number | bigint;
+      minIdentityFeedback: number | bigint;
+      tokenLockSlots: number | bigint;
+      reserved: Array<number>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      baseWeight: [8, u32()],
+      receiptWeight: [12, u32()],
+      maxReceipts: [16, u32()],
+      identityWeight: [20, u32()],
+      tokenWeight: [24, u32()],
+      padding1: [28, array(u8(), { size: 4 })],
+      tokenMint: [32, publicKeySerializer()],
+      minTokenBalance: [64, u64()],
+      minIdentityFeedback: [72, u64()],
+      tokenLockSlots: [80, u64()],
+      reserved: [88, array(u8(), { size: 24 })],
+    })
+    .deserializeUsing<WeightingPolicyV1>((account) =>
+      deserializeWeightingPolicyV1(account)
+    );
+}
+
+export function getWeightingPolicyV1Size(): number {
+  return 112;
+}
+
+export function findWeightingPolicyV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('weighting_policy'),
+  ]);
+}
+
+export async function fetchWeightingPolicyV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<WeightingPolicyV1> {
+  return fetchWeightingPolicyV1(
+    context,
+    findWeightingPolicyV1Pda(context),
+    options
+  );
+}
+
+export async function safeFetchWeightingPolicyV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<WeightingPolicyV1 | null> {
+  return safeFetchWeightingPolicyV1(
+    context,
+    findWeightingPolicyV1Pda(context),
+    options
+  );
+}
@@ -96,6 +96,562 @@ nameToErrorMap.set(
   AgentReputationAlreadyRegisteredError
 );
 
+/** AgentReputationNotRegistered: Agent Reputation is not registered */
+export class AgentReputationNotRegisteredError extends ProgramError {
+  override readonly name: string = 'AgentReputationNotRegistered';
+
+  readonly code: number = 0x6; // 6
+
+  constructor(program: Program, cause?: Error) {
+    super('Agent Reputation is not registered', program, cause);
+  }
+}
+codeToErrorMap.set(0x6, AgentReputationNotRegisteredError);
+nameToErrorMap.set(
+  'AgentReputationNotRegistered',
+  AgentReputationNotRegisteredError
+);
+
+/** InvalidFeedbackScore: Feedback score must be between 0 and 100 */
+export class InvalidFeedbackScoreError extends ProgramError {
+  override readonly name: string = 'InvalidFeedbackScore';
+
+  readonly code: number = 0x7; // 7
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback score must be between 0 and 100', program, cause);
+  }
+}
+codeToErrorMap.set(0x7, InvalidFeedbackScoreError);
+nameToErrorMap.set('InvalidFeedbackScore', InvalidFeedbackScoreError);
+
+/** FeedbackUriEmpty: Feedback URI is empty */
+export class FeedbackUriEmptyError extends ProgramError {
+  override readonly name: string = 'FeedbackUriEmpty';
+
+  readonly code: number = 0x8; // 8
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback URI is empty', program, cause);
+  }
+}
+codeToErrorMap.set(0x8, FeedbackUriEmptyError);
+nameToErrorMap.set('FeedbackUriEmpty', FeedbackUriEmptyError);
+
+/** FeedbackUriTooLong: Feedback URI exceeds the maximum length */
+export class FeedbackUriTooLongError extends ProgramError {
+  override readonly name: string = 'FeedbackUriTooLong';
+
+  readonly code: number = 0x9; // 9
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback URI exceeds the maximum length', program, cause);
+  }
+}
+codeToErrorMap.set(0x9, FeedbackUriTooLongError);
+nameToErrorMap.set('FeedbackUriTooLong', FeedbackUriTooLongError);
+
+/** FeedbackUriNotUtf8: Feedback URI is not valid UTF-8 */
+export class FeedbackUriNotUtf8Error extends ProgramError {
+  override readonly name: string = 'FeedbackUriNotUtf8';
+
+  readonly code: number = 0xa; // 10
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback URI is not valid UTF-8', program, cause);
+  }
+}
+codeToErrorMap.set(0xa, FeedbackUriNotUtf8Error);
+nameToErrorMap.set('FeedbackUriNotUtf8', FeedbackUriNotUtf8Error);
+
+/** FeedbackUriSchemeNotAllowed: Feedback URI scheme is not allowed */
+export class FeedbackUriSchemeNotAllowedError extends ProgramError {
+  override readonly name: string = 'FeedbackUriSchemeNotAllowed';
+
+  readonly code: number = 0xb; // 11
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback URI scheme is not allowed', program, cause);
+  }
+}
+codeToErrorMap.set(0xb, FeedbackUriSchemeNotAllowedError);
+nameToErrorMap.set(
+  'FeedbackUriSchemeNotAllowed',
+  FeedbackUriSchemeNotAllowedError
+);
+
+/** NumericalOverflow: Numerical overflow */
+export class NumericalOverflowError extends ProgramError {
+  override readonly name: string = 'NumericalOverflow';
+
+  readonly code: number = 0xc; // 12
+
+  constructor(program: Program, cause?: Error) {
+    super('Numerical overflow', program, cause);
+  }
+}
+codeToErrorMap.set(0xc, NumericalOverflowError);
+nameToErrorMap.set('NumericalOverflow', NumericalOverflowError);
+
+/** InvalidCompressionProgram: Invalid MPL Account Compression Program */
+export class InvalidCompressionProgramError extends ProgramError {
+  override readonly name: string = 'InvalidCompressionProgram';
+
+  readonly code: number = 0xd; // 13
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid MPL Account Compression Program', program, cause);
+  }
+}
+codeToErrorMap.set(0xd, InvalidCompressionProgramError);
+nameToErrorMap.set('InvalidCompressionProgram', InvalidCompressionProgramError);
+
+/** InvalidReceiptsTree: Invalid receipts tree */
+export class InvalidReceiptsTreeError extends ProgramError {
+  override readonly name: string = 'InvalidReceiptsTree';
+
+  readonly code: number = 0xe; // 14
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid receipts tree', program, cause);
+  }
+}
+codeToErrorMap.set(0xe, InvalidReceiptsTreeError);
+nameToErrorMap.set('InvalidReceiptsTree', InvalidReceiptsTreeError);
+
+/** ReceiptAlreadyReviewed: Work receipt was already reviewed */
+export class ReceiptAlreadyReviewedError extends ProgramError {
+  override readonly name: string = 'ReceiptAlreadyReviewed';
+
+  readonly code: number = 0xf; // 15
+
+  constructor(program: Program, cause?: Error) {
+    super('Work receipt was already reviewed', program, cause);
+  }
+}
+codeToErrorMap.set(0xf, ReceiptAlreadyReviewedError);
+nameToErrorMap.set('ReceiptAlreadyReviewed', ReceiptAlreadyReviewedError);
+
+/** InvalidFeedback: Invalid feedback account */
+export class InvalidFeedbackError extends ProgramError {
+  override readonly name: string = 'InvalidFeedback';
+
+  readonly code: number = 0x10; // 16
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid feedback account', program, cause);
+  }
+}
+codeToErrorMap.set(0x10, InvalidFeedbackError);
+nameToErrorMap.set('InvalidFeedback', InvalidFeedbackError);
+
+/** FeedbackAlreadyExists: Feedback index is already used */
+export class FeedbackAlreadyExistsError extends ProgramError {
+  override readonly name: string = 'FeedbackAlreadyExists';
+
+  readonly code: number = 0x11; // 17
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback index is already used', program, cause);
+  }
+}
+codeToErrorMap.set(0x11, FeedbackAlreadyExistsError);
+nameToErrorMap.set('FeedbackAlreadyExists', FeedbackAlreadyExistsError);
+
+/** InvalidResponseAuthority: Authority must be the asset owner or an execution delegate */
+export class InvalidResponseAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidResponseAuthority';
+
+  readonly code: number = 0x12; // 18
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Authority must be the asset owner or an execution delegate',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x12, InvalidResponseAuthorityError);
+nameToErrorMap.set('InvalidResponseAuthority', InvalidResponseAuthorityError);
+
+/** InvalidExecutionDelegateRecord: Invalid execution delegate record */
+export class InvalidExecutionDelegateRecordError extends ProgramError {
+  override readonly name: string = 'InvalidExecutionDelegateRecord';
+
+  readonly code: number = 0x13; // 19
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid execution delegate record', program, cause);
+  }
+}
+codeToErrorMap.set(0x13, InvalidExecutionDelegateRecordError);
+nameToErrorMap.set(
+  'InvalidExecutionDelegateRecord',
+  InvalidExecutionDelegateRecordError
+);
+
+/** ResponseUriEmpty: Response URI is empty */
+export class ResponseUriEmptyError extends ProgramError {
+  override readonly name: string = 'ResponseUriEmpty';
+
+  readonly code: number = 0x14; // 20
+
+  constructor(program: Program, cause?: Error) {
+    super('Response URI is empty', program, cause);
+  }
+}
+codeToErrorMap.set(0x14, ResponseUriEmptyError);
+nameToErrorMap.set('ResponseUriEmpty', ResponseUriEmptyError);
+
+/** ResponseUriTooLong: Response URI exceeds the maximum length */
+export class ResponseUriTooLongError extends ProgramError {
+  override readonly name: string = 'ResponseUriTooLong';
+
+  readonly code: number = 0x15; // 21
+
+  constructor(program: Program, cause?: Error) {
+    super('Response URI exceeds the maximum length', program, cause);
+  }
+}
+codeToErrorMap.set(0x15, ResponseUriTooLongError);
+nameToErrorMap.set('ResponseUriTooLong', ResponseUriTooLongError);
+
+/** ResponseUriNotUtf8: Response URI is not valid UTF-8 */
+export class ResponseUriNotUtf8Error extends ProgramError {
+  override readonly name: string = 'ResponseUriNotUtf8';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super('Response URI is not valid UTF-8', program, cause);
+  }
+}
+codeToErrorMap.set(0x16, ResponseUriNotUtf8Error);
+nameToErrorMap.set('ResponseUriNotUtf8', ResponseUriNotUtf8Error);
+
+/** ResponseUriSchemeNotAllowed: Response URI scheme is not allowed */
+export class ResponseUriSchemeNotAllowedError extends ProgramError {
+  override readonly name: string = 'ResponseUriSchemeNotAllowed';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super('Response URI scheme is not allowed', program, cause);
+  }
+}
+codeToErrorMap.set(0x17, ResponseUriSchemeNotAllowedError);
+nameToErrorMap.set(
+  'ResponseUriSchemeNotAllowed',
+  ResponseUriSchemeNotAllowedError
+);
+
+/** FeedbackRevoked: Feedback has been revoked */
+export class FeedbackRevokedError extends ProgramError {
+  override readonly name: string = 'FeedbackRevoked';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback has been revoked', program, cause);
+  }
+}
+codeToErrorMap.set(0x18, FeedbackRevokedError);
+nameToErrorMap.set('FeedbackRevoked', FeedbackRevokedError);
+
+/** FeedbackAlreadyDisputed: Feedback is already disputed */
+export class FeedbackAlreadyDisputedError extends ProgramError {
+  override readonly name: string = 'FeedbackAlreadyDisputed';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super('Feedback is already disputed', program, cause);
+  }
+}
+codeToErrorMap.set(0x19, FeedbackAlreadyDisputedError);
+nameToErrorMap.set('FeedbackAlreadyDisputed', FeedbackAlreadyDisputedError);
+
+/** InvalidReviewer: Only the reviewer may revoke their feedback */
+export class InvalidReviewerError extends ProgramError {
+  override readonly name: string = 'InvalidReviewer';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super('Only the reviewer may revoke their feedback', program, cause);
+  }
+}
+codeToErrorMap.set(0x1a, InvalidReviewerError);
+nameToErrorMap.set('InvalidReviewer', InvalidReviewerError);
+
+/** InvalidDisputeAuthority: Only the asset owner or an execution delegate may dispute feedback */
+export class InvalidDisputeAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidDisputeAuthority';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the asset owner or an execution delegate may dispute feedback',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1b, InvalidDisputeAuthorityError);
+nameToErrorMap.set('InvalidDisputeAuthority', InvalidDisputeAuthorityError);
+
+/** InvalidReputationConfig: Invalid reputation config account */
+export class InvalidReputationConfigError extends ProgramError {
+  override readonly name: string = 'InvalidReputationConfig';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid reputation config account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1c, InvalidReputationConfigError);
+nameToErrorMap.set('InvalidReputationConfig', InvalidReputationConfigError);
+
+/** ReputationConfigAlreadyInitialized: Reputation config is already initialized */
+export class ReputationConfigAlreadyInitializedError extends ProgramError {
+  override readonly name: string = 'ReputationConfigAlreadyInitialized';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super('Reputation config is already initialized', program, cause);
+  }
+}
+codeToErrorMap.set(0x1d, ReputationConfigAlreadyInitializedError);
+nameToErrorMap.set(
+  'ReputationConfigAlreadyInitialized',
+  ReputationConfigAlreadyInitializedError
+);
+
+/** InvalidHalfLife: Half-life must be non-zero */
+export class InvalidHalfLifeError extends ProgramError {
+  override readonly name: string = 'InvalidHalfLife';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super('Half-life must be non-zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x1e, InvalidHalfLifeError);
+nameToErrorMap.set('InvalidHalfLife', InvalidHalfLifeError);
+
+/** InvalidProgramData: Invalid program data account */
+export class InvalidProgramDataError extends ProgramError {
+  override readonly name: string = 'InvalidProgramData';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid program data account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1f, InvalidProgramDataError);
+nameToErrorMap.set('InvalidProgramData', InvalidProgramDataError);
+
+/** InvalidConfigAuthority: Authority does not match the config authority */
+export class InvalidConfigAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidConfigAuthority';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super('Authority does not match the config authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x20, InvalidConfigAuthorityError);
+nameToErrorMap.set('InvalidConfigAuthority', InvalidConfigAuthorityError);
+
+/** InvalidAgentIdentity: Invalid agent identity account */
+export class InvalidAgentIdentityError extends ProgramError {
+  override readonly name: string = 'InvalidAgentIdentity';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid agent identity account', program, cause);
+  }
+}
+codeToErrorMap.set(0x21, InvalidAgentIdentityError);
+nameToErrorMap.set('InvalidAgentIdentity', InvalidAgentIdentityError);
+
+/** OnlyAssetOwnerOrSignerCanRegisterReputation: Only the asset owner or its asset signer can register reputation */
+export class OnlyAssetOwnerOrSignerCanRegisterReputationError extends ProgramError {
+  override readonly name: string =
+    'OnlyAssetOwnerOrSignerCanRegisterReputation';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the asset owner or its asset signer can register reputation',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x22, OnlyAssetOwnerOrSignerCanRegisterReputationError);
+nameToErrorMap.set(
+  'OnlyAssetOwnerOrSignerCanRegisterReputation',
+  OnlyAssetOwnerOrSignerCanRegisterReputationError
+);
+
+/** InvalidWeightingPolicy: Invalid weighting policy */
+export class InvalidWeightingPolicyError extends ProgramError {
+  override readonly name: string = 'InvalidWeightingPolicy';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid weighting policy', program, cause);
+  }
+}
+codeToErrorMap.set(0x23, InvalidWeightingPolicyError);
+nameToErrorMap.set('InvalidWeightingPolicy', InvalidWeightingPolicyError);
+
+/** InvalidReviewerHistory: Invalid reviewer history account */
+export class InvalidReviewerHistoryError extends ProgramError {
+  override readonly name: string = 'InvalidReviewerHistory';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid reviewer history account', program, cause);
+  }
+}
+codeToErrorMap.set(0x24, InvalidReviewerHistoryError);
+nameToErrorMap.set('InvalidReviewerHistory', InvalidReviewerHistoryError);
+
+/** InvalidReviewerIdentity: Reviewer asset and identity do not prove a registered reviewer */
+export class InvalidReviewerIdentityError extends ProgramError {
+  override readonly name: string = 'InvalidReviewerIdentity';
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Reviewer asset and identity do not prove a registered reviewer',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x25, InvalidReviewerIdentityError);
+nameToErrorMap.set('InvalidReviewerIdentity', InvalidReviewerIdentityError);
+
+/** InvalidReviewerTokenAccount: Reviewer token account does not hold the weighting token */
+export class InvalidReviewerTokenAccountError extends ProgramError {
+  override readonly name: string = 'InvalidReviewerTokenAccount';
+
+  readonly code: number = 0x26; // 38
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Reviewer token account does not hold the weighting token',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x26, InvalidReviewerTokenAccountError);
+nameToErrorMap.set(
+  'InvalidReviewerTokenAccount',
+  InvalidReviewerTokenAccountError
+);
+
+/** InvalidReputationSnapshot: Invalid reputation snapshot account */
+export class InvalidReputationSnapshotError extends ProgramError {
+  override readonly name: string = 'InvalidReputationSnapshot';
+
+  readonly code: number = 0x27; // 39
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid reputation snapshot account', program, cause);
+  }
+}
+codeToErrorMap.set(0x27, InvalidReputationSnapshotError);
+nameToErrorMap.set('InvalidReputationSnapshot', InvalidReputationSnapshotError);
+
+/** ReputationAlreadySnapshotted: Reputation was already snapshotted this epoch */
+export class ReputationAlreadySnapshottedError extends ProgramError {
+  override readonly name: string = 'ReputationAlreadySnapshotted';
+
+  readonly code: number = 0x28; // 40
+
+  constructor(program: Program, cause?: Error) {
+    super('Reputation was already snapshotted this epoch', program, cause);
+  }
+}
+codeToErrorMap.set(0x28, ReputationAlreadySnapshottedError);
+nameToErrorMap.set(
+  'ReputationAlreadySnapshotted',
+  ReputationAlreadySnapshottedError
+);
+
+/** InvalidTokenLock: Invalid token lock account */
+export class InvalidTokenLockError extends ProgramError {
+  override readonly name: string = 'InvalidTokenLock';
+
+  readonly code: number = 0x29; // 41
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid token lock account', program, cause);
+  }
+}
+codeToErrorMap.set(0x29, InvalidTokenLockError);
+nameToErrorMap.set('InvalidTokenLock', InvalidTokenLockError);
+
+/** TokensLocked: Tokens are locked until a later slot */
+export class TokensLockedError extends ProgramError {
+  override readonly name: string = 'TokensLocked';
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super('Tokens are locked until a later slot', program, cause);
+  }
+}
+codeToErrorMap.set(0x2a, TokensLockedError);
+nameToErrorMap.set('TokensLocked', TokensLockedError);
+
+/** InvalidTokenProgram: Invalid token program */
+export class InvalidTokenProgramError extends ProgramError {
+  override readonly name: string = 'InvalidTokenProgram';
+
+  readonly code: number = 0x2b; // 43
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid token program', program, cause);
+  }
+}
+codeToErrorMap.set(0x2b, InvalidTokenProgramError);
+nameToErrorMap.set('InvalidTokenProgram', InvalidTokenProgramError);
+
+/** InvalidReviewerReputation: Reviewer reputation does not prove an established reviewer */
+export class InvalidReviewerReputationError extends ProgramError {
+  override readonly name: string = 'InvalidReviewerReputation';
+
+  readonly code: number = 0x2c; // 44
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Reviewer reputation does not prove an established reviewer',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2c, InvalidReviewerReputationError);
+nameToErrorMap.set('InvalidReviewerReputation', InvalidReviewerReputationError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -0,0 +1,150 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AppendResponseV1InstructionAccounts = {
+  /** The feedback PDA being responded to */
+  feedback: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The asset owner or the authority of the execution delegate record */
+  authority: Signer;
+  /** The mpl-agent-tools execution delegate record, if the authority is not the asset owner */
+  executionDelegateRecord?: PublicKey | Pda;
+};
+
+// Data.
+export type AppendResponseV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  responseHash: Uint8Array;
+  responseUri: string;
+};
+
+export type AppendResponseV1InstructionDataArgs = {
+  responseHash: Uint8Array;
+  responseUri: string;
+};
+
+export function getAppendResponseV1InstructionDataSerializer(): Serializer<
+  AppendResponseV1InstructionDataArgs,
+  AppendResponseV1InstructionData
+> {
+  return mapSerializer<
+    AppendResponseV1InstructionDataArgs,
+    any,
+    AppendResponseV1InstructionData
+  >(
+    struct<AppendResponseV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['responseHash', bytes({ size: 32 })],
+        ['responseUri', string()],
+      ],
+      { description: 'AppendResponseV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 2, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    AppendResponseV1InstructionDataArgs,
+    AppendResponseV1InstructionData
+  >;
+}
+
+// Args.
+export type AppendResponseV1InstructionArgs =
+  AppendResponseV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const appendResponseV1InstructionDiscriminator = 2;
+
+// Instruction.
+export function appendResponseV1(
+  context: Pick<Context, 'programs'>,
+  input: AppendResponseV1InstructionAccounts & AppendResponseV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    feedback: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.feedback ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    executionDelegateRecord: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.executionDelegateRecord ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AppendResponseV1InstructionArgs = { ...input };
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAppendResponseV1InstructionDataSerializer().serialize(
+    resolvedArgs as AppendResponseV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
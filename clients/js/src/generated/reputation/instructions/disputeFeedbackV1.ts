@@ -0,0 +1,193 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentReputationV2Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type DisputeFeedbackV1InstructionAccounts = {
+  /** The agent reputation PDA */
+  agentReputation?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The feedback PDA being disputed */
+  feedback: PublicKey | Pda;
+  /** The asset owner or the authority of the execution delegate record */
+  authority: Signer;
+  /** The mpl-agent-tools execution delegate record, if the authority is not the asset owner */
+  executionDelegateRecord?: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type DisputeFeedbackV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type DisputeFeedbackV1InstructionDataArgs = {};
+
+export function getDisputeFeedbackV1InstructionDataSerializer(): Serializer<
+  DisputeFeedbackV1InstructionDataArgs,
+  DisputeFeedbackV1InstructionData
+> {
+  return mapSerializer<
+    DisputeFeedbackV1InstructionDataArgs,
+    any,
+    DisputeFeedbackV1InstructionData
+  >(
+    struct<DisputeFeedbackV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'DisputeFeedbackV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 4, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    DisputeFeedbackV1InstructionDataArgs,
+    DisputeFeedbackV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const disputeFeedbackV1InstructionDiscriminator = 4;
+
+// Instruction.
+export function disputeFeedbackV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: DisputeFeedbackV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentReputation: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentReputation ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    feedback: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.feedback ?? null,
+    },
+    authority: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    executionDelegateRecord: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.executionDelegateRecord ?? null,
+    },
+    payer: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentReputation.value) {
+    resolvedAccounts.agentReputation.value = findAgentReputationV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getDisputeFeedbackV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
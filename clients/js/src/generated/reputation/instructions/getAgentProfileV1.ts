@@ -0,0 +1,147 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findAgentReputationV2Pda,
+  findReputationConfigV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type GetAgentProfileV1InstructionAccounts = {
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The mpl-agent-identity agent identity PDA, initialized or not */
+  agentIdentity: PublicKey | Pda;
+  /** The agent reputation PDA, initialized or not */
+  agentReputation?: PublicKey | Pda;
+  /** The reputation config PDA at ["reputation_config"], initialized or not */
+  reputationConfig?: PublicKey | Pda;
+};
+
+// Data.
+export type GetAgentProfileV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type GetAgentProfileV1InstructionDataArgs = {};
+
+export function getGetAgentProfileV1InstructionDataSerializer(): Serializer<
+  GetAgentProfileV1InstructionDataArgs,
+  GetAgentProfileV1InstructionData
+> {
+  return mapSerializer<
+    GetAgentProfileV1InstructionDataArgs,
+    any,
+    GetAgentProfileV1InstructionData
+  >(
+    struct<GetAgentProfileV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'GetAgentProfileV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 8, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    GetAgentProfileV1InstructionDataArgs,
+    GetAgentProfileV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const getAgentProfileV1InstructionDiscriminator = 8;
+
+// Instruction.
+export function getAgentProfileV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: GetAgentProfileV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    asset: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    agentIdentity: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.agentIdentity ?? null,
+    },
+    agentReputation: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.agentReputation ?? null,
+    },
+    reputationConfig: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.reputationConfig ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentReputation.value) {
+    resolvedAccounts.agentReputation.value = findAgentReputationV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getGetAgentProfileV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
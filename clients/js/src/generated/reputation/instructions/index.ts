@@ -6,4 +6,16 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './appendResponseV1';
+export * from './disputeFeedbackV1';
+export * from './getAgentProfileV1';
+export * from './initializeReputationConfigV1';
+export * from './refreshReputationV1';
+export * from './registerReputationAsOwnerV1';
 export * from './registerReputationV1';
+export * from './revokeFeedbackV1';
+export * from './setWeightingPolicyV1';
+export * from './snapshotReputationV1';
+export * from './submitFeedbackV1';
+export * from './unlockTokensV1';
+export * from './updateReputationConfigV1';
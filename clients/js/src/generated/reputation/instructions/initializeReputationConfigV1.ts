@@ -0,0 +1,174 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findReputationConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeReputationConfigV1InstructionAccounts = {
+  /** The reputation config PDA at ["reputation_config"] */
+  reputationConfig?: PublicKey | Pda;
+  /** The program data account of this program */
+  programData: PublicKey | Pda;
+  /** The upgrade authority of this program */
+  authority: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeReputationConfigV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  halfLifeSlots: bigint;
+};
+
+export type InitializeReputationConfigV1InstructionDataArgs = {
+  halfLifeSlots: number | bigint;
+};
+
+export function getInitializeReputationConfigV1InstructionDataSerializer(): Serializer<
+  InitializeReputationConfigV1InstructionDataArgs,
+  InitializeReputationConfigV1InstructionData
+> {
+  return mapSerializer<
+    InitializeReputationConfigV1InstructionDataArgs,
+    any,
+    InitializeReputationConfigV1InstructionData
+  >(
+    struct<InitializeReputationConfigV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['halfLifeSlots', u64()],
+      ],
+      { description: 'InitializeReputationConfigV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 5, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    InitializeReputationConfigV1InstructionDataArgs,
+    InitializeReputationConfigV1InstructionData
+  >;
+}
+
+// Args.
+export type InitializeReputationConfigV1InstructionArgs =
+  InitializeReputationConfigV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const initializeReputationConfigV1InstructionDiscriminator = 5;
+
+// Instruction.
+export function initializeReputationConfigV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: InitializeReputationConfigV1InstructionAccounts &
+    InitializeReputationConfigV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    reputationConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.reputationConfig ?? null,
+    },
+    programData: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.programData ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitializeReputationConfigV1InstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getInitializeReputationConfigV1InstructionDataSerializer().serialize(
+      resolvedArgs as InitializeReputationConfigV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
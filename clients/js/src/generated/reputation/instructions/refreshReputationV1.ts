@@ -0,0 +1,124 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findReputationConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RefreshReputationV1InstructionAccounts = {
+  /** The agent reputation PDA. Must be of type AgentReputationV2. */
+  agentReputation: PublicKey | Pda;
+  /** The reputation config PDA at ["reputation_config"], initialized or not */
+  reputationConfig?: PublicKey | Pda;
+};
+
+// Data.
+export type RefreshReputationV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type RefreshReputationV1InstructionDataArgs = {};
+
+export function getRefreshReputationV1InstructionDataSerializer(): Serializer<
+  RefreshReputationV1InstructionDataArgs,
+  RefreshReputationV1InstructionData
+> {
+  return mapSerializer<
+    RefreshReputationV1InstructionDataArgs,
+    any,
+    RefreshReputationV1InstructionData
+  >(
+    struct<RefreshReputationV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'RefreshReputationV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 7, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RefreshReputationV1InstructionDataArgs,
+    RefreshReputationV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const refreshReputationV1InstructionDiscriminator = 7;
+
+// Instruction.
+export function refreshReputationV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: RefreshReputationV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentReputation: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentReputation ?? null,
+    },
+    reputationConfig: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.reputationConfig ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRefreshReputationV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,159 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentReputationV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RegisterReputationAsOwnerV1InstructionAccounts = {
+  /** The agent reputation PDA */
+  agentReputation?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The asset owner or the asset signer PDA. If not provided, the payer will be used. */
+  authority?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RegisterReputationAsOwnerV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type RegisterReputationAsOwnerV1InstructionDataArgs = {};
+
+export function getRegisterReputationAsOwnerV1InstructionDataSerializer(): Serializer<
+  RegisterReputationAsOwnerV1InstructionDataArgs,
+  RegisterReputationAsOwnerV1InstructionData
+> {
+  return mapSerializer<
+    RegisterReputationAsOwnerV1InstructionDataArgs,
+    any,
+    RegisterReputationAsOwnerV1InstructionData
+  >(
+    struct<RegisterReputationAsOwnerV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'RegisterReputationAsOwnerV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 9, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RegisterReputationAsOwnerV1InstructionDataArgs,
+    RegisterReputationAsOwnerV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const registerReputationAsOwnerV1InstructionDiscriminator = 9;
+
+// Instruction.
+export function registerReputationAsOwnerV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RegisterReputationAsOwnerV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentReputation: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentReputation ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    payer: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentReputation.value) {
+    resolvedAccounts.agentReputation.value = findAgentReputationV1Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getRegisterReputationAsOwnerV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,200 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findAgentReputationV2Pda,
+  findReputationConfigV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RevokeFeedbackV1InstructionAccounts = {
+  /** The agent reputation PDA */
+  agentReputation?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The feedback PDA being revoked */
+  feedback: PublicKey | Pda;
+  /** The reputation config PDA at ["reputation_config"], initialized or not */
+  reputationConfig?: PublicKey | Pda;
+  /** The author of the feedback */
+  reviewer: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RevokeFeedbackV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type RevokeFeedbackV1InstructionDataArgs = {};
+
+export function getRevokeFeedbackV1InstructionDataSerializer(): Serializer<
+  RevokeFeedbackV1InstructionDataArgs,
+  RevokeFeedbackV1InstructionData
+> {
+  return mapSerializer<
+    RevokeFeedbackV1InstructionDataArgs,
+    any,
+    RevokeFeedbackV1InstructionData
+  >(
+    struct<RevokeFeedbackV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'RevokeFeedbackV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 3, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RevokeFeedbackV1InstructionDataArgs,
+    RevokeFeedbackV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const revokeFeedbackV1InstructionDiscriminator = 3;
+
+// Instruction.
+export function revokeFeedbackV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RevokeFeedbackV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentReputation: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentReputation ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    feedback: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.feedback ?? null,
+    },
+    reputationConfig: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.reputationConfig ?? null,
+    },
+    reviewer: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.reviewer ?? null,
+    },
+    payer: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentReputation.value) {
+    resolvedAccounts.agentReputation.value = findAgentReputationV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRevokeFeedbackV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,210 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findReputationConfigV1Pda,
+  findWeightingPolicyV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SetWeightingPolicyV1InstructionAccounts = {
+  /** The weighting policy PDA at ["weighting_policy"] */
+  weightingPolicy?: PublicKey | Pda;
+  /** The initialized reputation config PDA at ["reputation_config"] */
+  reputationConfig?: PublicKey | Pda;
+  /** The config authority */
+  authority: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SetWeightingPolicyV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  baseWeight: number;
+  receiptWeight: number;
+  maxReceipts: number;
+  identityWeight: number;
+  tokenWeight: number;
+  padding1: Array<number>;
+  tokenMint: PublicKey;
+  minTokenBalance: bigint;
+  minIdentityFeedback: bigint;
+  tokenLockSlots: bigint;
+};
+
+export type SetWeightingPolicyV1InstructionDataArgs = {
+  baseWeight: number;
+  receiptWeight: number;
+  maxReceipts: number;
+  identityWeight: number;
+  tokenWeight: number;
+  tokenMint: PublicKey;
+  minTokenBalance: number | bigint;
+  minIdentityFeedback: number | bigint;
+  tokenLockSlots: number | bigint;
+};
+
+export function getSetWeightingPolicyV1InstructionDataSerializer(): Serializer<
+  SetWeightingPolicyV1InstructionDataArgs,
+  SetWeightingPolicyV1InstructionData
+> {
+  return mapSerializer<
+    SetWeightingPolicyV1InstructionDataArgs,
+    any,
+    SetWeightingPolicyV1InstructionData
+  >(
+    struct<SetWeightingPolicyV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['baseWeight', u32()],
+        ['receiptWeight', u32()],
+        ['maxReceipts', u32()],
+        ['identityWeight', u32()],
+        ['tokenWeight', u32()],
+        ['padding1', array(u8(), { size: 4 })],
+        ['tokenMint', publicKeySerializer()],
+        ['minTokenBalance', u64()],
+        ['minIdentityFeedback', u64()],
+        ['tokenLockSlots', u64()],
+      ],
+      { description: 'SetWeightingPolicyV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 10,
+      padding: [0, 0, 0, 0, 0, 0, 0],
+      padding1: [0, 0, 0, 0],
+    })
+  ) as Serializer<
+    SetWeightingPolicyV1InstructionDataArgs,
+    SetWeightingPolicyV1InstructionData
+  >;
+}
+
+// Args.
+export type SetWeightingPolicyV1InstructionArgs =
+  SetWeightingPolicyV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const setWeightingPolicyV1InstructionDiscriminator = 10;
+
+// Instruction.
+export function setWeightingPolicyV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SetWeightingPolicyV1InstructionAccounts &
+    SetWeightingPolicyV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    weightingPolicy: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.weightingPolicy ?? null,
+    },
+    reputationConfig: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.reputationConfig ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SetWeightingPolicyV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.weightingPolicy.value) {
+    resolvedAccounts.weightingPolicy.value = findWeightingPolicyV1Pda(context);
+  }
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSetWeightingPolicyV1InstructionDataSerializer().serialize(
+    resolvedArgs as SetWeightingPolicyV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,172 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findAgentReputationV2Pda,
+  findReputationConfigV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SnapshotReputationV1InstructionAccounts = {
+  /** The reputation snapshot PDA at ["reputation_snapshot", asset, epoch_le] for the current epoch */
+  reputationSnapshot: PublicKey | Pda;
+  /** The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2. */
+  agentReputation?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The reputation config PDA at ["reputation_config"], initialized or not */
+  reputationConfig?: PublicKey | Pda;
+  /** The payer for the snapshot rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SnapshotReputationV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type SnapshotReputationV1InstructionDataArgs = {};
+
+export function getSnapshotReputationV1InstructionDataSerializer(): Serializer<
+  SnapshotReputationV1InstructionDataArgs,
+  SnapshotReputationV1InstructionData
+> {
+  return mapSerializer<
+    SnapshotReputationV1InstructionDataArgs,
+    any,
+    SnapshotReputationV1InstructionData
+  >(
+    struct<SnapshotReputationV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'SnapshotReputationV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 11, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    SnapshotReputationV1InstructionDataArgs,
+    SnapshotReputationV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const snapshotReputationV1InstructionDiscriminator = 11;
+
+// Instruction.
+export function snapshotReputationV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SnapshotReputationV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    reputationSnapshot: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.reputationSnapshot ?? null,
+    },
+    agentReputation: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.agentReputation ?? null,
+    },
+    asset: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    reputationConfig: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.reputationConfig ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentReputation.value) {
+    resolvedAccounts.agentReputation.value = findAgentReputationV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSnapshotReputationV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,338 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findAgentReputationV2Pda,
+  findReputationConfigV1Pda,
+  findReviewerHistoryV1Pda,
+  findWeightingPolicyV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SubmitFeedbackV1InstructionAccounts = {
+  /** The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2. */
+  agentReputation?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The author of the feedback and owner of the work receipt */
+  reviewer: Signer;
+  /** The receipt nullifier PDA at ["receipt_nullifier", tree_index_le, nonce_le] */
+  receiptNullifier: PublicKey | Pda;
+  /** The feedback PDA at ["feedback", asset, reviewer, feedback_index_le] */
+  feedback: PublicKey | Pda;
+  /** The reputation config PDA at ["reputation_config"], initialized or not */
+  reputationConfig?: PublicKey | Pda;
+  /** The weighting policy PDA at ["weighting_policy"], set or not */
+  weightingPolicy?: PublicKey | Pda;
+  /** The reviewer history PDA at ["reviewer_history", asset, reviewer] */
+  reviewerHistory?: PublicKey | Pda;
+  /** A Core asset owned by the reviewer, proving with its agent identity and reputation that the reviewer is established */
+  reviewerAsset?: PublicKey | Pda;
+  /** The mpl-agent-identity agent identity PDA of the reviewer asset */
+  reviewerIdentity?: PublicKey | Pda;
+  /** The agent reputation PDA of the reviewer asset */
+  reviewerReputation?: PublicKey | Pda;
+  /** The token lock PDA at ["token_lock", reviewer, token_mint], created on first use */
+  reviewerTokenLock?: PublicKey | Pda;
+  /** A token account of the token lock PDA holding the weighting policy token */
+  reviewerTokenVault?: PublicKey | Pda;
+  /** The mpl-agent-tools receipts merkle tree holding the work receipt */
+  merkleTree: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The MPL Account Compression program */
+  compressionProgram?: PublicKey | Pda;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type SubmitFeedbackV1InstructionData = {
+  discriminator: number;
+  score: number;
+  flags: number;
+  padding: Array<number>;
+  index: number;
+  treeIndex: bigint;
+  nonce: bigint;
+  feedbackIndex: bigint;
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  assetDataHash: Uint8Array;
+  tag1: Uint8Array;
+  tag2: Uint8Array;
+  feedbackUri: string;
+};
+
+export type SubmitFeedbackV1InstructionDataArgs = {
+  score: number;
+  flags: number;
+  index: number;
+  treeIndex: number | bigint;
+  nonce: number | bigint;
+  feedbackIndex: number | bigint;
+  root: Uint8Array;
+  dataHash: Uint8Array;
+  assetDataHash: Uint8Array;
+  tag1: Uint8Array;
+  tag2: Uint8Array;
+  feedbackUri: string;
+};
+
+export function getSubmitFeedbackV1InstructionDataSerializer(): Serializer<
+  SubmitFeedbackV1InstructionDataArgs,
+  SubmitFeedbackV1InstructionData
+> {
+  return mapSerializer<
+    SubmitFeedbackV1InstructionDataArgs,
+    any,
+    SubmitFeedbackV1InstructionData
+  >(
+    struct<SubmitFeedbackV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['score', u8()],
+        ['flags', u8()],
+        ['padding', array(u8(), { size: 1 })],
+        ['index', u32()],
+        ['treeIndex', u64()],
+        ['nonce', u64()],
+        ['feedbackIndex', u64()],
+        ['root', bytes({ size: 32 })],
+        ['dataHash', bytes({ size: 32 })],
+        ['assetDataHash', bytes({ size: 32 })],
+        ['tag1', bytes({ size: 32 })],
+        ['tag2', bytes({ size: 32 })],
+        ['feedbackUri', string()],
+      ],
+      { description: 'SubmitFeedbackV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 1, padding: [0] })
+  ) as Serializer<
+    SubmitFeedbackV1InstructionDataArgs,
+    SubmitFeedbackV1InstructionData
+  >;
+}
+
+// Args.
+export type SubmitFeedbackV1InstructionArgs =
+  SubmitFeedbackV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const submitFeedbackV1InstructionDiscriminator = 1;
+
+// Instruction.
+export function submitFeedbackV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: SubmitFeedbackV1InstructionAccounts & SubmitFeedbackV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    agentReputation: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.agentReputation ?? null,
+    },
+    asset: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    reviewer: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.reviewer ?? null,
+    },
+    receiptNullifier: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.receiptNullifier ?? null,
+    },
+    feedback: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.feedback ?? null,
+    },
+    reputationConfig: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.reputationConfig ?? null,
+    },
+    weightingPolicy: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.weightingPolicy ?? null,
+    },
+    reviewerHistory: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.reviewerHistory ?? null,
+    },
+    reviewerAsset: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.reviewerAsset ?? null,
+    },
+    reviewerIdentity: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.reviewerIdentity ?? null,
+    },
+    reviewerReputation: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.reviewerReputation ?? null,
+    },
+    reviewerTokenLock: {
+      index: 12,
+      isWritable: true as boolean,
+      value: input.reviewerTokenLock ?? null,
+    },
+    reviewerTokenVault: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.reviewerTokenVault ?? null,
+    },
+    merkleTree: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.merkleTree ?? null,
+    },
+    payer: {
+      index: 15,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    compressionProgram: {
+      index: 16,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    mplCoreProgram: {
+      index: 17,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 18,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SubmitFeedbackV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.agentReputation.value) {
+    resolvedAccounts.agentReputation.value = findAgentReputationV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.weightingPolicy.value) {
+    resolvedAccounts.weightingPolicy.value = findWeightingPolicyV1Pda(context);
+  }
+  if (!resolvedAccounts.reviewerHistory.value) {
+    resolvedAccounts.reviewerHistory.value = findReviewerHistoryV1Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+      reviewer: expectPublicKey(resolvedAccounts.reviewer.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSubmitFeedbackV1InstructionDataSerializer().serialize(
+    resolvedArgs as SubmitFeedbackV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,169 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findTokenLockV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UnlockTokensV1InstructionAccounts = {
+  /** The token lock PDA at ["token_lock", reviewer, mint] */
+  tokenLock?: PublicKey | Pda;
+  /** The token account of the token lock PDA holding the tokens */
+  tokenVault: PublicKey | Pda;
+  /** The token account receiving the tokens */
+  destination: PublicKey | Pda;
+  /** The mint of the locked tokens */
+  mint: PublicKey | Pda;
+  /** The reviewer owning the lock */
+  reviewer: Signer;
+  /** The SPL Token or Token 2022 program owning the token accounts */
+  tokenProgram: PublicKey | Pda;
+};
+
+// Data.
+export type UnlockTokensV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  amount: bigint;
+};
+
+export type UnlockTokensV1InstructionDataArgs = {
+  amount: number | bigint;
+};
+
+export function getUnlockTokensV1InstructionDataSerializer(): Serializer<
+  UnlockTokensV1InstructionDataArgs,
+  UnlockTokensV1InstructionData
+> {
+  return mapSerializer<
+    UnlockTokensV1InstructionDataArgs,
+    any,
+    UnlockTokensV1InstructionData
+  >(
+    struct<UnlockTokensV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['amount', u64()],
+      ],
+      { description: 'UnlockTokensV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 12, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    UnlockTokensV1InstructionDataArgs,
+    UnlockTokensV1InstructionData
+  >;
+}
+
+// Args.
+export type UnlockTokensV1InstructionArgs = UnlockTokensV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const unlockTokensV1InstructionDiscriminator = 12;
+
+// Instruction.
+export function unlockTokensV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: UnlockTokensV1InstructionAccounts & UnlockTokensV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    tokenLock: {
+      index: 0,
+      isWritable: false as boolean,
+      value: input.tokenLock ?? null,
+    },
+    tokenVault: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.tokenVault ?? null,
+    },
+    destination: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+    mint: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mint ?? null,
+    },
+    reviewer: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.reviewer ?? null,
+    },
+    tokenProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.tokenProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UnlockTokensV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.tokenLock.value) {
+    resolvedAccounts.tokenLock.value = findTokenLockV1Pda(context, {
+      reviewer: expectPublicKey(resolvedAccounts.reviewer.value),
+      mint: expectPublicKey(resolvedAccounts.mint.value),
+    });
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUnlockTokensV1InstructionDataSerializer().serialize(
+    resolvedArgs as UnlockTokensV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,147 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findReputationConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateReputationConfigV1InstructionAccounts = {
+  /** The reputation config PDA at ["reputation_config"] */
+  reputationConfig?: PublicKey | Pda;
+  /** The config authority */
+  authority: Signer;
+  /** The new config authority, if it changes */
+  newAuthority?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateReputationConfigV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  halfLifeSlots: bigint;
+};
+
+export type UpdateReputationConfigV1InstructionDataArgs = {
+  halfLifeSlots: number | bigint;
+};
+
+export function getUpdateReputationConfigV1InstructionDataSerializer(): Serializer<
+  UpdateReputationConfigV1InstructionDataArgs,
+  UpdateReputationConfigV1InstructionData
+> {
+  return mapSerializer<
+    UpdateReputationConfigV1InstructionDataArgs,
+    any,
+    UpdateReputationConfigV1InstructionData
+  >(
+    struct<UpdateReputationConfigV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['halfLifeSlots', u64()],
+      ],
+      { description: 'UpdateReputationConfigV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 6, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    UpdateReputationConfigV1InstructionDataArgs,
+    UpdateReputationConfigV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdateReputationConfigV1InstructionArgs =
+  UpdateReputationConfigV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const updateReputationConfigV1InstructionDiscriminator = 6;
+
+// Instruction.
+export function updateReputationConfigV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: UpdateReputationConfigV1InstructionAccounts &
+    UpdateReputationConfigV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentReputation',
+    'REPREG5c1gPHuHukEyANpksLdHFaJCiTrm6zJgNhRZR'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    reputationConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.reputationConfig ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    newAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.newAuthority ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateReputationConfigV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.reputationConfig.value) {
+    resolvedAccounts.reputationConfig.value =
+      findReputationConfigV1Pda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateReputationConfigV1InstructionDataSerializer().serialize(
+    resolvedArgs as UpdateReputationConfigV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
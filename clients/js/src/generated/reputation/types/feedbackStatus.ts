@@ -0,0 +1,26 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum FeedbackStatus {
+  Active,
+  Disputed,
+  Revoked,
+}
+
+export type FeedbackStatusArgs = FeedbackStatus;
+
+export function getFeedbackStatusSerializer(): Serializer<
+  FeedbackStatusArgs,
+  FeedbackStatus
+> {
+  return scalarEnum<FeedbackStatus>(FeedbackStatus, {
+    description: 'FeedbackStatus',
+  }) as Serializer<FeedbackStatusArgs, FeedbackStatus>;
+}
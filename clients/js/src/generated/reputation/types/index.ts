@@ -6,4 +6,6 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './feedbackStatus';
 export * from './key';
+export * from './mplAgentReputationEvent';
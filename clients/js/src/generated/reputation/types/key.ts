@@ -11,6 +11,14 @@ import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
 export enum Key {
   Uninitialized,
   AgentReputationV1,
+  AgentReputationV2,
+  ReceiptNullifierV1,
+  FeedbackV1,
+  ReputationConfigV1,
+  WeightingPolicyV1,
+  ReviewerHistoryV1,
+  ReputationSnapshotV1,
+  TokenLockV1,
 }
 
 export type KeyArgs = Key;
@@ -0,0 +1,409 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  PublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  dataEnum,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type MplAgentReputationEvent =
+  | {
+      __kind: 'FeedbackSubmittedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      score: number;
+      tag1: Uint8Array;
+      tag2: Uint8Array;
+      feedbackUri: string;
+    }
+  | {
+      __kind: 'ResponseAppendedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: bigint;
+      responder: PublicKey;
+      responseUri: string;
+      responseHash: Uint8Array;
+    }
+  | {
+      __kind: 'FeedbackRevokedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: bigint;
+      score: number;
+    }
+  | {
+      __kind: 'FeedbackDisputedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: bigint;
+      disputer: PublicKey;
+    }
+  | {
+      __kind: 'ReputationConfigUpdatedV1';
+      authority: PublicKey;
+      halfLifeSlots: bigint;
+    }
+  | {
+      __kind: 'ReputationRefreshedV1';
+      asset: PublicKey;
+      decayedScore: bigint;
+      decayedCount: bigint;
+      slot: bigint;
+    }
+  | {
+      __kind: 'WeightingPolicyUpdatedV1';
+      baseWeight: number;
+      receiptWeight: number;
+      maxReceipts: number;
+      identityWeight: number;
+      tokenWeight: number;
+      tokenMint: PublicKey;
+      minTokenBalance: bigint;
+      minIdentityFeedback: bigint;
+      tokenLockSlots: bigint;
+    }
+  | {
+      __kind: 'FeedbackWeightedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: bigint;
+      weight: number;
+    }
+  | {
+      __kind: 'ReputationSnapshottedV1';
+      asset: PublicKey;
+      epoch: bigint;
+      slot: bigint;
+      feedbackCount: bigint;
+      scoreSum: bigint;
+      decayedScore: bigint;
+      decayedCount: bigint;
+    }
+  | {
+      __kind: 'TokensUnlockedV1';
+      reviewer: PublicKey;
+      mint: PublicKey;
+      amount: bigint;
+    };
+
+export type MplAgentReputationEventArgs =
+  | {
+      __kind: 'FeedbackSubmittedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      score: number;
+      tag1: Uint8Array;
+      tag2: Uint8Array;
+      feedbackUri: string;
+    }
+  | {
+      __kind: 'ResponseAppendedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: number | bigint;
+      responder: PublicKey;
+      responseUri: string;
+      responseHash: Uint8Array;
+    }
+  | {
+      __kind: 'FeedbackRevokedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: number | bigint;
+      score: number;
+    }
+  | {
+      __kind: 'FeedbackDisputedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: number | bigint;
+      disputer: PublicKey;
+    }
+  | {
+      __kind: 'ReputationConfigUpdatedV1';
+      authority: PublicKey;
+      halfLifeSlots: number | bigint;
+    }
+  | {
+      __kind: 'ReputationRefreshedV1';
+      asset: PublicKey;
+      decayedScore: number | bigint;
+      decayedCount: number | bigint;
+      slot: number | bigint;
+    }
+  | {
+      __kind: 'WeightingPolicyUpdatedV1';
+      baseWeight: number;
+      receiptWeight: number;
+      maxReceipts: number;
+      identityWeight: number;
+      tokenWeight: number;
+      tokenMint: PublicKey;
+      minTokenBalance: number | bigint;
+      minIdentityFeedback: number | bigint;
+      tokenLockSlots: number | bigint;
+    }
+  | {
+      __kind: 'FeedbackWeightedV1';
+      asset: PublicKey;
+      reviewer: PublicKey;
+      feedbackIndex: number | bigint;
+      weight: number;
+    }
+  | {
+      __kind: 'ReputationSnapshottedV1';
+      asset: PublicKey;
+      epoch: number | bigint;
+      slot: number | bigint;
+      feedbackCount: number | bigint;
+      scoreSum: number | bigint;
+      decayedScore: number | bigint;
+      decayedCount: number | bigint;
+    }
+  | {
+      __kind: 'TokensUnlockedV1';
+      reviewer: PublicKey;
+      mint: PublicKey;
+      amount: number | bigint;
+    };
+
+export function getMplAgentReputationEventSerializer(): Serializer<
+  MplAgentReputationEventArgs,
+  MplAgentReputationEvent
+> {
+  return dataEnum<MplAgentReputationEvent>(
+    [
+      [
+        'FeedbackSubmittedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentReputationEvent, 'FeedbackSubmittedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['reviewer', publicKeySerializer()],
+          ['score', u8()],
+          ['tag1', bytes({ size: 32 })],
+          ['tag2', bytes({ size: 32 })],
+          ['feedbackUri', string()],
+        ]),
+      ],
+      [
+        'ResponseAppendedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentReputationEvent, 'ResponseAppendedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['reviewer', publicKeySerializer()],
+          ['feedbackIndex', u64()],
+          ['responder', publicKeySerializer()],
+          ['responseUri', string()],
+          ['responseHash', bytes({ size: 32 })],
+        ]),
+      ],
+      [
+        'FeedbackRevokedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentReputationEvent, 'FeedbackRevokedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['reviewer', publicKeySerializer()],
+          ['feedbackIndex', u64()],
+          ['score', u8()],
+        ]),
+      ],
+      [
+        'FeedbackDisputedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentReputationEvent, 'FeedbackDisputedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['reviewer', publicKeySerializer()],
+          ['feedbackIndex', u64()],
+          ['disputer', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ReputationConfigUpdatedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentReputationEvent,
+            'ReputationConfigUpdatedV1'
+          >
+        >([
+          ['authority', publicKeySerializer()],
+          ['halfLifeSlots', u64()],
+        ]),
+      ],
+      [
+        'ReputationRefreshedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentReputationEvent,
+            'ReputationRefreshedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['decayedScore', u64()],
+          ['decayedCount', u64()],
+          ['slot', u64()],
+        ]),
+      ],
+      [
+        'WeightingPolicyUpdatedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentReputationEvent,
+            'WeightingPolicyUpdatedV1'
+          >
+        >([
+          ['baseWeight', u32()],
+          ['receiptWeight', u32()],
+          ['maxReceipts', u32()],
+          ['identityWeight', u32()],
+          ['tokenWeight', u32()],
+          ['tokenMint', publicKeySerializer()],
+          ['minTokenBalance', u64()],
+          ['minIdentityFeedback', u64()],
+          ['tokenLockSlots', u64()],
+        ]),
+      ],
+      [
+        'FeedbackWeightedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentReputationEvent, 'FeedbackWeightedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['reviewer', publicKeySerializer()],
+          ['feedbackIndex', u64()],
+          ['weight', u32()],
+        ]),
+      ],
+      [
+        'ReputationSnapshottedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentReputationEvent,
+            'ReputationSnapshottedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['epoch', u64()],
+          ['slot', u64()],
+          ['feedbackCount', u64()],
+          ['scoreSum', u64()],
+          ['decayedScore', u64()],
+          ['decayedCount', u64()],
+        ]),
+      ],
+      [
+        'TokensUnlockedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentReputationEvent, 'TokensUnlockedV1'>
+        >([
+          ['reviewer', publicKeySerializer()],
+          ['mint', publicKeySerializer()],
+          ['amount', u64()],
+        ]),
+      ],
+    ],
+    { description: 'MplAgentReputationEvent' }
+  ) as Serializer<MplAgentReputationEventArgs, MplAgentReputationEvent>;
+}
+
+// Data Enum Helpers.
+export function mplAgentReputationEvent(
+  kind: 'FeedbackSubmittedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'FeedbackSubmittedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'FeedbackSubmittedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'ResponseAppendedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'ResponseAppendedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'ResponseAppendedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'FeedbackRevokedV1',
+  data: GetDataEnumKindContent<MplAgentReputationEventArgs, 'FeedbackRevokedV1'>
+): GetDataEnumKind<MplAgentReputationEventArgs, 'FeedbackRevokedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'FeedbackDisputedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'FeedbackDisputedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'FeedbackDisputedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'ReputationConfigUpdatedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'ReputationConfigUpdatedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'ReputationConfigUpdatedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'ReputationRefreshedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'ReputationRefreshedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'ReputationRefreshedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'WeightingPolicyUpdatedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'WeightingPolicyUpdatedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'WeightingPolicyUpdatedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'FeedbackWeightedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'FeedbackWeightedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'FeedbackWeightedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'ReputationSnapshottedV1',
+  data: GetDataEnumKindContent<
+    MplAgentReputationEventArgs,
+    'ReputationSnapshottedV1'
+  >
+): GetDataEnumKind<MplAgentReputationEventArgs, 'ReputationSnapshottedV1'>;
+export function mplAgentReputationEvent(
+  kind: 'TokensUnlockedV1',
+  data: GetDataEnumKindContent<MplAgentReputationEventArgs, 'TokensUnlockedV1'>
+): GetDataEnumKind<MplAgentReputationEventArgs, 'TokensUnlockedV1'>;
+export function mplAgentReputationEvent<
+  K extends MplAgentReputationEventArgs['__kind'],
+>(kind: K, data?: any): Extract<MplAgentReputationEventArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isMplAgentReputationEvent<
+  K extends MplAgentReputationEvent['__kind'],
+>(
+  kind: K,
+  value: MplAgentReputationEvent
+): value is MplAgentReputationEvent & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,214 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ExecutionDelegateCountV1 =
+  Account<ExecutionDelegateCountV1AccountData>;
+
+export type ExecutionDelegateCountV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  agentAsset: PublicKey;
+  count: bigint;
+};
+
+export type ExecutionDelegateCountV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  agentAsset: PublicKey;
+  count: number | bigint;
+};
+
+export function getExecutionDelegateCountV1AccountDataSerializer(): Serializer<
+  ExecutionDelegateCountV1AccountDataArgs,
+  ExecutionDelegateCountV1AccountData
+> {
+  return mapSerializer<
+    ExecutionDelegateCountV1AccountDataArgs,
+    any,
+    ExecutionDelegateCountV1AccountData
+  >(
+    struct<ExecutionDelegateCountV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['agentAsset', publicKeySerializer()],
+        ['count', u64()],
+      ],
+      { description: 'ExecutionDelegateCountV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ExecutionDelegateCountV1AccountDataArgs,
+    ExecutionDelegateCountV1AccountData
+  >;
+}
+
+export function deserializeExecutionDelegateCountV1(
+  rawAccount: RpcAccount
+): ExecutionDelegateCountV1 {
+  return deserializeAccount(
+    rawAccount,
+    getExecutionDelegateCountV1AccountDataSerializer()
+  );
+}
+
+export async function fetchExecutionDelegateCountV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateCountV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ExecutionDelegateCountV1');
+  return deserializeExecutionDelegateCountV1(maybeAccount);
+}
+
+export async function safeFetchExecutionDelegateCountV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateCountV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeExecutionDelegateCountV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllExecutionDelegateCountV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ExecutionDelegateCountV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ExecutionDelegateCountV1');
+    return deserializeExecutionDelegateCountV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllExecutionDelegateCountV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ExecutionDelegateCountV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeExecutionDelegateCountV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getExecutionDelegateCountV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      agentAsset: PublicKey;
+      count: number | bigint;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      agentAsset: [8, publicKeySerializer()],
+      count: [40, u64()],
+    })
+    .deserializeUsing<ExecutionDelegateCountV1>((account) =>
+      deserializeExecutionDelegateCountV1(account)
+    );
+}
+
+export function getExecutionDelegateCountV1Size(): number {
+  return 48;
+}
+
+export function findExecutionDelegateCountV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the agent asset */
+    agentAsset: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('execution_delegate_count'),
+    publicKeySerializer().serialize(seeds.agentAsset),
+  ]);
+}
+
+export async function fetchExecutionDelegateCountV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findExecutionDelegateCountV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateCountV1> {
+  return fetchExecutionDelegateCountV1(
+    context,
+    findExecutionDelegateCountV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchExecutionDelegateCountV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findExecutionDelegateCountV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateCountV1 | null> {
+  return safeFetchExecutionDelegateCountV1(
+    context,
+    findExecutionDelegateCountV1Pda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,207 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ExecutionDelegateMigrationV1 =
+  Account<ExecutionDelegateMigrationV1AccountData>;
+
+export type ExecutionDelegateMigrationV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  authority: PublicKey;
+  slot: bigint;
+};
+
+export type ExecutionDelegateMigrationV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  authority: PublicKey;
+  slot: number | bigint;
+};
+
+export function getExecutionDelegateMigrationV1AccountDataSerializer(): Serializer<
+  ExecutionDelegateMigrationV1AccountDataArgs,
+  ExecutionDelegateMigrationV1AccountData
+> {
+  return mapSerializer<
+    ExecutionDelegateMigrationV1AccountDataArgs,
+    any,
+    ExecutionDelegateMigrationV1AccountData
+  >(
+    struct<ExecutionDelegateMigrationV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['authority', publicKeySerializer()],
+        ['slot', u64()],
+      ],
+      { description: 'ExecutionDelegateMigrationV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ExecutionDelegateMigrationV1AccountDataArgs,
+    ExecutionDelegateMigrationV1AccountData
+  >;
+}
+
+export function deserializeExecutionDelegateMigrationV1(
+  rawAccount: RpcAccount
+): ExecutionDelegateMigrationV1 {
+  return deserializeAccount(
+    rawAccount,
+    getExecutionDelegateMigrationV1AccountDataSerializer()
+  );
+}
+
+export async function fetchExecutionDelegateMigrationV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateMigrationV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ExecutionDelegateMigrationV1');
+  return deserializeExecutionDelegateMigrationV1(maybeAccount);
+}
+
+export async function safeFetchExecutionDelegateMigrationV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateMigrationV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeExecutionDelegateMigrationV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllExecutionDelegateMigrationV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ExecutionDelegateMigrationV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ExecutionDelegateMigrationV1');
+    return deserializeExecutionDelegateMigrationV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllExecutionDelegateMigrationV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ExecutionDelegateMigrationV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeExecutionDelegateMigrationV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getExecutionDelegateMigrationV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      authority: PublicKey;
+      slot: number | bigint;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      authority: [8, publicKeySerializer()],
+      slot: [40, u64()],
+    })
+    .deserializeUsing<ExecutionDelegateMigrationV1>((account) =>
+      deserializeExecutionDelegateMigrationV1(account)
+    );
+}
+
+export function getExecutionDelegateMigrationV1Size(): number {
+  return 48;
+}
+
+export function findExecutionDelegateMigrationV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('execution_delegate_migration'),
+  ]);
+}
+
+export async function fetchExecutionDelegateMigrationV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateMigrationV1> {
+  return fetchExecutionDelegateMigrationV1(
+    context,
+    findExecutionDelegateMigrationV1Pda(context),
+    options
+  );
+}
+
+export async function safeFetchExecutionDelegateMigrationV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ExecutionDelegateMigrationV1 | null> {
+  return safeFetchExecutionDelegateMigrationV1(
+    context,
+    findExecutionDelegateMigrationV1Pda(context),
+    options
+  );
+}
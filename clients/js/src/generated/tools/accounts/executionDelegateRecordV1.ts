@@ -36,6 +36,7 @@ export type ExecutionDelegateRecordV1 =
 export type ExecutionDelegateRecordV1AccountData = {
   key: Key;
   bump: number;
+  counted: number;
   padding: Array<number>;
   executiveProfile: PublicKey;
   authority: PublicKey;
@@ -45,6 +46,7 @@ export type ExecutionDelegateRecordV1AccountData = {
 export type ExecutionDelegateRecordV1AccountDataArgs = {
   key: KeyArgs;
   bump: number;
+  counted: number;
   executiveProfile: PublicKey;
   authority: PublicKey;
   agentAsset: PublicKey;
@@ -63,14 +65,15 @@ export function getExecutionDelegateRecordV1AccountDataSerializer(): Serializer<
       [
         ['key', getKeySerializer()],
         ['bump', u8()],
-        ['padding', array(u8(), { size: 6 })],
+        ['counted', u8()],
+        ['padding', array(u8(), { size: 5 })],
         ['executiveProfile', publicKeySerializer()],
         ['authority', publicKeySerializer()],
         ['agentAsset', publicKeySerializer()],
       ],
       { description: 'ExecutionDelegateRecordV1AccountData' }
     ),
-    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0] })
   ) as Serializer<
     ExecutionDelegateRecordV1AccountDataArgs,
     ExecutionDelegateRecordV1AccountData
@@ -155,6 +158,7 @@ export function getExecutionDelegateRecordV1GpaBuilder(
     .registerFields<{
       key: KeyArgs;
       bump: number;
+      counted: number;
       padding: Array<number>;
       executiveProfile: PublicKey;
       authority: PublicKey;
@@ -162,7 +166,8 @@ export function getExecutionDelegateRecordV1GpaBuilder(
     }>({
       key: [0, getKeySerializer()],
       bump: [1, u8()],
-      padding: [2, array(u8(), { size: 6 })],
+      counted: [2, u8()],
+      padding: [3, array(u8(), { size: 5 })],
       executiveProfile: [8, publicKeySerializer()],
       authority: [40, publicKeySerializer()],
       agentAsset: [72, publicKeySerializer()],
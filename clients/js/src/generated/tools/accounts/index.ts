@@ -6,6 +6,9 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './executionDelegateCountV1';
+export * from './executionDelegateMigrationV1';
 export * from './executionDelegateRecordV1';
 export * from './executiveProfileV1';
+export * from './receiptReviewV1';
 export * from './standalonePdas';
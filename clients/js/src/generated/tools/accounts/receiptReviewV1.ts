@@ -0,0 +1,226 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ReceiptReviewV1 = Account<ReceiptReviewV1AccountData>;
+
+export type ReceiptReviewV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  agentAsset: PublicKey;
+  reviewer: PublicKey;
+  receipt: PublicKey;
+  reviewTreeIndex: bigint;
+  reviewNonce: bigint;
+};
+
+export type ReceiptReviewV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  agentAsset: PublicKey;
+  reviewer: PublicKey;
+  receipt: PublicKey;
+  reviewTreeIndex: number | bigint;
+  reviewNonce: number | bigint;
+};
+
+export function getReceiptReviewV1AccountDataSerializer(): Serializer<
+  ReceiptReviewV1AccountDataArgs,
+  ReceiptReviewV1AccountData
+> {
+  return mapSerializer<
+    ReceiptReviewV1AccountDataArgs,
+    any,
+    ReceiptReviewV1AccountData
+  >(
+    struct<ReceiptReviewV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['agentAsset', publicKeySerializer()],
+        ['reviewer', publicKeySerializer()],
+        ['receipt', publicKeySerializer()],
+        ['reviewTreeIndex', u64()],
+        ['reviewNonce', u64()],
+      ],
+      { description: 'ReceiptReviewV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<ReceiptReviewV1AccountDataArgs, ReceiptReviewV1AccountData>;
+}
+
+export function deserializeReceiptReviewV1(
+  rawAccount: RpcAccount
+): ReceiptReviewV1 {
+  return deserializeAccount(
+    rawAccount,
+    getReceiptReviewV1AccountDataSerializer()
+  );
+}
+
+export async function fetchReceiptReviewV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReceiptReviewV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ReceiptReviewV1');
+  return deserializeReceiptReviewV1(maybeAccount);
+}
+
+export async function safeFetchReceiptReviewV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ReceiptReviewV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists ? deserializeReceiptReviewV1(maybeAccount) : null;
+}
+
+export async function fetchAllReceiptReviewV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReceiptReviewV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ReceiptReviewV1');
+    return deserializeReceiptReviewV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllReceiptReviewV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ReceiptReviewV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeReceiptReviewV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getReceiptReviewV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      agentAsset: PublicKey;
+      reviewer: PublicKey;
+      receipt: PublicKey;
+      reviewTreeIndex: number | bigint;
+      reviewNonce: number | bigint;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      agentAsset: [8, publicKeySerializer()],
+      reviewer: [40, publicKeySerializer()],
+      receipt: [72, publicKeySerializer()],
+      reviewTreeIndex: [104, u64()],
+      reviewNonce: [112, u64()],
+    })
+    .deserializeUsing<ReceiptReviewV1>((account) =>
+      deserializeReceiptReviewV1(account)
+    );
+}
+
+export function getReceiptReviewV1Size(): number {
+  return 120;
+}
+
+export function findReceiptReviewV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** Index of the receipts tree */
+    receiptsTreeIndex: number | bigint;
+    /** Nonce of the receipt leaf */
+    receiptNonce: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('receipt_review'),
+    u64().serialize(seeds.receiptsTreeIndex),
+    u64().serialize(seeds.receiptNonce),
+  ]);
+}
+
+export async function fetchReceiptReviewV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReceiptReviewV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReceiptReviewV1> {
+  return fetchReceiptReviewV1(
+    context,
+    findReceiptReviewV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchReceiptReviewV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findReceiptReviewV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ReceiptReviewV1 | null> {
+  return safeFetchReceiptReviewV1(
+    context,
+    findReceiptReviewV1Pda(context, seeds),
+    options
+  );
+}
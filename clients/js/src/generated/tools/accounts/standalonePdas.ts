@@ -42,3 +42,29 @@ export function findReceiptsTreePda(
     u64().serialize(seeds.treeIndex),
   ]);
 }
+
+export function findReviewsCollectionPda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  return pda(context, [
+    string({ size: 'variable' }).serialize('reviews_collection'),
+  ]);
+}
+
+export function findReviewsAuthorityPda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  return pda(context, [
+    string({ size: 'variable' }).serialize('reviews_authority'),
+  ]);
+}
+
+export function findReviewsTreePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: { treeIndex: number | bigint }
+): Pda {
+  return pda(context, [
+    string({ size: 'variable' }).serialize('reviews_tree'),
+    u64().serialize(seeds.treeIndex),
+  ]);
+}
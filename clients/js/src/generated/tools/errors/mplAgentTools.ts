@@ -367,6 +367,252 @@ export class InvalidCompressionProgramError extends ProgramError {
 codeToErrorMap.set(0x16, InvalidCompressionProgramError);
 nameToErrorMap.set('InvalidCompressionProgram', InvalidCompressionProgramError);
 
+/** InvalidExecutionDelegateCountDerivation: Invalid Execution Delegate Count Derivation */
+export class InvalidExecutionDelegateCountDerivationError extends ProgramError {
+  override readonly name: string = 'InvalidExecutionDelegateCountDerivation';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Execution Delegate Count Derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x17, InvalidExecutionDelegateCountDerivationError);
+nameToErrorMap.set(
+  'InvalidExecutionDelegateCountDerivation',
+  InvalidExecutionDelegateCountDerivationError
+);
+
+/** InvalidExecutionDelegateCount: Invalid Execution Delegate Count */
+export class InvalidExecutionDelegateCountError extends ProgramError {
+  override readonly name: string = 'InvalidExecutionDelegateCount';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Execution Delegate Count', program, cause);
+  }
+}
+codeToErrorMap.set(0x18, InvalidExecutionDelegateCountError);
+nameToErrorMap.set(
+  'InvalidExecutionDelegateCount',
+  InvalidExecutionDelegateCountError
+);
+
+/** InvalidReviewsCollection: Supplied collection is not the canonical reviews collection PDA */
+export class InvalidReviewsCollectionError extends ProgramError {
+  override readonly name: string = 'InvalidReviewsCollection';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Supplied collection is not the canonical reviews collection PDA',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x19, InvalidReviewsCollectionError);
+nameToErrorMap.set('InvalidReviewsCollection', InvalidReviewsCollectionError);
+
+/** InvalidReviewsAuthority: Supplied authority is not the canonical reviews authority PDA */
+export class InvalidReviewsAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidReviewsAuthority';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Supplied authority is not the canonical reviews authority PDA',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1a, InvalidReviewsAuthorityError);
+nameToErrorMap.set('InvalidReviewsAuthority', InvalidReviewsAuthorityError);
+
+/** ReviewsCollectionAlreadyInitialized: Reviews collection already exists */
+export class ReviewsCollectionAlreadyInitializedError extends ProgramError {
+  override readonly name: string = 'ReviewsCollectionAlreadyInitialized';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super('Reviews collection already exists', program, cause);
+  }
+}
+codeToErrorMap.set(0x1b, ReviewsCollectionAlreadyInitializedError);
+nameToErrorMap.set(
+  'ReviewsCollectionAlreadyInitialized',
+  ReviewsCollectionAlreadyInitializedError
+);
+
+/** InvalidReviewsTreeDerivation: Invalid reviews tree PDA derivation */
+export class InvalidReviewsTreeDerivationError extends ProgramError {
+  override readonly name: string = 'InvalidReviewsTreeDerivation';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid reviews tree PDA derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x1c, InvalidReviewsTreeDerivationError);
+nameToErrorMap.set(
+  'InvalidReviewsTreeDerivation',
+  InvalidReviewsTreeDerivationError
+);
+
+/** ReviewUriInvalid: Review URI must be non-empty and within size limits */
+export class ReviewUriInvalidError extends ProgramError {
+  override readonly name: string = 'ReviewUriInvalid';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Review URI must be non-empty and within size limits',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x1d, ReviewUriInvalidError);
+nameToErrorMap.set('ReviewUriInvalid', ReviewUriInvalidError);
+
+/** InvalidReceiptReviewDerivation: Invalid Receipt Review Derivation */
+export class InvalidReceiptReviewDerivationError extends ProgramError {
+  override readonly name: string = 'InvalidReceiptReviewDerivation';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid Receipt Review Derivation', program, cause);
+  }
+}
+codeToErrorMap.set(0x1e, InvalidReceiptReviewDerivationError);
+nameToErrorMap.set(
+  'InvalidReceiptReviewDerivation',
+  InvalidReceiptReviewDerivationError
+);
+
+/** ReceiptAlreadyReviewed: Work receipt was already reviewed */
+export class ReceiptAlreadyReviewedError extends ProgramError {
+  override readonly name: string = 'ReceiptAlreadyReviewed';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super('Work receipt was already reviewed', program, cause);
+  }
+}
+codeToErrorMap.set(0x1f, ReceiptAlreadyReviewedError);
+nameToErrorMap.set('ReceiptAlreadyReviewed', ReceiptAlreadyReviewedError);
+
+/** MissingExecutionDelegateCount: The execution delegate count is required for a counted delegate record */
+export class MissingExecutionDelegateCountError extends ProgramError {
+  override readonly name: string = 'MissingExecutionDelegateCount';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The execution delegate count is required for a counted delegate record',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x20, MissingExecutionDelegateCountError);
+nameToErrorMap.set(
+  'MissingExecutionDelegateCount',
+  MissingExecutionDelegateCountError
+);
+
+/** ExecutionDelegateAlreadyCounted: Execution delegate record is already counted */
+export class ExecutionDelegateAlreadyCountedError extends ProgramError {
+  override readonly name: string = 'ExecutionDelegateAlreadyCounted';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super('Execution delegate record is already counted', program, cause);
+  }
+}
+codeToErrorMap.set(0x21, ExecutionDelegateAlreadyCountedError);
+nameToErrorMap.set(
+  'ExecutionDelegateAlreadyCounted',
+  ExecutionDelegateAlreadyCountedError
+);
+
+/** InvalidExecutionDelegateMigration: Invalid execution delegate migration account */
+export class InvalidExecutionDelegateMigrationError extends ProgramError {
+  override readonly name: string = 'InvalidExecutionDelegateMigration';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid execution delegate migration account', program, cause);
+  }
+}
+codeToErrorMap.set(0x22, InvalidExecutionDelegateMigrationError);
+nameToErrorMap.set(
+  'InvalidExecutionDelegateMigration',
+  InvalidExecutionDelegateMigrationError
+);
+
+/** ExecutionDelegateMigrationAlreadyCompleted: The execution delegate migration is already completed */
+export class ExecutionDelegateMigrationAlreadyCompletedError extends ProgramError {
+  override readonly name: string = 'ExecutionDelegateMigrationAlreadyCompleted';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'The execution delegate migration is already completed',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x23, ExecutionDelegateMigrationAlreadyCompletedError);
+nameToErrorMap.set(
+  'ExecutionDelegateMigrationAlreadyCompleted',
+  ExecutionDelegateMigrationAlreadyCompletedError
+);
+
+/** InvalidProgramData: Invalid program data account */
+export class InvalidProgramDataError extends ProgramError {
+  override readonly name: string = 'InvalidProgramData';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid program data account', program, cause);
+  }
+}
+codeToErrorMap.set(0x24, InvalidProgramDataError);
+nameToErrorMap.set('InvalidProgramData', InvalidProgramDataError);
+
+/** InvalidMigrationAuthority: Only the upgrade authority of the program can complete the migration */
+export class InvalidMigrationAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidMigrationAuthority';
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the upgrade authority of the program can complete the migration',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x25, InvalidMigrationAuthorityError);
+nameToErrorMap.set('InvalidMigrationAuthority', InvalidMigrationAuthorityError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -0,0 +1,159 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findExecutionDelegateMigrationV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CompleteExecutionDelegateMigrationV1InstructionAccounts = {
+  /** Execution delegate migration PDA at ["execution_delegate_migration"] */
+  executionDelegateMigration?: PublicKey | Pda;
+  /** The program data account of this program */
+  programData: PublicKey | Pda;
+  /** The upgrade authority of this program */
+  authority: Signer;
+  /** The payer for the migration account */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CompleteExecutionDelegateMigrationV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type CompleteExecutionDelegateMigrationV1InstructionDataArgs = {};
+
+export function getCompleteExecutionDelegateMigrationV1InstructionDataSerializer(): Serializer<
+  CompleteExecutionDelegateMigrationV1InstructionDataArgs,
+  CompleteExecutionDelegateMigrationV1InstructionData
+> {
+  return mapSerializer<
+    CompleteExecutionDelegateMigrationV1InstructionDataArgs,
+    any,
+    CompleteExecutionDelegateMigrationV1InstructionData
+  >(
+    struct<CompleteExecutionDelegateMigrationV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'CompleteExecutionDelegateMigrationV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 11, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    CompleteExecutionDelegateMigrationV1InstructionDataArgs,
+    CompleteExecutionDelegateMigrationV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const completeExecutionDelegateMigrationV1InstructionDiscriminator = 11;
+
+// Instruction.
+export function completeExecutionDelegateMigrationV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: CompleteExecutionDelegateMigrationV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    executionDelegateMigration: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.executionDelegateMigration ?? null,
+    },
+    programData: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.programData ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.executionDelegateMigration.value) {
+    resolvedAccounts.executionDelegateMigration.value =
+      findExecutionDelegateMigrationV1Pda(context);
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getCompleteExecutionDelegateMigrationV1InstructionDataSerializer().serialize(
+      {}
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
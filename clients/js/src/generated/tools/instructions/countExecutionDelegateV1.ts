@@ -0,0 +1,161 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findExecutionDelegateCountV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CountExecutionDelegateV1InstructionAccounts = {
+  /** The uncounted execution delegate record */
+  executionDelegateRecord: PublicKey | Pda;
+  /** The execution delegate count for the agent asset */
+  executionDelegateCount?: PublicKey | Pda;
+  /** The agent asset */
+  agentAsset: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CountExecutionDelegateV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type CountExecutionDelegateV1InstructionDataArgs = {};
+
+export function getCountExecutionDelegateV1InstructionDataSerializer(): Serializer<
+  CountExecutionDelegateV1InstructionDataArgs,
+  CountExecutionDelegateV1InstructionData
+> {
+  return mapSerializer<
+    CountExecutionDelegateV1InstructionDataArgs,
+    any,
+    CountExecutionDelegateV1InstructionData
+  >(
+    struct<CountExecutionDelegateV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'CountExecutionDelegateV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 10, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    CountExecutionDelegateV1InstructionDataArgs,
+    CountExecutionDelegateV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const countExecutionDelegateV1InstructionDiscriminator = 10;
+
+// Instruction.
+export function countExecutionDelegateV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: CountExecutionDelegateV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    executionDelegateRecord: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.executionDelegateRecord ?? null,
+    },
+    executionDelegateCount: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.executionDelegateCount ?? null,
+    },
+    agentAsset: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.agentAsset ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.executionDelegateCount.value) {
+    resolvedAccounts.executionDelegateCount.value =
+      findExecutionDelegateCountV1Pda(context, {
+        agentAsset: expectPublicKey(resolvedAccounts.agentAsset.value),
+      });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getCountExecutionDelegateV1InstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
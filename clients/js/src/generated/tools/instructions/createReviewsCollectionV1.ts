@@ -0,0 +1,166 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findReviewsAuthorityPda, findReviewsCollectionPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type CreateReviewsCollectionV1InstructionAccounts = {
+  /** Funds the collection's rent */
+  payer?: Signer;
+  /** Reviews collection PDA at ["reviews_collection"] */
+  collection?: PublicKey | Pda;
+  /** Reviews authority PDA at ["reviews_authority"] — becomes the collection's update_authority */
+  authority?: PublicKey | Pda;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type CreateReviewsCollectionV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type CreateReviewsCollectionV1InstructionDataArgs = {};
+
+export function getCreateReviewsCollectionV1InstructionDataSerializer(): Serializer<
+  CreateReviewsCollectionV1InstructionDataArgs,
+  CreateReviewsCollectionV1InstructionData
+> {
+  return mapSerializer<
+    CreateReviewsCollectionV1InstructionDataArgs,
+    any,
+    CreateReviewsCollectionV1InstructionData
+  >(
+    struct<CreateReviewsCollectionV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'CreateReviewsCollectionV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 7, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    CreateReviewsCollectionV1InstructionDataArgs,
+    CreateReviewsCollectionV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const createReviewsCollectionV1InstructionDiscriminator = 7;
+
+// Instruction.
+export function createReviewsCollectionV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: CreateReviewsCollectionV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    payer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    collection: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    mplCoreProgram: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.collection.value) {
+    resolvedAccounts.collection.value = findReviewsCollectionPda(context);
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = findReviewsAuthorityPda(context);
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getCreateReviewsCollectionV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
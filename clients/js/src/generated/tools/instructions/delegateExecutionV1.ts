@@ -21,7 +21,10 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
-import { findExecutionDelegateRecordV1Pda } from '../accounts';
+import {
+  findExecutionDelegateCountV1Pda,
+  findExecutionDelegateRecordV1Pda,
+} from '../accounts';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
@@ -45,6 +48,8 @@ export type DelegateExecutionV1InstructionAccounts = {
   authority?: Signer;
   /** The system program */
   systemProgram?: PublicKey | Pda;
+  /** The execution delegate count for the agent asset */
+  executionDelegateCount?: PublicKey | Pda;
 };
 
 // Data.
@@ -129,6 +134,11 @@ export function delegateExecutionV1(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    executionDelegateCount: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.executionDelegateCount ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -151,6 +161,12 @@ export function delegateExecutionV1(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.executionDelegateCount.value) {
+    resolvedAccounts.executionDelegateCount.value =
+      findExecutionDelegateCountV1Pda(context, {
+        agentAsset: expectPublicKey(resolvedAccounts.agentAsset.value),
+      });
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
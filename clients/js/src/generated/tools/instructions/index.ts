@@ -7,9 +7,14 @@
  */
 
 export * from './closeWorkReceiptV1';
+export * from './completeExecutionDelegateMigrationV1';
+export * from './countExecutionDelegateV1';
 export * from './createReceiptsCollectionV1';
+export * from './createReviewsCollectionV1';
 export * from './delegateExecutionV1';
+export * from './mintReviewV1';
 export * from './mintWorkReceiptV1';
 export * from './registerExecutiveV1';
 export * from './registerReceiptsTreeV1';
+export * from './registerReviewsTreeV1';
 export * from './revokeExecutionV1';
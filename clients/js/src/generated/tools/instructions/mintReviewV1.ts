@@ -0,0 +1,293 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findReviewsAuthorityPda, findReviewsCollectionPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type MintReviewV1InstructionAccounts = {
+  /** Pays for the review record and the cNFT mint */
+  payer?: Signer;
+  /** Owner of the work receipt and recipient of the review */
+  reviewer: Signer;
+  /** The Core asset representing the reviewed agent */
+  agentAsset: PublicKey | Pda;
+  /** Receipt review record PDA */
+  receiptReview: PublicKey | Pda;
+  /** Receipts merkle tree holding the work receipt */
+  receiptsMerkleTree: PublicKey | Pda;
+  /** Reviews authority PDA at ["reviews_authority"] — signs CPI via invoke_signed */
+  authority?: PublicKey | Pda;
+  /** Bubblegum tree config PDA for the reviews tree */
+  treeConfig: PublicKey | Pda;
+  /** Reviews merkle tree at PDA ["reviews_tree", tree_index_le] */
+  merkleTree: PublicKey | Pda;
+  /** Canonical reviews collection PDA at ["reviews_collection"] */
+  coreCollection?: PublicKey | Pda;
+  /** Bubblegum's mpl-core CPI signer PDA */
+  mplCoreCpiSigner: PublicKey | Pda;
+  /** MPL Noop / log wrapper program */
+  logWrapper?: PublicKey | Pda;
+  /** MPL Account Compression program */
+  compressionProgram?: PublicKey | Pda;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The MPL Bubblegum program */
+  bubblegumProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type MintReviewV1InstructionData = {
+  discriminator: number;
+  receiptFlags: number;
+  pad: Array<number>;
+  receiptIndex: number;
+  receiptsTreeIndex: bigint;
+  receiptNonce: bigint;
+  reviewsTreeIndex: bigint;
+  root: Uint8Array;
+  receiptDataHash: Uint8Array;
+  receiptAssetDataHash: Uint8Array;
+  reviewUri: string;
+};
+
+export type MintReviewV1InstructionDataArgs = {
+  receiptFlags: number;
+  receiptIndex: number;
+  receiptsTreeIndex: number | bigint;
+  receiptNonce: number | bigint;
+  reviewsTreeIndex: number | bigint;
+  root: Uint8Array;
+  receiptDataHash: Uint8Array;
+  receiptAssetDataHash: Uint8Array;
+  reviewUri: string;
+};
+
+export function getMintReviewV1InstructionDataSerializer(): Serializer<
+  MintReviewV1InstructionDataArgs,
+  MintReviewV1InstructionData
+> {
+  return mapSerializer<
+    MintReviewV1InstructionDataArgs,
+    any,
+    MintReviewV1InstructionData
+  >(
+    struct<MintReviewV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['receiptFlags', u8()],
+        ['pad', array(u8(), { size: 2 })],
+        ['receiptIndex', u32()],
+        ['receiptsTreeIndex', u64()],
+        ['receiptNonce', u64()],
+        ['reviewsTreeIndex', u64()],
+        ['root', bytes({ size: 32 })],
+        ['receiptDataHash', bytes({ size: 32 })],
+        ['receiptAssetDataHash', bytes({ size: 32 })],
+        ['reviewUri', string()],
+      ],
+      { description: 'MintReviewV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 9, pad: [0, 0] })
+  ) as Serializer<MintReviewV1InstructionDataArgs, MintReviewV1InstructionData>;
+}
+
+// Args.
+export type MintReviewV1InstructionArgs = MintReviewV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const mintReviewV1InstructionDiscriminator = 9;
+
+// Instruction.
+export function mintReviewV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: MintReviewV1InstructionAccounts & MintReviewV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    payer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    reviewer: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.reviewer ?? null,
+    },
+    agentAsset: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.agentAsset ?? null,
+    },
+    receiptReview: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.receiptReview ?? null,
+    },
+    receiptsMerkleTree: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.receiptsMerkleTree ?? null,
+    },
+    authority: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    treeConfig: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    merkleTree: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    coreCollection: {
+      index: 8,
+      isWritable: true as boolean,
+      value: input.coreCollection ?? null,
+    },
+    mplCoreCpiSigner: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.mplCoreCpiSigner ?? null,
+    },
+    logWrapper: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    mplCoreProgram: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    bubblegumProgram: {
+      index: 13,
+      isWritable: false as boolean,
+      value: input.bubblegumProgram ?? null,
+    },
+    systemProgram: {
+      index: 14,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: MintReviewV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = findReviewsAuthorityPda(context);
+  }
+  if (!resolvedAccounts.coreCollection.value) {
+    resolvedAccounts.coreCollection.value = findReviewsCollectionPda(context);
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'mplBubblegum',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getMintReviewV1InstructionDataSerializer().serialize(
+    resolvedArgs as MintReviewV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,229 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findReviewsAuthorityPda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RegisterReviewsTreeV1InstructionAccounts = {
+  /** Funds the tree rent */
+  payer?: Signer;
+  /** Reviews authority PDA at ["reviews_authority"] — set as tree_creator */
+  authority?: PublicKey | Pda;
+  /** Reviews merkle tree PDA at ["reviews_tree", tree_index_le] */
+  merkleTree: PublicKey | Pda;
+  /** Bubblegum tree config PDA (derived from merkle_tree) */
+  treeConfig: PublicKey | Pda;
+  /** MPL Noop / log wrapper program */
+  logWrapper?: PublicKey | Pda;
+  /** MPL Account Compression program */
+  compressionProgram?: PublicKey | Pda;
+  /** The MPL Bubblegum program */
+  bubblegumProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RegisterReviewsTreeV1InstructionData = {
+  discriminator: number;
+  pad: Array<number>;
+  treeIndex: bigint;
+  maxDepth: number;
+  maxBufferSize: number;
+  canopyDepth: number;
+  pad2: Array<number>;
+};
+
+export type RegisterReviewsTreeV1InstructionDataArgs = {
+  treeIndex: number | bigint;
+  maxDepth: number;
+  maxBufferSize: number;
+  canopyDepth: number;
+};
+
+export function getRegisterReviewsTreeV1InstructionDataSerializer(): Serializer<
+  RegisterReviewsTreeV1InstructionDataArgs,
+  RegisterReviewsTreeV1InstructionData
+> {
+  return mapSerializer<
+    RegisterReviewsTreeV1InstructionDataArgs,
+    any,
+    RegisterReviewsTreeV1InstructionData
+  >(
+    struct<RegisterReviewsTreeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['pad', array(u8(), { size: 7 })],
+        ['treeIndex', u64()],
+        ['maxDepth', u32()],
+        ['maxBufferSize', u32()],
+        ['canopyDepth', u32()],
+        ['pad2', array(u8(), { size: 4 })],
+      ],
+      { description: 'RegisterReviewsTreeV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 8,
+      pad: [0, 0, 0, 0, 0, 0, 0],
+      pad2: [0, 0, 0, 0],
+    })
+  ) as Serializer<
+    RegisterReviewsTreeV1InstructionDataArgs,
+    RegisterReviewsTreeV1InstructionData
+  >;
+}
+
+// Args.
+export type RegisterReviewsTreeV1InstructionArgs =
+  RegisterReviewsTreeV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const registerReviewsTreeV1InstructionDiscriminator = 8;
+
+// Instruction.
+export function registerReviewsTreeV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RegisterReviewsTreeV1InstructionAccounts &
+    RegisterReviewsTreeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentTools',
+    'TLREGni9ZEyGC3vnPZtqUh95xQ8oPqJSvNjvB7FGK8S'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    payer: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    merkleTree: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.merkleTree ?? null,
+    },
+    treeConfig: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.treeConfig ?? null,
+    },
+    logWrapper: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.logWrapper ?? null,
+    },
+    compressionProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.compressionProgram ?? null,
+    },
+    bubblegumProgram: {
+      index: 6,
+      isWritable: false as boolean,
+      value: input.bubblegumProgram ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RegisterReviewsTreeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.authority.value) {
+    resolvedAccounts.authority.value = findReviewsAuthorityPda(context);
+  }
+  if (!resolvedAccounts.logWrapper.value) {
+    resolvedAccounts.logWrapper.value = context.programs.getPublicKey(
+      'mplNoop',
+      'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3'
+    );
+    resolvedAccounts.logWrapper.isWritable = false;
+  }
+  if (!resolvedAccounts.compressionProgram.value) {
+    resolvedAccounts.compressionProgram.value = context.programs.getPublicKey(
+      'mplAccountCompression',
+      'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW'
+    );
+    resolvedAccounts.compressionProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.bubblegumProgram.value) {
+    resolvedAccounts.bubblegumProgram.value = context.programs.getPublicKey(
+      'mplBubblegum',
+      'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY'
+    );
+    resolvedAccounts.bubblegumProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRegisterReviewsTreeV1InstructionDataSerializer().serialize(
+    resolvedArgs as RegisterReviewsTreeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
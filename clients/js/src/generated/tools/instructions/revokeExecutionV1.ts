@@ -21,9 +21,11 @@ import {
   struct,
   u8,
 } from '@metaplex-foundation/umi/serializers';
+import { findExecutionDelegateCountV1Pda } from '../accounts';
 import {
   ResolvedAccount,
   ResolvedAccountsWithIndices,
+  expectPublicKey,
   getAccountMetasAndSigners,
 } from '../shared';
 
@@ -41,6 +43,8 @@ export type RevokeExecutionV1InstructionAccounts = {
   authority?: Signer;
   /** The system program */
   systemProgram?: PublicKey | Pda;
+  /** The execution delegate count for the agent asset */
+  executionDelegateCount?: PublicKey | Pda;
 };
 
 // Data.
@@ -79,7 +83,7 @@ export const revokeExecutionV1InstructionDiscriminator = 2;
 
 // Instruction.
 export function revokeExecutionV1(
-  context: Pick<Context, 'payer' | 'programs'>,
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
   input: RevokeExecutionV1InstructionAccounts
 ): TransactionBuilder {
   // Program ID.
@@ -120,6 +124,11 @@ export function revokeExecutionV1(
       isWritable: false as boolean,
       value: input.systemProgram ?? null,
     },
+    executionDelegateCount: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.executionDelegateCount ?? null,
+    },
   } satisfies ResolvedAccountsWithIndices;
 
   // Default values.
@@ -133,6 +142,12 @@ export function revokeExecutionV1(
     );
     resolvedAccounts.systemProgram.isWritable = false;
   }
+  if (!resolvedAccounts.executionDelegateCount.value) {
+    resolvedAccounts.executionDelegateCount.value =
+      findExecutionDelegateCountV1Pda(context, {
+        agentAsset: expectPublicKey(resolvedAccounts.agentAsset.value),
+      });
+  }
 
   // Accounts in order.
   const orderedAccounts: ResolvedAccount[] = Object.values(
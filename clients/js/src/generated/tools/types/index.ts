@@ -7,3 +7,4 @@
  */
 
 export * from './key';
+export * from './mplAgentToolsEvent';
@@ -12,6 +12,9 @@ export enum Key {
   Uninitialized,
   ExecutiveProfileV1,
   ExecutionDelegateRecordV1,
+  ExecutionDelegateCountV1,
+  ReceiptReviewV1,
+  ExecutionDelegateMigrationV1,
 }
 
 export type KeyArgs = Key;
@@ -0,0 +1,378 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  PublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  dataEnum,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+} from '@metaplex-foundation/umi/serializers';
+
+export type MplAgentToolsEvent =
+  | {
+      __kind: 'ExecutiveRegisteredV1';
+      executiveProfile: PublicKey;
+      authority: PublicKey;
+    }
+  | {
+      __kind: 'ExecutionDelegatedV1';
+      agentAsset: PublicKey;
+      executiveProfile: PublicKey;
+      executionDelegateRecord: PublicKey;
+      authority: PublicKey;
+    }
+  | {
+      __kind: 'ExecutionRevokedV1';
+      agentAsset: PublicKey;
+      executiveProfile: PublicKey;
+      executionDelegateRecord: PublicKey;
+    }
+  | { __kind: 'ReceiptsCollectionCreatedV1'; collection: PublicKey }
+  | {
+      __kind: 'ReceiptsTreeRegisteredV1';
+      treeIndex: bigint;
+      merkleTree: PublicKey;
+      maxDepth: number;
+      maxBufferSize: number;
+    }
+  | {
+      __kind: 'WorkReceiptMintedV1';
+      agentAsset: PublicKey;
+      client: PublicKey;
+      executiveAuthority: PublicKey;
+      treeIndex: bigint;
+      receiptUri: string;
+    }
+  | {
+      __kind: 'WorkReceiptClosedV1';
+      leafOwner: PublicKey;
+      treeIndex: bigint;
+      nonce: bigint;
+      index: number;
+    }
+  | { __kind: 'ReviewsCollectionCreatedV1'; collection: PublicKey }
+  | {
+      __kind: 'ReviewsTreeRegisteredV1';
+      treeIndex: bigint;
+      merkleTree: PublicKey;
+      maxDepth: number;
+      maxBufferSize: number;
+    }
+  | {
+      __kind: 'ReviewMintedV1';
+      agentAsset: PublicKey;
+      reviewer: PublicKey;
+      receipt: PublicKey;
+      treeIndex: bigint;
+      nonce: bigint;
+      reviewUri: string;
+    }
+  | { __kind: 'ExecutionDelegateMigrationCompletedV1'; authority: PublicKey }
+  | {
+      __kind: 'ExecutionDelegateCountedV1';
+      agentAsset: PublicKey;
+      executiveProfile: PublicKey;
+      count: bigint;
+    };
+
+export type MplAgentToolsEventArgs =
+  | {
+      __kind: 'ExecutiveRegisteredV1';
+      executiveProfile: PublicKey;
+      authority: PublicKey;
+    }
+  | {
+      __kind: 'ExecutionDelegatedV1';
+      agentAsset: PublicKey;
+      executiveProfile: PublicKey;
+      executionDelegateRecord: PublicKey;
+      authority: PublicKey;
+    }
+  | {
+      __kind: 'ExecutionRevokedV1';
+      agentAsset: PublicKey;
+      executiveProfile: PublicKey;
+      executionDelegateRecord: PublicKey;
+    }
+  | { __kind: 'ReceiptsCollectionCreatedV1'; collection: PublicKey }
+  | {
+      __kind: 'ReceiptsTreeRegisteredV1';
+      treeIndex: number | bigint;
+      merkleTree: PublicKey;
+      maxDepth: number;
+      maxBufferSize: number;
+    }
+  | {
+      __kind: 'WorkReceiptMintedV1';
+      agentAsset: PublicKey;
+      client: PublicKey;
+      executiveAuthority: PublicKey;
+      treeIndex: number | bigint;
+      receiptUri: string;
+    }
+  | {
+      __kind: 'WorkReceiptClosedV1';
+      leafOwner: PublicKey;
+      treeIndex: number | bigint;
+      nonce: number | bigint;
+      index: number;
+    }
+  | { __kind: 'ReviewsCollectionCreatedV1'; collection: PublicKey }
+  | {
+      __kind: 'ReviewsTreeRegisteredV1';
+      treeIndex: number | bigint;
+      merkleTree: PublicKey;
+      maxDepth: number;
+      maxBufferSize: number;
+    }
+  | {
+      __kind: 'ReviewMintedV1';
+      agentAsset: PublicKey;
+      reviewer: PublicKey;
+      receipt: PublicKey;
+      treeIndex: number | bigint;
+      nonce: number | bigint;
+      reviewUri: string;
+    }
+  | { __kind: 'ExecutionDelegateMigrationCompletedV1'; authority: PublicKey }
+  | {
+      __kind: 'ExecutionDelegateCountedV1';
+      agentAsset: PublicKey;
+      executiveProfile: PublicKey;
+      count: number | bigint;
+    };
+
+export function getMplAgentToolsEventSerializer(): Serializer<
+  MplAgentToolsEventArgs,
+  MplAgentToolsEvent
+> {
+  return dataEnum<MplAgentToolsEvent>(
+    [
+      [
+        'ExecutiveRegisteredV1',
+        struct<
+          GetDataEnumKindContent<MplAgentToolsEvent, 'ExecutiveRegisteredV1'>
+        >([
+          ['executiveProfile', publicKeySerializer()],
+          ['authority', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ExecutionDelegatedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentToolsEvent, 'ExecutionDelegatedV1'>
+        >([
+          ['agentAsset', publicKeySerializer()],
+          ['executiveProfile', publicKeySerializer()],
+          ['executionDelegateRecord', publicKeySerializer()],
+          ['authority', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ExecutionRevokedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentToolsEvent, 'ExecutionRevokedV1'>
+        >([
+          ['agentAsset', publicKeySerializer()],
+          ['executiveProfile', publicKeySerializer()],
+          ['executionDelegateRecord', publicKeySerializer()],
+        ]),
+      ],
+      [
+        'ReceiptsCollectionCreatedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentToolsEvent,
+            'ReceiptsCollectionCreatedV1'
+          >
+        >([['collection', publicKeySerializer()]]),
+      ],
+      [
+        'ReceiptsTreeRegisteredV1',
+        struct<
+          GetDataEnumKindContent<MplAgentToolsEvent, 'ReceiptsTreeRegisteredV1'>
+        >([
+          ['treeIndex', u64()],
+          ['merkleTree', publicKeySerializer()],
+          ['maxDepth', u32()],
+          ['maxBufferSize', u32()],
+        ]),
+      ],
+      [
+        'WorkReceiptMintedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentToolsEvent, 'WorkReceiptMintedV1'>
+        >([
+          ['agentAsset', publicKeySerializer()],
+          ['client', publicKeySerializer()],
+          ['executiveAuthority', publicKeySerializer()],
+          ['treeIndex', u64()],
+          ['receiptUri', string()],
+        ]),
+      ],
+      [
+        'WorkReceiptClosedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentToolsEvent, 'WorkReceiptClosedV1'>
+        >([
+          ['leafOwner', publicKeySerializer()],
+          ['treeIndex', u64()],
+          ['nonce', u64()],
+          ['index', u32()],
+        ]),
+      ],
+      [
+        'ReviewsCollectionCreatedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentToolsEvent,
+            'ReviewsCollectionCreatedV1'
+          >
+        >([['collection', publicKeySerializer()]]),
+      ],
+      [
+        'ReviewsTreeRegisteredV1',
+        struct<
+          GetDataEnumKindContent<MplAgentToolsEvent, 'ReviewsTreeRegisteredV1'>
+        >([
+          ['treeIndex', u64()],
+          ['merkleTree', publicKeySerializer()],
+          ['maxDepth', u32()],
+          ['maxBufferSize', u32()],
+        ]),
+      ],
+      [
+        'ReviewMintedV1',
+        struct<GetDataEnumKindContent<MplAgentToolsEvent, 'ReviewMintedV1'>>([
+          ['agentAsset', publicKeySerializer()],
+          ['reviewer', publicKeySerializer()],
+          ['receipt', publicKeySerializer()],
+          ['treeIndex', u64()],
+          ['nonce', u64()],
+          ['reviewUri', string()],
+        ]),
+      ],
+      [
+        'ExecutionDelegateMigrationCompletedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentToolsEvent,
+            'ExecutionDelegateMigrationCompletedV1'
+          >
+        >([['authority', publicKeySerializer()]]),
+      ],
+      [
+        'ExecutionDelegateCountedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentToolsEvent,
+            'ExecutionDelegateCountedV1'
+          >
+        >([
+          ['agentAsset', publicKeySerializer()],
+          ['executiveProfile', publicKeySerializer()],
+          ['count', u64()],
+        ]),
+      ],
+    ],
+    { description: 'MplAgentToolsEvent' }
+  ) as Serializer<MplAgentToolsEventArgs, MplAgentToolsEvent>;
+}
+
+// Data Enum Helpers.
+export function mplAgentToolsEvent(
+  kind: 'ExecutiveRegisteredV1',
+  data: GetDataEnumKindContent<MplAgentToolsEventArgs, 'ExecutiveRegisteredV1'>
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ExecutiveRegisteredV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ExecutionDelegatedV1',
+  data: GetDataEnumKindContent<MplAgentToolsEventArgs, 'ExecutionDelegatedV1'>
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ExecutionDelegatedV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ExecutionRevokedV1',
+  data: GetDataEnumKindContent<MplAgentToolsEventArgs, 'ExecutionRevokedV1'>
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ExecutionRevokedV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ReceiptsCollectionCreatedV1',
+  data: GetDataEnumKindContent<
+    MplAgentToolsEventArgs,
+    'ReceiptsCollectionCreatedV1'
+  >
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ReceiptsCollectionCreatedV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ReceiptsTreeRegisteredV1',
+  data: GetDataEnumKindContent<
+    MplAgentToolsEventArgs,
+    'ReceiptsTreeRegisteredV1'
+  >
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ReceiptsTreeRegisteredV1'>;
+export function mplAgentToolsEvent(
+  kind: 'WorkReceiptMintedV1',
+  data: GetDataEnumKindContent<MplAgentToolsEventArgs, 'WorkReceiptMintedV1'>
+): GetDataEnumKind<MplAgentToolsEventArgs, 'WorkReceiptMintedV1'>;
+export function mplAgentToolsEvent(
+  kind: 'WorkReceiptClosedV1',
+  data: GetDataEnumKindContent<MplAgentToolsEventArgs, 'WorkReceiptClosedV1'>
+): GetDataEnumKind<MplAgentToolsEventArgs, 'WorkReceiptClosedV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ReviewsCollectionCreatedV1',
+  data: GetDataEnumKindContent<
+    MplAgentToolsEventArgs,
+    'ReviewsCollectionCreatedV1'
+  >
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ReviewsCollectionCreatedV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ReviewsTreeRegisteredV1',
+  data: GetDataEnumKindContent<
+    MplAgentToolsEventArgs,
+    'ReviewsTreeRegisteredV1'
+  >
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ReviewsTreeRegisteredV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ReviewMintedV1',
+  data: GetDataEnumKindContent<MplAgentToolsEventArgs, 'ReviewMintedV1'>
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ReviewMintedV1'>;
+export function mplAgentToolsEvent(
+  kind: 'ExecutionDelegateMigrationCompletedV1',
+  data: GetDataEnumKindContent<
+    MplAgentToolsEventArgs,
+    'ExecutionDelegateMigrationCompletedV1'
+  >
+): GetDataEnumKind<
+  MplAgentToolsEventArgs,
+  'ExecutionDelegateMigrationCompletedV1'
+>;
+export function mplAgentToolsEvent(
+  kind: 'ExecutionDelegateCountedV1',
+  data: GetDataEnumKindContent<
+    MplAgentToolsEventArgs,
+    'ExecutionDelegateCountedV1'
+  >
+): GetDataEnumKind<MplAgentToolsEventArgs, 'ExecutionDelegateCountedV1'>;
+export function mplAgentToolsEvent<K extends MplAgentToolsEventArgs['__kind']>(
+  kind: K,
+  data?: any
+): Extract<MplAgentToolsEventArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isMplAgentToolsEvent<K extends MplAgentToolsEvent['__kind']>(
+  kind: K,
+  value: MplAgentToolsEvent
+): value is MplAgentToolsEvent & { __kind: K } {
+  return value.__kind === kind;
+}
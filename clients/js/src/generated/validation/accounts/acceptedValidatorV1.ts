@@ -0,0 +1,215 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type AcceptedValidatorV1 = Account<AcceptedValidatorV1AccountData>;
+
+export type AcceptedValidatorV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  asset: PublicKey;
+  validator: PublicKey;
+};
+
+export type AcceptedValidatorV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  asset: PublicKey;
+  validator: PublicKey;
+};
+
+export function getAcceptedValidatorV1AccountDataSerializer(): Serializer<
+  AcceptedValidatorV1AccountDataArgs,
+  AcceptedValidatorV1AccountData
+> {
+  return mapSerializer<
+    AcceptedValidatorV1AccountDataArgs,
+    any,
+    AcceptedValidatorV1AccountData
+  >(
+    struct<AcceptedValidatorV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['asset', publicKeySerializer()],
+        ['validator', publicKeySerializer()],
+      ],
+      { description: 'AcceptedValidatorV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    AcceptedValidatorV1AccountDataArgs,
+    AcceptedValidatorV1AccountData
+  >;
+}
+
+export function deserializeAcceptedValidatorV1(
+  rawAccount: RpcAccount
+): AcceptedValidatorV1 {
+  return deserializeAccount(
+    rawAccount,
+    getAcceptedValidatorV1AccountDataSerializer()
+  );
+}
+
+export async function fetchAcceptedValidatorV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AcceptedValidatorV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AcceptedValidatorV1');
+  return deserializeAcceptedValidatorV1(maybeAccount);
+}
+
+export async function safeFetchAcceptedValidatorV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AcceptedValidatorV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeAcceptedValidatorV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllAcceptedValidatorV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AcceptedValidatorV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AcceptedValidatorV1');
+    return deserializeAcceptedValidatorV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAcceptedValidatorV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AcceptedValidatorV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAcceptedValidatorV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAcceptedValidatorV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      asset: PublicKey;
+      validator: PublicKey;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      asset: [8, publicKeySerializer()],
+      validator: [40, publicKeySerializer()],
+    })
+    .deserializeUsing<AcceptedValidatorV1>((account) =>
+      deserializeAcceptedValidatorV1(account)
+    );
+}
+
+export function getAcceptedValidatorV1Size(): number {
+  return 72;
+}
+
+export function findAcceptedValidatorV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+    /** The address of the validator authority */
+    validator: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('accepted_validator'),
+    publicKeySerializer().serialize(seeds.asset),
+    publicKeySerializer().serialize(seeds.validator),
+  ]);
+}
+
+export async function fetchAcceptedValidatorV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAcceptedValidatorV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AcceptedValidatorV1> {
+  return fetchAcceptedValidatorV1(
+    context,
+    findAcceptedValidatorV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchAcceptedValidatorV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAcceptedValidatorV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AcceptedValidatorV1 | null> {
+  return safeFetchAcceptedValidatorV1(
+    context,
+    findAcceptedValidatorV1Pda(context, seeds),
+    options
+  );
+}
@@ -26,6 +26,7 @@ import {
   publicKey as publicKeySerializer,
   string,
   struct,
+  u32,
   u8,
 } from '@metaplex-foundation/umi/serializers';
 import { Key, KeyArgs, getKeySerializer } from '../types';
@@ -36,12 +37,14 @@ export type AgentValidationV1AccountData = {
   key: Key;
   bump: number;
   padding: Array<number>;
+  acceptedValidatorCount: number;
   asset: PublicKey;
 };
 
 export type AgentValidationV1AccountDataArgs = {
   key: KeyArgs;
   bump: number;
+  acceptedValidatorCount: number;
   asset: PublicKey;
 };
 
@@ -58,12 +61,13 @@ export function getAgentValidationV1AccountDataSerializer(): Serializer<
       [
         ['key', getKeySerializer()],
         ['bump', u8()],
-        ['padding', array(u8(), { size: 6 })],
+        ['padding', array(u8(), { size: 2 })],
+        ['acceptedValidatorCount', u32()],
         ['asset', publicKeySerializer()],
       ],
       { description: 'AgentValidationV1AccountData' }
     ),
-    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+    (value) => ({ ...value, padding: [0, 0] })
   ) as Serializer<
     AgentValidationV1AccountDataArgs,
     AgentValidationV1AccountData
@@ -149,11 +153,13 @@ export function getAgentValidationV1GpaBuilder(
       key: KeyArgs;
       bump: number;
       padding: Array<number>;
+      acceptedValidatorCount: number;
       asset: PublicKey;
     }>({
       key: [0, getKeySerializer()],
       bump: [1, u8()],
-      padding: [2, array(u8(), { size: 6 })],
+      padding: [2, array(u8(), { size: 2 })],
+      acceptedValidatorCount: [4, u32()],
       asset: [8, publicKeySerializer()],
     })
     .deserializeUsing<AgentValidationV1>((account) =>
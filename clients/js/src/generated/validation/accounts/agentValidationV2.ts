@@ -0,0 +1,251 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type AgentValidationV2 = Account<AgentValidationV2AccountData>;
+
+export type AgentValidationV2AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  acceptedValidatorCount: number;
+  asset: PublicKey;
+  validationCount: bigint;
+  passCount: bigint;
+  lastValidatedSlot: bigint;
+  topValidator: PublicKey;
+  topValidatorCount: bigint;
+  reserved: Uint8Array;
+};
+
+export type AgentValidationV2AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  acceptedValidatorCount: number;
+  asset: PublicKey;
+  validationCount: number | bigint;
+  passCount: number | bigint;
+  lastValidatedSlot: number | bigint;
+  topValidator: PublicKey;
+  topValidatorCount: number | bigint;
+};
+
+export function getAgentValidationV2AccountDataSerializer(): Serializer<
+  AgentValidationV2AccountDataArgs,
+  AgentValidationV2AccountData
+> {
+  return mapSerializer<
+    AgentValidationV2AccountDataArgs,
+    any,
+    AgentValidationV2AccountData
+  >(
+    struct<AgentValidationV2AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 2 })],
+        ['acceptedValidatorCount', u32()],
+        ['asset', publicKeySerializer()],
+        ['validationCount', u64()],
+        ['passCount', u64()],
+        ['lastValidatedSlot', u64()],
+        ['topValidator', publicKeySerializer()],
+        ['topValidatorCount', u64()],
+        ['reserved', bytes({ size: 32 })],
+      ],
+      { description: 'AgentValidationV2AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0],
+      reserved: [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+      ],
+    })
+  ) as Serializer<
+    AgentValidationV2AccountDataArgs,
+    AgentValidationV2AccountData
+  >;
+}
+
+export function deserializeAgentValidationV2(
+  rawAccount: RpcAccount
+): AgentValidationV2 {
+  return deserializeAccount(
+    rawAccount,
+    getAgentValidationV2AccountDataSerializer()
+  );
+}
+
+export async function fetchAgentValidationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AgentValidationV2> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'AgentValidationV2');
+  return deserializeAgentValidationV2(maybeAccount);
+}
+
+export async function safeFetchAgentValidationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<AgentValidationV2 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeAgentValidationV2(maybeAccount)
+    : null;
+}
+
+export async function fetchAllAgentValidationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AgentValidationV2[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'AgentValidationV2');
+    return deserializeAgentValidationV2(maybeAccount);
+  });
+}
+
+export async function safeFetchAllAgentValidationV2(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<AgentValidationV2[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeAgentValidationV2(maybeAccount as RpcAccount)
+    );
+}
+
+export function getAgentValidationV2GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      acceptedValidatorCount: number;
+      asset: PublicKey;
+      validationCount: number | bigint;
+      passCount: number | bigint;
+      lastValidatedSlot: number | bigint;
+      topValidator: PublicKey;
+      topValidatorCount: number | bigint;
+      reserved: Uint8Array;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 2 })],
+      acceptedValidatorCount: [4, u32()],
+      asset: [8, publicKeySerializer()],
+      validationCount: [40, u64()],
+      passCount: [48, u64()],
+      lastValidatedSlot: [56, u64()],
+      topValidator: [64, publicKeySerializer()],
+      topValidatorCount: [96, u64()],
+      reserved: [104, bytes({ size: 32 })],
+    })
+    .deserializeUsing<AgentValidationV2>((account) =>
+      deserializeAgentValidationV2(account)
+    );
+}
+
+export function getAgentValidationV2Size(): number {
+  return 136;
+}
+
+export function findAgentValidationV2Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('agent_validation'),
+    publicKeySerializer().serialize(seeds.asset),
+  ]);
+}
+
+export async function fetchAgentValidationV2FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAgentValidationV2Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AgentValidationV2> {
+  return fetchAgentValidationV2(
+    context,
+    findAgentValidationV2Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchAgentValidationV2FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findAgentValidationV2Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<AgentValidationV2 | null> {
+  return safeFetchAgentValidationV2(
+    context,
+    findAgentValidationV2Pda(context, seeds),
+    options
+  );
+}
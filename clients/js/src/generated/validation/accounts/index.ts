@@ -6,4 +6,12 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './acceptedValidatorV1';
 export * from './agentValidationV1';
+export * from './agentValidationV2';
+export * from './validationConfigV1';
+export * from './validationRequestV1';
+export * from './validatorAttesterV1';
+export * from './validatorHistoryV1';
+export * from './validatorProfileV1';
+export * from './validatorStakeVaultV1';
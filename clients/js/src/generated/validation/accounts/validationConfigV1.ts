@@ -0,0 +1,223 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ValidationConfigV1 = Account<ValidationConfigV1AccountData>;
+
+export type ValidationConfigV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  authority: PublicKey;
+  arbiter: PublicKey;
+  unbondingSlots: bigint;
+  reserved: Array<number>;
+};
+
+export type ValidationConfigV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  authority: PublicKey;
+  arbiter: PublicKey;
+  unbondingSlots: number | bigint;
+};
+
+export function getValidationConfigV1AccountDataSerializer(): Serializer<
+  ValidationConfigV1AccountDataArgs,
+  ValidationConfigV1AccountData
+> {
+  return mapSerializer<
+    ValidationConfigV1AccountDataArgs,
+    any,
+    ValidationConfigV1AccountData
+  >(
+    struct<ValidationConfigV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['authority', publicKeySerializer()],
+        ['arbiter', publicKeySerializer()],
+        ['unbondingSlots', u64()],
+        ['reserved', array(u8(), { size: 64 })],
+      ],
+      { description: 'ValidationConfigV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0, 0, 0, 0, 0],
+      reserved: [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+      ],
+    })
+  ) as Serializer<
+    ValidationConfigV1AccountDataArgs,
+    ValidationConfigV1AccountData
+  >;
+}
+
+export function deserializeValidationConfigV1(
+  rawAccount: RpcAccount
+): ValidationConfigV1 {
+  return deserializeAccount(
+    rawAccount,
+    getValidationConfigV1AccountDataSerializer()
+  );
+}
+
+export async function fetchValidationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidationConfigV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ValidationConfigV1');
+  return deserializeValidationConfigV1(maybeAccount);
+}
+
+export async function safeFetchValidationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidationConfigV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeValidationConfigV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllValidationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidationConfigV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ValidationConfigV1');
+    return deserializeValidationConfigV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllValidationConfigV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidationConfigV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeValidationConfigV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getValidationConfigV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      authority: PublicKey;
+      arbiter: PublicKey;
+      unbondingSlots: number | bigint;
+      reserved: Array<number>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      authority: [8, publicKeySerializer()],
+      arbiter: [40, publicKeySerializer()],
+      unbondingSlots: [72, u64()],
+      reserved: [80, array(u8(), { size: 64 })],
+    })
+    .deserializeUsing<ValidationConfigV1>((account) =>
+      deserializeValidationConfigV1(account)
+    );
+}
+
+export function getValidationConfigV1Size(): number {
+  return 144;
+}
+
+export function findValidationConfigV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('validation_config'),
+  ]);
+}
+
+export async function fetchValidationConfigV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ValidationConfigV1> {
+  return fetchValidationConfigV1(
+    context,
+    findValidationConfigV1Pda(context),
+    options
+  );
+}
+
+export async function safeFetchValidationConfigV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  options?: RpcGetAccountOptions
+): Promise<ValidationConfigV1 | null> {
+  return safeFetchValidationConfigV1(
+    context,
+    findValidationConfigV1Pda(context),
+    options
+  );
+}
@@ -0,0 +1,300 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  EvidenceType,
+  EvidenceTypeArgs,
+  Key,
+  KeyArgs,
+  ValidationStatus,
+  ValidationStatusArgs,
+  getEvidenceTypeSerializer,
+  getKeySerializer,
+  getValidationStatusSerializer,
+} from '../types';
+
+export type ValidationRequestV1 = Account<ValidationRequestV1AccountData>;
+
+export type ValidationRequestV1AccountData = {
+  key: Key;
+  bump: number;
+  status: ValidationStatus;
+  result: number;
+  evidenceType: EvidenceType;
+  counted: number;
+  padding: Array<number>;
+  asset: PublicKey;
+  requester: PublicKey;
+  validator: PublicKey;
+  nonce: bigint;
+  requestSlot: bigint;
+  responseSlot: bigint;
+  workHash: Uint8Array;
+  evidenceHash: Uint8Array;
+  reward: bigint;
+  expirySlot: bigint;
+  validatorStake: bigint;
+  reserved: Array<number>;
+};
+
+export type ValidationRequestV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  status: ValidationStatusArgs;
+  result: number;
+  evidenceType: EvidenceTypeArgs;
+  counted: number;
+  asset: PublicKey;
+  requester: PublicKey;
+  validator: PublicKey;
+  nonce: number | bigint;
+  requestSlot: number | bigint;
+  responseSlot: number | bigint;
+  workHash: Uint8Array;
+  evidenceHash: Uint8Array;
+  reward: number | bigint;
+  expirySlot: number | bigint;
+  validatorStake: number | bigint;
+};
+
+export function getValidationRequestV1AccountDataSerializer(): Serializer<
+  ValidationRequestV1AccountDataArgs,
+  ValidationRequestV1AccountData
+> {
+  return mapSerializer<
+    ValidationRequestV1AccountDataArgs,
+    any,
+    ValidationRequestV1AccountData
+  >(
+    struct<ValidationRequestV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['status', getValidationStatusSerializer()],
+        ['result', u8()],
+        ['evidenceType', getEvidenceTypeSerializer()],
+        ['counted', u8()],
+        ['padding', array(u8(), { size: 2 })],
+        ['asset', publicKeySerializer()],
+        ['requester', publicKeySerializer()],
+        ['validator', publicKeySerializer()],
+        ['nonce', u64()],
+        ['requestSlot', u64()],
+        ['responseSlot', u64()],
+        ['workHash', bytes({ size: 32 })],
+        ['evidenceHash', bytes({ size: 32 })],
+        ['reward', u64()],
+        ['expirySlot', u64()],
+        ['validatorStake', u64()],
+        ['reserved', array(u8(), { size: 8 })],
+      ],
+      { description: 'ValidationRequestV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0],
+      reserved: [0, 0, 0, 0, 0, 0, 0, 0],
+    })
+  ) as Serializer<
+    ValidationRequestV1AccountDataArgs,
+    ValidationRequestV1AccountData
+  >;
+}
+
+export function deserializeValidationRequestV1(
+  rawAccount: RpcAccount
+): ValidationRequestV1 {
+  return deserializeAccount(
+    rawAccount,
+    getValidationRequestV1AccountDataSerializer()
+  );
+}
+
+export async function fetchValidationRequestV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidationRequestV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ValidationRequestV1');
+  return deserializeValidationRequestV1(maybeAccount);
+}
+
+export async function safeFetchValidationRequestV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidationRequestV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeValidationRequestV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllValidationRequestV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidationRequestV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ValidationRequestV1');
+    return deserializeValidationRequestV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllValidationRequestV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidationRequestV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeValidationRequestV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getValidationRequestV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      status: ValidationStatusArgs;
+      result: number;
+      evidenceType: EvidenceTypeArgs;
+      counted: number;
+      padding: Array<number>;
+      asset: PublicKey;
+      requester: PublicKey;
+      validator: PublicKey;
+      nonce: number | bigint;
+      requestSlot: number | bigint;
+      responseSlot: number | bigint;
+      workHash: Uint8Array;
+      evidenceHash: Uint8Array;
+      reward: number | bigint;
+      expirySlot: number | bigint;
+      validatorStake: number | bigint;
+      reserved: Array<number>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      status: [2, getValidationStatusSerializer()],
+      result: [3, u8()],
+      evidenceType: [4, getEvidenceTypeSerializer()],
+      counted: [5, u8()],
+      padding: [6, array(u8(), { size: 2 })],
+      asset: [8, publicKeySerializer()],
+      requester: [40, publicKeySerializer()],
+      validator: [72, publicKeySerializer()],
+      nonce: [104, u64()],
+      requestSlot: [112, u64()],
+      responseSlot: [120, u64()],
+      workHash: [128, bytes({ size: 32 })],
+      evidenceHash: [160, bytes({ size: 32 })],
+      reward: [192, u64()],
+      expirySlot: [200, u64()],
+      validatorStake: [208, u64()],
+      reserved: [216, array(u8(), { size: 8 })],
+    })
+    .deserializeUsing<ValidationRequestV1>((account) =>
+      deserializeValidationRequestV1(account)
+    );
+}
+
+export function getValidationRequestV1Size(): number {
+  return 224;
+}
+
+export function findValidationRequestV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+    /** Nonce of the request among the requests for the asset */
+    nonce: number | bigint;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('validation_request'),
+    publicKeySerializer().serialize(seeds.asset),
+    u64().serialize(seeds.nonce),
+  ]);
+}
+
+export async function fetchValidationRequestV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidationRequestV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidationRequestV1> {
+  return fetchValidationRequestV1(
+    context,
+    findValidationRequestV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchValidationRequestV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidationRequestV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidationRequestV1 | null> {
+  return safeFetchValidationRequestV1(
+    context,
+    findValidationRequestV1Pda(context, seeds),
+    options
+  );
+}
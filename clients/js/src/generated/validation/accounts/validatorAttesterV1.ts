@@ -0,0 +1,215 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ValidatorAttesterV1 = Account<ValidatorAttesterV1AccountData>;
+
+export type ValidatorAttesterV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  validator: PublicKey;
+  attester: PublicKey;
+};
+
+export type ValidatorAttesterV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  validator: PublicKey;
+  attester: PublicKey;
+};
+
+export function getValidatorAttesterV1AccountDataSerializer(): Serializer<
+  ValidatorAttesterV1AccountDataArgs,
+  ValidatorAttesterV1AccountData
+> {
+  return mapSerializer<
+    ValidatorAttesterV1AccountDataArgs,
+    any,
+    ValidatorAttesterV1AccountData
+  >(
+    struct<ValidatorAttesterV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['validator', publicKeySerializer()],
+        ['attester', publicKeySerializer()],
+      ],
+      { description: 'ValidatorAttesterV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ValidatorAttesterV1AccountDataArgs,
+    ValidatorAttesterV1AccountData
+  >;
+}
+
+export function deserializeValidatorAttesterV1(
+  rawAccount: RpcAccount
+): ValidatorAttesterV1 {
+  return deserializeAccount(
+    rawAccount,
+    getValidatorAttesterV1AccountDataSerializer()
+  );
+}
+
+export async function fetchValidatorAttesterV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorAttesterV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ValidatorAttesterV1');
+  return deserializeValidatorAttesterV1(maybeAccount);
+}
+
+export async function safeFetchValidatorAttesterV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorAttesterV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeValidatorAttesterV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllValidatorAttesterV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorAttesterV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ValidatorAttesterV1');
+    return deserializeValidatorAttesterV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllValidatorAttesterV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorAttesterV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeValidatorAttesterV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getValidatorAttesterV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      validator: PublicKey;
+      attester: PublicKey;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      validator: [8, publicKeySerializer()],
+      attester: [40, publicKeySerializer()],
+    })
+    .deserializeUsing<ValidatorAttesterV1>((account) =>
+      deserializeValidatorAttesterV1(account)
+    );
+}
+
+export function getValidatorAttesterV1Size(): number {
+  return 72;
+}
+
+export function findValidatorAttesterV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the validator authority */
+    validator: PublicKey;
+    /** The ed25519 attester key */
+    attester: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('validator_attester'),
+    publicKeySerializer().serialize(seeds.validator),
+    publicKeySerializer().serialize(seeds.attester),
+  ]);
+}
+
+export async function fetchValidatorAttesterV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorAttesterV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorAttesterV1> {
+  return fetchValidatorAttesterV1(
+    context,
+    findValidatorAttesterV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchValidatorAttesterV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorAttesterV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorAttesterV1 | null> {
+  return safeFetchValidatorAttesterV1(
+    context,
+    findValidatorAttesterV1Pda(context, seeds),
+    options
+  );
+}
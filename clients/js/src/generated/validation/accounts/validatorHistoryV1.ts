@@ -0,0 +1,221 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ValidatorHistoryV1 = Account<ValidatorHistoryV1AccountData>;
+
+export type ValidatorHistoryV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  asset: PublicKey;
+  validator: PublicKey;
+  validationCount: bigint;
+};
+
+export type ValidatorHistoryV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  asset: PublicKey;
+  validator: PublicKey;
+  validationCount: number | bigint;
+};
+
+export function getValidatorHistoryV1AccountDataSerializer(): Serializer<
+  ValidatorHistoryV1AccountDataArgs,
+  ValidatorHistoryV1AccountData
+> {
+  return mapSerializer<
+    ValidatorHistoryV1AccountDataArgs,
+    any,
+    ValidatorHistoryV1AccountData
+  >(
+    struct<ValidatorHistoryV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['asset', publicKeySerializer()],
+        ['validator', publicKeySerializer()],
+        ['validationCount', u64()],
+      ],
+      { description: 'ValidatorHistoryV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ValidatorHistoryV1AccountDataArgs,
+    ValidatorHistoryV1AccountData
+  >;
+}
+
+export function deserializeValidatorHistoryV1(
+  rawAccount: RpcAccount
+): ValidatorHistoryV1 {
+  return deserializeAccount(
+    rawAccount,
+    getValidatorHistoryV1AccountDataSerializer()
+  );
+}
+
+export async function fetchValidatorHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorHistoryV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ValidatorHistoryV1');
+  return deserializeValidatorHistoryV1(maybeAccount);
+}
+
+export async function safeFetchValidatorHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorHistoryV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeValidatorHistoryV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllValidatorHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorHistoryV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ValidatorHistoryV1');
+    return deserializeValidatorHistoryV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllValidatorHistoryV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorHistoryV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeValidatorHistoryV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getValidatorHistoryV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      asset: PublicKey;
+      validator: PublicKey;
+      validationCount: number | bigint;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      asset: [8, publicKeySerializer()],
+      validator: [40, publicKeySerializer()],
+      validationCount: [72, u64()],
+    })
+    .deserializeUsing<ValidatorHistoryV1>((account) =>
+      deserializeValidatorHistoryV1(account)
+    );
+}
+
+export function getValidatorHistoryV1Size(): number {
+  return 80;
+}
+
+export function findValidatorHistoryV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the asset */
+    asset: PublicKey;
+    /** The address of the validator authority */
+    validator: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('validator_history'),
+    publicKeySerializer().serialize(seeds.asset),
+    publicKeySerializer().serialize(seeds.validator),
+  ]);
+}
+
+export async function fetchValidatorHistoryV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorHistoryV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorHistoryV1> {
+  return fetchValidatorHistoryV1(
+    context,
+    findValidatorHistoryV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchValidatorHistoryV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorHistoryV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorHistoryV1 | null> {
+  return safeFetchValidatorHistoryV1(
+    context,
+    findValidatorHistoryV1Pda(context, seeds),
+    options
+  );
+}
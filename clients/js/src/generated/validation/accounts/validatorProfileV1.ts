@@ -0,0 +1,250 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ValidatorProfileV1 = Account<ValidatorProfileV1AccountData>;
+
+export type ValidatorProfileV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  authority: PublicKey;
+  stake: bigint;
+  unbondingStake: bigint;
+  unbondingSlot: bigint;
+  slashedStake: bigint;
+  registeredSlot: bigint;
+  attesterCount: bigint;
+  reserved: Array<number>;
+};
+
+export type ValidatorProfileV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  authority: PublicKey;
+  stake: number | bigint;
+  unbondingStake: number | bigint;
+  unbondingSlot: number | bigint;
+  slashedStake: number | bigint;
+  registeredSlot: number | bigint;
+  attesterCount: number | bigint;
+};
+
+export function getValidatorProfileV1AccountDataSerializer(): Serializer<
+  ValidatorProfileV1AccountDataArgs,
+  ValidatorProfileV1AccountData
+> {
+  return mapSerializer<
+    ValidatorProfileV1AccountDataArgs,
+    any,
+    ValidatorProfileV1AccountData
+  >(
+    struct<ValidatorProfileV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['authority', publicKeySerializer()],
+        ['stake', u64()],
+        ['unbondingStake', u64()],
+        ['unbondingSlot', u64()],
+        ['slashedStake', u64()],
+        ['registeredSlot', u64()],
+        ['attesterCount', u64()],
+        ['reserved', array(u8(), { size: 56 })],
+      ],
+      { description: 'ValidatorProfileV1AccountData' }
+    ),
+    (value) => ({
+      ...value,
+      padding: [0, 0, 0, 0, 0, 0],
+      reserved: [
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+        0, 0, 0, 0, 0, 0, 0, 0,
+      ],
+    })
+  ) as Serializer<
+    ValidatorProfileV1AccountDataArgs,
+    ValidatorProfileV1AccountData
+  >;
+}
+
+export function deserializeValidatorProfileV1(
+  rawAccount: RpcAccount
+): ValidatorProfileV1 {
+  return deserializeAccount(
+    rawAccount,
+    getValidatorProfileV1AccountDataSerializer()
+  );
+}
+
+export async function fetchValidatorProfileV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorProfileV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ValidatorProfileV1');
+  return deserializeValidatorProfileV1(maybeAccount);
+}
+
+export async function safeFetchValidatorProfileV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorProfileV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeValidatorProfileV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllValidatorProfileV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorProfileV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ValidatorProfileV1');
+    return deserializeValidatorProfileV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllValidatorProfileV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorProfileV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeValidatorProfileV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getValidatorProfileV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      authority: PublicKey;
+      stake: number | bigint;
+      unbondingStake: number | bigint;
+      unbondingSlot: number | bigint;
+      slashedStake: number | bigint;
+      registeredSlot: number | bigint;
+      attesterCount: number | bigint;
+      reserved: Array<number>;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      authority: [8, publicKeySerializer()],
+      stake: [40, u64()],
+      unbondingStake: [48, u64()],
+      unbondingSlot: [56, u64()],
+      slashedStake: [64, u64()],
+      registeredSlot: [72, u64()],
+      attesterCount: [80, u64()],
+      reserved: [88, array(u8(), { size: 56 })],
+    })
+    .deserializeUsing<ValidatorProfileV1>((account) =>
+      deserializeValidatorProfileV1(account)
+    );
+}
+
+export function getValidatorProfileV1Size(): number {
+  return 144;
+}
+
+export function findValidatorProfileV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the validator authority */
+    validator: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('validator_profile'),
+    publicKeySerializer().serialize(seeds.validator),
+  ]);
+}
+
+export async function fetchValidatorProfileV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorProfileV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorProfileV1> {
+  return fetchValidatorProfileV1(
+    context,
+    findValidatorProfileV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchValidatorProfileV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorProfileV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorProfileV1 | null> {
+  return safeFetchValidatorProfileV1(
+    context,
+    findValidatorProfileV1Pda(context, seeds),
+    options
+  );
+}
@@ -0,0 +1,207 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Account,
+  Context,
+  Pda,
+  PublicKey,
+  RpcAccount,
+  RpcGetAccountOptions,
+  RpcGetAccountsOptions,
+  assertAccountExists,
+  deserializeAccount,
+  gpaBuilder,
+  publicKey as toPublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { Key, KeyArgs, getKeySerializer } from '../types';
+
+export type ValidatorStakeVaultV1 = Account<ValidatorStakeVaultV1AccountData>;
+
+export type ValidatorStakeVaultV1AccountData = {
+  key: Key;
+  bump: number;
+  padding: Array<number>;
+  validator: PublicKey;
+};
+
+export type ValidatorStakeVaultV1AccountDataArgs = {
+  key: KeyArgs;
+  bump: number;
+  validator: PublicKey;
+};
+
+export function getValidatorStakeVaultV1AccountDataSerializer(): Serializer<
+  ValidatorStakeVaultV1AccountDataArgs,
+  ValidatorStakeVaultV1AccountData
+> {
+  return mapSerializer<
+    ValidatorStakeVaultV1AccountDataArgs,
+    any,
+    ValidatorStakeVaultV1AccountData
+  >(
+    struct<ValidatorStakeVaultV1AccountData>(
+      [
+        ['key', getKeySerializer()],
+        ['bump', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['validator', publicKeySerializer()],
+      ],
+      { description: 'ValidatorStakeVaultV1AccountData' }
+    ),
+    (value) => ({ ...value, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ValidatorStakeVaultV1AccountDataArgs,
+    ValidatorStakeVaultV1AccountData
+  >;
+}
+
+export function deserializeValidatorStakeVaultV1(
+  rawAccount: RpcAccount
+): ValidatorStakeVaultV1 {
+  return deserializeAccount(
+    rawAccount,
+    getValidatorStakeVaultV1AccountDataSerializer()
+  );
+}
+
+export async function fetchValidatorStakeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorStakeVaultV1> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  assertAccountExists(maybeAccount, 'ValidatorStakeVaultV1');
+  return deserializeValidatorStakeVaultV1(maybeAccount);
+}
+
+export async function safeFetchValidatorStakeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKey: PublicKey | Pda,
+  options?: RpcGetAccountOptions
+): Promise<ValidatorStakeVaultV1 | null> {
+  const maybeAccount = await context.rpc.getAccount(
+    toPublicKey(publicKey, false),
+    options
+  );
+  return maybeAccount.exists
+    ? deserializeValidatorStakeVaultV1(maybeAccount)
+    : null;
+}
+
+export async function fetchAllValidatorStakeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorStakeVaultV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts.map((maybeAccount) => {
+    assertAccountExists(maybeAccount, 'ValidatorStakeVaultV1');
+    return deserializeValidatorStakeVaultV1(maybeAccount);
+  });
+}
+
+export async function safeFetchAllValidatorStakeVaultV1(
+  context: Pick<Context, 'rpc'>,
+  publicKeys: Array<PublicKey | Pda>,
+  options?: RpcGetAccountsOptions
+): Promise<ValidatorStakeVaultV1[]> {
+  const maybeAccounts = await context.rpc.getAccounts(
+    publicKeys.map((key) => toPublicKey(key, false)),
+    options
+  );
+  return maybeAccounts
+    .filter((maybeAccount) => maybeAccount.exists)
+    .map((maybeAccount) =>
+      deserializeValidatorStakeVaultV1(maybeAccount as RpcAccount)
+    );
+}
+
+export function getValidatorStakeVaultV1GpaBuilder(
+  context: Pick<Context, 'rpc' | 'programs'>
+) {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return gpaBuilder(context, programId)
+    .registerFields<{
+      key: KeyArgs;
+      bump: number;
+      padding: Array<number>;
+      validator: PublicKey;
+    }>({
+      key: [0, getKeySerializer()],
+      bump: [1, u8()],
+      padding: [2, array(u8(), { size: 6 })],
+      validator: [8, publicKeySerializer()],
+    })
+    .deserializeUsing<ValidatorStakeVaultV1>((account) =>
+      deserializeValidatorStakeVaultV1(account)
+    );
+}
+
+export function getValidatorStakeVaultV1Size(): number {
+  return 40;
+}
+
+export function findValidatorStakeVaultV1Pda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: {
+    /** The address of the validator authority */
+    validator: PublicKey;
+  }
+): Pda {
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+  return context.eddsa.findPda(programId, [
+    string({ size: 'variable' }).serialize('validator_stake_vault'),
+    publicKeySerializer().serialize(seeds.validator),
+  ]);
+}
+
+export async function fetchValidatorStakeVaultV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorStakeVaultV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorStakeVaultV1> {
+  return fetchValidatorStakeVaultV1(
+    context,
+    findValidatorStakeVaultV1Pda(context, seeds),
+    options
+  );
+}
+
+export async function safeFetchValidatorStakeVaultV1FromSeeds(
+  context: Pick<Context, 'eddsa' | 'programs' | 'rpc'>,
+  seeds: Parameters<typeof findValidatorStakeVaultV1Pda>[1],
+  options?: RpcGetAccountOptions
+): Promise<ValidatorStakeVaultV1 | null> {
+  return safeFetchValidatorStakeVaultV1(
+    context,
+    findValidatorStakeVaultV1Pda(context, seeds),
+    options
+  );
+}
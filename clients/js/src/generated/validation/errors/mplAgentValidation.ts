@@ -96,6 +96,598 @@ nameToErrorMap.set(
   AgentValidationAlreadyRegisteredError
 );
 
+/** AgentValidationNotRegistered: Asset has no agent validation */
+export class AgentValidationNotRegisteredError extends ProgramError {
+  override readonly name: string = 'AgentValidationNotRegistered';
+
+  readonly code: number = 0x6; // 6
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset has no agent validation', program, cause);
+  }
+}
+codeToErrorMap.set(0x6, AgentValidationNotRegisteredError);
+nameToErrorMap.set(
+  'AgentValidationNotRegistered',
+  AgentValidationNotRegisteredError
+);
+
+/** InvalidValidationRequest: Invalid validation request account */
+export class InvalidValidationRequestError extends ProgramError {
+  override readonly name: string = 'InvalidValidationRequest';
+
+  readonly code: number = 0x7; // 7
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid validation request account', program, cause);
+  }
+}
+codeToErrorMap.set(0x7, InvalidValidationRequestError);
+nameToErrorMap.set('InvalidValidationRequest', InvalidValidationRequestError);
+
+/** ValidationRequestAlreadyExists: Validation request already exists */
+export class ValidationRequestAlreadyExistsError extends ProgramError {
+  override readonly name: string = 'ValidationRequestAlreadyExists';
+
+  readonly code: number = 0x8; // 8
+
+  constructor(program: Program, cause?: Error) {
+    super('Validation request already exists', program, cause);
+  }
+}
+codeToErrorMap.set(0x8, ValidationRequestAlreadyExistsError);
+nameToErrorMap.set(
+  'ValidationRequestAlreadyExists',
+  ValidationRequestAlreadyExistsError
+);
+
+/** EmptyWorkHash: Work hash must be non-zero */
+export class EmptyWorkHashError extends ProgramError {
+  override readonly name: string = 'EmptyWorkHash';
+
+  readonly code: number = 0x9; // 9
+
+  constructor(program: Program, cause?: Error) {
+    super('Work hash must be non-zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x9, EmptyWorkHashError);
+nameToErrorMap.set('EmptyWorkHash', EmptyWorkHashError);
+
+/** RequesterCannotValidate: Requester cannot validate their own request */
+export class RequesterCannotValidateError extends ProgramError {
+  override readonly name: string = 'RequesterCannotValidate';
+
+  readonly code: number = 0xa; // 10
+
+  constructor(program: Program, cause?: Error) {
+    super('Requester cannot validate their own request', program, cause);
+  }
+}
+codeToErrorMap.set(0xa, RequesterCannotValidateError);
+nameToErrorMap.set('RequesterCannotValidate', RequesterCannotValidateError);
+
+/** OnlyDesignatedValidatorCanRespond: Only the designated validator can respond */
+export class OnlyDesignatedValidatorCanRespondError extends ProgramError {
+  override readonly name: string = 'OnlyDesignatedValidatorCanRespond';
+
+  readonly code: number = 0xb; // 11
+
+  constructor(program: Program, cause?: Error) {
+    super('Only the designated validator can respond', program, cause);
+  }
+}
+codeToErrorMap.set(0xb, OnlyDesignatedValidatorCanRespondError);
+nameToErrorMap.set(
+  'OnlyDesignatedValidatorCanRespond',
+  OnlyDesignatedValidatorCanRespondError
+);
+
+/** ValidationAlreadyResponded: Validation was already responded to */
+export class ValidationAlreadyRespondedError extends ProgramError {
+  override readonly name: string = 'ValidationAlreadyResponded';
+
+  readonly code: number = 0xc; // 12
+
+  constructor(program: Program, cause?: Error) {
+    super('Validation was already responded to', program, cause);
+  }
+}
+codeToErrorMap.set(0xc, ValidationAlreadyRespondedError);
+nameToErrorMap.set(
+  'ValidationAlreadyResponded',
+  ValidationAlreadyRespondedError
+);
+
+/** InvalidValidationResult: Validation result exceeds the maximum of 100 */
+export class InvalidValidationResultError extends ProgramError {
+  override readonly name: string = 'InvalidValidationResult';
+
+  readonly code: number = 0xd; // 13
+
+  constructor(program: Program, cause?: Error) {
+    super('Validation result exceeds the maximum of 100', program, cause);
+  }
+}
+codeToErrorMap.set(0xd, InvalidValidationResultError);
+nameToErrorMap.set('InvalidValidationResult', InvalidValidationResultError);
+
+/** EvidenceUriEmpty: Evidence URI must not be empty */
+export class EvidenceUriEmptyError extends ProgramError {
+  override readonly name: string = 'EvidenceUriEmpty';
+
+  readonly code: number = 0xe; // 14
+
+  constructor(program: Program, cause?: Error) {
+    super('Evidence URI must not be empty', program, cause);
+  }
+}
+codeToErrorMap.set(0xe, EvidenceUriEmptyError);
+nameToErrorMap.set('EvidenceUriEmpty', EvidenceUriEmptyError);
+
+/** EvidenceUriTooLong: Evidence URI exceeds the maximum length */
+export class EvidenceUriTooLongError extends ProgramError {
+  override readonly name: string = 'EvidenceUriTooLong';
+
+  readonly code: number = 0xf; // 15
+
+  constructor(program: Program, cause?: Error) {
+    super('Evidence URI exceeds the maximum length', program, cause);
+  }
+}
+codeToErrorMap.set(0xf, EvidenceUriTooLongError);
+nameToErrorMap.set('EvidenceUriTooLong', EvidenceUriTooLongError);
+
+/** EvidenceUriNotUtf8: Evidence URI is not valid UTF-8 */
+export class EvidenceUriNotUtf8Error extends ProgramError {
+  override readonly name: string = 'EvidenceUriNotUtf8';
+
+  readonly code: number = 0x10; // 16
+
+  constructor(program: Program, cause?: Error) {
+    super('Evidence URI is not valid UTF-8', program, cause);
+  }
+}
+codeToErrorMap.set(0x10, EvidenceUriNotUtf8Error);
+nameToErrorMap.set('EvidenceUriNotUtf8', EvidenceUriNotUtf8Error);
+
+/** EvidenceUriSchemeNotAllowed: Evidence URI scheme is not allowed */
+export class EvidenceUriSchemeNotAllowedError extends ProgramError {
+  override readonly name: string = 'EvidenceUriSchemeNotAllowed';
+
+  readonly code: number = 0x11; // 17
+
+  constructor(program: Program, cause?: Error) {
+    super('Evidence URI scheme is not allowed', program, cause);
+  }
+}
+codeToErrorMap.set(0x11, EvidenceUriSchemeNotAllowedError);
+nameToErrorMap.set(
+  'EvidenceUriSchemeNotAllowed',
+  EvidenceUriSchemeNotAllowedError
+);
+
+/** InvalidValidationConfig: Invalid validation config account */
+export class InvalidValidationConfigError extends ProgramError {
+  override readonly name: string = 'InvalidValidationConfig';
+
+  readonly code: number = 0x12; // 18
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid validation config account', program, cause);
+  }
+}
+codeToErrorMap.set(0x12, InvalidValidationConfigError);
+nameToErrorMap.set('InvalidValidationConfig', InvalidValidationConfigError);
+
+/** ValidationConfigAlreadyInitialized: Validation config already initialized */
+export class ValidationConfigAlreadyInitializedError extends ProgramError {
+  override readonly name: string = 'ValidationConfigAlreadyInitialized';
+
+  readonly code: number = 0x13; // 19
+
+  constructor(program: Program, cause?: Error) {
+    super('Validation config already initialized', program, cause);
+  }
+}
+codeToErrorMap.set(0x13, ValidationConfigAlreadyInitializedError);
+nameToErrorMap.set(
+  'ValidationConfigAlreadyInitialized',
+  ValidationConfigAlreadyInitializedError
+);
+
+/** InvalidProgramData: Invalid program data account */
+export class InvalidProgramDataError extends ProgramError {
+  override readonly name: string = 'InvalidProgramData';
+
+  readonly code: number = 0x14; // 20
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid program data account', program, cause);
+  }
+}
+codeToErrorMap.set(0x14, InvalidProgramDataError);
+nameToErrorMap.set('InvalidProgramData', InvalidProgramDataError);
+
+/** InvalidConfigAuthority: Signer is not the config authority */
+export class InvalidConfigAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidConfigAuthority';
+
+  readonly code: number = 0x15; // 21
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the config authority', program, cause);
+  }
+}
+codeToErrorMap.set(0x15, InvalidConfigAuthorityError);
+nameToErrorMap.set('InvalidConfigAuthority', InvalidConfigAuthorityError);
+
+/** InvalidUnbondingPeriod: Unbonding period must be non-zero */
+export class InvalidUnbondingPeriodError extends ProgramError {
+  override readonly name: string = 'InvalidUnbondingPeriod';
+
+  readonly code: number = 0x16; // 22
+
+  constructor(program: Program, cause?: Error) {
+    super('Unbonding period must be non-zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x16, InvalidUnbondingPeriodError);
+nameToErrorMap.set('InvalidUnbondingPeriod', InvalidUnbondingPeriodError);
+
+/** InvalidValidatorProfile: Invalid validator profile account */
+export class InvalidValidatorProfileError extends ProgramError {
+  override readonly name: string = 'InvalidValidatorProfile';
+
+  readonly code: number = 0x17; // 23
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid validator profile account', program, cause);
+  }
+}
+codeToErrorMap.set(0x17, InvalidValidatorProfileError);
+nameToErrorMap.set('InvalidValidatorProfile', InvalidValidatorProfileError);
+
+/** ValidatorAlreadyRegistered: Validator already registered */
+export class ValidatorAlreadyRegisteredError extends ProgramError {
+  override readonly name: string = 'ValidatorAlreadyRegistered';
+
+  readonly code: number = 0x18; // 24
+
+  constructor(program: Program, cause?: Error) {
+    super('Validator already registered', program, cause);
+  }
+}
+codeToErrorMap.set(0x18, ValidatorAlreadyRegisteredError);
+nameToErrorMap.set(
+  'ValidatorAlreadyRegistered',
+  ValidatorAlreadyRegisteredError
+);
+
+/** ValidatorNotRegistered: Validator is not registered */
+export class ValidatorNotRegisteredError extends ProgramError {
+  override readonly name: string = 'ValidatorNotRegistered';
+
+  readonly code: number = 0x19; // 25
+
+  constructor(program: Program, cause?: Error) {
+    super('Validator is not registered', program, cause);
+  }
+}
+codeToErrorMap.set(0x19, ValidatorNotRegisteredError);
+nameToErrorMap.set('ValidatorNotRegistered', ValidatorNotRegisteredError);
+
+/** InvalidValidatorStakeVault: Invalid validator stake vault account */
+export class InvalidValidatorStakeVaultError extends ProgramError {
+  override readonly name: string = 'InvalidValidatorStakeVault';
+
+  readonly code: number = 0x1a; // 26
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid validator stake vault account', program, cause);
+  }
+}
+codeToErrorMap.set(0x1a, InvalidValidatorStakeVaultError);
+nameToErrorMap.set(
+  'InvalidValidatorStakeVault',
+  InvalidValidatorStakeVaultError
+);
+
+/** InvalidStakeAmount: Stake amount must be non-zero */
+export class InvalidStakeAmountError extends ProgramError {
+  override readonly name: string = 'InvalidStakeAmount';
+
+  readonly code: number = 0x1b; // 27
+
+  constructor(program: Program, cause?: Error) {
+    super('Stake amount must be non-zero', program, cause);
+  }
+}
+codeToErrorMap.set(0x1b, InvalidStakeAmountError);
+nameToErrorMap.set('InvalidStakeAmount', InvalidStakeAmountError);
+
+/** InsufficientValidatorStake: Validator does not have enough stake */
+export class InsufficientValidatorStakeError extends ProgramError {
+  override readonly name: string = 'InsufficientValidatorStake';
+
+  readonly code: number = 0x1c; // 28
+
+  constructor(program: Program, cause?: Error) {
+    super('Validator does not have enough stake', program, cause);
+  }
+}
+codeToErrorMap.set(0x1c, InsufficientValidatorStakeError);
+nameToErrorMap.set(
+  'InsufficientValidatorStake',
+  InsufficientValidatorStakeError
+);
+
+/** NoUnbondingStake: Validator has no unbonding stake */
+export class NoUnbondingStakeError extends ProgramError {
+  override readonly name: string = 'NoUnbondingStake';
+
+  readonly code: number = 0x1d; // 29
+
+  constructor(program: Program, cause?: Error) {
+    super('Validator has no unbonding stake', program, cause);
+  }
+}
+codeToErrorMap.set(0x1d, NoUnbondingStakeError);
+nameToErrorMap.set('NoUnbondingStake', NoUnbondingStakeError);
+
+/** StakeStillUnbonding: Stake is still unbonding */
+export class StakeStillUnbondingError extends ProgramError {
+  override readonly name: string = 'StakeStillUnbonding';
+
+  readonly code: number = 0x1e; // 30
+
+  constructor(program: Program, cause?: Error) {
+    super('Stake is still unbonding', program, cause);
+  }
+}
+codeToErrorMap.set(0x1e, StakeStillUnbondingError);
+nameToErrorMap.set('StakeStillUnbonding', StakeStillUnbondingError);
+
+/** InvalidArbiter: Signer is not the arbiter */
+export class InvalidArbiterError extends ProgramError {
+  override readonly name: string = 'InvalidArbiter';
+
+  readonly code: number = 0x1f; // 31
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer is not the arbiter', program, cause);
+  }
+}
+codeToErrorMap.set(0x1f, InvalidArbiterError);
+nameToErrorMap.set('InvalidArbiter', InvalidArbiterError);
+
+/** NumericalOverflow: Numerical overflow */
+export class NumericalOverflowError extends ProgramError {
+  override readonly name: string = 'NumericalOverflow';
+
+  readonly code: number = 0x20; // 32
+
+  constructor(program: Program, cause?: Error) {
+    super('Numerical overflow', program, cause);
+  }
+}
+codeToErrorMap.set(0x20, NumericalOverflowError);
+nameToErrorMap.set('NumericalOverflow', NumericalOverflowError);
+
+/** InvalidAcceptedValidator: Invalid accepted validator account */
+export class InvalidAcceptedValidatorError extends ProgramError {
+  override readonly name: string = 'InvalidAcceptedValidator';
+
+  readonly code: number = 0x21; // 33
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid accepted validator account', program, cause);
+  }
+}
+codeToErrorMap.set(0x21, InvalidAcceptedValidatorError);
+nameToErrorMap.set('InvalidAcceptedValidator', InvalidAcceptedValidatorError);
+
+/** ValidatorAlreadyAccepted: Validator already accepted */
+export class ValidatorAlreadyAcceptedError extends ProgramError {
+  override readonly name: string = 'ValidatorAlreadyAccepted';
+
+  readonly code: number = 0x22; // 34
+
+  constructor(program: Program, cause?: Error) {
+    super('Validator already accepted', program, cause);
+  }
+}
+codeToErrorMap.set(0x22, ValidatorAlreadyAcceptedError);
+nameToErrorMap.set('ValidatorAlreadyAccepted', ValidatorAlreadyAcceptedError);
+
+/** ValidatorNotAccepted: Validator is not accepted by the agent */
+export class ValidatorNotAcceptedError extends ProgramError {
+  override readonly name: string = 'ValidatorNotAccepted';
+
+  readonly code: number = 0x23; // 35
+
+  constructor(program: Program, cause?: Error) {
+    super('Validator is not accepted by the agent', program, cause);
+  }
+}
+codeToErrorMap.set(0x23, ValidatorNotAcceptedError);
+nameToErrorMap.set('ValidatorNotAccepted', ValidatorNotAcceptedError);
+
+/** InvalidAgentAuthority: Signer does not own the agent asset */
+export class InvalidAgentAuthorityError extends ProgramError {
+  override readonly name: string = 'InvalidAgentAuthority';
+
+  readonly code: number = 0x24; // 36
+
+  constructor(program: Program, cause?: Error) {
+    super('Signer does not own the agent asset', program, cause);
+  }
+}
+codeToErrorMap.set(0x24, InvalidAgentAuthorityError);
+nameToErrorMap.set('InvalidAgentAuthority', InvalidAgentAuthorityError);
+
+/** InvalidValidatorHistory: Invalid validator history account */
+export class InvalidValidatorHistoryError extends ProgramError {
+  override readonly name: string = 'InvalidValidatorHistory';
+
+  readonly code: number = 0x25; // 37
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid validator history account', program, cause);
+  }
+}
+codeToErrorMap.set(0x25, InvalidValidatorHistoryError);
+nameToErrorMap.set('InvalidValidatorHistory', InvalidValidatorHistoryError);
+
+/** AssetMismatch: Asset does not match the validation request */
+export class AssetMismatchError extends ProgramError {
+  override readonly name: string = 'AssetMismatch';
+
+  readonly code: number = 0x26; // 38
+
+  constructor(program: Program, cause?: Error) {
+    super('Asset does not match the validation request', program, cause);
+  }
+}
+codeToErrorMap.set(0x26, AssetMismatchError);
+nameToErrorMap.set('AssetMismatch', AssetMismatchError);
+
+/** InvalidValidatorAttester: Invalid validator attester account */
+export class InvalidValidatorAttesterError extends ProgramError {
+  override readonly name: string = 'InvalidValidatorAttester';
+
+  readonly code: number = 0x27; // 39
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid validator attester account', program, cause);
+  }
+}
+codeToErrorMap.set(0x27, InvalidValidatorAttesterError);
+nameToErrorMap.set('InvalidValidatorAttester', InvalidValidatorAttesterError);
+
+/** AttesterAlreadyAdded: Attester already added */
+export class AttesterAlreadyAddedError extends ProgramError {
+  override readonly name: string = 'AttesterAlreadyAdded';
+
+  readonly code: number = 0x28; // 40
+
+  constructor(program: Program, cause?: Error) {
+    super('Attester already added', program, cause);
+  }
+}
+codeToErrorMap.set(0x28, AttesterAlreadyAddedError);
+nameToErrorMap.set('AttesterAlreadyAdded', AttesterAlreadyAddedError);
+
+/** InvalidInstructionsSysvar: Invalid instructions sysvar */
+export class InvalidInstructionsSysvarError extends ProgramError {
+  override readonly name: string = 'InvalidInstructionsSysvar';
+
+  readonly code: number = 0x29; // 41
+
+  constructor(program: Program, cause?: Error) {
+    super('Invalid instructions sysvar', program, cause);
+  }
+}
+codeToErrorMap.set(0x29, InvalidInstructionsSysvarError);
+nameToErrorMap.set('InvalidInstructionsSysvar', InvalidInstructionsSysvarError);
+
+/** MissingAttestation: Attestation must be verified by an Ed25519 program instruction right before */
+export class MissingAttestationError extends ProgramError {
+  override readonly name: string = 'MissingAttestation';
+
+  readonly code: number = 0x2a; // 42
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Attestation must be verified by an Ed25519 program instruction right before',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2a, MissingAttestationError);
+nameToErrorMap.set('MissingAttestation', MissingAttestationError);
+
+/** InvalidAttestation: Attestation does not match the attester or the response */
+export class InvalidAttestationError extends ProgramError {
+  override readonly name: string = 'InvalidAttestation';
+
+  readonly code: number = 0x2b; // 43
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Attestation does not match the attester or the response',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2b, InvalidAttestationError);
+nameToErrorMap.set('InvalidAttestation', InvalidAttestationError);
+
+/** ValidationRequestExpired: Validation request is expired */
+export class ValidationRequestExpiredError extends ProgramError {
+  override readonly name: string = 'ValidationRequestExpired';
+
+  readonly code: number = 0x2c; // 44
+
+  constructor(program: Program, cause?: Error) {
+    super('Validation request is expired', program, cause);
+  }
+}
+codeToErrorMap.set(0x2c, ValidationRequestExpiredError);
+nameToErrorMap.set('ValidationRequestExpired', ValidationRequestExpiredError);
+
+/** ValidationRequestNotExpired: Validation request is not expired yet */
+export class ValidationRequestNotExpiredError extends ProgramError {
+  override readonly name: string = 'ValidationRequestNotExpired';
+
+  readonly code: number = 0x2d; // 45
+
+  constructor(program: Program, cause?: Error) {
+    super('Validation request is not expired yet', program, cause);
+  }
+}
+codeToErrorMap.set(0x2d, ValidationRequestNotExpiredError);
+nameToErrorMap.set(
+  'ValidationRequestNotExpired',
+  ValidationRequestNotExpiredError
+);
+
+/** InvalidExpirySlot: Expiry slot must be in the future and within the maximum expiry */
+export class InvalidExpirySlotError extends ProgramError {
+  override readonly name: string = 'InvalidExpirySlot';
+
+  readonly code: number = 0x2e; // 46
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Expiry slot must be in the future and within the maximum expiry',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2e, InvalidExpirySlotError);
+nameToErrorMap.set('InvalidExpirySlot', InvalidExpirySlotError);
+
+/** OnlyRequesterCanReclaim: Only the requester can reclaim the validation request */
+export class OnlyRequesterCanReclaimError extends ProgramError {
+  override readonly name: string = 'OnlyRequesterCanReclaim';
+
+  readonly code: number = 0x2f; // 47
+
+  constructor(program: Program, cause?: Error) {
+    super(
+      'Only the requester can reclaim the validation request',
+      program,
+      cause
+    );
+  }
+}
+codeToErrorMap.set(0x2f, OnlyRequesterCanReclaimError);
+nameToErrorMap.set('OnlyRequesterCanReclaim', OnlyRequesterCanReclaimError);
+
 /**
  * Attempts to resolve a custom program error from the provided error code.
  * @category Errors
@@ -0,0 +1,180 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentValidationV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddAcceptedValidatorV1InstructionAccounts = {
+  /** The accepted validator PDA at ["accepted_validator", asset, validator] */
+  acceptedValidator: PublicKey | Pda;
+  /** The agent validation PDA of the asset */
+  agentValidation?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The owner of the asset */
+  authority: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddAcceptedValidatorV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  validator: PublicKey;
+};
+
+export type AddAcceptedValidatorV1InstructionDataArgs = {
+  validator: PublicKey;
+};
+
+export function getAddAcceptedValidatorV1InstructionDataSerializer(): Serializer<
+  AddAcceptedValidatorV1InstructionDataArgs,
+  AddAcceptedValidatorV1InstructionData
+> {
+  return mapSerializer<
+    AddAcceptedValidatorV1InstructionDataArgs,
+    any,
+    AddAcceptedValidatorV1InstructionData
+  >(
+    struct<AddAcceptedValidatorV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['validator', publicKeySerializer()],
+      ],
+      { description: 'AddAcceptedValidatorV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 9, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    AddAcceptedValidatorV1InstructionDataArgs,
+    AddAcceptedValidatorV1InstructionData
+  >;
+}
+
+// Args.
+export type AddAcceptedValidatorV1InstructionArgs =
+  AddAcceptedValidatorV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const addAcceptedValidatorV1InstructionDiscriminator = 9;
+
+// Instruction.
+export function addAcceptedValidatorV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: AddAcceptedValidatorV1InstructionAccounts &
+    AddAcceptedValidatorV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    acceptedValidator: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.acceptedValidator ?? null,
+    },
+    agentValidation: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.agentValidation ?? null,
+    },
+    asset: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: AddAcceptedValidatorV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.agentValidation.value) {
+    resolvedAccounts.agentValidation.value = findAgentValidationV1Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddAcceptedValidatorV1InstructionDataSerializer().serialize(
+    resolvedArgs as AddAcceptedValidatorV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,180 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findValidatorAttesterV1Pda,
+  findValidatorProfileV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type AddValidatorAttesterV1InstructionAccounts = {
+  /** The validator attester PDA at ["validator_attester", validator, attester] */
+  validatorAttester?: PublicKey | Pda;
+  /** The validator profile PDA */
+  validatorProfile?: PublicKey | Pda;
+  /** The validator authority */
+  validator: Signer;
+  /** The ed25519 attester key, signing as proof of possession */
+  attester: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type AddValidatorAttesterV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type AddValidatorAttesterV1InstructionDataArgs = {};
+
+export function getAddValidatorAttesterV1InstructionDataSerializer(): Serializer<
+  AddValidatorAttesterV1InstructionDataArgs,
+  AddValidatorAttesterV1InstructionData
+> {
+  return mapSerializer<
+    AddValidatorAttesterV1InstructionDataArgs,
+    any,
+    AddValidatorAttesterV1InstructionData
+  >(
+    struct<AddValidatorAttesterV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'AddValidatorAttesterV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 11, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    AddValidatorAttesterV1InstructionDataArgs,
+    AddValidatorAttesterV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const addValidatorAttesterV1InstructionDiscriminator = 11;
+
+// Instruction.
+export function addValidatorAttesterV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: AddValidatorAttesterV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validatorAttester: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validatorAttester ?? null,
+    },
+    validatorProfile: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    validator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.validator ?? null,
+    },
+    attester: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.attester ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.validatorAttester.value) {
+    resolvedAccounts.validatorAttester.value = findValidatorAttesterV1Pda(
+      context,
+      {
+        validator: expectPublicKey(resolvedAccounts.validator.value),
+        attester: expectPublicKey(resolvedAccounts.attester.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.validatorProfile.value) {
+    resolvedAccounts.validatorProfile.value = findValidatorProfileV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getAddValidatorAttesterV1InstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -6,4 +6,18 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './addAcceptedValidatorV1';
+export * from './addValidatorAttesterV1';
+export * from './initializeValidationConfigV1';
+export * from './reclaimValidationRequestV1';
 export * from './registerValidationV1';
+export * from './registerValidatorV1';
+export * from './removeAcceptedValidatorV1';
+export * from './removeValidatorAttesterV1';
+export * from './requestValidationV1';
+export * from './respondValidationAttestationV1';
+export * from './respondValidationV1';
+export * from './slashValidatorV1';
+export * from './unbondValidatorStakeV1';
+export * from './updateValidationConfigV1';
+export * from './withdrawValidatorStakeV1';
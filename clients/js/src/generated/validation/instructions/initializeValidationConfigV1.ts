@@ -0,0 +1,181 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findValidationConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type InitializeValidationConfigV1InstructionAccounts = {
+  /** The validation config PDA at ["validation_config"] */
+  validationConfig?: PublicKey | Pda;
+  /** The program data account of this program */
+  programData: PublicKey | Pda;
+  /** The upgrade authority of this program */
+  authority: Signer;
+  /** The authority allowed to slash validator stake */
+  arbiter: PublicKey | Pda;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type InitializeValidationConfigV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  unbondingSlots: bigint;
+};
+
+export type InitializeValidationConfigV1InstructionDataArgs = {
+  unbondingSlots: number | bigint;
+};
+
+export function getInitializeValidationConfigV1InstructionDataSerializer(): Serializer<
+  InitializeValidationConfigV1InstructionDataArgs,
+  InitializeValidationConfigV1InstructionData
+> {
+  return mapSerializer<
+    InitializeValidationConfigV1InstructionDataArgs,
+    any,
+    InitializeValidationConfigV1InstructionData
+  >(
+    struct<InitializeValidationConfigV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['unbondingSlots', u64()],
+      ],
+      { description: 'InitializeValidationConfigV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 3, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    InitializeValidationConfigV1InstructionDataArgs,
+    InitializeValidationConfigV1InstructionData
+  >;
+}
+
+// Args.
+export type InitializeValidationConfigV1InstructionArgs =
+  InitializeValidationConfigV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const initializeValidationConfigV1InstructionDiscriminator = 3;
+
+// Instruction.
+export function initializeValidationConfigV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: InitializeValidationConfigV1InstructionAccounts &
+    InitializeValidationConfigV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validationConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validationConfig ?? null,
+    },
+    programData: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.programData ?? null,
+    },
+    authority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    arbiter: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.arbiter ?? null,
+    },
+    payer: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 5,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: InitializeValidationConfigV1InstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.validationConfig.value) {
+    resolvedAccounts.validationConfig.value =
+      findValidationConfigV1Pda(context);
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getInitializeValidationConfigV1InstructionDataSerializer().serialize(
+      resolvedArgs as InitializeValidationConfigV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,126 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type ReclaimValidationRequestV1InstructionAccounts = {
+  /** The validation request PDA */
+  validationRequest: PublicKey | Pda;
+  /** The requester of the validation */
+  requester: Signer;
+  /** The account receiving the reward and the rent */
+  destination: PublicKey | Pda;
+};
+
+// Data.
+export type ReclaimValidationRequestV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type ReclaimValidationRequestV1InstructionDataArgs = {};
+
+export function getReclaimValidationRequestV1InstructionDataSerializer(): Serializer<
+  ReclaimValidationRequestV1InstructionDataArgs,
+  ReclaimValidationRequestV1InstructionData
+> {
+  return mapSerializer<
+    ReclaimValidationRequestV1InstructionDataArgs,
+    any,
+    ReclaimValidationRequestV1InstructionData
+  >(
+    struct<ReclaimValidationRequestV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'ReclaimValidationRequestV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 14, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    ReclaimValidationRequestV1InstructionDataArgs,
+    ReclaimValidationRequestV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const reclaimValidationRequestV1InstructionDiscriminator = 14;
+
+// Instruction.
+export function reclaimValidationRequestV1(
+  context: Pick<Context, 'programs'>,
+  input: ReclaimValidationRequestV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validationRequest: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validationRequest ?? null,
+    },
+    requester: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.requester ?? null,
+    },
+    destination: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getReclaimValidationRequestV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,183 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findValidatorProfileV1Pda,
+  findValidatorStakeVaultV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RegisterValidatorV1InstructionAccounts = {
+  /** The validator profile PDA at ["validator_profile", validator] */
+  validatorProfile?: PublicKey | Pda;
+  /** The validator stake vault PDA at ["validator_stake_vault", validator] */
+  validatorStakeVault?: PublicKey | Pda;
+  /** The validator authority, funding the stake */
+  validator: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RegisterValidatorV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  stake: bigint;
+};
+
+export type RegisterValidatorV1InstructionDataArgs = {
+  stake: number | bigint;
+};
+
+export function getRegisterValidatorV1InstructionDataSerializer(): Serializer<
+  RegisterValidatorV1InstructionDataArgs,
+  RegisterValidatorV1InstructionData
+> {
+  return mapSerializer<
+    RegisterValidatorV1InstructionDataArgs,
+    any,
+    RegisterValidatorV1InstructionData
+  >(
+    struct<RegisterValidatorV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['stake', u64()],
+      ],
+      { description: 'RegisterValidatorV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 5, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RegisterValidatorV1InstructionDataArgs,
+    RegisterValidatorV1InstructionData
+  >;
+}
+
+// Args.
+export type RegisterValidatorV1InstructionArgs =
+  RegisterValidatorV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const registerValidatorV1InstructionDiscriminator = 5;
+
+// Instruction.
+export function registerValidatorV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RegisterValidatorV1InstructionAccounts &
+    RegisterValidatorV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validatorProfile: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    validatorStakeVault: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.validatorStakeVault ?? null,
+    },
+    validator: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.validator ?? null,
+    },
+    payer: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RegisterValidatorV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.validatorProfile.value) {
+    resolvedAccounts.validatorProfile.value = findValidatorProfileV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+  if (!resolvedAccounts.validatorStakeVault.value) {
+    resolvedAccounts.validatorStakeVault.value = findValidatorStakeVaultV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRegisterValidatorV1InstructionDataSerializer().serialize(
+    resolvedArgs as RegisterValidatorV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
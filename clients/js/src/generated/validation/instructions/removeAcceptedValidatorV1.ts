@@ -0,0 +1,149 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentValidationV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemoveAcceptedValidatorV1InstructionAccounts = {
+  /** The accepted validator PDA */
+  acceptedValidator: PublicKey | Pda;
+  /** The agent validation PDA of the asset */
+  agentValidation?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The owner of the asset */
+  authority: Signer;
+  /** The account receiving the rent */
+  destination: PublicKey | Pda;
+};
+
+// Data.
+export type RemoveAcceptedValidatorV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type RemoveAcceptedValidatorV1InstructionDataArgs = {};
+
+export function getRemoveAcceptedValidatorV1InstructionDataSerializer(): Serializer<
+  RemoveAcceptedValidatorV1InstructionDataArgs,
+  RemoveAcceptedValidatorV1InstructionData
+> {
+  return mapSerializer<
+    RemoveAcceptedValidatorV1InstructionDataArgs,
+    any,
+    RemoveAcceptedValidatorV1InstructionData
+  >(
+    struct<RemoveAcceptedValidatorV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'RemoveAcceptedValidatorV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 10, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RemoveAcceptedValidatorV1InstructionDataArgs,
+    RemoveAcceptedValidatorV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const removeAcceptedValidatorV1InstructionDiscriminator = 10;
+
+// Instruction.
+export function removeAcceptedValidatorV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: RemoveAcceptedValidatorV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    acceptedValidator: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.acceptedValidator ?? null,
+    },
+    agentValidation: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.agentValidation ?? null,
+    },
+    asset: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    authority: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    destination: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.agentValidation.value) {
+    resolvedAccounts.agentValidation.value = findAgentValidationV1Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getRemoveAcceptedValidatorV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
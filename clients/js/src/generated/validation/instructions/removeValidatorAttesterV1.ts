@@ -0,0 +1,143 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findValidatorProfileV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RemoveValidatorAttesterV1InstructionAccounts = {
+  /** The validator attester PDA */
+  validatorAttester: PublicKey | Pda;
+  /** The validator profile PDA */
+  validatorProfile?: PublicKey | Pda;
+  /** The validator authority */
+  validator: Signer;
+  /** The account receiving the rent */
+  destination: PublicKey | Pda;
+};
+
+// Data.
+export type RemoveValidatorAttesterV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type RemoveValidatorAttesterV1InstructionDataArgs = {};
+
+export function getRemoveValidatorAttesterV1InstructionDataSerializer(): Serializer<
+  RemoveValidatorAttesterV1InstructionDataArgs,
+  RemoveValidatorAttesterV1InstructionData
+> {
+  return mapSerializer<
+    RemoveValidatorAttesterV1InstructionDataArgs,
+    any,
+    RemoveValidatorAttesterV1InstructionData
+  >(
+    struct<RemoveValidatorAttesterV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'RemoveValidatorAttesterV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 12, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RemoveValidatorAttesterV1InstructionDataArgs,
+    RemoveValidatorAttesterV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const removeValidatorAttesterV1InstructionDiscriminator = 12;
+
+// Instruction.
+export function removeValidatorAttesterV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: RemoveValidatorAttesterV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validatorAttester: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validatorAttester ?? null,
+    },
+    validatorProfile: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    validator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.validator ?? null,
+    },
+    destination: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.validatorProfile.value) {
+    resolvedAccounts.validatorProfile.value = findValidatorProfileV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getRemoveValidatorAttesterV1InstructionDataSerializer().serialize({});
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
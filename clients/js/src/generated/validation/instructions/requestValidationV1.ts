@@ -0,0 +1,214 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  publicKey as publicKeySerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findAgentValidationV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RequestValidationV1InstructionAccounts = {
+  /** The validation request PDA at ["validation_request", asset, nonce_le] */
+  validationRequest: PublicKey | Pda;
+  /** The agent validation PDA of the asset */
+  agentValidation?: PublicKey | Pda;
+  /** The validator profile PDA of the requested validator */
+  validatorProfile: PublicKey | Pda;
+  /** The accepted validator PDA of the requested validator, if the agent whitelists validators */
+  acceptedValidator?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The agent or client requesting the validation, funding the reward */
+  requester: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+};
+
+// Data.
+export type RequestValidationV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  nonce: bigint;
+  workHash: Uint8Array;
+  validator: PublicKey;
+  reward: bigint;
+  expirySlot: bigint;
+};
+
+export type RequestValidationV1InstructionDataArgs = {
+  nonce: number | bigint;
+  workHash: Uint8Array;
+  validator: PublicKey;
+  reward?: number | bigint;
+  expirySlot?: number | bigint;
+};
+
+export function getRequestValidationV1InstructionDataSerializer(): Serializer<
+  RequestValidationV1InstructionDataArgs,
+  RequestValidationV1InstructionData
+> {
+  return mapSerializer<
+    RequestValidationV1InstructionDataArgs,
+    any,
+    RequestValidationV1InstructionData
+  >(
+    struct<RequestValidationV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['nonce', u64()],
+        ['workHash', bytes({ size: 32 })],
+        ['validator', publicKeySerializer()],
+        ['reward', u64()],
+        ['expirySlot', u64()],
+      ],
+      { description: 'RequestValidationV1InstructionData' }
+    ),
+    (value) => ({
+      ...value,
+      discriminator: 1,
+      padding: [0, 0, 0, 0, 0, 0, 0],
+      reward: value.reward ?? 0,
+      expirySlot: value.expirySlot ?? 0,
+    })
+  ) as Serializer<
+    RequestValidationV1InstructionDataArgs,
+    RequestValidationV1InstructionData
+  >;
+}
+
+// Args.
+export type RequestValidationV1InstructionArgs =
+  RequestValidationV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const requestValidationV1InstructionDiscriminator = 1;
+
+// Instruction.
+export function requestValidationV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RequestValidationV1InstructionAccounts &
+    RequestValidationV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validationRequest: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validationRequest ?? null,
+    },
+    agentValidation: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.agentValidation ?? null,
+    },
+    validatorProfile: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    acceptedValidator: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.acceptedValidator ?? null,
+    },
+    asset: {
+      index: 4,
+      isWritable: false as boolean,
+      value: input.asset ?? null,
+    },
+    requester: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.requester ?? null,
+    },
+    payer: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    systemProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RequestValidationV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.agentValidation.value) {
+    resolvedAccounts.agentValidation.value = findAgentValidationV1Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRequestValidationV1InstructionDataSerializer().serialize(
+    resolvedArgs as RequestValidationV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,268 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findAgentValidationV2Pda,
+  findValidatorHistoryV1Pda,
+  findValidatorProfileV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RespondValidationAttestationV1InstructionAccounts = {
+  /** The validation request PDA */
+  validationRequest: PublicKey | Pda;
+  /** The agent validation PDA of the asset */
+  agentValidation?: PublicKey | Pda;
+  /** The validator history PDA at ["validator_history", asset, validator] */
+  validatorHistory?: PublicKey | Pda;
+  /** The validator attester PDA of the key that signed the attestation */
+  validatorAttester: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The validator designated in the request, receiving the reward */
+  validator: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The instructions sysvar */
+  instructionsSysvar?: PublicKey | Pda;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The validator profile PDA of the validator */
+  validatorProfile?: PublicKey | Pda;
+  /** The accepted validator PDA at ["accepted_validator", asset, validator], required if the agent accepts any validator */
+  acceptedValidator?: PublicKey | Pda;
+};
+
+// Data.
+export type RespondValidationAttestationV1InstructionData = {
+  discriminator: number;
+  result: number;
+  padding: Array<number>;
+  evidenceHash: Uint8Array;
+};
+
+export type RespondValidationAttestationV1InstructionDataArgs = {
+  result: number;
+  evidenceHash: Uint8Array;
+};
+
+export function getRespondValidationAttestationV1InstructionDataSerializer(): Serializer<
+  RespondValidationAttestationV1InstructionDataArgs,
+  RespondValidationAttestationV1InstructionData
+> {
+  return mapSerializer<
+    RespondValidationAttestationV1InstructionDataArgs,
+    any,
+    RespondValidationAttestationV1InstructionData
+  >(
+    struct<RespondValidationAttestationV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['result', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['evidenceHash', bytes({ size: 32 })],
+      ],
+      { description: 'RespondValidationAttestationV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 13, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RespondValidationAttestationV1InstructionDataArgs,
+    RespondValidationAttestationV1InstructionData
+  >;
+}
+
+// Args.
+export type RespondValidationAttestationV1InstructionArgs =
+  RespondValidationAttestationV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const respondValidationAttestationV1InstructionDiscriminator = 13;
+
+// Instruction.
+export function respondValidationAttestationV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RespondValidationAttestationV1InstructionAccounts &
+    RespondValidationAttestationV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validationRequest: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validationRequest ?? null,
+    },
+    agentValidation: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.agentValidation ?? null,
+    },
+    validatorHistory: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.validatorHistory ?? null,
+    },
+    validatorAttester: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.validatorAttester ?? null,
+    },
+    asset: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    validator: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.validator ?? null,
+    },
+    payer: {
+      index: 7,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    instructionsSysvar: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.instructionsSysvar ?? null,
+    },
+    mplCoreProgram: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    validatorProfile: {
+      index: 11,
+      isWritable: false as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    acceptedValidator: {
+      index: 12,
+      isWritable: false as boolean,
+      value: input.acceptedValidator ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RespondValidationAttestationV1InstructionArgs = {
+    ...input,
+  };
+
+  // Default values.
+  if (!resolvedAccounts.agentValidation.value) {
+    resolvedAccounts.agentValidation.value = findAgentValidationV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.validatorHistory.value) {
+    resolvedAccounts.validatorHistory.value = findValidatorHistoryV1Pda(
+      context,
+      {
+        asset: expectPublicKey(resolvedAccounts.asset.value),
+        validator: expectPublicKey(resolvedAccounts.validator.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.instructionsSysvar.value) {
+    resolvedAccounts.instructionsSysvar.value = context.programs.getPublicKey(
+      'sysvarInstructions',
+      'Sysvar1nstructions1111111111111111111111111'
+    );
+    resolvedAccounts.instructionsSysvar.isWritable = false;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.validatorProfile.value) {
+    resolvedAccounts.validatorProfile.value = findValidatorProfileV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data =
+    getRespondValidationAttestationV1InstructionDataSerializer().serialize(
+      resolvedArgs as RespondValidationAttestationV1InstructionDataArgs
+    );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
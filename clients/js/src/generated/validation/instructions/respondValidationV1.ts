@@ -0,0 +1,248 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  bytes,
+  mapSerializer,
+  string,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findAgentValidationV2Pda,
+  findValidatorHistoryV1Pda,
+  findValidatorProfileV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type RespondValidationV1InstructionAccounts = {
+  /** The validation request PDA */
+  validationRequest: PublicKey | Pda;
+  /** The agent validation PDA of the asset */
+  agentValidation?: PublicKey | Pda;
+  /** The validator history PDA at ["validator_history", asset, validator] */
+  validatorHistory?: PublicKey | Pda;
+  /** The address of the Core asset */
+  asset: PublicKey | Pda;
+  /** The address of the collection */
+  collection?: PublicKey | Pda;
+  /** The validator designated in the request, receiving the reward */
+  validator: Signer;
+  /** The payer for additional rent */
+  payer?: Signer;
+  /** The MPL Core program */
+  mplCoreProgram?: PublicKey | Pda;
+  /** The system program */
+  systemProgram?: PublicKey | Pda;
+  /** The validator profile PDA of the validator */
+  validatorProfile?: PublicKey | Pda;
+  /** The accepted validator PDA at ["accepted_validator", asset, validator], required if the agent accepts any validator */
+  acceptedValidator?: PublicKey | Pda;
+};
+
+// Data.
+export type RespondValidationV1InstructionData = {
+  discriminator: number;
+  result: number;
+  padding: Array<number>;
+  evidenceHash: Uint8Array;
+  evidenceUri: string;
+};
+
+export type RespondValidationV1InstructionDataArgs = {
+  result: number;
+  evidenceHash: Uint8Array;
+  evidenceUri: string;
+};
+
+export function getRespondValidationV1InstructionDataSerializer(): Serializer<
+  RespondValidationV1InstructionDataArgs,
+  RespondValidationV1InstructionData
+> {
+  return mapSerializer<
+    RespondValidationV1InstructionDataArgs,
+    any,
+    RespondValidationV1InstructionData
+  >(
+    struct<RespondValidationV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['result', u8()],
+        ['padding', array(u8(), { size: 6 })],
+        ['evidenceHash', bytes({ size: 32 })],
+        ['evidenceUri', string()],
+      ],
+      { description: 'RespondValidationV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 2, padding: [0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    RespondValidationV1InstructionDataArgs,
+    RespondValidationV1InstructionData
+  >;
+}
+
+// Args.
+export type RespondValidationV1InstructionArgs =
+  RespondValidationV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const respondValidationV1InstructionDiscriminator = 2;
+
+// Instruction.
+export function respondValidationV1(
+  context: Pick<Context, 'eddsa' | 'payer' | 'programs'>,
+  input: RespondValidationV1InstructionAccounts &
+    RespondValidationV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validationRequest: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validationRequest ?? null,
+    },
+    agentValidation: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.agentValidation ?? null,
+    },
+    validatorHistory: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.validatorHistory ?? null,
+    },
+    asset: {
+      index: 3,
+      isWritable: true as boolean,
+      value: input.asset ?? null,
+    },
+    collection: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.collection ?? null,
+    },
+    validator: {
+      index: 5,
+      isWritable: true as boolean,
+      value: input.validator ?? null,
+    },
+    payer: {
+      index: 6,
+      isWritable: true as boolean,
+      value: input.payer ?? null,
+    },
+    mplCoreProgram: {
+      index: 7,
+      isWritable: false as boolean,
+      value: input.mplCoreProgram ?? null,
+    },
+    systemProgram: {
+      index: 8,
+      isWritable: false as boolean,
+      value: input.systemProgram ?? null,
+    },
+    validatorProfile: {
+      index: 9,
+      isWritable: false as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    acceptedValidator: {
+      index: 10,
+      isWritable: false as boolean,
+      value: input.acceptedValidator ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: RespondValidationV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.agentValidation.value) {
+    resolvedAccounts.agentValidation.value = findAgentValidationV2Pda(context, {
+      asset: expectPublicKey(resolvedAccounts.asset.value),
+    });
+  }
+  if (!resolvedAccounts.validatorHistory.value) {
+    resolvedAccounts.validatorHistory.value = findValidatorHistoryV1Pda(
+      context,
+      {
+        asset: expectPublicKey(resolvedAccounts.asset.value),
+        validator: expectPublicKey(resolvedAccounts.validator.value),
+      }
+    );
+  }
+  if (!resolvedAccounts.payer.value) {
+    resolvedAccounts.payer.value = context.payer;
+  }
+  if (!resolvedAccounts.mplCoreProgram.value) {
+    resolvedAccounts.mplCoreProgram.value = context.programs.getPublicKey(
+      'mplCore',
+      'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d'
+    );
+    resolvedAccounts.mplCoreProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.systemProgram.value) {
+    resolvedAccounts.systemProgram.value = context.programs.getPublicKey(
+      'splSystem',
+      '11111111111111111111111111111111'
+    );
+    resolvedAccounts.systemProgram.isWritable = false;
+  }
+  if (!resolvedAccounts.validatorProfile.value) {
+    resolvedAccounts.validatorProfile.value = findValidatorProfileV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getRespondValidationV1InstructionDataSerializer().serialize(
+    resolvedArgs as RespondValidationV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
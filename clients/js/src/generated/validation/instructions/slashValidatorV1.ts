@@ -0,0 +1,160 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findValidationConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type SlashValidatorV1InstructionAccounts = {
+  /** The validator profile PDA */
+  validatorProfile: PublicKey | Pda;
+  /** The validator stake vault PDA */
+  validatorStakeVault: PublicKey | Pda;
+  /** The validation config PDA at ["validation_config"] */
+  validationConfig?: PublicKey | Pda;
+  /** The arbiter of the validation config */
+  arbiter: Signer;
+  /** The account receiving the slashed stake */
+  destination: PublicKey | Pda;
+};
+
+// Data.
+export type SlashValidatorV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  amount: bigint;
+};
+
+export type SlashValidatorV1InstructionDataArgs = {
+  amount: number | bigint;
+};
+
+export function getSlashValidatorV1InstructionDataSerializer(): Serializer<
+  SlashValidatorV1InstructionDataArgs,
+  SlashValidatorV1InstructionData
+> {
+  return mapSerializer<
+    SlashValidatorV1InstructionDataArgs,
+    any,
+    SlashValidatorV1InstructionData
+  >(
+    struct<SlashValidatorV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['amount', u64()],
+      ],
+      { description: 'SlashValidatorV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 8, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    SlashValidatorV1InstructionDataArgs,
+    SlashValidatorV1InstructionData
+  >;
+}
+
+// Args.
+export type SlashValidatorV1InstructionArgs =
+  SlashValidatorV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const slashValidatorV1InstructionDiscriminator = 8;
+
+// Instruction.
+export function slashValidatorV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: SlashValidatorV1InstructionAccounts & SlashValidatorV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validatorProfile: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    validatorStakeVault: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.validatorStakeVault ?? null,
+    },
+    validationConfig: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.validationConfig ?? null,
+    },
+    arbiter: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.arbiter ?? null,
+    },
+    destination: {
+      index: 4,
+      isWritable: true as boolean,
+      value: input.destination ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: SlashValidatorV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.validationConfig.value) {
+    resolvedAccounts.validationConfig.value =
+      findValidationConfigV1Pda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getSlashValidatorV1InstructionDataSerializer().serialize(
+    resolvedArgs as SlashValidatorV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
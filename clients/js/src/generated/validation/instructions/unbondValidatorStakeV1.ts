@@ -0,0 +1,157 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findValidationConfigV1Pda,
+  findValidatorProfileV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UnbondValidatorStakeV1InstructionAccounts = {
+  /** The validator profile PDA */
+  validatorProfile?: PublicKey | Pda;
+  /** The validation config PDA at ["validation_config"], initialized or not */
+  validationConfig?: PublicKey | Pda;
+  /** The validator authority */
+  validator: Signer;
+};
+
+// Data.
+export type UnbondValidatorStakeV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  amount: bigint;
+};
+
+export type UnbondValidatorStakeV1InstructionDataArgs = {
+  amount: number | bigint;
+};
+
+export function getUnbondValidatorStakeV1InstructionDataSerializer(): Serializer<
+  UnbondValidatorStakeV1InstructionDataArgs,
+  UnbondValidatorStakeV1InstructionData
+> {
+  return mapSerializer<
+    UnbondValidatorStakeV1InstructionDataArgs,
+    any,
+    UnbondValidatorStakeV1InstructionData
+  >(
+    struct<UnbondValidatorStakeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['amount', u64()],
+      ],
+      { description: 'UnbondValidatorStakeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 6, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    UnbondValidatorStakeV1InstructionDataArgs,
+    UnbondValidatorStakeV1InstructionData
+  >;
+}
+
+// Args.
+export type UnbondValidatorStakeV1InstructionArgs =
+  UnbondValidatorStakeV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const unbondValidatorStakeV1InstructionDiscriminator = 6;
+
+// Instruction.
+export function unbondValidatorStakeV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: UnbondValidatorStakeV1InstructionAccounts &
+    UnbondValidatorStakeV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validatorProfile: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    validationConfig: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.validationConfig ?? null,
+    },
+    validator: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.validator ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UnbondValidatorStakeV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.validatorProfile.value) {
+    resolvedAccounts.validatorProfile.value = findValidatorProfileV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+  if (!resolvedAccounts.validationConfig.value) {
+    resolvedAccounts.validationConfig.value =
+      findValidationConfigV1Pda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUnbondValidatorStakeV1InstructionDataSerializer().serialize(
+    resolvedArgs as UnbondValidatorStakeV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
@@ -0,0 +1,154 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import { findValidationConfigV1Pda } from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type UpdateValidationConfigV1InstructionAccounts = {
+  /** The validation config PDA at ["validation_config"] */
+  validationConfig?: PublicKey | Pda;
+  /** The config authority */
+  authority: Signer;
+  /** The new config authority, if it changes */
+  newAuthority?: PublicKey | Pda;
+  /** The new arbiter, if it changes */
+  newArbiter?: PublicKey | Pda;
+};
+
+// Data.
+export type UpdateValidationConfigV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+  unbondingSlots: bigint;
+};
+
+export type UpdateValidationConfigV1InstructionDataArgs = {
+  unbondingSlots: number | bigint;
+};
+
+export function getUpdateValidationConfigV1InstructionDataSerializer(): Serializer<
+  UpdateValidationConfigV1InstructionDataArgs,
+  UpdateValidationConfigV1InstructionData
+> {
+  return mapSerializer<
+    UpdateValidationConfigV1InstructionDataArgs,
+    any,
+    UpdateValidationConfigV1InstructionData
+  >(
+    struct<UpdateValidationConfigV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+        ['unbondingSlots', u64()],
+      ],
+      { description: 'UpdateValidationConfigV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 4, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    UpdateValidationConfigV1InstructionDataArgs,
+    UpdateValidationConfigV1InstructionData
+  >;
+}
+
+// Args.
+export type UpdateValidationConfigV1InstructionArgs =
+  UpdateValidationConfigV1InstructionDataArgs;
+
+// Instruction discriminator.
+export const updateValidationConfigV1InstructionDiscriminator = 4;
+
+// Instruction.
+export function updateValidationConfigV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: UpdateValidationConfigV1InstructionAccounts &
+    UpdateValidationConfigV1InstructionArgs
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validationConfig: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validationConfig ?? null,
+    },
+    authority: {
+      index: 1,
+      isWritable: false as boolean,
+      value: input.authority ?? null,
+    },
+    newAuthority: {
+      index: 2,
+      isWritable: false as boolean,
+      value: input.newAuthority ?? null,
+    },
+    newArbiter: {
+      index: 3,
+      isWritable: false as boolean,
+      value: input.newArbiter ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Arguments.
+  const resolvedArgs: UpdateValidationConfigV1InstructionArgs = { ...input };
+
+  // Default values.
+  if (!resolvedAccounts.validationConfig.value) {
+    resolvedAccounts.validationConfig.value =
+      findValidationConfigV1Pda(context);
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getUpdateValidationConfigV1InstructionDataSerializer().serialize(
+    resolvedArgs as UpdateValidationConfigV1InstructionDataArgs
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
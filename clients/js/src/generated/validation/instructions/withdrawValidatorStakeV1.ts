@@ -0,0 +1,146 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  Context,
+  Pda,
+  PublicKey,
+  Signer,
+  TransactionBuilder,
+  transactionBuilder,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  array,
+  mapSerializer,
+  struct,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+import {
+  findValidatorProfileV1Pda,
+  findValidatorStakeVaultV1Pda,
+} from '../accounts';
+import {
+  ResolvedAccount,
+  ResolvedAccountsWithIndices,
+  expectPublicKey,
+  getAccountMetasAndSigners,
+} from '../shared';
+
+// Accounts.
+export type WithdrawValidatorStakeV1InstructionAccounts = {
+  /** The validator profile PDA */
+  validatorProfile?: PublicKey | Pda;
+  /** The validator stake vault PDA */
+  validatorStakeVault?: PublicKey | Pda;
+  /** The validator authority, receiving the stake */
+  validator: Signer;
+};
+
+// Data.
+export type WithdrawValidatorStakeV1InstructionData = {
+  discriminator: number;
+  padding: Array<number>;
+};
+
+export type WithdrawValidatorStakeV1InstructionDataArgs = {};
+
+export function getWithdrawValidatorStakeV1InstructionDataSerializer(): Serializer<
+  WithdrawValidatorStakeV1InstructionDataArgs,
+  WithdrawValidatorStakeV1InstructionData
+> {
+  return mapSerializer<
+    WithdrawValidatorStakeV1InstructionDataArgs,
+    any,
+    WithdrawValidatorStakeV1InstructionData
+  >(
+    struct<WithdrawValidatorStakeV1InstructionData>(
+      [
+        ['discriminator', u8()],
+        ['padding', array(u8(), { size: 7 })],
+      ],
+      { description: 'WithdrawValidatorStakeV1InstructionData' }
+    ),
+    (value) => ({ ...value, discriminator: 7, padding: [0, 0, 0, 0, 0, 0, 0] })
+  ) as Serializer<
+    WithdrawValidatorStakeV1InstructionDataArgs,
+    WithdrawValidatorStakeV1InstructionData
+  >;
+}
+
+// Instruction discriminator.
+export const withdrawValidatorStakeV1InstructionDiscriminator = 7;
+
+// Instruction.
+export function withdrawValidatorStakeV1(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  input: WithdrawValidatorStakeV1InstructionAccounts
+): TransactionBuilder {
+  // Program ID.
+  const programId = context.programs.getPublicKey(
+    'mplAgentValidation',
+    'VALREGY66A9ieJfFUNs5GrxFTy498KUoSU7TbmSePQi'
+  );
+
+  // Accounts.
+  const resolvedAccounts = {
+    validatorProfile: {
+      index: 0,
+      isWritable: true as boolean,
+      value: input.validatorProfile ?? null,
+    },
+    validatorStakeVault: {
+      index: 1,
+      isWritable: true as boolean,
+      value: input.validatorStakeVault ?? null,
+    },
+    validator: {
+      index: 2,
+      isWritable: true as boolean,
+      value: input.validator ?? null,
+    },
+  } satisfies ResolvedAccountsWithIndices;
+
+  // Default values.
+  if (!resolvedAccounts.validatorProfile.value) {
+    resolvedAccounts.validatorProfile.value = findValidatorProfileV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+  if (!resolvedAccounts.validatorStakeVault.value) {
+    resolvedAccounts.validatorStakeVault.value = findValidatorStakeVaultV1Pda(
+      context,
+      { validator: expectPublicKey(resolvedAccounts.validator.value) }
+    );
+  }
+
+  // Accounts in order.
+  const orderedAccounts: ResolvedAccount[] = Object.values(
+    resolvedAccounts
+  ).sort((a, b) => a.index - b.index);
+
+  // Keys and Signers.
+  const [keys, signers] = getAccountMetasAndSigners(
+    orderedAccounts,
+    'programId',
+    programId
+  );
+
+  // Data.
+  const data = getWithdrawValidatorStakeV1InstructionDataSerializer().serialize(
+    {}
+  );
+
+  // Bytes Created On Chain.
+  const bytesCreatedOnChain = 0;
+
+  return transactionBuilder([
+    { instruction: { keys, programId, data }, signers, bytesCreatedOnChain },
+  ]);
+}
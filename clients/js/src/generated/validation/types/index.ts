@@ -6,4 +6,7 @@
  * @see https://github.com/metaplex-foundation/kinobi
  */
 
+export * from './evidenceType';
 export * from './key';
+export * from './mplAgentValidationEvent';
+export * from './validationStatus';
@@ -11,6 +11,14 @@ import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
 export enum Key {
   Uninitialized,
   AgentValidationV1,
+  ValidationRequestV1,
+  ValidationConfigV1,
+  ValidatorProfileV1,
+  ValidatorStakeVaultV1,
+  AcceptedValidatorV1,
+  AgentValidationV2,
+  ValidatorHistoryV1,
+  ValidatorAttesterV1,
 }
 
 export type KeyArgs = Key;
@@ -0,0 +1,562 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import {
+  GetDataEnumKind,
+  GetDataEnumKindContent,
+  PublicKey,
+} from '@metaplex-foundation/umi';
+import {
+  Serializer,
+  bytes,
+  dataEnum,
+  publicKey as publicKeySerializer,
+  string,
+  struct,
+  u32,
+  u64,
+  u8,
+} from '@metaplex-foundation/umi/serializers';
+
+export type MplAgentValidationEvent =
+  | {
+      __kind: 'ValidationRequestedV1';
+      asset: PublicKey;
+      nonce: bigint;
+      requester: PublicKey;
+      validator: PublicKey;
+      workHash: Uint8Array;
+    }
+  | {
+      __kind: 'ValidationRespondedV1';
+      asset: PublicKey;
+      nonce: bigint;
+      validator: PublicKey;
+      result: number;
+      evidenceUri: string;
+      evidenceHash: Uint8Array;
+    }
+  | {
+      __kind: 'ValidationConfigUpdatedV1';
+      authority: PublicKey;
+      arbiter: PublicKey;
+      unbondingSlots: bigint;
+    }
+  | { __kind: 'ValidatorRegisteredV1'; validator: PublicKey; stake: bigint }
+  | {
+      __kind: 'ValidatorStakeUnbondingV1';
+      validator: PublicKey;
+      amount: bigint;
+      unbondingStake: bigint;
+      unbondingSlot: bigint;
+    }
+  | {
+      __kind: 'ValidatorStakeWithdrawnV1';
+      validator: PublicKey;
+      amount: bigint;
+    }
+  | {
+      __kind: 'ValidatorSlashedV1';
+      validator: PublicKey;
+      arbiter: PublicKey;
+      amount: bigint;
+      stake: bigint;
+      unbondingStake: bigint;
+    }
+  | {
+      __kind: 'ValidatorAcceptedV1';
+      asset: PublicKey;
+      validator: PublicKey;
+      acceptedValidatorCount: number;
+    }
+  | {
+      __kind: 'ValidatorRemovedV1';
+      asset: PublicKey;
+      validator: PublicKey;
+      acceptedValidatorCount: number;
+    }
+  | {
+      __kind: 'ValidatorAttesterAddedV1';
+      validator: PublicKey;
+      attester: PublicKey;
+      attesterCount: bigint;
+    }
+  | {
+      __kind: 'ValidatorAttesterRemovedV1';
+      validator: PublicKey;
+      attester: PublicKey;
+      attesterCount: bigint;
+    }
+  | {
+      __kind: 'ValidationAttestedV1';
+      asset: PublicKey;
+      nonce: bigint;
+      validator: PublicKey;
+      attester: PublicKey;
+      result: number;
+      evidenceHash: Uint8Array;
+    }
+  | {
+      __kind: 'ValidationRewardEscrowedV1';
+      asset: PublicKey;
+      nonce: bigint;
+      requester: PublicKey;
+      reward: bigint;
+      expirySlot: bigint;
+    }
+  | {
+      __kind: 'ValidationRewardClaimedV1';
+      asset: PublicKey;
+      nonce: bigint;
+      validator: PublicKey;
+      reward: bigint;
+    }
+  | {
+      __kind: 'ValidationRequestReclaimedV1';
+      asset: PublicKey;
+      nonce: bigint;
+      requester: PublicKey;
+      reward: bigint;
+    };
+
+export type MplAgentValidationEventArgs =
+  | {
+      __kind: 'ValidationRequestedV1';
+      asset: PublicKey;
+      nonce: number | bigint;
+      requester: PublicKey;
+      validator: PublicKey;
+      workHash: Uint8Array;
+    }
+  | {
+      __kind: 'ValidationRespondedV1';
+      asset: PublicKey;
+      nonce: number | bigint;
+      validator: PublicKey;
+      result: number;
+      evidenceUri: string;
+      evidenceHash: Uint8Array;
+    }
+  | {
+      __kind: 'ValidationConfigUpdatedV1';
+      authority: PublicKey;
+      arbiter: PublicKey;
+      unbondingSlots: number | bigint;
+    }
+  | {
+      __kind: 'ValidatorRegisteredV1';
+      validator: PublicKey;
+      stake: number | bigint;
+    }
+  | {
+      __kind: 'ValidatorStakeUnbondingV1';
+      validator: PublicKey;
+      amount: number | bigint;
+      unbondingStake: number | bigint;
+      unbondingSlot: number | bigint;
+    }
+  | {
+      __kind: 'ValidatorStakeWithdrawnV1';
+      validator: PublicKey;
+      amount: number | bigint;
+    }
+  | {
+      __kind: 'ValidatorSlashedV1';
+      validator: PublicKey;
+      arbiter: PublicKey;
+      amount: number | bigint;
+      stake: number | bigint;
+      unbondingStake: number | bigint;
+    }
+  | {
+      __kind: 'ValidatorAcceptedV1';
+      asset: PublicKey;
+      validator: PublicKey;
+      acceptedValidatorCount: number;
+    }
+  | {
+      __kind: 'ValidatorRemovedV1';
+      asset: PublicKey;
+      validator: PublicKey;
+      acceptedValidatorCount: number;
+    }
+  | {
+      __kind: 'ValidatorAttesterAddedV1';
+      validator: PublicKey;
+      attester: PublicKey;
+      attesterCount: number | bigint;
+    }
+  | {
+      __kind: 'ValidatorAttesterRemovedV1';
+      validator: PublicKey;
+      attester: PublicKey;
+      attesterCount: number | bigint;
+    }
+  | {
+      __kind: 'ValidationAttestedV1';
+      asset: PublicKey;
+      nonce: number | bigint;
+      validator: PublicKey;
+      attester: PublicKey;
+      result: number;
+      evidenceHash: Uint8Array;
+    }
+  | {
+      __kind: 'ValidationRewardEscrowedV1';
+      asset: PublicKey;
+      nonce: number | bigint;
+      requester: PublicKey;
+      reward: number | bigint;
+      expirySlot: number | bigint;
+    }
+  | {
+      __kind: 'ValidationRewardClaimedV1';
+      asset: PublicKey;
+      nonce: number | bigint;
+      validator: PublicKey;
+      reward: number | bigint;
+    }
+  | {
+      __kind: 'ValidationRequestReclaimedV1';
+      asset: PublicKey;
+      nonce: number | bigint;
+      requester: PublicKey;
+      reward: number | bigint;
+    };
+
+export function getMplAgentValidationEventSerializer(): Serializer<
+  MplAgentValidationEventArgs,
+  MplAgentValidationEvent
+> {
+  return dataEnum<MplAgentValidationEvent>(
+    [
+      [
+        'ValidationRequestedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidationRequestedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['nonce', u64()],
+          ['requester', publicKeySerializer()],
+          ['validator', publicKeySerializer()],
+          ['workHash', bytes({ size: 32 })],
+        ]),
+      ],
+      [
+        'ValidationRespondedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidationRespondedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['nonce', u64()],
+          ['validator', publicKeySerializer()],
+          ['result', u8()],
+          ['evidenceUri', string()],
+          ['evidenceHash', bytes({ size: 32 })],
+        ]),
+      ],
+      [
+        'ValidationConfigUpdatedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidationConfigUpdatedV1'
+          >
+        >([
+          ['authority', publicKeySerializer()],
+          ['arbiter', publicKeySerializer()],
+          ['unbondingSlots', u64()],
+        ]),
+      ],
+      [
+        'ValidatorRegisteredV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidatorRegisteredV1'
+          >
+        >([
+          ['validator', publicKeySerializer()],
+          ['stake', u64()],
+        ]),
+      ],
+      [
+        'ValidatorStakeUnbondingV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidatorStakeUnbondingV1'
+          >
+        >([
+          ['validator', publicKeySerializer()],
+          ['amount', u64()],
+          ['unbondingStake', u64()],
+          ['unbondingSlot', u64()],
+        ]),
+      ],
+      [
+        'ValidatorStakeWithdrawnV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidatorStakeWithdrawnV1'
+          >
+        >([
+          ['validator', publicKeySerializer()],
+          ['amount', u64()],
+        ]),
+      ],
+      [
+        'ValidatorSlashedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentValidationEvent, 'ValidatorSlashedV1'>
+        >([
+          ['validator', publicKeySerializer()],
+          ['arbiter', publicKeySerializer()],
+          ['amount', u64()],
+          ['stake', u64()],
+          ['unbondingStake', u64()],
+        ]),
+      ],
+      [
+        'ValidatorAcceptedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentValidationEvent, 'ValidatorAcceptedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['validator', publicKeySerializer()],
+          ['acceptedValidatorCount', u32()],
+        ]),
+      ],
+      [
+        'ValidatorRemovedV1',
+        struct<
+          GetDataEnumKindContent<MplAgentValidationEvent, 'ValidatorRemovedV1'>
+        >([
+          ['asset', publicKeySerializer()],
+          ['validator', publicKeySerializer()],
+          ['acceptedValidatorCount', u32()],
+        ]),
+      ],
+      [
+        'ValidatorAttesterAddedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidatorAttesterAddedV1'
+          >
+        >([
+          ['validator', publicKeySerializer()],
+          ['attester', publicKeySerializer()],
+          ['attesterCount', u64()],
+        ]),
+      ],
+      [
+        'ValidatorAttesterRemovedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidatorAttesterRemovedV1'
+          >
+        >([
+          ['validator', publicKeySerializer()],
+          ['attester', publicKeySerializer()],
+          ['attesterCount', u64()],
+        ]),
+      ],
+      [
+        'ValidationAttestedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidationAttestedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['nonce', u64()],
+          ['validator', publicKeySerializer()],
+          ['attester', publicKeySerializer()],
+          ['result', u8()],
+          ['evidenceHash', bytes({ size: 32 })],
+        ]),
+      ],
+      [
+        'ValidationRewardEscrowedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidationRewardEscrowedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['nonce', u64()],
+          ['requester', publicKeySerializer()],
+          ['reward', u64()],
+          ['expirySlot', u64()],
+        ]),
+      ],
+      [
+        'ValidationRewardClaimedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidationRewardClaimedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['nonce', u64()],
+          ['validator', publicKeySerializer()],
+          ['reward', u64()],
+        ]),
+      ],
+      [
+        'ValidationRequestReclaimedV1',
+        struct<
+          GetDataEnumKindContent<
+            MplAgentValidationEvent,
+            'ValidationRequestReclaimedV1'
+          >
+        >([
+          ['asset', publicKeySerializer()],
+          ['nonce', u64()],
+          ['requester', publicKeySerializer()],
+          ['reward', u64()],
+        ]),
+      ],
+    ],
+    { description: 'MplAgentValidationEvent' }
+  ) as Serializer<MplAgentValidationEventArgs, MplAgentValidationEvent>;
+}
+
+// Data Enum Helpers.
+export function mplAgentValidationEvent(
+  kind: 'ValidationRequestedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidationRequestedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidationRequestedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidationRespondedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidationRespondedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidationRespondedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidationConfigUpdatedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidationConfigUpdatedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidationConfigUpdatedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorRegisteredV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorRegisteredV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorRegisteredV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorStakeUnbondingV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorStakeUnbondingV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorStakeUnbondingV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorStakeWithdrawnV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorStakeWithdrawnV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorStakeWithdrawnV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorSlashedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorSlashedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorSlashedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorAcceptedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorAcceptedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorAcceptedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorRemovedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorRemovedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorRemovedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorAttesterAddedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorAttesterAddedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorAttesterAddedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidatorAttesterRemovedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidatorAttesterRemovedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidatorAttesterRemovedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidationAttestedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidationAttestedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidationAttestedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidationRewardEscrowedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidationRewardEscrowedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidationRewardEscrowedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidationRewardClaimedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidationRewardClaimedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidationRewardClaimedV1'>;
+export function mplAgentValidationEvent(
+  kind: 'ValidationRequestReclaimedV1',
+  data: GetDataEnumKindContent<
+    MplAgentValidationEventArgs,
+    'ValidationRequestReclaimedV1'
+  >
+): GetDataEnumKind<MplAgentValidationEventArgs, 'ValidationRequestReclaimedV1'>;
+export function mplAgentValidationEvent<
+  K extends MplAgentValidationEventArgs['__kind'],
+>(kind: K, data?: any): Extract<MplAgentValidationEventArgs, { __kind: K }> {
+  return Array.isArray(data)
+    ? { __kind: kind, fields: data }
+    : { __kind: kind, ...(data ?? {}) };
+}
+export function isMplAgentValidationEvent<
+  K extends MplAgentValidationEvent['__kind'],
+>(
+  kind: K,
+  value: MplAgentValidationEvent
+): value is MplAgentValidationEvent & { __kind: K } {
+  return value.__kind === kind;
+}
@@ -0,0 +1,25 @@
+/**
+ * This code was AUTOGENERATED using the kinobi library.
+ * Please DO NOT EDIT THIS FILE, instead use visitors
+ * to add features, then rerun kinobi to update it.
+ *
+ * @see https://github.com/metaplex-foundation/kinobi
+ */
+
+import { Serializer, scalarEnum } from '@metaplex-foundation/umi/serializers';
+
+export enum ValidationStatus {
+  Pending,
+  Responded,
+}
+
+export type ValidationStatusArgs = ValidationStatus;
+
+export function getValidationStatusSerializer(): Serializer<
+  ValidationStatusArgs,
+  ValidationStatus
+> {
+  return scalarEnum<ValidationStatus>(ValidationStatus, {
+    description: 'ValidationStatus',
+  }) as Serializer<ValidationStatusArgs, ValidationStatus>;
+}
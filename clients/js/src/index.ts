@@ -1,4 +1,7 @@
 export * from './api';
+export * from './attestation';
+export * from './batch';
+export * from './effectiveIdentity';
 export * from './plugin';
 
 // Full namespace exports (includes everything: types, errors, shared helpers)
@@ -0,0 +1,154 @@
+import { findTreeConfigPda } from '@metaplex-foundation/mpl-bubblegum';
+import {
+  generateSigner,
+  PublicKey,
+  Signer,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  findFeedbackV1Pda,
+  findReceiptNullifierV1Pda,
+  registerReputationV1,
+  submitFeedbackV1,
+} from '../src/generated/reputation';
+import { mintWorkReceiptV1 } from '../src/generated/tools';
+import {
+  AgentSetup,
+  bootstrapReceipts,
+  DEFAULT_ASSET_DATA_HASH,
+  getReceiptProof,
+  hashReceiptLeaf,
+  MPL_CORE_CPI_SIGNER,
+  receiptDataHash,
+  ReceiptsBootstrap,
+  setupAgentWithExecutive,
+} from './_receiptsReviews';
+
+export const tag = (value: string) => {
+  const bytes = new Uint8Array(32);
+  bytes.set(new TextEncoder().encode(value));
+  return bytes;
+};
+
+export const FEEDBACK_URI = 'https://example.com/feedback.json';
+
+const RECEIPT_URI = 'https://example.com/receipt.json';
+
+export type FeedbackAgent = {
+  ctx: ReceiptsBootstrap;
+  setup: AgentSetup;
+  leaves: Uint8Array[];
+};
+
+export type WorkReceipt = { nonce: number; leaf: Uint8Array; client: PublicKey };
+
+/** Set up an agent with a registered reputation that issues work receipts. */
+export async function setupAgent(umi: Umi): Promise<FeedbackAgent> {
+  const ctx = await bootstrapReceipts(umi);
+  const setup = await setupAgentWithExecutive(umi);
+  await registerReputationV1(umi, {
+    asset: setup.agent,
+    collection: setup.collection,
+  }).sendAndConfirm(umi);
+
+  return { ctx, setup, leaves: [] as Uint8Array[] };
+}
+
+/** Mint a work receipt from the agent to `client`. */
+export async function issueReceipt(
+  umi: Umi,
+  agent: FeedbackAgent,
+  client: PublicKey
+): Promise<WorkReceipt> {
+  await mintWorkReceiptV1(umi, {
+    executiveAuthority: agent.setup.executive,
+    executionDelegateRecord: agent.setup.executionDelegateRecord,
+    agentAsset: agent.setup.agent,
+    client,
+    treeConfig: findTreeConfigPda(umi, { merkleTree: agent.ctx.receiptsTree }),
+    merkleTree: agent.ctx.receiptsTree,
+    coreCollection: agent.ctx.receiptsCollection,
+    mplCoreCpiSigner: MPL_CORE_CPI_SIGNER,
+    receiptUri: RECEIPT_URI,
+    treeIndex: agent.ctx.receiptsTreeIndex,
+  }).sendAndConfirm(umi);
+
+  const nonce = agent.leaves.length;
+  const leaf = hashReceiptLeaf(umi, {
+    merkleTree: agent.ctx.receiptsTree,
+    leafIndex: nonce,
+    owner: client,
+    agent: agent.setup.agent,
+    client,
+    receiptsCollection: agent.ctx.receiptsCollection,
+    receiptUri: RECEIPT_URI,
+  });
+  agent.leaves.push(leaf);
+
+  return { nonce, leaf, client };
+}
+
+/**
+ * Submit feedback of `reviewer` backed by `receipt`, recorded at the
+ * reviewer's `feedbackIndex`.
+ */
+export function submitFeedback(
+  umi: Umi,
+  agent: FeedbackAgent,
+  receipt: WorkReceipt,
+  reviewer: Signer,
+  score: number,
+  feedbackIndex = 0
+) {
+  const { root, proof } = getReceiptProof(agent.leaves, receipt.leaf);
+
+  return submitFeedbackV1(umi, {
+    asset: agent.setup.agent,
+    collection: agent.setup.collection,
+    reviewer,
+    receiptNullifier: findReceiptNullifierV1Pda(umi, {
+      treeIndex: agent.ctx.receiptsTreeIndex,
+      nonce: receipt.nonce,
+    }),
+    feedback: findFeedbackV1Pda(umi, {
+      asset: agent.setup.agent,
+      reviewer: reviewer.publicKey,
+      index: feedbackIndex,
+    }),
+    merkleTree: agent.ctx.receiptsTree,
+    score,
+    flags: 0,
+    index: receipt.nonce,
+    treeIndex: agent.ctx.receiptsTreeIndex,
+    nonce: receipt.nonce,
+    feedbackIndex,
+    root,
+    dataHash: receiptDataHash({
+      receiptUri: RECEIPT_URI,
+      agent: agent.setup.agent,
+      client: receipt.client,
+      receiptsCollection: agent.ctx.receiptsCollection,
+    }),
+    assetDataHash: DEFAULT_ASSET_DATA_HASH,
+    tag1: tag('quality'),
+    tag2: tag('latency'),
+    feedbackUri: FEEDBACK_URI,
+  }).addRemainingAccounts(
+    proof.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+  );
+}
+
+/** An agent with one piece of feedback at the reviewer's index 0. */
+export async function setupFeedback(umi: Umi) {
+  const agent = await setupAgent(umi);
+  const reviewer = generateSigner(umi);
+  const receipt = await issueReceipt(umi, agent, reviewer.publicKey);
+  await submitFeedback(umi, agent, receipt, reviewer, 60).sendAndConfirm(umi);
+
+  const feedback = findFeedbackV1Pda(umi, {
+    asset: agent.setup.agent,
+    reviewer: reviewer.publicKey,
+    index: 0,
+  });
+  return { agent, reviewer, feedback };
+}
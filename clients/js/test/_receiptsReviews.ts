@@ -5,6 +5,8 @@ import {
 } from '@metaplex-foundation/mpl-core';
 import {
   findTreeConfigPda,
+  getMerkleProof,
+  getMerkleRoot,
   hashLeafV2,
   TokenStandard,
   // eslint-disable-next-line @typescript-eslint/no-unused-vars
@@ -14,6 +16,7 @@ import {
   generateSigner,
   publicKey,
   PublicKey,
+  publicKeyBytes,
   Signer,
   some,
   Umi,
@@ -21,13 +24,17 @@ import {
 
 import {
   createReceiptsCollectionV1,
+  createReviewsCollectionV1,
   delegateExecutionV1,
   findExecutionDelegateRecordV1Pda,
   findExecutiveProfileV1Pda,
   findReceiptsCollectionPda,
   findReceiptsTreePda,
+  findReviewsCollectionPda,
+  findReviewsTreePda,
   registerExecutiveV1,
   registerReceiptsTreeV1,
+  registerReviewsTreeV1,
 } from '../src/generated/tools';
 import {
   findAgentIdentityV1Pda,
@@ -114,6 +121,47 @@ export async function bootstrapReceipts(umi: Umi): Promise<ReceiptsBootstrap> {
   };
 }
 
+/** Custom program error code for MplAgentToolsError::ReviewsCollectionAlreadyInitialized. */
+const REVIEWS_COLLECTION_ALREADY_INITIALIZED_HEX = '0x1b';
+
+export interface ReviewsBootstrap {
+  reviewsCollection: PublicKey;
+  reviewsTree: PublicKey;
+  reviewsTreeIndex: bigint;
+}
+
+/**
+ * Same as `bootstrapReceipts`, for the reviews collection and a fresh
+ * reviews tree.
+ */
+export async function bootstrapReviews(umi: Umi): Promise<ReviewsBootstrap> {
+  const reviewsCollection = publicKey(findReviewsCollectionPda(umi));
+
+  await maybe(
+    createReviewsCollectionV1(umi, {}).sendAndConfirm(umi),
+    REVIEWS_COLLECTION_ALREADY_INITIALIZED_HEX
+  );
+
+  const reviewsTreeIndex = randomU64();
+  const reviewsTree = publicKey(
+    findReviewsTreePda(umi, { treeIndex: reviewsTreeIndex })
+  );
+  await registerReviewsTreeV1(umi, {
+    merkleTree: reviewsTree,
+    treeConfig: findTreeConfigPda(umi, { merkleTree: reviewsTree }),
+    treeIndex: reviewsTreeIndex,
+    maxDepth: TREE_MAX_DEPTH,
+    maxBufferSize: TREE_MAX_BUFFER,
+    canopyDepth: 0,
+  }).sendAndConfirm(umi);
+
+  return {
+    reviewsCollection,
+    reviewsTree,
+    reviewsTreeIndex,
+  };
+}
+
 function randomU64(): bigint {
   // 56 random bits — comfortably within u64, no chance of collision in a
   // test run.
@@ -126,6 +174,7 @@ function randomU64(): bigint {
 
 export interface AgentSetup {
   agent: PublicKey;
+  collection: PublicKey;
   executive: Signer;
   executiveProfile: PublicKey;
   executionDelegateRecord: PublicKey;
@@ -176,6 +225,7 @@ export async function setupAgentWithExecutive(umi: Umi): Promise<AgentSetup> {
 
   return {
     agent: asset.publicKey,
+    collection: agentCollection.publicKey,
     executive,
     executiveProfile,
     executionDelegateRecord,
@@ -216,6 +266,41 @@ export function hashReceiptLeaf(
   });
 }
 
+/** Compute the leaf hash of a review minted by `MintReviewV1`. */
+export function hashReviewLeaf(
+  umi: Umi,
+  input: {
+    merkleTree: PublicKey;
+    leafIndex: number | bigint;
+    agent: PublicKey;
+    reviewer: PublicKey;
+    receipt: PublicKey;
+    reviewsCollection: PublicKey;
+    reviewUri: string;
+  }
+): Uint8Array {
+  return hashLeafV2(umi, {
+    merkleTree: input.merkleTree,
+    owner: input.reviewer,
+    leafIndex: input.leafIndex,
+    metadata: {
+      name: 'Agent Review',
+      symbol: 'AGENTRVW',
+      uri: input.reviewUri,
+      sellerFeeBasisPoints: 0,
+      primarySaleHappened: false,
+      isMutable: false,
+      tokenStandard: some(TokenStandard.NonFungible),
+      creators: [
+        { address: input.agent, verified: false, share: 100 },
+        { address: input.reviewer, verified: false, share: 0 },
+        { address: input.receipt, verified: false, share: 0 },
+      ],
+      collection: some(input.reviewsCollection),
+    },
+  });
+}
+
 /** Compute the data_hash a receipt minted by `MintWorkReceiptV1` carries. */
 export function receiptDataHash(input: {
   receiptUri: string;
@@ -267,3 +352,18 @@ export async function getCurrentTreeRoot(
   // entry's root immediately follows.
   return account.data.slice(88, 88 + 32);
 }
+
+/**
+ * Root and merkle proof of `leaf` in a receipts tree holding `leaves`, in
+ * mint order. The proof is passed as remaining accounts.
+ */
+export function getReceiptProof(
+  leaves: Uint8Array[],
+  leaf: Uint8Array
+): { root: Uint8Array; proof: PublicKey[] } {
+  const leafKeys = leaves.map((l) => publicKey(l));
+  return {
+    root: publicKeyBytes(getMerkleRoot(leafKeys, TREE_MAX_DEPTH)),
+    proof: getMerkleProof(leafKeys, TREE_MAX_DEPTH, publicKey(leaf)),
+  };
+}
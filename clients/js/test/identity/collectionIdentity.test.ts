@@ -0,0 +1,125 @@
+import test from 'ava';
+import { fetchCollection } from '@metaplex-foundation/mpl-core';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  fetchAgentIdentityV2FromSeeds,
+  fetchCollectionIdentityV1,
+  findCollectionIdentityV1Pda,
+  inheritCollectionIdentityV1,
+  Key,
+  registerCollectionIdentityV1,
+  registerIdentityV1,
+} from '../../src/generated/identity';
+import { resolveEffectiveIdentity } from '../../src';
+import { createCollectionAndAsset, createUmi } from '../_setup';
+
+test('it can register a collection identity', async (t) => {
+  // Given a Core collection.
+  const umi = await createUmi();
+  const { collection } = await createCollectionAndAsset(umi);
+
+  // When the collection update authority registers an identity for it.
+  await registerCollectionIdentityV1(umi, {
+    collection,
+    agentRegistrationUri: 'https://example.com/operator.json',
+  }).sendAndConfirm(umi);
+
+  // Then the collection identity PDA is created.
+  const collectionIdentity = await fetchCollectionIdentityV1(
+    umi,
+    findCollectionIdentityV1Pda(umi, { collection })
+  );
+  t.like(collectionIdentity, {
+    key: Key.CollectionIdentityV1,
+    collection,
+  });
+
+  // And the AgentIdentity plugin is on the collection.
+  const collectionData = await fetchCollection(umi, collection);
+  t.is(
+    collectionData.agentIdentities?.[0].uri,
+    'https://example.com/operator.json'
+  );
+});
+
+test('an asset can inherit its collection identity', async (t) => {
+  // Given a collection with a registered identity.
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerCollectionIdentityV1(umi, {
+    collection,
+    agentRegistrationUri: 'https://example.com/operator.json',
+  }).sendAndConfirm(umi);
+
+  // When the asset owner opts into it.
+  await inheritCollectionIdentityV1(umi, {
+    asset,
+    collection,
+  }).sendAndConfirm(umi);
+
+  // Then the asset's agent identity inherits from the collection.
+  const agentIdentity = await fetchAgentIdentityV2FromSeeds(umi, { asset });
+  t.true(agentIdentity.inheritsCollectionIdentity);
+
+  t.deepEqual(await resolveEffectiveIdentity(umi, asset), {
+    source: 'collection',
+    collection,
+    registrationUri: 'https://example.com/operator.json',
+  });
+});
+
+test('an asset identity takes precedence over its collection', async (t) => {
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerCollectionIdentityV1(umi, {
+    collection,
+    agentRegistrationUri: 'https://example.com/operator.json',
+  }).sendAndConfirm(umi);
+  await registerIdentityV1(umi, {
+    asset,
+    collection,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  t.deepEqual(await resolveEffectiveIdentity(umi, asset), {
+    source: 'asset',
+    registrationUri: 'https://example.com/agent.json',
+  });
+});
+
+test('it cannot inherit an unregistered collection identity', async (t) => {
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  const result = inheritCollectionIdentityV1(umi, {
+    asset,
+    collection,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'CollectionIdentityNotRegistered' });
+});
+
+test('it cannot inherit a collection identity if not the owner', async (t) => {
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerCollectionIdentityV1(umi, {
+    collection,
+    agentRegistrationUri: 'https://example.com/operator.json',
+  }).sendAndConfirm(umi);
+
+  // A random signer that neither owns the asset nor is its asset signer.
+  const attacker = generateSigner(umi);
+
+  const result = inheritCollectionIdentityV1(umi, {
+    asset,
+    collection,
+    authority: attacker,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, {
+    name: 'OnlyAssetOwnerOrSignerCanInheritIdentity',
+  });
+});
@@ -0,0 +1,41 @@
+import test from 'ava';
+import {
+  deregisterIdentityV1,
+  findAgentIdentityV2Pda,
+  registerIdentityV1,
+} from '../../src/generated/identity';
+import {
+  findExecutionDelegateCountV1Pda,
+  findExecutionDelegateMigrationV1Pda,
+} from '../../src/generated/tools';
+import { createCollectionAndAsset, createUmi } from '../_setup';
+
+// The local validator loads mpl-agent-tools without an upgrade authority, so
+// the execution delegate migration is never completed here.
+test('it cannot deregister an agent identity until every execution delegate is counted', async (t) => {
+  // Given a registered asset.
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerIdentityV1(umi, {
+    asset,
+    collection,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  // When the owner deregisters the identity.
+  const result = deregisterIdentityV1(umi, {
+    asset,
+    collection,
+    executionDelegateCount: findExecutionDelegateCountV1Pda(umi, {
+      agentAsset: asset,
+    }),
+    executionDelegateMigration: findExecutionDelegateMigrationV1Pda(umi),
+    destination: umi.identity.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then it fails and the agent identity PDA is kept.
+  await t.throwsAsync(result, { name: 'ExecutionDelegateMigrationIncomplete' });
+  const agentIdentityPda = findAgentIdentityV2Pda(umi, { asset });
+  t.true(await umi.rpc.accountExists(agentIdentityPda[0]));
+});
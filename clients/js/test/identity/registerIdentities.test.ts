@@ -0,0 +1,137 @@
+import test from 'ava';
+import {
+  create,
+  fetchAsset,
+  fetchCollection,
+} from '@metaplex-foundation/mpl-core';
+import { generateSigner, PublicKey, Umi } from '@metaplex-foundation/umi';
+import {
+  registerIdentityV1,
+  safeFetchAgentIdentityV2FromSeeds,
+} from '../../src/generated/identity';
+import { findFailedRegistrationIndex, registerIdentities } from '../../src';
+import { createCollectionAndAsset, createUmi } from '../_setup';
+
+async function createFleet(
+  umi: Umi,
+  count: number
+): Promise<{ collection: PublicKey; assets: PublicKey[] }> {
+  const { collection, asset } = await createCollectionAndAsset(umi);
+  const assets = [asset];
+
+  for (let i = 1; i < count; i += 1) {
+    const signer = generateSigner(umi);
+    // eslint-disable-next-line no-await-in-loop
+    await create(umi, {
+      asset: signer,
+      name: 'Test Asset',
+      uri: 'https://example.com/asset.json',
+      // eslint-disable-next-line no-await-in-loop
+      collection: await fetchCollection(umi, collection),
+    }).sendAndConfirm(umi);
+    assets.push(signer.publicKey);
+  }
+
+  return { collection, assets };
+}
+
+test('it can register the identities of a fleet with a shared URI', async (t) => {
+  // Given a collection of three assets.
+  const umi = await createUmi();
+  const { collection, assets } = await createFleet(umi, 3);
+
+  // When they are registered in a single instruction.
+  await registerIdentities(umi, {
+    collection,
+    assets,
+    agentRegistrationUris: ['https://example.com/fleet.json'],
+  }).sendAndConfirm(umi);
+
+  // Then every asset has an identity with the shared URI.
+  await Promise.all(
+    assets.map(async (asset) => {
+      const agentIdentity = await safeFetchAgentIdentityV2FromSeeds(umi, {
+        asset,
+      });
+      t.is(agentIdentity?.asset, asset);
+
+      const assetData = await fetchAsset(umi, asset);
+      t.is(
+        assetData.agentIdentities?.[0].uri,
+        'https://example.com/fleet.json'
+      );
+    })
+  );
+});
+
+test('it can register the identities of a fleet with per-asset URIs', async (t) => {
+  const umi = await createUmi();
+  const { collection, assets } = await createFleet(umi, 2);
+  const uris = ['https://example.com/agent-0.json', 'ipfs://agent-1'];
+
+  await registerIdentities(umi, {
+    collection,
+    assets,
+    agentRegistrationUris: uris,
+  }).sendAndConfirm(umi);
+
+  const assetData = await Promise.all(
+    assets.map((asset) => fetchAsset(umi, asset))
+  );
+  t.deepEqual(
+    assetData.map((data) => data.agentIdentities?.[0].uri),
+    uris
+  );
+});
+
+test('it cannot register a fleet with a mismatched URI table', async (t) => {
+  const umi = await createUmi();
+  const { collection, assets } = await createFleet(umi, 3);
+
+  const result = registerIdentities(umi, {
+    collection,
+    assets,
+    agentRegistrationUris: [
+      'https://example.com/agent-0.json',
+      'https://example.com/agent-1.json',
+    ],
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidRegistrationUriTable' });
+});
+
+test('a failed entry reverts the whole batch', async (t) => {
+  // Given a fleet whose second asset is already registered.
+  const umi = await createUmi();
+  const { collection, assets } = await createFleet(umi, 3);
+
+  await registerIdentityV1(umi, {
+    asset: assets[1],
+    collection,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  // When the fleet is registered.
+  const result = registerIdentities(umi, {
+    collection,
+    assets,
+    agentRegistrationUris: ['https://example.com/fleet.json'],
+  }).sendAndConfirm(umi);
+
+  // Then the batch fails and the first asset is left unregistered.
+  await t.throwsAsync(result, { name: 'AgentIdentityAlreadyRegistered' });
+  t.is(
+    await safeFetchAgentIdentityV2FromSeeds(umi, { asset: assets[0] }),
+    null
+  );
+});
+
+test('it finds the failed entry in the program logs', (t) => {
+  const logs = [
+    'Program log: Instruction: RegisterIdentitiesV1',
+    'Program log: Failed to register identity at index 4: 11111111111111111111111111111111',
+  ];
+
+  t.is(findFailedRegistrationIndex(logs), 4);
+  t.is(findFailedRegistrationIndex(logs.slice(0, 1)), null);
+});
@@ -0,0 +1,95 @@
+import test from 'ava';
+import { CheckResult, fetchAsset } from '@metaplex-foundation/mpl-core';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  registerIdentityV1,
+  updateIdentityLifecycleChecksV1,
+} from '../../src/generated/identity';
+import { createCollectionAndAsset, createUmi } from '../_setup';
+
+// Listen-only on transfer: bit 0 (listen) of the transfer slot at bit 3.
+const TRANSFER_LISTEN = 1 << 3;
+
+test('it can register an identity with custom lifecycle checks', async (t) => {
+  // Given a Core asset.
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  // When it is registered with a listen-only transfer policy.
+  await registerIdentityV1(umi, {
+    asset,
+    collection,
+    lifecycleChecks: TRANSFER_LISTEN,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  // Then the AgentIdentity plugin only listens to transfers.
+  const assetData = await fetchAsset(umi, asset);
+  t.deepEqual(assetData.agentIdentities?.[0].lifecycleChecks, {
+    transfer: [CheckResult.CAN_LISTEN],
+  });
+});
+
+test('it can update the lifecycle checks', async (t) => {
+  // Given an asset registered with the default policy.
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerIdentityV1(umi, {
+    asset,
+    collection,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  // When the owner narrows the policy.
+  await updateIdentityLifecycleChecksV1(umi, {
+    asset,
+    collection,
+    lifecycleChecks: TRANSFER_LISTEN,
+  }).sendAndConfirm(umi);
+
+  // Then the AgentIdentity plugin only listens to transfers.
+  const assetData = await fetchAsset(umi, asset);
+  t.deepEqual(assetData.agentIdentities?.[0].lifecycleChecks, {
+    transfer: [CheckResult.CAN_LISTEN],
+  });
+});
+
+test('it cannot register an identity with create lifecycle checks', async (t) => {
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  const result = registerIdentityV1(umi, {
+    asset,
+    collection,
+    lifecycleChecks: 1,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidLifecycleChecks' });
+});
+
+test('it cannot update the lifecycle checks if not the owner', async (t) => {
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerIdentityV1(umi, {
+    asset,
+    collection,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  // A random signer that neither owns the asset nor is its asset signer.
+  const attacker = generateSigner(umi);
+
+  const result = updateIdentityLifecycleChecksV1(umi, {
+    asset,
+    collection,
+    authority: attacker,
+    lifecycleChecks: TRANSFER_LISTEN,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, {
+    name: 'OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks',
+  });
+});
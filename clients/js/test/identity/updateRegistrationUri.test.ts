@@ -0,0 +1,58 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  registerIdentityV1,
+  updateAgentRegistrationUriV1,
+} from '../../src/generated/identity';
+import { createCollectionAndAsset, createUmi } from '../_setup';
+
+test('it can update the agent registration uri', async (t) => {
+  // Given a registered asset.
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerIdentityV1(umi, {
+    asset,
+    collection,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  // When the owner updates the registration URI.
+  await updateAgentRegistrationUriV1(umi, {
+    asset,
+    collection,
+    agentRegistrationUri: 'https://example.com/agent-v2.json',
+  }).sendAndConfirm(umi);
+
+  // Then the AgentIdentity plugin points at the new URI.
+  const assetData = await fetchAsset(umi, asset);
+  t.is(assetData?.agentIdentities?.length, 1);
+  t.like(assetData?.agentIdentities?.[0], {
+    type: 'AgentIdentity',
+    uri: 'https://example.com/agent-v2.json',
+  });
+});
+
+test('it cannot update the agent registration uri if not the owner', async (t) => {
+  const umi = await createUmi();
+  const { collection, asset } = await createCollectionAndAsset(umi);
+
+  await registerIdentityV1(umi, {
+    asset,
+    collection,
+    agentRegistrationUri: 'https://example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  // A random signer that neither owns the asset nor is its asset signer.
+  const attacker = generateSigner(umi);
+
+  const result = updateAgentRegistrationUriV1(umi, {
+    asset,
+    collection,
+    authority: attacker,
+    agentRegistrationUri: 'https://evil.example.com/agent.json',
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'OnlyAssetOwnerOrSignerCanUpdateUri' });
+});
@@ -0,0 +1,64 @@
+import test from 'ava';
+import { mplBubblegum } from '@metaplex-foundation/mpl-bubblegum';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  appendResponseV1,
+  fetchFeedbackV1,
+} from '../../src/generated/reputation';
+import { createUmi } from '../_setup';
+import { setupFeedback } from '../_feedback';
+
+const RESPONSE_URI = 'https://example.com/response.json';
+const RESPONSE_HASH = new Uint8Array(32).fill(7);
+
+test('the asset owner can respond to feedback', async (t) => {
+  // Given feedback left for an agent owned by the umi identity.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, feedback } = await setupFeedback(umi);
+
+  // When the owner responds.
+  await appendResponseV1(umi, {
+    feedback,
+    asset: agent.setup.agent,
+    authority: umi.identity,
+    responseHash: RESPONSE_HASH,
+    responseUri: RESPONSE_URI,
+  }).sendAndConfirm(umi);
+
+  // Then the response is recorded on the feedback.
+  t.like(await fetchFeedbackV1(umi, feedback), {
+    responseCount: 1n,
+    responseHash: RESPONSE_HASH,
+  });
+});
+
+test('an execution delegate can respond to feedback', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, feedback } = await setupFeedback(umi);
+
+  await appendResponseV1(umi, {
+    feedback,
+    asset: agent.setup.agent,
+    authority: agent.setup.executive,
+    executionDelegateRecord: agent.setup.executionDelegateRecord,
+    responseHash: RESPONSE_HASH,
+    responseUri: RESPONSE_URI,
+  }).sendAndConfirm(umi);
+
+  t.like(await fetchFeedbackV1(umi, feedback), { responseCount: 1n });
+});
+
+test('a stranger cannot respond to feedback', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, feedback } = await setupFeedback(umi);
+
+  const result = appendResponseV1(umi, {
+    feedback,
+    asset: agent.setup.agent,
+    authority: generateSigner(umi),
+    responseHash: RESPONSE_HASH,
+    responseUri: RESPONSE_URI,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidResponseAuthority' });
+});
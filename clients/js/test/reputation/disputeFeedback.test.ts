@@ -0,0 +1,58 @@
+import test from 'ava';
+import { mplBubblegum } from '@metaplex-foundation/mpl-bubblegum';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  disputeFeedbackV1,
+  FeedbackStatus,
+  fetchAgentReputationV2FromSeeds,
+  fetchFeedbackV1,
+} from '../../src/generated/reputation';
+import { createUmi } from '../_setup';
+import { setupFeedback } from '../_feedback';
+
+test('an execution delegate can dispute feedback', async (t) => {
+  // Given feedback left for an agent.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, feedback } = await setupFeedback(umi);
+
+  // When the agent's executive disputes it.
+  await disputeFeedbackV1(umi, {
+    asset: agent.setup.agent,
+    collection: agent.setup.collection,
+    feedback,
+    authority: agent.setup.executive,
+    executionDelegateRecord: agent.setup.executionDelegateRecord,
+  }).sendAndConfirm(umi);
+
+  // Then the feedback is marked as disputed.
+  t.like(await fetchFeedbackV1(umi, feedback), {
+    status: FeedbackStatus.Disputed,
+  });
+
+  // And it stays in the aggregates, and is counted as disputed.
+  const agentReputation = await fetchAgentReputationV2FromSeeds(umi, {
+    asset: agent.setup.agent,
+  });
+  t.like(agentReputation, {
+    feedbackCount: 1n,
+    scoreSum: 60n,
+    disputedCount: 1n,
+    disputedScoreSum: 60n,
+    disputedWeightSum: 1n,
+    disputedWeightedScoreSum: 60n,
+  });
+});
+
+test('a stranger cannot dispute feedback', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, feedback } = await setupFeedback(umi);
+
+  const result = disputeFeedbackV1(umi, {
+    asset: agent.setup.agent,
+    collection: agent.setup.collection,
+    feedback,
+    authority: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidDisputeAuthority' });
+});
@@ -0,0 +1,50 @@
+import test from 'ava';
+import { mplBubblegum } from '@metaplex-foundation/mpl-bubblegum';
+import {
+  fetchAgentReputationV2FromSeeds,
+  findAgentReputationV2Pda,
+  refreshReputationV1,
+} from '../../src/generated/reputation';
+import { createUmi } from '../_setup';
+import { setupAgent, setupFeedback } from '../_feedback';
+
+const ONE = 1n << 32n;
+
+test('anyone can refresh the decayed score of an agent', async (t) => {
+  // Given feedback left for an agent.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent } = await setupFeedback(umi);
+  const agentReputation = findAgentReputationV2Pda(umi, {
+    asset: agent.setup.agent,
+  });
+
+  // Which entered the decayed score at full weight.
+  t.like(
+    await fetchAgentReputationV2FromSeeds(umi, { asset: agent.setup.agent }),
+    { decayedScore: 60n * ONE, decayedCount: ONE }
+  );
+
+  // When a third party refreshes the reputation.
+  await refreshReputationV1(umi, { agentReputation }).sendAndConfirm(umi);
+
+  // Then the decayed score never grows and the raw aggregates are untouched.
+  const refreshed = await fetchAgentReputationV2FromSeeds(umi, {
+    asset: agent.setup.agent,
+  });
+  t.true(refreshed.decayedScore <= 60n * ONE);
+  t.true(refreshed.decayedCount <= ONE);
+  t.like(refreshed, { feedbackCount: 1n, scoreSum: 60n });
+});
+
+test('it cannot refresh an agent without feedback', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const agent = await setupAgent(umi);
+
+  const result = refreshReputationV1(umi, {
+    agentReputation: findAgentReputationV2Pda(umi, {
+      asset: agent.setup.agent,
+    }),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAccountData' });
+});
@@ -1,10 +1,15 @@
 import test from 'ava';
-import { fetchAsset } from '@metaplex-foundation/mpl-core';
-import { publicKey } from '@metaplex-foundation/umi';
+import {
+  create,
+  createCollection,
+  fetchAsset,
+} from '@metaplex-foundation/mpl-core';
+import { generateSigner, publicKey } from '@metaplex-foundation/umi';
 import {
   fetchAgentReputationV1,
   findAgentReputationV1Pda,
   Key,
+  registerReputationAsOwnerV1,
   registerReputationV1,
 } from '../../src/generated/reputation';
 import { createCollectionAndAsset, createUmi } from '../_setup';
@@ -35,3 +40,59 @@ test('it can register an asset', async (t) => {
     authority: { type: 'UpdateAuthority' },
   });
 });
+
+test('the asset owner can register without the collection authority', async (t) => {
+  // Given an asset whose owner is not the collection authority.
+  const umi = await createUmi();
+  const collectionAuthority = generateSigner(umi);
+  const owner = generateSigner(umi);
+  const collection = generateSigner(umi);
+  const asset = generateSigner(umi);
+  await createCollection(umi, {
+    collection,
+    updateAuthority: collectionAuthority.publicKey,
+    name: 'Test Collection',
+    uri: 'https://example.com/collection.json',
+  }).sendAndConfirm(umi);
+  await create(umi, {
+    asset,
+    collection,
+    authority: collectionAuthority,
+    owner: owner.publicKey,
+    name: 'Test Asset',
+    uri: 'https://example.com/asset.json',
+  }).sendAndConfirm(umi);
+
+  // When the owner registers the reputation.
+  await registerReputationAsOwnerV1(umi, {
+    asset: asset.publicKey,
+    authority: owner,
+  }).sendAndConfirm(umi);
+
+  // Then there's an Agent Reputation PDA.
+  const agentReputationPda = findAgentReputationV1Pda(umi, {
+    asset: asset.publicKey,
+  });
+  t.like(await fetchAgentReputationV1(umi, agentReputationPda), {
+    key: Key.AgentReputationV1,
+    asset: asset.publicKey,
+  });
+
+  // But no AppData plugin on the asset.
+  const assetData = await fetchAsset(umi, asset.publicKey);
+  t.is(assetData.appDatas?.length ?? 0, 0);
+});
+
+test('only the asset owner can register as owner', async (t) => {
+  const umi = await createUmi();
+  const { asset } = await createCollectionAndAsset(umi);
+
+  const result = registerReputationAsOwnerV1(umi, {
+    asset,
+    authority: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, {
+    name: 'OnlyAssetOwnerOrSignerCanRegisterReputation',
+  });
+});
@@ -0,0 +1,50 @@
+import test from 'ava';
+import { mplBubblegum } from '@metaplex-foundation/mpl-bubblegum';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  FeedbackStatus,
+  fetchAgentReputationV2FromSeeds,
+  fetchFeedbackV1,
+  revokeFeedbackV1,
+} from '../../src/generated/reputation';
+import { createUmi } from '../_setup';
+import { setupFeedback } from '../_feedback';
+
+test('the reviewer can revoke their feedback', async (t) => {
+  // Given feedback left for an agent.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, reviewer, feedback } = await setupFeedback(umi);
+
+  // When the reviewer revokes it.
+  await revokeFeedbackV1(umi, {
+    asset: agent.setup.agent,
+    collection: agent.setup.collection,
+    feedback,
+    reviewer,
+  }).sendAndConfirm(umi);
+
+  // Then the feedback is marked as revoked.
+  t.like(await fetchFeedbackV1(umi, feedback), {
+    status: FeedbackStatus.Revoked,
+  });
+
+  // And its score is removed from the aggregates.
+  const agentReputation = await fetchAgentReputationV2FromSeeds(umi, {
+    asset: agent.setup.agent,
+  });
+  t.like(agentReputation, { feedbackCount: 0n, scoreSum: 0n });
+});
+
+test('only the reviewer can revoke their feedback', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, feedback } = await setupFeedback(umi);
+
+  const result = revokeFeedbackV1(umi, {
+    asset: agent.setup.agent,
+    collection: agent.setup.collection,
+    feedback,
+    reviewer: generateSigner(umi),
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidReviewer' });
+});
@@ -0,0 +1,43 @@
+import test from 'ava';
+import { mplBubblegum } from '@metaplex-foundation/mpl-bubblegum';
+import {
+  fetchReputationSnapshotV1FromSeeds,
+  findReputationSnapshotV1Pda,
+  Key,
+  snapshotReputationV1,
+} from '../../src/generated/reputation';
+import { createUmi } from '../_setup';
+import { setupFeedback } from '../_feedback';
+
+test('anyone can snapshot the reputation of an agent', async (t) => {
+  // Given feedback left for an agent.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent } = await setupFeedback(umi);
+  const asset = agent.setup.agent;
+  const { epoch } = await umi.rpc.call<{ epoch: number }>('getEpochInfo');
+
+  // When a third party snapshots its reputation for the current epoch.
+  await snapshotReputationV1(umi, {
+    reputationSnapshot: findReputationSnapshotV1Pda(umi, { asset, epoch }),
+    asset,
+  }).sendAndConfirm(umi);
+
+  // Then the snapshot holds the aggregates.
+  t.like(await fetchReputationSnapshotV1FromSeeds(umi, { asset, epoch }), {
+    key: Key.ReputationSnapshotV1,
+    asset,
+    epoch: BigInt(epoch),
+    feedbackCount: 1n,
+    scoreSum: 60n,
+    scoreSumSquares: 3600n,
+    weightSum: 1n,
+    weightedScoreSum: 60n,
+  });
+
+  // And it cannot be taken again in the same epoch.
+  const result = snapshotReputationV1(umi, {
+    reputationSnapshot: findReputationSnapshotV1Pda(umi, { asset, epoch }),
+    asset,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(result, { name: 'ReputationAlreadySnapshotted' });
+});
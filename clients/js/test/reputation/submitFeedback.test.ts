@@ -0,0 +1,153 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import { mplBubblegum } from '@metaplex-foundation/mpl-bubblegum';
+import { generateSigner } from '@metaplex-foundation/umi';
+import {
+  fetchAgentReputationV2FromSeeds,
+  fetchFeedbackV1FromSeeds,
+  fetchReceiptNullifierV1,
+  findReceiptNullifierV1Pda,
+  Key,
+} from '../../src/generated/reputation';
+import { createUmi } from '../_setup';
+import { issueReceipt, setupAgent, submitFeedback } from '../_feedback';
+
+test('it can submit feedback with a work receipt', async (t) => {
+  // Given a reviewer holding a work receipt issued by the agent.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const agent = await setupAgent(umi);
+  const reviewer = generateSigner(umi);
+  const receipt = await issueReceipt(umi, agent, reviewer.publicKey);
+
+  // When the reviewer submits feedback.
+  await submitFeedback(umi, agent, receipt, reviewer, 80).sendAndConfirm(umi);
+
+  // Then the agent reputation holds the aggregates.
+  const agentReputation = await fetchAgentReputationV2FromSeeds(umi, {
+    asset: agent.setup.agent,
+  });
+  t.like(agentReputation, {
+    key: Key.AgentReputationV2,
+    asset: agent.setup.agent,
+    feedbackCount: 1n,
+    scoreSum: 80n,
+    scoreSumSquares: 6400n,
+  });
+
+  // And the summary is written to the AppData plugin.
+  const assetData = await fetchAsset(umi, agent.setup.agent);
+  const summary = assetData.appDatas?.[0].data as Uint8Array;
+  const view = new DataView(summary.buffer, summary.byteOffset);
+  t.is(summary.length, 40);
+  t.is(view.getUint8(0), 1);
+  t.is(view.getBigUint64(8, true), 1n);
+  t.is(view.getBigUint64(16, true), 80n);
+
+  // And the receipt is nullified.
+  const receiptNullifier = await fetchReceiptNullifierV1(
+    umi,
+    findReceiptNullifierV1Pda(umi, {
+      treeIndex: agent.ctx.receiptsTreeIndex,
+      nonce: receipt.nonce,
+    })
+  );
+  t.like(receiptNullifier, {
+    key: Key.ReceiptNullifierV1,
+    treeIndex: agent.ctx.receiptsTreeIndex,
+    nonce: 0n,
+    asset: agent.setup.agent,
+    reviewer: reviewer.publicKey,
+  });
+
+  // And the feedback is recorded.
+  const feedback = await fetchFeedbackV1FromSeeds(umi, {
+    asset: agent.setup.agent,
+    reviewer: reviewer.publicKey,
+    index: 0,
+  });
+  t.like(feedback, {
+    key: Key.FeedbackV1,
+    score: 80,
+    asset: agent.setup.agent,
+    reviewer: reviewer.publicKey,
+    index: 0n,
+    treeIndex: agent.ctx.receiptsTreeIndex,
+    nonce: 0n,
+    responseCount: 0n,
+  });
+});
+
+test('it cannot review the same work receipt twice', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const agent = await setupAgent(umi);
+  const reviewer = generateSigner(umi);
+  const receipt = await issueReceipt(umi, agent, reviewer.publicKey);
+
+  await submitFeedback(umi, agent, receipt, reviewer, 90).sendAndConfirm(umi);
+
+  const result = submitFeedback(
+    umi,
+    agent,
+    receipt,
+    reviewer,
+    10
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'ReceiptAlreadyReviewed' });
+});
+
+test('it cannot submit feedback with the receipt of another client', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const agent = await setupAgent(umi);
+  const client = generateSigner(umi);
+  const receipt = await issueReceipt(umi, agent, client.publicKey);
+
+  // A stranger presents the client's receipt.
+  const result = submitFeedback(
+    umi,
+    agent,
+    receipt,
+    generateSigner(umi),
+    0
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(result);
+});
+
+test('it cannot submit feedback above the maximum score', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const agent = await setupAgent(umi);
+  const reviewer = generateSigner(umi);
+  const receipt = await issueReceipt(umi, agent, reviewer.publicKey);
+
+  const result = submitFeedback(
+    umi,
+    agent,
+    receipt,
+    reviewer,
+    101
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidFeedbackScore' });
+});
+
+test('it cannot reuse a feedback index', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const agent = await setupAgent(umi);
+  const reviewer = generateSigner(umi);
+  const first = await issueReceipt(umi, agent, reviewer.publicKey);
+  const second = await issueReceipt(umi, agent, reviewer.publicKey);
+
+  await submitFeedback(umi, agent, first, reviewer, 70).sendAndConfirm(umi);
+
+  // The second receipt is fresh, but the feedback index is taken.
+  const result = submitFeedback(
+    umi,
+    agent,
+    second,
+    reviewer,
+    70
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'FeedbackAlreadyExists' });
+});
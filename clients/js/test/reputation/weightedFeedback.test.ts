@@ -0,0 +1,77 @@
+import test from 'ava';
+import { mplBubblegum } from '@metaplex-foundation/mpl-bubblegum';
+import { generateSigner, publicKey } from '@metaplex-foundation/umi';
+import {
+  fetchAgentReputationV2FromSeeds,
+  fetchFeedbackV1,
+  fetchReviewerHistoryV1FromSeeds,
+  Key,
+  setWeightingPolicyV1,
+} from '../../src/generated/reputation';
+import { createUmi } from '../_setup';
+import { issueReceipt, setupFeedback, submitFeedback } from '../_feedback';
+
+test('feedback weighs one until a weighting policy is set', async (t) => {
+  // Given feedback left for an agent.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const { agent, reviewer, feedback } = await setupFeedback(umi);
+
+  // Then it carries the default weight.
+  t.like(await fetchFeedbackV1(umi, feedback), { weight: 1 });
+  t.like(
+    await fetchAgentReputationV2FromSeeds(umi, { asset: agent.setup.agent }),
+    { weightSum: 1n, weightedScoreSum: 60n }
+  );
+
+  // And the receipt is counted in the reviewer history.
+  t.like(
+    await fetchReviewerHistoryV1FromSeeds(umi, {
+      asset: agent.setup.agent,
+      reviewer: reviewer.publicKey,
+    }),
+    {
+      key: Key.ReviewerHistoryV1,
+      asset: agent.setup.agent,
+      reviewer: reviewer.publicKey,
+      receiptCount: 1n,
+    }
+  );
+
+  // When the reviewer reviews a second receipt.
+  const receipt = await issueReceipt(umi, agent, reviewer.publicKey);
+  await submitFeedback(umi, agent, receipt, reviewer, 90, 1).sendAndConfirm(
+    umi
+  );
+
+  // Then both receipts are counted.
+  t.like(
+    await fetchReviewerHistoryV1FromSeeds(umi, {
+      asset: agent.setup.agent,
+      reviewer: reviewer.publicKey,
+    }),
+    { receiptCount: 2n }
+  );
+  t.like(
+    await fetchAgentReputationV2FromSeeds(umi, { asset: agent.setup.agent }),
+    { weightSum: 2n, weightedScoreSum: 150n }
+  );
+});
+
+test('it cannot set a weighting policy without a reputation config', async (t) => {
+  const umi = await createUmi();
+
+  const result = setWeightingPolicyV1(umi, {
+    authority: generateSigner(umi),
+    baseWeight: 1,
+    receiptWeight: 0,
+    maxReceipts: 0,
+    identityWeight: 0,
+    tokenWeight: 0,
+    tokenMint: publicKey('11111111111111111111111111111111'),
+    minTokenBalance: 0,
+    minIdentityFeedback: 0,
+    tokenLockSlots: 0,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidReputationConfig' });
+});
@@ -0,0 +1,232 @@
+import test from 'ava';
+import {
+  findLeafAssetIdPda,
+  findTreeConfigPda,
+  getMerkleRoot,
+  mplBubblegum,
+} from '@metaplex-foundation/mpl-bubblegum';
+import {
+  generateSigner,
+  publicKey,
+  PublicKey,
+  publicKeyBytes,
+  Signer,
+  Umi,
+} from '@metaplex-foundation/umi';
+
+import {
+  fetchReceiptReviewV1,
+  findReceiptReviewV1Pda,
+  Key,
+  mintReviewV1,
+  mintWorkReceiptV1,
+} from '../../src/generated/tools';
+import { createUmi } from '../_setup';
+import {
+  AgentSetup,
+  bootstrapReceipts,
+  bootstrapReviews,
+  DEFAULT_ASSET_DATA_HASH,
+  getCurrentTreeRoot,
+  getReceiptProof,
+  hashReceiptLeaf,
+  hashReviewLeaf,
+  MPL_CORE_CPI_SIGNER,
+  receiptDataHash,
+  ReceiptsBootstrap,
+  ReviewsBootstrap,
+  setupAgentWithExecutive,
+  TREE_MAX_DEPTH,
+} from '../_receiptsReviews';
+
+const RECEIPT_URI = 'https://example.com/receipt.json';
+const REVIEW_URI = 'https://example.com/review.json';
+
+interface Fixture {
+  receipts: ReceiptsBootstrap;
+  reviews: ReviewsBootstrap;
+  setup: AgentSetup;
+  leaves: Uint8Array[];
+}
+
+async function setupFixture(umi: Umi): Promise<Fixture> {
+  return {
+    receipts: await bootstrapReceipts(umi),
+    reviews: await bootstrapReviews(umi),
+    setup: await setupAgentWithExecutive(umi),
+    leaves: [],
+  };
+}
+
+/** Mint a work receipt from the agent to `client`. */
+async function issueReceipt(umi: Umi, fixture: Fixture, client: PublicKey) {
+  await mintWorkReceiptV1(umi, {
+    executiveAuthority: fixture.setup.executive,
+    executionDelegateRecord: fixture.setup.executionDelegateRecord,
+    agentAsset: fixture.setup.agent,
+    client,
+    treeConfig: findTreeConfigPda(umi, {
+      merkleTree: fixture.receipts.receiptsTree,
+    }),
+    merkleTree: fixture.receipts.receiptsTree,
+    coreCollection: fixture.receipts.receiptsCollection,
+    mplCoreCpiSigner: MPL_CORE_CPI_SIGNER,
+    receiptUri: RECEIPT_URI,
+    treeIndex: fixture.receipts.receiptsTreeIndex,
+  }).sendAndConfirm(umi);
+
+  const nonce = fixture.leaves.length;
+  const leaf = hashReceiptLeaf(umi, {
+    merkleTree: fixture.receipts.receiptsTree,
+    leafIndex: nonce,
+    owner: client,
+    agent: fixture.setup.agent,
+    client,
+    receiptsCollection: fixture.receipts.receiptsCollection,
+    receiptUri: RECEIPT_URI,
+  });
+  fixture.leaves.push(leaf);
+
+  return { nonce, leaf, client };
+}
+
+function mintReview(
+  umi: Umi,
+  fixture: Fixture,
+  receipt: { nonce: number; leaf: Uint8Array; client: PublicKey },
+  reviewer: Signer,
+  reviewUri = REVIEW_URI
+) {
+  const { root, proof } = getReceiptProof(fixture.leaves, receipt.leaf);
+
+  return mintReviewV1(umi, {
+    reviewer,
+    agentAsset: fixture.setup.agent,
+    receiptReview: findReceiptReviewV1Pda(umi, {
+      receiptsTreeIndex: fixture.receipts.receiptsTreeIndex,
+      receiptNonce: receipt.nonce,
+    }),
+    receiptsMerkleTree: fixture.receipts.receiptsTree,
+    treeConfig: findTreeConfigPda(umi, {
+      merkleTree: fixture.reviews.reviewsTree,
+    }),
+    merkleTree: fixture.reviews.reviewsTree,
+    mplCoreCpiSigner: MPL_CORE_CPI_SIGNER,
+    receiptFlags: 0,
+    receiptIndex: receipt.nonce,
+    receiptsTreeIndex: fixture.receipts.receiptsTreeIndex,
+    receiptNonce: receipt.nonce,
+    reviewsTreeIndex: fixture.reviews.reviewsTreeIndex,
+    root,
+    receiptDataHash: receiptDataHash({
+      receiptUri: RECEIPT_URI,
+      agent: fixture.setup.agent,
+      client: receipt.client,
+      receiptsCollection: fixture.receipts.receiptsCollection,
+    }),
+    receiptAssetDataHash: DEFAULT_ASSET_DATA_HASH,
+    reviewUri,
+  }).addRemainingAccounts(
+    proof.map((pubkey) => ({ pubkey, isSigner: false, isWritable: false }))
+  );
+}
+
+test('mintReviewV1 — receipt owner can mint a review', async (t) => {
+  // Given a reviewer holding a work receipt issued by the agent.
+  const umi = (await createUmi()).use(mplBubblegum());
+  const fixture = await setupFixture(umi);
+  const reviewer = generateSigner(umi);
+  const receipt = await issueReceipt(umi, fixture, reviewer.publicKey);
+
+  // When the reviewer mints a review of the receipt.
+  await mintReview(umi, fixture, receipt, reviewer).sendAndConfirm(umi);
+
+  // Then the receipt review record links the receipt to the review leaf.
+  const [receiptAssetId] = findLeafAssetIdPda(umi, {
+    merkleTree: fixture.receipts.receiptsTree,
+    leafIndex: receipt.nonce,
+  });
+  const receiptReview = await fetchReceiptReviewV1(
+    umi,
+    findReceiptReviewV1Pda(umi, {
+      receiptsTreeIndex: fixture.receipts.receiptsTreeIndex,
+      receiptNonce: receipt.nonce,
+    })
+  );
+  t.like(receiptReview, {
+    key: Key.ReceiptReviewV1,
+    agentAsset: fixture.setup.agent,
+    reviewer: reviewer.publicKey,
+    receipt: receiptAssetId,
+    reviewTreeIndex: fixture.reviews.reviewsTreeIndex,
+    reviewNonce: 0n,
+  });
+
+  // And the reviews tree holds the review leaf, owned by the reviewer.
+  const reviewLeaf = hashReviewLeaf(umi, {
+    merkleTree: fixture.reviews.reviewsTree,
+    leafIndex: 0,
+    agent: fixture.setup.agent,
+    reviewer: reviewer.publicKey,
+    receipt: receiptAssetId,
+    reviewsCollection: fixture.reviews.reviewsCollection,
+    reviewUri: REVIEW_URI,
+  });
+  t.deepEqual(
+    await getCurrentTreeRoot(umi, fixture.reviews.reviewsTree),
+    publicKeyBytes(getMerkleRoot([publicKey(reviewLeaf)], TREE_MAX_DEPTH))
+  );
+});
+
+test('mintReviewV1 — a receipt can only be reviewed once', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const fixture = await setupFixture(umi);
+  const reviewer = generateSigner(umi);
+  const receipt = await issueReceipt(umi, fixture, reviewer.publicKey);
+
+  await mintReview(umi, fixture, receipt, reviewer).sendAndConfirm(umi);
+
+  const result = mintReview(
+    umi,
+    fixture,
+    receipt,
+    reviewer,
+    'https://example.com/review-2.json'
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'ReceiptAlreadyReviewed' });
+});
+
+test('mintReviewV1 — cannot review the receipt of another client', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const fixture = await setupFixture(umi);
+  const client = generateSigner(umi);
+  const receipt = await issueReceipt(umi, fixture, client.publicKey);
+
+  // A stranger presents the client's receipt.
+  const result = mintReview(
+    umi,
+    fixture,
+    receipt,
+    generateSigner(umi)
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(result);
+});
+
+test('mintReviewV1 — rejects an empty review URI', async (t) => {
+  const umi = (await createUmi()).use(mplBubblegum());
+  const fixture = await setupFixture(umi);
+  const reviewer = generateSigner(umi);
+  const receipt = await issueReceipt(umi, fixture, reviewer.publicKey);
+
+  const result = mintReview(
+    umi,
+    fixture,
+    receipt,
+    reviewer,
+    ''
+  ).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'ReviewUriInvalid' });
+});
@@ -0,0 +1,132 @@
+import test from 'ava';
+import {
+  createSignerFromKeypair,
+  generateSigner,
+  publicKey,
+  Signer,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  addValidatorAttesterV1,
+  EvidenceType,
+  fetchValidationRequestV1,
+  fetchValidatorProfileV1,
+  findValidationRequestV1Pda,
+  findValidatorProfileV1Pda,
+  registerValidationV1,
+  registerValidatorV1,
+  requestValidationV1,
+  ValidationStatus,
+} from '../../src/generated/validation';
+import { attestationMessage, respondWithAttestation } from '../../src';
+import { createCollectionAndAsset, createUmi } from '../_setup';
+
+const WORK_HASH = new Uint8Array(32).fill(7);
+const EVIDENCE_HASH = new Uint8Array(32).fill(5);
+
+const setupRequest = async (umi: Umi, validator: Signer) => {
+  const { collection, asset } = await createCollectionAndAsset(umi);
+  await registerValidationV1(umi, { asset, collection }).sendAndConfirm(umi);
+  const validationRequest = findValidationRequestV1Pda(umi, {
+    asset,
+    nonce: 0,
+  });
+  await requestValidationV1(umi, {
+    validationRequest,
+    validatorProfile: findValidatorProfileV1Pda(umi, {
+      validator: validator.publicKey,
+    }),
+    asset,
+    requester: generateSigner(umi),
+    nonce: 0,
+    workHash: WORK_HASH,
+    validator: validator.publicKey,
+  }).sendAndConfirm(umi);
+  return { collection, asset, validationRequest };
+};
+
+test('a validator can respond with an attestation', async (t) => {
+  // Given a validator with an attester key.
+  const umi = await createUmi();
+  const validator = generateSigner(umi);
+  await registerValidatorV1(umi, { validator, stake: 0 }).sendAndConfirm(umi);
+  const attester = createSignerFromKeypair(umi, umi.eddsa.generateKeypair());
+  await addValidatorAttesterV1(umi, { validator, attester }).sendAndConfirm(
+    umi
+  );
+  t.like(
+    await fetchValidatorProfileV1(
+      umi,
+      findValidatorProfileV1Pda(umi, { validator: validator.publicKey })
+    ),
+    { attesterCount: 1n }
+  );
+
+  // And a request to the validator.
+  const { collection, asset, validationRequest } = await setupRequest(
+    umi,
+    validator
+  );
+
+  // When the validator responds with a result signed by the attester.
+  const message = attestationMessage(
+    publicKey(validationRequest),
+    validator.publicKey,
+    WORK_HASH,
+    90
+  );
+  await respondWithAttestation(umi, {
+    validationRequest,
+    asset,
+    collection,
+    validator,
+    result: 90,
+    evidenceHash: EVIDENCE_HASH,
+    attester: attester.publicKey,
+    signature: await attester.signMessage(message),
+    workHash: WORK_HASH,
+  }).sendAndConfirm(umi);
+
+  // Then the response is recorded as attested.
+  t.like(await fetchValidationRequestV1(umi, validationRequest), {
+    status: ValidationStatus.Responded,
+    result: 90,
+    evidenceType: EvidenceType.Attestation,
+    evidenceHash: EVIDENCE_HASH,
+  });
+});
+
+test('an attestation must match the result', async (t) => {
+  const umi = await createUmi();
+  const validator = generateSigner(umi);
+  await registerValidatorV1(umi, { validator, stake: 0 }).sendAndConfirm(umi);
+  const attester = createSignerFromKeypair(umi, umi.eddsa.generateKeypair());
+  await addValidatorAttesterV1(umi, { validator, attester }).sendAndConfirm(
+    umi
+  );
+  const { collection, asset, validationRequest } = await setupRequest(
+    umi,
+    validator
+  );
+
+  // The attester signed a failing result, the validator reports a pass.
+  const message = attestationMessage(
+    publicKey(validationRequest),
+    validator.publicKey,
+    WORK_HASH,
+    10
+  );
+  const result = respondWithAttestation(umi, {
+    validationRequest,
+    asset,
+    collection,
+    validator,
+    result: 90,
+    evidenceHash: EVIDENCE_HASH,
+    attester: attester.publicKey,
+    signature: await attester.signMessage(message),
+    workHash: WORK_HASH,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'InvalidAttestation' });
+});
@@ -0,0 +1,194 @@
+import test from 'ava';
+import { fetchAsset } from '@metaplex-foundation/mpl-core';
+import {
+  generateSigner,
+  publicKey,
+  Signer,
+  sol,
+  Umi,
+} from '@metaplex-foundation/umi';
+import {
+  fetchValidationRequestV1,
+  findValidationRequestV1Pda,
+  findValidatorProfileV1Pda,
+  Key,
+  reclaimValidationRequestV1,
+  registerValidationV1,
+  registerValidatorV1,
+  requestValidationV1,
+  respondValidationV1,
+  ValidationStatus,
+} from '../../src/generated/validation';
+import { createCollectionAndAsset, createUmi } from '../_setup';
+
+const WORK_HASH = new Uint8Array(32).fill(7);
+const EVIDENCE_HASH = new Uint8Array(32).fill(9);
+const EVIDENCE_URI = 'https://example.com/evidence.json';
+
+const setupAgent = async (umi: Umi) => {
+  const { collection, asset } = await createCollectionAndAsset(umi);
+  await registerValidationV1(umi, { asset, collection }).sendAndConfirm(umi);
+  return { collection, asset };
+};
+
+const setupValidator = async (umi: Umi): Promise<Signer> => {
+  const validator = generateSigner(umi);
+  await registerValidatorV1(umi, { validator, stake: 0 }).sendAndConfirm(umi);
+  return validator;
+};
+
+test('a validator can respond to a request', async (t) => {
+  // Given a registered agent owned by the identity and a validator.
+  const umi = await createUmi();
+  const { collection, asset } = await setupAgent(umi);
+  const requester = umi.identity;
+  const validator = await setupValidator(umi);
+  const validationRequest = findValidationRequestV1Pda(umi, {
+    asset,
+    nonce: 0,
+  });
+
+  // When the agent requests validation.
+  await requestValidationV1(umi, {
+    validationRequest,
+    validatorProfile: findValidatorProfileV1Pda(umi, {
+      validator: validator.publicKey,
+    }),
+    asset,
+    requester,
+    nonce: 0,
+    workHash: WORK_HASH,
+    validator: validator.publicKey,
+  }).sendAndConfirm(umi);
+
+  // Then the request is pending.
+  t.like(await fetchValidationRequestV1(umi, validationRequest), {
+    key: Key.ValidationRequestV1,
+    bump: validationRequest[1],
+    status: ValidationStatus.Pending,
+    asset: publicKey(asset),
+    requester: requester.publicKey,
+    validator: validator.publicKey,
+    nonce: 0n,
+    responseSlot: 0n,
+    workHash: WORK_HASH,
+  });
+
+  // And when the validator responds.
+  await respondValidationV1(umi, {
+    validationRequest,
+    asset,
+    collection,
+    validator,
+    result: 87,
+    evidenceHash: EVIDENCE_HASH,
+    evidenceUri: EVIDENCE_URI,
+  }).sendAndConfirm(umi);
+
+  // Then the response is recorded on the request and counts for the agent.
+  t.like(await fetchValidationRequestV1(umi, validationRequest), {
+    status: ValidationStatus.Responded,
+    result: 87,
+    evidenceHash: EVIDENCE_HASH,
+    counted: 1,
+  });
+
+  // And the summary is written to the AppData plugin.
+  const assetData = await fetchAsset(umi, asset);
+  const summary = assetData.appDatas?.[0].data as Uint8Array;
+  const view = new DataView(summary.buffer, summary.byteOffset);
+  t.is(summary.length, 72);
+  t.is(view.getUint8(0), 1);
+  t.is(view.getUint16(2, true), 10_000);
+  t.is(view.getBigUint64(8, true), 1n);
+  t.is(view.getBigUint64(16, true), 1n);
+  t.is(publicKey(summary.slice(32, 64)), validator.publicKey);
+});
+
+test('only the designated validator can respond', async (t) => {
+  const umi = await createUmi();
+  const { collection, asset } = await setupAgent(umi);
+  const validationRequest = findValidationRequestV1Pda(umi, {
+    asset,
+    nonce: 0,
+  });
+  const validator = await setupValidator(umi);
+  await requestValidationV1(umi, {
+    validationRequest,
+    validatorProfile: findValidatorProfileV1Pda(umi, {
+      validator: validator.publicKey,
+    }),
+    asset,
+    requester: generateSigner(umi),
+    nonce: 0,
+    workHash: WORK_HASH,
+    validator: validator.publicKey,
+  }).sendAndConfirm(umi);
+
+  const result = respondValidationV1(umi, {
+    validationRequest,
+    asset,
+    collection,
+    validator: generateSigner(umi),
+    result: 87,
+    evidenceHash: EVIDENCE_HASH,
+    evidenceUri: EVIDENCE_URI,
+  }).sendAndConfirm(umi);
+
+  await t.throwsAsync(result, { name: 'OnlyDesignatedValidatorCanRespond' });
+});
+
+test('a validator claims the reward escrowed in a request', async (t) => {
+  // Given a request escrowing a reward for the validator.
+  const umi = await createUmi();
+  const { collection, asset } = await setupAgent(umi);
+  const requester = generateSigner(umi);
+  await umi.rpc.airdrop(requester.publicKey, sol(1));
+  const validator = await setupValidator(umi);
+  const validationRequest = findValidationRequestV1Pda(umi, {
+    asset,
+    nonce: 0,
+  });
+  await requestValidationV1(umi, {
+    validationRequest,
+    validatorProfile: findValidatorProfileV1Pda(umi, {
+      validator: validator.publicKey,
+    }),
+    asset,
+    requester,
+    nonce: 0,
+    workHash: WORK_HASH,
+    validator: validator.publicKey,
+    reward: sol(0.5).basisPoints,
+  }).sendAndConfirm(umi);
+  const request = await fetchValidationRequestV1(umi, validationRequest);
+  t.is(request.reward, sol(0.5).basisPoints);
+  t.true(request.expirySlot > request.requestSlot);
+
+  // The requester cannot take the reward back before the request expires.
+  const reclaim = reclaimValidationRequestV1(umi, {
+    validationRequest,
+    requester,
+    destination: requester.publicKey,
+  }).sendAndConfirm(umi);
+  await t.throwsAsync(reclaim, { name: 'ValidationRequestNotExpired' });
+
+  // When the validator responds.
+  const balanceBefore = await umi.rpc.getBalance(validator.publicKey);
+  await respondValidationV1(umi, {
+    validationRequest,
+    asset,
+    collection,
+    validator,
+    result: 87,
+    evidenceHash: EVIDENCE_HASH,
+    evidenceUri: EVIDENCE_URI,
+  }).sendAndConfirm(umi);
+
+  // Then the validator receives the reward.
+  const balanceAfter = await umi.rpc.getBalance(validator.publicKey);
+  t.is(
+    balanceAfter.basisPoints - balanceBefore.basisPoints,
+    sol(0.5).basisPoints
+  );
+});
@@ -0,0 +1,67 @@
+import test from 'ava';
+import { generateSigner, sol } from '@metaplex-foundation/umi';
+import {
+  fetchValidatorProfileV1,
+  findValidatorProfileV1Pda,
+  Key,
+  registerValidatorV1,
+  unbondValidatorStakeV1,
+  withdrawValidatorStakeV1,
+} from '../../src/generated/validation';
+import { createUmi } from '../_setup';
+
+const STAKE = sol(2).basisPoints;
+
+test('a validator can register with stake', async (t) => {
+  // Given a funded validator.
+  const umi = await createUmi();
+  const validator = generateSigner(umi);
+  await umi.rpc.airdrop(validator.publicKey, sol(3));
+
+  // When it registers with stake.
+  await registerValidatorV1(umi, { validator, stake: STAKE }).sendAndConfirm(
+    umi
+  );
+
+  // Then its profile holds the stake.
+  const validatorProfile = findValidatorProfileV1Pda(umi, {
+    validator: validator.publicKey,
+  });
+  t.like(await fetchValidatorProfileV1(umi, validatorProfile), {
+    key: Key.ValidatorProfileV1,
+    bump: validatorProfile[1],
+    authority: validator.publicKey,
+    stake: STAKE,
+    unbondingStake: 0n,
+    slashedStake: 0n,
+  });
+});
+
+test('unbonding stake stays locked until the period ends', async (t) => {
+  // Given a staked validator unbonding its stake.
+  const umi = await createUmi();
+  const validator = generateSigner(umi);
+  await umi.rpc.airdrop(validator.publicKey, sol(3));
+  await registerValidatorV1(umi, { validator, stake: STAKE }).sendAndConfirm(
+    umi
+  );
+  await unbondValidatorStakeV1(umi, {
+    validator,
+    amount: STAKE,
+  }).sendAndConfirm(umi);
+
+  const profile = await fetchValidatorProfileV1(
+    umi,
+    findValidatorProfileV1Pda(umi, { validator: validator.publicKey })
+  );
+  t.is(profile.stake, 0n);
+  t.is(profile.unbondingStake, STAKE);
+
+  // When it tries to withdraw straight away.
+  const result = withdrawValidatorStakeV1(umi, { validator }).sendAndConfirm(
+    umi
+  );
+
+  // Then the stake is still locked.
+  await t.throwsAsync(result, { name: 'StakeStillUnbonding' });
+});
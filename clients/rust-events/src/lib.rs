@@ -0,0 +1,169 @@
+//! Decoding of the typed events emitted by the MPL Agent programs.
+//!
+//! Every event is written with `sol_log_data` as a single field holding the
+//! event version byte followed by the Borsh-serialized event. The program
+//! clients decode their own event enums with these helpers.
+
+use std::str::FromStr;
+
+use base64::{prelude::BASE64_STANDARD, Engine};
+use borsh::BorshDeserialize;
+use solana_program::pubkey::Pubkey;
+
+/// Decode an event from a `sol_log_data` payload. Returns `None` if the
+/// payload is not of the given version or is not a valid event.
+pub fn decode<E: BorshDeserialize>(version: u8, data: &[u8]) -> Option<E> {
+    match data.split_first() {
+        Some((event_version, event)) if *event_version == version => E::try_from_slice(event).ok(),
+        _ => None,
+    }
+}
+
+/// Decode all events emitted by `program_id` from transaction log messages.
+pub fn from_logs<E: BorshDeserialize, S: AsRef<str>>(
+    program_id: &Pubkey,
+    version: u8,
+    logs: &[S],
+) -> Vec<E> {
+    program_data_from_logs(program_id, logs)
+        .iter()
+        .filter_map(|data| decode(version, data))
+        .collect()
+}
+
+/// Collect the `sol_log_data` payloads emitted directly by `program_id` from
+/// transaction log messages. Data logged by other programs, including CPIs
+/// made by `program_id`, is skipped.
+pub fn program_data_from_logs<S: AsRef<str>>(program_id: &Pubkey, logs: &[S]) -> Vec<Vec<u8>> {
+    let mut invoke_stack: Vec<Pubkey> = Vec::new();
+    let mut payloads = Vec::new();
+
+    for log in logs {
+        match ProgramLog::parse(log.as_ref()) {
+            ProgramLog::Invoke(id) => invoke_stack.push(id),
+            ProgramLog::Exit(id) => {
+                if invoke_stack.last() == Some(&id) {
+                    invoke_stack.pop();
+                }
+            }
+            ProgramLog::Data(data) => {
+                if invoke_stack.last() == Some(program_id) {
+                    // Events are emitted as a single field.
+                    if let Ok(payload) = BASE64_STANDARD.decode(data.trim()) {
+                        payloads.push(payload);
+                    }
+                }
+            }
+            ProgramLog::Other => {}
+        }
+    }
+
+    payloads
+}
+
+/// The log messages written by the runtime that the decoder tracks.
+#[derive(Debug, PartialEq, Eq)]
+enum ProgramLog<'a> {
+    /// `Program <id> invoke [<depth>]`
+    Invoke(Pubkey),
+    /// `Program <id> success` or `Program <id> failed: <error>`
+    Exit(Pubkey),
+    /// `Program data: <base64>...`
+    Data(&'a str),
+    /// Any other message, including those logged by programs themselves.
+    Other,
+}
+
+impl<'a> ProgramLog<'a> {
+    fn parse(log: &'a str) -> Self {
+        if let Some(data) = log.strip_prefix("Program data: ") {
+            return Self::Data(data);
+        }
+
+        let Some((id, status)) = log
+            .strip_prefix("Program ")
+            .and_then(|rest| rest.split_once(' '))
+        else {
+            return Self::Other;
+        };
+        let Ok(id) = Pubkey::from_str(id) else {
+            return Self::Other;
+        };
+
+        let is_invoke = status
+            .strip_prefix("invoke [")
+            .and_then(|depth| depth.strip_suffix(']'))
+            .is_some_and(|depth| depth.parse::<usize>().is_ok());
+        if is_invoke {
+            Self::Invoke(id)
+        } else if status == "success" || status.starts_with("failed: ") {
+            Self::Exit(id)
+        } else {
+            Self::Other
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PROGRAM_ID: Pubkey =
+        solana_program::pubkey!("1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p");
+    const OTHER_ID: Pubkey =
+        solana_program::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+    fn payloads(logs: &[String]) -> Vec<Vec<u8>> {
+        program_data_from_logs(&PROGRAM_ID, logs)
+    }
+
+    #[test]
+    fn collects_data_of_the_program_only() {
+        let logs = vec![
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            format!("Program {OTHER_ID} invoke [2]"),
+            "Program data: AQ==".to_string(),
+            format!("Program {OTHER_ID} success"),
+            "Program data: Ag==".to_string(),
+            format!("Program {PROGRAM_ID} consumed 100 of 200000 compute units"),
+            format!("Program {PROGRAM_ID} success"),
+            "Program data: Aw==".to_string(),
+        ];
+
+        assert_eq!(payloads(&logs), vec![vec![2]]);
+    }
+
+    #[test]
+    fn ignores_messages_logged_by_programs() {
+        // A program logging text that looks like a runtime message must not
+        // move the invoke stack.
+        let logs = vec![
+            format!("Program {PROGRAM_ID} invoke [1]"),
+            "Program log: success".to_string(),
+            format!("Program log: {OTHER_ID} invoke [2]"),
+            "Program data: AQ==".to_string(),
+            format!("Program {PROGRAM_ID} success"),
+        ];
+
+        assert_eq!(payloads(&logs), vec![vec![1]]);
+    }
+
+    #[test]
+    fn failed_invocation_pops_the_program() {
+        let logs = vec![
+            format!("Program {OTHER_ID} invoke [1]"),
+            format!("Program {PROGRAM_ID} invoke [2]"),
+            format!("Program {PROGRAM_ID} failed: custom program error: 0x1"),
+            "Program data: AQ==".to_string(),
+        ];
+
+        assert!(payloads(&logs).is_empty());
+    }
+
+    #[test]
+    fn decodes_only_the_given_version() {
+        assert_eq!(decode::<u8>(1, &[1, 7]), Some(7));
+        assert_eq!(decode::<u8>(1, &[2, 7]), None);
+        assert_eq!(decode::<u8>(1, &[]), None);
+    }
+}
@@ -0,0 +1,65 @@
+//! Helpers for registering the identities of several assets of a collection
+//! with a single `RegisterIdentitiesV1` instruction.
+
+use solana_program::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+};
+
+use crate::{accounts::AgentIdentityV2, instructions::RegisterIdentitiesV1Builder};
+
+/// Number of assets registered by a single `RegisterIdentitiesV1`
+/// instruction. Each asset adds two unique accounts and a Core CPI, so this
+/// keeps a batch with a shared URI within the packet size limit. Larger
+/// batches also need a higher compute unit limit.
+pub const MAX_REGISTRATIONS_PER_INSTRUCTION: usize = 8;
+
+/// Prefix of the program log written when registering an entry of the batch
+/// fails. It is followed by the index of the entry and the asset address.
+pub const REGISTRATION_FAILURE_LOG_PREFIX: &str = "Failed to register identity at index ";
+
+/// Build the remaining accounts of `RegisterIdentitiesV1`: an
+/// (agent identity PDA, asset) pair for each asset.
+pub fn register_identities_remaining_accounts(assets: &[Pubkey]) -> Vec<AccountMeta> {
+    assets
+        .iter()
+        .flat_map(|asset| {
+            [
+                AccountMeta::new(AgentIdentityV2::find_pda(asset).0, false),
+                AccountMeta::new(*asset, false),
+            ]
+        })
+        .collect()
+}
+
+/// Build the `RegisterIdentitiesV1` instruction for `assets` of `collection`.
+/// `agent_registration_uris` holds either one URI shared by every asset or
+/// one URI per asset, in the same order as `assets`.
+pub fn register_identities_instruction(
+    collection: &Pubkey,
+    assets: &[Pubkey],
+    agent_registration_uris: Vec<String>,
+    payer: &Pubkey,
+    authority: Option<&Pubkey>,
+) -> Instruction {
+    RegisterIdentitiesV1Builder::new()
+        .collection(*collection)
+        .payer(*payer)
+        .authority(authority.copied())
+        .agent_registration_uris(agent_registration_uris)
+        .add_remaining_accounts(&register_identities_remaining_accounts(assets))
+        .instruction()
+}
+
+/// Find the index of the batch entry that made `RegisterIdentitiesV1` fail
+/// in the transaction logs.
+pub fn failed_registration_index<S: AsRef<str>>(logs: &[S]) -> Option<usize> {
+    logs.iter().find_map(|log| {
+        let log = log.as_ref().strip_prefix("Program log: ")?;
+        log.strip_prefix(REGISTRATION_FAILURE_LOG_PREFIX)?
+            .split(':')
+            .next()?
+            .parse()
+            .ok()
+    })
+}
@@ -0,0 +1,159 @@
+//! Resolve the Agent Identity that applies to an asset.
+//!
+//! An asset either carries its own Agent Identity plugin (`RegisterIdentityV1`)
+//! or, after `InheritCollectionIdentityV1`, uses the plugin registered on its
+//! collection with `RegisterCollectionIdentityV1`.
+//!
+//! The resolver works on raw account data. Enable the `rpc` feature to fetch
+//! the accounts from a cluster.
+
+use std::io::{Error, ErrorKind};
+
+use mpl_core::{types::UpdateAuthority, Asset, Collection};
+use solana_program::pubkey::Pubkey;
+
+use crate::{accounts::AgentIdentityV2, types::Key};
+
+/// The Agent Identity that applies to an asset.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum EffectiveIdentity {
+    /// The asset carries its own Agent Identity plugin.
+    Asset { registration_uri: String },
+    /// The asset inherits the Agent Identity plugin of its collection.
+    Collection {
+        collection: Pubkey,
+        registration_uri: String,
+    },
+}
+
+impl EffectiveIdentity {
+    /// The URI of the Agent Registration JSON file.
+    pub fn registration_uri(&self) -> &str {
+        match self {
+            Self::Asset { registration_uri } => registration_uri,
+            Self::Collection {
+                registration_uri, ..
+            } => registration_uri,
+        }
+    }
+}
+
+/// Returns `true` if `data` is an agent identity that inherits from the
+/// asset's collection.
+pub fn inherits_collection_identity(data: &[u8]) -> bool {
+    data.first() == Some(&(Key::AgentIdentityV2 as u8))
+        && AgentIdentityV2::from_bytes(data)
+            .map(|identity| identity.inherits_collection_identity)
+            .unwrap_or(false)
+}
+
+/// Returns the collection of an asset, if it belongs to one.
+pub fn asset_collection(asset_data: &[u8]) -> Result<Option<Pubkey>, Error> {
+    match Asset::from_bytes(asset_data)?.base.update_authority {
+        UpdateAuthority::Collection(collection) => Ok(Some(collection)),
+        _ => Ok(None),
+    }
+}
+
+/// Resolve the effective identity of an asset from its account data, the
+/// data of its agent identity PDA and, for inherited identities, the data of
+/// its collection.
+///
+/// Returns `None` if the asset has no agent identity registered.
+pub fn resolve_effective_identity(
+    asset_data: &[u8],
+    agent_identity_data: Option<&[u8]>,
+    collection_data: Option<&[u8]>,
+) -> Result<Option<EffectiveIdentity>, Error> {
+    let Some(agent_identity_data) = agent_identity_data else {
+        return Ok(None);
+    };
+    match agent_identity_data.first() {
+        Some(key) if *key == Key::AgentIdentityV1 as u8 || *key == Key::AgentIdentityV2 as u8 => {}
+        _ => return Ok(None),
+    }
+
+    let asset = Asset::from_bytes(asset_data)?;
+
+    if !inherits_collection_identity(agent_identity_data) {
+        return Ok(asset
+            .external_plugin_adapter_list
+            .agent_identities
+            .first()
+            .map(|identity| EffectiveIdentity::Asset {
+                registration_uri: identity.uri.clone(),
+            }));
+    }
+
+    let UpdateAuthority::Collection(collection) = asset.base.update_authority else {
+        return Err(Error::new(
+            ErrorKind::InvalidData,
+            "inherited agent identity on an asset without a collection",
+        ));
+    };
+    let collection_data = collection_data.ok_or_else(|| {
+        Error::new(
+            ErrorKind::InvalidInput,
+            "collection data is required to resolve an inherited agent identity",
+        )
+    })?;
+
+    Ok(Collection::from_bytes(collection_data)?
+        .external_plugin_adapter_list
+        .agent_identities
+        .first()
+        .map(|identity| EffectiveIdentity::Collection {
+            collection,
+            registration_uri: identity.uri.clone(),
+        }))
+}
+
+#[cfg(feature = "rpc")]
+mod rpc {
+    use solana_program::pubkey::Pubkey;
+    use solana_rpc_client::rpc_client::RpcClient;
+    use solana_rpc_client_api::client_error::{Error as ClientError, Result as ClientResult};
+
+    use super::{
+        asset_collection, inherits_collection_identity, resolve_effective_identity,
+        EffectiveIdentity,
+    };
+    use crate::accounts::AgentIdentityV2;
+
+    /// Fetch the accounts of `asset` and resolve its effective identity.
+    pub fn fetch_effective_identity(
+        rpc: &RpcClient,
+        asset: &Pubkey,
+    ) -> ClientResult<Option<EffectiveIdentity>> {
+        let (agent_identity, _) = AgentIdentityV2::find_pda(asset);
+        let accounts = rpc.get_multiple_accounts(&[*asset, agent_identity])?;
+
+        let Some(asset_account) = &accounts[0] else {
+            return Ok(None);
+        };
+        let agent_identity_data = accounts[1].as_ref().map(|account| account.data.as_slice());
+
+        // Only inherited identities need the collection.
+        let collection_account = match agent_identity_data {
+            Some(data) if inherits_collection_identity(data) => {
+                match asset_collection(&asset_account.data).map_err(ClientError::from)? {
+                    Some(collection) => Some(rpc.get_account(&collection)?),
+                    None => None,
+                }
+            }
+            _ => None,
+        };
+
+        resolve_effective_identity(
+            &asset_account.data,
+            agent_identity_data,
+            collection_account
+                .as_ref()
+                .map(|account| account.data.as_slice()),
+        )
+        .map_err(ClientError::from)
+    }
+}
+
+#[cfg(feature = "rpc")]
+pub use rpc::*;
@@ -5,6 +5,7 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+use crate::generated::types::AgentTokenSource;
 use crate::generated::types::Key;
 #[cfg(feature = "anchor")]
 use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
@@ -24,7 +25,11 @@ pub struct AgentIdentityV2 {
     )]
     pub asset: Pubkey,
     pub agent_token: Option<Pubkey>,
-    pub reserved: [u8; 32],
+    pub agent_token_source: AgentTokenSource,
+    pub padding1: [u8; 3],
+    pub agent_token_timelock: u32,
+    pub inherits_collection_identity: bool,
+    pub reserved: [u8; 23],
 }
 
 const AGENT_IDENTITY_V2_AGENT_TOKEN_FIXED_SIZE_OPTION_SENTINEL: [u8; 32] = [
@@ -44,6 +49,10 @@ impl BorshSerialize for AgentIdentityV2 {
                 &AGENT_IDENTITY_V2_AGENT_TOKEN_FIXED_SIZE_OPTION_SENTINEL,
             )?,
         };
+        BorshSerialize::serialize(&self.agent_token_source, writer)?;
+        BorshSerialize::serialize(&self.padding1, writer)?;
+        BorshSerialize::serialize(&self.agent_token_timelock, writer)?;
+        BorshSerialize::serialize(&self.inherits_collection_identity, writer)?;
         BorshSerialize::serialize(&self.reserved, writer)?;
         Ok(())
     }
@@ -65,6 +74,10 @@ impl BorshDeserialize for AgentIdentityV2 {
                 Some(BorshDeserialize::deserialize(&mut slice)?)
             }
         };
+        let agent_token_source = BorshDeserialize::deserialize_reader(reader)?;
+        let padding1 = BorshDeserialize::deserialize_reader(reader)?;
+        let agent_token_timelock = BorshDeserialize::deserialize_reader(reader)?;
+        let inherits_collection_identity = BorshDeserialize::deserialize_reader(reader)?;
         let reserved = BorshDeserialize::deserialize_reader(reader)?;
         Ok(Self {
             key,
@@ -72,6 +85,10 @@ impl BorshDeserialize for AgentIdentityV2 {
             padding,
             asset,
             agent_token,
+            agent_token_source,
+            padding1,
+            agent_token_timelock,
+            inherits_collection_identity,
             reserved,
         })
     }
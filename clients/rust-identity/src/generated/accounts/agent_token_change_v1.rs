@@ -0,0 +1,147 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::AgentTokenChange;
+use crate::generated::types::Key;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AgentTokenChangeV1 {
+    pub key: Key,
+    pub bump: u8,
+    pub change: AgentTokenChange,
+    pub padding: [u8; 5],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub asset: Pubkey,
+    pub new_agent_token: Option<Pubkey>,
+    pub unlock_at: i64,
+    pub reserved: [u8; 32],
+}
+
+const AGENT_TOKEN_CHANGE_V1_NEW_AGENT_TOKEN_FIXED_SIZE_OPTION_SENTINEL: [u8; 32] = [
+    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
+];
+
+impl BorshSerialize for AgentTokenChangeV1 {
+    fn serialize<W: borsh::io::Write>(&self, writer: &mut W) -> borsh::io::Result<()> {
+        BorshSerialize::serialize(&self.key, writer)?;
+        BorshSerialize::serialize(&self.bump, writer)?;
+        BorshSerialize::serialize(&self.change, writer)?;
+        BorshSerialize::serialize(&self.padding, writer)?;
+        BorshSerialize::serialize(&self.asset, writer)?;
+        match &self.new_agent_token {
+            Some(value) => BorshSerialize::serialize(value, writer)?,
+            None => borsh::io::Write::write_all(
+                writer,
+                &AGENT_TOKEN_CHANGE_V1_NEW_AGENT_TOKEN_FIXED_SIZE_OPTION_SENTINEL,
+            )?,
+        };
+        BorshSerialize::serialize(&self.unlock_at, writer)?;
+        BorshSerialize::serialize(&self.reserved, writer)?;
+        Ok(())
+    }
+}
+
+impl BorshDeserialize for AgentTokenChangeV1 {
+    fn deserialize_reader<R: borsh::io::Read>(reader: &mut R) -> borsh::io::Result<Self> {
+        let key = BorshDeserialize::deserialize_reader(reader)?;
+        let bump = BorshDeserialize::deserialize_reader(reader)?;
+        let change = BorshDeserialize::deserialize_reader(reader)?;
+        let padding = BorshDeserialize::deserialize_reader(reader)?;
+        let asset = BorshDeserialize::deserialize_reader(reader)?;
+        let new_agent_token = {
+            let mut buffer = [0u8; 32];
+            borsh::io::Read::read_exact(reader, &mut buffer)?;
+            if buffer == AGENT_TOKEN_CHANGE_V1_NEW_AGENT_TOKEN_FIXED_SIZE_OPTION_SENTINEL {
+                None
+            } else {
+                let mut slice: &[u8] = &buffer;
+                Some(BorshDeserialize::deserialize(&mut slice)?)
+            }
+        };
+        let unlock_at = BorshDeserialize::deserialize_reader(reader)?;
+        let reserved = BorshDeserialize::deserialize_reader(reader)?;
+        Ok(Self {
+            key,
+            bump,
+            change,
+            padding,
+            asset,
+            new_agent_token,
+            unlock_at,
+            reserved,
+        })
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::prelude::AnchorSerialize for AgentTokenChangeV1 {
+    fn serialize<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        BorshSerialize::serialize(self, writer)
+    }
+}
+
+#[cfg(feature = "anchor")]
+impl anchor_lang::prelude::AnchorDeserialize for AgentTokenChangeV1 {
+    fn deserialize_reader<R: std::io::Read>(reader: &mut R) -> std::io::Result<Self> {
+        BorshDeserialize::deserialize_reader(reader)
+    }
+}
+
+impl AgentTokenChangeV1 {
+    pub const LEN: usize = 112;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `AgentTokenChangeV1::PREFIX`
+    ///   1. asset (`Pubkey`)
+    pub const PREFIX: &'static [u8] = "agent_token_change".as_bytes();
+
+    pub fn create_pda(
+        asset: Pubkey,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &["agent_token_change".as_bytes(), asset.as_ref(), &[bump]],
+            &crate::MPL_AGENT_IDENTITY_ID,
+        )
+    }
+
+    pub fn find_pda(asset: &Pubkey) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &["agent_token_change".as_bytes(), asset.as_ref()],
+            &crate::MPL_AGENT_IDENTITY_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for AgentTokenChangeV1 {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
@@ -7,6 +7,10 @@
 
 pub(crate) mod r#agent_identity_v1;
 pub(crate) mod r#agent_identity_v2;
+pub(crate) mod r#agent_token_change_v1;
+pub(crate) mod r#collection_identity_v1;
 
 pub use self::r#agent_identity_v1::*;
 pub use self::r#agent_identity_v2::*;
+pub use self::r#agent_token_change_v1::*;
+pub use self::r#collection_identity_v1::*;
@@ -47,6 +47,81 @@ pub enum MplAgentIdentityError {
     /// 11 (0xB) - Genesis account is not mint-funded
     #[error("Genesis account is not mint-funded")]
     GenesisNotMintFunded,
+    /// 12 (0xC) - Only the asset owner or Asset Signer can update the registration URI
+    #[error("Only the asset owner or Asset Signer can update the registration URI")]
+    OnlyAssetOwnerOrSignerCanUpdateUri,
+    /// 13 (0xD) - Registration URI is empty
+    #[error("Registration URI is empty")]
+    RegistrationUriEmpty,
+    /// 14 (0xE) - Registration URI exceeds the maximum length
+    #[error("Registration URI exceeds the maximum length")]
+    RegistrationUriTooLong,
+    /// 15 (0xF) - Registration URI is not valid UTF-8
+    #[error("Registration URI is not valid UTF-8")]
+    RegistrationUriNotUtf8,
+    /// 16 (0x10) - Registration URI scheme is not allowed
+    #[error("Registration URI scheme is not allowed")]
+    RegistrationUriSchemeNotAllowed,
+    /// 17 (0x11) - Only the asset owner can deregister the agent identity
+    #[error("Only the asset owner can deregister the agent identity")]
+    OnlyAssetOwnerCanDeregister,
+    /// 18 (0x12) - Invalid Execution Delegate Count account
+    #[error("Invalid Execution Delegate Count account")]
+    InvalidExecutionDelegateCount,
+    /// 19 (0x13) - Agent still has active execution delegates
+    #[error("Agent still has active execution delegates")]
+    ExecutionDelegatesStillActive,
+    /// 20 (0x14) - Agent Identity is already an AgentIdentityV2
+    #[error("Agent Identity is already an AgentIdentityV2")]
+    AgentIdentityAlreadyMigrated,
+    /// 21 (0x15) - Invalid SPL Token or Token-2022 mint
+    #[error("Invalid SPL Token or Token-2022 mint")]
+    InvalidMint,
+    /// 22 (0x16) - Mint authority must be the asset signer or sign the instruction
+    #[error("Mint authority must be the asset signer or sign the instruction")]
+    MintAuthorityMismatch,
+    /// 23 (0x17) - Agent token is not set
+    #[error("Agent token is not set")]
+    AgentTokenNotSet,
+    /// 24 (0x18) - Agent token change is still time-locked
+    #[error("Agent token change is still time-locked")]
+    AgentTokenChangeLocked,
+    /// 25 (0x19) - Agent token timelock cannot be shortened while an agent token is set
+    #[error("Agent token timelock cannot be shortened while an agent token is set")]
+    AgentTokenTimelockDecrease,
+    /// 26 (0x1A) - Invalid lifecycle checks
+    #[error("Invalid lifecycle checks")]
+    InvalidLifecycleChecks,
+    /// 27 (0x1B) - Only the asset owner or Asset Signer can update the lifecycle checks
+    #[error("Only the asset owner or Asset Signer can update the lifecycle checks")]
+    OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks,
+    /// 28 (0x1C) - Invalid Core Collection
+    #[error("Invalid Core Collection")]
+    InvalidCoreCollection,
+    /// 29 (0x1D) - Collection identity is not registered
+    #[error("Collection identity is not registered")]
+    CollectionIdentityNotRegistered,
+    /// 30 (0x1E) - Asset does not belong to the collection
+    #[error("Asset does not belong to the collection")]
+    AssetNotInCollection,
+    /// 31 (0x1F) - Only the asset owner or Asset Signer can inherit the collection identity
+    #[error("Only the asset owner or Asset Signer can inherit the collection identity")]
+    OnlyAssetOwnerOrSignerCanInheritIdentity,
+    /// 32 (0x20) - Agent identity is inherited from the collection
+    #[error("Agent identity is inherited from the collection")]
+    AgentIdentityInheritedFromCollection,
+    /// 33 (0x21) - Batch accounts must be non-empty (agent identity, asset) pairs
+    #[error("Batch accounts must be non-empty (agent identity, asset) pairs")]
+    InvalidBatchAccounts,
+    /// 34 (0x22) - Registration URI table must have one URI or one per asset
+    #[error("Registration URI table must have one URI or one per asset")]
+    InvalidRegistrationUriTable,
+    /// 35 (0x23) - Execution delegates are not all counted yet
+    #[error("Execution delegates are not all counted yet")]
+    ExecutionDelegateMigrationIncomplete,
+    /// 36 (0x24) - Invalid Agent Token Change account
+    #[error("Invalid Agent Token Change account")]
+    InvalidAgentTokenChange,
 }
 
 impl From<MplAgentIdentityError> for ProgramError {
@@ -71,6 +146,31 @@ impl TryFrom<u32> for MplAgentIdentityError {
             9 => Ok(MplAgentIdentityError::AgentIdentityAlreadyRegistered),
             10 => Ok(MplAgentIdentityError::InvalidGenesisAccount),
             11 => Ok(MplAgentIdentityError::GenesisNotMintFunded),
+            12 => Ok(MplAgentIdentityError::OnlyAssetOwnerOrSignerCanUpdateUri),
+            13 => Ok(MplAgentIdentityError::RegistrationUriEmpty),
+            14 => Ok(MplAgentIdentityError::RegistrationUriTooLong),
+            15 => Ok(MplAgentIdentityError::RegistrationUriNotUtf8),
+            16 => Ok(MplAgentIdentityError::RegistrationUriSchemeNotAllowed),
+            17 => Ok(MplAgentIdentityError::OnlyAssetOwnerCanDeregister),
+            18 => Ok(MplAgentIdentityError::InvalidExecutionDelegateCount),
+            19 => Ok(MplAgentIdentityError::ExecutionDelegatesStillActive),
+            20 => Ok(MplAgentIdentityError::AgentIdentityAlreadyMigrated),
+            21 => Ok(MplAgentIdentityError::InvalidMint),
+            22 => Ok(MplAgentIdentityError::MintAuthorityMismatch),
+            23 => Ok(MplAgentIdentityError::AgentTokenNotSet),
+            24 => Ok(MplAgentIdentityError::AgentTokenChangeLocked),
+            25 => Ok(MplAgentIdentityError::AgentTokenTimelockDecrease),
+            26 => Ok(MplAgentIdentityError::InvalidLifecycleChecks),
+            27 => Ok(MplAgentIdentityError::OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks),
+            28 => Ok(MplAgentIdentityError::InvalidCoreCollection),
+            29 => Ok(MplAgentIdentityError::CollectionIdentityNotRegistered),
+            30 => Ok(MplAgentIdentityError::AssetNotInCollection),
+            31 => Ok(MplAgentIdentityError::OnlyAssetOwnerOrSignerCanInheritIdentity),
+            32 => Ok(MplAgentIdentityError::AgentIdentityInheritedFromCollection),
+            33 => Ok(MplAgentIdentityError::InvalidBatchAccounts),
+            34 => Ok(MplAgentIdentityError::InvalidRegistrationUriTable),
+            35 => Ok(MplAgentIdentityError::ExecutionDelegateMigrationIncomplete),
+            36 => Ok(MplAgentIdentityError::InvalidAgentTokenChange),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -95,6 +195,67 @@ impl ToStr for MplAgentIdentityError {
             }
             MplAgentIdentityError::InvalidGenesisAccount => "Invalid Genesis Account",
             MplAgentIdentityError::GenesisNotMintFunded => "Genesis account is not mint-funded",
+            MplAgentIdentityError::OnlyAssetOwnerOrSignerCanUpdateUri => {
+                "Only the asset owner or Asset Signer can update the registration URI"
+            }
+            MplAgentIdentityError::RegistrationUriEmpty => "Registration URI is empty",
+            MplAgentIdentityError::RegistrationUriTooLong => {
+                "Registration URI exceeds the maximum length"
+            }
+            MplAgentIdentityError::RegistrationUriNotUtf8 => "Registration URI is not valid UTF-8",
+            MplAgentIdentityError::RegistrationUriSchemeNotAllowed => {
+                "Registration URI scheme is not allowed"
+            }
+            MplAgentIdentityError::OnlyAssetOwnerCanDeregister => {
+                "Only the asset owner can deregister the agent identity"
+            }
+            MplAgentIdentityError::InvalidExecutionDelegateCount => {
+                "Invalid Execution Delegate Count account"
+            }
+            MplAgentIdentityError::ExecutionDelegatesStillActive => {
+                "Agent still has active execution delegates"
+            }
+            MplAgentIdentityError::AgentIdentityAlreadyMigrated => {
+                "Agent Identity is already an AgentIdentityV2"
+            }
+            MplAgentIdentityError::InvalidMint => "Invalid SPL Token or Token-2022 mint",
+            MplAgentIdentityError::MintAuthorityMismatch => {
+                "Mint authority must be the asset signer or sign the instruction"
+            }
+            MplAgentIdentityError::AgentTokenNotSet => "Agent token is not set",
+            MplAgentIdentityError::AgentTokenChangeLocked => {
+                "Agent token change is still time-locked"
+            }
+            MplAgentIdentityError::AgentTokenTimelockDecrease => {
+                "Agent token timelock cannot be shortened while an agent token is set"
+            }
+            MplAgentIdentityError::InvalidLifecycleChecks => "Invalid lifecycle checks",
+            MplAgentIdentityError::OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks => {
+                "Only the asset owner or Asset Signer can update the lifecycle checks"
+            }
+            MplAgentIdentityError::InvalidCoreCollection => "Invalid Core Collection",
+            MplAgentIdentityError::CollectionIdentityNotRegistered => {
+                "Collection identity is not registered"
+            }
+            MplAgentIdentityError::AssetNotInCollection => {
+                "Asset does not belong to the collection"
+            }
+            MplAgentIdentityError::OnlyAssetOwnerOrSignerCanInheritIdentity => {
+                "Only the asset owner or Asset Signer can inherit the collection identity"
+            }
+            MplAgentIdentityError::AgentIdentityInheritedFromCollection => {
+                "Agent identity is inherited from the collection"
+            }
+            MplAgentIdentityError::InvalidBatchAccounts => {
+                "Batch accounts must be non-empty (agent identity, asset) pairs"
+            }
+            MplAgentIdentityError::InvalidRegistrationUriTable => {
+                "Registration URI table must have one URI or one per asset"
+            }
+            MplAgentIdentityError::ExecutionDelegateMigrationIncomplete => {
+                "Execution delegates are not all counted yet"
+            }
+            MplAgentIdentityError::InvalidAgentTokenChange => "Invalid Agent Token Change account",
         }
     }
 }
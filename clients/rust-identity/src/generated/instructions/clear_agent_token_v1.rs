@@ -0,0 +1,510 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct ClearAgentTokenV1 {
+    /// The agent identity PDA. Must be of type AgentIdentityV2.
+    pub agent_identity: solana_program::pubkey::Pubkey,
+    /// The agent token change PDA holding the scheduled change
+    pub agent_token_change: solana_program::pubkey::Pubkey,
+    /// The address of the Core asset
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The payer for the agent token change account
+    pub payer: solana_program::pubkey::Pubkey,
+    /// Authority must be the asset signer. If not provided, the payer will be used.
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl ClearAgentTokenV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.agent_identity,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.agent_token_change,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_IDENTITY_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(ClearAgentTokenV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_IDENTITY_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct ClearAgentTokenV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl ClearAgentTokenV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 7,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+/// Instruction builder for `ClearAgentTokenV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_identity
+///   1. `[writable]` agent_token_change
+///   2. `[]` asset
+///   3. `[writable, signer]` payer
+///   4. `[signer, optional]` authority
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct ClearAgentTokenV1Builder {
+    agent_identity: Option<solana_program::pubkey::Pubkey>,
+    agent_token_change: Option<solana_program::pubkey::Pubkey>,
+    asset: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl ClearAgentTokenV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The agent identity PDA. Must be of type AgentIdentityV2.
+    #[inline(always)]
+    pub fn agent_identity(&mut self, agent_identity: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.agent_identity = Some(agent_identity);
+        self
+    }
+    /// The agent token change PDA holding the scheduled change
+    #[inline(always)]
+    pub fn agent_token_change(
+        &mut self,
+        agent_token_change: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.agent_token_change = Some(agent_token_change);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// The payer for the agent token change account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// Authority must be the asset signer. If not provided, the payer will be used.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = ClearAgentTokenV1 {
+            agent_identity: self.agent_identity.expect("agent_identity is not set"),
+            agent_token_change: self
+                .agent_token_change
+                .expect("agent_token_change is not set"),
+            asset: self.asset.expect("asset is not set"),
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority,
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `clear_agent_token_v1` CPI accounts.
+pub struct ClearAgentTokenV1CpiAccounts<'a, 'b> {
+    /// The agent identity PDA. Must be of type AgentIdentityV2.
+    pub agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent token change PDA holding the scheduled change
+    pub agent_token_change: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for the agent token change account
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Authority must be the asset signer. If not provided, the payer will be used.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `clear_agent_token_v1` CPI instruction.
+pub struct ClearAgentTokenV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent identity PDA. Must be of type AgentIdentityV2.
+    pub agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent token change PDA holding the scheduled change
+    pub agent_token_change: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for the agent token change account
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Authority must be the asset signer. If not provided, the payer will be used.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> ClearAgentTokenV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: ClearAgentTokenV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            agent_identity: accounts.agent_identity,
+            agent_token_change: accounts.agent_token_change,
+            asset: accounts.asset,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.agent_identity.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.agent_token_change.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_IDENTITY_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(ClearAgentTokenV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_IDENTITY_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.agent_identity.clone());
+        account_infos.push(self.agent_token_change.clone());
+        account_infos.push(self.asset.clone());
+        account_infos.push(self.payer.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `ClearAgentTokenV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_identity
+///   1. `[writable]` agent_token_change
+///   2. `[]` asset
+///   3. `[writable, signer]` payer
+///   4. `[signer, optional]` authority
+///   5. `[]` system_program
+pub struct ClearAgentTokenV1CpiBuilder<'a, 'b> {
+    instruction: Box<ClearAgentTokenV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> ClearAgentTokenV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(ClearAgentTokenV1CpiBuilderInstruction {
+            __program: program,
+            agent_identity: None,
+            agent_token_change: None,
+            asset: None,
+            payer: None,
+            authority: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The agent identity PDA. Must be of type AgentIdentityV2.
+    #[inline(always)]
+    pub fn agent_identity(
+        &mut self,
+        agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_identity = Some(agent_identity);
+        self
+    }
+    /// The agent token change PDA holding the scheduled change
+    #[inline(always)]
+    pub fn agent_token_change(
+        &mut self,
+        agent_token_change: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_token_change = Some(agent_token_change);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// The payer for the agent token change account
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// Authority must be the asset signer. If not provided, the payer will be used.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = ClearAgentTokenV1Cpi {
+            __program: self.instruction.__program,
+
+            agent_identity: self
+                .instruction
+                .agent_identity
+                .expect("agent_identity is not set"),
+
+            agent_token_change: self
+                .instruction
+                .agent_token_change
+                .expect("agent_token_change is not set"),
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority,
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct ClearAgentTokenV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    agent_identity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    agent_token_change: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
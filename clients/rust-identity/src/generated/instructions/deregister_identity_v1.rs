@@ -0,0 +1,703 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct DeregisterIdentityV1 {
+    /// The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.
+    pub agent_identity: solana_program::pubkey::Pubkey,
+    /// The address of the Core asset
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The address of the collection
+    pub collection: Option<solana_program::pubkey::Pubkey>,
+    /// The mpl-agent-tools execution delegate count PDA for the asset
+    pub execution_delegate_count: solana_program::pubkey::Pubkey,
+    /// The mpl-agent-tools execution delegate migration PDA
+    pub execution_delegate_migration: solana_program::pubkey::Pubkey,
+    /// The destination for the reclaimed rent
+    pub destination: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// Authority must be the asset owner. If not provided, the payer will be used.
+    pub authority: Option<solana_program::pubkey::Pubkey>,
+    /// The MPL Core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl DeregisterIdentityV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.agent_identity,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.asset, false,
+        ));
+        if let Some(collection) = self.collection {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                collection, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_IDENTITY_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.execution_delegate_count,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.execution_delegate_migration,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                authority, true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_IDENTITY_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(DeregisterIdentityV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_IDENTITY_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct DeregisterIdentityV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl DeregisterIdentityV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 3,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+/// Instruction builder for `DeregisterIdentityV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_identity
+///   1. `[writable]` asset
+///   2. `[writable, optional]` collection
+///   3. `[]` execution_delegate_count
+///   4. `[]` execution_delegate_migration
+///   5. `[writable]` destination
+///   6. `[writable, signer]` payer
+///   7. `[signer, optional]` authority
+///   8. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   9. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct DeregisterIdentityV1Builder {
+    agent_identity: Option<solana_program::pubkey::Pubkey>,
+    asset: Option<solana_program::pubkey::Pubkey>,
+    collection: Option<solana_program::pubkey::Pubkey>,
+    execution_delegate_count: Option<solana_program::pubkey::Pubkey>,
+    execution_delegate_migration: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl DeregisterIdentityV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.
+    #[inline(always)]
+    pub fn agent_identity(&mut self, agent_identity: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.agent_identity = Some(agent_identity);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the collection
+    #[inline(always)]
+    pub fn collection(&mut self, collection: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.collection = collection;
+        self
+    }
+    /// The mpl-agent-tools execution delegate count PDA for the asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
+    /// The mpl-agent-tools execution delegate migration PDA
+    #[inline(always)]
+    pub fn execution_delegate_migration(
+        &mut self,
+        execution_delegate_migration: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.execution_delegate_migration = Some(execution_delegate_migration);
+        self
+    }
+    /// The destination for the reclaimed rent
+    #[inline(always)]
+    pub fn destination(&mut self, destination: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// Authority must be the asset owner. If not provided, the payer will be used.
+    #[inline(always)]
+    pub fn authority(&mut self, authority: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.authority = authority;
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = DeregisterIdentityV1 {
+            agent_identity: self.agent_identity.expect("agent_identity is not set"),
+            asset: self.asset.expect("asset is not set"),
+            collection: self.collection,
+            execution_delegate_count: self
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
+            execution_delegate_migration: self
+                .execution_delegate_migration
+                .expect("execution_delegate_migration is not set"),
+            destination: self.destination.expect("destination is not set"),
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority,
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `deregister_identity_v1` CPI accounts.
+pub struct DeregisterIdentityV1CpiAccounts<'a, 'b> {
+    /// The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.
+    pub agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the collection
+    pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The mpl-agent-tools execution delegate count PDA for the asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl-agent-tools execution delegate migration PDA
+    pub execution_delegate_migration: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The destination for the reclaimed rent
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Authority must be the asset owner. If not provided, the payer will be used.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `deregister_identity_v1` CPI instruction.
+pub struct DeregisterIdentityV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.
+    pub agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the collection
+    pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The mpl-agent-tools execution delegate count PDA for the asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl-agent-tools execution delegate migration PDA
+    pub execution_delegate_migration: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The destination for the reclaimed rent
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Authority must be the asset owner. If not provided, the payer will be used.
+    pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> DeregisterIdentityV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: DeregisterIdentityV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            agent_identity: accounts.agent_identity,
+            asset: accounts.asset,
+            collection: accounts.collection,
+            execution_delegate_count: accounts.execution_delegate_count,
+            execution_delegate_migration: accounts.execution_delegate_migration,
+            destination: accounts.destination,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            mpl_core_program: accounts.mpl_core_program,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(10 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.agent_identity.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.asset.key,
+            false,
+        ));
+        if let Some(collection) = self.collection {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *collection.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_IDENTITY_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.execution_delegate_count.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.execution_delegate_migration.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        if let Some(authority) = self.authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *authority.key,
+                true,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_IDENTITY_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(DeregisterIdentityV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_IDENTITY_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(10 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.agent_identity.clone());
+        account_infos.push(self.asset.clone());
+        if let Some(collection) = self.collection {
+            account_infos.push(collection.clone());
+        }
+        account_infos.push(self.execution_delegate_count.clone());
+        account_infos.push(self.execution_delegate_migration.clone());
+        account_infos.push(self.destination.clone());
+        account_infos.push(self.payer.clone());
+        if let Some(authority) = self.authority {
+            account_infos.push(authority.clone());
+        }
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `DeregisterIdentityV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_identity
+///   1. `[writable]` asset
+///   2. `[writable, optional]` collection
+///   3. `[]` execution_delegate_count
+///   4. `[]` execution_delegate_migration
+///   5. `[writable]` destination
+///   6. `[writable, signer]` payer
+///   7. `[signer, optional]` authority
+///   8. `[]` mpl_core_program
+///   9. `[]` system_program
+pub struct DeregisterIdentityV1CpiBuilder<'a, 'b> {
+    instruction: Box<DeregisterIdentityV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> DeregisterIdentityV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(DeregisterIdentityV1CpiBuilderInstruction {
+            __program: program,
+            agent_identity: None,
+            asset: None,
+            collection: None,
+            execution_delegate_count: None,
+            execution_delegate_migration: None,
+            destination: None,
+            payer: None,
+            authority: None,
+            mpl_core_program: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.
+    #[inline(always)]
+    pub fn agent_identity(
+        &mut self,
+        agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_identity = Some(agent_identity);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the collection
+    #[inline(always)]
+    pub fn collection(
+        &mut self,
+        collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.collection = collection;
+        self
+    }
+    /// The mpl-agent-tools execution delegate count PDA for the asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
+    /// The mpl-agent-tools execution delegate migration PDA
+    #[inline(always)]
+    pub fn execution_delegate_migration(
+        &mut self,
+        execution_delegate_migration: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.execution_delegate_migration = Some(execution_delegate_migration);
+        self
+    }
+    /// The destination for the reclaimed rent
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination = Some(destination);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// `[optional account]`
+    /// Authority must be the asset owner. If not provided, the payer will be used.
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.authority = authority;
+        self
+    }
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = DeregisterIdentityV1Cpi {
+            __program: self.instruction.__program,
+
+            agent_identity: self
+                .instruction
+                .agent_identity
+                .expect("agent_identity is not set"),
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            collection: self.instruction.collection,
+
+            execution_delegate_count: self
+                .instruction
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
+
+            execution_delegate_migration: self
+                .instruction
+                .execution_delegate_migration
+                .expect("execution_delegate_migration is not set"),
+
+            destination: self
+                .instruction
+                .destination
+                .expect("destination is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority,
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct DeregisterIdentityV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    agent_identity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    execution_delegate_count: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    execution_delegate_migration: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -5,8 +5,30 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#clear_agent_token_v1;
+pub(crate) mod r#deregister_identity_v1;
+pub(crate) mod r#inherit_collection_identity_v1;
+pub(crate) mod r#migrate_identity_v1_to_v2;
+pub(crate) mod r#register_collection_identity_v1;
+pub(crate) mod r#register_identities_v1;
 pub(crate) mod r#register_identity_v1;
+pub(crate) mod r#replace_agent_token_v1;
+pub(crate) mod r#set_agent_token_from_mint_v1;
+pub(crate) mod r#set_agent_token_timelock_v1;
 pub(crate) mod r#set_agent_token_v1;
+pub(crate) mod r#update_agent_registration_uri_v1;
+pub(crate) mod r#update_identity_lifecycle_checks_v1;
 
+pub use self::r#clear_agent_token_v1::*;
+pub use self::r#deregister_identity_v1::*;
+pub use self::r#inherit_collection_identity_v1::*;
+pub use self::r#migrate_identity_v1_to_v2::*;
+pub use self::r#register_collection_identity_v1::*;
+pub use self::r#register_identities_v1::*;
 pub use self::r#register_identity_v1::*;
+pub use self::r#replace_agent_token_v1::*;
+pub use self::r#set_agent_token_from_mint_v1::*;
+pub use self::r#set_agent_token_timelock_v1::*;
 pub use self::r#set_agent_token_v1::*;
+pub use self::r#update_agent_registration_uri_v1::*;
+pub use self::r#update_identity_lifecycle_checks_v1::*;
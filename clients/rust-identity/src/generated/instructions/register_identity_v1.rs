@@ -97,14 +97,14 @@ impl RegisterIdentityV1 {
 #[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
 pub struct RegisterIdentityV1InstructionData {
     discriminator: u8,
-    padding: [u8; 7],
+    padding: [u8; 3],
 }
 
 impl RegisterIdentityV1InstructionData {
     pub fn new() -> Self {
         Self {
             discriminator: 0,
-            padding: [0, 0, 0, 0, 0, 0, 0],
+            padding: [0, 0, 0],
         }
     }
 }
@@ -114,6 +114,7 @@ impl RegisterIdentityV1InstructionData {
 #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, Eq, PartialEq)]
 pub struct RegisterIdentityV1InstructionArgs {
+    pub lifecycle_checks: u32,
     pub agent_registration_uri: String,
 }
 
@@ -137,6 +138,7 @@ pub struct RegisterIdentityV1Builder {
     authority: Option<solana_program::pubkey::Pubkey>,
     mpl_core_program: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    lifecycle_checks: Option<u32>,
     agent_registration_uri: Option<String>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
@@ -194,6 +196,12 @@ impl RegisterIdentityV1Builder {
         self.system_program = Some(system_program);
         self
     }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn lifecycle_checks(&mut self, lifecycle_checks: u32) -> &mut Self {
+        self.lifecycle_checks = Some(lifecycle_checks);
+        self
+    }
     #[inline(always)]
     pub fn agent_registration_uri(&mut self, agent_registration_uri: String) -> &mut Self {
         self.agent_registration_uri = Some(agent_registration_uri);
@@ -233,6 +241,7 @@ impl RegisterIdentityV1Builder {
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
         };
         let args = RegisterIdentityV1InstructionArgs {
+            lifecycle_checks: self.lifecycle_checks.clone().unwrap_or(0),
             agent_registration_uri: self
                 .agent_registration_uri
                 .clone()
@@ -444,6 +453,7 @@ impl<'a, 'b> RegisterIdentityV1CpiBuilder<'a, 'b> {
             authority: None,
             mpl_core_program: None,
             system_program: None,
+            lifecycle_checks: None,
             agent_registration_uri: None,
             __remaining_accounts: Vec::new(),
         });
@@ -508,6 +518,12 @@ impl<'a, 'b> RegisterIdentityV1CpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn lifecycle_checks(&mut self, lifecycle_checks: u32) -> &mut Self {
+        self.instruction.lifecycle_checks = Some(lifecycle_checks);
+        self
+    }
     #[inline(always)]
     pub fn agent_registration_uri(&mut self, agent_registration_uri: String) -> &mut Self {
         self.instruction.agent_registration_uri = Some(agent_registration_uri);
@@ -555,6 +571,7 @@ impl<'a, 'b> RegisterIdentityV1CpiBuilder<'a, 'b> {
         signers_seeds: &[&[&[u8]]],
     ) -> solana_program::entrypoint::ProgramResult {
         let args = RegisterIdentityV1InstructionArgs {
+            lifecycle_checks: self.instruction.lifecycle_checks.clone().unwrap_or(0),
             agent_registration_uri: self
                 .instruction
                 .agent_registration_uri
@@ -604,6 +621,7 @@ struct RegisterIdentityV1CpiBuilderInstruction<'a, 'b> {
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    lifecycle_checks: Option<u32>,
     agent_registration_uri: Option<String>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
@@ -19,4 +19,6 @@ pub enum Key {
     Uninitialized,
     AgentIdentityV1,
     AgentIdentityV2,
+    CollectionIdentityV1,
+    AgentTokenChangeV1,
 }
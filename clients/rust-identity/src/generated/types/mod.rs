@@ -5,6 +5,12 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#agent_token_change;
+pub(crate) mod r#agent_token_source;
 pub(crate) mod r#key;
+pub(crate) mod r#mpl_agent_identity_event;
 
+pub use self::r#agent_token_change::*;
+pub use self::r#agent_token_source::*;
 pub use self::r#key::*;
+pub use self::r#mpl_agent_identity_event::*;
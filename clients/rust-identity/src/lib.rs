@@ -1,4 +1,10 @@
+pub mod batch;
+pub mod effective_identity;
+pub mod events;
+#[allow(unexpected_cfgs, clippy::new_without_default)]
 mod generated;
+pub mod lifecycle;
+pub mod migration;
 
 pub use generated::programs::MPL_AGENT_IDENTITY_ID as ID;
 pub use generated::*;
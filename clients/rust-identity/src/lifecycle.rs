@@ -0,0 +1,46 @@
+//! Bitmask values for the `lifecycle_checks` argument of `RegisterIdentityV1`
+//! and `UpdateIdentityLifecycleChecksV1`.
+//!
+//! Each configurable lifecycle event owns three bits, `[listen, approve,
+//! reject]`, starting at its shift. A mask of `0` selects
+//! [`DEFAULT_LIFECYCLE_CHECKS`].
+//!
+//! ```
+//! use mpl_agent_identity::lifecycle::*;
+//!
+//! // Listen to transfers, allow the plugin to reject burns.
+//! let mask = (LIFECYCLE_CHECK_LISTEN << LIFECYCLE_TRANSFER_SHIFT)
+//!     | ((LIFECYCLE_CHECK_LISTEN | LIFECYCLE_CHECK_REJECT) << LIFECYCLE_BURN_SHIFT);
+//! assert_eq!(mask & !VALID_LIFECYCLE_CHECKS, 0);
+//! ```
+
+/// The plugin is notified of the lifecycle event.
+pub const LIFECYCLE_CHECK_LISTEN: u32 = 1 << 0;
+
+/// The plugin may approve the lifecycle event.
+pub const LIFECYCLE_CHECK_APPROVE: u32 = 1 << 1;
+
+/// The plugin may reject the lifecycle event.
+pub const LIFECYCLE_CHECK_REJECT: u32 = 1 << 2;
+
+/// All checks for a single lifecycle event.
+pub const LIFECYCLE_CHECK_ALL: u32 =
+    LIFECYCLE_CHECK_LISTEN | LIFECYCLE_CHECK_APPROVE | LIFECYCLE_CHECK_REJECT;
+
+/// Bit offsets of each configurable lifecycle event in the mask.
+pub const LIFECYCLE_TRANSFER_SHIFT: u32 = 3;
+pub const LIFECYCLE_BURN_SHIFT: u32 = 6;
+pub const LIFECYCLE_UPDATE_SHIFT: u32 = 9;
+pub const LIFECYCLE_EXECUTE_SHIFT: u32 = 12;
+
+/// Every bit the program accepts in a lifecycle check mask.
+pub const VALID_LIFECYCLE_CHECKS: u32 = (LIFECYCLE_CHECK_ALL << LIFECYCLE_TRANSFER_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_BURN_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_UPDATE_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_EXECUTE_SHIFT);
+
+/// The policy the program applies when the mask is `0`: full checks on
+/// transfer, update and execute.
+pub const DEFAULT_LIFECYCLE_CHECKS: u32 = (LIFECYCLE_CHECK_ALL << LIFECYCLE_TRANSFER_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_UPDATE_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_EXECUTE_SHIFT);
@@ -0,0 +1,114 @@
+//! Helpers for migrating `AgentIdentityV1` accounts to `AgentIdentityV2`
+//! with the permissionless `MigrateIdentityV1ToV2` instruction.
+//!
+//! The instruction builders are always available. Enable the `rpc` feature
+//! to scan a cluster for V1 accounts and build the migration transactions.
+
+use solana_program::{instruction::Instruction, pubkey::Pubkey};
+
+use crate::{accounts::AgentIdentityV1, instructions::MigrateIdentityV1ToV2Builder, types::Key};
+
+/// Number of migrations packed into a single transaction. Each migration
+/// adds two unique accounts, so this stays well under the packet size limit.
+pub const MAX_MIGRATIONS_PER_TRANSACTION: usize = 10;
+
+/// Returns `true` if `data` is an `AgentIdentityV1` account.
+pub fn is_agent_identity_v1(data: &[u8]) -> bool {
+    data.len() == AgentIdentityV1::LEN && data[0] == Key::AgentIdentityV1 as u8
+}
+
+/// Build the `MigrateIdentityV1ToV2` instruction for the identity of `asset`.
+pub fn migrate_identity_instruction(asset: &Pubkey, payer: &Pubkey) -> Instruction {
+    MigrateIdentityV1ToV2Builder::new()
+        .agent_identity(AgentIdentityV1::find_pda(asset).0)
+        .asset(*asset)
+        .payer(*payer)
+        .instruction()
+}
+
+/// Build migration instructions for `assets`, grouped into batches of at
+/// most `batch_size` instructions. Each batch fits in one transaction as
+/// long as `batch_size` does not exceed [`MAX_MIGRATIONS_PER_TRANSACTION`].
+pub fn migration_instruction_batches(
+    assets: &[Pubkey],
+    payer: &Pubkey,
+    batch_size: usize,
+) -> Vec<Vec<Instruction>> {
+    assets
+        .chunks(batch_size.max(1))
+        .map(|chunk| {
+            chunk
+                .iter()
+                .map(|asset| migrate_identity_instruction(asset, payer))
+                .collect()
+        })
+        .collect()
+}
+
+#[cfg(feature = "rpc")]
+mod rpc {
+    use solana_account_decoder_client_types::UiAccountEncoding;
+    use solana_program::pubkey::Pubkey;
+    use solana_rpc_client::rpc_client::RpcClient;
+    use solana_rpc_client_api::{
+        client_error::Result as ClientResult,
+        config::{RpcAccountInfoConfig, RpcProgramAccountsConfig},
+        filter::{Memcmp, RpcFilterType},
+    };
+    use solana_transaction::Transaction;
+
+    use super::{migration_instruction_batches, MAX_MIGRATIONS_PER_TRANSACTION};
+    use crate::{accounts::AgentIdentityV1, types::Key};
+
+    /// Fetch every `AgentIdentityV1` account owned by the identity program.
+    pub fn fetch_agent_identity_v1_accounts(
+        rpc: &RpcClient,
+    ) -> ClientResult<Vec<(Pubkey, AgentIdentityV1)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(vec![
+                RpcFilterType::DataSize(AgentIdentityV1::LEN as u64),
+                RpcFilterType::Memcmp(Memcmp::new_raw_bytes(0, vec![Key::AgentIdentityV1 as u8])),
+            ]),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                ..RpcAccountInfoConfig::default()
+            },
+            ..RpcProgramAccountsConfig::default()
+        };
+
+        let accounts = rpc.get_program_ui_accounts_with_config(&crate::ID, config)?;
+
+        Ok(accounts
+            .into_iter()
+            .filter_map(|(address, account)| {
+                let data = account.data.decode()?;
+                AgentIdentityV1::from_bytes(&data)
+                    .ok()
+                    .map(|identity| (address, identity))
+            })
+            .collect())
+    }
+
+    /// Scan for `AgentIdentityV1` accounts and build unsigned transactions
+    /// migrating them, [`MAX_MIGRATIONS_PER_TRANSACTION`] per transaction.
+    /// The caller signs each transaction with `payer` and a recent blockhash.
+    pub fn build_migration_transactions(
+        rpc: &RpcClient,
+        payer: &Pubkey,
+    ) -> ClientResult<Vec<Transaction>> {
+        let assets = fetch_agent_identity_v1_accounts(rpc)?
+            .into_iter()
+            .map(|(_, identity)| identity.asset)
+            .collect::<Vec<_>>();
+
+        Ok(
+            migration_instruction_batches(&assets, payer, MAX_MIGRATIONS_PER_TRANSACTION)
+                .iter()
+                .map(|instructions| Transaction::new_with_payer(instructions, Some(payer)))
+                .collect(),
+        )
+    }
+}
+
+#[cfg(feature = "rpc")]
+pub use rpc::*;
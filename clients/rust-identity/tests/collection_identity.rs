@@ -0,0 +1,281 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::{AgentIdentityV2, CollectionIdentityV1},
+    effective_identity::{resolve_effective_identity, EffectiveIdentity},
+    errors::MplAgentIdentityError,
+    instructions::{
+        DeregisterIdentityV1Builder, InheritCollectionIdentityV1Builder,
+        UpdateAgentRegistrationUriV1Builder,
+    },
+    types::Key,
+};
+use mpl_core::{Asset, Collection};
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+fn inherit_ix(context: &ProgramTestContext, asset: Pubkey, collection: Pubkey) -> Instruction {
+    InheritCollectionIdentityV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .collection(collection)
+        .collection_identity(CollectionIdentityV1::find_pda(&collection).0)
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+async fn account_data(context: &mut ProgramTestContext, address: Pubkey) -> Option<Vec<u8>> {
+    context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .map(|account| account.data)
+}
+
+async fn effective_identity(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    collection: Pubkey,
+) -> Option<EffectiveIdentity> {
+    let asset_data = account_data(context, asset).await.unwrap();
+    let agent_identity_data = account_data(context, AgentIdentityV2::find_pda(&asset).0).await;
+    let collection_data = account_data(context, collection).await;
+
+    resolve_effective_identity(
+        &asset_data,
+        agent_identity_data.as_deref(),
+        collection_data.as_deref(),
+    )
+    .unwrap()
+}
+
+#[tokio::test]
+async fn register_collection_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, _) = setup::create_collection_and_asset(&mut context).await;
+    let collection_identity_pda =
+        setup::register_collection_identity(&mut context, collection).await;
+
+    // The collection identity PDA is initialized.
+    let data = account_data(&mut context, collection_identity_pda)
+        .await
+        .unwrap();
+    let collection_identity = CollectionIdentityV1::from_bytes(&data).unwrap();
+    assert_eq!(collection_identity.key, Key::CollectionIdentityV1);
+    assert_eq!(collection_identity.collection, collection);
+
+    // And the Agent Identity plugin is on the collection.
+    let data = account_data(&mut context, collection).await.unwrap();
+    let collection = Collection::from_bytes(&data).unwrap();
+    let agent_identities = &collection.external_plugin_adapter_list.agent_identities;
+    assert_eq!(agent_identities.len(), 1);
+    assert_eq!(agent_identities[0].uri, "https://example.com/operator.json");
+}
+
+#[tokio::test]
+async fn asset_can_inherit_collection_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_collection_identity(&mut context, collection).await;
+
+    // When the asset owner opts into the collection identity.
+    let ix = inherit_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    // Then the asset has an agent identity PDA that inherits.
+    let (agent_identity_pda, _) = AgentIdentityV2::find_pda(&asset);
+    let data = account_data(&mut context, agent_identity_pda)
+        .await
+        .unwrap();
+    let agent_identity = AgentIdentityV2::from_bytes(&data).unwrap();
+    assert_eq!(agent_identity.asset, asset);
+    assert!(agent_identity.inherits_collection_identity);
+
+    // But no plugin of its own.
+    let data = account_data(&mut context, asset).await.unwrap();
+    assert!(Asset::from_bytes(&data)
+        .unwrap()
+        .external_plugin_adapter_list
+        .agent_identities
+        .is_empty());
+
+    // And its effective identity is the collection's.
+    assert_eq!(
+        effective_identity(&mut context, asset, collection).await,
+        Some(EffectiveIdentity::Collection {
+            collection,
+            registration_uri: "https://example.com/operator.json".to_string(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn asset_identity_takes_precedence_over_collection_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_collection_identity(&mut context, collection).await;
+    setup::register_identity(&mut context, asset, collection).await;
+
+    assert_eq!(
+        effective_identity(&mut context, asset, collection).await,
+        Some(EffectiveIdentity::Asset {
+            registration_uri: "https://example.com/agent.json".to_string(),
+        })
+    );
+}
+
+#[tokio::test]
+async fn asset_without_identity_has_no_effective_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_collection_identity(&mut context, collection).await;
+
+    // Inheriting is opt-in.
+    assert_eq!(
+        effective_identity(&mut context, asset, collection).await,
+        None
+    );
+}
+
+#[tokio::test]
+async fn cannot_inherit_unregistered_collection_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    let ix = inherit_ix(&context, asset, collection);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::CollectionIdentityNotRegistered as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_inherit_identity_of_another_collection() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (_, asset) = setup::create_collection_and_asset(&mut context).await;
+    let (other_collection, _) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_collection_identity(&mut context, other_collection).await;
+
+    let ix = inherit_ix(&context, asset, other_collection);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::AssetNotInCollection as u32);
+}
+
+#[tokio::test]
+async fn non_owner_cannot_inherit_collection_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_collection_identity(&mut context, collection).await;
+
+    let attacker = Keypair::new();
+    let ix = InheritCollectionIdentityV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .collection(collection)
+        .collection_identity(CollectionIdentityV1::find_pda(&collection).0)
+        .payer(context.payer.pubkey())
+        .authority(Some(attacker.pubkey()))
+        .instruction();
+    let err = process(&mut context, ix, &[&attacker]).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::OnlyAssetOwnerOrSignerCanInheritIdentity as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_update_registration_uri_of_inherited_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_collection_identity(&mut context, collection).await;
+    let ix = inherit_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let ix = UpdateAgentRegistrationUriV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/agent-v2.json".to_string())
+        .instruction();
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::AgentIdentityInheritedFromCollection as u32,
+    );
+}
+
+#[tokio::test]
+async fn owner_can_deregister_inherited_identity() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_collection_identity(&mut context, collection).await;
+    let ix = inherit_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+    setup::complete_execution_delegate_migration(&mut context).await;
+
+    let (agent_identity_pda, _) = AgentIdentityV2::find_pda(&asset);
+    let ix = DeregisterIdentityV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .execution_delegate_count(setup::find_execution_delegate_count_pda(&asset))
+        .execution_delegate_migration(setup::find_execution_delegate_migration_pda())
+        .destination(context.payer.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    // The asset's PDA is closed while the collection keeps its plugin.
+    assert!(account_data(&mut context, agent_identity_pda)
+        .await
+        .is_none());
+    let data = account_data(&mut context, collection).await.unwrap();
+    assert_eq!(
+        Collection::from_bytes(&data)
+            .unwrap()
+            .external_plugin_adapter_list
+            .agent_identities
+            .len(),
+        1
+    );
+}
@@ -0,0 +1,250 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    errors::MplAgentIdentityError, instructions::DeregisterIdentityV1Builder,
+};
+use mpl_core::Asset;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+fn deregister_ix(
+    context: &ProgramTestContext,
+    agent_identity: Pubkey,
+    asset: Pubkey,
+    collection: Pubkey,
+    destination: Pubkey,
+) -> Instruction {
+    DeregisterIdentityV1Builder::new()
+        .agent_identity(agent_identity)
+        .asset(asset)
+        .collection(Some(collection))
+        .execution_delegate_count(setup::find_execution_delegate_count_pda(&asset))
+        .execution_delegate_migration(setup::find_execution_delegate_migration_pda())
+        .destination(destination)
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+async fn process_err(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> BanksClientError {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+}
+
+#[tokio::test]
+async fn owner_can_deregister_identity() {
+    let mut context = setup::setup().start_with_context().await;
+    setup::complete_execution_delegate_migration(&mut context).await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    let destination = Pubkey::new_unique();
+    let identity_lamports = context
+        .banks_client
+        .get_account(agent_identity_pda)
+        .await
+        .unwrap()
+        .unwrap()
+        .lamports;
+
+    // When the owner deregisters the identity.
+    let ix = deregister_ix(&context, agent_identity_pda, asset, collection, destination);
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the agent identity PDA is closed and its rent sent to the destination.
+    let account = context
+        .banks_client
+        .get_account(agent_identity_pda)
+        .await
+        .unwrap();
+    assert!(account.is_none(), "Agent identity should be closed");
+
+    let destination_account = context
+        .banks_client
+        .get_account(destination)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(destination_account.lamports, identity_lamports);
+
+    // And the Agent Identity plugin is removed from the asset.
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    let asset = Asset::from_bytes(&account.data).unwrap();
+    assert!(asset
+        .external_plugin_adapter_list
+        .agent_identities
+        .is_empty());
+}
+
+#[tokio::test]
+async fn owner_can_deregister_after_all_delegates_revoked() {
+    let mut context = setup::setup().start_with_context().await;
+    setup::complete_execution_delegate_migration(&mut context).await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // The asset was delegated before, but every delegate has been revoked.
+    setup::create_execution_delegate_count(&mut context, asset, 0).await;
+
+    let ix = deregister_ix(
+        &context,
+        agent_identity_pda,
+        asset,
+        collection,
+        context.payer.pubkey(),
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(agent_identity_pda)
+        .await
+        .unwrap();
+    assert!(account.is_none(), "Agent identity should be closed");
+}
+
+#[tokio::test]
+async fn cannot_deregister_with_active_execution_delegates() {
+    let mut context = setup::setup().start_with_context().await;
+    setup::complete_execution_delegate_migration(&mut context).await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // The asset still has an open execution delegate record in mpl-agent-tools.
+    setup::create_execution_delegate_count(&mut context, asset, 1).await;
+
+    let ix = deregister_ix(
+        &context,
+        agent_identity_pda,
+        asset,
+        collection,
+        context.payer.pubkey(),
+    );
+    let err = process_err(&mut context, ix, &[]).await;
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::ExecutionDelegatesStillActive as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_deregister_before_execution_delegates_are_counted() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // Records delegated before delegations were counted may still exist.
+    let ix = deregister_ix(
+        &context,
+        agent_identity_pda,
+        asset,
+        collection,
+        context.payer.pubkey(),
+    );
+    let err = process_err(&mut context, ix, &[]).await;
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::ExecutionDelegateMigrationIncomplete as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_deregister_with_wrong_execution_delegate_count() {
+    let mut context = setup::setup().start_with_context().await;
+    setup::complete_execution_delegate_migration(&mut context).await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    let mut ix = deregister_ix(
+        &context,
+        agent_identity_pda,
+        asset,
+        collection,
+        context.payer.pubkey(),
+    );
+    // Point the count account at some unrelated address.
+    ix.accounts[3].pubkey = Pubkey::new_unique();
+
+    let err = process_err(&mut context, ix, &[]).await;
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::InvalidExecutionDelegateCount as u32,
+    );
+}
+
+#[tokio::test]
+async fn non_owner_cannot_deregister_identity() {
+    let mut context = setup::setup().start_with_context().await;
+    setup::complete_execution_delegate_migration(&mut context).await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // A random wallet that does not own the asset.
+    let attacker = Keypair::new();
+
+    let ix = DeregisterIdentityV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .execution_delegate_count(setup::find_execution_delegate_count_pda(&asset))
+        .execution_delegate_migration(setup::find_execution_delegate_migration_pda())
+        .destination(attacker.pubkey())
+        .payer(context.payer.pubkey())
+        .authority(Some(attacker.pubkey()))
+        .instruction();
+
+    let err = process_err(&mut context, ix, &[&attacker]).await;
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::OnlyAssetOwnerCanDeregister as u32,
+    );
+}
@@ -0,0 +1,115 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::AgentIdentityV2,
+    events::{MplAgentIdentityEvent, EVENT_VERSION},
+    instructions::{DeregisterIdentityV1Builder, RegisterIdentityV1Builder},
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+};
+
+/// Process a transaction and decode the identity events from its logs.
+async fn process_and_decode_events(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Vec<MplAgentIdentityEvent> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    MplAgentIdentityEvent::from_logs(&result.metadata.unwrap().log_messages)
+}
+
+#[tokio::test]
+async fn register_identity_emits_event() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    let ix = RegisterIdentityV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/agent.json".to_string())
+        .instruction();
+    let events = process_and_decode_events(&mut context, ix).await;
+
+    // Only the identity program's own event is decoded, not MPL Core's logs.
+    assert_eq!(
+        events,
+        vec![MplAgentIdentityEvent::IdentityRegisteredV1 {
+            asset,
+            collection: Some(collection),
+            registration_uri: "https://example.com/agent.json".to_string(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn deregister_identity_emits_event() {
+    let mut context = setup::setup().start_with_context().await;
+    setup::complete_execution_delegate_migration(&mut context).await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    let ix = DeregisterIdentityV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .execution_delegate_count(setup::find_execution_delegate_count_pda(&asset))
+        .execution_delegate_migration(setup::find_execution_delegate_migration_pda())
+        .destination(context.payer.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    let events = process_and_decode_events(&mut context, ix).await;
+
+    assert_eq!(
+        events,
+        vec![MplAgentIdentityEvent::IdentityDeregisteredV1 { asset }]
+    );
+}
+
+#[test]
+fn decoder_skips_unknown_versions_and_other_programs() {
+    let asset = Pubkey::new_unique();
+    let event = MplAgentIdentityEvent::IdentityMigratedV1 { asset };
+
+    let mut payload = vec![EVENT_VERSION];
+    payload.extend(borsh::to_vec(&event).unwrap());
+    assert_eq!(MplAgentIdentityEvent::decode(&payload), Some(event.clone()));
+
+    let mut future_payload = payload.clone();
+    future_payload[0] = EVENT_VERSION + 1;
+    assert_eq!(MplAgentIdentityEvent::decode(&future_payload), None);
+
+    let encoded = {
+        use base64::{prelude::BASE64_STANDARD, Engine};
+        BASE64_STANDARD.encode(&payload)
+    };
+    let other_program = Pubkey::new_unique();
+    let logs = vec![
+        format!("Program {} invoke [1]", mpl_agent_identity::ID),
+        format!("Program {} invoke [2]", other_program),
+        format!("Program data: {}", encoded),
+        format!("Program {} success", other_program),
+        format!("Program data: {}", encoded),
+        format!("Program {} success", mpl_agent_identity::ID),
+    ];
+
+    assert_eq!(MplAgentIdentityEvent::from_logs(&logs), vec![event]);
+}
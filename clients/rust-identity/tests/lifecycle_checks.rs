@@ -0,0 +1,206 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::AgentIdentityV2,
+    errors::MplAgentIdentityError,
+    instructions::{RegisterIdentityV1Builder, UpdateIdentityLifecycleChecksV1Builder},
+    lifecycle::*,
+};
+use mpl_core::{Asset, PluginRegistryV1Safe};
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+fn register_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    collection: Pubkey,
+    lifecycle_checks: u32,
+) -> Instruction {
+    RegisterIdentityV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .lifecycle_checks(lifecycle_checks)
+        .agent_registration_uri("https://example.com/agent.json".to_string())
+        .instruction()
+}
+
+/// Read the lifecycle checks registered on the asset's Agent Identity plugin
+/// and fold them back into the program's bitmask encoding.
+async fn fetch_lifecycle_checks(context: &mut ProgramTestContext, asset: Pubkey) -> u32 {
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    let plugin_header = Asset::from_bytes(&account.data)
+        .unwrap()
+        .plugin_header
+        .unwrap();
+    let registry = PluginRegistryV1Safe::from_bytes(
+        &account.data[plugin_header.plugin_registry_offset as usize..],
+    )
+    .unwrap();
+    assert_eq!(registry.external_registry.len(), 1);
+
+    registry.external_registry[0]
+        .lifecycle_checks
+        .clone()
+        .unwrap_or_default()
+        .iter()
+        .fold(0, |mask, (event, result)| {
+            mask | (result.flags << (3 * *event as u32))
+        })
+}
+
+#[tokio::test]
+async fn register_identity_with_default_lifecycle_checks() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_identity(&mut context, asset, collection).await;
+
+    assert_eq!(
+        fetch_lifecycle_checks(&mut context, asset).await,
+        DEFAULT_LIFECYCLE_CHECKS
+    );
+}
+
+#[tokio::test]
+async fn register_identity_with_custom_lifecycle_checks() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    // Listen to transfers, and let the plugin approve or reject burns.
+    let lifecycle_checks = (LIFECYCLE_CHECK_LISTEN << LIFECYCLE_TRANSFER_SHIFT)
+        | ((LIFECYCLE_CHECK_APPROVE | LIFECYCLE_CHECK_REJECT) << LIFECYCLE_BURN_SHIFT);
+
+    let ix = register_ix(&context, asset, collection, lifecycle_checks);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    assert_eq!(
+        fetch_lifecycle_checks(&mut context, asset).await,
+        lifecycle_checks
+    );
+}
+
+#[tokio::test]
+async fn cannot_register_identity_with_create_lifecycle_checks() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    // Create hooks sit below the transfer shift and are never configurable.
+    let ix = register_ix(&context, asset, collection, LIFECYCLE_CHECK_LISTEN);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::InvalidLifecycleChecks as u32);
+}
+
+#[tokio::test]
+async fn cannot_register_identity_with_unknown_lifecycle_checks() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    let ix = register_ix(
+        &context,
+        asset,
+        collection,
+        DEFAULT_LIFECYCLE_CHECKS | (1 << 15),
+    );
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::InvalidLifecycleChecks as u32);
+}
+
+#[tokio::test]
+async fn owner_can_update_lifecycle_checks() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // When the owner narrows the policy to listening on execute.
+    let lifecycle_checks = LIFECYCLE_CHECK_LISTEN << LIFECYCLE_EXECUTE_SHIFT;
+    let ix = UpdateIdentityLifecycleChecksV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .lifecycle_checks(lifecycle_checks)
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    assert_eq!(
+        fetch_lifecycle_checks(&mut context, asset).await,
+        lifecycle_checks
+    );
+
+    // And restores the default policy by passing no mask.
+    let ix = UpdateIdentityLifecycleChecksV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix, &[]).await.unwrap();
+
+    assert_eq!(
+        fetch_lifecycle_checks(&mut context, asset).await,
+        DEFAULT_LIFECYCLE_CHECKS
+    );
+}
+
+#[tokio::test]
+async fn non_owner_cannot_update_lifecycle_checks() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // A random wallet that does not own the asset.
+    let attacker = Keypair::new();
+
+    let ix = UpdateIdentityLifecycleChecksV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .authority(Some(attacker.pubkey()))
+        .lifecycle_checks(LIFECYCLE_CHECK_LISTEN << LIFECYCLE_TRANSFER_SHIFT)
+        .instruction();
+    let err = process(&mut context, ix, &[&attacker]).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks as u32,
+    );
+}
@@ -0,0 +1,164 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::{AgentIdentityV1, AgentIdentityV2},
+    errors::MplAgentIdentityError,
+    migration::{migrate_identity_instruction, migration_instruction_batches},
+    types::Key,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Rewrite a registered AgentIdentityV2 PDA as a legacy AgentIdentityV1
+/// account (40 bytes, key=1) funded only for the V1 size.
+async fn downgrade_to_v1(context: &mut ProgramTestContext, asset: Pubkey) -> Pubkey {
+    let (agent_identity_pda, bump) = AgentIdentityV1::find_pda(&asset);
+
+    // Construct V1 data: key=1 (AgentIdentityV1), bump, padding[6], asset[32]
+    let mut v1_data = vec![0u8; AgentIdentityV1::LEN];
+    v1_data[0] = Key::AgentIdentityV1 as u8;
+    v1_data[1] = bump;
+    v1_data[8..40].copy_from_slice(asset.as_ref());
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(AgentIdentityV1::LEN);
+
+    let mut account_data =
+        AccountSharedData::new(lamports, AgentIdentityV1::LEN, &mpl_agent_identity::ID);
+    account_data.set_data_from_slice(&v1_data);
+    context.set_account(&agent_identity_pda, &account_data);
+
+    agent_identity_pda
+}
+
+async fn assert_migrated(
+    context: &mut ProgramTestContext,
+    agent_identity_pda: Pubkey,
+    asset: Pubkey,
+) {
+    let account = context
+        .banks_client
+        .get_account(agent_identity_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), AgentIdentityV2::LEN);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    assert!(rent.is_exempt(account.lamports, account.data.len()));
+
+    let agent_identity = AgentIdentityV2::from_bytes(&account.data).unwrap();
+    assert_eq!(agent_identity.key, Key::AgentIdentityV2);
+    assert_eq!(agent_identity.bump, AgentIdentityV2::find_pda(&asset).1);
+    assert_eq!(agent_identity.asset, asset);
+    assert_eq!(agent_identity.agent_token, None);
+}
+
+#[tokio::test]
+async fn anyone_can_migrate_identity_v1_to_v2() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_identity(&mut context, asset, collection).await;
+    let agent_identity_pda = downgrade_to_v1(&mut context, asset).await;
+
+    // The payer does not need to own the asset.
+    let ix = migrate_identity_instruction(&asset, &context.payer.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    assert_migrated(&mut context, agent_identity_pda, asset).await;
+}
+
+#[tokio::test]
+async fn migrate_identities_in_a_batch() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let mut assets = vec![];
+    for _ in 0..3 {
+        let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+        setup::register_identity(&mut context, asset, collection).await;
+        downgrade_to_v1(&mut context, asset).await;
+        assets.push(asset);
+    }
+
+    let batches = migration_instruction_batches(&assets, &context.payer.pubkey(), 2);
+    assert_eq!(batches.len(), 2);
+    assert_eq!(batches[0].len(), 2);
+    assert_eq!(batches[1].len(), 1);
+
+    for instructions in batches {
+        let tx = Transaction::new_signed_with_payer(
+            &instructions,
+            Some(&context.payer.pubkey()),
+            &[&context.payer],
+            context.last_blockhash,
+        );
+        context.banks_client.process_transaction(tx).await.unwrap();
+    }
+
+    for asset in assets {
+        let (agent_identity_pda, _) = AgentIdentityV2::find_pda(&asset);
+        assert_migrated(&mut context, agent_identity_pda, asset).await;
+    }
+}
+
+#[tokio::test]
+async fn cannot_migrate_identity_that_is_already_v2() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_identity(&mut context, asset, collection).await;
+
+    let ix = migrate_identity_instruction(&asset, &context.payer.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::AgentIdentityAlreadyMigrated as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_migrate_unregistered_identity() {
+    let context = setup::setup().start_with_context().await;
+
+    let asset = Keypair::new().pubkey();
+
+    let ix = migrate_identity_instruction(&asset, &context.payer.pubkey());
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::InvalidAgentIdentity as u32);
+}
@@ -0,0 +1,253 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::AgentIdentityV2,
+    batch::{failed_registration_index, register_identities_instruction},
+    errors::MplAgentIdentityError,
+    instructions::RegisterIdentitiesV1Builder,
+};
+use mpl_core::Asset;
+use solana_program::instruction::{AccountMeta, Instruction, InstructionError};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+/// Create a collection with `count` assets.
+async fn create_fleet(context: &mut ProgramTestContext, count: usize) -> (Pubkey, Vec<Pubkey>) {
+    let (collection, asset) = setup::create_collection_and_asset(context).await;
+
+    let mut assets = vec![asset];
+    for _ in 1..count {
+        assets.push(setup::create_asset_in_collection(context, collection).await);
+    }
+
+    (collection, assets)
+}
+
+async fn registration_uri(context: &mut ProgramTestContext, asset: Pubkey) -> Option<String> {
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    Asset::from_bytes(&account.data)
+        .unwrap()
+        .external_plugin_adapter_list
+        .agent_identities
+        .first()
+        .map(|identity| identity.uri.clone())
+}
+
+async fn is_registered(context: &mut ProgramTestContext, asset: Pubkey) -> bool {
+    let (agent_identity_pda, _) = AgentIdentityV2::find_pda(&asset);
+    context
+        .banks_client
+        .get_account(agent_identity_pda)
+        .await
+        .unwrap()
+        .is_some()
+}
+
+#[tokio::test]
+async fn register_identities_with_shared_uri() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, assets) = create_fleet(&mut context, 3).await;
+
+    let ix = register_identities_instruction(
+        &collection,
+        &assets,
+        vec!["https://example.com/fleet.json".to_string()],
+        &context.payer.pubkey(),
+        None,
+    );
+    process(&mut context, ix, &[]).await.unwrap();
+
+    for asset in assets {
+        let (agent_identity_pda, _) = AgentIdentityV2::find_pda(&asset);
+        let account = context
+            .banks_client
+            .get_account(agent_identity_pda)
+            .await
+            .unwrap()
+            .unwrap();
+        let agent_identity = AgentIdentityV2::from_bytes(&account.data).unwrap();
+        assert_eq!(agent_identity.asset, asset);
+
+        assert_eq!(
+            registration_uri(&mut context, asset).await.as_deref(),
+            Some("https://example.com/fleet.json")
+        );
+    }
+}
+
+#[tokio::test]
+async fn register_identities_with_per_asset_uris() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, assets) = create_fleet(&mut context, 2).await;
+    let uris = vec![
+        "https://example.com/agent-0.json".to_string(),
+        "ipfs://agent-1".to_string(),
+    ];
+
+    let ix = register_identities_instruction(
+        &collection,
+        &assets,
+        uris.clone(),
+        &context.payer.pubkey(),
+        None,
+    );
+    process(&mut context, ix, &[]).await.unwrap();
+
+    for (asset, uri) in assets.into_iter().zip(uris) {
+        assert_eq!(registration_uri(&mut context, asset).await, Some(uri));
+    }
+}
+
+#[tokio::test]
+async fn cannot_register_identities_with_mismatched_uri_table() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, assets) = create_fleet(&mut context, 3).await;
+
+    let ix = register_identities_instruction(
+        &collection,
+        &assets,
+        vec![
+            "https://example.com/agent-0.json".to_string(),
+            "https://example.com/agent-1.json".to_string(),
+        ],
+        &context.payer.pubkey(),
+        None,
+    );
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::InvalidRegistrationUriTable as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_register_identities_with_unpaired_accounts() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, assets) = create_fleet(&mut context, 1).await;
+
+    // The asset is missing after its agent identity PDA.
+    let ix = RegisterIdentitiesV1Builder::new()
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .agent_registration_uris(vec!["https://example.com/fleet.json".to_string()])
+        .add_remaining_account(AccountMeta::new(
+            AgentIdentityV2::find_pda(&assets[0]).0,
+            false,
+        ))
+        .instruction();
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::InvalidBatchAccounts as u32);
+}
+
+#[tokio::test]
+async fn failed_entry_is_reported_and_reverts_the_batch() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, assets) = create_fleet(&mut context, 3).await;
+
+    // The second asset is already registered.
+    setup::register_identity(&mut context, assets[1], collection).await;
+
+    let ix = register_identities_instruction(
+        &collection,
+        &assets,
+        vec!["https://example.com/fleet.json".to_string()],
+        &context.payer.pubkey(),
+        None,
+    );
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+
+    assert_eq!(
+        result.result.unwrap_err(),
+        TransactionError::InstructionError(
+            0,
+            InstructionError::Custom(MplAgentIdentityError::AgentIdentityAlreadyRegistered as u32)
+        )
+    );
+    assert_eq!(
+        failed_registration_index(&result.metadata.unwrap().log_messages),
+        Some(1)
+    );
+
+    // The first entry was rolled back with the rest of the batch.
+    assert!(!is_registered(&mut context, assets[0]).await);
+    assert!(!is_registered(&mut context, assets[2]).await);
+}
+
+#[tokio::test]
+async fn non_collection_authority_cannot_register_identities() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, assets) = create_fleet(&mut context, 2).await;
+
+    // A random signer that is not the collection update authority.
+    let attacker = Keypair::new();
+
+    let ix = register_identities_instruction(
+        &collection,
+        &assets,
+        vec!["https://example.com/fleet.json".to_string()],
+        &context.payer.pubkey(),
+        Some(&attacker.pubkey()),
+    );
+    let result = process(&mut context, ix, &[&attacker]).await;
+
+    assert!(result.is_err());
+    assert!(!is_registered(&mut context, assets[0]).await);
+}
+
+#[test]
+fn failed_registration_index_parses_program_logs() {
+    let logs = [
+        "Program 1DREGFgysWYxLnRnKQnwrxnJQeSMk2HmGaC6whw2B2p invoke [1]",
+        "Program log: Instruction: RegisterIdentitiesV1",
+        "Program log: Failed to register identity at index 4: 11111111111111111111111111111111",
+    ];
+    assert_eq!(failed_registration_index(&logs), Some(4));
+
+    assert_eq!(failed_registration_index(&logs[..2]), None);
+}
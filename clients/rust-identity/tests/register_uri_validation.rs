@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::AgentIdentityV2, errors::MplAgentIdentityError,
+    instructions::RegisterIdentityV1Builder,
+};
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{signature::Signer, transaction::Transaction};
+
+/// Offset of the URI length prefix in the RegisterIdentityV1 instruction data
+/// (discriminator + padding).
+const URI_OFFSET: usize = 8;
+
+async fn register_ix(context: &mut ProgramTestContext, uri: &str) -> Instruction {
+    let (collection, asset) = setup::create_collection_and_asset(context).await;
+    let (agent_identity_pda, _) = AgentIdentityV2::find_pda(&asset);
+
+    RegisterIdentityV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri(uri.to_string())
+        .instruction()
+}
+
+async fn process(context: &mut ProgramTestContext, ix: Instruction) -> BanksClientError {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err()
+}
+
+#[tokio::test]
+async fn cannot_register_with_empty_uri() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let ix = register_ix(&mut context, "").await;
+    let err = process(&mut context, ix).await;
+
+    setup::assert_custom_error(err, MplAgentIdentityError::RegistrationUriEmpty as u32);
+}
+
+#[tokio::test]
+async fn cannot_register_with_oversized_uri() {
+    let mut context = setup::setup().start_with_context().await;
+
+    // 201 bytes, one over the limit.
+    let uri = format!("https://example.com/{}", "a".repeat(181));
+    assert_eq!(uri.len(), 201);
+
+    let ix = register_ix(&mut context, &uri).await;
+    let err = process(&mut context, ix).await;
+
+    setup::assert_custom_error(err, MplAgentIdentityError::RegistrationUriTooLong as u32);
+}
+
+#[tokio::test]
+async fn cannot_register_with_non_utf8_uri() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let mut ix = register_ix(&mut context, "https://example.com/agent.json").await;
+    // Replace the last URI byte with a lone continuation byte.
+    *ix.data.last_mut().unwrap() = 0x80;
+
+    let err = process(&mut context, ix).await;
+
+    setup::assert_custom_error(err, MplAgentIdentityError::RegistrationUriNotUtf8 as u32);
+}
+
+#[tokio::test]
+async fn cannot_register_with_truncated_uri() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let mut ix = register_ix(&mut context, "https://example.com/agent.json").await;
+    // The length prefix claims more bytes than the instruction carries.
+    ix.data.truncate(URI_OFFSET + 10);
+
+    let err = process(&mut context, ix).await;
+
+    setup::assert_custom_error(err, MplAgentIdentityError::InvalidInstructionData as u32);
+}
+
+#[tokio::test]
+async fn cannot_register_with_disallowed_scheme() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let ix = register_ix(&mut context, "http://example.com/agent.json").await;
+    let err = process(&mut context, ix).await;
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::RegistrationUriSchemeNotAllowed as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_register_with_bare_scheme() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let ix = register_ix(&mut context, "ipfs://").await;
+    let err = process(&mut context, ix).await;
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::RegistrationUriSchemeNotAllowed as u32,
+    );
+}
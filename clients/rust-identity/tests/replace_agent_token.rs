@@ -0,0 +1,420 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::{AgentIdentityV2, AgentTokenChangeV1},
+    errors::MplAgentIdentityError,
+    instructions::{
+        ClearAgentTokenV1Builder, ReplaceAgentTokenV1Builder, SetAgentTokenFromMintV1Builder,
+        SetAgentTokenTimelockV1Builder,
+    },
+    types::{AgentTokenChange, AgentTokenSource},
+};
+use solana_program::clock::Clock;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    instruction::Instruction,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+const TIMELOCK: u32 = 3_600;
+
+/// Seconds after unlocking during which a scheduled change can be executed.
+const AGENT_TOKEN_CHANGE_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    // Fresh blockhash so that repeated identical instructions are not deduplicated.
+    let blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn fetch_agent_identity(context: &mut ProgramTestContext, asset: Pubkey) -> AgentIdentityV2 {
+    let account = context
+        .banks_client
+        .get_account(AgentIdentityV2::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    AgentIdentityV2::from_bytes(&account.data).unwrap()
+}
+
+async fn fetch_agent_token_change(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+) -> AgentTokenChangeV1 {
+    let account = context
+        .banks_client
+        .get_account(AgentTokenChangeV1::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    AgentTokenChangeV1::from_bytes(&account.data).unwrap()
+}
+
+async fn warp_forward(context: &mut ProgramTestContext, seconds: i64) {
+    let mut clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.unix_timestamp += seconds;
+    context.set_sysvar(&clock);
+}
+
+/// Register an identity for a new asset and link a mint held by its asset signer.
+async fn setup_identity_with_agent_token(
+    context: &mut ProgramTestContext,
+) -> (Pubkey, Pubkey, Pubkey) {
+    let (collection, asset) = setup::create_collection_and_asset(context).await;
+    setup::register_identity(context, asset, collection).await;
+
+    let asset_signer = setup::find_asset_signer(&asset);
+    let mint = setup::create_mint(
+        context,
+        setup::SPL_TOKEN_PROGRAM_ID,
+        Some(asset_signer),
+        false,
+    )
+    .await;
+
+    let ix = SetAgentTokenFromMintV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .authority(Some(asset_signer))
+        .instruction();
+    let ix = setup::execute_as_asset_signer(asset, collection, context.payer.pubkey(), ix);
+    process(context, ix, &[]).await.unwrap();
+
+    (collection, asset, mint)
+}
+
+fn clear_ix(context: &ProgramTestContext, asset: Pubkey, collection: Pubkey) -> Instruction {
+    let ix = ClearAgentTokenV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .agent_token_change(AgentTokenChangeV1::find_pda(&asset).0)
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(setup::find_asset_signer(&asset)))
+        .instruction();
+    setup::execute_as_asset_signer(asset, collection, context.payer.pubkey(), ix)
+}
+
+/// Replace the agent token with `mint`, whose mint authority is the asset signer.
+fn replace_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    collection: Pubkey,
+    mint: Pubkey,
+) -> Instruction {
+    let ix = ReplaceAgentTokenV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .agent_token_change(AgentTokenChangeV1::find_pda(&asset).0)
+        .asset(asset)
+        .mint(mint)
+        .payer(context.payer.pubkey())
+        .authority(Some(setup::find_asset_signer(&asset)))
+        .instruction();
+    setup::execute_as_asset_signer(asset, collection, context.payer.pubkey(), ix)
+}
+
+async fn create_asset_signer_mint(context: &mut ProgramTestContext, asset: Pubkey) -> Pubkey {
+    let asset_signer = setup::find_asset_signer(&asset);
+    setup::create_mint(
+        context,
+        setup::SPL_TOKEN_PROGRAM_ID,
+        Some(asset_signer),
+        false,
+    )
+    .await
+}
+
+fn set_timelock_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    collection: Pubkey,
+    timelock: u32,
+) -> Instruction {
+    let ix = SetAgentTokenTimelockV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(setup::find_asset_signer(&asset)))
+        .timelock(timelock)
+        .instruction();
+    setup::execute_as_asset_signer(asset, collection, context.payer.pubkey(), ix)
+}
+
+#[tokio::test]
+async fn asset_signer_can_clear_agent_token() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, _) = setup_identity_with_agent_token(&mut context).await;
+
+    let ix = clear_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, None);
+
+    // Without a timelock, no change is scheduled.
+    let agent_token_change = context
+        .banks_client
+        .get_account(AgentTokenChangeV1::find_pda(&asset).0)
+        .await
+        .unwrap();
+    assert!(agent_token_change.is_none());
+}
+
+#[tokio::test]
+async fn asset_signer_can_replace_agent_token() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, _) = setup_identity_with_agent_token(&mut context).await;
+
+    // The new mint is controlled by an external authority that co-signs.
+    let mint_authority = Keypair::new();
+    let new_mint = setup::create_mint(
+        &mut context,
+        setup::SPL_TOKEN_2022_PROGRAM_ID,
+        Some(mint_authority.pubkey()),
+        false,
+    )
+    .await;
+
+    let ix = ReplaceAgentTokenV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .agent_token_change(AgentTokenChangeV1::find_pda(&asset).0)
+        .asset(asset)
+        .mint(new_mint)
+        .mint_authority(Some(mint_authority.pubkey()))
+        .payer(context.payer.pubkey())
+        .authority(Some(setup::find_asset_signer(&asset)))
+        .instruction();
+    let ix = setup::execute_as_asset_signer(asset, collection, context.payer.pubkey(), ix);
+    process(&mut context, ix, &[&mint_authority]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, Some(new_mint));
+    assert_eq!(
+        agent_identity.agent_token_source,
+        AgentTokenSource::MintAuthority
+    );
+}
+
+#[tokio::test]
+async fn timelocked_clear_is_scheduled_then_applied() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, mint) = setup_identity_with_agent_token(&mut context).await;
+
+    let ix = set_timelock_ix(&context, asset, collection, TIMELOCK);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    // The first clear only schedules the change.
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    let ix = clear_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, Some(mint));
+    assert_eq!(agent_identity.agent_token_timelock, TIMELOCK);
+
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert_eq!(agent_token_change.asset, asset);
+    assert_eq!(agent_token_change.change, AgentTokenChange::Clear);
+    assert_eq!(agent_token_change.new_agent_token, None);
+    assert!(agent_token_change.unlock_at >= clock.unix_timestamp + TIMELOCK as i64);
+
+    // Clearing again before the notice period has elapsed fails.
+    let ix = clear_ix(&context, asset, collection);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentIdentityError::AgentTokenChangeLocked as u32);
+
+    // Once the notice period has elapsed, the change is applied.
+    warp_forward(&mut context, TIMELOCK as i64).await;
+    let ix = clear_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, None);
+
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert_eq!(agent_token_change.change, AgentTokenChange::None);
+    assert_eq!(agent_token_change.unlock_at, 0);
+}
+
+#[tokio::test]
+async fn timelocked_replacement_only_applies_the_scheduled_mint() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, mint) = setup_identity_with_agent_token(&mut context).await;
+    let scheduled_mint = create_asset_signer_mint(&mut context, asset).await;
+    let other_mint = create_asset_signer_mint(&mut context, asset).await;
+
+    let ix = set_timelock_ix(&context, asset, collection, TIMELOCK);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let ix = replace_ix(&context, asset, collection, scheduled_mint);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert_eq!(agent_token_change.change, AgentTokenChange::Replace);
+    assert_eq!(agent_token_change.new_agent_token, Some(scheduled_mint));
+
+    // Once unlocked, neither another mint nor a clear is applied: each only
+    // schedules itself in place of the replacement.
+    warp_forward(&mut context, TIMELOCK as i64).await;
+    let ix = replace_ix(&context, asset, collection, other_mint);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, Some(mint));
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert_eq!(agent_token_change.new_agent_token, Some(other_mint));
+
+    warp_forward(&mut context, TIMELOCK as i64).await;
+    let ix = clear_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, Some(mint));
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert_eq!(agent_token_change.change, AgentTokenChange::Clear);
+    assert_eq!(agent_token_change.new_agent_token, None);
+
+    // The change is applied once it is scheduled again and unlocked.
+    let ix = replace_ix(&context, asset, collection, scheduled_mint);
+    process(&mut context, ix, &[]).await.unwrap();
+    warp_forward(&mut context, TIMELOCK as i64).await;
+    let ix = replace_ix(&context, asset, collection, scheduled_mint);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, Some(scheduled_mint));
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert_eq!(agent_token_change.change, AgentTokenChange::None);
+}
+
+#[tokio::test]
+async fn timelocked_replacement_is_bound_to_the_full_mint_address() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, mint) = setup_identity_with_agent_token(&mut context).await;
+    let scheduled_mint = create_asset_signer_mint(&mut context, asset).await;
+
+    // A valid mint whose address only differs from the scheduled one in its
+    // last byte.
+    let mut lookalike_bytes = scheduled_mint.to_bytes();
+    lookalike_bytes[31] ^= 1;
+    let lookalike_mint = Pubkey::new_from_array(lookalike_bytes);
+    let scheduled_mint_account = context
+        .banks_client
+        .get_account(scheduled_mint)
+        .await
+        .unwrap()
+        .unwrap();
+    context.set_account(
+        &lookalike_mint,
+        &AccountSharedData::from(scheduled_mint_account),
+    );
+
+    let ix = set_timelock_ix(&context, asset, collection, TIMELOCK);
+    process(&mut context, ix, &[]).await.unwrap();
+    let ix = replace_ix(&context, asset, collection, scheduled_mint);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    // Once unlocked, the lookalike mint only schedules itself.
+    warp_forward(&mut context, TIMELOCK as i64).await;
+    let ix = replace_ix(&context, asset, collection, lookalike_mint);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, Some(mint));
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert_eq!(agent_token_change.new_agent_token, Some(lookalike_mint));
+}
+
+#[tokio::test]
+async fn scheduled_change_expires() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, mint) = setup_identity_with_agent_token(&mut context).await;
+
+    let ix = set_timelock_ix(&context, asset, collection, TIMELOCK);
+    process(&mut context, ix, &[]).await.unwrap();
+    let ix = clear_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+    let unlock_at = fetch_agent_token_change(&mut context, asset)
+        .await
+        .unlock_at;
+
+    // Past the execution window, the clear is scheduled again.
+    warp_forward(&mut context, TIMELOCK as i64 + AGENT_TOKEN_CHANGE_WINDOW).await;
+    let ix = clear_ix(&context, asset, collection);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, asset).await;
+    assert_eq!(agent_identity.agent_token, Some(mint));
+    let agent_token_change = fetch_agent_token_change(&mut context, asset).await;
+    assert!(agent_token_change.unlock_at >= unlock_at + AGENT_TOKEN_CHANGE_WINDOW);
+}
+
+#[tokio::test]
+async fn cannot_shorten_timelock_while_agent_token_is_set() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, _) = setup_identity_with_agent_token(&mut context).await;
+
+    let ix = set_timelock_ix(&context, asset, collection, TIMELOCK);
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let ix = set_timelock_ix(&context, asset, collection, 0);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::AgentTokenTimelockDecrease as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_clear_agent_token_that_is_not_set() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_identity(&mut context, asset, collection).await;
+
+    let ix = clear_ix(&context, asset, collection);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::AgentTokenNotSet as u32);
+}
+
+#[tokio::test]
+async fn cannot_clear_agent_token_without_asset_signer() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset, _) = setup_identity_with_agent_token(&mut context).await;
+
+    // The asset owner calls directly rather than through the asset signer.
+    let ix = ClearAgentTokenV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .agent_token_change(AgentTokenChangeV1::find_pda(&asset).0)
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::OnlyAssetSignerCanSetAgentToken as u32,
+    );
+}
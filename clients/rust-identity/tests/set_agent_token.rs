@@ -6,7 +6,7 @@ use mpl_agent_identity::{
     accounts::{AgentIdentityV1, AgentIdentityV2},
     errors::MplAgentIdentityError,
     instructions::SetAgentTokenV1Builder,
-    types::Key,
+    types::{AgentTokenSource, Key},
 };
 use mpl_core::instructions::ExecuteV1Builder;
 use solana_program_test::tokio;
@@ -92,6 +92,7 @@ async fn set_agent_token() {
     assert_eq!(agent_identity.key, Key::AgentIdentityV2);
     assert_eq!(agent_identity.asset, asset);
     assert_eq!(agent_identity.agent_token, Some(base_mint));
+    assert_eq!(agent_identity.agent_token_source, AgentTokenSource::Genesis);
 }
 
 #[tokio::test]
@@ -0,0 +1,282 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::AgentIdentityV2,
+    errors::MplAgentIdentityError,
+    instructions::SetAgentTokenFromMintV1Builder,
+    types::{AgentTokenSource, Key},
+};
+use mpl_core::instructions::ExecuteV1Builder;
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    instruction::{AccountMeta, Instruction},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+const SYSTEM_PROGRAM_ID: Pubkey = solana_program::pubkey!("11111111111111111111111111111111");
+
+fn find_asset_signer(asset: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(
+        &["mpl-core-execute".as_bytes(), asset.as_ref()],
+        &setup::MPL_CORE_ID,
+    )
+    .0
+}
+
+/// Build an ExecuteV1 instruction that wraps a SetAgentTokenFromMintV1 CPI.
+fn build_set_agent_token_from_mint_via_execute(
+    asset: Pubkey,
+    collection: Pubkey,
+    agent_identity_pda: Pubkey,
+    mint: Pubkey,
+    mint_authority: Option<Pubkey>,
+    payer: Pubkey,
+) -> Instruction {
+    let asset_signer_pda = find_asset_signer(&asset);
+
+    // SetAgentTokenFromMintV1 instruction data: discriminator 5 + 7 bytes padding.
+    let instruction_data = vec![5, 0, 0, 0, 0, 0, 0, 0];
+
+    // Omitted optional accounts are passed as the program ID.
+    let mint_authority_meta = match mint_authority {
+        Some(mint_authority) => AccountMeta::new_readonly(mint_authority, true),
+        None => AccountMeta::new_readonly(mpl_agent_identity::ID, false),
+    };
+
+    ExecuteV1Builder::new()
+        .asset(asset)
+        .collection(Some(collection))
+        .asset_signer(asset_signer_pda)
+        .payer(payer, true)
+        .program_id(mpl_agent_identity::ID)
+        .instruction_data(instruction_data)
+        .add_remaining_account(AccountMeta::new(agent_identity_pda, false))
+        .add_remaining_account(AccountMeta::new_readonly(asset, false))
+        .add_remaining_account(AccountMeta::new_readonly(mint, false))
+        .add_remaining_account(mint_authority_meta)
+        .add_remaining_account(AccountMeta::new(payer, true))
+        .add_remaining_account(AccountMeta::new_readonly(asset_signer_pda, false))
+        .add_remaining_account(AccountMeta::new_readonly(SYSTEM_PROGRAM_ID, false))
+        .instruction()
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+async fn fetch_agent_identity(
+    context: &mut ProgramTestContext,
+    agent_identity_pda: Pubkey,
+) -> AgentIdentityV2 {
+    let account = context
+        .banks_client
+        .get_account(agent_identity_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    AgentIdentityV2::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn set_agent_token_from_mint_held_by_asset_signer() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // An SPL Token mint whose authority is the asset signer.
+    let mint = setup::create_mint(
+        &mut context,
+        setup::SPL_TOKEN_PROGRAM_ID,
+        Some(find_asset_signer(&asset)),
+        false,
+    )
+    .await;
+
+    let ix = build_set_agent_token_from_mint_via_execute(
+        asset,
+        collection,
+        agent_identity_pda,
+        mint,
+        None,
+        context.payer.pubkey(),
+    );
+    process(&mut context, ix, &[]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, agent_identity_pda).await;
+    assert_eq!(agent_identity.key, Key::AgentIdentityV2);
+    assert_eq!(agent_identity.agent_token, Some(mint));
+    assert_eq!(
+        agent_identity.agent_token_source,
+        AgentTokenSource::AssetSigner
+    );
+}
+
+#[tokio::test]
+async fn set_agent_token_from_token_2022_mint_with_mint_authority_signature() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // A Token-2022 mint with extensions, controlled by an external authority.
+    let mint_authority = Keypair::new();
+    let mint = setup::create_mint(
+        &mut context,
+        setup::SPL_TOKEN_2022_PROGRAM_ID,
+        Some(mint_authority.pubkey()),
+        true,
+    )
+    .await;
+
+    let ix = build_set_agent_token_from_mint_via_execute(
+        asset,
+        collection,
+        agent_identity_pda,
+        mint,
+        Some(mint_authority.pubkey()),
+        context.payer.pubkey(),
+    );
+    process(&mut context, ix, &[&mint_authority]).await.unwrap();
+
+    let agent_identity = fetch_agent_identity(&mut context, agent_identity_pda).await;
+    assert_eq!(agent_identity.agent_token, Some(mint));
+    assert_eq!(
+        agent_identity.agent_token_source,
+        AgentTokenSource::MintAuthority
+    );
+}
+
+#[tokio::test]
+async fn cannot_set_agent_token_from_mint_without_mint_authority() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    let mint_authority = Keypair::new();
+    let mint = setup::create_mint(
+        &mut context,
+        setup::SPL_TOKEN_PROGRAM_ID,
+        Some(mint_authority.pubkey()),
+        false,
+    )
+    .await;
+
+    // A different signer claims to be the mint authority.
+    let impostor = Keypair::new();
+    let ix = build_set_agent_token_from_mint_via_execute(
+        asset,
+        collection,
+        agent_identity_pda,
+        mint,
+        Some(impostor.pubkey()),
+        context.payer.pubkey(),
+    );
+    let err = process(&mut context, ix, &[&impostor]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::MintAuthorityMismatch as u32);
+}
+
+#[tokio::test]
+async fn cannot_set_agent_token_from_mint_without_any_mint_authority() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // Fixed-supply mint: the mint authority was revoked.
+    let mint = setup::create_mint(&mut context, setup::SPL_TOKEN_PROGRAM_ID, None, false).await;
+
+    let ix = build_set_agent_token_from_mint_via_execute(
+        asset,
+        collection,
+        agent_identity_pda,
+        mint,
+        None,
+        context.payer.pubkey(),
+    );
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::MintAuthorityMismatch as u32);
+}
+
+#[tokio::test]
+async fn cannot_set_agent_token_from_account_that_is_not_a_mint() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // A mint-shaped account that isn't owned by a token program.
+    let mint = setup::create_mint(
+        &mut context,
+        SYSTEM_PROGRAM_ID,
+        Some(find_asset_signer(&asset)),
+        false,
+    )
+    .await;
+
+    let ix = build_set_agent_token_from_mint_via_execute(
+        asset,
+        collection,
+        agent_identity_pda,
+        mint,
+        None,
+        context.payer.pubkey(),
+    );
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::InvalidMint as u32);
+}
+
+#[tokio::test]
+async fn cannot_set_agent_token_from_mint_without_asset_signer() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    let mint_authority = Keypair::new();
+    let mint = setup::create_mint(
+        &mut context,
+        setup::SPL_TOKEN_PROGRAM_ID,
+        Some(mint_authority.pubkey()),
+        false,
+    )
+    .await;
+
+    // Called directly by the owner rather than through the asset signer.
+    let ix = SetAgentTokenFromMintV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .mint(mint)
+        .mint_authority(Some(mint_authority.pubkey()))
+        .payer(context.payer.pubkey())
+        .instruction();
+    let err = process(&mut context, ix, &[&mint_authority])
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::OnlyAssetSignerCanSetAgentToken as u32,
+    );
+}
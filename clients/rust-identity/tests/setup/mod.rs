@@ -1,4 +1,11 @@
-use mpl_agent_identity::{accounts::AgentIdentityV2, instructions::RegisterIdentityV1Builder};
+use mpl_agent_identity::{
+    accounts::{AgentIdentityV2, CollectionIdentityV1},
+    instructions::{RegisterCollectionIdentityV1Builder, RegisterIdentityV1Builder},
+};
+use mpl_agent_tools::{
+    accounts::{ExecutionDelegateCountV1, ExecutionDelegateMigrationV1},
+    types::Key as MplAgentToolsKey,
+};
 use mpl_core::instructions::{CreateCollectionV1Builder, CreateV1Builder};
 use solana_program::instruction::InstructionError;
 use solana_program_test::BanksClientError;
@@ -76,6 +83,34 @@ pub async fn create_collection_and_asset(
     (collection.pubkey(), asset.pubkey())
 }
 
+/// Create another asset in an existing collection. The payer is the
+/// collection update authority.
+#[allow(dead_code)]
+pub async fn create_asset_in_collection(
+    context: &mut solana_program_test::ProgramTestContext,
+    collection: Pubkey,
+) -> Pubkey {
+    let asset = Keypair::new();
+
+    let create_asset_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .name("Test Asset".to_string())
+        .uri("https://example.com/asset.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_asset_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    asset.pubkey()
+}
+
 pub async fn register_identity(
     context: &mut solana_program_test::ProgramTestContext,
     asset: Pubkey,
@@ -143,3 +178,192 @@ pub async fn create_genesis_account(
 
     genesis_address
 }
+
+/// Returns the address of the mpl-agent-tools ExecutionDelegateCountV1 PDA
+/// for `asset`.
+#[allow(dead_code)]
+pub fn find_execution_delegate_count_pda(asset: &Pubkey) -> Pubkey {
+    ExecutionDelegateCountV1::find_pda(asset).0
+}
+
+/// Create an mpl-agent-tools ExecutionDelegateCountV1 account for `asset`
+/// holding `count` delegate records.
+///
+/// Returns the address of the count account.
+#[allow(dead_code)]
+pub async fn create_execution_delegate_count(
+    context: &mut solana_program_test::ProgramTestContext,
+    asset: Pubkey,
+    count: u64,
+) -> Pubkey {
+    let (address, bump) = ExecutionDelegateCountV1::find_pda(&asset);
+
+    let data = borsh::to_vec(&ExecutionDelegateCountV1 {
+        key: MplAgentToolsKey::ExecutionDelegateCountV1,
+        bump,
+        padding: [0u8; 6],
+        agent_asset: asset,
+        count,
+    })
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(ExecutionDelegateCountV1::LEN);
+
+    let mut account_data = AccountSharedData::new(
+        lamports,
+        ExecutionDelegateCountV1::LEN,
+        &mpl_agent_tools::ID,
+    );
+    account_data.set_data_from_slice(&data);
+    context.set_account(&address, &account_data);
+
+    address
+}
+
+/// Returns the address of the mpl-agent-tools ExecutionDelegateMigrationV1
+/// PDA.
+#[allow(dead_code)]
+pub fn find_execution_delegate_migration_pda() -> Pubkey {
+    ExecutionDelegateMigrationV1::find_pda().0
+}
+
+/// Create the mpl-agent-tools ExecutionDelegateMigrationV1 account, marking
+/// every execution delegate record as counted.
+#[allow(dead_code)]
+pub async fn complete_execution_delegate_migration(
+    context: &mut solana_program_test::ProgramTestContext,
+) {
+    let (address, bump) = ExecutionDelegateMigrationV1::find_pda();
+
+    let data = borsh::to_vec(&ExecutionDelegateMigrationV1 {
+        key: MplAgentToolsKey::ExecutionDelegateMigrationV1,
+        bump,
+        padding: [0u8; 6],
+        authority: Pubkey::new_unique(),
+        slot: 0,
+    })
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(ExecutionDelegateMigrationV1::LEN);
+
+    let mut account_data = AccountSharedData::new(
+        lamports,
+        ExecutionDelegateMigrationV1::LEN,
+        &mpl_agent_tools::ID,
+    );
+    account_data.set_data_from_slice(&data);
+    context.set_account(&address, &account_data);
+}
+
+pub const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+pub const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Create a fake initialized SPL Token mint owned by `token_program`.
+///
+/// Layout (82 bytes, or 166 bytes for a Token-2022 mint with extensions):
+///   offset 0:   mint_authority tag (u32) — 0=None, 1=Some
+///   offset 4:   mint_authority (Pubkey, 32 bytes)
+///   offset 44:  decimals (u8)
+///   offset 45:  is_initialized (u8)
+///   offset 165: account_type (u8) = 1 (Mint), Token-2022 extensions only
+///
+/// Returns the address of the mint.
+#[allow(dead_code)]
+pub async fn create_mint(
+    context: &mut solana_program_test::ProgramTestContext,
+    token_program: Pubkey,
+    mint_authority: Option<Pubkey>,
+    with_extensions: bool,
+) -> Pubkey {
+    let mint = Keypair::new().pubkey();
+    let size = if with_extensions { 166 } else { 82 };
+
+    let mut data = vec![0u8; size];
+    if let Some(mint_authority) = mint_authority {
+        data[0] = 1;
+        data[4..36].copy_from_slice(mint_authority.as_ref());
+    }
+    data[44] = 6;
+    data[45] = 1;
+    if with_extensions {
+        data[165] = 1;
+    }
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let lamports = rent.minimum_balance(size);
+
+    let mut account_data = AccountSharedData::new(lamports, size, &token_program);
+    account_data.set_data_from_slice(&data);
+    context.set_account(&mint, &account_data);
+
+    mint
+}
+
+/// Find the MPL Core asset signer PDA for an asset.
+#[allow(dead_code)]
+pub fn find_asset_signer(asset: &Pubkey) -> Pubkey {
+    Pubkey::find_program_address(&[b"mpl-core-execute", asset.as_ref()], &MPL_CORE_ID).0
+}
+
+/// Wrap an agent identity instruction in an MPL Core ExecuteV1 so that the
+/// asset signer PDA signs it. The asset signer signature is provided by MPL
+/// Core, so it is passed through as a non-signer remaining account.
+#[allow(dead_code)]
+pub fn execute_as_asset_signer(
+    asset: Pubkey,
+    collection: Pubkey,
+    payer: Pubkey,
+    instruction: solana_program::instruction::Instruction,
+) -> solana_program::instruction::Instruction {
+    let asset_signer = find_asset_signer(&asset);
+
+    let mut builder = mpl_core::instructions::ExecuteV1Builder::new();
+    builder
+        .asset(asset)
+        .collection(Some(collection))
+        .asset_signer(asset_signer)
+        .payer(payer, true)
+        .program_id(instruction.program_id)
+        .instruction_data(instruction.data);
+
+    for mut account in instruction.accounts {
+        if account.pubkey == asset_signer {
+            account.is_signer = false;
+        }
+        builder.add_remaining_account(account);
+    }
+
+    builder.instruction()
+}
+
+/// Register an agent identity for `collection`. The payer is the collection
+/// update authority. Returns the address of the collection identity PDA.
+#[allow(dead_code)]
+pub async fn register_collection_identity(
+    context: &mut solana_program_test::ProgramTestContext,
+    collection: Pubkey,
+) -> Pubkey {
+    let (collection_identity_pda, _) = CollectionIdentityV1::find_pda(&collection);
+
+    let ix = RegisterCollectionIdentityV1Builder::new()
+        .collection_identity(collection_identity_pda)
+        .collection(collection)
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/operator.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    collection_identity_pda
+}
@@ -0,0 +1,118 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{
+    accounts::AgentIdentityV2, errors::MplAgentIdentityError,
+    instructions::UpdateAgentRegistrationUriV1Builder,
+};
+use mpl_core::Asset;
+use solana_program_test::tokio;
+use solana_sdk::{
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+#[tokio::test]
+async fn owner_can_update_registration_uri() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // When the owner (the payer) updates the registration URI.
+    let ix = UpdateAgentRegistrationUriV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/agent-v2.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    // Then the Agent Identity plugin carries the new URI.
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    let asset = Asset::from_bytes(&account.data).unwrap();
+    let agent_identities = &asset.external_plugin_adapter_list.agent_identities;
+    assert_eq!(agent_identities.len(), 1);
+    assert_eq!(agent_identities[0].uri, "https://example.com/agent-v2.json");
+}
+
+#[tokio::test]
+async fn non_owner_cannot_update_registration_uri() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let agent_identity_pda = setup::register_identity(&mut context, asset, collection).await;
+
+    // A random wallet that does not own the asset.
+    let attacker = Keypair::new();
+
+    let ix = UpdateAgentRegistrationUriV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .authority(Some(attacker.pubkey()))
+        .agent_registration_uri("https://evil.example.com/agent.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &attacker],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentIdentityError::OnlyAssetOwnerOrSignerCanUpdateUri as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_update_registration_uri_of_unregistered_asset() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let (agent_identity_pda, _) = AgentIdentityV2::find_pda(&asset);
+
+    let ix = UpdateAgentRegistrationUriV1Builder::new()
+        .agent_identity(agent_identity_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/agent-v2.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let err = context
+        .banks_client
+        .process_transaction(tx)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentIdentityError::InvalidAgentIdentity as u32);
+}
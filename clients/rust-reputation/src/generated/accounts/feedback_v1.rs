@@ -0,0 +1,108 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::FeedbackStatus;
+use crate::generated::types::Key;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct FeedbackV1 {
+    pub key: Key,
+    pub bump: u8,
+    pub score: u8,
+    pub status: FeedbackStatus,
+    pub weight: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub asset: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub reviewer: Pubkey,
+    pub index: u64,
+    pub tree_index: u64,
+    pub nonce: u64,
+    pub slot: u64,
+    pub response_count: u64,
+    pub response_slot: u64,
+    pub response_hash: [u8; 32],
+}
+
+impl FeedbackV1 {
+    pub const LEN: usize = 152;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `FeedbackV1::PREFIX`
+    ///   1. asset (`Pubkey`)
+    ///   2. reviewer (`Pubkey`)
+    ///   3. index (`u64`)
+    pub const PREFIX: &'static [u8] = "feedback".as_bytes();
+
+    pub fn create_pda(
+        asset: Pubkey,
+        reviewer: Pubkey,
+        index: u64,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "feedback".as_bytes(),
+                asset.as_ref(),
+                reviewer.as_ref(),
+                index.to_le_bytes().as_ref(),
+                &[bump],
+            ],
+            &crate::MPL_AGENT_REPUTATION_ID,
+        )
+    }
+
+    pub fn find_pda(
+        asset: &Pubkey,
+        reviewer: &Pubkey,
+        index: u64,
+    ) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "feedback".as_bytes(),
+                asset.as_ref(),
+                reviewer.as_ref(),
+                index.to_le_bytes().as_ref(),
+            ],
+            &crate::MPL_AGENT_REPUTATION_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for FeedbackV1 {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
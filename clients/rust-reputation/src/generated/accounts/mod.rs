@@ -6,5 +6,21 @@
 //!
 
 pub(crate) mod r#agent_reputation_v1;
+pub(crate) mod r#agent_reputation_v2;
+pub(crate) mod r#feedback_v1;
+pub(crate) mod r#receipt_nullifier_v1;
+pub(crate) mod r#reputation_config_v1;
+pub(crate) mod r#reputation_snapshot_v1;
+pub(crate) mod r#reviewer_history_v1;
+pub(crate) mod r#token_lock_v1;
+pub(crate) mod r#weighting_policy_v1;
 
 pub use self::r#agent_reputation_v1::*;
+pub use self::r#agent_reputation_v2::*;
+pub use self::r#feedback_v1::*;
+pub use self::r#receipt_nullifier_v1::*;
+pub use self::r#reputation_config_v1::*;
+pub use self::r#reputation_snapshot_v1::*;
+pub use self::r#reviewer_history_v1::*;
+pub use self::r#token_lock_v1::*;
+pub use self::r#weighting_policy_v1::*;
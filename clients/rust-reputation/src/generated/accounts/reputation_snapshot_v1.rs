@@ -0,0 +1,99 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::Key;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReputationSnapshotV1 {
+    pub key: Key,
+    pub bump: u8,
+    pub padding: [u8; 6],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub asset: Pubkey,
+    pub epoch: u64,
+    pub slot: u64,
+    pub feedback_count: u64,
+    pub score_sum: u64,
+    pub score_sum_squares: u64,
+    pub last_feedback_slot: u64,
+    pub decayed_score: u64,
+    pub decayed_count: u64,
+    pub weight_sum: u64,
+    pub weighted_score_sum: u64,
+    pub disputed_count: u64,
+    pub disputed_score_sum: u64,
+    pub disputed_weight_sum: u64,
+    pub disputed_weighted_score_sum: u64,
+}
+
+impl ReputationSnapshotV1 {
+    pub const LEN: usize = 152;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `ReputationSnapshotV1::PREFIX`
+    ///   1. asset (`Pubkey`)
+    ///   2. epoch (`u64`)
+    pub const PREFIX: &'static [u8] = "reputation_snapshot".as_bytes();
+
+    pub fn create_pda(
+        asset: Pubkey,
+        epoch: u64,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "reputation_snapshot".as_bytes(),
+                asset.as_ref(),
+                epoch.to_le_bytes().as_ref(),
+                &[bump],
+            ],
+            &crate::MPL_AGENT_REPUTATION_ID,
+        )
+    }
+
+    pub fn find_pda(asset: &Pubkey, epoch: u64) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "reputation_snapshot".as_bytes(),
+                asset.as_ref(),
+                epoch.to_le_bytes().as_ref(),
+            ],
+            &crate::MPL_AGENT_REPUTATION_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for ReputationSnapshotV1 {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
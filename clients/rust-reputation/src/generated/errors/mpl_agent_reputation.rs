@@ -29,6 +29,123 @@ pub enum MplAgentReputationError {
     /// 5 (0x5) - Agent Reputation already registered
     #[error("Agent Reputation already registered")]
     AgentReputationAlreadyRegistered,
+    /// 6 (0x6) - Agent Reputation is not registered
+    #[error("Agent Reputation is not registered")]
+    AgentReputationNotRegistered,
+    /// 7 (0x7) - Feedback score must be between 0 and 100
+    #[error("Feedback score must be between 0 and 100")]
+    InvalidFeedbackScore,
+    /// 8 (0x8) - Feedback URI is empty
+    #[error("Feedback URI is empty")]
+    FeedbackUriEmpty,
+    /// 9 (0x9) - Feedback URI exceeds the maximum length
+    #[error("Feedback URI exceeds the maximum length")]
+    FeedbackUriTooLong,
+    /// 10 (0xA) - Feedback URI is not valid UTF-8
+    #[error("Feedback URI is not valid UTF-8")]
+    FeedbackUriNotUtf8,
+    /// 11 (0xB) - Feedback URI scheme is not allowed
+    #[error("Feedback URI scheme is not allowed")]
+    FeedbackUriSchemeNotAllowed,
+    /// 12 (0xC) - Numerical overflow
+    #[error("Numerical overflow")]
+    NumericalOverflow,
+    /// 13 (0xD) - Invalid MPL Account Compression Program
+    #[error("Invalid MPL Account Compression Program")]
+    InvalidCompressionProgram,
+    /// 14 (0xE) - Invalid receipts tree
+    #[error("Invalid receipts tree")]
+    InvalidReceiptsTree,
+    /// 15 (0xF) - Work receipt was already reviewed
+    #[error("Work receipt was already reviewed")]
+    ReceiptAlreadyReviewed,
+    /// 16 (0x10) - Invalid feedback account
+    #[error("Invalid feedback account")]
+    InvalidFeedback,
+    /// 17 (0x11) - Feedback index is already used
+    #[error("Feedback index is already used")]
+    FeedbackAlreadyExists,
+    /// 18 (0x12) - Authority must be the asset owner or an execution delegate
+    #[error("Authority must be the asset owner or an execution delegate")]
+    InvalidResponseAuthority,
+    /// 19 (0x13) - Invalid execution delegate record
+    #[error("Invalid execution delegate record")]
+    InvalidExecutionDelegateRecord,
+    /// 20 (0x14) - Response URI is empty
+    #[error("Response URI is empty")]
+    ResponseUriEmpty,
+    /// 21 (0x15) - Response URI exceeds the maximum length
+    #[error("Response URI exceeds the maximum length")]
+    ResponseUriTooLong,
+    /// 22 (0x16) - Response URI is not valid UTF-8
+    #[error("Response URI is not valid UTF-8")]
+    ResponseUriNotUtf8,
+    /// 23 (0x17) - Response URI scheme is not allowed
+    #[error("Response URI scheme is not allowed")]
+    ResponseUriSchemeNotAllowed,
+    /// 24 (0x18) - Feedback has been revoked
+    #[error("Feedback has been revoked")]
+    FeedbackRevoked,
+    /// 25 (0x19) - Feedback is already disputed
+    #[error("Feedback is already disputed")]
+    FeedbackAlreadyDisputed,
+    /// 26 (0x1A) - Only the reviewer may revoke their feedback
+    #[error("Only the reviewer may revoke their feedback")]
+    InvalidReviewer,
+    /// 27 (0x1B) - Only the asset owner or an execution delegate may dispute feedback
+    #[error("Only the asset owner or an execution delegate may dispute feedback")]
+    InvalidDisputeAuthority,
+    /// 28 (0x1C) - Invalid reputation config account
+    #[error("Invalid reputation config account")]
+    InvalidReputationConfig,
+    /// 29 (0x1D) - Reputation config is already initialized
+    #[error("Reputation config is already initialized")]
+    ReputationConfigAlreadyInitialized,
+    /// 30 (0x1E) - Half-life must be non-zero
+    #[error("Half-life must be non-zero")]
+    InvalidHalfLife,
+    /// 31 (0x1F) - Invalid program data account
+    #[error("Invalid program data account")]
+    InvalidProgramData,
+    /// 32 (0x20) - Authority does not match the config authority
+    #[error("Authority does not match the config authority")]
+    InvalidConfigAuthority,
+    /// 33 (0x21) - Invalid agent identity account
+    #[error("Invalid agent identity account")]
+    InvalidAgentIdentity,
+    /// 34 (0x22) - Only the asset owner or its asset signer can register reputation
+    #[error("Only the asset owner or its asset signer can register reputation")]
+    OnlyAssetOwnerOrSignerCanRegisterReputation,
+    /// 35 (0x23) - Invalid weighting policy
+    #[error("Invalid weighting policy")]
+    InvalidWeightingPolicy,
+    /// 36 (0x24) - Invalid reviewer history account
+    #[error("Invalid reviewer history account")]
+    InvalidReviewerHistory,
+    /// 37 (0x25) - Reviewer asset and identity do not prove a registered reviewer
+    #[error("Reviewer asset and identity do not prove a registered reviewer")]
+    InvalidReviewerIdentity,
+    /// 38 (0x26) - Reviewer token account does not hold the weighting token
+    #[error("Reviewer token account does not hold the weighting token")]
+    InvalidReviewerTokenAccount,
+    /// 39 (0x27) - Invalid reputation snapshot account
+    #[error("Invalid reputation snapshot account")]
+    InvalidReputationSnapshot,
+    /// 40 (0x28) - Reputation was already snapshotted this epoch
+    #[error("Reputation was already snapshotted this epoch")]
+    ReputationAlreadySnapshotted,
+    /// 41 (0x29) - Invalid token lock account
+    #[error("Invalid token lock account")]
+    InvalidTokenLock,
+    /// 42 (0x2A) - Tokens are locked until a later slot
+    #[error("Tokens are locked until a later slot")]
+    TokensLocked,
+    /// 43 (0x2B) - Invalid token program
+    #[error("Invalid token program")]
+    InvalidTokenProgram,
+    /// 44 (0x2C) - Reviewer reputation does not prove an established reviewer
+    #[error("Reviewer reputation does not prove an established reviewer")]
+    InvalidReviewerReputation,
 }
 
 impl From<MplAgentReputationError> for ProgramError {
@@ -47,6 +164,45 @@ impl TryFrom<u32> for MplAgentReputationError {
             3 => Ok(MplAgentReputationError::InvalidMplCoreProgram),
             4 => Ok(MplAgentReputationError::InvalidCoreAsset),
             5 => Ok(MplAgentReputationError::AgentReputationAlreadyRegistered),
+            6 => Ok(MplAgentReputationError::AgentReputationNotRegistered),
+            7 => Ok(MplAgentReputationError::InvalidFeedbackScore),
+            8 => Ok(MplAgentReputationError::FeedbackUriEmpty),
+            9 => Ok(MplAgentReputationError::FeedbackUriTooLong),
+            10 => Ok(MplAgentReputationError::FeedbackUriNotUtf8),
+            11 => Ok(MplAgentReputationError::FeedbackUriSchemeNotAllowed),
+            12 => Ok(MplAgentReputationError::NumericalOverflow),
+            13 => Ok(MplAgentReputationError::InvalidCompressionProgram),
+            14 => Ok(MplAgentReputationError::InvalidReceiptsTree),
+            15 => Ok(MplAgentReputationError::ReceiptAlreadyReviewed),
+            16 => Ok(MplAgentReputationError::InvalidFeedback),
+            17 => Ok(MplAgentReputationError::FeedbackAlreadyExists),
+            18 => Ok(MplAgentReputationError::InvalidResponseAuthority),
+            19 => Ok(MplAgentReputationError::InvalidExecutionDelegateRecord),
+            20 => Ok(MplAgentReputationError::ResponseUriEmpty),
+            21 => Ok(MplAgentReputationError::ResponseUriTooLong),
+            22 => Ok(MplAgentReputationError::ResponseUriNotUtf8),
+            23 => Ok(MplAgentReputationError::ResponseUriSchemeNotAllowed),
+            24 => Ok(MplAgentReputationError::FeedbackRevoked),
+            25 => Ok(MplAgentReputationError::FeedbackAlreadyDisputed),
+            26 => Ok(MplAgentReputationError::InvalidReviewer),
+            27 => Ok(MplAgentReputationError::InvalidDisputeAuthority),
+            28 => Ok(MplAgentReputationError::InvalidReputationConfig),
+            29 => Ok(MplAgentReputationError::ReputationConfigAlreadyInitialized),
+            30 => Ok(MplAgentReputationError::InvalidHalfLife),
+            31 => Ok(MplAgentReputationError::InvalidProgramData),
+            32 => Ok(MplAgentReputationError::InvalidConfigAuthority),
+            33 => Ok(MplAgentReputationError::InvalidAgentIdentity),
+            34 => Ok(MplAgentReputationError::OnlyAssetOwnerOrSignerCanRegisterReputation),
+            35 => Ok(MplAgentReputationError::InvalidWeightingPolicy),
+            36 => Ok(MplAgentReputationError::InvalidReviewerHistory),
+            37 => Ok(MplAgentReputationError::InvalidReviewerIdentity),
+            38 => Ok(MplAgentReputationError::InvalidReviewerTokenAccount),
+            39 => Ok(MplAgentReputationError::InvalidReputationSnapshot),
+            40 => Ok(MplAgentReputationError::ReputationAlreadySnapshotted),
+            41 => Ok(MplAgentReputationError::InvalidTokenLock),
+            42 => Ok(MplAgentReputationError::TokensLocked),
+            43 => Ok(MplAgentReputationError::InvalidTokenProgram),
+            44 => Ok(MplAgentReputationError::InvalidReviewerReputation),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -63,6 +219,83 @@ impl ToStr for MplAgentReputationError {
             MplAgentReputationError::AgentReputationAlreadyRegistered => {
                 "Agent Reputation already registered"
             }
+            MplAgentReputationError::AgentReputationNotRegistered => {
+                "Agent Reputation is not registered"
+            }
+            MplAgentReputationError::InvalidFeedbackScore => {
+                "Feedback score must be between 0 and 100"
+            }
+            MplAgentReputationError::FeedbackUriEmpty => "Feedback URI is empty",
+            MplAgentReputationError::FeedbackUriTooLong => {
+                "Feedback URI exceeds the maximum length"
+            }
+            MplAgentReputationError::FeedbackUriNotUtf8 => "Feedback URI is not valid UTF-8",
+            MplAgentReputationError::FeedbackUriSchemeNotAllowed => {
+                "Feedback URI scheme is not allowed"
+            }
+            MplAgentReputationError::NumericalOverflow => "Numerical overflow",
+            MplAgentReputationError::InvalidCompressionProgram => {
+                "Invalid MPL Account Compression Program"
+            }
+            MplAgentReputationError::InvalidReceiptsTree => "Invalid receipts tree",
+            MplAgentReputationError::ReceiptAlreadyReviewed => "Work receipt was already reviewed",
+            MplAgentReputationError::InvalidFeedback => "Invalid feedback account",
+            MplAgentReputationError::FeedbackAlreadyExists => "Feedback index is already used",
+            MplAgentReputationError::InvalidResponseAuthority => {
+                "Authority must be the asset owner or an execution delegate"
+            }
+            MplAgentReputationError::InvalidExecutionDelegateRecord => {
+                "Invalid execution delegate record"
+            }
+            MplAgentReputationError::ResponseUriEmpty => "Response URI is empty",
+            MplAgentReputationError::ResponseUriTooLong => {
+                "Response URI exceeds the maximum length"
+            }
+            MplAgentReputationError::ResponseUriNotUtf8 => "Response URI is not valid UTF-8",
+            MplAgentReputationError::ResponseUriSchemeNotAllowed => {
+                "Response URI scheme is not allowed"
+            }
+            MplAgentReputationError::FeedbackRevoked => "Feedback has been revoked",
+            MplAgentReputationError::FeedbackAlreadyDisputed => "Feedback is already disputed",
+            MplAgentReputationError::InvalidReviewer => {
+                "Only the reviewer may revoke their feedback"
+            }
+            MplAgentReputationError::InvalidDisputeAuthority => {
+                "Only the asset owner or an execution delegate may dispute feedback"
+            }
+            MplAgentReputationError::InvalidReputationConfig => "Invalid reputation config account",
+            MplAgentReputationError::ReputationConfigAlreadyInitialized => {
+                "Reputation config is already initialized"
+            }
+            MplAgentReputationError::InvalidHalfLife => "Half-life must be non-zero",
+            MplAgentReputationError::InvalidProgramData => "Invalid program data account",
+            MplAgentReputationError::InvalidConfigAuthority => {
+                "Authority does not match the config authority"
+            }
+            MplAgentReputationError::InvalidAgentIdentity => "Invalid agent identity account",
+            MplAgentReputationError::OnlyAssetOwnerOrSignerCanRegisterReputation => {
+                "Only the asset owner or its asset signer can register reputation"
+            }
+            MplAgentReputationError::InvalidWeightingPolicy => "Invalid weighting policy",
+            MplAgentReputationError::InvalidReviewerHistory => "Invalid reviewer history account",
+            MplAgentReputationError::InvalidReviewerIdentity => {
+                "Reviewer asset and identity do not prove a registered reviewer"
+            }
+            MplAgentReputationError::InvalidReviewerTokenAccount => {
+                "Reviewer token account does not hold the weighting token"
+            }
+            MplAgentReputationError::InvalidReputationSnapshot => {
+                "Invalid reputation snapshot account"
+            }
+            MplAgentReputationError::ReputationAlreadySnapshotted => {
+                "Reputation was already snapshotted this epoch"
+            }
+            MplAgentReputationError::InvalidTokenLock => "Invalid token lock account",
+            MplAgentReputationError::TokensLocked => "Tokens are locked until a later slot",
+            MplAgentReputationError::InvalidTokenProgram => "Invalid token program",
+            MplAgentReputationError::InvalidReviewerReputation => {
+                "Reviewer reputation does not prove an established reviewer"
+            }
         }
     }
 }
@@ -0,0 +1,490 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AppendResponseV1 {
+    /// The feedback PDA being responded to
+    pub feedback: solana_program::pubkey::Pubkey,
+    /// The address of the Core asset
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The asset owner or the authority of the execution delegate record
+    pub authority: solana_program::pubkey::Pubkey,
+    /// The mpl-agent-tools execution delegate record, if the authority is not the asset owner
+    pub execution_delegate_record: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl AppendResponseV1 {
+    pub fn instruction(
+        &self,
+        args: AppendResponseV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: AppendResponseV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.feedback,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        if let Some(execution_delegate_record) = self.execution_delegate_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                execution_delegate_record,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(AppendResponseV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AppendResponseV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl AppendResponseV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 2,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct AppendResponseV1InstructionArgs {
+    pub response_hash: [u8; 32],
+    pub response_uri: String,
+}
+
+/// Instruction builder for `AppendResponseV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` feedback
+///   1. `[]` asset
+///   2. `[signer]` authority
+///   3. `[optional]` execution_delegate_record
+#[derive(Default)]
+pub struct AppendResponseV1Builder {
+    feedback: Option<solana_program::pubkey::Pubkey>,
+    asset: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    execution_delegate_record: Option<solana_program::pubkey::Pubkey>,
+    response_hash: Option<[u8; 32]>,
+    response_uri: Option<String>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AppendResponseV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The feedback PDA being responded to
+    #[inline(always)]
+    pub fn feedback(&mut self, feedback: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.feedback = Some(feedback);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// The asset owner or the authority of the execution delegate record
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// The mpl-agent-tools execution delegate record, if the authority is not the asset owner
+    #[inline(always)]
+    pub fn execution_delegate_record(
+        &mut self,
+        execution_delegate_record: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.execution_delegate_record = execution_delegate_record;
+        self
+    }
+    #[inline(always)]
+    pub fn response_hash(&mut self, response_hash: [u8; 32]) -> &mut Self {
+        self.response_hash = Some(response_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn response_uri(&mut self, response_uri: String) -> &mut Self {
+        self.response_uri = Some(response_uri);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AppendResponseV1 {
+            feedback: self.feedback.expect("feedback is not set"),
+            asset: self.asset.expect("asset is not set"),
+            authority: self.authority.expect("authority is not set"),
+            execution_delegate_record: self.execution_delegate_record,
+        };
+        let args = AppendResponseV1InstructionArgs {
+            response_hash: self
+                .response_hash
+                .clone()
+                .expect("response_hash is not set"),
+            response_uri: self.response_uri.clone().expect("response_uri is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `append_response_v1` CPI accounts.
+pub struct AppendResponseV1CpiAccounts<'a, 'b> {
+    /// The feedback PDA being responded to
+    pub feedback: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The asset owner or the authority of the execution delegate record
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl-agent-tools execution delegate record, if the authority is not the asset owner
+    pub execution_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `append_response_v1` CPI instruction.
+pub struct AppendResponseV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The feedback PDA being responded to
+    pub feedback: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The asset owner or the authority of the execution delegate record
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl-agent-tools execution delegate record, if the authority is not the asset owner
+    pub execution_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: AppendResponseV1InstructionArgs,
+}
+
+impl<'a, 'b> AppendResponseV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AppendResponseV1CpiAccounts<'a, 'b>,
+        args: AppendResponseV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            feedback: accounts.feedback,
+            asset: accounts.asset,
+            authority: accounts.authority,
+            execution_delegate_record: accounts.execution_delegate_record,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.feedback.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        if let Some(execution_delegate_record) = self.execution_delegate_record {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *execution_delegate_record.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(AppendResponseV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.feedback.clone());
+        account_infos.push(self.asset.clone());
+        account_infos.push(self.authority.clone());
+        if let Some(execution_delegate_record) = self.execution_delegate_record {
+            account_infos.push(execution_delegate_record.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AppendResponseV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` feedback
+///   1. `[]` asset
+///   2. `[signer]` authority
+///   3. `[optional]` execution_delegate_record
+pub struct AppendResponseV1CpiBuilder<'a, 'b> {
+    instruction: Box<AppendResponseV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AppendResponseV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AppendResponseV1CpiBuilderInstruction {
+            __program: program,
+            feedback: None,
+            asset: None,
+            authority: None,
+            execution_delegate_record: None,
+            response_hash: None,
+            response_uri: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The feedback PDA being responded to
+    #[inline(always)]
+    pub fn feedback(
+        &mut self,
+        feedback: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.feedback = Some(feedback);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// The asset owner or the authority of the execution delegate record
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// The mpl-agent-tools execution delegate record, if the authority is not the asset owner
+    #[inline(always)]
+    pub fn execution_delegate_record(
+        &mut self,
+        execution_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.execution_delegate_record = execution_delegate_record;
+        self
+    }
+    #[inline(always)]
+    pub fn response_hash(&mut self, response_hash: [u8; 32]) -> &mut Self {
+        self.instruction.response_hash = Some(response_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn response_uri(&mut self, response_uri: String) -> &mut Self {
+        self.instruction.response_uri = Some(response_uri);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = AppendResponseV1InstructionArgs {
+            response_hash: self
+                .instruction
+                .response_hash
+                .clone()
+                .expect("response_hash is not set"),
+            response_uri: self
+                .instruction
+                .response_uri
+                .clone()
+                .expect("response_uri is not set"),
+        };
+        let instruction = AppendResponseV1Cpi {
+            __program: self.instruction.__program,
+
+            feedback: self.instruction.feedback.expect("feedback is not set"),
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            execution_delegate_record: self.instruction.execution_delegate_record,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AppendResponseV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    feedback: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    execution_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    response_hash: Option<[u8; 32]>,
+    response_uri: Option<String>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
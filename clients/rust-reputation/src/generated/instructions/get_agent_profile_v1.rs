@@ -0,0 +1,419 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct GetAgentProfileV1 {
+    /// The address of the Core asset
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The mpl-agent-identity agent identity PDA, initialized or not
+    pub agent_identity: solana_program::pubkey::Pubkey,
+    /// The agent reputation PDA, initialized or not
+    pub agent_reputation: solana_program::pubkey::Pubkey,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: solana_program::pubkey::Pubkey,
+}
+
+impl GetAgentProfileV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.agent_identity,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.agent_reputation,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reputation_config,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(GetAgentProfileV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct GetAgentProfileV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl GetAgentProfileV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 8,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+/// Instruction builder for `GetAgentProfileV1`.
+///
+/// ### Accounts:
+///
+///   0. `[]` asset
+///   1. `[]` agent_identity
+///   2. `[]` agent_reputation
+///   3. `[]` reputation_config
+#[derive(Default)]
+pub struct GetAgentProfileV1Builder {
+    asset: Option<solana_program::pubkey::Pubkey>,
+    agent_identity: Option<solana_program::pubkey::Pubkey>,
+    agent_reputation: Option<solana_program::pubkey::Pubkey>,
+    reputation_config: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl GetAgentProfileV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// The mpl-agent-identity agent identity PDA, initialized or not
+    #[inline(always)]
+    pub fn agent_identity(&mut self, agent_identity: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.agent_identity = Some(agent_identity);
+        self
+    }
+    /// The agent reputation PDA, initialized or not
+    #[inline(always)]
+    pub fn agent_reputation(
+        &mut self,
+        agent_reputation: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.agent_reputation = Some(agent_reputation);
+        self
+    }
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reputation_config = Some(reputation_config);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = GetAgentProfileV1 {
+            asset: self.asset.expect("asset is not set"),
+            agent_identity: self.agent_identity.expect("agent_identity is not set"),
+            agent_reputation: self.agent_reputation.expect("agent_reputation is not set"),
+            reputation_config: self
+                .reputation_config
+                .expect("reputation_config is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `get_agent_profile_v1` CPI accounts.
+pub struct GetAgentProfileV1CpiAccounts<'a, 'b> {
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl-agent-identity agent identity PDA, initialized or not
+    pub agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent reputation PDA, initialized or not
+    pub agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `get_agent_profile_v1` CPI instruction.
+pub struct GetAgentProfileV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mpl-agent-identity agent identity PDA, initialized or not
+    pub agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent reputation PDA, initialized or not
+    pub agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> GetAgentProfileV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: GetAgentProfileV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            asset: accounts.asset,
+            agent_identity: accounts.agent_identity,
+            agent_reputation: accounts.agent_reputation,
+            reputation_config: accounts.reputation_config,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.agent_identity.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.agent_reputation.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reputation_config.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(GetAgentProfileV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.asset.clone());
+        account_infos.push(self.agent_identity.clone());
+        account_infos.push(self.agent_reputation.clone());
+        account_infos.push(self.reputation_config.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `GetAgentProfileV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` asset
+///   1. `[]` agent_identity
+///   2. `[]` agent_reputation
+///   3. `[]` reputation_config
+pub struct GetAgentProfileV1CpiBuilder<'a, 'b> {
+    instruction: Box<GetAgentProfileV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> GetAgentProfileV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(GetAgentProfileV1CpiBuilderInstruction {
+            __program: program,
+            asset: None,
+            agent_identity: None,
+            agent_reputation: None,
+            reputation_config: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// The mpl-agent-identity agent identity PDA, initialized or not
+    #[inline(always)]
+    pub fn agent_identity(
+        &mut self,
+        agent_identity: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_identity = Some(agent_identity);
+        self
+    }
+    /// The agent reputation PDA, initialized or not
+    #[inline(always)]
+    pub fn agent_reputation(
+        &mut self,
+        agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_reputation = Some(agent_reputation);
+        self
+    }
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reputation_config = Some(reputation_config);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = GetAgentProfileV1Cpi {
+            __program: self.instruction.__program,
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            agent_identity: self
+                .instruction
+                .agent_identity
+                .expect("agent_identity is not set"),
+
+            agent_reputation: self
+                .instruction
+                .agent_reputation
+                .expect("agent_reputation is not set"),
+
+            reputation_config: self
+                .instruction
+                .reputation_config
+                .expect("reputation_config is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct GetAgentProfileV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    agent_identity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    agent_reputation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reputation_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
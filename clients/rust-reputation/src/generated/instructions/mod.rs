@@ -5,6 +5,30 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#append_response_v1;
+pub(crate) mod r#dispute_feedback_v1;
+pub(crate) mod r#get_agent_profile_v1;
+pub(crate) mod r#initialize_reputation_config_v1;
+pub(crate) mod r#refresh_reputation_v1;
+pub(crate) mod r#register_reputation_as_owner_v1;
 pub(crate) mod r#register_reputation_v1;
+pub(crate) mod r#revoke_feedback_v1;
+pub(crate) mod r#set_weighting_policy_v1;
+pub(crate) mod r#snapshot_reputation_v1;
+pub(crate) mod r#submit_feedback_v1;
+pub(crate) mod r#unlock_tokens_v1;
+pub(crate) mod r#update_reputation_config_v1;
 
+pub use self::r#append_response_v1::*;
+pub use self::r#dispute_feedback_v1::*;
+pub use self::r#get_agent_profile_v1::*;
+pub use self::r#initialize_reputation_config_v1::*;
+pub use self::r#refresh_reputation_v1::*;
+pub use self::r#register_reputation_as_owner_v1::*;
 pub use self::r#register_reputation_v1::*;
+pub use self::r#revoke_feedback_v1::*;
+pub use self::r#set_weighting_policy_v1::*;
+pub use self::r#snapshot_reputation_v1::*;
+pub use self::r#submit_feedback_v1::*;
+pub use self::r#unlock_tokens_v1::*;
+pub use self::r#update_reputation_config_v1::*;
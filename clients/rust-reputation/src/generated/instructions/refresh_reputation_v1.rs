@@ -0,0 +1,342 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct RefreshReputationV1 {
+    /// The agent reputation PDA. Must be of type AgentReputationV2.
+    pub agent_reputation: solana_program::pubkey::Pubkey,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: solana_program::pubkey::Pubkey,
+}
+
+impl RefreshReputationV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.agent_reputation,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reputation_config,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(RefreshReputationV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RefreshReputationV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl RefreshReputationV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 7,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+/// Instruction builder for `RefreshReputationV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_reputation
+///   1. `[]` reputation_config
+#[derive(Default)]
+pub struct RefreshReputationV1Builder {
+    agent_reputation: Option<solana_program::pubkey::Pubkey>,
+    reputation_config: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RefreshReputationV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The agent reputation PDA. Must be of type AgentReputationV2.
+    #[inline(always)]
+    pub fn agent_reputation(
+        &mut self,
+        agent_reputation: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.agent_reputation = Some(agent_reputation);
+        self
+    }
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reputation_config = Some(reputation_config);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RefreshReputationV1 {
+            agent_reputation: self.agent_reputation.expect("agent_reputation is not set"),
+            reputation_config: self
+                .reputation_config
+                .expect("reputation_config is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `refresh_reputation_v1` CPI accounts.
+pub struct RefreshReputationV1CpiAccounts<'a, 'b> {
+    /// The agent reputation PDA. Must be of type AgentReputationV2.
+    pub agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `refresh_reputation_v1` CPI instruction.
+pub struct RefreshReputationV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent reputation PDA. Must be of type AgentReputationV2.
+    pub agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> RefreshReputationV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RefreshReputationV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            agent_reputation: accounts.agent_reputation,
+            reputation_config: accounts.reputation_config,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(2 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.agent_reputation.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reputation_config.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(RefreshReputationV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(2 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.agent_reputation.clone());
+        account_infos.push(self.reputation_config.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RefreshReputationV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_reputation
+///   1. `[]` reputation_config
+pub struct RefreshReputationV1CpiBuilder<'a, 'b> {
+    instruction: Box<RefreshReputationV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RefreshReputationV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RefreshReputationV1CpiBuilderInstruction {
+            __program: program,
+            agent_reputation: None,
+            reputation_config: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The agent reputation PDA. Must be of type AgentReputationV2.
+    #[inline(always)]
+    pub fn agent_reputation(
+        &mut self,
+        agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_reputation = Some(agent_reputation);
+        self
+    }
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reputation_config = Some(reputation_config);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = RefreshReputationV1Cpi {
+            __program: self.instruction.__program,
+
+            agent_reputation: self
+                .instruction
+                .agent_reputation
+                .expect("agent_reputation is not set"),
+
+            reputation_config: self
+                .instruction
+                .reputation_config
+                .expect("reputation_config is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RefreshReputationV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    agent_reputation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reputation_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,687 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct SetWeightingPolicyV1 {
+    /// The weighting policy PDA at ["weighting_policy"]
+    pub weighting_policy: solana_program::pubkey::Pubkey,
+    /// The initialized reputation config PDA at ["reputation_config"]
+    pub reputation_config: solana_program::pubkey::Pubkey,
+    /// The config authority
+    pub authority: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl SetWeightingPolicyV1 {
+    pub fn instruction(
+        &self,
+        args: SetWeightingPolicyV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: SetWeightingPolicyV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.weighting_policy,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reputation_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(SetWeightingPolicyV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct SetWeightingPolicyV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+    padding1: [u8; 4],
+}
+
+impl SetWeightingPolicyV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 10,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+            padding1: [0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SetWeightingPolicyV1InstructionArgs {
+    pub base_weight: u32,
+    pub receipt_weight: u32,
+    pub max_receipts: u32,
+    pub identity_weight: u32,
+    pub token_weight: u32,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub token_mint: Pubkey,
+    pub min_token_balance: u64,
+    pub min_identity_feedback: u64,
+    pub token_lock_slots: u64,
+}
+
+/// Instruction builder for `SetWeightingPolicyV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` weighting_policy
+///   1. `[]` reputation_config
+///   2. `[signer]` authority
+///   3. `[writable, signer]` payer
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct SetWeightingPolicyV1Builder {
+    weighting_policy: Option<solana_program::pubkey::Pubkey>,
+    reputation_config: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    base_weight: Option<u32>,
+    receipt_weight: Option<u32>,
+    max_receipts: Option<u32>,
+    identity_weight: Option<u32>,
+    token_weight: Option<u32>,
+    token_mint: Option<Pubkey>,
+    min_token_balance: Option<u64>,
+    min_identity_feedback: Option<u64>,
+    token_lock_slots: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl SetWeightingPolicyV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The weighting policy PDA at ["weighting_policy"]
+    #[inline(always)]
+    pub fn weighting_policy(
+        &mut self,
+        weighting_policy: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.weighting_policy = Some(weighting_policy);
+        self
+    }
+    /// The initialized reputation config PDA at ["reputation_config"]
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reputation_config = Some(reputation_config);
+        self
+    }
+    /// The config authority
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn base_weight(&mut self, base_weight: u32) -> &mut Self {
+        self.base_weight = Some(base_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_weight(&mut self, receipt_weight: u32) -> &mut Self {
+        self.receipt_weight = Some(receipt_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn max_receipts(&mut self, max_receipts: u32) -> &mut Self {
+        self.max_receipts = Some(max_receipts);
+        self
+    }
+    #[inline(always)]
+    pub fn identity_weight(&mut self, identity_weight: u32) -> &mut Self {
+        self.identity_weight = Some(identity_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn token_weight(&mut self, token_weight: u32) -> &mut Self {
+        self.token_weight = Some(token_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn token_mint(&mut self, token_mint: Pubkey) -> &mut Self {
+        self.token_mint = Some(token_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn min_token_balance(&mut self, min_token_balance: u64) -> &mut Self {
+        self.min_token_balance = Some(min_token_balance);
+        self
+    }
+    #[inline(always)]
+    pub fn min_identity_feedback(&mut self, min_identity_feedback: u64) -> &mut Self {
+        self.min_identity_feedback = Some(min_identity_feedback);
+        self
+    }
+    #[inline(always)]
+    pub fn token_lock_slots(&mut self, token_lock_slots: u64) -> &mut Self {
+        self.token_lock_slots = Some(token_lock_slots);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = SetWeightingPolicyV1 {
+            weighting_policy: self.weighting_policy.expect("weighting_policy is not set"),
+            reputation_config: self
+                .reputation_config
+                .expect("reputation_config is not set"),
+            authority: self.authority.expect("authority is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = SetWeightingPolicyV1InstructionArgs {
+            base_weight: self.base_weight.clone().expect("base_weight is not set"),
+            receipt_weight: self
+                .receipt_weight
+                .clone()
+                .expect("receipt_weight is not set"),
+            max_receipts: self.max_receipts.clone().expect("max_receipts is not set"),
+            identity_weight: self
+                .identity_weight
+                .clone()
+                .expect("identity_weight is not set"),
+            token_weight: self.token_weight.clone().expect("token_weight is not set"),
+            token_mint: self.token_mint.clone().expect("token_mint is not set"),
+            min_token_balance: self
+                .min_token_balance
+                .clone()
+                .expect("min_token_balance is not set"),
+            min_identity_feedback: self
+                .min_identity_feedback
+                .clone()
+                .expect("min_identity_feedback is not set"),
+            token_lock_slots: self
+                .token_lock_slots
+                .clone()
+                .expect("token_lock_slots is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `set_weighting_policy_v1` CPI accounts.
+pub struct SetWeightingPolicyV1CpiAccounts<'a, 'b> {
+    /// The weighting policy PDA at ["weighting_policy"]
+    pub weighting_policy: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The initialized reputation config PDA at ["reputation_config"]
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The config authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `set_weighting_policy_v1` CPI instruction.
+pub struct SetWeightingPolicyV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The weighting policy PDA at ["weighting_policy"]
+    pub weighting_policy: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The initialized reputation config PDA at ["reputation_config"]
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The config authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: SetWeightingPolicyV1InstructionArgs,
+}
+
+impl<'a, 'b> SetWeightingPolicyV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: SetWeightingPolicyV1CpiAccounts<'a, 'b>,
+        args: SetWeightingPolicyV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            weighting_policy: accounts.weighting_policy,
+            reputation_config: accounts.reputation_config,
+            authority: accounts.authority,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.weighting_policy.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reputation_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(SetWeightingPolicyV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.weighting_policy.clone());
+        account_infos.push(self.reputation_config.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `SetWeightingPolicyV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` weighting_policy
+///   1. `[]` reputation_config
+///   2. `[signer]` authority
+///   3. `[writable, signer]` payer
+///   4. `[]` system_program
+pub struct SetWeightingPolicyV1CpiBuilder<'a, 'b> {
+    instruction: Box<SetWeightingPolicyV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> SetWeightingPolicyV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(SetWeightingPolicyV1CpiBuilderInstruction {
+            __program: program,
+            weighting_policy: None,
+            reputation_config: None,
+            authority: None,
+            payer: None,
+            system_program: None,
+            base_weight: None,
+            receipt_weight: None,
+            max_receipts: None,
+            identity_weight: None,
+            token_weight: None,
+            token_mint: None,
+            min_token_balance: None,
+            min_identity_feedback: None,
+            token_lock_slots: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The weighting policy PDA at ["weighting_policy"]
+    #[inline(always)]
+    pub fn weighting_policy(
+        &mut self,
+        weighting_policy: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.weighting_policy = Some(weighting_policy);
+        self
+    }
+    /// The initialized reputation config PDA at ["reputation_config"]
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reputation_config = Some(reputation_config);
+        self
+    }
+    /// The config authority
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn base_weight(&mut self, base_weight: u32) -> &mut Self {
+        self.instruction.base_weight = Some(base_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_weight(&mut self, receipt_weight: u32) -> &mut Self {
+        self.instruction.receipt_weight = Some(receipt_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn max_receipts(&mut self, max_receipts: u32) -> &mut Self {
+        self.instruction.max_receipts = Some(max_receipts);
+        self
+    }
+    #[inline(always)]
+    pub fn identity_weight(&mut self, identity_weight: u32) -> &mut Self {
+        self.instruction.identity_weight = Some(identity_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn token_weight(&mut self, token_weight: u32) -> &mut Self {
+        self.instruction.token_weight = Some(token_weight);
+        self
+    }
+    #[inline(always)]
+    pub fn token_mint(&mut self, token_mint: Pubkey) -> &mut Self {
+        self.instruction.token_mint = Some(token_mint);
+        self
+    }
+    #[inline(always)]
+    pub fn min_token_balance(&mut self, min_token_balance: u64) -> &mut Self {
+        self.instruction.min_token_balance = Some(min_token_balance);
+        self
+    }
+    #[inline(always)]
+    pub fn min_identity_feedback(&mut self, min_identity_feedback: u64) -> &mut Self {
+        self.instruction.min_identity_feedback = Some(min_identity_feedback);
+        self
+    }
+    #[inline(always)]
+    pub fn token_lock_slots(&mut self, token_lock_slots: u64) -> &mut Self {
+        self.instruction.token_lock_slots = Some(token_lock_slots);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = SetWeightingPolicyV1InstructionArgs {
+            base_weight: self
+                .instruction
+                .base_weight
+                .clone()
+                .expect("base_weight is not set"),
+            receipt_weight: self
+                .instruction
+                .receipt_weight
+                .clone()
+                .expect("receipt_weight is not set"),
+            max_receipts: self
+                .instruction
+                .max_receipts
+                .clone()
+                .expect("max_receipts is not set"),
+            identity_weight: self
+                .instruction
+                .identity_weight
+                .clone()
+                .expect("identity_weight is not set"),
+            token_weight: self
+                .instruction
+                .token_weight
+                .clone()
+                .expect("token_weight is not set"),
+            token_mint: self
+                .instruction
+                .token_mint
+                .clone()
+                .expect("token_mint is not set"),
+            min_token_balance: self
+                .instruction
+                .min_token_balance
+                .clone()
+                .expect("min_token_balance is not set"),
+            min_identity_feedback: self
+                .instruction
+                .min_identity_feedback
+                .clone()
+                .expect("min_identity_feedback is not set"),
+            token_lock_slots: self
+                .instruction
+                .token_lock_slots
+                .clone()
+                .expect("token_lock_slots is not set"),
+        };
+        let instruction = SetWeightingPolicyV1Cpi {
+            __program: self.instruction.__program,
+
+            weighting_policy: self
+                .instruction
+                .weighting_policy
+                .expect("weighting_policy is not set"),
+
+            reputation_config: self
+                .instruction
+                .reputation_config
+                .expect("reputation_config is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct SetWeightingPolicyV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    weighting_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reputation_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    base_weight: Option<u32>,
+    receipt_weight: Option<u32>,
+    max_receipts: Option<u32>,
+    identity_weight: Option<u32>,
+    token_weight: Option<u32>,
+    token_mint: Option<Pubkey>,
+    min_token_balance: Option<u64>,
+    min_identity_feedback: Option<u64>,
+    token_lock_slots: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,1408 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct SubmitFeedbackV1 {
+    /// The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2.
+    pub agent_reputation: solana_program::pubkey::Pubkey,
+    /// The address of the Core asset
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The address of the collection
+    pub collection: Option<solana_program::pubkey::Pubkey>,
+    /// The author of the feedback and owner of the work receipt
+    pub reviewer: solana_program::pubkey::Pubkey,
+    /// The receipt nullifier PDA at ["receipt_nullifier", tree_index_le, nonce_le]
+    pub receipt_nullifier: solana_program::pubkey::Pubkey,
+    /// The feedback PDA at ["feedback", asset, reviewer, feedback_index_le]
+    pub feedback: solana_program::pubkey::Pubkey,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: solana_program::pubkey::Pubkey,
+    /// The weighting policy PDA at ["weighting_policy"], set or not
+    pub weighting_policy: solana_program::pubkey::Pubkey,
+    /// The reviewer history PDA at ["reviewer_history", asset, reviewer]
+    pub reviewer_history: solana_program::pubkey::Pubkey,
+    /// A Core asset owned by the reviewer, proving with its agent identity and reputation that the reviewer is established
+    pub reviewer_asset: Option<solana_program::pubkey::Pubkey>,
+    /// The mpl-agent-identity agent identity PDA of the reviewer asset
+    pub reviewer_identity: Option<solana_program::pubkey::Pubkey>,
+    /// The agent reputation PDA of the reviewer asset
+    pub reviewer_reputation: Option<solana_program::pubkey::Pubkey>,
+    /// The token lock PDA at ["token_lock", reviewer, token_mint], created on first use
+    pub reviewer_token_lock: Option<solana_program::pubkey::Pubkey>,
+    /// A token account of the token lock PDA holding the weighting policy token
+    pub reviewer_token_vault: Option<solana_program::pubkey::Pubkey>,
+    /// The mpl-agent-tools receipts merkle tree holding the work receipt
+    pub merkle_tree: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The MPL Account Compression program
+    pub compression_program: solana_program::pubkey::Pubkey,
+    /// The MPL Core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl SubmitFeedbackV1 {
+    pub fn instruction(
+        &self,
+        args: SubmitFeedbackV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: SubmitFeedbackV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.agent_reputation,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.asset, false,
+        ));
+        if let Some(collection) = self.collection {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                collection, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reviewer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.receipt_nullifier,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.feedback,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reputation_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.weighting_policy,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.reviewer_history,
+            false,
+        ));
+        if let Some(reviewer_asset) = self.reviewer_asset {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                reviewer_asset,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_identity) = self.reviewer_identity {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                reviewer_identity,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_reputation) = self.reviewer_reputation {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                reviewer_reputation,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_token_lock) = self.reviewer_token_lock {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                reviewer_token_lock,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_token_vault) = self.reviewer_token_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                reviewer_token_vault,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.merkle_tree,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.compression_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(SubmitFeedbackV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct SubmitFeedbackV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 1],
+}
+
+impl SubmitFeedbackV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 1,
+            padding: [0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SubmitFeedbackV1InstructionArgs {
+    pub score: u8,
+    pub flags: u8,
+    pub index: u32,
+    pub tree_index: u64,
+    pub nonce: u64,
+    pub feedback_index: u64,
+    pub root: [u8; 32],
+    pub data_hash: [u8; 32],
+    pub asset_data_hash: [u8; 32],
+    pub tag1: [u8; 32],
+    pub tag2: [u8; 32],
+    pub feedback_uri: String,
+}
+
+/// Instruction builder for `SubmitFeedbackV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_reputation
+///   1. `[writable]` asset
+///   2. `[writable, optional]` collection
+///   3. `[signer]` reviewer
+///   4. `[writable]` receipt_nullifier
+///   5. `[writable]` feedback
+///   6. `[]` reputation_config
+///   7. `[]` weighting_policy
+///   8. `[writable]` reviewer_history
+///   9. `[optional]` reviewer_asset
+///   10. `[optional]` reviewer_identity
+///   11. `[optional]` reviewer_reputation
+///   12. `[writable, optional]` reviewer_token_lock
+///   13. `[optional]` reviewer_token_vault
+///   14. `[]` merkle_tree
+///   15. `[writable, signer]` payer
+///   16. `[optional]` compression_program (default to `mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW`)
+///   17. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   18. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct SubmitFeedbackV1Builder {
+    agent_reputation: Option<solana_program::pubkey::Pubkey>,
+    asset: Option<solana_program::pubkey::Pubkey>,
+    collection: Option<solana_program::pubkey::Pubkey>,
+    reviewer: Option<solana_program::pubkey::Pubkey>,
+    receipt_nullifier: Option<solana_program::pubkey::Pubkey>,
+    feedback: Option<solana_program::pubkey::Pubkey>,
+    reputation_config: Option<solana_program::pubkey::Pubkey>,
+    weighting_policy: Option<solana_program::pubkey::Pubkey>,
+    reviewer_history: Option<solana_program::pubkey::Pubkey>,
+    reviewer_asset: Option<solana_program::pubkey::Pubkey>,
+    reviewer_identity: Option<solana_program::pubkey::Pubkey>,
+    reviewer_reputation: Option<solana_program::pubkey::Pubkey>,
+    reviewer_token_lock: Option<solana_program::pubkey::Pubkey>,
+    reviewer_token_vault: Option<solana_program::pubkey::Pubkey>,
+    merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    compression_program: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    score: Option<u8>,
+    flags: Option<u8>,
+    index: Option<u32>,
+    tree_index: Option<u64>,
+    nonce: Option<u64>,
+    feedback_index: Option<u64>,
+    root: Option<[u8; 32]>,
+    data_hash: Option<[u8; 32]>,
+    asset_data_hash: Option<[u8; 32]>,
+    tag1: Option<[u8; 32]>,
+    tag2: Option<[u8; 32]>,
+    feedback_uri: Option<String>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl SubmitFeedbackV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2.
+    #[inline(always)]
+    pub fn agent_reputation(
+        &mut self,
+        agent_reputation: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.agent_reputation = Some(agent_reputation);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the collection
+    #[inline(always)]
+    pub fn collection(&mut self, collection: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.collection = collection;
+        self
+    }
+    /// The author of the feedback and owner of the work receipt
+    #[inline(always)]
+    pub fn reviewer(&mut self, reviewer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reviewer = Some(reviewer);
+        self
+    }
+    /// The receipt nullifier PDA at ["receipt_nullifier", tree_index_le, nonce_le]
+    #[inline(always)]
+    pub fn receipt_nullifier(
+        &mut self,
+        receipt_nullifier: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.receipt_nullifier = Some(receipt_nullifier);
+        self
+    }
+    /// The feedback PDA at ["feedback", asset, reviewer, feedback_index_le]
+    #[inline(always)]
+    pub fn feedback(&mut self, feedback: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.feedback = Some(feedback);
+        self
+    }
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reputation_config = Some(reputation_config);
+        self
+    }
+    /// The weighting policy PDA at ["weighting_policy"], set or not
+    #[inline(always)]
+    pub fn weighting_policy(
+        &mut self,
+        weighting_policy: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.weighting_policy = Some(weighting_policy);
+        self
+    }
+    /// The reviewer history PDA at ["reviewer_history", asset, reviewer]
+    #[inline(always)]
+    pub fn reviewer_history(
+        &mut self,
+        reviewer_history: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reviewer_history = Some(reviewer_history);
+        self
+    }
+    /// `[optional account]`
+    /// A Core asset owned by the reviewer, proving with its agent identity and reputation that the reviewer is established
+    #[inline(always)]
+    pub fn reviewer_asset(
+        &mut self,
+        reviewer_asset: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.reviewer_asset = reviewer_asset;
+        self
+    }
+    /// `[optional account]`
+    /// The mpl-agent-identity agent identity PDA of the reviewer asset
+    #[inline(always)]
+    pub fn reviewer_identity(
+        &mut self,
+        reviewer_identity: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.reviewer_identity = reviewer_identity;
+        self
+    }
+    /// `[optional account]`
+    /// The agent reputation PDA of the reviewer asset
+    #[inline(always)]
+    pub fn reviewer_reputation(
+        &mut self,
+        reviewer_reputation: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.reviewer_reputation = reviewer_reputation;
+        self
+    }
+    /// `[optional account]`
+    /// The token lock PDA at ["token_lock", reviewer, token_mint], created on first use
+    #[inline(always)]
+    pub fn reviewer_token_lock(
+        &mut self,
+        reviewer_token_lock: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.reviewer_token_lock = reviewer_token_lock;
+        self
+    }
+    /// `[optional account]`
+    /// A token account of the token lock PDA holding the weighting policy token
+    #[inline(always)]
+    pub fn reviewer_token_vault(
+        &mut self,
+        reviewer_token_vault: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.reviewer_token_vault = reviewer_token_vault;
+        self
+    }
+    /// The mpl-agent-tools receipts merkle tree holding the work receipt
+    #[inline(always)]
+    pub fn merkle_tree(&mut self, merkle_tree: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to 'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW']`
+    /// The MPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.compression_program = Some(compression_program);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn score(&mut self, score: u8) -> &mut Self {
+        self.score = Some(score);
+        self
+    }
+    #[inline(always)]
+    pub fn flags(&mut self, flags: u8) -> &mut Self {
+        self.flags = Some(flags);
+        self
+    }
+    #[inline(always)]
+    pub fn index(&mut self, index: u32) -> &mut Self {
+        self.index = Some(index);
+        self
+    }
+    #[inline(always)]
+    pub fn tree_index(&mut self, tree_index: u64) -> &mut Self {
+        self.tree_index = Some(tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn feedback_index(&mut self, feedback_index: u64) -> &mut Self {
+        self.feedback_index = Some(feedback_index);
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn data_hash(&mut self, data_hash: [u8; 32]) -> &mut Self {
+        self.data_hash = Some(data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn asset_data_hash(&mut self, asset_data_hash: [u8; 32]) -> &mut Self {
+        self.asset_data_hash = Some(asset_data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn tag1(&mut self, tag1: [u8; 32]) -> &mut Self {
+        self.tag1 = Some(tag1);
+        self
+    }
+    #[inline(always)]
+    pub fn tag2(&mut self, tag2: [u8; 32]) -> &mut Self {
+        self.tag2 = Some(tag2);
+        self
+    }
+    #[inline(always)]
+    pub fn feedback_uri(&mut self, feedback_uri: String) -> &mut Self {
+        self.feedback_uri = Some(feedback_uri);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = SubmitFeedbackV1 {
+            agent_reputation: self.agent_reputation.expect("agent_reputation is not set"),
+            asset: self.asset.expect("asset is not set"),
+            collection: self.collection,
+            reviewer: self.reviewer.expect("reviewer is not set"),
+            receipt_nullifier: self
+                .receipt_nullifier
+                .expect("receipt_nullifier is not set"),
+            feedback: self.feedback.expect("feedback is not set"),
+            reputation_config: self
+                .reputation_config
+                .expect("reputation_config is not set"),
+            weighting_policy: self.weighting_policy.expect("weighting_policy is not set"),
+            reviewer_history: self.reviewer_history.expect("reviewer_history is not set"),
+            reviewer_asset: self.reviewer_asset,
+            reviewer_identity: self.reviewer_identity,
+            reviewer_reputation: self.reviewer_reputation,
+            reviewer_token_lock: self.reviewer_token_lock,
+            reviewer_token_vault: self.reviewer_token_vault,
+            merkle_tree: self.merkle_tree.expect("merkle_tree is not set"),
+            payer: self.payer.expect("payer is not set"),
+            compression_program: self.compression_program.unwrap_or(solana_program::pubkey!(
+                "mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW"
+            )),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = SubmitFeedbackV1InstructionArgs {
+            score: self.score.clone().expect("score is not set"),
+            flags: self.flags.clone().expect("flags is not set"),
+            index: self.index.clone().expect("index is not set"),
+            tree_index: self.tree_index.clone().expect("tree_index is not set"),
+            nonce: self.nonce.clone().expect("nonce is not set"),
+            feedback_index: self
+                .feedback_index
+                .clone()
+                .expect("feedback_index is not set"),
+            root: self.root.clone().expect("root is not set"),
+            data_hash: self.data_hash.clone().expect("data_hash is not set"),
+            asset_data_hash: self
+                .asset_data_hash
+                .clone()
+                .expect("asset_data_hash is not set"),
+            tag1: self.tag1.clone().expect("tag1 is not set"),
+            tag2: self.tag2.clone().expect("tag2 is not set"),
+            feedback_uri: self.feedback_uri.clone().expect("feedback_uri is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `submit_feedback_v1` CPI accounts.
+pub struct SubmitFeedbackV1CpiAccounts<'a, 'b> {
+    /// The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2.
+    pub agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the collection
+    pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The author of the feedback and owner of the work receipt
+    pub reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The receipt nullifier PDA at ["receipt_nullifier", tree_index_le, nonce_le]
+    pub receipt_nullifier: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The feedback PDA at ["feedback", asset, reviewer, feedback_index_le]
+    pub feedback: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The weighting policy PDA at ["weighting_policy"], set or not
+    pub weighting_policy: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reviewer history PDA at ["reviewer_history", asset, reviewer]
+    pub reviewer_history: &'b solana_program::account_info::AccountInfo<'a>,
+    /// A Core asset owned by the reviewer, proving with its agent identity and reputation that the reviewer is established
+    pub reviewer_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The mpl-agent-identity agent identity PDA of the reviewer asset
+    pub reviewer_identity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The agent reputation PDA of the reviewer asset
+    pub reviewer_reputation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token lock PDA at ["token_lock", reviewer, token_mint], created on first use
+    pub reviewer_token_lock: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// A token account of the token lock PDA holding the weighting policy token
+    pub reviewer_token_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The mpl-agent-tools receipts merkle tree holding the work receipt
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `submit_feedback_v1` CPI instruction.
+pub struct SubmitFeedbackV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2.
+    pub agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the collection
+    pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The author of the feedback and owner of the work receipt
+    pub reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The receipt nullifier PDA at ["receipt_nullifier", tree_index_le, nonce_le]
+    pub receipt_nullifier: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The feedback PDA at ["feedback", asset, reviewer, feedback_index_le]
+    pub feedback: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The weighting policy PDA at ["weighting_policy"], set or not
+    pub weighting_policy: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reviewer history PDA at ["reviewer_history", asset, reviewer]
+    pub reviewer_history: &'b solana_program::account_info::AccountInfo<'a>,
+    /// A Core asset owned by the reviewer, proving with its agent identity and reputation that the reviewer is established
+    pub reviewer_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The mpl-agent-identity agent identity PDA of the reviewer asset
+    pub reviewer_identity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The agent reputation PDA of the reviewer asset
+    pub reviewer_reputation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The token lock PDA at ["token_lock", reviewer, token_mint], created on first use
+    pub reviewer_token_lock: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// A token account of the token lock PDA holding the weighting policy token
+    pub reviewer_token_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The mpl-agent-tools receipts merkle tree holding the work receipt
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: SubmitFeedbackV1InstructionArgs,
+}
+
+impl<'a, 'b> SubmitFeedbackV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: SubmitFeedbackV1CpiAccounts<'a, 'b>,
+        args: SubmitFeedbackV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            agent_reputation: accounts.agent_reputation,
+            asset: accounts.asset,
+            collection: accounts.collection,
+            reviewer: accounts.reviewer,
+            receipt_nullifier: accounts.receipt_nullifier,
+            feedback: accounts.feedback,
+            reputation_config: accounts.reputation_config,
+            weighting_policy: accounts.weighting_policy,
+            reviewer_history: accounts.reviewer_history,
+            reviewer_asset: accounts.reviewer_asset,
+            reviewer_identity: accounts.reviewer_identity,
+            reviewer_reputation: accounts.reviewer_reputation,
+            reviewer_token_lock: accounts.reviewer_token_lock,
+            reviewer_token_vault: accounts.reviewer_token_vault,
+            merkle_tree: accounts.merkle_tree,
+            payer: accounts.payer,
+            compression_program: accounts.compression_program,
+            mpl_core_program: accounts.mpl_core_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(19 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.agent_reputation.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.asset.key,
+            false,
+        ));
+        if let Some(collection) = self.collection {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *collection.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reviewer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.receipt_nullifier.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.feedback.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reputation_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.weighting_policy.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.reviewer_history.key,
+            false,
+        ));
+        if let Some(reviewer_asset) = self.reviewer_asset {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *reviewer_asset.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_identity) = self.reviewer_identity {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *reviewer_identity.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_reputation) = self.reviewer_reputation {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *reviewer_reputation.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_token_lock) = self.reviewer_token_lock {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *reviewer_token_lock.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        if let Some(reviewer_token_vault) = self.reviewer_token_vault {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *reviewer_token_vault.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.merkle_tree.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.compression_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(SubmitFeedbackV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(19 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.agent_reputation.clone());
+        account_infos.push(self.asset.clone());
+        if let Some(collection) = self.collection {
+            account_infos.push(collection.clone());
+        }
+        account_infos.push(self.reviewer.clone());
+        account_infos.push(self.receipt_nullifier.clone());
+        account_infos.push(self.feedback.clone());
+        account_infos.push(self.reputation_config.clone());
+        account_infos.push(self.weighting_policy.clone());
+        account_infos.push(self.reviewer_history.clone());
+        if let Some(reviewer_asset) = self.reviewer_asset {
+            account_infos.push(reviewer_asset.clone());
+        }
+        if let Some(reviewer_identity) = self.reviewer_identity {
+            account_infos.push(reviewer_identity.clone());
+        }
+        if let Some(reviewer_reputation) = self.reviewer_reputation {
+            account_infos.push(reviewer_reputation.clone());
+        }
+        if let Some(reviewer_token_lock) = self.reviewer_token_lock {
+            account_infos.push(reviewer_token_lock.clone());
+        }
+        if let Some(reviewer_token_vault) = self.reviewer_token_vault {
+            account_infos.push(reviewer_token_vault.clone());
+        }
+        account_infos.push(self.merkle_tree.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.compression_program.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `SubmitFeedbackV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` agent_reputation
+///   1. `[writable]` asset
+///   2. `[writable, optional]` collection
+///   3. `[signer]` reviewer
+///   4. `[writable]` receipt_nullifier
+///   5. `[writable]` feedback
+///   6. `[]` reputation_config
+///   7. `[]` weighting_policy
+///   8. `[writable]` reviewer_history
+///   9. `[optional]` reviewer_asset
+///   10. `[optional]` reviewer_identity
+///   11. `[optional]` reviewer_reputation
+///   12. `[writable, optional]` reviewer_token_lock
+///   13. `[optional]` reviewer_token_vault
+///   14. `[]` merkle_tree
+///   15. `[writable, signer]` payer
+///   16. `[]` compression_program
+///   17. `[]` mpl_core_program
+///   18. `[]` system_program
+pub struct SubmitFeedbackV1CpiBuilder<'a, 'b> {
+    instruction: Box<SubmitFeedbackV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> SubmitFeedbackV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(SubmitFeedbackV1CpiBuilderInstruction {
+            __program: program,
+            agent_reputation: None,
+            asset: None,
+            collection: None,
+            reviewer: None,
+            receipt_nullifier: None,
+            feedback: None,
+            reputation_config: None,
+            weighting_policy: None,
+            reviewer_history: None,
+            reviewer_asset: None,
+            reviewer_identity: None,
+            reviewer_reputation: None,
+            reviewer_token_lock: None,
+            reviewer_token_vault: None,
+            merkle_tree: None,
+            payer: None,
+            compression_program: None,
+            mpl_core_program: None,
+            system_program: None,
+            score: None,
+            flags: None,
+            index: None,
+            tree_index: None,
+            nonce: None,
+            feedback_index: None,
+            root: None,
+            data_hash: None,
+            asset_data_hash: None,
+            tag1: None,
+            tag2: None,
+            feedback_uri: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2.
+    #[inline(always)]
+    pub fn agent_reputation(
+        &mut self,
+        agent_reputation: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_reputation = Some(agent_reputation);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the collection
+    #[inline(always)]
+    pub fn collection(
+        &mut self,
+        collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.collection = collection;
+        self
+    }
+    /// The author of the feedback and owner of the work receipt
+    #[inline(always)]
+    pub fn reviewer(
+        &mut self,
+        reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reviewer = Some(reviewer);
+        self
+    }
+    /// The receipt nullifier PDA at ["receipt_nullifier", tree_index_le, nonce_le]
+    #[inline(always)]
+    pub fn receipt_nullifier(
+        &mut self,
+        receipt_nullifier: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_nullifier = Some(receipt_nullifier);
+        self
+    }
+    /// The feedback PDA at ["feedback", asset, reviewer, feedback_index_le]
+    #[inline(always)]
+    pub fn feedback(
+        &mut self,
+        feedback: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.feedback = Some(feedback);
+        self
+    }
+    /// The reputation config PDA at ["reputation_config"], initialized or not
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reputation_config = Some(reputation_config);
+        self
+    }
+    /// The weighting policy PDA at ["weighting_policy"], set or not
+    #[inline(always)]
+    pub fn weighting_policy(
+        &mut self,
+        weighting_policy: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.weighting_policy = Some(weighting_policy);
+        self
+    }
+    /// The reviewer history PDA at ["reviewer_history", asset, reviewer]
+    #[inline(always)]
+    pub fn reviewer_history(
+        &mut self,
+        reviewer_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reviewer_history = Some(reviewer_history);
+        self
+    }
+    /// `[optional account]`
+    /// A Core asset owned by the reviewer, proving with its agent identity and reputation that the reviewer is established
+    #[inline(always)]
+    pub fn reviewer_asset(
+        &mut self,
+        reviewer_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.reviewer_asset = reviewer_asset;
+        self
+    }
+    /// `[optional account]`
+    /// The mpl-agent-identity agent identity PDA of the reviewer asset
+    #[inline(always)]
+    pub fn reviewer_identity(
+        &mut self,
+        reviewer_identity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.reviewer_identity = reviewer_identity;
+        self
+    }
+    /// `[optional account]`
+    /// The agent reputation PDA of the reviewer asset
+    #[inline(always)]
+    pub fn reviewer_reputation(
+        &mut self,
+        reviewer_reputation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.reviewer_reputation = reviewer_reputation;
+        self
+    }
+    /// `[optional account]`
+    /// The token lock PDA at ["token_lock", reviewer, token_mint], created on first use
+    #[inline(always)]
+    pub fn reviewer_token_lock(
+        &mut self,
+        reviewer_token_lock: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.reviewer_token_lock = reviewer_token_lock;
+        self
+    }
+    /// `[optional account]`
+    /// A token account of the token lock PDA holding the weighting policy token
+    #[inline(always)]
+    pub fn reviewer_token_vault(
+        &mut self,
+        reviewer_token_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.reviewer_token_vault = reviewer_token_vault;
+        self
+    }
+    /// The mpl-agent-tools receipts merkle tree holding the work receipt
+    #[inline(always)]
+    pub fn merkle_tree(
+        &mut self,
+        merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The MPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.compression_program = Some(compression_program);
+        self
+    }
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn score(&mut self, score: u8) -> &mut Self {
+        self.instruction.score = Some(score);
+        self
+    }
+    #[inline(always)]
+    pub fn flags(&mut self, flags: u8) -> &mut Self {
+        self.instruction.flags = Some(flags);
+        self
+    }
+    #[inline(always)]
+    pub fn index(&mut self, index: u32) -> &mut Self {
+        self.instruction.index = Some(index);
+        self
+    }
+    #[inline(always)]
+    pub fn tree_index(&mut self, tree_index: u64) -> &mut Self {
+        self.instruction.tree_index = Some(tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.instruction.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn feedback_index(&mut self, feedback_index: u64) -> &mut Self {
+        self.instruction.feedback_index = Some(feedback_index);
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.instruction.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn data_hash(&mut self, data_hash: [u8; 32]) -> &mut Self {
+        self.instruction.data_hash = Some(data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn asset_data_hash(&mut self, asset_data_hash: [u8; 32]) -> &mut Self {
+        self.instruction.asset_data_hash = Some(asset_data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn tag1(&mut self, tag1: [u8; 32]) -> &mut Self {
+        self.instruction.tag1 = Some(tag1);
+        self
+    }
+    #[inline(always)]
+    pub fn tag2(&mut self, tag2: [u8; 32]) -> &mut Self {
+        self.instruction.tag2 = Some(tag2);
+        self
+    }
+    #[inline(always)]
+    pub fn feedback_uri(&mut self, feedback_uri: String) -> &mut Self {
+        self.instruction.feedback_uri = Some(feedback_uri);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = SubmitFeedbackV1InstructionArgs {
+            score: self.instruction.score.clone().expect("score is not set"),
+            flags: self.instruction.flags.clone().expect("flags is not set"),
+            index: self.instruction.index.clone().expect("index is not set"),
+            tree_index: self
+                .instruction
+                .tree_index
+                .clone()
+                .expect("tree_index is not set"),
+            nonce: self.instruction.nonce.clone().expect("nonce is not set"),
+            feedback_index: self
+                .instruction
+                .feedback_index
+                .clone()
+                .expect("feedback_index is not set"),
+            root: self.instruction.root.clone().expect("root is not set"),
+            data_hash: self
+                .instruction
+                .data_hash
+                .clone()
+                .expect("data_hash is not set"),
+            asset_data_hash: self
+                .instruction
+                .asset_data_hash
+                .clone()
+                .expect("asset_data_hash is not set"),
+            tag1: self.instruction.tag1.clone().expect("tag1 is not set"),
+            tag2: self.instruction.tag2.clone().expect("tag2 is not set"),
+            feedback_uri: self
+                .instruction
+                .feedback_uri
+                .clone()
+                .expect("feedback_uri is not set"),
+        };
+        let instruction = SubmitFeedbackV1Cpi {
+            __program: self.instruction.__program,
+
+            agent_reputation: self
+                .instruction
+                .agent_reputation
+                .expect("agent_reputation is not set"),
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            collection: self.instruction.collection,
+
+            reviewer: self.instruction.reviewer.expect("reviewer is not set"),
+
+            receipt_nullifier: self
+                .instruction
+                .receipt_nullifier
+                .expect("receipt_nullifier is not set"),
+
+            feedback: self.instruction.feedback.expect("feedback is not set"),
+
+            reputation_config: self
+                .instruction
+                .reputation_config
+                .expect("reputation_config is not set"),
+
+            weighting_policy: self
+                .instruction
+                .weighting_policy
+                .expect("weighting_policy is not set"),
+
+            reviewer_history: self
+                .instruction
+                .reviewer_history
+                .expect("reviewer_history is not set"),
+
+            reviewer_asset: self.instruction.reviewer_asset,
+
+            reviewer_identity: self.instruction.reviewer_identity,
+
+            reviewer_reputation: self.instruction.reviewer_reputation,
+
+            reviewer_token_lock: self.instruction.reviewer_token_lock,
+
+            reviewer_token_vault: self.instruction.reviewer_token_vault,
+
+            merkle_tree: self
+                .instruction
+                .merkle_tree
+                .expect("merkle_tree is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            compression_program: self
+                .instruction
+                .compression_program
+                .expect("compression_program is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct SubmitFeedbackV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    agent_reputation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_nullifier: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    feedback: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reputation_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    weighting_policy: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer_identity: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer_reputation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer_token_lock: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer_token_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    compression_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    score: Option<u8>,
+    flags: Option<u8>,
+    index: Option<u32>,
+    tree_index: Option<u64>,
+    nonce: Option<u64>,
+    feedback_index: Option<u64>,
+    root: Option<[u8; 32]>,
+    data_hash: Option<[u8; 32]>,
+    asset_data_hash: Option<[u8; 32]>,
+    tag1: Option<[u8; 32]>,
+    tag2: Option<[u8; 32]>,
+    feedback_uri: Option<String>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,529 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct UnlockTokensV1 {
+    /// The token lock PDA at ["token_lock", reviewer, mint]
+    pub token_lock: solana_program::pubkey::Pubkey,
+    /// The token account of the token lock PDA holding the tokens
+    pub token_vault: solana_program::pubkey::Pubkey,
+    /// The token account receiving the tokens
+    pub destination: solana_program::pubkey::Pubkey,
+    /// The mint of the locked tokens
+    pub mint: solana_program::pubkey::Pubkey,
+    /// The reviewer owning the lock
+    pub reviewer: solana_program::pubkey::Pubkey,
+    /// The SPL Token or Token 2022 program owning the token accounts
+    pub token_program: solana_program::pubkey::Pubkey,
+}
+
+impl UnlockTokensV1 {
+    pub fn instruction(
+        &self,
+        args: UnlockTokensV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: UnlockTokensV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_lock,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.token_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mint, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reviewer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.token_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(UnlockTokensV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct UnlockTokensV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl UnlockTokensV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 12,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UnlockTokensV1InstructionArgs {
+    pub amount: u64,
+}
+
+/// Instruction builder for `UnlockTokensV1`.
+///
+/// ### Accounts:
+///
+///   0. `[]` token_lock
+///   1. `[writable]` token_vault
+///   2. `[writable]` destination
+///   3. `[]` mint
+///   4. `[signer]` reviewer
+///   5. `[]` token_program
+#[derive(Default)]
+pub struct UnlockTokensV1Builder {
+    token_lock: Option<solana_program::pubkey::Pubkey>,
+    token_vault: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    mint: Option<solana_program::pubkey::Pubkey>,
+    reviewer: Option<solana_program::pubkey::Pubkey>,
+    token_program: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl UnlockTokensV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The token lock PDA at ["token_lock", reviewer, mint]
+    #[inline(always)]
+    pub fn token_lock(&mut self, token_lock: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_lock = Some(token_lock);
+        self
+    }
+    /// The token account of the token lock PDA holding the tokens
+    #[inline(always)]
+    pub fn token_vault(&mut self, token_vault: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_vault = Some(token_vault);
+        self
+    }
+    /// The token account receiving the tokens
+    #[inline(always)]
+    pub fn destination(&mut self, destination: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    /// The mint of the locked tokens
+    #[inline(always)]
+    pub fn mint(&mut self, mint: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.mint = Some(mint);
+        self
+    }
+    /// The reviewer owning the lock
+    #[inline(always)]
+    pub fn reviewer(&mut self, reviewer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reviewer = Some(reviewer);
+        self
+    }
+    /// The SPL Token or Token 2022 program owning the token accounts
+    #[inline(always)]
+    pub fn token_program(&mut self, token_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = UnlockTokensV1 {
+            token_lock: self.token_lock.expect("token_lock is not set"),
+            token_vault: self.token_vault.expect("token_vault is not set"),
+            destination: self.destination.expect("destination is not set"),
+            mint: self.mint.expect("mint is not set"),
+            reviewer: self.reviewer.expect("reviewer is not set"),
+            token_program: self.token_program.expect("token_program is not set"),
+        };
+        let args = UnlockTokensV1InstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `unlock_tokens_v1` CPI accounts.
+pub struct UnlockTokensV1CpiAccounts<'a, 'b> {
+    /// The token lock PDA at ["token_lock", reviewer, mint]
+    pub token_lock: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account of the token lock PDA holding the tokens
+    pub token_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the tokens
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mint of the locked tokens
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reviewer owning the lock
+    pub reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL Token or Token 2022 program owning the token accounts
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `unlock_tokens_v1` CPI instruction.
+pub struct UnlockTokensV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token lock PDA at ["token_lock", reviewer, mint]
+    pub token_lock: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account of the token lock PDA holding the tokens
+    pub token_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The token account receiving the tokens
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The mint of the locked tokens
+    pub mint: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reviewer owning the lock
+    pub reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The SPL Token or Token 2022 program owning the token accounts
+    pub token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: UnlockTokensV1InstructionArgs,
+}
+
+impl<'a, 'b> UnlockTokensV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: UnlockTokensV1CpiAccounts<'a, 'b>,
+        args: UnlockTokensV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            token_lock: accounts.token_lock,
+            token_vault: accounts.token_vault,
+            destination: accounts.destination,
+            mint: accounts.mint,
+            reviewer: accounts.reviewer,
+            token_program: accounts.token_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_lock.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.token_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mint.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reviewer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.token_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(UnlockTokensV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.token_lock.clone());
+        account_infos.push(self.token_vault.clone());
+        account_infos.push(self.destination.clone());
+        account_infos.push(self.mint.clone());
+        account_infos.push(self.reviewer.clone());
+        account_infos.push(self.token_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `UnlockTokensV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[]` token_lock
+///   1. `[writable]` token_vault
+///   2. `[writable]` destination
+///   3. `[]` mint
+///   4. `[signer]` reviewer
+///   5. `[]` token_program
+pub struct UnlockTokensV1CpiBuilder<'a, 'b> {
+    instruction: Box<UnlockTokensV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> UnlockTokensV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(UnlockTokensV1CpiBuilderInstruction {
+            __program: program,
+            token_lock: None,
+            token_vault: None,
+            destination: None,
+            mint: None,
+            reviewer: None,
+            token_program: None,
+            amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The token lock PDA at ["token_lock", reviewer, mint]
+    #[inline(always)]
+    pub fn token_lock(
+        &mut self,
+        token_lock: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_lock = Some(token_lock);
+        self
+    }
+    /// The token account of the token lock PDA holding the tokens
+    #[inline(always)]
+    pub fn token_vault(
+        &mut self,
+        token_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_vault = Some(token_vault);
+        self
+    }
+    /// The token account receiving the tokens
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination = Some(destination);
+        self
+    }
+    /// The mint of the locked tokens
+    #[inline(always)]
+    pub fn mint(&mut self, mint: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.mint = Some(mint);
+        self
+    }
+    /// The reviewer owning the lock
+    #[inline(always)]
+    pub fn reviewer(
+        &mut self,
+        reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reviewer = Some(reviewer);
+        self
+    }
+    /// The SPL Token or Token 2022 program owning the token accounts
+    #[inline(always)]
+    pub fn token_program(
+        &mut self,
+        token_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.token_program = Some(token_program);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = UnlockTokensV1InstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
+        let instruction = UnlockTokensV1Cpi {
+            __program: self.instruction.__program,
+
+            token_lock: self.instruction.token_lock.expect("token_lock is not set"),
+
+            token_vault: self
+                .instruction
+                .token_vault
+                .expect("token_vault is not set"),
+
+            destination: self
+                .instruction
+                .destination
+                .expect("destination is not set"),
+
+            mint: self.instruction.mint.expect("mint is not set"),
+
+            reviewer: self.instruction.reviewer.expect("reviewer is not set"),
+
+            token_program: self
+                .instruction
+                .token_program
+                .expect("token_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct UnlockTokensV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    token_lock: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mint: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    token_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
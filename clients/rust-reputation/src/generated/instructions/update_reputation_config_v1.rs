@@ -0,0 +1,443 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct UpdateReputationConfigV1 {
+    /// The reputation config PDA at ["reputation_config"]
+    pub reputation_config: solana_program::pubkey::Pubkey,
+    /// The config authority
+    pub authority: solana_program::pubkey::Pubkey,
+    /// The new config authority, if it changes
+    pub new_authority: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl UpdateReputationConfigV1 {
+    pub fn instruction(
+        &self,
+        args: UpdateReputationConfigV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: UpdateReputationConfigV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.reputation_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        if let Some(new_authority) = self.new_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                new_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(UpdateReputationConfigV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct UpdateReputationConfigV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl UpdateReputationConfigV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 6,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateReputationConfigV1InstructionArgs {
+    pub half_life_slots: u64,
+}
+
+/// Instruction builder for `UpdateReputationConfigV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reputation_config
+///   1. `[signer]` authority
+///   2. `[optional]` new_authority
+#[derive(Default)]
+pub struct UpdateReputationConfigV1Builder {
+    reputation_config: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    new_authority: Option<solana_program::pubkey::Pubkey>,
+    half_life_slots: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl UpdateReputationConfigV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The reputation config PDA at ["reputation_config"]
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.reputation_config = Some(reputation_config);
+        self
+    }
+    /// The config authority
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// The new config authority, if it changes
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.new_authority = new_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn half_life_slots(&mut self, half_life_slots: u64) -> &mut Self {
+        self.half_life_slots = Some(half_life_slots);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = UpdateReputationConfigV1 {
+            reputation_config: self
+                .reputation_config
+                .expect("reputation_config is not set"),
+            authority: self.authority.expect("authority is not set"),
+            new_authority: self.new_authority,
+        };
+        let args = UpdateReputationConfigV1InstructionArgs {
+            half_life_slots: self
+                .half_life_slots
+                .clone()
+                .expect("half_life_slots is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `update_reputation_config_v1` CPI accounts.
+pub struct UpdateReputationConfigV1CpiAccounts<'a, 'b> {
+    /// The reputation config PDA at ["reputation_config"]
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The config authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new config authority, if it changes
+    pub new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `update_reputation_config_v1` CPI instruction.
+pub struct UpdateReputationConfigV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The reputation config PDA at ["reputation_config"]
+    pub reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The config authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new config authority, if it changes
+    pub new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: UpdateReputationConfigV1InstructionArgs,
+}
+
+impl<'a, 'b> UpdateReputationConfigV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: UpdateReputationConfigV1CpiAccounts<'a, 'b>,
+        args: UpdateReputationConfigV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            reputation_config: accounts.reputation_config,
+            authority: accounts.authority,
+            new_authority: accounts.new_authority,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(3 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.reputation_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        if let Some(new_authority) = self.new_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *new_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_REPUTATION_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(UpdateReputationConfigV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_REPUTATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(3 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.reputation_config.clone());
+        account_infos.push(self.authority.clone());
+        if let Some(new_authority) = self.new_authority {
+            account_infos.push(new_authority.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `UpdateReputationConfigV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` reputation_config
+///   1. `[signer]` authority
+///   2. `[optional]` new_authority
+pub struct UpdateReputationConfigV1CpiBuilder<'a, 'b> {
+    instruction: Box<UpdateReputationConfigV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> UpdateReputationConfigV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(UpdateReputationConfigV1CpiBuilderInstruction {
+            __program: program,
+            reputation_config: None,
+            authority: None,
+            new_authority: None,
+            half_life_slots: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The reputation config PDA at ["reputation_config"]
+    #[inline(always)]
+    pub fn reputation_config(
+        &mut self,
+        reputation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reputation_config = Some(reputation_config);
+        self
+    }
+    /// The config authority
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// The new config authority, if it changes
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.new_authority = new_authority;
+        self
+    }
+    #[inline(always)]
+    pub fn half_life_slots(&mut self, half_life_slots: u64) -> &mut Self {
+        self.instruction.half_life_slots = Some(half_life_slots);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = UpdateReputationConfigV1InstructionArgs {
+            half_life_slots: self
+                .instruction
+                .half_life_slots
+                .clone()
+                .expect("half_life_slots is not set"),
+        };
+        let instruction = UpdateReputationConfigV1Cpi {
+            __program: self.instruction.__program,
+
+            reputation_config: self
+                .instruction
+                .reputation_config
+                .expect("reputation_config is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            new_authority: self.instruction.new_authority,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct UpdateReputationConfigV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    reputation_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    half_life_slots: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -18,4 +18,12 @@ use num_derive::FromPrimitive;
 pub enum Key {
     Uninitialized,
     AgentReputationV1,
+    AgentReputationV2,
+    ReceiptNullifierV1,
+    FeedbackV1,
+    ReputationConfigV1,
+    WeightingPolicyV1,
+    ReviewerHistoryV1,
+    ReputationSnapshotV1,
+    TokenLockV1,
 }
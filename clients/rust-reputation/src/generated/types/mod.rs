@@ -5,6 +5,10 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#feedback_status;
 pub(crate) mod r#key;
+pub(crate) mod r#mpl_agent_reputation_event;
 
+pub use self::r#feedback_status::*;
 pub use self::r#key::*;
+pub use self::r#mpl_agent_reputation_event::*;
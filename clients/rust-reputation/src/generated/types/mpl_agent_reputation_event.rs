@@ -0,0 +1,160 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub enum MplAgentReputationEvent {
+    FeedbackSubmittedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        asset: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        reviewer: Pubkey,
+        score: u8,
+        tag1: [u8; 32],
+        tag2: [u8; 32],
+        feedback_uri: String,
+    },
+    ResponseAppendedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        asset: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        reviewer: Pubkey,
+        feedback_index: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        responder: Pubkey,
+        response_uri: String,
+        response_hash: [u8; 32],
+    },
+    FeedbackRevokedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        asset: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        reviewer: Pubkey,
+        feedback_index: u64,
+        score: u8,
+    },
+    FeedbackDisputedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        asset: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        reviewer: Pubkey,
+        feedback_index: u64,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        disputer: Pubkey,
+    },
+    ReputationConfigUpdatedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        authority: Pubkey,
+        half_life_slots: u64,
+    },
+    ReputationRefreshedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        asset: Pubkey,
+        decayed_score: u64,
+        decayed_count: u64,
+        slot: u64,
+    },
+    WeightingPolicyUpdatedV1 {
+        base_weight: u32,
+        receipt_weight: u32,
+        max_receipts: u32,
+        identity_weight: u32,
+        token_weight: u32,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        token_mint: Pubkey,
+        min_token_balance: u64,
+        min_identity_feedback: u64,
+        token_lock_slots: u64,
+    },
+    FeedbackWeightedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        asset: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        reviewer: Pubkey,
+        feedback_index: u64,
+        weight: u32,
+    },
+    ReputationSnapshottedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        asset: Pubkey,
+        epoch: u64,
+        slot: u64,
+        feedback_count: u64,
+        score_sum: u64,
+        decayed_score: u64,
+        decayed_count: u64,
+    },
+    TokensUnlockedV1 {
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        reviewer: Pubkey,
+        #[cfg_attr(
+            feature = "serde",
+            serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+        )]
+        mint: Pubkey,
+        amount: u64,
+    },
+}
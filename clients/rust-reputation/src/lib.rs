@@ -1,4 +1,9 @@
+pub mod events;
+#[allow(unexpected_cfgs, clippy::new_without_default)]
 mod generated;
+pub mod profile;
+pub mod snapshots;
+pub mod summary;
 
 pub use generated::programs::MPL_AGENT_REPUTATION_ID as ID;
 pub use generated::*;
@@ -0,0 +1,145 @@
+//! Reader for the agent profile `GetAgentProfileV1` returns as return data,
+//! for programs checking an agent in-transaction.
+//!
+//! The profile mirrors `mpl_agent_reputation_program::state::AgentProfileV1`:
+//! a version byte, three flag bytes, 4 bytes of padding, the asset and agent
+//! token addresses and thirteen little-endian `u64` fields.
+
+use solana_program::{program::get_return_data, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::instructions::GetAgentProfileV1Cpi;
+
+/// Profile of an agent.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct AgentProfileV1 {
+    /// Whether the asset has an agent identity.
+    pub identity_registered: bool,
+    /// Whether the asset has an agent reputation.
+    pub reputation_registered: bool,
+    /// The address of the asset.
+    pub asset: Pubkey,
+    /// The address of the agent token, if one is linked.
+    pub agent_token: Option<Pubkey>,
+    /// Number of feedback entries in the aggregates.
+    pub feedback_count: u64,
+    /// Sum of the feedback scores.
+    pub score_sum: u64,
+    /// Sum of the squared feedback scores.
+    pub score_sum_squares: u64,
+    /// Slot of the last feedback.
+    pub last_feedback_slot: u64,
+    /// Decayed score as of `slot`, as Q32.32.
+    pub decayed_score: u64,
+    /// Decayed feedback count as of `slot`, as Q32.32.
+    pub decayed_count: u64,
+    /// Slot the profile was read at.
+    pub slot: u64,
+    /// Sum of the feedback weights.
+    pub weight_sum: u64,
+    /// Sum of the feedback scores, each multiplied by its weight.
+    pub weighted_score_sum: u64,
+    /// Number of disputed feedback entries, included in the aggregates.
+    pub disputed_count: u64,
+    /// Sum of the disputed feedback scores.
+    pub disputed_score_sum: u64,
+    /// Sum of the disputed feedback weights.
+    pub disputed_weight_sum: u64,
+    /// Sum of the disputed feedback scores, each multiplied by its weight.
+    pub disputed_weighted_score_sum: u64,
+}
+
+impl AgentProfileV1 {
+    /// Version byte of this layout.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the encoded profile in bytes.
+    pub const LEN: usize = 176;
+
+    /// Decode a profile from return data. Returns `None` if the data has an
+    /// unknown version or is too short.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < Self::LEN || data[0] != Self::VERSION {
+            return None;
+        }
+
+        let pubkey =
+            |offset: usize| Pubkey::new_from_array(data[offset..offset + 32].try_into().unwrap());
+        let field =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Some(Self {
+            identity_registered: data[1] != 0,
+            reputation_registered: data[2] != 0,
+            asset: pubkey(8),
+            agent_token: (data[3] != 0).then(|| pubkey(40)),
+            feedback_count: field(72),
+            score_sum: field(80),
+            score_sum_squares: field(88),
+            last_feedback_slot: field(96),
+            decayed_score: field(104),
+            decayed_count: field(112),
+            slot: field(120),
+            weight_sum: field(128),
+            weighted_score_sum: field(136),
+            disputed_count: field(144),
+            disputed_score_sum: field(152),
+            disputed_weight_sum: field(160),
+            disputed_weighted_score_sum: field(168),
+        })
+    }
+
+    /// Read the profile from the return data of the last instruction, which
+    /// must be a `GetAgentProfileV1` of this program.
+    pub fn from_return_data() -> Option<Self> {
+        get_return_data()
+            .filter(|(program_id, _)| *program_id == crate::ID)
+            .and_then(|(_, data)| Self::from_bytes(&data))
+    }
+
+    /// Whether the asset has both an agent identity and an agent reputation.
+    pub fn is_registered(&self) -> bool {
+        self.identity_registered && self.reputation_registered
+    }
+
+    /// Whether at least `min_count` feedback entries were submitted and their
+    /// mean score is at least `min_score`. Uses integer arithmetic only.
+    pub fn meets_average_score(&self, min_score: u8, min_count: u64) -> bool {
+        self.feedback_count > 0
+            && self.feedback_count >= min_count
+            && self.score_sum as u128 >= min_score as u128 * self.feedback_count as u128
+    }
+
+    /// Mean of the feedback scores, if any feedback was submitted.
+    pub fn average_score(&self) -> Option<f64> {
+        (self.feedback_count > 0).then(|| self.score_sum as f64 / self.feedback_count as f64)
+    }
+
+    /// Mean of the feedback scores weighted by reviewer, if any weighted
+    /// feedback was submitted.
+    pub fn weighted_average_score(&self) -> Option<f64> {
+        (self.weight_sum > 0).then(|| self.weighted_score_sum as f64 / self.weight_sum as f64)
+    }
+
+    /// Mean of the undisputed feedback scores, if any undisputed feedback
+    /// was submitted.
+    pub fn undisputed_average_score(&self) -> Option<f64> {
+        let count = self.feedback_count.saturating_sub(self.disputed_count);
+        (count > 0)
+            .then(|| self.score_sum.saturating_sub(self.disputed_score_sum) as f64 / count as f64)
+    }
+
+    /// Recency-weighted mean of the feedback scores, if any decayed feedback
+    /// is left.
+    pub fn decayed_average_score(&self) -> Option<f64> {
+        (self.decayed_count > 0).then(|| self.decayed_score as f64 / self.decayed_count as f64)
+    }
+}
+
+/// Invoke `GetAgentProfileV1` and read the profile it returns.
+///
+/// Fails with `ProgramError::InvalidAccountData` if the reputation program
+/// returned no profile, which only happens if `cpi` targets another program.
+pub fn get_agent_profile(cpi: &GetAgentProfileV1Cpi) -> Result<AgentProfileV1, ProgramError> {
+    cpi.invoke()?;
+    AgentProfileV1::from_return_data().ok_or(ProgramError::InvalidAccountData)
+}
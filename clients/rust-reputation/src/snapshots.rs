@@ -0,0 +1,106 @@
+//! Iterator over the reputation snapshots `SnapshotReputationV1` takes of an
+//! agent, one per epoch, for charting and auditing its reputation over time.
+//!
+//! The iterator is agnostic of the RPC client: it hands batches of snapshot
+//! addresses to a fetch function and decodes the accounts it returns. With a
+//! blocking `RpcClient`:
+//!
+//! ```ignore
+//! let series = reputation_snapshots(asset, 500..600, |addresses| {
+//!     rpc.get_multiple_accounts(addresses).map(|accounts| {
+//!         accounts.into_iter().map(|account| account.map(|a| a.data)).collect()
+//!     })
+//! });
+//! for snapshot in series {
+//!     let snapshot = snapshot?;
+//!     println!("{}: {}", snapshot.epoch, snapshot.score_sum);
+//! }
+//! ```
+
+use std::{collections::VecDeque, ops::Range};
+
+use solana_program::pubkey::Pubkey;
+
+use crate::{accounts::ReputationSnapshotV1, types::Key};
+
+/// Maximum number of addresses handed to the fetch function at once, the
+/// limit of the `getMultipleAccounts` RPC method.
+pub const MAX_FETCH_ADDRESSES: usize = 100;
+
+/// Addresses of the snapshots of `asset` for each epoch of `epochs`.
+pub fn snapshot_addresses(asset: &Pubkey, epochs: Range<u64>) -> Vec<Pubkey> {
+    epochs
+        .map(|epoch| ReputationSnapshotV1::find_pda(asset, epoch).0)
+        .collect()
+}
+
+/// Snapshots of `asset` taken in `epochs`, in epoch order. `fetch` returns
+/// the data of the accounts at the addresses it is given, in order, or
+/// `None` for missing accounts. Epochs without a snapshot are skipped.
+pub fn reputation_snapshots<F, E>(
+    asset: Pubkey,
+    epochs: Range<u64>,
+    fetch: F,
+) -> ReputationSnapshots<F>
+where
+    F: FnMut(&[Pubkey]) -> Result<Vec<Option<Vec<u8>>>, E>,
+{
+    ReputationSnapshots {
+        asset,
+        epochs,
+        fetch,
+        snapshots: VecDeque::new(),
+    }
+}
+
+/// Iterator returned by [`reputation_snapshots`]. Stops after the first
+/// fetch error.
+pub struct ReputationSnapshots<F> {
+    asset: Pubkey,
+    epochs: Range<u64>,
+    fetch: F,
+    snapshots: VecDeque<ReputationSnapshotV1>,
+}
+
+impl<F, E> Iterator for ReputationSnapshots<F>
+where
+    F: FnMut(&[Pubkey]) -> Result<Vec<Option<Vec<u8>>>, E>,
+{
+    type Item = Result<ReputationSnapshotV1, E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(snapshot) = self.snapshots.pop_front() {
+                return Some(Ok(snapshot));
+            }
+            if self.epochs.is_empty() {
+                return None;
+            }
+
+            let end = self
+                .epochs
+                .end
+                .min(self.epochs.start.saturating_add(MAX_FETCH_ADDRESSES as u64));
+            let batch = self.epochs.start..end;
+            self.epochs.start = end;
+
+            let accounts = match (self.fetch)(&snapshot_addresses(&self.asset, batch)) {
+                Ok(accounts) => accounts,
+                Err(err) => {
+                    self.epochs.start = self.epochs.end;
+                    return Some(Err(err));
+                }
+            };
+
+            self.snapshots.extend(
+                accounts
+                    .into_iter()
+                    .flatten()
+                    .filter_map(|data| ReputationSnapshotV1::from_bytes(&data).ok())
+                    .filter(|snapshot| {
+                        snapshot.key == Key::ReputationSnapshotV1 && snapshot.asset == self.asset
+                    }),
+            );
+        }
+    }
+}
@@ -0,0 +1,97 @@
+//! Decoder for the feedback summary the program writes to the asset's
+//! AppData plugin, whose data authority is the agent reputation PDA.
+//!
+//! The summary mirrors `mpl_agent_reputation_program::state::ReputationSummaryV1`:
+//! a version byte, 7 bytes of padding and six little-endian `u64` fields.
+
+use mpl_core::{types::PluginAuthority, Asset};
+use solana_program::pubkey::Pubkey;
+
+use crate::accounts::AgentReputationV2;
+
+/// Feedback aggregates of an agent.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ReputationSummaryV1 {
+    /// Number of feedback entries in the aggregates.
+    pub feedback_count: u64,
+    /// Sum of the feedback scores.
+    pub score_sum: u64,
+    /// Sum of the squared feedback scores.
+    pub score_sum_squares: u64,
+    /// Slot of the last feedback.
+    pub last_feedback_slot: u64,
+    /// Number of disputed feedback entries, included in the aggregates.
+    pub disputed_count: u64,
+    /// Sum of the disputed feedback scores.
+    pub disputed_score_sum: u64,
+}
+
+impl ReputationSummaryV1 {
+    /// Version byte of this layout.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the encoded summary in bytes.
+    pub const LEN: usize = 56;
+
+    /// Decode a summary from AppData bytes. Returns `None` if the data has an
+    /// unknown version or is too short.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < Self::LEN || data[0] != Self::VERSION {
+            return None;
+        }
+
+        let field =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Some(Self {
+            feedback_count: field(8),
+            score_sum: field(16),
+            score_sum_squares: field(24),
+            last_feedback_slot: field(32),
+            disputed_count: field(40),
+            disputed_score_sum: field(48),
+        })
+    }
+
+    /// Find and decode the summary in the AppData plugin of the Core asset
+    /// at `asset`. Returns `None` if the asset has no reputation AppData
+    /// plugin or no feedback was submitted yet.
+    pub fn from_asset_data(asset: &Pubkey, asset_data: &[u8]) -> Option<Self> {
+        let (agent_reputation, _) = AgentReputationV2::find_pda(asset);
+        let data_authority = PluginAuthority::Address {
+            address: agent_reputation,
+        };
+
+        Asset::from_bytes(asset_data)
+            .ok()?
+            .external_plugin_adapter_list
+            .app_data
+            .iter()
+            .find(|app_data| app_data.base.data_authority == data_authority)
+            .and_then(|app_data| {
+                asset_data.get(app_data.data_offset..app_data.data_offset + app_data.data_len)
+            })
+            .and_then(Self::from_bytes)
+    }
+
+    /// Mean of the feedback scores, if any feedback was submitted.
+    pub fn average_score(&self) -> Option<f64> {
+        (self.feedback_count > 0).then(|| self.score_sum as f64 / self.feedback_count as f64)
+    }
+
+    /// Mean of the undisputed feedback scores, if any undisputed feedback
+    /// was submitted.
+    pub fn undisputed_average_score(&self) -> Option<f64> {
+        let count = self.feedback_count.saturating_sub(self.disputed_count);
+        (count > 0)
+            .then(|| self.score_sum.saturating_sub(self.disputed_score_sum) as f64 / count as f64)
+    }
+
+    /// Population variance of the feedback scores, if any feedback was
+    /// submitted.
+    pub fn score_variance(&self) -> Option<f64> {
+        self.average_score().map(|mean| {
+            (self.score_sum_squares as f64 / self.feedback_count as f64 - mean * mean).max(0.0)
+        })
+    }
+}
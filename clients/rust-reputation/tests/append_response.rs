@@ -0,0 +1,168 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_reputation::{
+    accounts::FeedbackV1, errors::MplAgentReputationError, events::MplAgentReputationEvent,
+    instructions::AppendResponseV1Builder,
+};
+use setup::feedback::{delegate_execution, fetch_feedback, process, setup_feedback, Agent};
+use solana_program_test::tokio;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const RESPONSE_URI: &str = "https://example.com/response.json";
+const RESPONSE_HASH: [u8; 32] = [7u8; 32];
+
+fn append_response_builder(
+    agent: &Agent,
+    reviewer: Pubkey,
+    authority: Pubkey,
+) -> AppendResponseV1Builder {
+    let mut builder = AppendResponseV1Builder::new();
+    builder
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer, 0).0)
+        .asset(agent.asset)
+        .authority(authority)
+        .response_hash(RESPONSE_HASH)
+        .response_uri(RESPONSE_URI.to_string());
+    builder
+}
+
+#[tokio::test]
+async fn asset_owner_can_respond() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let owner = context.payer.insecure_clone();
+    let ix = append_response_builder(&agent, reviewer.pubkey(), owner.pubkey()).instruction();
+    let events = process(&mut context, ix, &owner).await.unwrap();
+
+    // The response is recorded on the feedback.
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.response_count, 1);
+    assert_eq!(feedback.response_hash, RESPONSE_HASH);
+    assert!(feedback.response_slot >= feedback.slot);
+
+    // And the response details are emitted.
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::ResponseAppendedV1 {
+            asset: agent.asset,
+            reviewer: reviewer.pubkey(),
+            feedback_index: 0,
+            responder: owner.pubkey(),
+            response_uri: RESPONSE_URI.to_string(),
+            response_hash: RESPONSE_HASH,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn responses_accumulate() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let owner = context.payer.insecure_clone();
+    let ix = append_response_builder(&agent, reviewer.pubkey(), owner.pubkey()).instruction();
+    process(&mut context, ix, &owner).await.unwrap();
+
+    let ix = append_response_builder(&agent, reviewer.pubkey(), owner.pubkey())
+        .response_hash([9u8; 32])
+        .instruction();
+    process(&mut context, ix, &owner).await.unwrap();
+
+    // Only the latest response hash is kept.
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.response_count, 2);
+    assert_eq!(feedback.response_hash, [9u8; 32]);
+}
+
+#[tokio::test]
+async fn execution_delegate_can_respond() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+    let (executive, execution_delegate_record) = delegate_execution(&mut context, &agent).await;
+
+    let ix = append_response_builder(&agent, reviewer.pubkey(), executive.pubkey())
+        .execution_delegate_record(Some(execution_delegate_record))
+        .instruction();
+    let events = process(&mut context, ix, &executive).await.unwrap();
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.response_count, 1);
+    assert!(matches!(
+        events.as_slice(),
+        [MplAgentReputationEvent::ResponseAppendedV1 { responder, .. }]
+            if *responder == executive.pubkey()
+    ));
+}
+
+#[tokio::test]
+async fn cannot_respond_without_authority() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let stranger = Keypair::new();
+    let ix = append_response_builder(&agent, reviewer.pubkey(), stranger.pubkey()).instruction();
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::InvalidResponseAuthority as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_respond_with_another_executives_delegate_record() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+    let (_, execution_delegate_record) = delegate_execution(&mut context, &agent).await;
+
+    // The record is valid, but it belongs to another executive.
+    let stranger = Keypair::new();
+    let ix = append_response_builder(&agent, reviewer.pubkey(), stranger.pubkey())
+        .execution_delegate_record(Some(execution_delegate_record))
+        .instruction();
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::InvalidResponseAuthority as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_respond_to_feedback_for_another_asset() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+    let (_, other_asset) = setup::create_collection_and_asset(&mut context).await;
+
+    // The payer owns both assets, but the feedback is about the first one.
+    let owner = context.payer.insecure_clone();
+    let ix = append_response_builder(&agent, reviewer.pubkey(), owner.pubkey())
+        .asset(other_asset)
+        .instruction();
+    let err = process(&mut context, ix, &owner).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidFeedback as u32);
+}
+
+#[tokio::test]
+async fn cannot_respond_with_disallowed_uri_scheme() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let owner = context.payer.insecure_clone();
+    let ix = append_response_builder(&agent, reviewer.pubkey(), owner.pubkey())
+        .response_uri("http://example.com/response.json".to_string())
+        .instruction();
+    let err = process(&mut context, ix, &owner).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::ResponseUriSchemeNotAllowed as u32,
+    );
+}
@@ -0,0 +1,172 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_reputation::{
+    accounts::{AgentReputationV2, FeedbackV1, ReputationConfigV1},
+    errors::MplAgentReputationError,
+    events::MplAgentReputationEvent,
+    instructions::{DisputeFeedbackV1Builder, RevokeFeedbackV1Builder},
+    types::FeedbackStatus,
+};
+use setup::feedback::{
+    delegate_execution, fetch_feedback, fetch_reputation, fetch_summary, process, setup_feedback,
+    Agent,
+};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn dispute_feedback_builder(
+    context: &ProgramTestContext,
+    agent: &Agent,
+    reviewer: Pubkey,
+    authority: Pubkey,
+) -> DisputeFeedbackV1Builder {
+    let mut builder = DisputeFeedbackV1Builder::new();
+    builder
+        .agent_reputation(AgentReputationV2::find_pda(&agent.asset).0)
+        .asset(agent.asset)
+        .collection(Some(agent.collection))
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer, 0).0)
+        .authority(authority)
+        .payer(context.payer.pubkey());
+    builder
+}
+
+#[tokio::test]
+async fn asset_owner_can_dispute_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let owner = context.payer.insecure_clone();
+    let ix =
+        dispute_feedback_builder(&context, &agent, reviewer.pubkey(), owner.pubkey()).instruction();
+    let events = process(&mut context, ix, &owner).await.unwrap();
+
+    // The feedback is marked as disputed.
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.status, FeedbackStatus::Disputed);
+
+    // It stays in the aggregates, and is counted as disputed.
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.feedback_count, 1);
+    assert_eq!(agent_reputation.score_sum, 60);
+    assert_eq!(agent_reputation.disputed_count, 1);
+    assert_eq!(agent_reputation.disputed_score_sum, 60);
+    assert_eq!(agent_reputation.disputed_weight_sum, 1);
+    assert_eq!(agent_reputation.disputed_weighted_score_sum, 60);
+
+    // And the summary carries the disputed aggregates.
+    let summary = fetch_summary(&mut context, agent.asset).await;
+    assert_eq!(summary.disputed_count, 1);
+    assert_eq!(summary.disputed_score_sum, 60);
+    assert_eq!(summary.undisputed_average_score(), None);
+
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::FeedbackDisputedV1 {
+            asset: agent.asset,
+            reviewer: reviewer.pubkey(),
+            feedback_index: 0,
+            disputer: owner.pubkey(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn execution_delegate_can_dispute_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+    let (executive, execution_delegate_record) = delegate_execution(&mut context, &agent).await;
+
+    let ix = dispute_feedback_builder(&context, &agent, reviewer.pubkey(), executive.pubkey())
+        .execution_delegate_record(Some(execution_delegate_record))
+        .instruction();
+    process(&mut context, ix, &executive).await.unwrap();
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.status, FeedbackStatus::Disputed);
+}
+
+#[tokio::test]
+async fn stranger_cannot_dispute_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let stranger = Keypair::new();
+    let ix = dispute_feedback_builder(&context, &agent, reviewer.pubkey(), stranger.pubkey())
+        .instruction();
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidDisputeAuthority as u32);
+}
+
+#[tokio::test]
+async fn cannot_dispute_feedback_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+    let (executive, execution_delegate_record) = delegate_execution(&mut context, &agent).await;
+
+    let owner = context.payer.insecure_clone();
+    let ix =
+        dispute_feedback_builder(&context, &agent, reviewer.pubkey(), owner.pubkey()).instruction();
+    process(&mut context, ix, &owner).await.unwrap();
+
+    let ix = dispute_feedback_builder(&context, &agent, reviewer.pubkey(), executive.pubkey())
+        .execution_delegate_record(Some(execution_delegate_record))
+        .instruction();
+    let err = process(&mut context, ix, &executive).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::FeedbackAlreadyDisputed as u32);
+}
+
+#[tokio::test]
+async fn reviewer_can_revoke_disputed_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let owner = context.payer.insecure_clone();
+    let ix =
+        dispute_feedback_builder(&context, &agent, reviewer.pubkey(), owner.pubkey()).instruction();
+    process(&mut context, ix, &owner).await.unwrap();
+
+    // The reviewer concedes the dispute.
+    let ix = RevokeFeedbackV1Builder::new()
+        .agent_reputation(AgentReputationV2::find_pda(&agent.asset).0)
+        .asset(agent.asset)
+        .collection(Some(agent.collection))
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer.pubkey(), 0).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .reviewer(reviewer.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.status, FeedbackStatus::Revoked);
+
+    // Revoking disputed feedback removes it from the disputed aggregates
+    // too.
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.feedback_count, 0);
+    assert_eq!(agent_reputation.disputed_count, 0);
+    assert_eq!(agent_reputation.disputed_score_sum, 0);
+    assert_eq!(agent_reputation.disputed_weight_sum, 0);
+    assert_eq!(agent_reputation.disputed_weighted_score_sum, 0);
+    assert_eq!(
+        fetch_summary(&mut context, agent.asset)
+            .await
+            .disputed_count,
+        0
+    );
+
+    // Revoked feedback can no longer be disputed.
+    let ix =
+        dispute_feedback_builder(&context, &agent, reviewer.pubkey(), owner.pubkey()).instruction();
+    let err = process(&mut context, ix, &owner).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::FeedbackRevoked as u32);
+}
@@ -0,0 +1,154 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::accounts::AgentIdentityV2;
+use mpl_agent_reputation::{
+    accounts::{AgentReputationV2, ReputationConfigV1},
+    errors::MplAgentReputationError,
+    instructions::GetAgentProfileV1Builder,
+    profile::AgentProfileV1,
+};
+use setup::feedback::{fetch_reputation, setup_feedback};
+use solana_program::{clock::Clock, instruction::Instruction};
+use solana_program_test::{tokio, BanksClientError, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Signer, transaction::Transaction};
+
+fn get_agent_profile_ix(asset: Pubkey) -> Instruction {
+    GetAgentProfileV1Builder::new()
+        .asset(asset)
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .agent_reputation(AgentReputationV2::find_pda(&asset).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .instruction()
+}
+
+/// Process `ix` and decode the profile from its return data.
+async fn get_agent_profile(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+) -> Result<AgentProfileV1, BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await?;
+    result.result?;
+
+    let return_data = result.metadata.unwrap().return_data.unwrap();
+    assert_eq!(return_data.program_id, mpl_agent_reputation::ID);
+    Ok(AgentProfileV1::from_bytes(&return_data.data).unwrap())
+}
+
+#[tokio::test]
+async fn returns_the_profile_of_an_agent_with_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, _) = setup_feedback(&mut context).await;
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+
+    let profile = get_agent_profile(&mut context, get_agent_profile_ix(agent.asset))
+        .await
+        .unwrap();
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    assert_eq!(
+        profile,
+        AgentProfileV1 {
+            identity_registered: true,
+            reputation_registered: true,
+            asset: agent.asset,
+            agent_token: None,
+            feedback_count: 1,
+            score_sum: 60,
+            score_sum_squares: 3_600,
+            last_feedback_slot: agent_reputation.last_feedback_slot,
+            decayed_score: agent_reputation.decayed_score,
+            decayed_count: agent_reputation.decayed_count,
+            slot: clock.slot,
+            weight_sum: 1,
+            weighted_score_sum: 60,
+            disputed_count: 0,
+            disputed_score_sum: 0,
+            disputed_weight_sum: 0,
+            disputed_weighted_score_sum: 0,
+        }
+    );
+    assert!(profile.is_registered());
+    assert!(profile.meets_average_score(60, 1));
+    assert!(!profile.meets_average_score(61, 1));
+
+    // The view leaves the reputation untouched.
+    assert_eq!(
+        fetch_reputation(&mut context, agent.asset).await,
+        agent_reputation
+    );
+}
+
+#[tokio::test]
+async fn returns_an_empty_profile_for_an_unregistered_asset() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    let profile = get_agent_profile(&mut context, get_agent_profile_ix(asset))
+        .await
+        .unwrap();
+
+    assert!(!profile.identity_registered);
+    assert!(!profile.reputation_registered);
+    assert_eq!(profile.asset, asset);
+    assert_eq!(profile.feedback_count, 0);
+    assert!(!profile.meets_average_score(0, 0));
+}
+
+#[tokio::test]
+async fn returns_no_aggregates_before_the_first_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_reputation(&mut context, asset, collection).await;
+
+    let profile = get_agent_profile(&mut context, get_agent_profile_ix(asset))
+        .await
+        .unwrap();
+
+    assert!(!profile.identity_registered);
+    assert!(profile.reputation_registered);
+    assert_eq!(profile.feedback_count, 0);
+    assert_eq!(profile.decayed_score, 0);
+}
+
+#[tokio::test]
+async fn rejects_an_agent_identity_of_another_asset() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    let ix = GetAgentProfileV1Builder::new()
+        .asset(asset)
+        .agent_identity(AgentIdentityV2::find_pda(&Pubkey::new_unique()).0)
+        .agent_reputation(AgentReputationV2::find_pda(&asset).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .instruction();
+    let err = get_agent_profile(&mut context, ix).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidAgentIdentity as u32);
+}
+
+#[tokio::test]
+async fn rejects_an_agent_reputation_of_another_asset() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    let ix = GetAgentProfileV1Builder::new()
+        .asset(asset)
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .agent_reputation(AgentReputationV2::find_pda(&Pubkey::new_unique()).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .instruction();
+    let err = get_agent_profile(&mut context, ix).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidAccountData as u32);
+}
@@ -0,0 +1,165 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_reputation::{
+    accounts::{AgentReputationV2, FeedbackV1, ReputationConfigV1},
+    errors::MplAgentReputationError,
+    events::MplAgentReputationEvent,
+    instructions::{
+        InitializeReputationConfigV1Builder, RefreshReputationV1Builder, RevokeFeedbackV1Builder,
+    },
+};
+use setup::feedback::{
+    fetch_reputation, issue_receipt, process, setup_agent, setup_feedback, submit_feedback_ix,
+    Agent,
+};
+use solana_program::{clock::Clock, instruction::Instruction};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const HALF_LIFE: u64 = 1_000;
+
+/// `1.0` as Q32.32.
+const ONE: u64 = 1 << 32;
+
+/// Start a test validator with a reputation config using `HALF_LIFE`.
+async fn start() -> ProgramTestContext {
+    let upgrade_authority = Keypair::new();
+    let mut program_test = setup::setup();
+    let program_data = setup::add_program_data(&mut program_test, upgrade_authority.pubkey());
+    let mut context = program_test.start_with_context().await;
+
+    let ix = InitializeReputationConfigV1Builder::new()
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .program_data(program_data)
+        .authority(upgrade_authority.pubkey())
+        .payer(context.payer.pubkey())
+        .half_life_slots(HALF_LIFE)
+        .instruction();
+    process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    context
+}
+
+fn refresh_reputation_ix(asset: Pubkey) -> Instruction {
+    RefreshReputationV1Builder::new()
+        .agent_reputation(AgentReputationV2::find_pda(&asset).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .instruction()
+}
+
+/// Warp `half_lives` half-lives past the last decay of the agent's score.
+async fn warp_half_lives(context: &mut ProgramTestContext, agent: &Agent, half_lives: u64) {
+    let decay_slot = fetch_reputation(context, agent.asset).await.decay_slot;
+    context
+        .warp_to_slot(decay_slot + half_lives * HALF_LIFE)
+        .unwrap();
+}
+
+#[tokio::test]
+async fn feedback_enters_the_decayed_score_at_full_weight() {
+    let mut context = start().await;
+    let (agent, _) = setup_feedback(&mut context).await;
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.decayed_score, 60 * ONE);
+    assert_eq!(agent_reputation.decayed_count, ONE);
+    assert_eq!(
+        agent_reputation.decay_slot,
+        agent_reputation.last_feedback_slot
+    );
+}
+
+#[tokio::test]
+async fn refresh_halves_the_score_every_half_life() {
+    let mut context = start().await;
+    let (agent, _) = setup_feedback(&mut context).await;
+    warp_half_lives(&mut context, &agent, 2).await;
+
+    // Anyone may crank the refresh.
+    let cranker = Keypair::new();
+    let events = process(&mut context, refresh_reputation_ix(agent.asset), &cranker)
+        .await
+        .unwrap();
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.decayed_score, 15 * ONE);
+    assert_eq!(agent_reputation.decayed_count, ONE / 4);
+
+    // The raw aggregates are untouched.
+    assert_eq!(agent_reputation.feedback_count, 1);
+    assert_eq!(agent_reputation.score_sum, 60);
+
+    let clock = context.banks_client.get_sysvar::<Clock>().await.unwrap();
+    assert_eq!(agent_reputation.decay_slot, clock.slot);
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::ReputationRefreshedV1 {
+            asset: agent.asset,
+            decayed_score: 15 * ONE,
+            decayed_count: ONE / 4,
+            slot: clock.slot,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn new_feedback_decays_the_previous_score_first() {
+    let mut context = start().await;
+    let (mut agent, _) = setup_feedback(&mut context).await;
+    warp_half_lives(&mut context, &agent, 1).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 80);
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    // 60 halved once, plus 80 at full weight.
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.decayed_score, 110 * ONE);
+    assert_eq!(agent_reputation.decayed_count, ONE + ONE / 2);
+    assert_eq!(
+        agent_reputation.decay_slot,
+        agent_reputation.last_feedback_slot
+    );
+}
+
+#[tokio::test]
+async fn revoking_removes_what_is_left_of_the_feedback() {
+    let mut context = start().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+    warp_half_lives(&mut context, &agent, 1).await;
+
+    let ix = RevokeFeedbackV1Builder::new()
+        .agent_reputation(AgentReputationV2::find_pda(&agent.asset).0)
+        .asset(agent.asset)
+        .collection(Some(agent.collection))
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer.pubkey(), 0).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .reviewer(reviewer.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.decayed_score, 0);
+    assert_eq!(agent_reputation.decayed_count, 0);
+}
+
+#[tokio::test]
+async fn cannot_refresh_reputation_without_feedback() {
+    let mut context = start().await;
+    let agent = setup_agent(&mut context).await;
+
+    // The reputation is still an AgentReputationV1, which has no score.
+    let cranker = Keypair::new();
+    let err = process(&mut context, refresh_reputation_ix(agent.asset), &cranker)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidAccountData as u32);
+}
@@ -0,0 +1,218 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_reputation::{
+    accounts::AgentReputationV1,
+    errors::MplAgentReputationError,
+    instructions::{RegisterReputationAsOwnerV1Builder, RegisterReputationV1Builder},
+    summary::ReputationSummaryV1,
+    types::Key,
+};
+use mpl_core::instructions::{CreateCollectionV1Builder, CreateV1Builder};
+use setup::feedback::{
+    fetch_reputation, fetch_summary, issue_receipt, process, submit_feedback_ix, Agent,
+};
+use setup::receipts;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+/// Create an asset owned by `owner` in a collection whose update authority
+/// is neither the payer nor `owner`.
+async fn create_asset_owned_by(context: &mut ProgramTestContext, owner: Pubkey) -> Pubkey {
+    let collection_authority = Keypair::new();
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+
+    let create_collection_ix = CreateCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .update_authority(Some(collection_authority.pubkey()))
+        .payer(context.payer.pubkey())
+        .name("Test Collection".to_string())
+        .uri("https://example.com/collection.json".to_string())
+        .instruction();
+    let create_asset_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .authority(Some(collection_authority.pubkey()))
+        .owner(Some(owner))
+        .payer(context.payer.pubkey())
+        .name("Test Asset".to_string())
+        .uri("https://example.com/asset.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_collection_ix, create_asset_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &collection, &asset, &collection_authority],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    asset.pubkey()
+}
+
+fn register_as_owner_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    authority: Pubkey,
+) -> Instruction {
+    RegisterReputationAsOwnerV1Builder::new()
+        .agent_reputation(AgentReputationV1::find_pda(&asset).0)
+        .asset(asset)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority))
+        .instruction()
+}
+
+fn register_ix(context: &ProgramTestContext, agent: &Agent) -> Instruction {
+    RegisterReputationV1Builder::new()
+        .agent_reputation(AgentReputationV1::find_pda(&agent.asset).0)
+        .asset(agent.asset)
+        .collection(Some(agent.collection))
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+async fn asset_data(context: &mut ProgramTestContext, asset: Pubkey) -> Vec<u8> {
+    context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap()
+        .data
+}
+
+/// An agent whose reputation the payer registered as the asset owner, ready
+/// to issue work receipts.
+async fn setup_owner_registered_agent(context: &mut ProgramTestContext) -> Agent {
+    let (collection, asset) = setup::create_collection_and_asset(context).await;
+    let owner = context.payer.insecure_clone();
+    process(
+        context,
+        register_as_owner_ix(context, asset, owner.pubkey()),
+        &owner,
+    )
+    .await
+    .unwrap();
+
+    let execution_delegate_record =
+        receipts::delegate_execution_to_payer(context, asset, collection).await;
+    let receipts_tree = receipts::bootstrap_receipts(context, 0).await;
+
+    Agent {
+        asset,
+        collection,
+        execution_delegate_record,
+        receipts_tree,
+    }
+}
+
+#[tokio::test]
+async fn owner_can_register_without_the_collection_authority() {
+    let mut context = setup::setup().start_with_context().await;
+    let owner = Keypair::new();
+    let asset = create_asset_owned_by(&mut context, owner.pubkey()).await;
+
+    let ix = register_as_owner_ix(&context, asset, owner.pubkey());
+    process(&mut context, ix, &owner).await.unwrap();
+
+    let (agent_reputation_pda, bump) = AgentReputationV1::find_pda(&asset);
+    let account = context
+        .banks_client
+        .get_account(agent_reputation_pda)
+        .await
+        .unwrap()
+        .unwrap();
+    let agent_reputation = AgentReputationV1::from_bytes(&account.data).unwrap();
+    assert_eq!(agent_reputation.key, Key::AgentReputationV1);
+    assert_eq!(agent_reputation.bump, bump);
+    assert_eq!(agent_reputation.asset, asset);
+
+    // No AppData plugin is added to the asset.
+    let data = asset_data(&mut context, asset).await;
+    assert_eq!(ReputationSummaryV1::from_asset_data(&asset, &data), None);
+}
+
+#[tokio::test]
+async fn only_the_owner_can_register_as_owner() {
+    let mut context = setup::setup().start_with_context().await;
+    let owner = Keypair::new();
+    let asset = create_asset_owned_by(&mut context, owner.pubkey()).await;
+
+    let stranger = Keypair::new();
+    let ix = register_as_owner_ix(&context, asset, stranger.pubkey());
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::OnlyAssetOwnerOrSignerCanRegisterReputation as u32,
+    );
+}
+
+#[tokio::test]
+async fn owner_cannot_register_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let owner = Keypair::new();
+    let asset = create_asset_owned_by(&mut context, owner.pubkey()).await;
+
+    let ix = register_as_owner_ix(&context, asset, owner.pubkey());
+    process(&mut context, ix, &owner).await.unwrap();
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = register_as_owner_ix(&context, asset, owner.pubkey());
+    let err = process(&mut context, ix, &owner).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::AgentReputationAlreadyRegistered as u32,
+    );
+}
+
+#[tokio::test]
+async fn collection_authority_can_attach_the_summary_later() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_owner_registered_agent(&mut context).await;
+
+    // Feedback is recorded without a summary to write.
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 60);
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    assert_eq!(
+        fetch_reputation(&mut context, agent.asset).await.score_sum,
+        60
+    );
+    let data = asset_data(&mut context, agent.asset).await;
+    assert_eq!(
+        ReputationSummaryV1::from_asset_data(&agent.asset, &data),
+        None
+    );
+
+    // When the collection authority registers the reputation, the plugin is
+    // attached and carries the feedback submitted so far.
+    let authority = context.payer.insecure_clone();
+    let ix = register_ix(&context, &agent);
+    process(&mut context, ix, &authority).await.unwrap();
+
+    let summary = fetch_summary(&mut context, agent.asset).await;
+    assert_eq!(summary.feedback_count, 1);
+    assert_eq!(summary.score_sum, 60);
+
+    // Once attached, the reputation is fully registered.
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = register_ix(&context, &agent);
+    let err = process(&mut context, ix, &authority).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::AgentReputationAlreadyRegistered as u32,
+    );
+}
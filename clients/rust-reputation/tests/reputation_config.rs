@@ -0,0 +1,167 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_reputation::{
+    accounts::ReputationConfigV1,
+    errors::MplAgentReputationError,
+    events::MplAgentReputationEvent,
+    instructions::{InitializeReputationConfigV1Builder, UpdateReputationConfigV1Builder},
+    types::Key,
+};
+use setup::feedback::process;
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+/// Start a test validator on which `upgrade_authority` can upgrade the
+/// reputation program.
+async fn start(upgrade_authority: &Keypair) -> (ProgramTestContext, Pubkey) {
+    let mut program_test = setup::setup();
+    let program_data = setup::add_program_data(&mut program_test, upgrade_authority.pubkey());
+    (program_test.start_with_context().await, program_data)
+}
+
+fn initialize_config_ix(
+    context: &ProgramTestContext,
+    program_data: Pubkey,
+    authority: Pubkey,
+    half_life_slots: u64,
+) -> Instruction {
+    InitializeReputationConfigV1Builder::new()
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .program_data(program_data)
+        .authority(authority)
+        .payer(context.payer.pubkey())
+        .half_life_slots(half_life_slots)
+        .instruction()
+}
+
+async fn fetch_config(context: &mut ProgramTestContext) -> ReputationConfigV1 {
+    let account = context
+        .banks_client
+        .get_account(ReputationConfigV1::find_pda().0)
+        .await
+        .unwrap()
+        .unwrap();
+    ReputationConfigV1::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn upgrade_authority_can_initialize_config() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let ix = initialize_config_ix(&context, program_data, upgrade_authority.pubkey(), 1_000);
+    let events = process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    let config = fetch_config(&mut context).await;
+    assert_eq!(config.key, Key::ReputationConfigV1);
+    assert_eq!(config.authority, upgrade_authority.pubkey());
+    assert_eq!(config.half_life_slots, 1_000);
+
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::ReputationConfigUpdatedV1 {
+            authority: upgrade_authority.pubkey(),
+            half_life_slots: 1_000,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn only_upgrade_authority_can_initialize_config() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let stranger = Keypair::new();
+    let ix = initialize_config_ix(&context, program_data, stranger.pubkey(), 1_000);
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidConfigAuthority as u32);
+}
+
+#[tokio::test]
+async fn cannot_initialize_config_twice() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let ix = initialize_config_ix(&context, program_data, upgrade_authority.pubkey(), 1_000);
+    process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    let ix = initialize_config_ix(&context, program_data, upgrade_authority.pubkey(), 2_000);
+    let err = process(&mut context, ix, &upgrade_authority)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::ReputationConfigAlreadyInitialized as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_initialize_config_with_zero_half_life() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let ix = initialize_config_ix(&context, program_data, upgrade_authority.pubkey(), 0);
+    let err = process(&mut context, ix, &upgrade_authority)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidHalfLife as u32);
+}
+
+#[tokio::test]
+async fn config_authority_can_update_config_and_hand_it_over() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let ix = initialize_config_ix(&context, program_data, upgrade_authority.pubkey(), 1_000);
+    process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    // The upgrade authority hands the config over.
+    let new_authority = Keypair::new();
+    let ix = UpdateReputationConfigV1Builder::new()
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .authority(upgrade_authority.pubkey())
+        .new_authority(Some(new_authority.pubkey()))
+        .half_life_slots(2_000)
+        .instruction();
+    process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    let config = fetch_config(&mut context).await;
+    assert_eq!(config.authority, new_authority.pubkey());
+    assert_eq!(config.half_life_slots, 2_000);
+
+    // The previous authority can no longer update it.
+    let ix = UpdateReputationConfigV1Builder::new()
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .authority(upgrade_authority.pubkey())
+        .half_life_slots(3_000)
+        .instruction();
+    let err = process(&mut context, ix, &upgrade_authority)
+        .await
+        .unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidConfigAuthority as u32);
+}
+
+#[tokio::test]
+async fn cannot_update_uninitialized_config() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let authority = Keypair::new();
+    let ix = UpdateReputationConfigV1Builder::new()
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .authority(authority.pubkey())
+        .half_life_slots(1_000)
+        .instruction();
+    let err = process(&mut context, ix, &authority).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidReputationConfig as u32);
+}
@@ -0,0 +1,135 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_reputation::{
+    accounts::{AgentReputationV2, FeedbackV1, ReputationConfigV1},
+    errors::MplAgentReputationError,
+    events::MplAgentReputationEvent,
+    instructions::{AppendResponseV1Builder, RevokeFeedbackV1Builder},
+    types::FeedbackStatus,
+};
+use setup::feedback::{
+    fetch_feedback, fetch_reputation, fetch_summary, issue_receipt, process, setup_feedback,
+    submit_feedback_at_builder, Agent,
+};
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn revoke_feedback_ix(
+    context: &ProgramTestContext,
+    agent: &Agent,
+    reviewer: Pubkey,
+    signer: Pubkey,
+) -> Instruction {
+    RevokeFeedbackV1Builder::new()
+        .agent_reputation(AgentReputationV2::find_pda(&agent.asset).0)
+        .asset(agent.asset)
+        .collection(Some(agent.collection))
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer, 0).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .reviewer(signer)
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+#[tokio::test]
+async fn reviewer_can_revoke_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (mut agent, reviewer) = setup_feedback(&mut context).await;
+
+    // Given a second piece of feedback that stays in the aggregates.
+    let other_reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, other_reviewer.pubkey()).await;
+    let ix = submit_feedback_at_builder(&context, &agent, &other_reviewer, &receipt, 90, 0)
+        .instruction();
+    process(&mut context, ix, &other_reviewer).await.unwrap();
+
+    // When the reviewer revokes their feedback.
+    let ix = revoke_feedback_ix(&context, &agent, reviewer.pubkey(), reviewer.pubkey());
+    let events = process(&mut context, ix, &reviewer).await.unwrap();
+
+    // Then the feedback is marked as revoked.
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.status, FeedbackStatus::Revoked);
+
+    // And its score is removed from the aggregates and the summary.
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.feedback_count, 1);
+    assert_eq!(agent_reputation.score_sum, 90);
+    assert_eq!(agent_reputation.score_sum_squares, 8100);
+
+    let summary = fetch_summary(&mut context, agent.asset).await;
+    assert_eq!(summary.feedback_count, 1);
+    assert_eq!(summary.average_score(), Some(90.0));
+
+    // And the revocation is emitted.
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::FeedbackRevokedV1 {
+            asset: agent.asset,
+            reviewer: reviewer.pubkey(),
+            feedback_index: 0,
+            score: 60,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn only_the_reviewer_can_revoke_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let stranger = Keypair::new();
+    let ix = revoke_feedback_ix(&context, &agent, reviewer.pubkey(), stranger.pubkey());
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidReviewer as u32);
+}
+
+#[tokio::test]
+async fn cannot_revoke_feedback_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let ix = revoke_feedback_ix(&context, &agent, reviewer.pubkey(), reviewer.pubkey());
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    // Fresh blockhash so that the identical instruction is not deduplicated.
+    context.last_blockhash = context.get_new_latest_blockhash().await.unwrap();
+
+    let ix = revoke_feedback_ix(&context, &agent, reviewer.pubkey(), reviewer.pubkey());
+    let err = process(&mut context, ix, &reviewer).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::FeedbackRevoked as u32);
+
+    // The aggregates are only adjusted once.
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.feedback_count, 0);
+    assert_eq!(agent_reputation.score_sum, 0);
+}
+
+#[tokio::test]
+async fn cannot_respond_to_revoked_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let ix = revoke_feedback_ix(&context, &agent, reviewer.pubkey(), reviewer.pubkey());
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    let owner = context.payer.insecure_clone();
+    let ix = AppendResponseV1Builder::new()
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer.pubkey(), 0).0)
+        .asset(agent.asset)
+        .authority(owner.pubkey())
+        .response_hash([7u8; 32])
+        .response_uri("https://example.com/response.json".to_string())
+        .instruction();
+    let err = process(&mut context, ix, &owner).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::FeedbackRevoked as u32);
+}
@@ -0,0 +1,248 @@
+//! Agents that issue work receipts, and the feedback their clients submit.
+#![allow(dead_code)]
+
+use mpl_agent_identity::accounts::AgentIdentityV2;
+use mpl_agent_reputation::{
+    accounts::{
+        AgentReputationV2, FeedbackV1, ReceiptNullifierV1, ReputationConfigV1, ReviewerHistoryV1,
+        WeightingPolicyV1,
+    },
+    events::MplAgentReputationEvent,
+    instructions::SubmitFeedbackV1Builder,
+    summary::ReputationSummaryV1,
+};
+use mpl_agent_tools::{
+    accounts::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, ExecutiveProfileV1},
+    instructions::{DelegateExecutionV1Builder, RegisterExecutiveV1Builder},
+};
+use mpl_bubblegum::hash::DEFAULT_ASSET_DATA_HASH;
+use solana_program::instruction::Instruction;
+use solana_program_test::{BanksClientError, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::Transaction,
+};
+
+use super::receipts::{self, ReceiptsTree, WorkReceipt};
+
+pub const FEEDBACK_URI: &str = "https://example.com/feedback.json";
+
+pub fn tag(value: &str) -> [u8; 32] {
+    let mut tag = [0u8; 32];
+    tag[..value.len()].copy_from_slice(value.as_bytes());
+    tag
+}
+
+/// An agent with a registered reputation whose execution is delegated to
+/// the payer, so the tests can mint work receipts for it.
+pub struct Agent {
+    pub asset: Pubkey,
+    pub collection: Pubkey,
+    pub execution_delegate_record: Pubkey,
+    pub receipts_tree: ReceiptsTree,
+}
+
+pub async fn setup_agent(context: &mut ProgramTestContext) -> Agent {
+    let (collection, asset) = super::create_collection_and_asset(context).await;
+    super::register_reputation(context, asset, collection).await;
+
+    let execution_delegate_record =
+        receipts::delegate_execution_to_payer(context, asset, collection).await;
+    let receipts_tree = receipts::bootstrap_receipts(context, 0).await;
+
+    Agent {
+        asset,
+        collection,
+        execution_delegate_record,
+        receipts_tree,
+    }
+}
+
+pub async fn issue_receipt(
+    context: &mut ProgramTestContext,
+    agent: &mut Agent,
+    client: Pubkey,
+) -> WorkReceipt {
+    receipts::mint_work_receipt(
+        context,
+        &mut agent.receipts_tree,
+        agent.asset,
+        agent.execution_delegate_record,
+        client,
+    )
+    .await
+}
+
+/// Feedback of `reviewer` backed by `receipt`, recorded at the reviewer's
+/// feedback index 0.
+pub fn submit_feedback_builder(
+    context: &ProgramTestContext,
+    agent: &Agent,
+    reviewer: &Keypair,
+    receipt: &WorkReceipt,
+    score: u8,
+) -> SubmitFeedbackV1Builder {
+    let (root, proof) = receipts::merkle_proof(&agent.receipts_tree, receipt.index);
+
+    let mut builder = SubmitFeedbackV1Builder::new();
+    builder
+        .agent_reputation(AgentReputationV2::find_pda(&agent.asset).0)
+        .asset(agent.asset)
+        .collection(Some(agent.collection))
+        .reviewer(reviewer.pubkey())
+        .receipt_nullifier(ReceiptNullifierV1::find_pda(receipt.tree_index, receipt.nonce).0)
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer.pubkey(), 0).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .weighting_policy(WeightingPolicyV1::find_pda().0)
+        .reviewer_history(ReviewerHistoryV1::find_pda(&agent.asset, &reviewer.pubkey()).0)
+        .merkle_tree(agent.receipts_tree.merkle_tree)
+        .payer(context.payer.pubkey())
+        .score(score)
+        .flags(0)
+        .index(receipt.index)
+        .tree_index(receipt.tree_index)
+        .nonce(receipt.nonce)
+        .feedback_index(0)
+        .root(root)
+        .data_hash(receipt.data_hash)
+        .asset_data_hash(DEFAULT_ASSET_DATA_HASH)
+        .tag1(tag("quality"))
+        .tag2(tag("latency"))
+        .feedback_uri(FEEDBACK_URI.to_string())
+        .add_remaining_accounts(&proof);
+    builder
+}
+
+/// Same as `submit_feedback_builder`, at the reviewer's feedback index
+/// `feedback_index`.
+pub fn submit_feedback_at_builder(
+    context: &ProgramTestContext,
+    agent: &Agent,
+    reviewer: &Keypair,
+    receipt: &WorkReceipt,
+    score: u8,
+    feedback_index: u64,
+) -> SubmitFeedbackV1Builder {
+    let mut builder = submit_feedback_builder(context, agent, reviewer, receipt, score);
+    builder
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer.pubkey(), feedback_index).0)
+        .feedback_index(feedback_index);
+    builder
+}
+
+pub fn submit_feedback_ix(
+    context: &ProgramTestContext,
+    agent: &Agent,
+    reviewer: &Keypair,
+    receipt: &WorkReceipt,
+    score: u8,
+) -> Instruction {
+    submit_feedback_builder(context, agent, reviewer, receipt, score).instruction()
+}
+
+/// Process `ix`, signed by the payer and `signer`, and return the events
+/// the reputation program emitted.
+pub async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<Vec<MplAgentReputationEvent>, BanksClientError> {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, signer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await?;
+    result.result?;
+
+    Ok(MplAgentReputationEvent::from_logs(
+        &result.metadata.unwrap().log_messages,
+    ))
+}
+
+pub async fn fetch_feedback(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    reviewer: Pubkey,
+    index: u64,
+) -> FeedbackV1 {
+    let account = context
+        .banks_client
+        .get_account(FeedbackV1::find_pda(&asset, &reviewer, index).0)
+        .await
+        .unwrap()
+        .unwrap();
+    FeedbackV1::from_bytes(&account.data).unwrap()
+}
+
+pub async fn fetch_reputation(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+) -> AgentReputationV2 {
+    let account = context
+        .banks_client
+        .get_account(AgentReputationV2::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), AgentReputationV2::LEN);
+    AgentReputationV2::from_bytes(&account.data).unwrap()
+}
+
+pub async fn fetch_summary(context: &mut ProgramTestContext, asset: Pubkey) -> ReputationSummaryV1 {
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    ReputationSummaryV1::from_asset_data(&asset, &account.data).unwrap()
+}
+
+/// An agent with one piece of feedback, left by the returned reviewer at
+/// feedback index 0.
+pub async fn setup_feedback(context: &mut ProgramTestContext) -> (Agent, Keypair) {
+    let mut agent = setup_agent(context).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(context, &mut agent, reviewer.pubkey()).await;
+    let ix = submit_feedback_ix(context, &agent, &reviewer, &receipt, 60);
+    process(context, ix, &reviewer).await.unwrap();
+
+    (agent, reviewer)
+}
+
+/// Register a fresh executive and delegate the agent's execution to it.
+pub async fn delegate_execution(
+    context: &mut ProgramTestContext,
+    agent: &Agent,
+) -> (Keypair, Pubkey) {
+    let executive = Keypair::new();
+    let executive_profile = ExecutiveProfileV1::find_pda(&executive.pubkey()).0;
+    let ix = RegisterExecutiveV1Builder::new()
+        .executive_profile(executive_profile)
+        .payer(context.payer.pubkey())
+        .authority(Some(executive.pubkey()))
+        .instruction();
+    process(context, ix, &executive).await.unwrap();
+
+    let execution_delegate_record =
+        ExecutionDelegateRecordV1::find_pda(&executive_profile, &agent.asset).0;
+    let ix = DelegateExecutionV1Builder::new()
+        .executive_profile(executive_profile)
+        .agent_asset(agent.asset)
+        .agent_identity(AgentIdentityV2::find_pda(&agent.asset).0)
+        .execution_delegate_record(execution_delegate_record)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&agent.asset).0)
+        .payer(context.payer.pubkey())
+        .instruction();
+    let payer = context.payer.insecure_clone();
+    process(context, ix, &payer).await.unwrap();
+
+    (executive, execution_delegate_record)
+}
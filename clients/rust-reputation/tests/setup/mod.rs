@@ -0,0 +1,148 @@
+use mpl_agent_reputation::{
+    accounts::AgentReputationV1, instructions::RegisterReputationV1Builder,
+};
+use mpl_core::instructions::{CreateCollectionV1Builder, CreateV1Builder};
+use solana_program::instruction::InstructionError;
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+pub mod feedback;
+pub mod receipts;
+
+pub const MPL_CORE_ID: Pubkey =
+    solana_program::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+/// Asserts that a BanksClientError is a custom program error matching the
+/// expected error variant. Callers pass the error enum variant cast as u32,
+/// e.g. `assert_custom_error(err, MplAgentReputationError::SomeError as u32)`.
+#[allow(dead_code)]
+pub fn assert_custom_error(error: BanksClientError, expected_code: u32) {
+    match error.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected_code);
+        }
+        err => panic!("Expected InstructionError::Custom({expected_code}), got: {err:?}"),
+    }
+}
+
+pub const MPL_ACCOUNT_COMPRESSION_ID: Pubkey =
+    solana_program::pubkey!("mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW");
+
+pub const MPL_NOOP_ID: Pubkey =
+    solana_program::pubkey!("mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3");
+
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_program::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Add a program data account naming `upgrade_authority` as the upgrade
+/// authority of the reputation program, as a deployment would.
+#[allow(dead_code)]
+pub fn add_program_data(program_test: &mut ProgramTest, upgrade_authority: Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[mpl_agent_reputation::ID.as_ref()],
+        &BPF_LOADER_UPGRADEABLE_ID,
+    );
+
+    // UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address }
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.as_ref());
+
+    program_test.add_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: BPF_LOADER_UPGRADEABLE_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    address
+}
+
+pub fn setup() -> ProgramTest {
+    let mut program_test = ProgramTest::new(
+        "mpl_agent_reputation_program",
+        mpl_agent_reputation::ID,
+        None,
+    );
+    program_test.add_program("mpl_core", MPL_CORE_ID, None);
+    program_test.add_program("mpl_agent_identity_program", mpl_agent_identity::ID, None);
+    program_test.add_program("mpl_agent_tools_program", mpl_agent_tools::ID, None);
+    program_test.add_program("mpl_bubblegum", mpl_bubblegum::ID, None);
+    program_test.add_program("mpl_account_compression", MPL_ACCOUNT_COMPRESSION_ID, None);
+    program_test.add_program("mpl_noop", MPL_NOOP_ID, None);
+    program_test
+}
+
+pub async fn create_collection_and_asset(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+
+    let create_collection_ix = CreateCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Collection".to_string())
+        .uri("https://example.com/collection.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_collection_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &collection],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let create_asset_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .name("Test Asset".to_string())
+        .uri("https://example.com/asset.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_asset_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (collection.pubkey(), asset.pubkey())
+}
+
+/// Register the reputation of `asset`. The payer is the collection update
+/// authority. Returns the address of the agent reputation PDA.
+pub async fn register_reputation(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    collection: Pubkey,
+) -> Pubkey {
+    let (agent_reputation_pda, _) = AgentReputationV1::find_pda(&asset);
+
+    let ix = RegisterReputationV1Builder::new()
+        .agent_reputation(agent_reputation_pda)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    agent_reputation_pda
+}
@@ -0,0 +1,263 @@
+//! Work receipts minted by mpl-agent-tools, which reviewers must hold to
+//! submit feedback.
+#![allow(dead_code)]
+
+use mpl_agent_identity::{accounts::AgentIdentityV2, instructions::RegisterIdentityV1Builder};
+use mpl_agent_tools::{
+    accounts::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, ExecutiveProfileV1},
+    instructions::{
+        CreateReceiptsCollectionV1Builder, DelegateExecutionV1Builder, MintWorkReceiptV1Builder,
+        RegisterExecutiveV1Builder, RegisterReceiptsTreeV1Builder,
+    },
+};
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    hash::{hash_collection_option, hash_creators, hash_metadata, DEFAULT_ASSET_DATA_HASH},
+    types::{Creator, LeafSchema, MetadataArgsV2, TokenStandard},
+    utils::get_asset_id,
+};
+use solana_program::{instruction::AccountMeta, keccak};
+use solana_program_test::ProgramTestContext;
+use solana_sdk::{
+    instruction::Instruction, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+};
+
+pub const MPL_CORE_CPI_SIGNER: Pubkey =
+    solana_program::pubkey!("CbNY3JiXdXNE9tPNEk1aRZVEkWdj2v7kfJLNQwZZgpXk");
+
+pub const TREE_MAX_DEPTH: usize = 5;
+pub const TREE_MAX_BUFFER: u32 = 8;
+
+/// A receipts tree and the leaves minted into it, from which the tests
+/// build merkle proofs.
+pub struct ReceiptsTree {
+    pub tree_index: u64,
+    pub merkle_tree: Pubkey,
+    leaves: Vec<[u8; 32]>,
+}
+
+/// A work receipt minted to `owner` by `agent`.
+#[derive(Clone, Copy, Debug)]
+pub struct WorkReceipt {
+    pub tree_index: u64,
+    pub nonce: u64,
+    pub index: u32,
+    pub data_hash: [u8; 32],
+    pub agent: Pubkey,
+    pub owner: Pubkey,
+}
+
+pub fn receipts_collection() -> Pubkey {
+    Pubkey::find_program_address(&[b"receipts_collection"], &mpl_agent_tools::ID).0
+}
+
+pub fn receipts_authority() -> Pubkey {
+    Pubkey::find_program_address(&[b"receipts_authority"], &mpl_agent_tools::ID).0
+}
+
+pub fn receipts_tree(tree_index: u64) -> Pubkey {
+    Pubkey::find_program_address(
+        &[b"receipts_tree", &tree_index.to_le_bytes()],
+        &mpl_agent_tools::ID,
+    )
+    .0
+}
+
+async fn process(context: &mut ProgramTestContext, ix: Instruction) {
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Create the receipts collection and register the receipts tree at
+/// `tree_index`.
+pub async fn bootstrap_receipts(context: &mut ProgramTestContext, tree_index: u64) -> ReceiptsTree {
+    let ix = CreateReceiptsCollectionV1Builder::new()
+        .payer(context.payer.pubkey())
+        .collection(receipts_collection())
+        .authority(receipts_authority())
+        .instruction();
+    process(context, ix).await;
+
+    let merkle_tree = receipts_tree(tree_index);
+    let ix = RegisterReceiptsTreeV1Builder::new()
+        .payer(context.payer.pubkey())
+        .authority(receipts_authority())
+        .merkle_tree(merkle_tree)
+        .tree_config(TreeConfig::find_pda(&merkle_tree).0)
+        .tree_index(tree_index)
+        .max_depth(TREE_MAX_DEPTH as u32)
+        .max_buffer_size(TREE_MAX_BUFFER)
+        .canopy_depth(0)
+        .instruction();
+    process(context, ix).await;
+
+    ReceiptsTree {
+        tree_index,
+        merkle_tree,
+        leaves: Vec::new(),
+    }
+}
+
+/// Register the identity of `agent` and delegate its execution to the
+/// payer, who can then mint work receipts for the agent.
+pub async fn delegate_execution_to_payer(
+    context: &mut ProgramTestContext,
+    agent: Pubkey,
+    collection: Pubkey,
+) -> Pubkey {
+    let agent_identity = AgentIdentityV2::find_pda(&agent).0;
+    let ix = RegisterIdentityV1Builder::new()
+        .agent_identity(agent_identity)
+        .asset(agent)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/agent.json".to_string())
+        .instruction();
+    process(context, ix).await;
+
+    let executive_profile = ExecutiveProfileV1::find_pda(&context.payer.pubkey()).0;
+    let registered = context
+        .banks_client
+        .get_account(executive_profile)
+        .await
+        .unwrap()
+        .is_some();
+    if !registered {
+        let ix = RegisterExecutiveV1Builder::new()
+            .executive_profile(executive_profile)
+            .payer(context.payer.pubkey())
+            .instruction();
+        process(context, ix).await;
+    }
+
+    let execution_delegate_record =
+        ExecutionDelegateRecordV1::find_pda(&executive_profile, &agent).0;
+    let ix = DelegateExecutionV1Builder::new()
+        .executive_profile(executive_profile)
+        .agent_asset(agent)
+        .agent_identity(agent_identity)
+        .execution_delegate_record(execution_delegate_record)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&agent).0)
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(context, ix).await;
+
+    execution_delegate_record
+}
+
+fn receipt_creators(agent: Pubkey, owner: Pubkey) -> Vec<Creator> {
+    vec![
+        Creator {
+            address: agent,
+            verified: false,
+            share: 100,
+        },
+        Creator {
+            address: owner,
+            verified: false,
+            share: 0,
+        },
+    ]
+}
+
+/// Mint a work receipt from `agent` to `client`. The payer must hold an
+/// execution delegate record for the agent.
+pub async fn mint_work_receipt(
+    context: &mut ProgramTestContext,
+    tree: &mut ReceiptsTree,
+    agent: Pubkey,
+    execution_delegate_record: Pubkey,
+    client: Pubkey,
+) -> WorkReceipt {
+    let receipt_uri = "https://example.com/receipt.json".to_string();
+
+    let ix = MintWorkReceiptV1Builder::new()
+        .payer(context.payer.pubkey())
+        .executive_authority(context.payer.pubkey())
+        .execution_delegate_record(execution_delegate_record)
+        .agent_asset(agent)
+        .client(client)
+        .authority(receipts_authority())
+        .tree_config(TreeConfig::find_pda(&tree.merkle_tree).0)
+        .merkle_tree(tree.merkle_tree)
+        .core_collection(receipts_collection())
+        .mpl_core_cpi_signer(MPL_CORE_CPI_SIGNER)
+        .tree_index(tree.tree_index)
+        .receipt_uri(receipt_uri.clone())
+        .instruction();
+    process(context, ix).await;
+
+    let data_hash = hash_metadata(&MetadataArgsV2 {
+        name: "Agent Work Receipt".to_string(),
+        symbol: "AGENTRCPT".to_string(),
+        uri: receipt_uri,
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        token_standard: Some(TokenStandard::NonFungible),
+        creators: receipt_creators(agent, client),
+        collection: Some(receipts_collection()),
+    })
+    .unwrap();
+
+    let receipt = WorkReceipt {
+        tree_index: tree.tree_index,
+        nonce: tree.leaves.len() as u64,
+        index: tree.leaves.len() as u32,
+        data_hash,
+        agent,
+        owner: client,
+    };
+    tree.leaves.push(leaf_hash(tree.merkle_tree, &receipt));
+
+    receipt
+}
+
+/// Hash of the Bubblegum V2 leaf of `receipt`.
+pub fn leaf_hash(merkle_tree: Pubkey, receipt: &WorkReceipt) -> [u8; 32] {
+    LeafSchema::V2 {
+        id: get_asset_id(&merkle_tree, receipt.nonce),
+        owner: receipt.owner,
+        delegate: receipt.owner,
+        nonce: receipt.nonce,
+        data_hash: receipt.data_hash,
+        creator_hash: hash_creators(&receipt_creators(receipt.agent, receipt.owner)),
+        collection_hash: hash_collection_option(Some(receipts_collection())).unwrap(),
+        asset_data_hash: DEFAULT_ASSET_DATA_HASH,
+        flags: 0,
+    }
+    .hash()
+}
+
+/// Current root of `tree` and the merkle proof of the leaf at `index`, as
+/// remaining accounts.
+pub fn merkle_proof(tree: &ReceiptsTree, index: u32) -> ([u8; 32], Vec<AccountMeta>) {
+    let hash_pair = |left: &[u8; 32], right: &[u8; 32]| keccak::hashv(&[left, right]).to_bytes();
+
+    let mut layer = tree.leaves.clone();
+    let mut empty = [0u8; 32];
+    let mut position = index as usize;
+    let mut proof = Vec::with_capacity(TREE_MAX_DEPTH);
+
+    for _ in 0..TREE_MAX_DEPTH {
+        let sibling = layer.get(position ^ 1).copied().unwrap_or(empty);
+        proof.push(AccountMeta::new_readonly(
+            Pubkey::new_from_array(sibling),
+            false,
+        ));
+
+        layer = layer
+            .chunks(2)
+            .map(|pair| hash_pair(&pair[0], pair.get(1).unwrap_or(&empty)))
+            .collect();
+        empty = hash_pair(&empty, &empty);
+        position /= 2;
+    }
+
+    (layer.first().copied().unwrap_or(empty), proof)
+}
@@ -0,0 +1,219 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use std::collections::HashMap;
+
+use mpl_agent_reputation::{
+    accounts::{AgentReputationV2, ReputationConfigV1, ReputationSnapshotV1},
+    errors::MplAgentReputationError,
+    events::MplAgentReputationEvent,
+    instructions::SnapshotReputationV1Builder,
+    snapshots::{reputation_snapshots, snapshot_addresses},
+    types::Key,
+};
+use setup::feedback::{
+    fetch_reputation, issue_receipt, process, setup_feedback, submit_feedback_at_builder,
+};
+use solana_program::{clock::Clock, instruction::Instruction};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{pubkey::Pubkey, signature::Signer};
+
+fn snapshot_reputation_ix(context: &ProgramTestContext, asset: Pubkey, epoch: u64) -> Instruction {
+    SnapshotReputationV1Builder::new()
+        .reputation_snapshot(ReputationSnapshotV1::find_pda(&asset, epoch).0)
+        .agent_reputation(AgentReputationV2::find_pda(&asset).0)
+        .asset(asset)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+async fn current_epoch(context: &mut ProgramTestContext) -> u64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .epoch
+}
+
+/// Snapshot the reputation of `asset` in the current epoch.
+async fn snapshot(context: &mut ProgramTestContext, asset: Pubkey) -> ReputationSnapshotV1 {
+    let epoch = current_epoch(context).await;
+    let ix = snapshot_reputation_ix(context, asset, epoch);
+    let payer = context.payer.insecure_clone();
+    process(context, ix, &payer).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(ReputationSnapshotV1::find_pda(&asset, epoch).0)
+        .await
+        .unwrap()
+        .unwrap();
+    ReputationSnapshotV1::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn anyone_can_snapshot_reputation() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, _) = setup_feedback(&mut context).await;
+
+    let epoch = current_epoch(&mut context).await;
+    let ix = snapshot_reputation_ix(&context, agent.asset, epoch);
+    let payer = context.payer.insecure_clone();
+    let events = process(&mut context, ix, &payer).await.unwrap();
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    let account = context
+        .banks_client
+        .get_account(ReputationSnapshotV1::find_pda(&agent.asset, epoch).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let snapshot = ReputationSnapshotV1::from_bytes(&account.data).unwrap();
+    assert_eq!(snapshot.key, Key::ReputationSnapshotV1);
+    assert_eq!(snapshot.asset, agent.asset);
+    assert_eq!(snapshot.epoch, epoch);
+    assert_eq!(snapshot.slot, agent_reputation.decay_slot);
+    assert_eq!(snapshot.feedback_count, 1);
+    assert_eq!(snapshot.score_sum, 60);
+    assert_eq!(snapshot.score_sum_squares, 3_600);
+    assert_eq!(
+        snapshot.last_feedback_slot,
+        agent_reputation.last_feedback_slot
+    );
+    assert_eq!(snapshot.decayed_score, agent_reputation.decayed_score);
+    assert_eq!(snapshot.decayed_count, agent_reputation.decayed_count);
+    assert_eq!(snapshot.weight_sum, 1);
+    assert_eq!(snapshot.weighted_score_sum, 60);
+    assert_eq!(snapshot.disputed_count, 0);
+
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::ReputationSnapshottedV1 {
+            asset: agent.asset,
+            epoch,
+            slot: snapshot.slot,
+            feedback_count: 1,
+            score_sum: 60,
+            decayed_score: snapshot.decayed_score,
+            decayed_count: snapshot.decayed_count,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn snapshot_upgrades_reputation_without_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_reputation(&mut context, asset, collection).await;
+
+    let snapshot = snapshot(&mut context, asset).await;
+    assert_eq!(snapshot.asset, asset);
+    assert_eq!(snapshot.feedback_count, 0);
+    assert_eq!(snapshot.score_sum, 0);
+
+    let agent_reputation = fetch_reputation(&mut context, asset).await;
+    assert_eq!(agent_reputation.key, Key::AgentReputationV2);
+}
+
+#[tokio::test]
+async fn cannot_snapshot_twice_in_an_epoch() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, _) = setup_feedback(&mut context).await;
+    snapshot(&mut context, agent.asset).await;
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let epoch = current_epoch(&mut context).await;
+    let ix = snapshot_reputation_ix(&context, agent.asset, epoch);
+    let payer = context.payer.insecure_clone();
+    let err = process(&mut context, ix, &payer).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::ReputationAlreadySnapshotted as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_snapshot_another_epoch() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, _) = setup_feedback(&mut context).await;
+
+    let epoch = current_epoch(&mut context).await;
+    let ix = snapshot_reputation_ix(&context, agent.asset, epoch + 1);
+    let payer = context.payer.insecure_clone();
+    let err = process(&mut context, ix, &payer).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::InvalidReputationSnapshot as u32,
+    );
+}
+
+#[tokio::test]
+async fn snapshots_form_a_series() {
+    let mut context = setup::setup().start_with_context().await;
+    let (mut agent, reviewer) = setup_feedback(&mut context).await;
+
+    // Given snapshots in three epochs, with feedback in between.
+    let first_epoch = current_epoch(&mut context).await;
+    snapshot(&mut context, agent.asset).await;
+
+    context.warp_to_epoch(first_epoch + 150).unwrap();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+    let ix = submit_feedback_at_builder(&context, &agent, &reviewer, &receipt, 90, 1).instruction();
+    process(&mut context, ix, &reviewer).await.unwrap();
+    snapshot(&mut context, agent.asset).await;
+
+    context.warp_to_epoch(first_epoch + 260).unwrap();
+    snapshot(&mut context, agent.asset).await;
+
+    // When the series is fetched over 300 epochs.
+    let epochs = first_epoch..first_epoch + 300;
+    let mut accounts = HashMap::new();
+    for address in snapshot_addresses(&agent.asset, epochs.clone()) {
+        if let Some(account) = context.banks_client.get_account(address).await.unwrap() {
+            accounts.insert(address, account.data);
+        }
+    }
+
+    let mut fetches = 0;
+    let series = reputation_snapshots(agent.asset, epochs, |addresses: &[Pubkey]| {
+        fetches += 1;
+        assert!(addresses.len() <= 100);
+        Ok::<_, ()>(
+            addresses
+                .iter()
+                .map(|address| accounts.get(address).cloned())
+                .collect(),
+        )
+    })
+    .collect::<Result<Vec<_>, _>>()
+    .unwrap();
+
+    // Then the snapshots come in epoch order, in batches of 100 epochs.
+    assert_eq!(fetches, 3);
+    assert_eq!(
+        series
+            .iter()
+            .map(|snapshot| (snapshot.epoch - first_epoch, snapshot.feedback_count))
+            .collect::<Vec<_>>(),
+        vec![(0, 1), (150, 2), (260, 2)]
+    );
+    assert_eq!(series[1].score_sum, 150);
+    assert!(series[2].decayed_score <= series[1].decayed_score);
+}
+
+#[tokio::test]
+async fn series_stops_at_fetch_error() {
+    let mut calls = 0;
+    let mut series = reputation_snapshots(Pubkey::new_unique(), 0..1_000, |_: &[Pubkey]| {
+        calls += 1;
+        Err::<Vec<Option<Vec<u8>>>, _>("unavailable")
+    });
+
+    assert_eq!(series.next(), Some(Err("unavailable")));
+    assert_eq!(series.next(), None);
+    drop(series);
+    assert_eq!(calls, 1);
+}
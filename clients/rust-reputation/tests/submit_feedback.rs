@@ -0,0 +1,342 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_reputation::{
+    accounts::{AgentReputationV2, ReceiptNullifierV1},
+    errors::MplAgentReputationError,
+    events::MplAgentReputationEvent,
+    summary::ReputationSummaryV1,
+    types::Key,
+};
+use setup::{
+    feedback::{
+        fetch_feedback, fetch_reputation, fetch_summary, issue_receipt, process, setup_agent,
+        submit_feedback_at_builder, submit_feedback_builder, submit_feedback_ix, tag, Agent,
+        FEEDBACK_URI,
+    },
+    receipts,
+};
+use solana_program_test::tokio;
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+#[tokio::test]
+async fn submit_feedback() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    // Given a reviewer holding a work receipt issued by the agent.
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    // When the reviewer submits feedback.
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 80);
+    let events = process(&mut context, ix, &reviewer).await.unwrap();
+
+    // Then the agent reputation is upgraded and holds the aggregates.
+    let asset = agent.asset;
+    let agent_reputation = fetch_reputation(&mut context, asset).await;
+    assert_eq!(agent_reputation.key, Key::AgentReputationV2);
+    assert_eq!(agent_reputation.asset, asset);
+    assert_eq!(agent_reputation.feedback_count, 1);
+    assert_eq!(agent_reputation.score_sum, 80);
+    assert_eq!(agent_reputation.score_sum_squares, 6400);
+
+    // And the summary is written to the AppData plugin.
+    assert_eq!(
+        fetch_summary(&mut context, asset).await,
+        ReputationSummaryV1 {
+            feedback_count: 1,
+            score_sum: 80,
+            score_sum_squares: 6400,
+            last_feedback_slot: agent_reputation.last_feedback_slot,
+            disputed_count: 0,
+            disputed_score_sum: 0,
+        }
+    );
+
+    // And the receipt is nullified.
+    let account = context
+        .banks_client
+        .get_account(ReceiptNullifierV1::find_pda(receipt.tree_index, receipt.nonce).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let receipt_nullifier = ReceiptNullifierV1::from_bytes(&account.data).unwrap();
+    assert_eq!(receipt_nullifier.key, Key::ReceiptNullifierV1);
+    assert_eq!(receipt_nullifier.tree_index, receipt.tree_index);
+    assert_eq!(receipt_nullifier.nonce, receipt.nonce);
+    assert_eq!(receipt_nullifier.asset, asset);
+    assert_eq!(receipt_nullifier.reviewer, reviewer.pubkey());
+
+    // And the feedback is recorded.
+    let feedback = fetch_feedback(&mut context, asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.key, Key::FeedbackV1);
+    assert_eq!(feedback.score, 80);
+    assert_eq!(feedback.asset, asset);
+    assert_eq!(feedback.reviewer, reviewer.pubkey());
+    assert_eq!(feedback.index, 0);
+    assert_eq!(feedback.tree_index, receipt.tree_index);
+    assert_eq!(feedback.nonce, receipt.nonce);
+    assert_eq!(feedback.slot, agent_reputation.last_feedback_slot);
+    assert_eq!(feedback.response_count, 0);
+
+    // And the feedback details are emitted.
+    assert_eq!(
+        events,
+        vec![
+            MplAgentReputationEvent::FeedbackSubmittedV1 {
+                asset,
+                reviewer: reviewer.pubkey(),
+                score: 80,
+                tag1: tag("quality"),
+                tag2: tag("latency"),
+                feedback_uri: FEEDBACK_URI.to_string(),
+            },
+            MplAgentReputationEvent::FeedbackWeightedV1 {
+                asset,
+                reviewer: reviewer.pubkey(),
+                feedback_index: 0,
+                weight: 1,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn feedback_aggregates_accumulate() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewers = [Keypair::new(), Keypair::new(), Keypair::new()];
+    let mut receipts = Vec::new();
+    for reviewer in &reviewers {
+        receipts.push(issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await);
+    }
+
+    for ((reviewer, receipt), score) in reviewers.iter().zip(&receipts).zip([100, 50, 0]) {
+        let ix = submit_feedback_ix(&context, &agent, reviewer, receipt, score);
+        process(&mut context, ix, reviewer).await.unwrap();
+    }
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.feedback_count, 3);
+    assert_eq!(agent_reputation.score_sum, 150);
+    assert_eq!(agent_reputation.score_sum_squares, 12_500);
+
+    let summary = fetch_summary(&mut context, agent.asset).await;
+    assert_eq!(summary.feedback_count, 3);
+    assert_eq!(summary.average_score(), Some(50.0));
+}
+
+#[tokio::test]
+async fn cannot_review_a_receipt_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 90);
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    // The same receipt cannot back a second review.
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 10);
+    let err = process(&mut context, ix, &reviewer).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::ReceiptAlreadyReviewed as u32);
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.feedback_count, 1);
+}
+
+#[tokio::test]
+async fn reviewer_with_several_receipts_can_review_each() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let first = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+    let second = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    for (feedback_index, receipt) in [first, second].iter().enumerate() {
+        let ix = submit_feedback_at_builder(
+            &context,
+            &agent,
+            &reviewer,
+            receipt,
+            70,
+            feedback_index as u64,
+        )
+        .instruction();
+        process(&mut context, ix, &reviewer).await.unwrap();
+    }
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.feedback_count, 2);
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 1).await;
+    assert_eq!(feedback.nonce, second.nonce);
+}
+
+#[tokio::test]
+async fn cannot_reuse_a_feedback_index() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let first = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+    let second = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &first, 70);
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    // The second receipt is fresh, but the feedback index is taken.
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &second, 70);
+    let err = process(&mut context, ix, &reviewer).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::FeedbackAlreadyExists as u32);
+}
+
+#[tokio::test]
+async fn cannot_submit_feedback_with_receipt_of_another_client() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let client = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, client.pubkey()).await;
+
+    // A stranger presents the client's receipt.
+    let stranger = Keypair::new();
+    let ix = submit_feedback_ix(&context, &agent, &stranger, &receipt, 0);
+    let result = process(&mut context, ix, &stranger).await;
+
+    assert!(result.is_err());
+    assert!(context
+        .banks_client
+        .get_account(ReceiptNullifierV1::find_pda(receipt.tree_index, receipt.nonce).0)
+        .await
+        .unwrap()
+        .is_none());
+}
+
+#[tokio::test]
+async fn cannot_submit_feedback_with_receipt_of_another_agent() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    // The receipt was issued by `agent`, not by the reviewed asset.
+    let (other_collection, other_asset) = setup::create_collection_and_asset(&mut context).await;
+    setup::register_reputation(&mut context, other_asset, other_collection).await;
+
+    let ix = submit_feedback_builder(&context, &agent, &reviewer, &receipt, 0)
+        .agent_reputation(AgentReputationV2::find_pda(&other_asset).0)
+        .asset(other_asset)
+        .collection(Some(other_collection))
+        .instruction();
+    let result = process(&mut context, ix, &reviewer).await;
+
+    assert!(result.is_err());
+}
+
+#[tokio::test]
+async fn cannot_submit_feedback_with_foreign_tree() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    let ix = submit_feedback_builder(&context, &agent, &reviewer, &receipt, 50)
+        .merkle_tree(Pubkey::new_unique())
+        .instruction();
+    let err = process(&mut context, ix, &reviewer).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidReceiptsTree as u32);
+}
+
+#[tokio::test]
+async fn cannot_submit_feedback_above_max_score() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 101);
+    let err = process(&mut context, ix, &reviewer).await.unwrap_err();
+
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidFeedbackScore as u32);
+}
+
+#[tokio::test]
+async fn cannot_submit_feedback_with_invalid_uri() {
+    let mut context = setup::setup().start_with_context().await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    let ix = submit_feedback_builder(&context, &agent, &reviewer, &receipt, 90)
+        .feedback_uri("http://example.com/feedback.json".to_string())
+        .instruction();
+    let err = process(&mut context, ix, &reviewer).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::FeedbackUriSchemeNotAllowed as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_submit_feedback_for_unregistered_reputation() {
+    let mut context = setup::setup().start_with_context().await;
+
+    let (collection, asset) = setup::create_collection_and_asset(&mut context).await;
+    let execution_delegate_record =
+        receipts::delegate_execution_to_payer(&mut context, asset, collection).await;
+    let mut agent = Agent {
+        asset,
+        collection,
+        execution_delegate_record,
+        receipts_tree: receipts::bootstrap_receipts(&mut context, 0).await,
+    };
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 90);
+    let err = process(&mut context, ix, &reviewer).await.unwrap_err();
+
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::AgentReputationNotRegistered as u32,
+    );
+}
+
+#[test]
+fn decode_summary() {
+    let mut data = vec![ReputationSummaryV1::VERSION, 0, 0, 0, 0, 0, 0, 0];
+    for field in [2u64, 150, 12_500, 42, 1, 50] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+
+    let summary = ReputationSummaryV1::from_bytes(&data).unwrap();
+    assert_eq!(summary.feedback_count, 2);
+    assert_eq!(summary.score_sum, 150);
+    assert_eq!(summary.last_feedback_slot, 42);
+    assert_eq!(summary.average_score(), Some(75.0));
+    assert_eq!(summary.score_variance(), Some(625.0));
+    assert_eq!(summary.disputed_count, 1);
+    assert_eq!(summary.undisputed_average_score(), Some(100.0));
+
+    // Unknown versions are rejected.
+    data[0] = 2;
+    assert_eq!(ReputationSummaryV1::from_bytes(&data), None);
+}
@@ -0,0 +1,746 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_identity::{accounts::AgentIdentityV2, instructions::RegisterIdentityV1Builder};
+use mpl_agent_reputation::{
+    accounts::{
+        AgentReputationV2, FeedbackV1, ReputationConfigV1, ReviewerHistoryV1, TokenLockV1,
+        WeightingPolicyV1,
+    },
+    errors::MplAgentReputationError,
+    events::MplAgentReputationEvent,
+    instructions::{
+        DisputeFeedbackV1Builder, InitializeReputationConfigV1Builder, RevokeFeedbackV1Builder,
+        SetWeightingPolicyV1Builder, UnlockTokensV1Builder,
+    },
+    types::Key,
+};
+use setup::feedback::{
+    fetch_feedback, fetch_reputation, issue_receipt, process, setup_agent, setup_feedback,
+    submit_feedback_at_builder, submit_feedback_ix, Agent,
+};
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// A policy weighting feedback by receipts, identity and tokens.
+struct Policy {
+    base_weight: u32,
+    receipt_weight: u32,
+    max_receipts: u32,
+    identity_weight: u32,
+    token_weight: u32,
+    token_mint: Pubkey,
+    min_token_balance: u64,
+    min_identity_feedback: u64,
+    token_lock_slots: u64,
+}
+
+const POLICY: Policy = Policy {
+    base_weight: 1,
+    receipt_weight: 2,
+    max_receipts: 2,
+    identity_weight: 10,
+    token_weight: 100,
+    token_mint: Pubkey::new_from_array([0u8; 32]),
+    min_token_balance: 0,
+    min_identity_feedback: 1,
+    token_lock_slots: 1_000,
+};
+
+/// Start a test validator with a reputation config whose authority is
+/// returned.
+async fn start(mut program_test: ProgramTest) -> (ProgramTestContext, Keypair) {
+    let authority = Keypair::new();
+    let program_data = setup::add_program_data(&mut program_test, authority.pubkey());
+    let mut context = program_test.start_with_context().await;
+
+    let ix = InitializeReputationConfigV1Builder::new()
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .program_data(program_data)
+        .authority(authority.pubkey())
+        .payer(context.payer.pubkey())
+        .half_life_slots(1_000_000)
+        .instruction();
+    process(&mut context, ix, &authority).await.unwrap();
+
+    (context, authority)
+}
+
+fn set_weighting_policy_ix(
+    context: &ProgramTestContext,
+    authority: Pubkey,
+    policy: &Policy,
+) -> Instruction {
+    SetWeightingPolicyV1Builder::new()
+        .weighting_policy(WeightingPolicyV1::find_pda().0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .authority(authority)
+        .payer(context.payer.pubkey())
+        .base_weight(policy.base_weight)
+        .receipt_weight(policy.receipt_weight)
+        .max_receipts(policy.max_receipts)
+        .identity_weight(policy.identity_weight)
+        .token_weight(policy.token_weight)
+        .token_mint(policy.token_mint)
+        .min_token_balance(policy.min_token_balance)
+        .min_identity_feedback(policy.min_identity_feedback)
+        .token_lock_slots(policy.token_lock_slots)
+        .instruction()
+}
+
+async fn set_policy(context: &mut ProgramTestContext, authority: &Keypair, policy: &Policy) {
+    let ix = set_weighting_policy_ix(context, authority.pubkey(), policy);
+    process(context, ix, authority).await.unwrap();
+}
+
+async fn fetch_history(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    reviewer: Pubkey,
+) -> ReviewerHistoryV1 {
+    let account = context
+        .banks_client
+        .get_account(ReviewerHistoryV1::find_pda(&asset, &reviewer).0)
+        .await
+        .unwrap()
+        .unwrap();
+    ReviewerHistoryV1::from_bytes(&account.data).unwrap()
+}
+
+/// Give the payer an asset with a registered agent identity, other than
+/// the reviewed one. Returns the asset.
+async fn register_reviewer_identity(context: &mut ProgramTestContext) -> Pubkey {
+    let (collection, asset) = setup::create_collection_and_asset(context).await;
+    let ix = RegisterIdentityV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/reviewer.json".to_string())
+        .instruction();
+    let payer = context.payer.insecure_clone();
+    process(context, ix, &payer).await.unwrap();
+    asset
+}
+
+/// An agent of the payer, with a registered identity, that received
+/// feedback from a client.
+struct ReviewerAgent {
+    asset: Pubkey,
+    collection: Pubkey,
+    /// The feedback PDA of the client's feedback.
+    feedback: Pubkey,
+}
+
+/// Give the payer an agent that received feedback, and an agent to review
+/// sharing its receipts tree.
+async fn setup_established_reviewer(context: &mut ProgramTestContext) -> (ReviewerAgent, Agent) {
+    let mut reviewer_agent = setup_agent(context).await;
+    let client = Keypair::new();
+    let receipt = issue_receipt(context, &mut reviewer_agent, client.pubkey()).await;
+    let ix = submit_feedback_ix(context, &reviewer_agent, &client, &receipt, 80);
+    process(context, ix, &client).await.unwrap();
+
+    let (collection, asset) = setup::create_collection_and_asset(context).await;
+    setup::register_reputation(context, asset, collection).await;
+    let execution_delegate_record =
+        setup::receipts::delegate_execution_to_payer(context, asset, collection).await;
+    let agent = Agent {
+        asset,
+        collection,
+        execution_delegate_record,
+        receipts_tree: reviewer_agent.receipts_tree,
+    };
+
+    let reviewer = ReviewerAgent {
+        asset: reviewer_agent.asset,
+        collection: reviewer_agent.collection,
+        feedback: FeedbackV1::find_pda(&reviewer_agent.asset, &client.pubkey(), 0).0,
+    };
+    (reviewer, agent)
+}
+
+/// Add an SPL Token mint with no authorities.
+fn add_mint(program_test: &mut ProgramTest) -> Pubkey {
+    let address = Pubkey::new_unique();
+    let mut data = vec![0u8; 82];
+    // Supply, decimals and initialized.
+    data[36..44].copy_from_slice(&u64::MAX.to_le_bytes());
+    data[44] = 6;
+    data[45] = 1;
+    program_test.add_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: SPL_TOKEN_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    address
+}
+
+/// Add a token account of `owner` holding `amount` of `mint`.
+fn add_token_account(
+    program_test: &mut ProgramTest,
+    mint: Pubkey,
+    owner: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let address = Pubkey::new_unique();
+    let mut data = vec![0u8; 165];
+    data[..32].copy_from_slice(mint.as_ref());
+    data[32..64].copy_from_slice(owner.as_ref());
+    data[64..72].copy_from_slice(&amount.to_le_bytes());
+    // Initialized.
+    data[108] = 1;
+    program_test.add_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: SPL_TOKEN_PROGRAM_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    address
+}
+
+/// Add a token account of the token lock of `reviewer` holding `amount` of
+/// `mint`.
+fn add_locked_tokens(
+    program_test: &mut ProgramTest,
+    mint: Pubkey,
+    reviewer: Pubkey,
+    amount: u64,
+) -> Pubkey {
+    let token_lock = TokenLockV1::find_pda(&reviewer, &mint).0;
+    add_token_account(program_test, mint, token_lock, amount)
+}
+
+async fn token_balance(context: &mut ProgramTestContext, token_account: Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(token_account)
+        .await
+        .unwrap()
+        .unwrap();
+    u64::from_le_bytes(account.data[64..72].try_into().unwrap())
+}
+
+/// Submit feedback of score 50 from `reviewer` at `feedback_index`, backed
+/// by a fresh receipt, proving an identity and tokens locked in the token
+/// vault of the given mint as given.
+async fn submit(
+    context: &mut ProgramTestContext,
+    agent: &mut Agent,
+    reviewer: &Keypair,
+    feedback_index: u64,
+    reviewer_asset: Option<Pubkey>,
+    locked_tokens: Option<(Pubkey, Pubkey)>,
+) -> Result<Vec<MplAgentReputationEvent>, BanksClientError> {
+    let receipt = issue_receipt(context, agent, reviewer.pubkey()).await;
+    let mut builder =
+        submit_feedback_at_builder(context, agent, reviewer, &receipt, 50, feedback_index);
+    builder
+        .reviewer_asset(reviewer_asset)
+        .reviewer_identity(reviewer_asset.map(|asset| AgentIdentityV2::find_pda(&asset).0))
+        .reviewer_reputation(reviewer_asset.map(|asset| AgentReputationV2::find_pda(&asset).0))
+        .reviewer_token_lock(
+            locked_tokens.map(|(mint, _)| TokenLockV1::find_pda(&reviewer.pubkey(), &mint).0),
+        )
+        .reviewer_token_vault(locked_tokens.map(|(_, token_vault)| token_vault));
+    let ix = builder.instruction();
+    process(context, ix, reviewer).await
+}
+
+#[tokio::test]
+async fn feedback_weighs_one_without_policy() {
+    let mut context = setup::setup().start_with_context().await;
+    let (agent, reviewer) = setup_feedback(&mut context).await;
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.weight, 1);
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.weight_sum, 1);
+    assert_eq!(agent_reputation.weighted_score_sum, 60);
+
+    let history = fetch_history(&mut context, agent.asset, reviewer.pubkey()).await;
+    assert_eq!(history.key, Key::ReviewerHistoryV1);
+    assert_eq!(history.asset, agent.asset);
+    assert_eq!(history.reviewer, reviewer.pubkey());
+    assert_eq!(history.receipt_count, 1);
+}
+
+#[tokio::test]
+async fn config_authority_can_set_policy() {
+    let (mut context, authority) = start(setup::setup()).await;
+
+    let token_mint = Pubkey::new_unique();
+    let policy = Policy {
+        token_mint,
+        min_token_balance: 1_000,
+        ..POLICY
+    };
+    let ix = set_weighting_policy_ix(&context, authority.pubkey(), &policy);
+    let events = process(&mut context, ix, &authority).await.unwrap();
+
+    let account = context
+        .banks_client
+        .get_account(WeightingPolicyV1::find_pda().0)
+        .await
+        .unwrap()
+        .unwrap();
+    let weighting_policy = WeightingPolicyV1::from_bytes(&account.data).unwrap();
+    assert_eq!(weighting_policy.key, Key::WeightingPolicyV1);
+    assert_eq!(weighting_policy.base_weight, 1);
+    assert_eq!(weighting_policy.receipt_weight, 2);
+    assert_eq!(weighting_policy.max_receipts, 2);
+    assert_eq!(weighting_policy.identity_weight, 10);
+    assert_eq!(weighting_policy.token_weight, 100);
+    assert_eq!(weighting_policy.token_mint, token_mint);
+    assert_eq!(weighting_policy.min_token_balance, 1_000);
+    assert_eq!(weighting_policy.min_identity_feedback, 1);
+    assert_eq!(weighting_policy.token_lock_slots, 1_000);
+
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::WeightingPolicyUpdatedV1 {
+            base_weight: 1,
+            receipt_weight: 2,
+            max_receipts: 2,
+            identity_weight: 10,
+            token_weight: 100,
+            token_mint,
+            min_token_balance: 1_000,
+            min_identity_feedback: 1,
+            token_lock_slots: 1_000,
+        }]
+    );
+
+    // The policy can be updated in place.
+    context.get_new_latest_blockhash().await.unwrap();
+    set_policy(
+        &mut context,
+        &authority,
+        &Policy {
+            base_weight: 5,
+            ..POLICY
+        },
+    )
+    .await;
+    let account = context
+        .banks_client
+        .get_account(WeightingPolicyV1::find_pda().0)
+        .await
+        .unwrap()
+        .unwrap();
+    let weighting_policy = WeightingPolicyV1::from_bytes(&account.data).unwrap();
+    assert_eq!(weighting_policy.base_weight, 5);
+    assert_eq!(weighting_policy.token_mint, Pubkey::default());
+}
+
+#[tokio::test]
+async fn only_config_authority_can_set_policy() {
+    let (mut context, _) = start(setup::setup()).await;
+
+    let impostor = Keypair::new();
+    let ix = set_weighting_policy_ix(&context, impostor.pubkey(), &POLICY);
+    let err = process(&mut context, ix, &impostor).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidConfigAuthority as u32);
+}
+
+#[tokio::test]
+async fn cannot_set_policy_with_zero_base_weight() {
+    let (mut context, authority) = start(setup::setup()).await;
+
+    let ix = set_weighting_policy_ix(
+        &context,
+        authority.pubkey(),
+        &Policy {
+            base_weight: 0,
+            ..POLICY
+        },
+    );
+    let err = process(&mut context, ix, &authority).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidWeightingPolicy as u32);
+}
+
+#[tokio::test]
+async fn cannot_set_policy_with_free_identity_or_token_weight() {
+    let (mut context, authority) = start(setup::setup()).await;
+
+    for policy in [
+        Policy {
+            min_identity_feedback: 0,
+            ..POLICY
+        },
+        Policy {
+            token_mint: Pubkey::new_unique(),
+            token_lock_slots: 0,
+            ..POLICY
+        },
+    ] {
+        let ix = set_weighting_policy_ix(&context, authority.pubkey(), &policy);
+        let err = process(&mut context, ix, &authority).await.unwrap_err();
+        setup::assert_custom_error(err, MplAgentReputationError::InvalidWeightingPolicy as u32);
+    }
+}
+
+#[tokio::test]
+async fn proven_receipts_add_weight_up_to_max() {
+    let (mut context, authority) = start(setup::setup()).await;
+    set_policy(&mut context, &authority, &POLICY).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    // 1 + 2 * min(receipts, 2) for the 1st, 2nd and 3rd receipt.
+    let reviewer = Keypair::new();
+    for (index, weight) in [(0, 3), (1, 5), (2, 5)] {
+        let events = submit(&mut context, &mut agent, &reviewer, index, None, None)
+            .await
+            .unwrap();
+        assert_eq!(
+            events[1],
+            MplAgentReputationEvent::FeedbackWeightedV1 {
+                asset: agent.asset,
+                reviewer: reviewer.pubkey(),
+                feedback_index: index,
+                weight,
+            }
+        );
+        let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), index).await;
+        assert_eq!(feedback.weight, weight);
+    }
+
+    let history = fetch_history(&mut context, agent.asset, reviewer.pubkey()).await;
+    assert_eq!(history.receipt_count, 3);
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.score_sum, 150);
+    assert_eq!(agent_reputation.weight_sum, 13);
+    assert_eq!(agent_reputation.weighted_score_sum, 650);
+}
+
+#[tokio::test]
+async fn established_identity_adds_weight() {
+    let (mut context, authority) = start(setup::setup()).await;
+    set_policy(&mut context, &authority, &POLICY).await;
+    let (reviewer_agent, mut agent) = setup_established_reviewer(&mut context).await;
+
+    let reviewer = context.payer.insecure_clone();
+    submit(
+        &mut context,
+        &mut agent,
+        &reviewer,
+        0,
+        Some(reviewer_agent.asset),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.weight, 1 + 2 + 10);
+}
+
+#[tokio::test]
+async fn identity_without_feedback_adds_no_weight() {
+    let (mut context, authority) = start(setup::setup()).await;
+    set_policy(&mut context, &authority, &POLICY).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    // Registering an identity alone is not enough.
+    let reviewer = context.payer.insecure_clone();
+    let reviewer_asset = register_reviewer_identity(&mut context).await;
+    submit(
+        &mut context,
+        &mut agent,
+        &reviewer,
+        0,
+        Some(reviewer_asset),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.weight, 1 + 2);
+}
+
+#[tokio::test]
+async fn identity_with_disputed_feedback_adds_no_weight() {
+    let (mut context, authority) = start(setup::setup()).await;
+    set_policy(&mut context, &authority, &POLICY).await;
+    let (reviewer_agent, mut agent) = setup_established_reviewer(&mut context).await;
+
+    // The reviewer disputes the only feedback their agent received, which
+    // then no longer counts.
+    let reviewer = context.payer.insecure_clone();
+    let ix = DisputeFeedbackV1Builder::new()
+        .agent_reputation(AgentReputationV2::find_pda(&reviewer_agent.asset).0)
+        .asset(reviewer_agent.asset)
+        .collection(Some(reviewer_agent.collection))
+        .feedback(reviewer_agent.feedback)
+        .authority(reviewer.pubkey())
+        .payer(reviewer.pubkey())
+        .instruction();
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    submit(
+        &mut context,
+        &mut agent,
+        &reviewer,
+        0,
+        Some(reviewer_agent.asset),
+        None,
+    )
+    .await
+    .unwrap();
+
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    assert_eq!(feedback.weight, 1 + 2);
+}
+
+#[tokio::test]
+async fn cannot_claim_identity_of_unregistered_asset() {
+    let (mut context, authority) = start(setup::setup()).await;
+    set_policy(&mut context, &authority, &POLICY).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = context.payer.insecure_clone();
+    let (_, reviewer_asset) = setup::create_collection_and_asset(&mut context).await;
+    let err = submit(
+        &mut context,
+        &mut agent,
+        &reviewer,
+        0,
+        Some(reviewer_asset),
+        None,
+    )
+    .await
+    .unwrap_err();
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidReviewerIdentity as u32);
+}
+
+#[tokio::test]
+async fn cannot_claim_identity_of_reviewed_asset() {
+    let (mut context, authority) = start(setup::setup()).await;
+    set_policy(&mut context, &authority, &POLICY).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    // The payer owns the agent, whose identity is registered.
+    let reviewer = context.payer.insecure_clone();
+    let asset = agent.asset;
+    let err = submit(&mut context, &mut agent, &reviewer, 0, Some(asset), None)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidReviewerIdentity as u32);
+}
+
+#[tokio::test]
+async fn locked_tokens_add_weight() {
+    let rich = Keypair::new();
+    let poor = Keypair::new();
+
+    let mut program_test = setup::setup();
+    let token_mint = add_mint(&mut program_test);
+    let rich_tokens = add_locked_tokens(&mut program_test, token_mint, rich.pubkey(), 1_000);
+    let poor_tokens = add_locked_tokens(&mut program_test, token_mint, poor.pubkey(), 999);
+    let (mut context, authority) = start(program_test).await;
+    let policy = Policy {
+        token_mint,
+        min_token_balance: 1_000,
+        ..POLICY
+    };
+    set_policy(&mut context, &authority, &policy).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let locked_tokens = Some((token_mint, rich_tokens));
+    submit(&mut context, &mut agent, &rich, 0, None, locked_tokens)
+        .await
+        .unwrap();
+    let feedback = fetch_feedback(&mut context, agent.asset, rich.pubkey(), 0).await;
+    assert_eq!(feedback.weight, 1 + 2 + 100);
+
+    // The tokens stay locked for the lock period after the feedback.
+    let account = context
+        .banks_client
+        .get_account(TokenLockV1::find_pda(&rich.pubkey(), &token_mint).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let token_lock = TokenLockV1::from_bytes(&account.data).unwrap();
+    assert_eq!(token_lock.key, Key::TokenLockV1);
+    assert_eq!(token_lock.reviewer, rich.pubkey());
+    assert_eq!(token_lock.mint, token_mint);
+    assert!(token_lock.unlock_slot >= feedback.slot + 1_000);
+
+    let locked_tokens = Some((token_mint, poor_tokens));
+    submit(&mut context, &mut agent, &poor, 0, None, locked_tokens)
+        .await
+        .unwrap();
+    let feedback = fetch_feedback(&mut context, agent.asset, poor.pubkey(), 0).await;
+    assert_eq!(feedback.weight, 1 + 2);
+
+    // Tokens that add no weight are not locked.
+    let account = context
+        .banks_client
+        .get_account(TokenLockV1::find_pda(&poor.pubkey(), &token_mint).0)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn unlocked_tokens_add_no_weight() {
+    // Tokens held in the reviewer's own account could be moved to another
+    // wallet right after the feedback.
+    let reviewer = Keypair::new();
+    let mut program_test = setup::setup();
+    let token_mint = add_mint(&mut program_test);
+    let tokens = add_token_account(&mut program_test, token_mint, reviewer.pubkey(), 1_000);
+    let (mut context, authority) = start(program_test).await;
+    let policy = Policy {
+        token_mint,
+        min_token_balance: 1_000,
+        ..POLICY
+    };
+    set_policy(&mut context, &authority, &policy).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let locked_tokens = Some((token_mint, tokens));
+    let err = submit(&mut context, &mut agent, &reviewer, 0, None, locked_tokens)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentReputationError::InvalidReviewerTokenAccount as u32,
+    );
+}
+
+#[tokio::test]
+async fn locked_tokens_can_be_withdrawn_after_the_lock_period() {
+    let reviewer = Keypair::new();
+    let mut program_test = setup::setup();
+    let token_mint = add_mint(&mut program_test);
+    let token_vault = add_locked_tokens(&mut program_test, token_mint, reviewer.pubkey(), 1_000);
+    let destination = add_token_account(&mut program_test, token_mint, reviewer.pubkey(), 0);
+    let (mut context, authority) = start(program_test).await;
+    let policy = Policy {
+        token_mint,
+        min_token_balance: 1_000,
+        ..POLICY
+    };
+    set_policy(&mut context, &authority, &policy).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let locked_tokens = Some((token_mint, token_vault));
+    submit(&mut context, &mut agent, &reviewer, 0, None, locked_tokens)
+        .await
+        .unwrap();
+
+    let unlock_ix = |withdrawer: Pubkey| {
+        UnlockTokensV1Builder::new()
+            .token_lock(TokenLockV1::find_pda(&reviewer.pubkey(), &token_mint).0)
+            .token_vault(token_vault)
+            .destination(destination)
+            .mint(token_mint)
+            .reviewer(withdrawer)
+            .token_program(SPL_TOKEN_PROGRAM_ID)
+            .amount(1_000)
+            .instruction()
+    };
+
+    // The tokens cannot be moved to back another wallet's feedback.
+    let err = process(&mut context, unlock_ix(reviewer.pubkey()), &reviewer)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentReputationError::TokensLocked as u32);
+
+    // Only the reviewer can withdraw them.
+    let stranger = Keypair::new();
+    let err = process(&mut context, unlock_ix(stranger.pubkey()), &stranger)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentReputationError::InvalidReviewer as u32);
+
+    // Once the lock period is over, the reviewer withdraws them.
+    let feedback = fetch_feedback(&mut context, agent.asset, reviewer.pubkey(), 0).await;
+    context.warp_to_slot(feedback.slot + 1_001).unwrap();
+    let events = process(&mut context, unlock_ix(reviewer.pubkey()), &reviewer)
+        .await
+        .unwrap();
+    assert_eq!(
+        events,
+        vec![MplAgentReputationEvent::TokensUnlockedV1 {
+            reviewer: reviewer.pubkey(),
+            mint: token_mint,
+            amount: 1_000,
+        }]
+    );
+    assert_eq!(token_balance(&mut context, token_vault).await, 0);
+    assert_eq!(token_balance(&mut context, destination).await, 1_000);
+}
+
+#[tokio::test]
+async fn revoking_feedback_removes_its_weight() {
+    let (mut context, authority) = start(setup::setup()).await;
+    set_policy(&mut context, &authority, &POLICY).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    submit(&mut context, &mut agent, &reviewer, 0, None, None)
+        .await
+        .unwrap();
+    submit(&mut context, &mut agent, &reviewer, 1, None, None)
+        .await
+        .unwrap();
+
+    let ix = RevokeFeedbackV1Builder::new()
+        .agent_reputation(AgentReputationV2::find_pda(&agent.asset).0)
+        .asset(agent.asset)
+        .collection(Some(agent.collection))
+        .feedback(FeedbackV1::find_pda(&agent.asset, &reviewer.pubkey(), 1).0)
+        .reputation_config(ReputationConfigV1::find_pda().0)
+        .reviewer(reviewer.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction();
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.weight_sum, 3);
+    assert_eq!(agent_reputation.weighted_score_sum, 150);
+}
+
+#[tokio::test]
+async fn feedback_submitted_before_policy_keeps_its_weight() {
+    let (mut context, authority) = start(setup::setup()).await;
+    let mut agent = setup_agent(&mut context).await;
+
+    let reviewer = Keypair::new();
+    let receipt = issue_receipt(&mut context, &mut agent, reviewer.pubkey()).await;
+    let ix = submit_feedback_ix(&context, &agent, &reviewer, &receipt, 50);
+    process(&mut context, ix, &reviewer).await.unwrap();
+
+    set_policy(&mut context, &authority, &POLICY).await;
+    submit(&mut context, &mut agent, &reviewer, 1, None, None)
+        .await
+        .unwrap();
+
+    // 1 under the default policy, then 1 + 2 * 2 for the 2nd receipt.
+    let agent_reputation = fetch_reputation(&mut context, agent.asset).await;
+    assert_eq!(agent_reputation.weight_sum, 1 + 5);
+    assert_eq!(agent_reputation.weighted_score_sum, 50 * 6);
+}
@@ -5,8 +5,14 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#execution_delegate_count_v1;
+pub(crate) mod r#execution_delegate_migration_v1;
 pub(crate) mod r#execution_delegate_record_v1;
 pub(crate) mod r#executive_profile_v1;
+pub(crate) mod r#receipt_review_v1;
 
+pub use self::r#execution_delegate_count_v1::*;
+pub use self::r#execution_delegate_migration_v1::*;
 pub use self::r#execution_delegate_record_v1::*;
 pub use self::r#executive_profile_v1::*;
+pub use self::r#receipt_review_v1::*;
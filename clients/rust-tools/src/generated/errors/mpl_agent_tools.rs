@@ -80,6 +80,51 @@ pub enum MplAgentToolsError {
     /// 22 (0x16) - Invalid MPL Account Compression Program
     #[error("Invalid MPL Account Compression Program")]
     InvalidCompressionProgram,
+    /// 23 (0x17) - Invalid Execution Delegate Count Derivation
+    #[error("Invalid Execution Delegate Count Derivation")]
+    InvalidExecutionDelegateCountDerivation,
+    /// 24 (0x18) - Invalid Execution Delegate Count
+    #[error("Invalid Execution Delegate Count")]
+    InvalidExecutionDelegateCount,
+    /// 25 (0x19) - Supplied collection is not the canonical reviews collection PDA
+    #[error("Supplied collection is not the canonical reviews collection PDA")]
+    InvalidReviewsCollection,
+    /// 26 (0x1A) - Supplied authority is not the canonical reviews authority PDA
+    #[error("Supplied authority is not the canonical reviews authority PDA")]
+    InvalidReviewsAuthority,
+    /// 27 (0x1B) - Reviews collection already exists
+    #[error("Reviews collection already exists")]
+    ReviewsCollectionAlreadyInitialized,
+    /// 28 (0x1C) - Invalid reviews tree PDA derivation
+    #[error("Invalid reviews tree PDA derivation")]
+    InvalidReviewsTreeDerivation,
+    /// 29 (0x1D) - Review URI must be non-empty and within size limits
+    #[error("Review URI must be non-empty and within size limits")]
+    ReviewUriInvalid,
+    /// 30 (0x1E) - Invalid Receipt Review Derivation
+    #[error("Invalid Receipt Review Derivation")]
+    InvalidReceiptReviewDerivation,
+    /// 31 (0x1F) - Work receipt was already reviewed
+    #[error("Work receipt was already reviewed")]
+    ReceiptAlreadyReviewed,
+    /// 32 (0x20) - The execution delegate count is required for a counted delegate record
+    #[error("The execution delegate count is required for a counted delegate record")]
+    MissingExecutionDelegateCount,
+    /// 33 (0x21) - Execution delegate record is already counted
+    #[error("Execution delegate record is already counted")]
+    ExecutionDelegateAlreadyCounted,
+    /// 34 (0x22) - Invalid execution delegate migration account
+    #[error("Invalid execution delegate migration account")]
+    InvalidExecutionDelegateMigration,
+    /// 35 (0x23) - The execution delegate migration is already completed
+    #[error("The execution delegate migration is already completed")]
+    ExecutionDelegateMigrationAlreadyCompleted,
+    /// 36 (0x24) - Invalid program data account
+    #[error("Invalid program data account")]
+    InvalidProgramData,
+    /// 37 (0x25) - Only the upgrade authority of the program can complete the migration
+    #[error("Only the upgrade authority of the program can complete the migration")]
+    InvalidMigrationAuthority,
 }
 
 impl From<MplAgentToolsError> for ProgramError {
@@ -115,6 +160,21 @@ impl TryFrom<u32> for MplAgentToolsError {
             20 => Ok(MplAgentToolsError::ReceiptsCollectionAlreadyInitialized),
             21 => Ok(MplAgentToolsError::InvalidReceiptsTreeDerivation),
             22 => Ok(MplAgentToolsError::InvalidCompressionProgram),
+            23 => Ok(MplAgentToolsError::InvalidExecutionDelegateCountDerivation),
+            24 => Ok(MplAgentToolsError::InvalidExecutionDelegateCount),
+            25 => Ok(MplAgentToolsError::InvalidReviewsCollection),
+            26 => Ok(MplAgentToolsError::InvalidReviewsAuthority),
+            27 => Ok(MplAgentToolsError::ReviewsCollectionAlreadyInitialized),
+            28 => Ok(MplAgentToolsError::InvalidReviewsTreeDerivation),
+            29 => Ok(MplAgentToolsError::ReviewUriInvalid),
+            30 => Ok(MplAgentToolsError::InvalidReceiptReviewDerivation),
+            31 => Ok(MplAgentToolsError::ReceiptAlreadyReviewed),
+            32 => Ok(MplAgentToolsError::MissingExecutionDelegateCount),
+            33 => Ok(MplAgentToolsError::ExecutionDelegateAlreadyCounted),
+            34 => Ok(MplAgentToolsError::InvalidExecutionDelegateMigration),
+            35 => Ok(MplAgentToolsError::ExecutionDelegateMigrationAlreadyCompleted),
+            36 => Ok(MplAgentToolsError::InvalidProgramData),
+            37 => Ok(MplAgentToolsError::InvalidMigrationAuthority),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -176,6 +236,45 @@ impl ToStr for MplAgentToolsError {
             MplAgentToolsError::InvalidCompressionProgram => {
                 "Invalid MPL Account Compression Program"
             }
+            MplAgentToolsError::InvalidExecutionDelegateCountDerivation => {
+                "Invalid Execution Delegate Count Derivation"
+            }
+            MplAgentToolsError::InvalidExecutionDelegateCount => "Invalid Execution Delegate Count",
+            MplAgentToolsError::InvalidReviewsCollection => {
+                "Supplied collection is not the canonical reviews collection PDA"
+            }
+            MplAgentToolsError::InvalidReviewsAuthority => {
+                "Supplied authority is not the canonical reviews authority PDA"
+            }
+            MplAgentToolsError::ReviewsCollectionAlreadyInitialized => {
+                "Reviews collection already exists"
+            }
+            MplAgentToolsError::InvalidReviewsTreeDerivation => {
+                "Invalid reviews tree PDA derivation"
+            }
+            MplAgentToolsError::ReviewUriInvalid => {
+                "Review URI must be non-empty and within size limits"
+            }
+            MplAgentToolsError::InvalidReceiptReviewDerivation => {
+                "Invalid Receipt Review Derivation"
+            }
+            MplAgentToolsError::ReceiptAlreadyReviewed => "Work receipt was already reviewed",
+            MplAgentToolsError::MissingExecutionDelegateCount => {
+                "The execution delegate count is required for a counted delegate record"
+            }
+            MplAgentToolsError::ExecutionDelegateAlreadyCounted => {
+                "Execution delegate record is already counted"
+            }
+            MplAgentToolsError::InvalidExecutionDelegateMigration => {
+                "Invalid execution delegate migration account"
+            }
+            MplAgentToolsError::ExecutionDelegateMigrationAlreadyCompleted => {
+                "The execution delegate migration is already completed"
+            }
+            MplAgentToolsError::InvalidProgramData => "Invalid program data account",
+            MplAgentToolsError::InvalidMigrationAuthority => {
+                "Only the upgrade authority of the program can complete the migration"
+            }
         }
     }
 }
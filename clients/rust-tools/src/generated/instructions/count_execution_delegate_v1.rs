@@ -0,0 +1,466 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct CountExecutionDelegateV1 {
+    /// The uncounted execution delegate record
+    pub execution_delegate_record: solana_program::pubkey::Pubkey,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: solana_program::pubkey::Pubkey,
+    /// The agent asset
+    pub agent_asset: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl CountExecutionDelegateV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.execution_delegate_record,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.execution_delegate_count,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.agent_asset,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(CountExecutionDelegateV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_TOOLS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct CountExecutionDelegateV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl CountExecutionDelegateV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 10,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+/// Instruction builder for `CountExecutionDelegateV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` execution_delegate_record
+///   1. `[writable]` execution_delegate_count
+///   2. `[]` agent_asset
+///   3. `[writable, signer]` payer
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct CountExecutionDelegateV1Builder {
+    execution_delegate_record: Option<solana_program::pubkey::Pubkey>,
+    execution_delegate_count: Option<solana_program::pubkey::Pubkey>,
+    agent_asset: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl CountExecutionDelegateV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The uncounted execution delegate record
+    #[inline(always)]
+    pub fn execution_delegate_record(
+        &mut self,
+        execution_delegate_record: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.execution_delegate_record = Some(execution_delegate_record);
+        self
+    }
+    /// The execution delegate count for the agent asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
+    /// The agent asset
+    #[inline(always)]
+    pub fn agent_asset(&mut self, agent_asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.agent_asset = Some(agent_asset);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = CountExecutionDelegateV1 {
+            execution_delegate_record: self
+                .execution_delegate_record
+                .expect("execution_delegate_record is not set"),
+            execution_delegate_count: self
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
+            agent_asset: self.agent_asset.expect("agent_asset is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `count_execution_delegate_v1` CPI accounts.
+pub struct CountExecutionDelegateV1CpiAccounts<'a, 'b> {
+    /// The uncounted execution delegate record
+    pub execution_delegate_record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent asset
+    pub agent_asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `count_execution_delegate_v1` CPI instruction.
+pub struct CountExecutionDelegateV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The uncounted execution delegate record
+    pub execution_delegate_record: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent asset
+    pub agent_asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> CountExecutionDelegateV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: CountExecutionDelegateV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            execution_delegate_record: accounts.execution_delegate_record,
+            execution_delegate_count: accounts.execution_delegate_count,
+            agent_asset: accounts.agent_asset,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.execution_delegate_record.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.execution_delegate_count.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.agent_asset.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(CountExecutionDelegateV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_TOOLS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.execution_delegate_record.clone());
+        account_infos.push(self.execution_delegate_count.clone());
+        account_infos.push(self.agent_asset.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `CountExecutionDelegateV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` execution_delegate_record
+///   1. `[writable]` execution_delegate_count
+///   2. `[]` agent_asset
+///   3. `[writable, signer]` payer
+///   4. `[]` system_program
+pub struct CountExecutionDelegateV1CpiBuilder<'a, 'b> {
+    instruction: Box<CountExecutionDelegateV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> CountExecutionDelegateV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(CountExecutionDelegateV1CpiBuilderInstruction {
+            __program: program,
+            execution_delegate_record: None,
+            execution_delegate_count: None,
+            agent_asset: None,
+            payer: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The uncounted execution delegate record
+    #[inline(always)]
+    pub fn execution_delegate_record(
+        &mut self,
+        execution_delegate_record: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.execution_delegate_record = Some(execution_delegate_record);
+        self
+    }
+    /// The execution delegate count for the agent asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
+    /// The agent asset
+    #[inline(always)]
+    pub fn agent_asset(
+        &mut self,
+        agent_asset: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_asset = Some(agent_asset);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = CountExecutionDelegateV1Cpi {
+            __program: self.instruction.__program,
+
+            execution_delegate_record: self
+                .instruction
+                .execution_delegate_record
+                .expect("execution_delegate_record is not set"),
+
+            execution_delegate_count: self
+                .instruction
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
+
+            agent_asset: self
+                .instruction
+                .agent_asset
+                .expect("agent_asset is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct CountExecutionDelegateV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    execution_delegate_record: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    execution_delegate_count: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    agent_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
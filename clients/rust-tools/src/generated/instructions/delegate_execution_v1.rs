@@ -26,6 +26,8 @@ pub struct DelegateExecutionV1 {
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// The system program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: solana_program::pubkey::Pubkey,
 }
 
 impl DelegateExecutionV1 {
@@ -37,7 +39,7 @@ impl DelegateExecutionV1 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             self.executive_profile,
             false,
@@ -71,6 +73,10 @@ impl DelegateExecutionV1 {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.execution_delegate_count,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&(DelegateExecutionV1InstructionData::new())).unwrap();
 
@@ -109,6 +115,7 @@ impl DelegateExecutionV1InstructionData {
 ///   4. `[writable, signer]` payer
 ///   5. `[signer, optional]` authority
 ///   6. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   7. `[writable]` execution_delegate_count
 #[derive(Default)]
 pub struct DelegateExecutionV1Builder {
     executive_profile: Option<solana_program::pubkey::Pubkey>,
@@ -118,6 +125,7 @@ pub struct DelegateExecutionV1Builder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    execution_delegate_count: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -175,6 +183,15 @@ impl DelegateExecutionV1Builder {
         self.system_program = Some(system_program);
         self
     }
+    /// The execution delegate count for the agent asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -209,6 +226,9 @@ impl DelegateExecutionV1Builder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            execution_delegate_count: self
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -231,6 +251,8 @@ pub struct DelegateExecutionV1CpiAccounts<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `delegate_execution_v1` CPI instruction.
@@ -251,6 +273,8 @@ pub struct DelegateExecutionV1Cpi<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> DelegateExecutionV1Cpi<'a, 'b> {
@@ -267,6 +291,7 @@ impl<'a, 'b> DelegateExecutionV1Cpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            execution_delegate_count: accounts.execution_delegate_count,
         }
     }
     #[inline(always)]
@@ -302,7 +327,7 @@ impl<'a, 'b> DelegateExecutionV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new_readonly(
             *self.executive_profile.key,
             false,
@@ -338,6 +363,10 @@ impl<'a, 'b> DelegateExecutionV1Cpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.execution_delegate_count.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -352,7 +381,7 @@ impl<'a, 'b> DelegateExecutionV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.executive_profile.clone());
         account_infos.push(self.agent_asset.clone());
@@ -363,6 +392,7 @@ impl<'a, 'b> DelegateExecutionV1Cpi<'a, 'b> {
             account_infos.push(authority.clone());
         }
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.execution_delegate_count.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -386,6 +416,7 @@ impl<'a, 'b> DelegateExecutionV1Cpi<'a, 'b> {
 ///   4. `[writable, signer]` payer
 ///   5. `[signer, optional]` authority
 ///   6. `[]` system_program
+///   7. `[writable]` execution_delegate_count
 pub struct DelegateExecutionV1CpiBuilder<'a, 'b> {
     instruction: Box<DelegateExecutionV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -401,6 +432,7 @@ impl<'a, 'b> DelegateExecutionV1CpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            execution_delegate_count: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -466,6 +498,15 @@ impl<'a, 'b> DelegateExecutionV1CpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// The execution delegate count for the agent asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -538,6 +579,11 @@ impl<'a, 'b> DelegateExecutionV1CpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            execution_delegate_count: self
+                .instruction
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -555,6 +601,7 @@ struct DelegateExecutionV1CpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    execution_delegate_count: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
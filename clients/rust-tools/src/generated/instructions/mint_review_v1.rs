@@ -0,0 +1,1125 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct MintReviewV1 {
+    /// Pays for the review record and the cNFT mint
+    pub payer: solana_program::pubkey::Pubkey,
+    /// Owner of the work receipt and recipient of the review
+    pub reviewer: solana_program::pubkey::Pubkey,
+    /// The Core asset representing the reviewed agent
+    pub agent_asset: solana_program::pubkey::Pubkey,
+    /// Receipt review record PDA
+    pub receipt_review: solana_program::pubkey::Pubkey,
+    /// Receipts merkle tree holding the work receipt
+    pub receipts_merkle_tree: solana_program::pubkey::Pubkey,
+    /// Reviews authority PDA at ["reviews_authority"] — signs CPI via invoke_signed
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Bubblegum tree config PDA for the reviews tree
+    pub tree_config: solana_program::pubkey::Pubkey,
+    /// Reviews merkle tree at PDA ["reviews_tree", tree_index_le]
+    pub merkle_tree: solana_program::pubkey::Pubkey,
+    /// Canonical reviews collection PDA at ["reviews_collection"]
+    pub core_collection: solana_program::pubkey::Pubkey,
+    /// Bubblegum's mpl-core CPI signer PDA
+    pub mpl_core_cpi_signer: solana_program::pubkey::Pubkey,
+    /// MPL Noop / log wrapper program
+    pub log_wrapper: solana_program::pubkey::Pubkey,
+    /// MPL Account Compression program
+    pub compression_program: solana_program::pubkey::Pubkey,
+    /// The MPL Core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The MPL Bubblegum program
+    pub bubblegum_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl MintReviewV1 {
+    pub fn instruction(
+        &self,
+        args: MintReviewV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: MintReviewV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.reviewer,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.agent_asset,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.receipt_review,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.receipts_merkle_tree,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tree_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.merkle_tree,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.core_collection,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_cpi_signer,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.log_wrapper,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.compression_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.bubblegum_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(MintReviewV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_TOOLS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct MintReviewV1InstructionData {
+    discriminator: u8,
+    pad: [u8; 2],
+}
+
+impl MintReviewV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 9,
+            pad: [0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct MintReviewV1InstructionArgs {
+    pub receipt_flags: u8,
+    pub receipt_index: u32,
+    pub receipts_tree_index: u64,
+    pub receipt_nonce: u64,
+    pub reviews_tree_index: u64,
+    pub root: [u8; 32],
+    pub receipt_data_hash: [u8; 32],
+    pub receipt_asset_data_hash: [u8; 32],
+    pub review_uri: String,
+}
+
+/// Instruction builder for `MintReviewV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[signer]` reviewer
+///   2. `[]` agent_asset
+///   3. `[writable]` receipt_review
+///   4. `[]` receipts_merkle_tree
+///   5. `[]` authority
+///   6. `[writable]` tree_config
+///   7. `[writable]` merkle_tree
+///   8. `[writable]` core_collection
+///   9. `[]` mpl_core_cpi_signer
+///   10. `[optional]` log_wrapper (default to `mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3`)
+///   11. `[optional]` compression_program (default to `mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW`)
+///   12. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   13. `[optional]` bubblegum_program (default to `BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY`)
+///   14. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct MintReviewV1Builder {
+    payer: Option<solana_program::pubkey::Pubkey>,
+    reviewer: Option<solana_program::pubkey::Pubkey>,
+    agent_asset: Option<solana_program::pubkey::Pubkey>,
+    receipt_review: Option<solana_program::pubkey::Pubkey>,
+    receipts_merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    tree_config: Option<solana_program::pubkey::Pubkey>,
+    merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    core_collection: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_cpi_signer: Option<solana_program::pubkey::Pubkey>,
+    log_wrapper: Option<solana_program::pubkey::Pubkey>,
+    compression_program: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    bubblegum_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    receipt_flags: Option<u8>,
+    receipt_index: Option<u32>,
+    receipts_tree_index: Option<u64>,
+    receipt_nonce: Option<u64>,
+    reviews_tree_index: Option<u64>,
+    root: Option<[u8; 32]>,
+    receipt_data_hash: Option<[u8; 32]>,
+    receipt_asset_data_hash: Option<[u8; 32]>,
+    review_uri: Option<String>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl MintReviewV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Pays for the review record and the cNFT mint
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Owner of the work receipt and recipient of the review
+    #[inline(always)]
+    pub fn reviewer(&mut self, reviewer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.reviewer = Some(reviewer);
+        self
+    }
+    /// The Core asset representing the reviewed agent
+    #[inline(always)]
+    pub fn agent_asset(&mut self, agent_asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.agent_asset = Some(agent_asset);
+        self
+    }
+    /// Receipt review record PDA
+    #[inline(always)]
+    pub fn receipt_review(&mut self, receipt_review: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.receipt_review = Some(receipt_review);
+        self
+    }
+    /// Receipts merkle tree holding the work receipt
+    #[inline(always)]
+    pub fn receipts_merkle_tree(
+        &mut self,
+        receipts_merkle_tree: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.receipts_merkle_tree = Some(receipts_merkle_tree);
+        self
+    }
+    /// Reviews authority PDA at ["reviews_authority"] — signs CPI via invoke_signed
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Bubblegum tree config PDA for the reviews tree
+    #[inline(always)]
+    pub fn tree_config(&mut self, tree_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tree_config = Some(tree_config);
+        self
+    }
+    /// Reviews merkle tree at PDA ["reviews_tree", tree_index_le]
+    #[inline(always)]
+    pub fn merkle_tree(&mut self, merkle_tree: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// Canonical reviews collection PDA at ["reviews_collection"]
+    #[inline(always)]
+    pub fn core_collection(
+        &mut self,
+        core_collection: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.core_collection = Some(core_collection);
+        self
+    }
+    /// Bubblegum's mpl-core CPI signer PDA
+    #[inline(always)]
+    pub fn mpl_core_cpi_signer(
+        &mut self,
+        mpl_core_cpi_signer: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_cpi_signer = Some(mpl_core_cpi_signer);
+        self
+    }
+    /// `[optional account, default to 'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3']`
+    /// MPL Noop / log wrapper program
+    #[inline(always)]
+    pub fn log_wrapper(&mut self, log_wrapper: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.log_wrapper = Some(log_wrapper);
+        self
+    }
+    /// `[optional account, default to 'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW']`
+    /// MPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.compression_program = Some(compression_program);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to 'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY']`
+    /// The MPL Bubblegum program
+    #[inline(always)]
+    pub fn bubblegum_program(
+        &mut self,
+        bubblegum_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.bubblegum_program = Some(bubblegum_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_flags(&mut self, receipt_flags: u8) -> &mut Self {
+        self.receipt_flags = Some(receipt_flags);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_index(&mut self, receipt_index: u32) -> &mut Self {
+        self.receipt_index = Some(receipt_index);
+        self
+    }
+    #[inline(always)]
+    pub fn receipts_tree_index(&mut self, receipts_tree_index: u64) -> &mut Self {
+        self.receipts_tree_index = Some(receipts_tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_nonce(&mut self, receipt_nonce: u64) -> &mut Self {
+        self.receipt_nonce = Some(receipt_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn reviews_tree_index(&mut self, reviews_tree_index: u64) -> &mut Self {
+        self.reviews_tree_index = Some(reviews_tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_data_hash(&mut self, receipt_data_hash: [u8; 32]) -> &mut Self {
+        self.receipt_data_hash = Some(receipt_data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_asset_data_hash(&mut self, receipt_asset_data_hash: [u8; 32]) -> &mut Self {
+        self.receipt_asset_data_hash = Some(receipt_asset_data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn review_uri(&mut self, review_uri: String) -> &mut Self {
+        self.review_uri = Some(review_uri);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = MintReviewV1 {
+            payer: self.payer.expect("payer is not set"),
+            reviewer: self.reviewer.expect("reviewer is not set"),
+            agent_asset: self.agent_asset.expect("agent_asset is not set"),
+            receipt_review: self.receipt_review.expect("receipt_review is not set"),
+            receipts_merkle_tree: self
+                .receipts_merkle_tree
+                .expect("receipts_merkle_tree is not set"),
+            authority: self.authority.expect("authority is not set"),
+            tree_config: self.tree_config.expect("tree_config is not set"),
+            merkle_tree: self.merkle_tree.expect("merkle_tree is not set"),
+            core_collection: self.core_collection.expect("core_collection is not set"),
+            mpl_core_cpi_signer: self
+                .mpl_core_cpi_signer
+                .expect("mpl_core_cpi_signer is not set"),
+            log_wrapper: self.log_wrapper.unwrap_or(solana_program::pubkey!(
+                "mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3"
+            )),
+            compression_program: self.compression_program.unwrap_or(solana_program::pubkey!(
+                "mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW"
+            )),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            bubblegum_program: self.bubblegum_program.unwrap_or(solana_program::pubkey!(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = MintReviewV1InstructionArgs {
+            receipt_flags: self
+                .receipt_flags
+                .clone()
+                .expect("receipt_flags is not set"),
+            receipt_index: self
+                .receipt_index
+                .clone()
+                .expect("receipt_index is not set"),
+            receipts_tree_index: self
+                .receipts_tree_index
+                .clone()
+                .expect("receipts_tree_index is not set"),
+            receipt_nonce: self
+                .receipt_nonce
+                .clone()
+                .expect("receipt_nonce is not set"),
+            reviews_tree_index: self
+                .reviews_tree_index
+                .clone()
+                .expect("reviews_tree_index is not set"),
+            root: self.root.clone().expect("root is not set"),
+            receipt_data_hash: self
+                .receipt_data_hash
+                .clone()
+                .expect("receipt_data_hash is not set"),
+            receipt_asset_data_hash: self
+                .receipt_asset_data_hash
+                .clone()
+                .expect("receipt_asset_data_hash is not set"),
+            review_uri: self.review_uri.clone().expect("review_uri is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `mint_review_v1` CPI accounts.
+pub struct MintReviewV1CpiAccounts<'a, 'b> {
+    /// Pays for the review record and the cNFT mint
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner of the work receipt and recipient of the review
+    pub reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Core asset representing the reviewed agent
+    pub agent_asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt review record PDA
+    pub receipt_review: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipts merkle tree holding the work receipt
+    pub receipts_merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews authority PDA at ["reviews_authority"] — signs CPI via invoke_signed
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bubblegum tree config PDA for the reviews tree
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews merkle tree at PDA ["reviews_tree", tree_index_le]
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Canonical reviews collection PDA at ["reviews_collection"]
+    pub core_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bubblegum's mpl-core CPI signer PDA
+    pub mpl_core_cpi_signer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Noop / log wrapper program
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Bubblegum program
+    pub bubblegum_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `mint_review_v1` CPI instruction.
+pub struct MintReviewV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Pays for the review record and the cNFT mint
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Owner of the work receipt and recipient of the review
+    pub reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The Core asset representing the reviewed agent
+    pub agent_asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipt review record PDA
+    pub receipt_review: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Receipts merkle tree holding the work receipt
+    pub receipts_merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews authority PDA at ["reviews_authority"] — signs CPI via invoke_signed
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bubblegum tree config PDA for the reviews tree
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews merkle tree at PDA ["reviews_tree", tree_index_le]
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Canonical reviews collection PDA at ["reviews_collection"]
+    pub core_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bubblegum's mpl-core CPI signer PDA
+    pub mpl_core_cpi_signer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Noop / log wrapper program
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Bubblegum program
+    pub bubblegum_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: MintReviewV1InstructionArgs,
+}
+
+impl<'a, 'b> MintReviewV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: MintReviewV1CpiAccounts<'a, 'b>,
+        args: MintReviewV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            payer: accounts.payer,
+            reviewer: accounts.reviewer,
+            agent_asset: accounts.agent_asset,
+            receipt_review: accounts.receipt_review,
+            receipts_merkle_tree: accounts.receipts_merkle_tree,
+            authority: accounts.authority,
+            tree_config: accounts.tree_config,
+            merkle_tree: accounts.merkle_tree,
+            core_collection: accounts.core_collection,
+            mpl_core_cpi_signer: accounts.mpl_core_cpi_signer,
+            log_wrapper: accounts.log_wrapper,
+            compression_program: accounts.compression_program,
+            mpl_core_program: accounts.mpl_core_program,
+            bubblegum_program: accounts.bubblegum_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(15 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.reviewer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.agent_asset.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.receipt_review.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.receipts_merkle_tree.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tree_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.merkle_tree.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.core_collection.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_cpi_signer.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.log_wrapper.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.compression_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.bubblegum_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(MintReviewV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_TOOLS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(15 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.reviewer.clone());
+        account_infos.push(self.agent_asset.clone());
+        account_infos.push(self.receipt_review.clone());
+        account_infos.push(self.receipts_merkle_tree.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.tree_config.clone());
+        account_infos.push(self.merkle_tree.clone());
+        account_infos.push(self.core_collection.clone());
+        account_infos.push(self.mpl_core_cpi_signer.clone());
+        account_infos.push(self.log_wrapper.clone());
+        account_infos.push(self.compression_program.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.bubblegum_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `MintReviewV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[signer]` reviewer
+///   2. `[]` agent_asset
+///   3. `[writable]` receipt_review
+///   4. `[]` receipts_merkle_tree
+///   5. `[]` authority
+///   6. `[writable]` tree_config
+///   7. `[writable]` merkle_tree
+///   8. `[writable]` core_collection
+///   9. `[]` mpl_core_cpi_signer
+///   10. `[]` log_wrapper
+///   11. `[]` compression_program
+///   12. `[]` mpl_core_program
+///   13. `[]` bubblegum_program
+///   14. `[]` system_program
+pub struct MintReviewV1CpiBuilder<'a, 'b> {
+    instruction: Box<MintReviewV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> MintReviewV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(MintReviewV1CpiBuilderInstruction {
+            __program: program,
+            payer: None,
+            reviewer: None,
+            agent_asset: None,
+            receipt_review: None,
+            receipts_merkle_tree: None,
+            authority: None,
+            tree_config: None,
+            merkle_tree: None,
+            core_collection: None,
+            mpl_core_cpi_signer: None,
+            log_wrapper: None,
+            compression_program: None,
+            mpl_core_program: None,
+            bubblegum_program: None,
+            system_program: None,
+            receipt_flags: None,
+            receipt_index: None,
+            receipts_tree_index: None,
+            receipt_nonce: None,
+            reviews_tree_index: None,
+            root: None,
+            receipt_data_hash: None,
+            receipt_asset_data_hash: None,
+            review_uri: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Pays for the review record and the cNFT mint
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Owner of the work receipt and recipient of the review
+    #[inline(always)]
+    pub fn reviewer(
+        &mut self,
+        reviewer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.reviewer = Some(reviewer);
+        self
+    }
+    /// The Core asset representing the reviewed agent
+    #[inline(always)]
+    pub fn agent_asset(
+        &mut self,
+        agent_asset: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_asset = Some(agent_asset);
+        self
+    }
+    /// Receipt review record PDA
+    #[inline(always)]
+    pub fn receipt_review(
+        &mut self,
+        receipt_review: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipt_review = Some(receipt_review);
+        self
+    }
+    /// Receipts merkle tree holding the work receipt
+    #[inline(always)]
+    pub fn receipts_merkle_tree(
+        &mut self,
+        receipts_merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.receipts_merkle_tree = Some(receipts_merkle_tree);
+        self
+    }
+    /// Reviews authority PDA at ["reviews_authority"] — signs CPI via invoke_signed
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Bubblegum tree config PDA for the reviews tree
+    #[inline(always)]
+    pub fn tree_config(
+        &mut self,
+        tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tree_config = Some(tree_config);
+        self
+    }
+    /// Reviews merkle tree at PDA ["reviews_tree", tree_index_le]
+    #[inline(always)]
+    pub fn merkle_tree(
+        &mut self,
+        merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// Canonical reviews collection PDA at ["reviews_collection"]
+    #[inline(always)]
+    pub fn core_collection(
+        &mut self,
+        core_collection: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.core_collection = Some(core_collection);
+        self
+    }
+    /// Bubblegum's mpl-core CPI signer PDA
+    #[inline(always)]
+    pub fn mpl_core_cpi_signer(
+        &mut self,
+        mpl_core_cpi_signer: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_cpi_signer = Some(mpl_core_cpi_signer);
+        self
+    }
+    /// MPL Noop / log wrapper program
+    #[inline(always)]
+    pub fn log_wrapper(
+        &mut self,
+        log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.log_wrapper = Some(log_wrapper);
+        self
+    }
+    /// MPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.compression_program = Some(compression_program);
+        self
+    }
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The MPL Bubblegum program
+    #[inline(always)]
+    pub fn bubblegum_program(
+        &mut self,
+        bubblegum_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bubblegum_program = Some(bubblegum_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_flags(&mut self, receipt_flags: u8) -> &mut Self {
+        self.instruction.receipt_flags = Some(receipt_flags);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_index(&mut self, receipt_index: u32) -> &mut Self {
+        self.instruction.receipt_index = Some(receipt_index);
+        self
+    }
+    #[inline(always)]
+    pub fn receipts_tree_index(&mut self, receipts_tree_index: u64) -> &mut Self {
+        self.instruction.receipts_tree_index = Some(receipts_tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_nonce(&mut self, receipt_nonce: u64) -> &mut Self {
+        self.instruction.receipt_nonce = Some(receipt_nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn reviews_tree_index(&mut self, reviews_tree_index: u64) -> &mut Self {
+        self.instruction.reviews_tree_index = Some(reviews_tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn root(&mut self, root: [u8; 32]) -> &mut Self {
+        self.instruction.root = Some(root);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_data_hash(&mut self, receipt_data_hash: [u8; 32]) -> &mut Self {
+        self.instruction.receipt_data_hash = Some(receipt_data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn receipt_asset_data_hash(&mut self, receipt_asset_data_hash: [u8; 32]) -> &mut Self {
+        self.instruction.receipt_asset_data_hash = Some(receipt_asset_data_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn review_uri(&mut self, review_uri: String) -> &mut Self {
+        self.instruction.review_uri = Some(review_uri);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = MintReviewV1InstructionArgs {
+            receipt_flags: self
+                .instruction
+                .receipt_flags
+                .clone()
+                .expect("receipt_flags is not set"),
+            receipt_index: self
+                .instruction
+                .receipt_index
+                .clone()
+                .expect("receipt_index is not set"),
+            receipts_tree_index: self
+                .instruction
+                .receipts_tree_index
+                .clone()
+                .expect("receipts_tree_index is not set"),
+            receipt_nonce: self
+                .instruction
+                .receipt_nonce
+                .clone()
+                .expect("receipt_nonce is not set"),
+            reviews_tree_index: self
+                .instruction
+                .reviews_tree_index
+                .clone()
+                .expect("reviews_tree_index is not set"),
+            root: self.instruction.root.clone().expect("root is not set"),
+            receipt_data_hash: self
+                .instruction
+                .receipt_data_hash
+                .clone()
+                .expect("receipt_data_hash is not set"),
+            receipt_asset_data_hash: self
+                .instruction
+                .receipt_asset_data_hash
+                .clone()
+                .expect("receipt_asset_data_hash is not set"),
+            review_uri: self
+                .instruction
+                .review_uri
+                .clone()
+                .expect("review_uri is not set"),
+        };
+        let instruction = MintReviewV1Cpi {
+            __program: self.instruction.__program,
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            reviewer: self.instruction.reviewer.expect("reviewer is not set"),
+
+            agent_asset: self
+                .instruction
+                .agent_asset
+                .expect("agent_asset is not set"),
+
+            receipt_review: self
+                .instruction
+                .receipt_review
+                .expect("receipt_review is not set"),
+
+            receipts_merkle_tree: self
+                .instruction
+                .receipts_merkle_tree
+                .expect("receipts_merkle_tree is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            tree_config: self
+                .instruction
+                .tree_config
+                .expect("tree_config is not set"),
+
+            merkle_tree: self
+                .instruction
+                .merkle_tree
+                .expect("merkle_tree is not set"),
+
+            core_collection: self
+                .instruction
+                .core_collection
+                .expect("core_collection is not set"),
+
+            mpl_core_cpi_signer: self
+                .instruction
+                .mpl_core_cpi_signer
+                .expect("mpl_core_cpi_signer is not set"),
+
+            log_wrapper: self
+                .instruction
+                .log_wrapper
+                .expect("log_wrapper is not set"),
+
+            compression_program: self
+                .instruction
+                .compression_program
+                .expect("compression_program is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            bubblegum_program: self
+                .instruction
+                .bubblegum_program
+                .expect("bubblegum_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct MintReviewV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    reviewer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    agent_asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_review: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipts_merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tree_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    core_collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_cpi_signer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    compression_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bubblegum_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    receipt_flags: Option<u8>,
+    receipt_index: Option<u32>,
+    receipts_tree_index: Option<u64>,
+    receipt_nonce: Option<u64>,
+    reviews_tree_index: Option<u64>,
+    root: Option<[u8; 32]>,
+    receipt_data_hash: Option<[u8; 32]>,
+    receipt_asset_data_hash: Option<[u8; 32]>,
+    review_uri: Option<String>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -6,17 +6,27 @@
 //!
 
 pub(crate) mod r#close_work_receipt_v1;
+pub(crate) mod r#complete_execution_delegate_migration_v1;
+pub(crate) mod r#count_execution_delegate_v1;
 pub(crate) mod r#create_receipts_collection_v1;
+pub(crate) mod r#create_reviews_collection_v1;
 pub(crate) mod r#delegate_execution_v1;
+pub(crate) mod r#mint_review_v1;
 pub(crate) mod r#mint_work_receipt_v1;
 pub(crate) mod r#register_executive_v1;
 pub(crate) mod r#register_receipts_tree_v1;
+pub(crate) mod r#register_reviews_tree_v1;
 pub(crate) mod r#revoke_execution_v1;
 
 pub use self::r#close_work_receipt_v1::*;
+pub use self::r#complete_execution_delegate_migration_v1::*;
+pub use self::r#count_execution_delegate_v1::*;
 pub use self::r#create_receipts_collection_v1::*;
+pub use self::r#create_reviews_collection_v1::*;
 pub use self::r#delegate_execution_v1::*;
+pub use self::r#mint_review_v1::*;
 pub use self::r#mint_work_receipt_v1::*;
 pub use self::r#register_executive_v1::*;
 pub use self::r#register_receipts_tree_v1::*;
+pub use self::r#register_reviews_tree_v1::*;
 pub use self::r#revoke_execution_v1::*;
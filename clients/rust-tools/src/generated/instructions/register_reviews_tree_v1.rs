@@ -0,0 +1,703 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct RegisterReviewsTreeV1 {
+    /// Funds the tree rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// Reviews authority PDA at ["reviews_authority"] — set as tree_creator
+    pub authority: solana_program::pubkey::Pubkey,
+    /// Reviews merkle tree PDA at ["reviews_tree", tree_index_le]
+    pub merkle_tree: solana_program::pubkey::Pubkey,
+    /// Bubblegum tree config PDA (derived from merkle_tree)
+    pub tree_config: solana_program::pubkey::Pubkey,
+    /// MPL Noop / log wrapper program
+    pub log_wrapper: solana_program::pubkey::Pubkey,
+    /// MPL Account Compression program
+    pub compression_program: solana_program::pubkey::Pubkey,
+    /// The MPL Bubblegum program
+    pub bubblegum_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl RegisterReviewsTreeV1 {
+    pub fn instruction(
+        &self,
+        args: RegisterReviewsTreeV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: RegisterReviewsTreeV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.merkle_tree,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.tree_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.log_wrapper,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.compression_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.bubblegum_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(RegisterReviewsTreeV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_TOOLS_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RegisterReviewsTreeV1InstructionData {
+    discriminator: u8,
+    pad: [u8; 7],
+    pad2: [u8; 4],
+}
+
+impl RegisterReviewsTreeV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 8,
+            pad: [0, 0, 0, 0, 0, 0, 0],
+            pad2: [0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegisterReviewsTreeV1InstructionArgs {
+    pub tree_index: u64,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub canopy_depth: u32,
+}
+
+/// Instruction builder for `RegisterReviewsTreeV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[]` authority
+///   2. `[writable]` merkle_tree
+///   3. `[writable]` tree_config
+///   4. `[optional]` log_wrapper (default to `mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3`)
+///   5. `[optional]` compression_program (default to `mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW`)
+///   6. `[optional]` bubblegum_program (default to `BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY`)
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct RegisterReviewsTreeV1Builder {
+    payer: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    merkle_tree: Option<solana_program::pubkey::Pubkey>,
+    tree_config: Option<solana_program::pubkey::Pubkey>,
+    log_wrapper: Option<solana_program::pubkey::Pubkey>,
+    compression_program: Option<solana_program::pubkey::Pubkey>,
+    bubblegum_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    tree_index: Option<u64>,
+    max_depth: Option<u32>,
+    max_buffer_size: Option<u32>,
+    canopy_depth: Option<u32>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RegisterReviewsTreeV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// Funds the tree rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// Reviews authority PDA at ["reviews_authority"] — set as tree_creator
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// Reviews merkle tree PDA at ["reviews_tree", tree_index_le]
+    #[inline(always)]
+    pub fn merkle_tree(&mut self, merkle_tree: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// Bubblegum tree config PDA (derived from merkle_tree)
+    #[inline(always)]
+    pub fn tree_config(&mut self, tree_config: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.tree_config = Some(tree_config);
+        self
+    }
+    /// `[optional account, default to 'mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3']`
+    /// MPL Noop / log wrapper program
+    #[inline(always)]
+    pub fn log_wrapper(&mut self, log_wrapper: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.log_wrapper = Some(log_wrapper);
+        self
+    }
+    /// `[optional account, default to 'mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW']`
+    /// MPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.compression_program = Some(compression_program);
+        self
+    }
+    /// `[optional account, default to 'BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY']`
+    /// The MPL Bubblegum program
+    #[inline(always)]
+    pub fn bubblegum_program(
+        &mut self,
+        bubblegum_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.bubblegum_program = Some(bubblegum_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn tree_index(&mut self, tree_index: u64) -> &mut Self {
+        self.tree_index = Some(tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn max_depth(&mut self, max_depth: u32) -> &mut Self {
+        self.max_depth = Some(max_depth);
+        self
+    }
+    #[inline(always)]
+    pub fn max_buffer_size(&mut self, max_buffer_size: u32) -> &mut Self {
+        self.max_buffer_size = Some(max_buffer_size);
+        self
+    }
+    #[inline(always)]
+    pub fn canopy_depth(&mut self, canopy_depth: u32) -> &mut Self {
+        self.canopy_depth = Some(canopy_depth);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RegisterReviewsTreeV1 {
+            payer: self.payer.expect("payer is not set"),
+            authority: self.authority.expect("authority is not set"),
+            merkle_tree: self.merkle_tree.expect("merkle_tree is not set"),
+            tree_config: self.tree_config.expect("tree_config is not set"),
+            log_wrapper: self.log_wrapper.unwrap_or(solana_program::pubkey!(
+                "mnoopTCrg4p8ry25e4bcWA9XZjbNjMTfgYVGGEdRsf3"
+            )),
+            compression_program: self.compression_program.unwrap_or(solana_program::pubkey!(
+                "mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW"
+            )),
+            bubblegum_program: self.bubblegum_program.unwrap_or(solana_program::pubkey!(
+                "BGUMAp9Gq7iTEuizy4pqaxsTyUCBK68MDfK752saRPUY"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = RegisterReviewsTreeV1InstructionArgs {
+            tree_index: self.tree_index.clone().expect("tree_index is not set"),
+            max_depth: self.max_depth.clone().expect("max_depth is not set"),
+            max_buffer_size: self
+                .max_buffer_size
+                .clone()
+                .expect("max_buffer_size is not set"),
+            canopy_depth: self.canopy_depth.clone().expect("canopy_depth is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `register_reviews_tree_v1` CPI accounts.
+pub struct RegisterReviewsTreeV1CpiAccounts<'a, 'b> {
+    /// Funds the tree rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews authority PDA at ["reviews_authority"] — set as tree_creator
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews merkle tree PDA at ["reviews_tree", tree_index_le]
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bubblegum tree config PDA (derived from merkle_tree)
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Noop / log wrapper program
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Bubblegum program
+    pub bubblegum_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `register_reviews_tree_v1` CPI instruction.
+pub struct RegisterReviewsTreeV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Funds the tree rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews authority PDA at ["reviews_authority"] — set as tree_creator
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Reviews merkle tree PDA at ["reviews_tree", tree_index_le]
+    pub merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    /// Bubblegum tree config PDA (derived from merkle_tree)
+    pub tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Noop / log wrapper program
+    pub log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    /// MPL Account Compression program
+    pub compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Bubblegum program
+    pub bubblegum_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: RegisterReviewsTreeV1InstructionArgs,
+}
+
+impl<'a, 'b> RegisterReviewsTreeV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RegisterReviewsTreeV1CpiAccounts<'a, 'b>,
+        args: RegisterReviewsTreeV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            payer: accounts.payer,
+            authority: accounts.authority,
+            merkle_tree: accounts.merkle_tree,
+            tree_config: accounts.tree_config,
+            log_wrapper: accounts.log_wrapper,
+            compression_program: accounts.compression_program,
+            bubblegum_program: accounts.bubblegum_program,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.merkle_tree.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.tree_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.log_wrapper.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.compression_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.bubblegum_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(RegisterReviewsTreeV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_TOOLS_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.authority.clone());
+        account_infos.push(self.merkle_tree.clone());
+        account_infos.push(self.tree_config.clone());
+        account_infos.push(self.log_wrapper.clone());
+        account_infos.push(self.compression_program.clone());
+        account_infos.push(self.bubblegum_program.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RegisterReviewsTreeV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable, signer]` payer
+///   1. `[]` authority
+///   2. `[writable]` merkle_tree
+///   3. `[writable]` tree_config
+///   4. `[]` log_wrapper
+///   5. `[]` compression_program
+///   6. `[]` bubblegum_program
+///   7. `[]` system_program
+pub struct RegisterReviewsTreeV1CpiBuilder<'a, 'b> {
+    instruction: Box<RegisterReviewsTreeV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RegisterReviewsTreeV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RegisterReviewsTreeV1CpiBuilderInstruction {
+            __program: program,
+            payer: None,
+            authority: None,
+            merkle_tree: None,
+            tree_config: None,
+            log_wrapper: None,
+            compression_program: None,
+            bubblegum_program: None,
+            system_program: None,
+            tree_index: None,
+            max_depth: None,
+            max_buffer_size: None,
+            canopy_depth: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// Funds the tree rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// Reviews authority PDA at ["reviews_authority"] — set as tree_creator
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// Reviews merkle tree PDA at ["reviews_tree", tree_index_le]
+    #[inline(always)]
+    pub fn merkle_tree(
+        &mut self,
+        merkle_tree: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.merkle_tree = Some(merkle_tree);
+        self
+    }
+    /// Bubblegum tree config PDA (derived from merkle_tree)
+    #[inline(always)]
+    pub fn tree_config(
+        &mut self,
+        tree_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.tree_config = Some(tree_config);
+        self
+    }
+    /// MPL Noop / log wrapper program
+    #[inline(always)]
+    pub fn log_wrapper(
+        &mut self,
+        log_wrapper: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.log_wrapper = Some(log_wrapper);
+        self
+    }
+    /// MPL Account Compression program
+    #[inline(always)]
+    pub fn compression_program(
+        &mut self,
+        compression_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.compression_program = Some(compression_program);
+        self
+    }
+    /// The MPL Bubblegum program
+    #[inline(always)]
+    pub fn bubblegum_program(
+        &mut self,
+        bubblegum_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.bubblegum_program = Some(bubblegum_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn tree_index(&mut self, tree_index: u64) -> &mut Self {
+        self.instruction.tree_index = Some(tree_index);
+        self
+    }
+    #[inline(always)]
+    pub fn max_depth(&mut self, max_depth: u32) -> &mut Self {
+        self.instruction.max_depth = Some(max_depth);
+        self
+    }
+    #[inline(always)]
+    pub fn max_buffer_size(&mut self, max_buffer_size: u32) -> &mut Self {
+        self.instruction.max_buffer_size = Some(max_buffer_size);
+        self
+    }
+    #[inline(always)]
+    pub fn canopy_depth(&mut self, canopy_depth: u32) -> &mut Self {
+        self.instruction.canopy_depth = Some(canopy_depth);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = RegisterReviewsTreeV1InstructionArgs {
+            tree_index: self
+                .instruction
+                .tree_index
+                .clone()
+                .expect("tree_index is not set"),
+            max_depth: self
+                .instruction
+                .max_depth
+                .clone()
+                .expect("max_depth is not set"),
+            max_buffer_size: self
+                .instruction
+                .max_buffer_size
+                .clone()
+                .expect("max_buffer_size is not set"),
+            canopy_depth: self
+                .instruction
+                .canopy_depth
+                .clone()
+                .expect("canopy_depth is not set"),
+        };
+        let instruction = RegisterReviewsTreeV1Cpi {
+            __program: self.instruction.__program,
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            merkle_tree: self
+                .instruction
+                .merkle_tree
+                .expect("merkle_tree is not set"),
+
+            tree_config: self
+                .instruction
+                .tree_config
+                .expect("tree_config is not set"),
+
+            log_wrapper: self
+                .instruction
+                .log_wrapper
+                .expect("log_wrapper is not set"),
+
+            compression_program: self
+                .instruction
+                .compression_program
+                .expect("compression_program is not set"),
+
+            bubblegum_program: self
+                .instruction
+                .bubblegum_program
+                .expect("bubblegum_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RegisterReviewsTreeV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    merkle_tree: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tree_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    log_wrapper: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    compression_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    bubblegum_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    tree_index: Option<u64>,
+    max_depth: Option<u32>,
+    max_buffer_size: Option<u32>,
+    canopy_depth: Option<u32>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -24,6 +24,8 @@ pub struct RevokeExecutionV1 {
     pub authority: Option<solana_program::pubkey::Pubkey>,
     /// The system program
     pub system_program: solana_program::pubkey::Pubkey,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: solana_program::pubkey::Pubkey,
 }
 
 impl RevokeExecutionV1 {
@@ -35,7 +37,7 @@ impl RevokeExecutionV1 {
         &self,
         remaining_accounts: &[solana_program::instruction::AccountMeta],
     ) -> solana_program::instruction::Instruction {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             self.execution_delegate_record,
             false,
@@ -65,6 +67,10 @@ impl RevokeExecutionV1 {
             self.system_program,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.execution_delegate_count,
+            false,
+        ));
         accounts.extend_from_slice(remaining_accounts);
         let data = borsh::to_vec(&(RevokeExecutionV1InstructionData::new())).unwrap();
 
@@ -102,6 +108,7 @@ impl RevokeExecutionV1InstructionData {
 ///   3. `[writable, signer]` payer
 ///   4. `[signer, optional]` authority
 ///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   6. `[writable]` execution_delegate_count
 #[derive(Default)]
 pub struct RevokeExecutionV1Builder {
     execution_delegate_record: Option<solana_program::pubkey::Pubkey>,
@@ -110,6 +117,7 @@ pub struct RevokeExecutionV1Builder {
     payer: Option<solana_program::pubkey::Pubkey>,
     authority: Option<solana_program::pubkey::Pubkey>,
     system_program: Option<solana_program::pubkey::Pubkey>,
+    execution_delegate_count: Option<solana_program::pubkey::Pubkey>,
     __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
 }
 
@@ -158,6 +166,15 @@ impl RevokeExecutionV1Builder {
         self.system_program = Some(system_program);
         self
     }
+    /// The execution delegate count for the agent asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
     /// Add an aditional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -189,6 +206,9 @@ impl RevokeExecutionV1Builder {
             system_program: self
                 .system_program
                 .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            execution_delegate_count: self
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
         };
 
         accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
@@ -209,6 +229,8 @@ pub struct RevokeExecutionV1CpiAccounts<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 /// `revoke_execution_v1` CPI instruction.
@@ -227,6 +249,8 @@ pub struct RevokeExecutionV1Cpi<'a, 'b> {
     pub authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// The system program
     pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The execution delegate count for the agent asset
+    pub execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
 }
 
 impl<'a, 'b> RevokeExecutionV1Cpi<'a, 'b> {
@@ -242,6 +266,7 @@ impl<'a, 'b> RevokeExecutionV1Cpi<'a, 'b> {
             payer: accounts.payer,
             authority: accounts.authority,
             system_program: accounts.system_program,
+            execution_delegate_count: accounts.execution_delegate_count,
         }
     }
     #[inline(always)]
@@ -277,7 +302,7 @@ impl<'a, 'b> RevokeExecutionV1Cpi<'a, 'b> {
             bool,
         )],
     ) -> solana_program::entrypoint::ProgramResult {
-        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        let mut accounts = Vec::with_capacity(7 + remaining_accounts.len());
         accounts.push(solana_program::instruction::AccountMeta::new(
             *self.execution_delegate_record.key,
             false,
@@ -309,6 +334,10 @@ impl<'a, 'b> RevokeExecutionV1Cpi<'a, 'b> {
             *self.system_program.key,
             false,
         ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.execution_delegate_count.key,
+            false,
+        ));
         remaining_accounts.iter().for_each(|remaining_account| {
             accounts.push(solana_program::instruction::AccountMeta {
                 pubkey: *remaining_account.0.key,
@@ -323,7 +352,7 @@ impl<'a, 'b> RevokeExecutionV1Cpi<'a, 'b> {
             accounts,
             data,
         };
-        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        let mut account_infos = Vec::with_capacity(7 + 1 + remaining_accounts.len());
         account_infos.push(self.__program.clone());
         account_infos.push(self.execution_delegate_record.clone());
         account_infos.push(self.agent_asset.clone());
@@ -333,6 +362,7 @@ impl<'a, 'b> RevokeExecutionV1Cpi<'a, 'b> {
             account_infos.push(authority.clone());
         }
         account_infos.push(self.system_program.clone());
+        account_infos.push(self.execution_delegate_count.clone());
         remaining_accounts
             .iter()
             .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
@@ -355,6 +385,7 @@ impl<'a, 'b> RevokeExecutionV1Cpi<'a, 'b> {
 ///   3. `[writable, signer]` payer
 ///   4. `[signer, optional]` authority
 ///   5. `[]` system_program
+///   6. `[writable]` execution_delegate_count
 pub struct RevokeExecutionV1CpiBuilder<'a, 'b> {
     instruction: Box<RevokeExecutionV1CpiBuilderInstruction<'a, 'b>>,
 }
@@ -369,6 +400,7 @@ impl<'a, 'b> RevokeExecutionV1CpiBuilder<'a, 'b> {
             payer: None,
             authority: None,
             system_program: None,
+            execution_delegate_count: None,
             __remaining_accounts: Vec::new(),
         });
         Self { instruction }
@@ -425,6 +457,15 @@ impl<'a, 'b> RevokeExecutionV1CpiBuilder<'a, 'b> {
         self.instruction.system_program = Some(system_program);
         self
     }
+    /// The execution delegate count for the agent asset
+    #[inline(always)]
+    pub fn execution_delegate_count(
+        &mut self,
+        execution_delegate_count: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.execution_delegate_count = Some(execution_delegate_count);
+        self
+    }
     /// Add an additional account to the instruction.
     #[inline(always)]
     pub fn add_remaining_account(
@@ -492,6 +533,11 @@ impl<'a, 'b> RevokeExecutionV1CpiBuilder<'a, 'b> {
                 .instruction
                 .system_program
                 .expect("system_program is not set"),
+
+            execution_delegate_count: self
+                .instruction
+                .execution_delegate_count
+                .expect("execution_delegate_count is not set"),
         };
         instruction.invoke_signed_with_remaining_accounts(
             signers_seeds,
@@ -508,6 +554,7 @@ struct RevokeExecutionV1CpiBuilderInstruction<'a, 'b> {
     payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    execution_delegate_count: Option<&'b solana_program::account_info::AccountInfo<'a>>,
     /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
     __remaining_accounts: Vec<(
         &'b solana_program::account_info::AccountInfo<'a>,
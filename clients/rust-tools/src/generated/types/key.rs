@@ -19,4 +19,7 @@ pub enum Key {
     Uninitialized,
     ExecutiveProfileV1,
     ExecutionDelegateRecordV1,
+    ExecutionDelegateCountV1,
+    ReceiptReviewV1,
+    ExecutionDelegateMigrationV1,
 }
@@ -6,5 +6,7 @@
 //!
 
 pub(crate) mod r#key;
+pub(crate) mod r#mpl_agent_tools_event;
 
 pub use self::r#key::*;
+pub use self::r#mpl_agent_tools_event::*;
@@ -1,4 +1,8 @@
+pub mod events;
+#[allow(unexpected_cfgs, clippy::new_without_default)]
 mod generated;
+pub mod receipts;
+pub mod reviews;
 
 pub use generated::programs::MPL_AGENT_TOOLS_ID as ID;
 pub use generated::*;
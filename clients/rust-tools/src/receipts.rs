@@ -0,0 +1,136 @@
+//! Work receipt cNFTs minted by `MintWorkReceiptV1`.
+//!
+//! Receipts are Bubblegum V2 leaves in the receipts trees, owned by the
+//! client. The program mints them with the agent as the 100-share creator and
+//! the client as a 0-share creator, so a program holding the leaf data can
+//! rebuild the leaf and have the compression program verify it against the
+//! tree root.
+
+use mpl_bubblegum::{
+    hash::{hash_collection_option, hash_creators},
+    types::{Creator, LeafSchema},
+    utils::get_asset_id,
+};
+use solana_program::{
+    account_info::AccountInfo,
+    entrypoint::ProgramResult,
+    instruction::{AccountMeta, Instruction},
+    program::invoke,
+    program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+/// MPL Account Compression program id (owner of the receipts trees).
+pub const MPL_ACCOUNT_COMPRESSION_ID: Pubkey =
+    solana_program::pubkey!("mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW");
+
+/// Anchor discriminator of the compression program's `verify_leaf`.
+const VERIFY_LEAF_DISCRIMINATOR: [u8; 8] = [124, 220, 22, 223, 104, 10, 250, 224];
+
+/// Find the canonical receipts collection PDA: `["receipts_collection"]`.
+pub fn find_receipts_collection_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"receipts_collection"], &crate::ID)
+}
+
+/// Find the receipts merkle tree PDA at `tree_index`:
+/// `["receipts_tree", tree_index_le]`.
+pub fn find_receipts_tree_pda(tree_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"receipts_tree", &tree_index.to_le_bytes()], &crate::ID)
+}
+
+/// Leaf data of a work receipt the caller claims to hold.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct ReceiptProof {
+    /// Receipt leaf's nonce within its tree.
+    pub nonce: u64,
+    /// Receipt leaf's index within its tree.
+    pub index: u32,
+    /// Current root of the receipts merkle tree.
+    pub root: [u8; 32],
+    /// Hash of the receipt's metadata.
+    pub data_hash: [u8; 32],
+    /// Hash of the receipt's `asset_data` blob.
+    pub asset_data_hash: [u8; 32],
+    /// Leaf flags.
+    pub flags: u8,
+}
+
+impl ReceiptProof {
+    /// Hash of the leaf of a receipt in `merkle_tree` minted by `agent` to
+    /// `client`.
+    pub fn leaf_hash(
+        &self,
+        merkle_tree: &Pubkey,
+        agent: &Pubkey,
+        client: &Pubkey,
+    ) -> Result<[u8; 32], ProgramError> {
+        let creator_hash = hash_creators(&[
+            Creator {
+                address: *agent,
+                verified: false,
+                share: 100,
+            },
+            Creator {
+                address: *client,
+                verified: false,
+                share: 0,
+            },
+        ]);
+
+        Ok(LeafSchema::V2 {
+            id: get_asset_id(merkle_tree, self.nonce),
+            owner: *client,
+            delegate: *client,
+            nonce: self.nonce,
+            data_hash: self.data_hash,
+            creator_hash,
+            collection_hash: hash_collection_option(Some(find_receipts_collection_pda().0))?,
+            asset_data_hash: self.asset_data_hash,
+            flags: self.flags,
+        }
+        .hash())
+    }
+}
+
+/// Have the compression program verify that `merkle_tree` holds a work
+/// receipt minted by `agent` to `client`. The proof accounts are the merkle
+/// proof nodes. The caller is responsible for checking that `merkle_tree` is
+/// a receipts tree and that `compression_program` is the compression program.
+pub fn verify_receipt<'a>(
+    merkle_tree: &AccountInfo<'a>,
+    compression_program: &AccountInfo<'a>,
+    proof_accounts: &[AccountInfo<'a>],
+    agent: &Pubkey,
+    client: &Pubkey,
+    proof: &ReceiptProof,
+) -> ProgramResult {
+    let leaf = proof.leaf_hash(merkle_tree.key, agent, client)?;
+
+    let mut data = Vec::with_capacity(76);
+    data.extend_from_slice(&VERIFY_LEAF_DISCRIMINATOR);
+    data.extend_from_slice(&proof.root);
+    data.extend_from_slice(&leaf);
+    data.extend_from_slice(&proof.index.to_le_bytes());
+
+    let mut accounts = Vec::with_capacity(proof_accounts.len() + 1);
+    accounts.push(AccountMeta::new_readonly(*merkle_tree.key, false));
+    accounts.extend(
+        proof_accounts
+            .iter()
+            .map(|node| AccountMeta::new_readonly(*node.key, false)),
+    );
+
+    let mut account_infos = Vec::with_capacity(proof_accounts.len() + 2);
+    account_infos.push(merkle_tree.clone());
+    account_infos.extend_from_slice(proof_accounts);
+    account_infos.push(compression_program.clone());
+
+    invoke(
+        &Instruction {
+            program_id: MPL_ACCOUNT_COMPRESSION_ID,
+            accounts,
+            data,
+        },
+        &account_infos,
+    )
+}
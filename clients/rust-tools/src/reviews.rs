@@ -0,0 +1,127 @@
+//! Review cNFTs minted by `MintReviewV1`.
+//!
+//! Reviews are Bubblegum V2 leaves in the reviews trees, owned by their
+//! author. The program mints them with fixed metadata whose creators link
+//! the review to the agent, the reviewer and the reviewed work receipt, so a
+//! review can be decoded from its metadata and checked against its leaf.
+
+use mpl_bubblegum::{
+    hash::{hash_collection_option, hash_creators, hash_metadata, DEFAULT_ASSET_DATA_HASH},
+    types::{Creator, LeafSchema, MetadataArgsV2, TokenStandard},
+    utils::get_asset_id,
+};
+use solana_program::pubkey::Pubkey;
+
+/// Find the canonical reviews collection PDA: `["reviews_collection"]`.
+pub fn find_reviews_collection_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"reviews_collection"], &crate::ID)
+}
+
+/// Find the reviews signing authority PDA: `["reviews_authority"]`.
+pub fn find_reviews_authority_pda() -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"reviews_authority"], &crate::ID)
+}
+
+/// Find the reviews merkle tree PDA at `tree_index`:
+/// `["reviews_tree", tree_index_le]`.
+pub fn find_reviews_tree_pda(tree_index: u64) -> (Pubkey, u8) {
+    Pubkey::find_program_address(&[b"reviews_tree", &tree_index.to_le_bytes()], &crate::ID)
+}
+
+/// A review of a work receipt.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ReviewV1 {
+    /// The reviewed agent asset.
+    pub agent: Pubkey,
+    /// The author of the review, who owns the review leaf.
+    pub reviewer: Pubkey,
+    /// Bubblegum asset id of the reviewed work receipt.
+    pub receipt: Pubkey,
+    /// URI of the off-chain review JSON.
+    pub review_uri: String,
+}
+
+impl ReviewV1 {
+    /// Name of every review cNFT.
+    pub const NAME: &'static str = "Agent Review";
+
+    /// Symbol of every review cNFT.
+    pub const SYMBOL: &'static str = "AGENTRVW";
+
+    /// Decode a review from the metadata of a cNFT. Returns `None` if the
+    /// metadata was not minted by `MintReviewV1`.
+    pub fn from_metadata(metadata: &MetadataArgsV2) -> Option<Self> {
+        let review = match metadata.creators.as_slice() {
+            [agent, reviewer, receipt] => Self {
+                agent: agent.address,
+                reviewer: reviewer.address,
+                receipt: receipt.address,
+                review_uri: metadata.uri.clone(),
+            },
+            _ => return None,
+        };
+
+        (review.metadata() == *metadata).then_some(review)
+    }
+
+    /// Metadata of the review cNFT as minted by `MintReviewV1`.
+    pub fn metadata(&self) -> MetadataArgsV2 {
+        MetadataArgsV2 {
+            name: Self::NAME.to_string(),
+            symbol: Self::SYMBOL.to_string(),
+            uri: self.review_uri.clone(),
+            seller_fee_basis_points: 0,
+            primary_sale_happened: false,
+            is_mutable: false,
+            token_standard: Some(TokenStandard::NonFungible),
+            creators: self.creators(),
+            collection: Some(find_reviews_collection_pda().0),
+        }
+    }
+
+    /// Bubblegum asset id of the review minted at `nonce` in `merkle_tree`.
+    pub fn asset_id(merkle_tree: &Pubkey, nonce: u64) -> Pubkey {
+        get_asset_id(merkle_tree, nonce)
+    }
+
+    /// Leaf of the review minted at `nonce` in `merkle_tree`.
+    pub fn leaf_schema(&self, merkle_tree: &Pubkey, nonce: u64) -> LeafSchema {
+        LeafSchema::V2 {
+            id: Self::asset_id(merkle_tree, nonce),
+            owner: self.reviewer,
+            delegate: self.reviewer,
+            nonce,
+            data_hash: hash_metadata(&self.metadata()).unwrap(),
+            creator_hash: hash_creators(&self.creators()),
+            collection_hash: hash_collection_option(Some(find_reviews_collection_pda().0)).unwrap(),
+            asset_data_hash: DEFAULT_ASSET_DATA_HASH,
+            flags: 0,
+        }
+    }
+
+    /// Hash of the review leaf minted at `nonce` in `merkle_tree`, as stored
+    /// in the tree.
+    pub fn leaf_hash(&self, merkle_tree: &Pubkey, nonce: u64) -> [u8; 32] {
+        self.leaf_schema(merkle_tree, nonce).hash()
+    }
+
+    fn creators(&self) -> Vec<Creator> {
+        vec![
+            Creator {
+                address: self.agent,
+                verified: false,
+                share: 100,
+            },
+            Creator {
+                address: self.reviewer,
+                verified: false,
+                share: 0,
+            },
+            Creator {
+                address: self.receipt,
+                verified: false,
+                share: 0,
+            },
+        ]
+    }
+}
@@ -2,7 +2,7 @@
 
 use mpl_agent_identity::{accounts::AgentIdentityV2, instructions::RegisterIdentityV1Builder};
 use mpl_agent_tools::{
-    accounts::{ExecutionDelegateRecordV1, ExecutiveProfileV1},
+    accounts::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, ExecutiveProfileV1},
     errors::MplAgentToolsError,
     instructions::{DelegateExecutionV1Builder, RegisterExecutiveV1Builder},
     types::Key,
@@ -162,6 +162,7 @@ async fn delegate_execution() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -187,6 +188,19 @@ async fn delegate_execution() {
     assert_eq!(delegate_record.executive_profile, executive_profile_pda);
     assert_eq!(delegate_record.authority, context.payer.pubkey());
     assert_eq!(delegate_record.agent_asset, asset);
+
+    // And the agent asset's delegate count is incremented.
+    let account = context
+        .banks_client
+        .get_account(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+
+    let delegate_count = ExecutionDelegateCountV1::from_bytes(&account.data).unwrap();
+    assert_eq!(delegate_count.key, Key::ExecutionDelegateCountV1);
+    assert_eq!(delegate_count.agent_asset, asset);
+    assert_eq!(delegate_count.count, 1);
 }
 
 /// Equivalent of JS test: tools/delegateExecution.test.ts -
@@ -215,6 +229,7 @@ async fn execute_as_delegate_without_owner() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -276,6 +291,7 @@ async fn transfer_sol_via_delegate_execution() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -459,6 +475,7 @@ async fn cannot_delegate_if_not_owner() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .authority(Some(non_owner.pubkey()))
         .instruction();
@@ -503,6 +520,7 @@ async fn cannot_delegate_without_identity() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -542,6 +560,7 @@ async fn cannot_delegate_twice() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -559,6 +578,7 @@ async fn cannot_delegate_twice() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -605,6 +625,7 @@ async fn cannot_delegate_with_uninitialized_profile() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
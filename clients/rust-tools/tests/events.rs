@@ -0,0 +1,110 @@
+#![cfg(feature = "test-sbf")]
+
+use mpl_agent_tools::{
+    accounts::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, ExecutiveProfileV1},
+    events::MplAgentToolsEvent,
+    instructions::{CountExecutionDelegateV1Builder, RegisterExecutiveV1Builder},
+    types::Key,
+};
+use solana_program_test::{tokio, ProgramTest};
+use solana_sdk::{
+    account::AccountSharedData, pubkey::Pubkey, signature::Signer, transaction::Transaction,
+};
+
+fn setup() -> ProgramTest {
+    ProgramTest::new("mpl_agent_tools_program", mpl_agent_tools::ID, None)
+}
+
+#[tokio::test]
+async fn register_executive_emits_event() {
+    let context = setup().start_with_context().await;
+
+    let (executive_profile_pda, _) = ExecutiveProfileV1::find_pda(&context.payer.pubkey());
+
+    let ix = RegisterExecutiveV1Builder::new()
+        .executive_profile(executive_profile_pda)
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    let events = MplAgentToolsEvent::from_logs(&result.metadata.unwrap().log_messages);
+    assert_eq!(
+        events,
+        vec![MplAgentToolsEvent::ExecutiveRegisteredV1 {
+            executive_profile: executive_profile_pda,
+            authority: context.payer.pubkey(),
+        }]
+    );
+}
+
+#[tokio::test]
+async fn count_execution_delegate_emits_event() {
+    let mut context = setup().start_with_context().await;
+
+    // A record written before delegations were counted.
+    let agent_asset = Pubkey::new_unique();
+    let executive_profile = Pubkey::new_unique();
+    let (execution_delegate_record, bump) =
+        ExecutionDelegateRecordV1::find_pda(&executive_profile, &agent_asset);
+    let data = borsh::to_vec(&ExecutionDelegateRecordV1 {
+        key: Key::ExecutionDelegateRecordV1,
+        bump,
+        counted: 0,
+        padding: [0u8; 5],
+        executive_profile,
+        authority: Pubkey::new_unique(),
+        agent_asset,
+    })
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut account = AccountSharedData::new(
+        rent.minimum_balance(ExecutionDelegateRecordV1::LEN),
+        ExecutionDelegateRecordV1::LEN,
+        &mpl_agent_tools::ID,
+    );
+    account.set_data_from_slice(&data);
+    context.set_account(&execution_delegate_record, &account);
+
+    let ix = CountExecutionDelegateV1Builder::new()
+        .execution_delegate_record(execution_delegate_record)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&agent_asset).0)
+        .agent_asset(agent_asset)
+        .payer(context.payer.pubkey())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await
+        .unwrap();
+    result.result.unwrap();
+
+    let events = MplAgentToolsEvent::from_logs(&result.metadata.unwrap().log_messages);
+    assert_eq!(
+        events,
+        vec![MplAgentToolsEvent::ExecutionDelegateCountedV1 {
+            agent_asset,
+            executive_profile,
+            count: 1,
+        }]
+    );
+}
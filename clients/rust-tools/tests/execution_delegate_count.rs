@@ -0,0 +1,447 @@
+#![cfg(feature = "test-sbf")]
+
+use mpl_agent_identity::{
+    accounts::AgentIdentityV2,
+    errors::MplAgentIdentityError,
+    instructions::{DeregisterIdentityV1Builder, RegisterIdentityV1Builder},
+};
+use mpl_agent_tools::{
+    accounts::{
+        ExecutionDelegateCountV1, ExecutionDelegateMigrationV1, ExecutionDelegateRecordV1,
+        ExecutiveProfileV1,
+    },
+    errors::MplAgentToolsError,
+    instructions::{
+        CompleteExecutionDelegateMigrationV1Builder, CountExecutionDelegateV1Builder,
+        DelegateExecutionV1Builder, RegisterExecutiveV1Builder, RevokeExecutionV1Builder,
+    },
+    types::Key,
+};
+use mpl_core::instructions::{CreateCollectionV1Builder, CreateV1Builder};
+use solana_program::instruction::{Instruction, InstructionError};
+use solana_program_test::{tokio, BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::{Account, AccountSharedData},
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+
+const MPL_CORE_ID: Pubkey = solana_program::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+const SPL_NOOP_ID: Pubkey = solana_program::pubkey!("noopb9bkMVfRPU8AsbpTUg8AQkHtKwMYZiFUjNRtMmV");
+const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_program::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+fn assert_custom_error(error: BanksClientError, expected_code: u32) {
+    match error.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected_code);
+        }
+        err => panic!("Expected InstructionError::Custom({expected_code}), got: {err:?}"),
+    }
+}
+
+fn setup() -> ProgramTest {
+    let mut program_test = ProgramTest::new("mpl_agent_tools_program", mpl_agent_tools::ID, None);
+    program_test.add_program("mpl_agent_identity_program", mpl_agent_identity::ID, None);
+    program_test.add_program("mpl_core", MPL_CORE_ID, None);
+    program_test.add_program("spl_noop", SPL_NOOP_ID, None);
+    program_test
+}
+
+/// Add a program data account naming `upgrade_authority` as the upgrade
+/// authority of the tools program, as a deployment would.
+fn add_program_data(program_test: &mut ProgramTest, upgrade_authority: Pubkey) -> Pubkey {
+    let (address, _) =
+        Pubkey::find_program_address(&[mpl_agent_tools::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+
+    // UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address }
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.as_ref());
+
+    program_test.add_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: BPF_LOADER_UPGRADEABLE_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+
+    address
+}
+
+async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signers: &[&Keypair],
+) -> Result<(), BanksClientError> {
+    let mut all_signers = vec![&context.payer];
+    all_signers.extend_from_slice(signers);
+
+    let recent_blockhash = context.banks_client.get_latest_blockhash().await.unwrap();
+    let tx = Transaction::new_signed_with_payer(
+        &[ix],
+        Some(&context.payer.pubkey()),
+        &all_signers,
+        recent_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await
+}
+
+/// A Core asset with a registered agent identity, owned by the payer.
+/// Returns the collection and the asset.
+async fn setup_agent(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+
+    let ix = CreateCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Collection".to_string())
+        .uri("https://example.com/collection.json".to_string())
+        .instruction();
+    process(context, ix, &[&collection]).await.unwrap();
+
+    let ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .name("Test Asset".to_string())
+        .uri("https://example.com/asset.json".to_string())
+        .instruction();
+    process(context, ix, &[&asset]).await.unwrap();
+
+    let ix = RegisterIdentityV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset.pubkey()).0)
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .agent_registration_uri("https://example.com/agent.json".to_string())
+        .instruction();
+    process(context, ix, &[]).await.unwrap();
+
+    (collection.pubkey(), asset.pubkey())
+}
+
+/// Register an executive with its own authority. Returns the executive
+/// profile and its authority.
+async fn register_executive(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+    let authority = Keypair::new();
+    let (executive_profile, _) = ExecutiveProfileV1::find_pda(&authority.pubkey());
+    let ix = RegisterExecutiveV1Builder::new()
+        .executive_profile(executive_profile)
+        .payer(context.payer.pubkey())
+        .authority(Some(authority.pubkey()))
+        .instruction();
+    process(context, ix, &[&authority]).await.unwrap();
+
+    (executive_profile, authority.pubkey())
+}
+
+fn delegate_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    executive_profile: Pubkey,
+) -> Instruction {
+    DelegateExecutionV1Builder::new()
+        .executive_profile(executive_profile)
+        .agent_asset(asset)
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .execution_delegate_record(
+            ExecutionDelegateRecordV1::find_pda(&executive_profile, &asset).0,
+        )
+        .payer(context.payer.pubkey())
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .instruction()
+}
+
+/// Delegate the agent to a new executive. Returns the record.
+async fn delegate(context: &mut ProgramTestContext, asset: Pubkey) -> Pubkey {
+    let (executive_profile, _) = register_executive(context).await;
+    let ix = delegate_ix(context, asset, executive_profile);
+    process(context, ix, &[]).await.unwrap();
+
+    ExecutionDelegateRecordV1::find_pda(&executive_profile, &asset).0
+}
+
+/// Write a record delegated to a new executive before delegations were
+/// counted. Returns the record.
+async fn delegate_before_counting(context: &mut ProgramTestContext, asset: Pubkey) -> Pubkey {
+    let (executive_profile, authority) = register_executive(context).await;
+    let (address, bump) = ExecutionDelegateRecordV1::find_pda(&executive_profile, &asset);
+
+    let data = borsh::to_vec(&ExecutionDelegateRecordV1 {
+        key: Key::ExecutionDelegateRecordV1,
+        bump,
+        counted: 0,
+        padding: [0u8; 5],
+        executive_profile,
+        authority,
+        agent_asset: asset,
+    })
+    .unwrap();
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut account = AccountSharedData::new(
+        rent.minimum_balance(ExecutionDelegateRecordV1::LEN),
+        ExecutionDelegateRecordV1::LEN,
+        &mpl_agent_tools::ID,
+    );
+    account.set_data_from_slice(&data);
+    context.set_account(&address, &account);
+
+    address
+}
+
+fn revoke_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    execution_delegate_record: Pubkey,
+) -> Instruction {
+    RevokeExecutionV1Builder::new()
+        .execution_delegate_record(execution_delegate_record)
+        .agent_asset(asset)
+        .destination(context.payer.pubkey())
+        .payer(context.payer.pubkey())
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .instruction()
+}
+
+fn count_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    execution_delegate_record: Pubkey,
+) -> Instruction {
+    CountExecutionDelegateV1Builder::new()
+        .execution_delegate_record(execution_delegate_record)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .agent_asset(asset)
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+fn complete_migration_ix(context: &ProgramTestContext, authority: Pubkey) -> Instruction {
+    CompleteExecutionDelegateMigrationV1Builder::new()
+        .execution_delegate_migration(ExecutionDelegateMigrationV1::find_pda().0)
+        .program_data(
+            Pubkey::find_program_address(
+                &[mpl_agent_tools::ID.as_ref()],
+                &BPF_LOADER_UPGRADEABLE_ID,
+            )
+            .0,
+        )
+        .authority(authority)
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+fn deregister_ix(context: &ProgramTestContext, collection: Pubkey, asset: Pubkey) -> Instruction {
+    DeregisterIdentityV1Builder::new()
+        .agent_identity(AgentIdentityV2::find_pda(&asset).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .execution_delegate_migration(ExecutionDelegateMigrationV1::find_pda().0)
+        .destination(context.payer.pubkey())
+        .payer(context.payer.pubkey())
+        .instruction()
+}
+
+async fn fetch_record(
+    context: &mut ProgramTestContext,
+    execution_delegate_record: Pubkey,
+) -> ExecutionDelegateRecordV1 {
+    let account = context
+        .banks_client
+        .get_account(execution_delegate_record)
+        .await
+        .unwrap()
+        .unwrap();
+    ExecutionDelegateRecordV1::from_bytes(&account.data).unwrap()
+}
+
+async fn fetch_count(context: &mut ProgramTestContext, asset: Pubkey) -> Option<u64> {
+    context
+        .banks_client
+        .get_account(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .map(|account| {
+            ExecutionDelegateCountV1::from_bytes(&account.data)
+                .unwrap()
+                .count
+        })
+}
+
+#[tokio::test]
+async fn delegation_is_counted_until_revoked() {
+    let mut context = setup().start_with_context().await;
+    let (_, asset) = setup_agent(&mut context).await;
+
+    let record = delegate(&mut context, asset).await;
+    assert_eq!(fetch_record(&mut context, record).await.counted, 1);
+
+    let account = context
+        .banks_client
+        .get_account(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let delegate_count = ExecutionDelegateCountV1::from_bytes(&account.data).unwrap();
+    assert_eq!(delegate_count.key, Key::ExecutionDelegateCountV1);
+    assert_eq!(delegate_count.agent_asset, asset);
+    assert_eq!(delegate_count.count, 1);
+
+    let ix = revoke_ix(&context, asset, record);
+    process(&mut context, ix, &[]).await.unwrap();
+    assert_eq!(fetch_count(&mut context, asset).await, Some(0));
+}
+
+#[tokio::test]
+async fn cannot_delegate_without_the_count() {
+    let mut context = setup().start_with_context().await;
+    let (_, asset) = setup_agent(&mut context).await;
+    let (executive_profile, _) = register_executive(&mut context).await;
+
+    let mut ix = delegate_ix(&context, asset, executive_profile);
+    ix.accounts.pop();
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::NotEnoughAccountKeys)
+    );
+}
+
+#[tokio::test]
+async fn revoking_an_uncounted_delegation_keeps_the_count() {
+    let mut context = setup().start_with_context().await;
+    let (_, asset) = setup_agent(&mut context).await;
+    delegate(&mut context, asset).await;
+    let uncounted = delegate_before_counting(&mut context, asset).await;
+    assert_eq!(fetch_count(&mut context, asset).await, Some(1));
+
+    let ix = revoke_ix(&context, asset, uncounted);
+    process(&mut context, ix, &[]).await.unwrap();
+    assert_eq!(fetch_count(&mut context, asset).await, Some(1));
+}
+
+#[tokio::test]
+async fn uncounted_delegation_can_be_backfilled() {
+    let mut context = setup().start_with_context().await;
+    let (_, asset) = setup_agent(&mut context).await;
+    let record = delegate_before_counting(&mut context, asset).await;
+    assert_eq!(fetch_count(&mut context, asset).await, None);
+
+    // Anyone may count the record, once.
+    let ix = count_ix(&context, asset, record);
+    process(&mut context, ix, &[]).await.unwrap();
+    assert_eq!(fetch_record(&mut context, record).await.counted, 1);
+    assert_eq!(fetch_count(&mut context, asset).await, Some(1));
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = count_ix(&context, asset, record);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+    assert_custom_error(
+        err,
+        MplAgentToolsError::ExecutionDelegateAlreadyCounted as u32,
+    );
+
+    // The backfilled record is then revoked like a counted one.
+    let ix = revoke_ix(&context, asset, record);
+    process(&mut context, ix, &[]).await.unwrap();
+    assert_eq!(fetch_count(&mut context, asset).await, Some(0));
+}
+
+#[tokio::test]
+async fn uncounted_delegation_blocks_deregistration() {
+    let upgrade_authority = Keypair::new();
+    let mut program_test = setup();
+    add_program_data(&mut program_test, upgrade_authority.pubkey());
+    let mut context = program_test.start_with_context().await;
+    let (collection, asset) = setup_agent(&mut context).await;
+
+    // A record delegated before delegations were counted is not in the
+    // count, so the identity cannot be deregistered until the migration
+    // has counted every record.
+    let record = delegate_before_counting(&mut context, asset).await;
+    assert_eq!(fetch_count(&mut context, asset).await, None);
+
+    let ix = deregister_ix(&context, collection, asset);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+    assert_custom_error(
+        err,
+        MplAgentIdentityError::ExecutionDelegateMigrationIncomplete as u32,
+    );
+
+    // Once the record is counted and the migration completed, the count
+    // keeps the identity registered.
+    let ix = count_ix(&context, asset, record);
+    process(&mut context, ix, &[]).await.unwrap();
+    let ix = complete_migration_ix(&context, upgrade_authority.pubkey());
+    process(&mut context, ix, &[&upgrade_authority])
+        .await
+        .unwrap();
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = deregister_ix(&context, collection, asset);
+    let err = process(&mut context, ix, &[]).await.unwrap_err();
+    assert_custom_error(
+        err,
+        MplAgentIdentityError::ExecutionDelegatesStillActive as u32,
+    );
+
+    // After the record is revoked, the identity can be deregistered.
+    let ix = revoke_ix(&context, asset, record);
+    process(&mut context, ix, &[]).await.unwrap();
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = deregister_ix(&context, collection, asset);
+    process(&mut context, ix, &[]).await.unwrap();
+    let account = context
+        .banks_client
+        .get_account(AgentIdentityV2::find_pda(&asset).0)
+        .await
+        .unwrap();
+    assert!(account.is_none());
+}
+
+#[tokio::test]
+async fn only_the_upgrade_authority_can_complete_the_migration_once() {
+    let upgrade_authority = Keypair::new();
+    let mut program_test = setup();
+    add_program_data(&mut program_test, upgrade_authority.pubkey());
+    let mut context = program_test.start_with_context().await;
+
+    let stranger = Keypair::new();
+    let ix = complete_migration_ix(&context, stranger.pubkey());
+    let err = process(&mut context, ix, &[&stranger]).await.unwrap_err();
+    assert_custom_error(err, MplAgentToolsError::InvalidMigrationAuthority as u32);
+
+    let ix = complete_migration_ix(&context, upgrade_authority.pubkey());
+    process(&mut context, ix, &[&upgrade_authority])
+        .await
+        .unwrap();
+    let account = context
+        .banks_client
+        .get_account(ExecutionDelegateMigrationV1::find_pda().0)
+        .await
+        .unwrap()
+        .unwrap();
+    let migration = ExecutionDelegateMigrationV1::from_bytes(&account.data).unwrap();
+    assert_eq!(migration.key, Key::ExecutionDelegateMigrationV1);
+    assert_eq!(migration.authority, upgrade_authority.pubkey());
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = complete_migration_ix(&context, upgrade_authority.pubkey());
+    let err = process(&mut context, ix, &[&upgrade_authority])
+        .await
+        .unwrap_err();
+    assert_custom_error(
+        err,
+        MplAgentToolsError::ExecutionDelegateMigrationAlreadyCompleted as u32,
+    );
+}
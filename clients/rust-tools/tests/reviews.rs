@@ -0,0 +1,104 @@
+#![cfg(feature = "test-sbf")]
+
+use mpl_agent_tools::reviews::{
+    find_reviews_authority_pda, find_reviews_collection_pda, find_reviews_tree_pda, ReviewV1,
+};
+use mpl_bubblegum::{hash::hash_metadata, types::LeafSchema, utils::get_asset_id};
+use solana_sdk::pubkey::Pubkey;
+
+fn review() -> ReviewV1 {
+    ReviewV1 {
+        agent: Pubkey::new_unique(),
+        reviewer: Pubkey::new_unique(),
+        receipt: Pubkey::new_unique(),
+        review_uri: "https://example.com/review.json".to_string(),
+    }
+}
+
+#[test]
+fn review_pdas() {
+    let (collection, _) = find_reviews_collection_pda();
+    let (authority, _) = find_reviews_authority_pda();
+    let (tree_0, _) = find_reviews_tree_pda(0);
+    let (tree_1, _) = find_reviews_tree_pda(1);
+
+    assert_eq!(
+        collection,
+        Pubkey::find_program_address(&[b"reviews_collection"], &mpl_agent_tools::ID).0
+    );
+    assert_eq!(
+        authority,
+        Pubkey::find_program_address(&[b"reviews_authority"], &mpl_agent_tools::ID).0
+    );
+    assert_eq!(
+        tree_1,
+        Pubkey::find_program_address(
+            &[b"reviews_tree", &1u64.to_le_bytes()],
+            &mpl_agent_tools::ID
+        )
+        .0
+    );
+    assert_ne!(tree_0, tree_1);
+}
+
+#[test]
+fn decode_review_metadata() {
+    let review = review();
+    let metadata = review.metadata();
+
+    assert_eq!(metadata.name, "Agent Review");
+    assert_eq!(metadata.symbol, "AGENTRVW");
+    assert_eq!(metadata.collection, Some(find_reviews_collection_pda().0));
+    assert_eq!(ReviewV1::from_metadata(&metadata), Some(review));
+}
+
+#[test]
+fn decode_rejects_foreign_metadata() {
+    let review = review();
+
+    // A work receipt has two creators.
+    let mut metadata = review.metadata();
+    metadata.creators.pop();
+    assert_eq!(ReviewV1::from_metadata(&metadata), None);
+
+    // Same shape, another collection.
+    let mut metadata = review.metadata();
+    metadata.collection = Some(Pubkey::new_unique());
+    assert_eq!(ReviewV1::from_metadata(&metadata), None);
+
+    // Same shape, another symbol.
+    let mut metadata = review.metadata();
+    metadata.symbol = "AGENTRCPT".to_string();
+    assert_eq!(ReviewV1::from_metadata(&metadata), None);
+}
+
+#[test]
+fn review_leaf() {
+    let review = review();
+    let merkle_tree = find_reviews_tree_pda(7).0;
+
+    let leaf = review.leaf_schema(&merkle_tree, 3);
+    match &leaf {
+        LeafSchema::V2 {
+            id,
+            owner,
+            delegate,
+            nonce,
+            data_hash,
+            ..
+        } => {
+            assert_eq!(*id, get_asset_id(&merkle_tree, 3));
+            assert_eq!(*id, ReviewV1::asset_id(&merkle_tree, 3));
+            assert_eq!(*owner, review.reviewer);
+            assert_eq!(*delegate, review.reviewer);
+            assert_eq!(*nonce, 3);
+            assert_eq!(*data_hash, hash_metadata(&review.metadata()).unwrap());
+        }
+        _ => panic!("expected a V2 leaf"),
+    }
+    assert_eq!(review.leaf_hash(&merkle_tree, 3), leaf.hash());
+    assert_ne!(
+        review.leaf_hash(&merkle_tree, 3),
+        review.leaf_hash(&merkle_tree, 4)
+    );
+}
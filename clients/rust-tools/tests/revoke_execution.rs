@@ -2,7 +2,7 @@
 
 use mpl_agent_identity::{accounts::AgentIdentityV2, instructions::RegisterIdentityV1Builder};
 use mpl_agent_tools::{
-    accounts::{ExecutionDelegateRecordV1, ExecutiveProfileV1},
+    accounts::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, ExecutiveProfileV1},
     errors::MplAgentToolsError,
     instructions::{
         DelegateExecutionV1Builder, RegisterExecutiveV1Builder, RevokeExecutionV1Builder,
@@ -150,6 +150,7 @@ async fn delegate_execution(
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -191,6 +192,7 @@ async fn revoke_execution_by_owner() {
     // Revoke as the owner (payer is the owner).
     let ix = RevokeExecutionV1Builder::new()
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .agent_asset(asset)
         .destination(context.payer.pubkey())
         .payer(context.payer.pubkey())
@@ -212,6 +214,16 @@ async fn revoke_execution_by_owner() {
         .await
         .unwrap();
     assert!(account.is_none(), "Delegate record should be closed");
+
+    // The agent asset's delegate count drops back to zero.
+    let account = context
+        .banks_client
+        .get_account(ExecutionDelegateCountV1::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let delegate_count = ExecutionDelegateCountV1::from_bytes(&account.data).unwrap();
+    assert_eq!(delegate_count.count, 0);
 }
 
 /// Owner delegates to a separate executive, then the executive authority revokes.
@@ -236,6 +248,7 @@ async fn revoke_execution_by_executive() {
     // Revoke as the executive authority.
     let ix = RevokeExecutionV1Builder::new()
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .agent_asset(asset)
         .destination(context.payer.pubkey())
         .payer(context.payer.pubkey())
@@ -275,6 +288,7 @@ async fn cannot_revoke_uninitialized_record() {
 
     let ix = RevokeExecutionV1Builder::new()
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .agent_asset(asset)
         .destination(context.payer.pubkey())
         .payer(context.payer.pubkey())
@@ -321,6 +335,7 @@ async fn cannot_revoke_as_unauthorized_party() {
 
     let ix = RevokeExecutionV1Builder::new()
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .agent_asset(asset)
         .destination(context.payer.pubkey())
         .payer(context.payer.pubkey())
@@ -381,6 +396,7 @@ async fn cannot_revoke_with_mismatched_agent_asset() {
     // Try to revoke with the delegate_record for `asset` but pass `other_asset`.
     let ix = RevokeExecutionV1Builder::new()
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .agent_asset(other_asset.pubkey())
         .destination(context.payer.pubkey())
         .payer(context.payer.pubkey())
@@ -426,6 +442,7 @@ async fn can_redelegate_after_revoke() {
     // Revoke.
     let ix = RevokeExecutionV1Builder::new()
         .execution_delegate_record(delegate_record_pda)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .agent_asset(asset)
         .destination(context.payer.pubkey())
         .payer(context.payer.pubkey())
@@ -450,6 +467,7 @@ async fn can_redelegate_after_revoke() {
         .agent_asset(asset)
         .agent_identity(agent_identity_pda)
         .execution_delegate_record(delegate_record_pda_2)
+        .execution_delegate_count(ExecutionDelegateCountV1::find_pda(&asset).0)
         .payer(context.payer.pubkey())
         .instruction();
 
@@ -0,0 +1,29 @@
+//! Statement an attester key signs to back a validation response with
+//! `RespondValidationAttestationV1`.
+//!
+//! The statement mirrors `mpl_agent_validation_program::processor::attestation_message`:
+//! the validation request address, the validator, the work hash of the
+//! request and the result byte. The signature is verified by an Ed25519
+//! program instruction placed right before the response in the same
+//! transaction.
+
+use solana_program::pubkey::Pubkey;
+
+/// Size of an attestation statement in bytes.
+pub const ATTESTATION_MESSAGE_LEN: usize = 97;
+
+/// Build the statement an attester signs for the response `result` of
+/// `validator` to the request `validation_request` for `work_hash`.
+pub fn attestation_message(
+    validation_request: &Pubkey,
+    validator: &Pubkey,
+    work_hash: &[u8; 32],
+    result: u8,
+) -> [u8; ATTESTATION_MESSAGE_LEN] {
+    let mut message = [0u8; ATTESTATION_MESSAGE_LEN];
+    message[..32].copy_from_slice(validation_request.as_ref());
+    message[32..64].copy_from_slice(validator.as_ref());
+    message[64..96].copy_from_slice(work_hash);
+    message[96] = result;
+    message
+}
@@ -0,0 +1,24 @@
+//! Decoders for the typed events emitted by the program.
+//!
+//! Every event is written with `sol_log_data` as a single field holding the
+//! event version byte followed by the Borsh-serialized event, and decoded with
+//! the helpers shared by the program clients in `mpl_agent_events`. The event
+//! types are generated from the IDL and mirror `mpl_agent_validation_program::events`.
+
+pub use crate::generated::types::MplAgentValidationEvent;
+
+/// Event encoding version understood by this decoder.
+pub const EVENT_VERSION: u8 = 1;
+
+impl MplAgentValidationEvent {
+    /// Decode an event from a `sol_log_data` payload. Returns `None` if the
+    /// payload has an unknown version or is not a valid event.
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        mpl_agent_events::decode(EVENT_VERSION, data)
+    }
+
+    /// Decode all events emitted by the program from transaction log messages.
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Vec<Self> {
+        mpl_agent_events::from_logs(&crate::ID, EVENT_VERSION, logs)
+    }
+}
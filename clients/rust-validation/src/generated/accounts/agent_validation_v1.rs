@@ -19,7 +19,8 @@ use solana_program::pubkey::Pubkey;
 pub struct AgentValidationV1 {
     pub key: Key,
     pub bump: u8,
-    pub padding: [u8; 6],
+    pub padding: [u8; 2],
+    pub accepted_validator_count: u32,
     #[cfg_attr(
         feature = "serde",
         serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
@@ -5,6 +5,22 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#accepted_validator_v1;
 pub(crate) mod r#agent_validation_v1;
+pub(crate) mod r#agent_validation_v2;
+pub(crate) mod r#validation_config_v1;
+pub(crate) mod r#validation_request_v1;
+pub(crate) mod r#validator_attester_v1;
+pub(crate) mod r#validator_history_v1;
+pub(crate) mod r#validator_profile_v1;
+pub(crate) mod r#validator_stake_vault_v1;
 
+pub use self::r#accepted_validator_v1::*;
 pub use self::r#agent_validation_v1::*;
+pub use self::r#agent_validation_v2::*;
+pub use self::r#validation_config_v1::*;
+pub use self::r#validation_request_v1::*;
+pub use self::r#validator_attester_v1::*;
+pub use self::r#validator_history_v1::*;
+pub use self::r#validator_profile_v1::*;
+pub use self::r#validator_stake_vault_v1::*;
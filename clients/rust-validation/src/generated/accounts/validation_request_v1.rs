@@ -0,0 +1,110 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+use crate::generated::types::EvidenceType;
+use crate::generated::types::Key;
+use crate::generated::types::ValidationStatus;
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ValidationRequestV1 {
+    pub key: Key,
+    pub bump: u8,
+    pub status: ValidationStatus,
+    pub result: u8,
+    pub evidence_type: EvidenceType,
+    pub counted: u8,
+    pub padding: [u8; 2],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub asset: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub requester: Pubkey,
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub validator: Pubkey,
+    pub nonce: u64,
+    pub request_slot: u64,
+    pub response_slot: u64,
+    pub work_hash: [u8; 32],
+    pub evidence_hash: [u8; 32],
+    pub reward: u64,
+    pub expiry_slot: u64,
+    pub validator_stake: u64,
+    pub reserved: [u8; 8],
+}
+
+impl ValidationRequestV1 {
+    pub const LEN: usize = 224;
+
+    /// Prefix values used to generate a PDA for this account.
+    ///
+    /// Values are positional and appear in the following order:
+    ///
+    ///   0. `ValidationRequestV1::PREFIX`
+    ///   1. asset (`Pubkey`)
+    ///   2. nonce (`u64`)
+    pub const PREFIX: &'static [u8] = "validation_request".as_bytes();
+
+    pub fn create_pda(
+        asset: Pubkey,
+        nonce: u64,
+        bump: u8,
+    ) -> Result<solana_program::pubkey::Pubkey, solana_program::pubkey::PubkeyError> {
+        solana_program::pubkey::Pubkey::create_program_address(
+            &[
+                "validation_request".as_bytes(),
+                asset.as_ref(),
+                nonce.to_le_bytes().as_ref(),
+                &[bump],
+            ],
+            &crate::MPL_AGENT_VALIDATION_ID,
+        )
+    }
+
+    pub fn find_pda(asset: &Pubkey, nonce: u64) -> (solana_program::pubkey::Pubkey, u8) {
+        solana_program::pubkey::Pubkey::find_program_address(
+            &[
+                "validation_request".as_bytes(),
+                asset.as_ref(),
+                nonce.to_le_bytes().as_ref(),
+            ],
+            &crate::MPL_AGENT_VALIDATION_ID,
+        )
+    }
+
+    #[inline(always)]
+    pub fn from_bytes(data: &[u8]) -> Result<Self, std::io::Error> {
+        let mut data = data;
+        Self::deserialize(&mut data)
+    }
+}
+
+impl<'a> TryFrom<&solana_program::account_info::AccountInfo<'a>> for ValidationRequestV1 {
+    type Error = std::io::Error;
+
+    fn try_from(
+        account_info: &solana_program::account_info::AccountInfo<'a>,
+    ) -> Result<Self, Self::Error> {
+        let mut data: &[u8] = &(*account_info.data).borrow();
+        Self::deserialize(&mut data)
+    }
+}
@@ -29,6 +29,132 @@ pub enum MplAgentValidationError {
     /// 5 (0x5) - Agent Validation already registered
     #[error("Agent Validation already registered")]
     AgentValidationAlreadyRegistered,
+    /// 6 (0x6) - Asset has no agent validation
+    #[error("Asset has no agent validation")]
+    AgentValidationNotRegistered,
+    /// 7 (0x7) - Invalid validation request account
+    #[error("Invalid validation request account")]
+    InvalidValidationRequest,
+    /// 8 (0x8) - Validation request already exists
+    #[error("Validation request already exists")]
+    ValidationRequestAlreadyExists,
+    /// 9 (0x9) - Work hash must be non-zero
+    #[error("Work hash must be non-zero")]
+    EmptyWorkHash,
+    /// 10 (0xA) - Requester cannot validate their own request
+    #[error("Requester cannot validate their own request")]
+    RequesterCannotValidate,
+    /// 11 (0xB) - Only the designated validator can respond
+    #[error("Only the designated validator can respond")]
+    OnlyDesignatedValidatorCanRespond,
+    /// 12 (0xC) - Validation was already responded to
+    #[error("Validation was already responded to")]
+    ValidationAlreadyResponded,
+    /// 13 (0xD) - Validation result exceeds the maximum of 100
+    #[error("Validation result exceeds the maximum of 100")]
+    InvalidValidationResult,
+    /// 14 (0xE) - Evidence URI must not be empty
+    #[error("Evidence URI must not be empty")]
+    EvidenceUriEmpty,
+    /// 15 (0xF) - Evidence URI exceeds the maximum length
+    #[error("Evidence URI exceeds the maximum length")]
+    EvidenceUriTooLong,
+    /// 16 (0x10) - Evidence URI is not valid UTF-8
+    #[error("Evidence URI is not valid UTF-8")]
+    EvidenceUriNotUtf8,
+    /// 17 (0x11) - Evidence URI scheme is not allowed
+    #[error("Evidence URI scheme is not allowed")]
+    EvidenceUriSchemeNotAllowed,
+    /// 18 (0x12) - Invalid validation config account
+    #[error("Invalid validation config account")]
+    InvalidValidationConfig,
+    /// 19 (0x13) - Validation config already initialized
+    #[error("Validation config already initialized")]
+    ValidationConfigAlreadyInitialized,
+    /// 20 (0x14) - Invalid program data account
+    #[error("Invalid program data account")]
+    InvalidProgramData,
+    /// 21 (0x15) - Signer is not the config authority
+    #[error("Signer is not the config authority")]
+    InvalidConfigAuthority,
+    /// 22 (0x16) - Unbonding period must be non-zero
+    #[error("Unbonding period must be non-zero")]
+    InvalidUnbondingPeriod,
+    /// 23 (0x17) - Invalid validator profile account
+    #[error("Invalid validator profile account")]
+    InvalidValidatorProfile,
+    /// 24 (0x18) - Validator already registered
+    #[error("Validator already registered")]
+    ValidatorAlreadyRegistered,
+    /// 25 (0x19) - Validator is not registered
+    #[error("Validator is not registered")]
+    ValidatorNotRegistered,
+    /// 26 (0x1A) - Invalid validator stake vault account
+    #[error("Invalid validator stake vault account")]
+    InvalidValidatorStakeVault,
+    /// 27 (0x1B) - Stake amount must be non-zero
+    #[error("Stake amount must be non-zero")]
+    InvalidStakeAmount,
+    /// 28 (0x1C) - Validator does not have enough stake
+    #[error("Validator does not have enough stake")]
+    InsufficientValidatorStake,
+    /// 29 (0x1D) - Validator has no unbonding stake
+    #[error("Validator has no unbonding stake")]
+    NoUnbondingStake,
+    /// 30 (0x1E) - Stake is still unbonding
+    #[error("Stake is still unbonding")]
+    StakeStillUnbonding,
+    /// 31 (0x1F) - Signer is not the arbiter
+    #[error("Signer is not the arbiter")]
+    InvalidArbiter,
+    /// 32 (0x20) - Numerical overflow
+    #[error("Numerical overflow")]
+    NumericalOverflow,
+    /// 33 (0x21) - Invalid accepted validator account
+    #[error("Invalid accepted validator account")]
+    InvalidAcceptedValidator,
+    /// 34 (0x22) - Validator already accepted
+    #[error("Validator already accepted")]
+    ValidatorAlreadyAccepted,
+    /// 35 (0x23) - Validator is not accepted by the agent
+    #[error("Validator is not accepted by the agent")]
+    ValidatorNotAccepted,
+    /// 36 (0x24) - Signer does not own the agent asset
+    #[error("Signer does not own the agent asset")]
+    InvalidAgentAuthority,
+    /// 37 (0x25) - Invalid validator history account
+    #[error("Invalid validator history account")]
+    InvalidValidatorHistory,
+    /// 38 (0x26) - Asset does not match the validation request
+    #[error("Asset does not match the validation request")]
+    AssetMismatch,
+    /// 39 (0x27) - Invalid validator attester account
+    #[error("Invalid validator attester account")]
+    InvalidValidatorAttester,
+    /// 40 (0x28) - Attester already added
+    #[error("Attester already added")]
+    AttesterAlreadyAdded,
+    /// 41 (0x29) - Invalid instructions sysvar
+    #[error("Invalid instructions sysvar")]
+    InvalidInstructionsSysvar,
+    /// 42 (0x2A) - Attestation must be verified by an Ed25519 program instruction right before
+    #[error("Attestation must be verified by an Ed25519 program instruction right before")]
+    MissingAttestation,
+    /// 43 (0x2B) - Attestation does not match the attester or the response
+    #[error("Attestation does not match the attester or the response")]
+    InvalidAttestation,
+    /// 44 (0x2C) - Validation request is expired
+    #[error("Validation request is expired")]
+    ValidationRequestExpired,
+    /// 45 (0x2D) - Validation request is not expired yet
+    #[error("Validation request is not expired yet")]
+    ValidationRequestNotExpired,
+    /// 46 (0x2E) - Expiry slot must be in the future and within the maximum expiry
+    #[error("Expiry slot must be in the future and within the maximum expiry")]
+    InvalidExpirySlot,
+    /// 47 (0x2F) - Only the requester can reclaim the validation request
+    #[error("Only the requester can reclaim the validation request")]
+    OnlyRequesterCanReclaim,
 }
 
 impl From<MplAgentValidationError> for ProgramError {
@@ -47,6 +173,48 @@ impl TryFrom<u32> for MplAgentValidationError {
             3 => Ok(MplAgentValidationError::InvalidMplCoreProgram),
             4 => Ok(MplAgentValidationError::InvalidCoreAsset),
             5 => Ok(MplAgentValidationError::AgentValidationAlreadyRegistered),
+            6 => Ok(MplAgentValidationError::AgentValidationNotRegistered),
+            7 => Ok(MplAgentValidationError::InvalidValidationRequest),
+            8 => Ok(MplAgentValidationError::ValidationRequestAlreadyExists),
+            9 => Ok(MplAgentValidationError::EmptyWorkHash),
+            10 => Ok(MplAgentValidationError::RequesterCannotValidate),
+            11 => Ok(MplAgentValidationError::OnlyDesignatedValidatorCanRespond),
+            12 => Ok(MplAgentValidationError::ValidationAlreadyResponded),
+            13 => Ok(MplAgentValidationError::InvalidValidationResult),
+            14 => Ok(MplAgentValidationError::EvidenceUriEmpty),
+            15 => Ok(MplAgentValidationError::EvidenceUriTooLong),
+            16 => Ok(MplAgentValidationError::EvidenceUriNotUtf8),
+            17 => Ok(MplAgentValidationError::EvidenceUriSchemeNotAllowed),
+            18 => Ok(MplAgentValidationError::InvalidValidationConfig),
+            19 => Ok(MplAgentValidationError::ValidationConfigAlreadyInitialized),
+            20 => Ok(MplAgentValidationError::InvalidProgramData),
+            21 => Ok(MplAgentValidationError::InvalidConfigAuthority),
+            22 => Ok(MplAgentValidationError::InvalidUnbondingPeriod),
+            23 => Ok(MplAgentValidationError::InvalidValidatorProfile),
+            24 => Ok(MplAgentValidationError::ValidatorAlreadyRegistered),
+            25 => Ok(MplAgentValidationError::ValidatorNotRegistered),
+            26 => Ok(MplAgentValidationError::InvalidValidatorStakeVault),
+            27 => Ok(MplAgentValidationError::InvalidStakeAmount),
+            28 => Ok(MplAgentValidationError::InsufficientValidatorStake),
+            29 => Ok(MplAgentValidationError::NoUnbondingStake),
+            30 => Ok(MplAgentValidationError::StakeStillUnbonding),
+            31 => Ok(MplAgentValidationError::InvalidArbiter),
+            32 => Ok(MplAgentValidationError::NumericalOverflow),
+            33 => Ok(MplAgentValidationError::InvalidAcceptedValidator),
+            34 => Ok(MplAgentValidationError::ValidatorAlreadyAccepted),
+            35 => Ok(MplAgentValidationError::ValidatorNotAccepted),
+            36 => Ok(MplAgentValidationError::InvalidAgentAuthority),
+            37 => Ok(MplAgentValidationError::InvalidValidatorHistory),
+            38 => Ok(MplAgentValidationError::AssetMismatch),
+            39 => Ok(MplAgentValidationError::InvalidValidatorAttester),
+            40 => Ok(MplAgentValidationError::AttesterAlreadyAdded),
+            41 => Ok(MplAgentValidationError::InvalidInstructionsSysvar),
+            42 => Ok(MplAgentValidationError::MissingAttestation),
+            43 => Ok(MplAgentValidationError::InvalidAttestation),
+            44 => Ok(MplAgentValidationError::ValidationRequestExpired),
+            45 => Ok(MplAgentValidationError::ValidationRequestNotExpired),
+            46 => Ok(MplAgentValidationError::InvalidExpirySlot),
+            47 => Ok(MplAgentValidationError::OnlyRequesterCanReclaim),
             _ => Err(ProgramError::InvalidArgument),
         }
     }
@@ -63,6 +231,88 @@ impl ToStr for MplAgentValidationError {
             MplAgentValidationError::AgentValidationAlreadyRegistered => {
                 "Agent Validation already registered"
             }
+            MplAgentValidationError::AgentValidationNotRegistered => {
+                "Asset has no agent validation"
+            }
+            MplAgentValidationError::InvalidValidationRequest => {
+                "Invalid validation request account"
+            }
+            MplAgentValidationError::ValidationRequestAlreadyExists => {
+                "Validation request already exists"
+            }
+            MplAgentValidationError::EmptyWorkHash => "Work hash must be non-zero",
+            MplAgentValidationError::RequesterCannotValidate => {
+                "Requester cannot validate their own request"
+            }
+            MplAgentValidationError::OnlyDesignatedValidatorCanRespond => {
+                "Only the designated validator can respond"
+            }
+            MplAgentValidationError::ValidationAlreadyResponded => {
+                "Validation was already responded to"
+            }
+            MplAgentValidationError::InvalidValidationResult => {
+                "Validation result exceeds the maximum of 100"
+            }
+            MplAgentValidationError::EvidenceUriEmpty => "Evidence URI must not be empty",
+            MplAgentValidationError::EvidenceUriTooLong => {
+                "Evidence URI exceeds the maximum length"
+            }
+            MplAgentValidationError::EvidenceUriNotUtf8 => "Evidence URI is not valid UTF-8",
+            MplAgentValidationError::EvidenceUriSchemeNotAllowed => {
+                "Evidence URI scheme is not allowed"
+            }
+            MplAgentValidationError::InvalidValidationConfig => "Invalid validation config account",
+            MplAgentValidationError::ValidationConfigAlreadyInitialized => {
+                "Validation config already initialized"
+            }
+            MplAgentValidationError::InvalidProgramData => "Invalid program data account",
+            MplAgentValidationError::InvalidConfigAuthority => "Signer is not the config authority",
+            MplAgentValidationError::InvalidUnbondingPeriod => "Unbonding period must be non-zero",
+            MplAgentValidationError::InvalidValidatorProfile => "Invalid validator profile account",
+            MplAgentValidationError::ValidatorAlreadyRegistered => "Validator already registered",
+            MplAgentValidationError::ValidatorNotRegistered => "Validator is not registered",
+            MplAgentValidationError::InvalidValidatorStakeVault => {
+                "Invalid validator stake vault account"
+            }
+            MplAgentValidationError::InvalidStakeAmount => "Stake amount must be non-zero",
+            MplAgentValidationError::InsufficientValidatorStake => {
+                "Validator does not have enough stake"
+            }
+            MplAgentValidationError::NoUnbondingStake => "Validator has no unbonding stake",
+            MplAgentValidationError::StakeStillUnbonding => "Stake is still unbonding",
+            MplAgentValidationError::InvalidArbiter => "Signer is not the arbiter",
+            MplAgentValidationError::NumericalOverflow => "Numerical overflow",
+            MplAgentValidationError::InvalidAcceptedValidator => {
+                "Invalid accepted validator account"
+            }
+            MplAgentValidationError::ValidatorAlreadyAccepted => "Validator already accepted",
+            MplAgentValidationError::ValidatorNotAccepted => {
+                "Validator is not accepted by the agent"
+            }
+            MplAgentValidationError::InvalidAgentAuthority => "Signer does not own the agent asset",
+            MplAgentValidationError::InvalidValidatorHistory => "Invalid validator history account",
+            MplAgentValidationError::AssetMismatch => "Asset does not match the validation request",
+            MplAgentValidationError::InvalidValidatorAttester => {
+                "Invalid validator attester account"
+            }
+            MplAgentValidationError::AttesterAlreadyAdded => "Attester already added",
+            MplAgentValidationError::InvalidInstructionsSysvar => "Invalid instructions sysvar",
+            MplAgentValidationError::MissingAttestation => {
+                "Attestation must be verified by an Ed25519 program instruction right before"
+            }
+            MplAgentValidationError::InvalidAttestation => {
+                "Attestation does not match the attester or the response"
+            }
+            MplAgentValidationError::ValidationRequestExpired => "Validation request is expired",
+            MplAgentValidationError::ValidationRequestNotExpired => {
+                "Validation request is not expired yet"
+            }
+            MplAgentValidationError::InvalidExpirySlot => {
+                "Expiry slot must be in the future and within the maximum expiry"
+            }
+            MplAgentValidationError::OnlyRequesterCanReclaim => {
+                "Only the requester can reclaim the validation request"
+            }
         }
     }
 }
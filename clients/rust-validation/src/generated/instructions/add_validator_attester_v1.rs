@@ -0,0 +1,502 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct AddValidatorAttesterV1 {
+    /// The validator attester PDA at ["validator_attester", validator, attester]
+    pub validator_attester: solana_program::pubkey::Pubkey,
+    /// The validator profile PDA
+    pub validator_profile: solana_program::pubkey::Pubkey,
+    /// The validator authority
+    pub validator: solana_program::pubkey::Pubkey,
+    /// The ed25519 attester key, signing as proof of possession
+    pub attester: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl AddValidatorAttesterV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_attester,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_profile,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validator,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.attester,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(AddValidatorAttesterV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct AddValidatorAttesterV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl AddValidatorAttesterV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 11,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+/// Instruction builder for `AddValidatorAttesterV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_attester
+///   1. `[writable]` validator_profile
+///   2. `[signer]` validator
+///   3. `[signer]` attester
+///   4. `[writable, signer]` payer
+///   5. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct AddValidatorAttesterV1Builder {
+    validator_attester: Option<solana_program::pubkey::Pubkey>,
+    validator_profile: Option<solana_program::pubkey::Pubkey>,
+    validator: Option<solana_program::pubkey::Pubkey>,
+    attester: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl AddValidatorAttesterV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The validator attester PDA at ["validator_attester", validator, attester]
+    #[inline(always)]
+    pub fn validator_attester(
+        &mut self,
+        validator_attester: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_attester = Some(validator_attester);
+        self
+    }
+    /// The validator profile PDA
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator authority
+    #[inline(always)]
+    pub fn validator(&mut self, validator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.validator = Some(validator);
+        self
+    }
+    /// The ed25519 attester key, signing as proof of possession
+    #[inline(always)]
+    pub fn attester(&mut self, attester: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.attester = Some(attester);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = AddValidatorAttesterV1 {
+            validator_attester: self
+                .validator_attester
+                .expect("validator_attester is not set"),
+            validator_profile: self
+                .validator_profile
+                .expect("validator_profile is not set"),
+            validator: self.validator.expect("validator is not set"),
+            attester: self.attester.expect("attester is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `add_validator_attester_v1` CPI accounts.
+pub struct AddValidatorAttesterV1CpiAccounts<'a, 'b> {
+    /// The validator attester PDA at ["validator_attester", validator, attester]
+    pub validator_attester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator authority
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The ed25519 attester key, signing as proof of possession
+    pub attester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `add_validator_attester_v1` CPI instruction.
+pub struct AddValidatorAttesterV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator attester PDA at ["validator_attester", validator, attester]
+    pub validator_attester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator authority
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The ed25519 attester key, signing as proof of possession
+    pub attester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> AddValidatorAttesterV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: AddValidatorAttesterV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            validator_attester: accounts.validator_attester,
+            validator_profile: accounts.validator_profile,
+            validator: accounts.validator,
+            attester: accounts.attester,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(6 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_attester.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_profile.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validator.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.attester.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(AddValidatorAttesterV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(6 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.validator_attester.clone());
+        account_infos.push(self.validator_profile.clone());
+        account_infos.push(self.validator.clone());
+        account_infos.push(self.attester.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `AddValidatorAttesterV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_attester
+///   1. `[writable]` validator_profile
+///   2. `[signer]` validator
+///   3. `[signer]` attester
+///   4. `[writable, signer]` payer
+///   5. `[]` system_program
+pub struct AddValidatorAttesterV1CpiBuilder<'a, 'b> {
+    instruction: Box<AddValidatorAttesterV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> AddValidatorAttesterV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(AddValidatorAttesterV1CpiBuilderInstruction {
+            __program: program,
+            validator_attester: None,
+            validator_profile: None,
+            validator: None,
+            attester: None,
+            payer: None,
+            system_program: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The validator attester PDA at ["validator_attester", validator, attester]
+    #[inline(always)]
+    pub fn validator_attester(
+        &mut self,
+        validator_attester: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_attester = Some(validator_attester);
+        self
+    }
+    /// The validator profile PDA
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator authority
+    #[inline(always)]
+    pub fn validator(
+        &mut self,
+        validator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator = Some(validator);
+        self
+    }
+    /// The ed25519 attester key, signing as proof of possession
+    #[inline(always)]
+    pub fn attester(
+        &mut self,
+        attester: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.attester = Some(attester);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = AddValidatorAttesterV1Cpi {
+            __program: self.instruction.__program,
+
+            validator_attester: self
+                .instruction
+                .validator_attester
+                .expect("validator_attester is not set"),
+
+            validator_profile: self
+                .instruction
+                .validator_profile
+                .expect("validator_profile is not set"),
+
+            validator: self.instruction.validator.expect("validator is not set"),
+
+            attester: self.instruction.attester.expect("attester is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct AddValidatorAttesterV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    validator_attester: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_profile: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    attester: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
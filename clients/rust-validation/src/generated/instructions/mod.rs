@@ -5,6 +5,34 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#add_accepted_validator_v1;
+pub(crate) mod r#add_validator_attester_v1;
+pub(crate) mod r#initialize_validation_config_v1;
+pub(crate) mod r#reclaim_validation_request_v1;
 pub(crate) mod r#register_validation_v1;
+pub(crate) mod r#register_validator_v1;
+pub(crate) mod r#remove_accepted_validator_v1;
+pub(crate) mod r#remove_validator_attester_v1;
+pub(crate) mod r#request_validation_v1;
+pub(crate) mod r#respond_validation_attestation_v1;
+pub(crate) mod r#respond_validation_v1;
+pub(crate) mod r#slash_validator_v1;
+pub(crate) mod r#unbond_validator_stake_v1;
+pub(crate) mod r#update_validation_config_v1;
+pub(crate) mod r#withdraw_validator_stake_v1;
 
+pub use self::r#add_accepted_validator_v1::*;
+pub use self::r#add_validator_attester_v1::*;
+pub use self::r#initialize_validation_config_v1::*;
+pub use self::r#reclaim_validation_request_v1::*;
 pub use self::r#register_validation_v1::*;
+pub use self::r#register_validator_v1::*;
+pub use self::r#remove_accepted_validator_v1::*;
+pub use self::r#remove_validator_attester_v1::*;
+pub use self::r#request_validation_v1::*;
+pub use self::r#respond_validation_attestation_v1::*;
+pub use self::r#respond_validation_v1::*;
+pub use self::r#slash_validator_v1::*;
+pub use self::r#unbond_validator_stake_v1::*;
+pub use self::r#update_validation_config_v1::*;
+pub use self::r#withdraw_validator_stake_v1::*;
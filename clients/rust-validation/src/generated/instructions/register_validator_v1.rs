@@ -0,0 +1,503 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct RegisterValidatorV1 {
+    /// The validator profile PDA at ["validator_profile", validator]
+    pub validator_profile: solana_program::pubkey::Pubkey,
+    /// The validator stake vault PDA at ["validator_stake_vault", validator]
+    pub validator_stake_vault: solana_program::pubkey::Pubkey,
+    /// The validator authority, funding the stake
+    pub validator: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl RegisterValidatorV1 {
+    pub fn instruction(
+        &self,
+        args: RegisterValidatorV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: RegisterValidatorV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_profile,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_stake_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(RegisterValidatorV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RegisterValidatorV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl RegisterValidatorV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 5,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RegisterValidatorV1InstructionArgs {
+    pub stake: u64,
+}
+
+/// Instruction builder for `RegisterValidatorV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_profile
+///   1. `[writable]` validator_stake_vault
+///   2. `[writable, signer]` validator
+///   3. `[writable, signer]` payer
+///   4. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct RegisterValidatorV1Builder {
+    validator_profile: Option<solana_program::pubkey::Pubkey>,
+    validator_stake_vault: Option<solana_program::pubkey::Pubkey>,
+    validator: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    stake: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RegisterValidatorV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The validator profile PDA at ["validator_profile", validator]
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator stake vault PDA at ["validator_stake_vault", validator]
+    #[inline(always)]
+    pub fn validator_stake_vault(
+        &mut self,
+        validator_stake_vault: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_stake_vault = Some(validator_stake_vault);
+        self
+    }
+    /// The validator authority, funding the stake
+    #[inline(always)]
+    pub fn validator(&mut self, validator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.validator = Some(validator);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn stake(&mut self, stake: u64) -> &mut Self {
+        self.stake = Some(stake);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RegisterValidatorV1 {
+            validator_profile: self
+                .validator_profile
+                .expect("validator_profile is not set"),
+            validator_stake_vault: self
+                .validator_stake_vault
+                .expect("validator_stake_vault is not set"),
+            validator: self.validator.expect("validator is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = RegisterValidatorV1InstructionArgs {
+            stake: self.stake.clone().expect("stake is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `register_validator_v1` CPI accounts.
+pub struct RegisterValidatorV1CpiAccounts<'a, 'b> {
+    /// The validator profile PDA at ["validator_profile", validator]
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator stake vault PDA at ["validator_stake_vault", validator]
+    pub validator_stake_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator authority, funding the stake
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `register_validator_v1` CPI instruction.
+pub struct RegisterValidatorV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA at ["validator_profile", validator]
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator stake vault PDA at ["validator_stake_vault", validator]
+    pub validator_stake_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator authority, funding the stake
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: RegisterValidatorV1InstructionArgs,
+}
+
+impl<'a, 'b> RegisterValidatorV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RegisterValidatorV1CpiAccounts<'a, 'b>,
+        args: RegisterValidatorV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            validator_profile: accounts.validator_profile,
+            validator_stake_vault: accounts.validator_stake_vault,
+            validator: accounts.validator,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_profile.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_stake_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(RegisterValidatorV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.validator_profile.clone());
+        account_infos.push(self.validator_stake_vault.clone());
+        account_infos.push(self.validator.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RegisterValidatorV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_profile
+///   1. `[writable]` validator_stake_vault
+///   2. `[writable, signer]` validator
+///   3. `[writable, signer]` payer
+///   4. `[]` system_program
+pub struct RegisterValidatorV1CpiBuilder<'a, 'b> {
+    instruction: Box<RegisterValidatorV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RegisterValidatorV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RegisterValidatorV1CpiBuilderInstruction {
+            __program: program,
+            validator_profile: None,
+            validator_stake_vault: None,
+            validator: None,
+            payer: None,
+            system_program: None,
+            stake: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The validator profile PDA at ["validator_profile", validator]
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator stake vault PDA at ["validator_stake_vault", validator]
+    #[inline(always)]
+    pub fn validator_stake_vault(
+        &mut self,
+        validator_stake_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_stake_vault = Some(validator_stake_vault);
+        self
+    }
+    /// The validator authority, funding the stake
+    #[inline(always)]
+    pub fn validator(
+        &mut self,
+        validator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator = Some(validator);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn stake(&mut self, stake: u64) -> &mut Self {
+        self.instruction.stake = Some(stake);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = RegisterValidatorV1InstructionArgs {
+            stake: self.instruction.stake.clone().expect("stake is not set"),
+        };
+        let instruction = RegisterValidatorV1Cpi {
+            __program: self.instruction.__program,
+
+            validator_profile: self
+                .instruction
+                .validator_profile
+                .expect("validator_profile is not set"),
+
+            validator_stake_vault: self
+                .instruction
+                .validator_stake_vault
+                .expect("validator_stake_vault is not set"),
+
+            validator: self.instruction.validator.expect("validator is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RegisterValidatorV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    validator_profile: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_stake_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    stake: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,425 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct RemoveValidatorAttesterV1 {
+    /// The validator attester PDA
+    pub validator_attester: solana_program::pubkey::Pubkey,
+    /// The validator profile PDA
+    pub validator_profile: solana_program::pubkey::Pubkey,
+    /// The validator authority
+    pub validator: solana_program::pubkey::Pubkey,
+    /// The account receiving the rent
+    pub destination: solana_program::pubkey::Pubkey,
+}
+
+impl RemoveValidatorAttesterV1 {
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(&[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_attester,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_profile,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validator,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let data = borsh::to_vec(&(RemoveValidatorAttesterV1InstructionData::new())).unwrap();
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RemoveValidatorAttesterV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl RemoveValidatorAttesterV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 12,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+/// Instruction builder for `RemoveValidatorAttesterV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_attester
+///   1. `[writable]` validator_profile
+///   2. `[signer]` validator
+///   3. `[writable]` destination
+#[derive(Default)]
+pub struct RemoveValidatorAttesterV1Builder {
+    validator_attester: Option<solana_program::pubkey::Pubkey>,
+    validator_profile: Option<solana_program::pubkey::Pubkey>,
+    validator: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RemoveValidatorAttesterV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The validator attester PDA
+    #[inline(always)]
+    pub fn validator_attester(
+        &mut self,
+        validator_attester: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_attester = Some(validator_attester);
+        self
+    }
+    /// The validator profile PDA
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator authority
+    #[inline(always)]
+    pub fn validator(&mut self, validator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.validator = Some(validator);
+        self
+    }
+    /// The account receiving the rent
+    #[inline(always)]
+    pub fn destination(&mut self, destination: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RemoveValidatorAttesterV1 {
+            validator_attester: self
+                .validator_attester
+                .expect("validator_attester is not set"),
+            validator_profile: self
+                .validator_profile
+                .expect("validator_profile is not set"),
+            validator: self.validator.expect("validator is not set"),
+            destination: self.destination.expect("destination is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(&self.__remaining_accounts)
+    }
+}
+
+/// `remove_validator_attester_v1` CPI accounts.
+pub struct RemoveValidatorAttesterV1CpiAccounts<'a, 'b> {
+    /// The validator attester PDA
+    pub validator_attester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator authority
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account receiving the rent
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `remove_validator_attester_v1` CPI instruction.
+pub struct RemoveValidatorAttesterV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator attester PDA
+    pub validator_attester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator authority
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account receiving the rent
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+impl<'a, 'b> RemoveValidatorAttesterV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RemoveValidatorAttesterV1CpiAccounts<'a, 'b>,
+    ) -> Self {
+        Self {
+            __program: program,
+            validator_attester: accounts.validator_attester,
+            validator_profile: accounts.validator_profile,
+            validator: accounts.validator,
+            destination: accounts.destination,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_attester.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_profile.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validator.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let data = borsh::to_vec(&(RemoveValidatorAttesterV1InstructionData::new())).unwrap();
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.validator_attester.clone());
+        account_infos.push(self.validator_profile.clone());
+        account_infos.push(self.validator.clone());
+        account_infos.push(self.destination.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RemoveValidatorAttesterV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_attester
+///   1. `[writable]` validator_profile
+///   2. `[signer]` validator
+///   3. `[writable]` destination
+pub struct RemoveValidatorAttesterV1CpiBuilder<'a, 'b> {
+    instruction: Box<RemoveValidatorAttesterV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RemoveValidatorAttesterV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RemoveValidatorAttesterV1CpiBuilderInstruction {
+            __program: program,
+            validator_attester: None,
+            validator_profile: None,
+            validator: None,
+            destination: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The validator attester PDA
+    #[inline(always)]
+    pub fn validator_attester(
+        &mut self,
+        validator_attester: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_attester = Some(validator_attester);
+        self
+    }
+    /// The validator profile PDA
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator authority
+    #[inline(always)]
+    pub fn validator(
+        &mut self,
+        validator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator = Some(validator);
+        self
+    }
+    /// The account receiving the rent
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination = Some(destination);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let instruction = RemoveValidatorAttesterV1Cpi {
+            __program: self.instruction.__program,
+
+            validator_attester: self
+                .instruction
+                .validator_attester
+                .expect("validator_attester is not set"),
+
+            validator_profile: self
+                .instruction
+                .validator_profile
+                .expect("validator_profile is not set"),
+
+            validator: self.instruction.validator.expect("validator is not set"),
+
+            destination: self
+                .instruction
+                .destination
+                .expect("destination is not set"),
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RemoveValidatorAttesterV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    validator_attester: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_profile: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,724 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::pubkey::Pubkey;
+
+/// Accounts.
+pub struct RequestValidationV1 {
+    /// The validation request PDA at ["validation_request", asset, nonce_le]
+    pub validation_request: solana_program::pubkey::Pubkey,
+    /// The agent validation PDA of the asset
+    pub agent_validation: solana_program::pubkey::Pubkey,
+    /// The validator profile PDA of the requested validator
+    pub validator_profile: solana_program::pubkey::Pubkey,
+    /// The accepted validator PDA of the requested validator, if the agent whitelists validators
+    pub accepted_validator: Option<solana_program::pubkey::Pubkey>,
+    /// The address of the Core asset
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The agent or client requesting the validation, funding the reward
+    pub requester: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+}
+
+impl RequestValidationV1 {
+    pub fn instruction(
+        &self,
+        args: RequestValidationV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: RequestValidationV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validation_request,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.agent_validation,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validator_profile,
+            false,
+        ));
+        if let Some(accepted_validator) = self.accepted_validator {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                accepted_validator,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.asset, false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.requester,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(RequestValidationV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RequestValidationV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl RequestValidationV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 1,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RequestValidationV1InstructionArgs {
+    pub nonce: u64,
+    pub work_hash: [u8; 32],
+    #[cfg_attr(
+        feature = "serde",
+        serde(with = "serde_with::As::<serde_with::DisplayFromStr>")
+    )]
+    pub validator: Pubkey,
+    pub reward: u64,
+    pub expiry_slot: u64,
+}
+
+/// Instruction builder for `RequestValidationV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validation_request
+///   1. `[]` agent_validation
+///   2. `[]` validator_profile
+///   3. `[optional]` accepted_validator
+///   4. `[]` asset
+///   5. `[writable, signer]` requester
+///   6. `[writable, signer]` payer
+///   7. `[optional]` system_program (default to `11111111111111111111111111111111`)
+#[derive(Default)]
+pub struct RequestValidationV1Builder {
+    validation_request: Option<solana_program::pubkey::Pubkey>,
+    agent_validation: Option<solana_program::pubkey::Pubkey>,
+    validator_profile: Option<solana_program::pubkey::Pubkey>,
+    accepted_validator: Option<solana_program::pubkey::Pubkey>,
+    asset: Option<solana_program::pubkey::Pubkey>,
+    requester: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    nonce: Option<u64>,
+    work_hash: Option<[u8; 32]>,
+    validator: Option<Pubkey>,
+    reward: Option<u64>,
+    expiry_slot: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RequestValidationV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The validation request PDA at ["validation_request", asset, nonce_le]
+    #[inline(always)]
+    pub fn validation_request(
+        &mut self,
+        validation_request: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validation_request = Some(validation_request);
+        self
+    }
+    /// The agent validation PDA of the asset
+    #[inline(always)]
+    pub fn agent_validation(
+        &mut self,
+        agent_validation: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.agent_validation = Some(agent_validation);
+        self
+    }
+    /// The validator profile PDA of the requested validator
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_profile = Some(validator_profile);
+        self
+    }
+    /// `[optional account]`
+    /// The accepted validator PDA of the requested validator, if the agent whitelists validators
+    #[inline(always)]
+    pub fn accepted_validator(
+        &mut self,
+        accepted_validator: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.accepted_validator = accepted_validator;
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// The agent or client requesting the validation, funding the reward
+    #[inline(always)]
+    pub fn requester(&mut self, requester: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.requester = Some(requester);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn work_hash(&mut self, work_hash: [u8; 32]) -> &mut Self {
+        self.work_hash = Some(work_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn validator(&mut self, validator: Pubkey) -> &mut Self {
+        self.validator = Some(validator);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn reward(&mut self, reward: u64) -> &mut Self {
+        self.reward = Some(reward);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn expiry_slot(&mut self, expiry_slot: u64) -> &mut Self {
+        self.expiry_slot = Some(expiry_slot);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RequestValidationV1 {
+            validation_request: self
+                .validation_request
+                .expect("validation_request is not set"),
+            agent_validation: self.agent_validation.expect("agent_validation is not set"),
+            validator_profile: self
+                .validator_profile
+                .expect("validator_profile is not set"),
+            accepted_validator: self.accepted_validator,
+            asset: self.asset.expect("asset is not set"),
+            requester: self.requester.expect("requester is not set"),
+            payer: self.payer.expect("payer is not set"),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+        };
+        let args = RequestValidationV1InstructionArgs {
+            nonce: self.nonce.clone().expect("nonce is not set"),
+            work_hash: self.work_hash.clone().expect("work_hash is not set"),
+            validator: self.validator.clone().expect("validator is not set"),
+            reward: self.reward.clone().unwrap_or(0),
+            expiry_slot: self.expiry_slot.clone().unwrap_or(0),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `request_validation_v1` CPI accounts.
+pub struct RequestValidationV1CpiAccounts<'a, 'b> {
+    /// The validation request PDA at ["validation_request", asset, nonce_le]
+    pub validation_request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent validation PDA of the asset
+    pub agent_validation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA of the requested validator
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The accepted validator PDA of the requested validator, if the agent whitelists validators
+    pub accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent or client requesting the validation, funding the reward
+    pub requester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `request_validation_v1` CPI instruction.
+pub struct RequestValidationV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validation request PDA at ["validation_request", asset, nonce_le]
+    pub validation_request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent validation PDA of the asset
+    pub agent_validation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA of the requested validator
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The accepted validator PDA of the requested validator, if the agent whitelists validators
+    pub accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent or client requesting the validation, funding the reward
+    pub requester: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: RequestValidationV1InstructionArgs,
+}
+
+impl<'a, 'b> RequestValidationV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RequestValidationV1CpiAccounts<'a, 'b>,
+        args: RequestValidationV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            validation_request: accounts.validation_request,
+            agent_validation: accounts.agent_validation,
+            validator_profile: accounts.validator_profile,
+            accepted_validator: accounts.accepted_validator,
+            asset: accounts.asset,
+            requester: accounts.requester,
+            payer: accounts.payer,
+            system_program: accounts.system_program,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(8 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validation_request.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.agent_validation.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validator_profile.key,
+            false,
+        ));
+        if let Some(accepted_validator) = self.accepted_validator {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *accepted_validator.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.asset.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.requester.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(RequestValidationV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(8 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.validation_request.clone());
+        account_infos.push(self.agent_validation.clone());
+        account_infos.push(self.validator_profile.clone());
+        if let Some(accepted_validator) = self.accepted_validator {
+            account_infos.push(accepted_validator.clone());
+        }
+        account_infos.push(self.asset.clone());
+        account_infos.push(self.requester.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.system_program.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RequestValidationV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validation_request
+///   1. `[]` agent_validation
+///   2. `[]` validator_profile
+///   3. `[optional]` accepted_validator
+///   4. `[]` asset
+///   5. `[writable, signer]` requester
+///   6. `[writable, signer]` payer
+///   7. `[]` system_program
+pub struct RequestValidationV1CpiBuilder<'a, 'b> {
+    instruction: Box<RequestValidationV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RequestValidationV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RequestValidationV1CpiBuilderInstruction {
+            __program: program,
+            validation_request: None,
+            agent_validation: None,
+            validator_profile: None,
+            accepted_validator: None,
+            asset: None,
+            requester: None,
+            payer: None,
+            system_program: None,
+            nonce: None,
+            work_hash: None,
+            validator: None,
+            reward: None,
+            expiry_slot: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The validation request PDA at ["validation_request", asset, nonce_le]
+    #[inline(always)]
+    pub fn validation_request(
+        &mut self,
+        validation_request: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validation_request = Some(validation_request);
+        self
+    }
+    /// The agent validation PDA of the asset
+    #[inline(always)]
+    pub fn agent_validation(
+        &mut self,
+        agent_validation: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_validation = Some(agent_validation);
+        self
+    }
+    /// The validator profile PDA of the requested validator
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_profile = Some(validator_profile);
+        self
+    }
+    /// `[optional account]`
+    /// The accepted validator PDA of the requested validator, if the agent whitelists validators
+    #[inline(always)]
+    pub fn accepted_validator(
+        &mut self,
+        accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.accepted_validator = accepted_validator;
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// The agent or client requesting the validation, funding the reward
+    #[inline(always)]
+    pub fn requester(
+        &mut self,
+        requester: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.requester = Some(requester);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    #[inline(always)]
+    pub fn nonce(&mut self, nonce: u64) -> &mut Self {
+        self.instruction.nonce = Some(nonce);
+        self
+    }
+    #[inline(always)]
+    pub fn work_hash(&mut self, work_hash: [u8; 32]) -> &mut Self {
+        self.instruction.work_hash = Some(work_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn validator(&mut self, validator: Pubkey) -> &mut Self {
+        self.instruction.validator = Some(validator);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn reward(&mut self, reward: u64) -> &mut Self {
+        self.instruction.reward = Some(reward);
+        self
+    }
+    /// `[optional argument, defaults to '0']`
+    #[inline(always)]
+    pub fn expiry_slot(&mut self, expiry_slot: u64) -> &mut Self {
+        self.instruction.expiry_slot = Some(expiry_slot);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = RequestValidationV1InstructionArgs {
+            nonce: self.instruction.nonce.clone().expect("nonce is not set"),
+            work_hash: self
+                .instruction
+                .work_hash
+                .clone()
+                .expect("work_hash is not set"),
+            validator: self
+                .instruction
+                .validator
+                .clone()
+                .expect("validator is not set"),
+            reward: self.instruction.reward.clone().unwrap_or(0),
+            expiry_slot: self.instruction.expiry_slot.clone().unwrap_or(0),
+        };
+        let instruction = RequestValidationV1Cpi {
+            __program: self.instruction.__program,
+
+            validation_request: self
+                .instruction
+                .validation_request
+                .expect("validation_request is not set"),
+
+            agent_validation: self
+                .instruction
+                .agent_validation
+                .expect("agent_validation is not set"),
+
+            validator_profile: self
+                .instruction
+                .validator_profile
+                .expect("validator_profile is not set"),
+
+            accepted_validator: self.instruction.accepted_validator,
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            requester: self.instruction.requester.expect("requester is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RequestValidationV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    validation_request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    agent_validation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_profile: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    requester: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    nonce: Option<u64>,
+    work_hash: Option<[u8; 32]>,
+    validator: Option<Pubkey>,
+    reward: Option<u64>,
+    expiry_slot: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,837 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct RespondValidationV1 {
+    /// The validation request PDA
+    pub validation_request: solana_program::pubkey::Pubkey,
+    /// The agent validation PDA of the asset
+    pub agent_validation: solana_program::pubkey::Pubkey,
+    /// The validator history PDA at ["validator_history", asset, validator]
+    pub validator_history: solana_program::pubkey::Pubkey,
+    /// The address of the Core asset
+    pub asset: solana_program::pubkey::Pubkey,
+    /// The address of the collection
+    pub collection: Option<solana_program::pubkey::Pubkey>,
+    /// The validator designated in the request, receiving the reward
+    pub validator: solana_program::pubkey::Pubkey,
+    /// The payer for additional rent
+    pub payer: solana_program::pubkey::Pubkey,
+    /// The MPL Core program
+    pub mpl_core_program: solana_program::pubkey::Pubkey,
+    /// The system program
+    pub system_program: solana_program::pubkey::Pubkey,
+    /// The validator profile PDA of the validator
+    pub validator_profile: solana_program::pubkey::Pubkey,
+    /// The accepted validator PDA at ["accepted_validator", asset, validator], required if the agent accepts any validator
+    pub accepted_validator: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl RespondValidationV1 {
+    pub fn instruction(
+        &self,
+        args: RespondValidationV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: RespondValidationV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validation_request,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.agent_validation,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_history,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.asset, false,
+        ));
+        if let Some(collection) = self.collection {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                collection, false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.payer, true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.mpl_core_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.system_program,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validator_profile,
+            false,
+        ));
+        if let Some(accepted_validator) = self.accepted_validator {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                accepted_validator,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(RespondValidationV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct RespondValidationV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 6],
+}
+
+impl RespondValidationV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 2,
+            padding: [0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct RespondValidationV1InstructionArgs {
+    pub result: u8,
+    pub evidence_hash: [u8; 32],
+    pub evidence_uri: String,
+}
+
+/// Instruction builder for `RespondValidationV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validation_request
+///   1. `[writable]` agent_validation
+///   2. `[writable]` validator_history
+///   3. `[writable]` asset
+///   4. `[writable, optional]` collection
+///   5. `[writable, signer]` validator
+///   6. `[writable, signer]` payer
+///   7. `[optional]` mpl_core_program (default to `CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d`)
+///   8. `[optional]` system_program (default to `11111111111111111111111111111111`)
+///   9. `[]` validator_profile
+///   10. `[optional]` accepted_validator
+#[derive(Default)]
+pub struct RespondValidationV1Builder {
+    validation_request: Option<solana_program::pubkey::Pubkey>,
+    agent_validation: Option<solana_program::pubkey::Pubkey>,
+    validator_history: Option<solana_program::pubkey::Pubkey>,
+    asset: Option<solana_program::pubkey::Pubkey>,
+    collection: Option<solana_program::pubkey::Pubkey>,
+    validator: Option<solana_program::pubkey::Pubkey>,
+    payer: Option<solana_program::pubkey::Pubkey>,
+    mpl_core_program: Option<solana_program::pubkey::Pubkey>,
+    system_program: Option<solana_program::pubkey::Pubkey>,
+    validator_profile: Option<solana_program::pubkey::Pubkey>,
+    accepted_validator: Option<solana_program::pubkey::Pubkey>,
+    result: Option<u8>,
+    evidence_hash: Option<[u8; 32]>,
+    evidence_uri: Option<String>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl RespondValidationV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The validation request PDA
+    #[inline(always)]
+    pub fn validation_request(
+        &mut self,
+        validation_request: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validation_request = Some(validation_request);
+        self
+    }
+    /// The agent validation PDA of the asset
+    #[inline(always)]
+    pub fn agent_validation(
+        &mut self,
+        agent_validation: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.agent_validation = Some(agent_validation);
+        self
+    }
+    /// The validator history PDA at ["validator_history", asset, validator]
+    #[inline(always)]
+    pub fn validator_history(
+        &mut self,
+        validator_history: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_history = Some(validator_history);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.asset = Some(asset);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the collection
+    #[inline(always)]
+    pub fn collection(&mut self, collection: Option<solana_program::pubkey::Pubkey>) -> &mut Self {
+        self.collection = collection;
+        self
+    }
+    /// The validator designated in the request, receiving the reward
+    #[inline(always)]
+    pub fn validator(&mut self, validator: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.validator = Some(validator);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.payer = Some(payer);
+        self
+    }
+    /// `[optional account, default to 'CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d']`
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// `[optional account, default to '11111111111111111111111111111111']`
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(&mut self, system_program: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.system_program = Some(system_program);
+        self
+    }
+    /// The validator profile PDA of the validator
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_profile = Some(validator_profile);
+        self
+    }
+    /// `[optional account]`
+    /// The accepted validator PDA at ["accepted_validator", asset, validator], required if the agent accepts any validator
+    #[inline(always)]
+    pub fn accepted_validator(
+        &mut self,
+        accepted_validator: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.accepted_validator = accepted_validator;
+        self
+    }
+    #[inline(always)]
+    pub fn result(&mut self, result: u8) -> &mut Self {
+        self.result = Some(result);
+        self
+    }
+    #[inline(always)]
+    pub fn evidence_hash(&mut self, evidence_hash: [u8; 32]) -> &mut Self {
+        self.evidence_hash = Some(evidence_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn evidence_uri(&mut self, evidence_uri: String) -> &mut Self {
+        self.evidence_uri = Some(evidence_uri);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = RespondValidationV1 {
+            validation_request: self
+                .validation_request
+                .expect("validation_request is not set"),
+            agent_validation: self.agent_validation.expect("agent_validation is not set"),
+            validator_history: self
+                .validator_history
+                .expect("validator_history is not set"),
+            asset: self.asset.expect("asset is not set"),
+            collection: self.collection,
+            validator: self.validator.expect("validator is not set"),
+            payer: self.payer.expect("payer is not set"),
+            mpl_core_program: self.mpl_core_program.unwrap_or(solana_program::pubkey!(
+                "CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d"
+            )),
+            system_program: self
+                .system_program
+                .unwrap_or(solana_program::pubkey!("11111111111111111111111111111111")),
+            validator_profile: self
+                .validator_profile
+                .expect("validator_profile is not set"),
+            accepted_validator: self.accepted_validator,
+        };
+        let args = RespondValidationV1InstructionArgs {
+            result: self.result.clone().expect("result is not set"),
+            evidence_hash: self
+                .evidence_hash
+                .clone()
+                .expect("evidence_hash is not set"),
+            evidence_uri: self.evidence_uri.clone().expect("evidence_uri is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `respond_validation_v1` CPI accounts.
+pub struct RespondValidationV1CpiAccounts<'a, 'b> {
+    /// The validation request PDA
+    pub validation_request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent validation PDA of the asset
+    pub agent_validation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator history PDA at ["validator_history", asset, validator]
+    pub validator_history: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the collection
+    pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The validator designated in the request, receiving the reward
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA of the validator
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The accepted validator PDA at ["accepted_validator", asset, validator], required if the agent accepts any validator
+    pub accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `respond_validation_v1` CPI instruction.
+pub struct RespondValidationV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validation request PDA
+    pub validation_request: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The agent validation PDA of the asset
+    pub agent_validation: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator history PDA at ["validator_history", asset, validator]
+    pub validator_history: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the Core asset
+    pub asset: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The address of the collection
+    pub collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The validator designated in the request, receiving the reward
+    pub validator: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The payer for additional rent
+    pub payer: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The MPL Core program
+    pub mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The system program
+    pub system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA of the validator
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The accepted validator PDA at ["accepted_validator", asset, validator], required if the agent accepts any validator
+    pub accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: RespondValidationV1InstructionArgs,
+}
+
+impl<'a, 'b> RespondValidationV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: RespondValidationV1CpiAccounts<'a, 'b>,
+        args: RespondValidationV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            validation_request: accounts.validation_request,
+            agent_validation: accounts.agent_validation,
+            validator_history: accounts.validator_history,
+            asset: accounts.asset,
+            collection: accounts.collection,
+            validator: accounts.validator,
+            payer: accounts.payer,
+            mpl_core_program: accounts.mpl_core_program,
+            system_program: accounts.system_program,
+            validator_profile: accounts.validator_profile,
+            accepted_validator: accounts.accepted_validator,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(11 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validation_request.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.agent_validation.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_history.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.asset.key,
+            false,
+        ));
+        if let Some(collection) = self.collection {
+            accounts.push(solana_program::instruction::AccountMeta::new(
+                *collection.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.payer.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.mpl_core_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.system_program.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validator_profile.key,
+            false,
+        ));
+        if let Some(accepted_validator) = self.accepted_validator {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *accepted_validator.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(RespondValidationV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(11 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.validation_request.clone());
+        account_infos.push(self.agent_validation.clone());
+        account_infos.push(self.validator_history.clone());
+        account_infos.push(self.asset.clone());
+        if let Some(collection) = self.collection {
+            account_infos.push(collection.clone());
+        }
+        account_infos.push(self.validator.clone());
+        account_infos.push(self.payer.clone());
+        account_infos.push(self.mpl_core_program.clone());
+        account_infos.push(self.system_program.clone());
+        account_infos.push(self.validator_profile.clone());
+        if let Some(accepted_validator) = self.accepted_validator {
+            account_infos.push(accepted_validator.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `RespondValidationV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validation_request
+///   1. `[writable]` agent_validation
+///   2. `[writable]` validator_history
+///   3. `[writable]` asset
+///   4. `[writable, optional]` collection
+///   5. `[writable, signer]` validator
+///   6. `[writable, signer]` payer
+///   7. `[]` mpl_core_program
+///   8. `[]` system_program
+///   9. `[]` validator_profile
+///   10. `[optional]` accepted_validator
+pub struct RespondValidationV1CpiBuilder<'a, 'b> {
+    instruction: Box<RespondValidationV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> RespondValidationV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(RespondValidationV1CpiBuilderInstruction {
+            __program: program,
+            validation_request: None,
+            agent_validation: None,
+            validator_history: None,
+            asset: None,
+            collection: None,
+            validator: None,
+            payer: None,
+            mpl_core_program: None,
+            system_program: None,
+            validator_profile: None,
+            accepted_validator: None,
+            result: None,
+            evidence_hash: None,
+            evidence_uri: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The validation request PDA
+    #[inline(always)]
+    pub fn validation_request(
+        &mut self,
+        validation_request: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validation_request = Some(validation_request);
+        self
+    }
+    /// The agent validation PDA of the asset
+    #[inline(always)]
+    pub fn agent_validation(
+        &mut self,
+        agent_validation: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.agent_validation = Some(agent_validation);
+        self
+    }
+    /// The validator history PDA at ["validator_history", asset, validator]
+    #[inline(always)]
+    pub fn validator_history(
+        &mut self,
+        validator_history: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_history = Some(validator_history);
+        self
+    }
+    /// The address of the Core asset
+    #[inline(always)]
+    pub fn asset(&mut self, asset: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.asset = Some(asset);
+        self
+    }
+    /// `[optional account]`
+    /// The address of the collection
+    #[inline(always)]
+    pub fn collection(
+        &mut self,
+        collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.collection = collection;
+        self
+    }
+    /// The validator designated in the request, receiving the reward
+    #[inline(always)]
+    pub fn validator(
+        &mut self,
+        validator: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator = Some(validator);
+        self
+    }
+    /// The payer for additional rent
+    #[inline(always)]
+    pub fn payer(&mut self, payer: &'b solana_program::account_info::AccountInfo<'a>) -> &mut Self {
+        self.instruction.payer = Some(payer);
+        self
+    }
+    /// The MPL Core program
+    #[inline(always)]
+    pub fn mpl_core_program(
+        &mut self,
+        mpl_core_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.mpl_core_program = Some(mpl_core_program);
+        self
+    }
+    /// The system program
+    #[inline(always)]
+    pub fn system_program(
+        &mut self,
+        system_program: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.system_program = Some(system_program);
+        self
+    }
+    /// The validator profile PDA of the validator
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_profile = Some(validator_profile);
+        self
+    }
+    /// `[optional account]`
+    /// The accepted validator PDA at ["accepted_validator", asset, validator], required if the agent accepts any validator
+    #[inline(always)]
+    pub fn accepted_validator(
+        &mut self,
+        accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.accepted_validator = accepted_validator;
+        self
+    }
+    #[inline(always)]
+    pub fn result(&mut self, result: u8) -> &mut Self {
+        self.instruction.result = Some(result);
+        self
+    }
+    #[inline(always)]
+    pub fn evidence_hash(&mut self, evidence_hash: [u8; 32]) -> &mut Self {
+        self.instruction.evidence_hash = Some(evidence_hash);
+        self
+    }
+    #[inline(always)]
+    pub fn evidence_uri(&mut self, evidence_uri: String) -> &mut Self {
+        self.instruction.evidence_uri = Some(evidence_uri);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = RespondValidationV1InstructionArgs {
+            result: self.instruction.result.clone().expect("result is not set"),
+            evidence_hash: self
+                .instruction
+                .evidence_hash
+                .clone()
+                .expect("evidence_hash is not set"),
+            evidence_uri: self
+                .instruction
+                .evidence_uri
+                .clone()
+                .expect("evidence_uri is not set"),
+        };
+        let instruction = RespondValidationV1Cpi {
+            __program: self.instruction.__program,
+
+            validation_request: self
+                .instruction
+                .validation_request
+                .expect("validation_request is not set"),
+
+            agent_validation: self
+                .instruction
+                .agent_validation
+                .expect("agent_validation is not set"),
+
+            validator_history: self
+                .instruction
+                .validator_history
+                .expect("validator_history is not set"),
+
+            asset: self.instruction.asset.expect("asset is not set"),
+
+            collection: self.instruction.collection,
+
+            validator: self.instruction.validator.expect("validator is not set"),
+
+            payer: self.instruction.payer.expect("payer is not set"),
+
+            mpl_core_program: self
+                .instruction
+                .mpl_core_program
+                .expect("mpl_core_program is not set"),
+
+            system_program: self
+                .instruction
+                .system_program
+                .expect("system_program is not set"),
+
+            validator_profile: self
+                .instruction
+                .validator_profile
+                .expect("validator_profile is not set"),
+
+            accepted_validator: self.instruction.accepted_validator,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct RespondValidationV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    validation_request: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    agent_validation: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_history: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    asset: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    collection: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    payer: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    mpl_core_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    system_program: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_profile: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    accepted_validator: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    result: Option<u8>,
+    evidence_hash: Option<[u8; 32]>,
+    evidence_uri: Option<String>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,512 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct SlashValidatorV1 {
+    /// The validator profile PDA
+    pub validator_profile: solana_program::pubkey::Pubkey,
+    /// The validator stake vault PDA
+    pub validator_stake_vault: solana_program::pubkey::Pubkey,
+    /// The validation config PDA at ["validation_config"]
+    pub validation_config: solana_program::pubkey::Pubkey,
+    /// The arbiter of the validation config
+    pub arbiter: solana_program::pubkey::Pubkey,
+    /// The account receiving the slashed stake
+    pub destination: solana_program::pubkey::Pubkey,
+}
+
+impl SlashValidatorV1 {
+    pub fn instruction(
+        &self,
+        args: SlashValidatorV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: SlashValidatorV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_profile,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validator_stake_vault,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.validation_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.arbiter,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.destination,
+            false,
+        ));
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(SlashValidatorV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct SlashValidatorV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl SlashValidatorV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 8,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct SlashValidatorV1InstructionArgs {
+    pub amount: u64,
+}
+
+/// Instruction builder for `SlashValidatorV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_profile
+///   1. `[writable]` validator_stake_vault
+///   2. `[]` validation_config
+///   3. `[signer]` arbiter
+///   4. `[writable]` destination
+#[derive(Default)]
+pub struct SlashValidatorV1Builder {
+    validator_profile: Option<solana_program::pubkey::Pubkey>,
+    validator_stake_vault: Option<solana_program::pubkey::Pubkey>,
+    validation_config: Option<solana_program::pubkey::Pubkey>,
+    arbiter: Option<solana_program::pubkey::Pubkey>,
+    destination: Option<solana_program::pubkey::Pubkey>,
+    amount: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl SlashValidatorV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The validator profile PDA
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator stake vault PDA
+    #[inline(always)]
+    pub fn validator_stake_vault(
+        &mut self,
+        validator_stake_vault: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validator_stake_vault = Some(validator_stake_vault);
+        self
+    }
+    /// The validation config PDA at ["validation_config"]
+    #[inline(always)]
+    pub fn validation_config(
+        &mut self,
+        validation_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validation_config = Some(validation_config);
+        self
+    }
+    /// The arbiter of the validation config
+    #[inline(always)]
+    pub fn arbiter(&mut self, arbiter: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.arbiter = Some(arbiter);
+        self
+    }
+    /// The account receiving the slashed stake
+    #[inline(always)]
+    pub fn destination(&mut self, destination: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.destination = Some(destination);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.amount = Some(amount);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = SlashValidatorV1 {
+            validator_profile: self
+                .validator_profile
+                .expect("validator_profile is not set"),
+            validator_stake_vault: self
+                .validator_stake_vault
+                .expect("validator_stake_vault is not set"),
+            validation_config: self
+                .validation_config
+                .expect("validation_config is not set"),
+            arbiter: self.arbiter.expect("arbiter is not set"),
+            destination: self.destination.expect("destination is not set"),
+        };
+        let args = SlashValidatorV1InstructionArgs {
+            amount: self.amount.clone().expect("amount is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `slash_validator_v1` CPI accounts.
+pub struct SlashValidatorV1CpiAccounts<'a, 'b> {
+    /// The validator profile PDA
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator stake vault PDA
+    pub validator_stake_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validation config PDA at ["validation_config"]
+    pub validation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arbiter of the validation config
+    pub arbiter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account receiving the slashed stake
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+}
+
+/// `slash_validator_v1` CPI instruction.
+pub struct SlashValidatorV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator profile PDA
+    pub validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validator stake vault PDA
+    pub validator_stake_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validation config PDA at ["validation_config"]
+    pub validation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arbiter of the validation config
+    pub arbiter: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The account receiving the slashed stake
+    pub destination: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The arguments for the instruction.
+    pub __args: SlashValidatorV1InstructionArgs,
+}
+
+impl<'a, 'b> SlashValidatorV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: SlashValidatorV1CpiAccounts<'a, 'b>,
+        args: SlashValidatorV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            validator_profile: accounts.validator_profile,
+            validator_stake_vault: accounts.validator_stake_vault,
+            validation_config: accounts.validation_config,
+            arbiter: accounts.arbiter,
+            destination: accounts.destination,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(5 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_profile.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validator_stake_vault.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.validation_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.arbiter.key,
+            true,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.destination.key,
+            false,
+        ));
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(SlashValidatorV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(5 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.validator_profile.clone());
+        account_infos.push(self.validator_stake_vault.clone());
+        account_infos.push(self.validation_config.clone());
+        account_infos.push(self.arbiter.clone());
+        account_infos.push(self.destination.clone());
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `SlashValidatorV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validator_profile
+///   1. `[writable]` validator_stake_vault
+///   2. `[]` validation_config
+///   3. `[signer]` arbiter
+///   4. `[writable]` destination
+pub struct SlashValidatorV1CpiBuilder<'a, 'b> {
+    instruction: Box<SlashValidatorV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> SlashValidatorV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(SlashValidatorV1CpiBuilderInstruction {
+            __program: program,
+            validator_profile: None,
+            validator_stake_vault: None,
+            validation_config: None,
+            arbiter: None,
+            destination: None,
+            amount: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The validator profile PDA
+    #[inline(always)]
+    pub fn validator_profile(
+        &mut self,
+        validator_profile: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_profile = Some(validator_profile);
+        self
+    }
+    /// The validator stake vault PDA
+    #[inline(always)]
+    pub fn validator_stake_vault(
+        &mut self,
+        validator_stake_vault: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validator_stake_vault = Some(validator_stake_vault);
+        self
+    }
+    /// The validation config PDA at ["validation_config"]
+    #[inline(always)]
+    pub fn validation_config(
+        &mut self,
+        validation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validation_config = Some(validation_config);
+        self
+    }
+    /// The arbiter of the validation config
+    #[inline(always)]
+    pub fn arbiter(
+        &mut self,
+        arbiter: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.arbiter = Some(arbiter);
+        self
+    }
+    /// The account receiving the slashed stake
+    #[inline(always)]
+    pub fn destination(
+        &mut self,
+        destination: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.destination = Some(destination);
+        self
+    }
+    #[inline(always)]
+    pub fn amount(&mut self, amount: u64) -> &mut Self {
+        self.instruction.amount = Some(amount);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = SlashValidatorV1InstructionArgs {
+            amount: self.instruction.amount.clone().expect("amount is not set"),
+        };
+        let instruction = SlashValidatorV1Cpi {
+            __program: self.instruction.__program,
+
+            validator_profile: self
+                .instruction
+                .validator_profile
+                .expect("validator_profile is not set"),
+
+            validator_stake_vault: self
+                .instruction
+                .validator_stake_vault
+                .expect("validator_stake_vault is not set"),
+
+            validation_config: self
+                .instruction
+                .validation_config
+                .expect("validation_config is not set"),
+
+            arbiter: self.instruction.arbiter.expect("arbiter is not set"),
+
+            destination: self
+                .instruction
+                .destination
+                .expect("destination is not set"),
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct SlashValidatorV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    validator_profile: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validator_stake_vault: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    validation_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    arbiter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    destination: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    amount: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
@@ -0,0 +1,503 @@
+//! This code was AUTOGENERATED using the kinobi library.
+//! Please DO NOT EDIT THIS FILE, instead use visitors
+//! to add features, then rerun kinobi to update it.
+//!
+//! [https://github.com/metaplex-foundation/kinobi]
+//!
+
+#[cfg(feature = "anchor")]
+use anchor_lang::prelude::{AnchorDeserialize, AnchorSerialize};
+#[cfg(not(feature = "anchor"))]
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Accounts.
+pub struct UpdateValidationConfigV1 {
+    /// The validation config PDA at ["validation_config"]
+    pub validation_config: solana_program::pubkey::Pubkey,
+    /// The config authority
+    pub authority: solana_program::pubkey::Pubkey,
+    /// The new config authority, if it changes
+    pub new_authority: Option<solana_program::pubkey::Pubkey>,
+    /// The new arbiter, if it changes
+    pub new_arbiter: Option<solana_program::pubkey::Pubkey>,
+}
+
+impl UpdateValidationConfigV1 {
+    pub fn instruction(
+        &self,
+        args: UpdateValidationConfigV1InstructionArgs,
+    ) -> solana_program::instruction::Instruction {
+        self.instruction_with_remaining_accounts(args, &[])
+    }
+    #[allow(clippy::vec_init_then_push)]
+    pub fn instruction_with_remaining_accounts(
+        &self,
+        args: UpdateValidationConfigV1InstructionArgs,
+        remaining_accounts: &[solana_program::instruction::AccountMeta],
+    ) -> solana_program::instruction::Instruction {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            self.validation_config,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            self.authority,
+            true,
+        ));
+        if let Some(new_authority) = self.new_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                new_authority,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        if let Some(new_arbiter) = self.new_arbiter {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                new_arbiter,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        accounts.extend_from_slice(remaining_accounts);
+        let mut data = borsh::to_vec(&(UpdateValidationConfigV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&args).unwrap();
+        data.append(&mut args);
+
+        solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+pub struct UpdateValidationConfigV1InstructionData {
+    discriminator: u8,
+    padding: [u8; 7],
+}
+
+impl UpdateValidationConfigV1InstructionData {
+    pub fn new() -> Self {
+        Self {
+            discriminator: 4,
+            padding: [0, 0, 0, 0, 0, 0, 0],
+        }
+    }
+}
+
+#[cfg_attr(not(feature = "anchor"), derive(BorshSerialize, BorshDeserialize))]
+#[cfg_attr(feature = "anchor", derive(AnchorSerialize, AnchorDeserialize))]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct UpdateValidationConfigV1InstructionArgs {
+    pub unbonding_slots: u64,
+}
+
+/// Instruction builder for `UpdateValidationConfigV1`.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validation_config
+///   1. `[signer]` authority
+///   2. `[optional]` new_authority
+///   3. `[optional]` new_arbiter
+#[derive(Default)]
+pub struct UpdateValidationConfigV1Builder {
+    validation_config: Option<solana_program::pubkey::Pubkey>,
+    authority: Option<solana_program::pubkey::Pubkey>,
+    new_authority: Option<solana_program::pubkey::Pubkey>,
+    new_arbiter: Option<solana_program::pubkey::Pubkey>,
+    unbonding_slots: Option<u64>,
+    __remaining_accounts: Vec<solana_program::instruction::AccountMeta>,
+}
+
+impl UpdateValidationConfigV1Builder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+    /// The validation config PDA at ["validation_config"]
+    #[inline(always)]
+    pub fn validation_config(
+        &mut self,
+        validation_config: solana_program::pubkey::Pubkey,
+    ) -> &mut Self {
+        self.validation_config = Some(validation_config);
+        self
+    }
+    /// The config authority
+    #[inline(always)]
+    pub fn authority(&mut self, authority: solana_program::pubkey::Pubkey) -> &mut Self {
+        self.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// The new config authority, if it changes
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.new_authority = new_authority;
+        self
+    }
+    /// `[optional account]`
+    /// The new arbiter, if it changes
+    #[inline(always)]
+    pub fn new_arbiter(
+        &mut self,
+        new_arbiter: Option<solana_program::pubkey::Pubkey>,
+    ) -> &mut Self {
+        self.new_arbiter = new_arbiter;
+        self
+    }
+    #[inline(always)]
+    pub fn unbonding_slots(&mut self, unbonding_slots: u64) -> &mut Self {
+        self.unbonding_slots = Some(unbonding_slots);
+        self
+    }
+    /// Add an aditional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: solana_program::instruction::AccountMeta,
+    ) -> &mut Self {
+        self.__remaining_accounts.push(account);
+        self
+    }
+    /// Add additional accounts to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[solana_program::instruction::AccountMeta],
+    ) -> &mut Self {
+        self.__remaining_accounts.extend_from_slice(accounts);
+        self
+    }
+    #[allow(clippy::clone_on_copy)]
+    pub fn instruction(&self) -> solana_program::instruction::Instruction {
+        let accounts = UpdateValidationConfigV1 {
+            validation_config: self
+                .validation_config
+                .expect("validation_config is not set"),
+            authority: self.authority.expect("authority is not set"),
+            new_authority: self.new_authority,
+            new_arbiter: self.new_arbiter,
+        };
+        let args = UpdateValidationConfigV1InstructionArgs {
+            unbonding_slots: self
+                .unbonding_slots
+                .clone()
+                .expect("unbonding_slots is not set"),
+        };
+
+        accounts.instruction_with_remaining_accounts(args, &self.__remaining_accounts)
+    }
+}
+
+/// `update_validation_config_v1` CPI accounts.
+pub struct UpdateValidationConfigV1CpiAccounts<'a, 'b> {
+    /// The validation config PDA at ["validation_config"]
+    pub validation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The config authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new config authority, if it changes
+    pub new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The new arbiter, if it changes
+    pub new_arbiter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+}
+
+/// `update_validation_config_v1` CPI instruction.
+pub struct UpdateValidationConfigV1Cpi<'a, 'b> {
+    /// The program to invoke.
+    pub __program: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The validation config PDA at ["validation_config"]
+    pub validation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The config authority
+    pub authority: &'b solana_program::account_info::AccountInfo<'a>,
+    /// The new config authority, if it changes
+    pub new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The new arbiter, if it changes
+    pub new_arbiter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    /// The arguments for the instruction.
+    pub __args: UpdateValidationConfigV1InstructionArgs,
+}
+
+impl<'a, 'b> UpdateValidationConfigV1Cpi<'a, 'b> {
+    pub fn new(
+        program: &'b solana_program::account_info::AccountInfo<'a>,
+        accounts: UpdateValidationConfigV1CpiAccounts<'a, 'b>,
+        args: UpdateValidationConfigV1InstructionArgs,
+    ) -> Self {
+        Self {
+            __program: program,
+            validation_config: accounts.validation_config,
+            authority: accounts.authority,
+            new_authority: accounts.new_authority,
+            new_arbiter: accounts.new_arbiter,
+            __args: args,
+        }
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], &[])
+    }
+    #[inline(always)]
+    pub fn invoke_with_remaining_accounts(
+        &self,
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(&[], remaining_accounts)
+    }
+    #[inline(always)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed_with_remaining_accounts(signers_seeds, &[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed_with_remaining_accounts(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+        remaining_accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let mut accounts = Vec::with_capacity(4 + remaining_accounts.len());
+        accounts.push(solana_program::instruction::AccountMeta::new(
+            *self.validation_config.key,
+            false,
+        ));
+        accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+            *self.authority.key,
+            true,
+        ));
+        if let Some(new_authority) = self.new_authority {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *new_authority.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        if let Some(new_arbiter) = self.new_arbiter {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                *new_arbiter.key,
+                false,
+            ));
+        } else {
+            accounts.push(solana_program::instruction::AccountMeta::new_readonly(
+                crate::MPL_AGENT_VALIDATION_ID,
+                false,
+            ));
+        }
+        remaining_accounts.iter().for_each(|remaining_account| {
+            accounts.push(solana_program::instruction::AccountMeta {
+                pubkey: *remaining_account.0.key,
+                is_writable: remaining_account.1,
+                is_signer: remaining_account.2,
+            })
+        });
+        let mut data = borsh::to_vec(&(UpdateValidationConfigV1InstructionData::new())).unwrap();
+        let mut args = borsh::to_vec(&self.__args).unwrap();
+        data.append(&mut args);
+
+        let instruction = solana_program::instruction::Instruction {
+            program_id: crate::MPL_AGENT_VALIDATION_ID,
+            accounts,
+            data,
+        };
+        let mut account_infos = Vec::with_capacity(4 + 1 + remaining_accounts.len());
+        account_infos.push(self.__program.clone());
+        account_infos.push(self.validation_config.clone());
+        account_infos.push(self.authority.clone());
+        if let Some(new_authority) = self.new_authority {
+            account_infos.push(new_authority.clone());
+        }
+        if let Some(new_arbiter) = self.new_arbiter {
+            account_infos.push(new_arbiter.clone());
+        }
+        remaining_accounts
+            .iter()
+            .for_each(|remaining_account| account_infos.push(remaining_account.0.clone()));
+
+        if signers_seeds.is_empty() {
+            solana_program::program::invoke(&instruction, &account_infos)
+        } else {
+            solana_program::program::invoke_signed(&instruction, &account_infos, signers_seeds)
+        }
+    }
+}
+
+/// Instruction builder for `UpdateValidationConfigV1` via CPI.
+///
+/// ### Accounts:
+///
+///   0. `[writable]` validation_config
+///   1. `[signer]` authority
+///   2. `[optional]` new_authority
+///   3. `[optional]` new_arbiter
+pub struct UpdateValidationConfigV1CpiBuilder<'a, 'b> {
+    instruction: Box<UpdateValidationConfigV1CpiBuilderInstruction<'a, 'b>>,
+}
+
+impl<'a, 'b> UpdateValidationConfigV1CpiBuilder<'a, 'b> {
+    pub fn new(program: &'b solana_program::account_info::AccountInfo<'a>) -> Self {
+        let instruction = Box::new(UpdateValidationConfigV1CpiBuilderInstruction {
+            __program: program,
+            validation_config: None,
+            authority: None,
+            new_authority: None,
+            new_arbiter: None,
+            unbonding_slots: None,
+            __remaining_accounts: Vec::new(),
+        });
+        Self { instruction }
+    }
+    /// The validation config PDA at ["validation_config"]
+    #[inline(always)]
+    pub fn validation_config(
+        &mut self,
+        validation_config: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.validation_config = Some(validation_config);
+        self
+    }
+    /// The config authority
+    #[inline(always)]
+    pub fn authority(
+        &mut self,
+        authority: &'b solana_program::account_info::AccountInfo<'a>,
+    ) -> &mut Self {
+        self.instruction.authority = Some(authority);
+        self
+    }
+    /// `[optional account]`
+    /// The new config authority, if it changes
+    #[inline(always)]
+    pub fn new_authority(
+        &mut self,
+        new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.new_authority = new_authority;
+        self
+    }
+    /// `[optional account]`
+    /// The new arbiter, if it changes
+    #[inline(always)]
+    pub fn new_arbiter(
+        &mut self,
+        new_arbiter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    ) -> &mut Self {
+        self.instruction.new_arbiter = new_arbiter;
+        self
+    }
+    #[inline(always)]
+    pub fn unbonding_slots(&mut self, unbonding_slots: u64) -> &mut Self {
+        self.instruction.unbonding_slots = Some(unbonding_slots);
+        self
+    }
+    /// Add an additional account to the instruction.
+    #[inline(always)]
+    pub fn add_remaining_account(
+        &mut self,
+        account: &'b solana_program::account_info::AccountInfo<'a>,
+        is_writable: bool,
+        is_signer: bool,
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .push((account, is_writable, is_signer));
+        self
+    }
+    /// Add additional accounts to the instruction.
+    ///
+    /// Each account is represented by a tuple of the `AccountInfo`, a `bool` indicating whether the account is writable or not,
+    /// and a `bool` indicating whether the account is a signer or not.
+    #[inline(always)]
+    pub fn add_remaining_accounts(
+        &mut self,
+        accounts: &[(
+            &'b solana_program::account_info::AccountInfo<'a>,
+            bool,
+            bool,
+        )],
+    ) -> &mut Self {
+        self.instruction
+            .__remaining_accounts
+            .extend_from_slice(accounts);
+        self
+    }
+    #[inline(always)]
+    pub fn invoke(&self) -> solana_program::entrypoint::ProgramResult {
+        self.invoke_signed(&[])
+    }
+    #[allow(clippy::clone_on_copy)]
+    #[allow(clippy::vec_init_then_push)]
+    pub fn invoke_signed(
+        &self,
+        signers_seeds: &[&[&[u8]]],
+    ) -> solana_program::entrypoint::ProgramResult {
+        let args = UpdateValidationConfigV1InstructionArgs {
+            unbonding_slots: self
+                .instruction
+                .unbonding_slots
+                .clone()
+                .expect("unbonding_slots is not set"),
+        };
+        let instruction = UpdateValidationConfigV1Cpi {
+            __program: self.instruction.__program,
+
+            validation_config: self
+                .instruction
+                .validation_config
+                .expect("validation_config is not set"),
+
+            authority: self.instruction.authority.expect("authority is not set"),
+
+            new_authority: self.instruction.new_authority,
+
+            new_arbiter: self.instruction.new_arbiter,
+            __args: args,
+        };
+        instruction.invoke_signed_with_remaining_accounts(
+            signers_seeds,
+            &self.instruction.__remaining_accounts,
+        )
+    }
+}
+
+struct UpdateValidationConfigV1CpiBuilderInstruction<'a, 'b> {
+    __program: &'b solana_program::account_info::AccountInfo<'a>,
+    validation_config: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_authority: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    new_arbiter: Option<&'b solana_program::account_info::AccountInfo<'a>>,
+    unbonding_slots: Option<u64>,
+    /// Additional instruction accounts `(AccountInfo, is_writable, is_signer)`.
+    __remaining_accounts: Vec<(
+        &'b solana_program::account_info::AccountInfo<'a>,
+        bool,
+        bool,
+    )>,
+}
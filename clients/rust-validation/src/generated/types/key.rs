@@ -18,4 +18,12 @@ use num_derive::FromPrimitive;
 pub enum Key {
     Uninitialized,
     AgentValidationV1,
+    ValidationRequestV1,
+    ValidationConfigV1,
+    ValidatorProfileV1,
+    ValidatorStakeVaultV1,
+    AcceptedValidatorV1,
+    AgentValidationV2,
+    ValidatorHistoryV1,
+    ValidatorAttesterV1,
 }
@@ -5,6 +5,12 @@
 //! [https://github.com/metaplex-foundation/kinobi]
 //!
 
+pub(crate) mod r#evidence_type;
 pub(crate) mod r#key;
+pub(crate) mod r#mpl_agent_validation_event;
+pub(crate) mod r#validation_status;
 
+pub use self::r#evidence_type::*;
 pub use self::r#key::*;
+pub use self::r#mpl_agent_validation_event::*;
+pub use self::r#validation_status::*;
@@ -1,4 +1,8 @@
+pub mod attestation;
+pub mod events;
+#[allow(unexpected_cfgs, clippy::new_without_default)]
 mod generated;
+pub mod summary;
 
 pub use generated::programs::MPL_AGENT_VALIDATION_ID as ID;
 pub use generated::*;
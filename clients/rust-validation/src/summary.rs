@@ -0,0 +1,83 @@
+//! Decoder for the validation summary the program writes to the asset's
+//! AppData plugin, whose data authority is the agent validation PDA.
+//!
+//! The summary mirrors `mpl_agent_validation_program::state::ValidationSummaryV1`:
+//! a version byte, a byte of padding, the little-endian `u16` pass rate,
+//! 4 reserved bytes, three little-endian `u64` fields, the top validator
+//! address and its little-endian `u64` response count.
+
+use mpl_core::{types::PluginAuthority, Asset};
+use solana_program::pubkey::Pubkey;
+
+use crate::accounts::AgentValidationV2;
+
+/// Validation aggregates of an agent.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct ValidationSummaryV1 {
+    /// Share of passing validations, in basis points.
+    pub pass_rate_bps: u16,
+    /// Number of validation responses.
+    pub validation_count: u64,
+    /// Number of validation responses with a passing result.
+    pub pass_count: u64,
+    /// Slot of the last validation response.
+    pub last_validated_slot: u64,
+    /// The validator with the most responses for the agent.
+    pub top_validator: Pubkey,
+    /// Number of responses of the top validator.
+    pub top_validator_count: u64,
+}
+
+impl ValidationSummaryV1 {
+    /// Version byte of this layout.
+    pub const VERSION: u8 = 1;
+
+    /// Size of the encoded summary in bytes.
+    pub const LEN: usize = 72;
+
+    /// Decode a summary from AppData bytes. Returns `None` if the data has an
+    /// unknown version or is too short.
+    pub fn from_bytes(data: &[u8]) -> Option<Self> {
+        if data.len() < Self::LEN || data[0] != Self::VERSION {
+            return None;
+        }
+
+        let field =
+            |offset: usize| u64::from_le_bytes(data[offset..offset + 8].try_into().unwrap());
+
+        Some(Self {
+            pass_rate_bps: u16::from_le_bytes([data[2], data[3]]),
+            validation_count: field(8),
+            pass_count: field(16),
+            last_validated_slot: field(24),
+            top_validator: Pubkey::new_from_array(data[32..64].try_into().unwrap()),
+            top_validator_count: field(64),
+        })
+    }
+
+    /// Find and decode the summary in the AppData plugin of the Core asset
+    /// at `asset`. Returns `None` if the asset has no validation AppData
+    /// plugin or no validation was responded to yet.
+    pub fn from_asset_data(asset: &Pubkey, asset_data: &[u8]) -> Option<Self> {
+        let (agent_validation, _) = AgentValidationV2::find_pda(asset);
+        let data_authority = PluginAuthority::Address {
+            address: agent_validation,
+        };
+
+        Asset::from_bytes(asset_data)
+            .ok()?
+            .external_plugin_adapter_list
+            .app_data
+            .iter()
+            .find(|app_data| app_data.base.data_authority == data_authority)
+            .and_then(|app_data| {
+                asset_data.get(app_data.data_offset..app_data.data_offset + app_data.data_len)
+            })
+            .and_then(Self::from_bytes)
+    }
+
+    /// Share of passing validations, if any validation was responded to.
+    pub fn pass_rate(&self) -> Option<f64> {
+        (self.validation_count > 0).then(|| self.pass_count as f64 / self.validation_count as f64)
+    }
+}
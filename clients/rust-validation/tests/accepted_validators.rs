@@ -0,0 +1,351 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_validation::{
+    accounts::{
+        AcceptedValidatorV1, AgentValidationV1, AgentValidationV2, ValidationRequestV1,
+        ValidatorHistoryV1, ValidatorProfileV1,
+    },
+    errors::MplAgentValidationError,
+    events::MplAgentValidationEvent,
+    instructions::{
+        AddAcceptedValidatorV1Builder, RemoveAcceptedValidatorV1Builder,
+        RequestValidationV1Builder, RespondValidationV1Builder,
+    },
+    types::{Key, ValidationStatus},
+};
+use setup::{
+    fetch_request, process, register_validator, setup_agent, setup_agent_in_collection,
+    EVIDENCE_URI,
+};
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+fn accept_ix(context: &ProgramTestContext, asset: Pubkey, validator: Pubkey) -> Instruction {
+    AddAcceptedValidatorV1Builder::new()
+        .accepted_validator(AcceptedValidatorV1::find_pda(&asset, &validator).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .asset(asset)
+        .authority(context.payer.pubkey())
+        .payer(context.payer.pubkey())
+        .validator(validator)
+        .instruction()
+}
+
+fn remove_ix(context: &ProgramTestContext, asset: Pubkey, validator: Pubkey) -> Instruction {
+    RemoveAcceptedValidatorV1Builder::new()
+        .accepted_validator(AcceptedValidatorV1::find_pda(&asset, &validator).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .asset(asset)
+        .authority(context.payer.pubkey())
+        .destination(context.payer.pubkey())
+        .instruction()
+}
+
+fn request_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    requester: Pubkey,
+    validator: Pubkey,
+    accepted_validator: Option<Pubkey>,
+) -> Instruction {
+    request_ix_with_nonce(context, asset, requester, validator, accepted_validator, 0)
+}
+
+fn request_ix_with_nonce(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    requester: Pubkey,
+    validator: Pubkey,
+    accepted_validator: Option<Pubkey>,
+    nonce: u64,
+) -> Instruction {
+    RequestValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, nonce).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .accepted_validator(accepted_validator)
+        .asset(asset)
+        .requester(requester)
+        .payer(context.payer.pubkey())
+        .nonce(nonce)
+        .work_hash([7u8; 32])
+        .validator(validator)
+        .instruction()
+}
+
+fn respond_ix(
+    context: &ProgramTestContext,
+    collection: Pubkey,
+    asset: Pubkey,
+    validator: Pubkey,
+    nonce: u64,
+) -> Instruction {
+    RespondValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, nonce).0)
+        .agent_validation(AgentValidationV2::find_pda(&asset).0)
+        .validator_history(ValidatorHistoryV1::find_pda(&asset, &validator).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .accepted_validator(Some(AcceptedValidatorV1::find_pda(&asset, &validator).0))
+        .asset(asset)
+        .collection(Some(collection))
+        .validator(validator)
+        .payer(context.payer.pubkey())
+        .result(87)
+        .evidence_hash([9u8; 32])
+        .evidence_uri(EVIDENCE_URI.to_string())
+        .instruction()
+}
+
+async fn fetch_agent_validation(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+) -> AgentValidationV1 {
+    let account = context
+        .banks_client
+        .get_account(AgentValidationV1::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    AgentValidationV1::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn owner_can_accept_a_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+    let validator = Pubkey::new_unique();
+
+    let ix = accept_ix(&context, asset, validator);
+    let payer = context.payer.insecure_clone();
+    let events = process(&mut context, ix, &payer).await.unwrap();
+
+    let (address, bump) = AcceptedValidatorV1::find_pda(&asset, &validator);
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    let accepted_validator = AcceptedValidatorV1::from_bytes(&account.data).unwrap();
+    assert_eq!(accepted_validator.key, Key::AcceptedValidatorV1);
+    assert_eq!(accepted_validator.bump, bump);
+    assert_eq!(accepted_validator.asset, asset);
+    assert_eq!(accepted_validator.validator, validator);
+
+    let agent_validation = fetch_agent_validation(&mut context, asset).await;
+    assert_eq!(agent_validation.accepted_validator_count, 1);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorAcceptedV1 {
+            asset,
+            validator,
+            accepted_validator_count: 1,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn only_owner_can_accept_a_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+
+    let stranger = Keypair::new();
+    let validator = Pubkey::new_unique();
+    let ix = AddAcceptedValidatorV1Builder::new()
+        .accepted_validator(AcceptedValidatorV1::find_pda(&asset, &validator).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .asset(asset)
+        .authority(stranger.pubkey())
+        .payer(context.payer.pubkey())
+        .validator(validator)
+        .instruction();
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidAgentAuthority as u32);
+}
+
+#[tokio::test]
+async fn cannot_accept_a_validator_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+    let validator = Pubkey::new_unique();
+    let payer = context.payer.insecure_clone();
+    let ix = accept_ix(&context, asset, validator);
+    process(&mut context, ix, &payer).await.unwrap();
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = accept_ix(&context, asset, validator);
+    let err = process(&mut context, ix, &payer).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidatorAlreadyAccepted as u32,
+    );
+}
+
+#[tokio::test]
+async fn whitelisted_agent_only_accepts_its_validators() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+    let accepted = register_validator(&mut context, 0).await;
+    let other = register_validator(&mut context, 0).await;
+    let payer = context.payer.insecure_clone();
+    let ix = accept_ix(&context, asset, accepted.pubkey());
+    process(&mut context, ix, &payer).await.unwrap();
+
+    let requester = Keypair::new();
+
+    // A validator outside the whitelist cannot be requested.
+    let ix = request_ix(&context, asset, requester.pubkey(), other.pubkey(), None);
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::ValidatorNotAccepted as u32);
+
+    // Nor by passing the whitelist entry of another validator.
+    let ix = request_ix(
+        &context,
+        asset,
+        requester.pubkey(),
+        other.pubkey(),
+        Some(AcceptedValidatorV1::find_pda(&asset, &accepted.pubkey()).0),
+    );
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InvalidAcceptedValidator as u32,
+    );
+
+    // An accepted validator can.
+    let ix = request_ix(
+        &context,
+        asset,
+        requester.pubkey(),
+        accepted.pubkey(),
+        Some(AcceptedValidatorV1::find_pda(&asset, &accepted.pubkey()).0),
+    );
+    process(&mut context, ix, &requester).await.unwrap();
+}
+
+#[tokio::test]
+async fn removing_the_last_validator_opens_the_agent() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+    let accepted = Pubkey::new_unique();
+    let payer = context.payer.insecure_clone();
+    let ix = accept_ix(&context, asset, accepted);
+    process(&mut context, ix, &payer).await.unwrap();
+
+    let ix = remove_ix(&context, asset, accepted);
+
+    let events = process(&mut context, ix, &payer).await.unwrap();
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorRemovedV1 {
+            asset,
+            validator: accepted,
+            accepted_validator_count: 0,
+        }]
+    );
+    assert!(context
+        .banks_client
+        .get_account(AcceptedValidatorV1::find_pda(&asset, &accepted).0)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(
+        fetch_agent_validation(&mut context, asset)
+            .await
+            .accepted_validator_count,
+        0
+    );
+
+    // Any registered validator can be requested again.
+    let validator = register_validator(&mut context, 0).await;
+    let requester = Keypair::new();
+    let ix = request_ix(
+        &context,
+        asset,
+        requester.pubkey(),
+        validator.pubkey(),
+        None,
+    );
+    process(&mut context, ix, &requester).await.unwrap();
+}
+
+#[tokio::test]
+async fn only_owner_can_remove_a_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+    let accepted = Pubkey::new_unique();
+    let payer = context.payer.insecure_clone();
+    let ix = accept_ix(&context, asset, accepted);
+    process(&mut context, ix, &payer).await.unwrap();
+
+    let stranger = Keypair::new();
+    let ix = RemoveAcceptedValidatorV1Builder::new()
+        .accepted_validator(AcceptedValidatorV1::find_pda(&asset, &accepted).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .asset(asset)
+        .authority(stranger.pubkey())
+        .destination(stranger.pubkey())
+        .instruction();
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidAgentAuthority as u32);
+}
+
+#[tokio::test]
+async fn removed_validator_cannot_respond() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset) = setup_agent_in_collection(&mut context).await;
+    let removed = register_validator(&mut context, 0).await;
+    let kept = register_validator(&mut context, 0).await;
+    let payer = context.payer.insecure_clone();
+    for validator in [removed.pubkey(), kept.pubkey()] {
+        let ix = accept_ix(&context, asset, validator);
+        process(&mut context, ix, &payer).await.unwrap();
+    }
+
+    // Both validators are requested while accepted.
+    let requester = Keypair::new();
+    for (nonce, validator) in [(0, removed.pubkey()), (1, kept.pubkey())] {
+        let ix = request_ix_with_nonce(
+            &context,
+            asset,
+            requester.pubkey(),
+            validator,
+            Some(AcceptedValidatorV1::find_pda(&asset, &validator).0),
+            nonce,
+        );
+        process(&mut context, ix, &requester).await.unwrap();
+    }
+
+    // The agent then stops accepting one of them.
+    let ix = remove_ix(&context, asset, removed.pubkey());
+    process(&mut context, ix, &payer).await.unwrap();
+
+    let ix = respond_ix(&context, collection, asset, removed.pubkey(), 0);
+    let err = process(&mut context, ix, &removed).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::ValidatorNotAccepted as u32);
+
+    // The validator it still accepts can respond, and its response to a
+    // client counts for the agent.
+    let ix = respond_ix(&context, collection, asset, kept.pubkey(), 1);
+    process(&mut context, ix, &kept).await.unwrap();
+    let request = fetch_request(&mut context, asset, 1).await;
+    assert_eq!(request.status, ValidationStatus::Responded);
+    assert_eq!(request.counted, 1);
+
+    let account = context
+        .banks_client
+        .get_account(AgentValidationV2::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let agent_validation = AgentValidationV2::from_bytes(&account.data).unwrap();
+    assert_eq!(agent_validation.validation_count, 1);
+    assert_eq!(agent_validation.top_validator, kept.pubkey());
+}
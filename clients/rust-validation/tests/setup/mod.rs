@@ -0,0 +1,305 @@
+#![allow(dead_code)]
+
+use mpl_agent_validation::{
+    accounts::{
+        AgentValidationV1, ValidationConfigV1, ValidationRequestV1, ValidatorProfileV1,
+        ValidatorStakeVaultV1,
+    },
+    events::MplAgentValidationEvent,
+    instructions::{
+        InitializeValidationConfigV1Builder, RegisterValidationV1Builder,
+        RegisterValidatorV1Builder,
+    },
+};
+use mpl_core::instructions::{CreateCollectionV1Builder, CreateV1Builder};
+use solana_program::instruction::{Instruction, InstructionError};
+use solana_program_test::{BanksClientError, ProgramTest, ProgramTestContext};
+use solana_sdk::{
+    account::Account,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::{Transaction, TransactionError},
+};
+use solana_system_interface::instruction as system_instruction;
+
+pub const MPL_CORE_ID: Pubkey =
+    solana_program::pubkey!("CoREENxT6tW1HoK8ypY1SxRMZTcVPm7R94rH4PZNhX7d");
+
+pub const EVIDENCE_URI: &str = "https://example.com/evidence.json";
+
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_program::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Add a program data account naming `upgrade_authority` as the upgrade
+/// authority of the validation program, as a deployment would.
+pub fn add_program_data(program_test: &mut ProgramTest, upgrade_authority: Pubkey) -> Pubkey {
+    let (address, _) = Pubkey::find_program_address(
+        &[mpl_agent_validation::ID.as_ref()],
+        &BPF_LOADER_UPGRADEABLE_ID,
+    );
+
+    // UpgradeableLoaderState::ProgramData { slot: 0, upgrade_authority_address }
+    let mut data = 3u32.to_le_bytes().to_vec();
+    data.extend_from_slice(&0u64.to_le_bytes());
+    data.push(1);
+    data.extend_from_slice(upgrade_authority.as_ref());
+
+    program_test.add_account(
+        address,
+        Account {
+            lamports: 1_000_000_000,
+            data,
+            owner: BPF_LOADER_UPGRADEABLE_ID,
+            executable: false,
+            rent_epoch: 0,
+        },
+    );
+    address
+}
+
+/// Asserts that a BanksClientError is a custom program error matching the
+/// expected error variant. Callers pass the error enum variant cast as u32,
+/// e.g. `assert_custom_error(err, MplAgentValidationError::SomeError as u32)`.
+pub fn assert_custom_error(error: BanksClientError, expected_code: u32) {
+    match error.unwrap() {
+        TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+            assert_eq!(code, expected_code);
+        }
+        err => panic!("Expected InstructionError::Custom({expected_code}), got: {err:?}"),
+    }
+}
+
+pub fn setup() -> ProgramTest {
+    let mut program_test = ProgramTest::new(
+        "mpl_agent_validation_program",
+        mpl_agent_validation::ID,
+        None,
+    );
+    program_test.add_program("mpl_core", MPL_CORE_ID, None);
+    program_test
+}
+
+/// Start a test validator on which `upgrade_authority` can upgrade the
+/// validation program.
+pub async fn start(upgrade_authority: &Keypair) -> (ProgramTestContext, Pubkey) {
+    let mut program_test = setup();
+    let program_data = add_program_data(&mut program_test, upgrade_authority.pubkey());
+    (program_test.start_with_context().await, program_data)
+}
+
+pub fn initialize_config_ix(
+    context: &ProgramTestContext,
+    program_data: Pubkey,
+    authority: Pubkey,
+    arbiter: Pubkey,
+    unbonding_slots: u64,
+) -> Instruction {
+    InitializeValidationConfigV1Builder::new()
+        .validation_config(ValidationConfigV1::find_pda().0)
+        .program_data(program_data)
+        .authority(authority)
+        .arbiter(arbiter)
+        .payer(context.payer.pubkey())
+        .unbonding_slots(unbonding_slots)
+        .instruction()
+}
+
+/// Start a test validator with the validation config initialized, naming
+/// the returned arbiter. Unbonding takes `unbonding_slots`.
+pub async fn start_with_config(unbonding_slots: u64) -> (ProgramTestContext, Keypair) {
+    let upgrade_authority = Keypair::new();
+    let arbiter = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let ix = initialize_config_ix(
+        &context,
+        program_data,
+        upgrade_authority.pubkey(),
+        arbiter.pubkey(),
+        unbonding_slots,
+    );
+    process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    (context, arbiter)
+}
+
+pub async fn create_collection_and_asset(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+    let collection = Keypair::new();
+    let asset = Keypair::new();
+
+    let create_collection_ix = CreateCollectionV1Builder::new()
+        .collection(collection.pubkey())
+        .payer(context.payer.pubkey())
+        .name("Test Collection".to_string())
+        .uri("https://example.com/collection.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_collection_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &collection],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    let create_asset_ix = CreateV1Builder::new()
+        .asset(asset.pubkey())
+        .collection(Some(collection.pubkey()))
+        .payer(context.payer.pubkey())
+        .name("Test Asset".to_string())
+        .uri("https://example.com/asset.json".to_string())
+        .instruction();
+
+    let tx = Transaction::new_signed_with_payer(
+        &[create_asset_ix],
+        Some(&context.payer.pubkey()),
+        &[&context.payer, &asset],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+
+    (collection.pubkey(), asset.pubkey())
+}
+
+/// Create an asset and register it for validation. The payer is the
+/// collection update authority and the asset owner. Returns the asset.
+pub async fn setup_agent(context: &mut ProgramTestContext) -> Pubkey {
+    setup_agent_in_collection(context).await.1
+}
+
+/// Like `setup_agent`, also returning the collection of the asset first.
+pub async fn setup_agent_in_collection(context: &mut ProgramTestContext) -> (Pubkey, Pubkey) {
+    let (collection, asset) = create_collection_and_asset(context).await;
+
+    let ix = RegisterValidationV1Builder::new()
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .payer(context.payer.pubkey())
+        .instruction();
+    let payer = context.payer.insecure_clone();
+    process(context, ix, &payer).await.unwrap();
+
+    (collection, asset)
+}
+
+/// Transfer `lamports` from the payer to `to`.
+pub async fn fund(context: &mut ProgramTestContext, to: Pubkey, lamports: u64) {
+    let tx = Transaction::new_signed_with_payer(
+        &[system_instruction::transfer(
+            &context.payer.pubkey(),
+            &to,
+            lamports,
+        )],
+        Some(&context.payer.pubkey()),
+        &[&context.payer],
+        context.last_blockhash,
+    );
+    context.banks_client.process_transaction(tx).await.unwrap();
+}
+
+/// Register a new validator escrowing `stake` lamports. Returns the
+/// validator authority, funded with an extra SOL for fees.
+pub async fn register_validator(context: &mut ProgramTestContext, stake: u64) -> Keypair {
+    let validator = Keypair::new();
+    fund(context, validator.pubkey(), stake + 1_000_000_000).await;
+
+    let ix = RegisterValidatorV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator.pubkey()).0)
+        .validator_stake_vault(ValidatorStakeVaultV1::find_pda(&validator.pubkey()).0)
+        .validator(validator.pubkey())
+        .payer(context.payer.pubkey())
+        .stake(stake)
+        .instruction();
+    process(context, ix, &validator).await.unwrap();
+
+    validator
+}
+
+/// Process `ix`, signed by the payer and `signer`, and return the events
+/// the validation program emitted.
+pub async fn process(
+    context: &mut ProgramTestContext,
+    ix: Instruction,
+    signer: &Keypair,
+) -> Result<Vec<MplAgentValidationEvent>, BanksClientError> {
+    process_ixs(context, &[ix], signer).await
+}
+
+/// Process `ixs` in one transaction, signed by the payer and `signer`, and
+/// return the events the validation program emitted.
+pub async fn process_ixs(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+    signer: &Keypair,
+) -> Result<Vec<MplAgentValidationEvent>, BanksClientError> {
+    process_signed(context, ixs, &[signer]).await
+}
+
+/// Process `ixs` in one transaction, signed by the payer and `signers`, and
+/// return the events the validation program emitted.
+pub async fn process_signed(
+    context: &mut ProgramTestContext,
+    ixs: &[Instruction],
+    signers: &[&Keypair],
+) -> Result<Vec<MplAgentValidationEvent>, BanksClientError> {
+    let payer = context.payer.insecure_clone();
+    let mut all_signers = vec![&payer];
+    all_signers.extend_from_slice(signers);
+    let tx = Transaction::new_signed_with_payer(
+        ixs,
+        Some(&payer.pubkey()),
+        &all_signers,
+        context.last_blockhash,
+    );
+    let result = context
+        .banks_client
+        .process_transaction_with_metadata(tx)
+        .await?;
+    result.result?;
+
+    Ok(MplAgentValidationEvent::from_logs(
+        &result.metadata.unwrap().log_messages,
+    ))
+}
+
+pub async fn fetch_request(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+    nonce: u64,
+) -> ValidationRequestV1 {
+    let account = context
+        .banks_client
+        .get_account(ValidationRequestV1::find_pda(&asset, nonce).0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), ValidationRequestV1::LEN);
+    ValidationRequestV1::from_bytes(&account.data).unwrap()
+}
+
+pub async fn fetch_validator_profile(
+    context: &mut ProgramTestContext,
+    validator: Pubkey,
+) -> ValidatorProfileV1 {
+    let account = context
+        .banks_client
+        .get_account(ValidatorProfileV1::find_pda(&validator).0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), ValidatorProfileV1::LEN);
+    ValidatorProfileV1::from_bytes(&account.data).unwrap()
+}
+
+/// Lamports held by the stake vault of `validator`, above its rent.
+pub async fn vault_stake(context: &mut ProgramTestContext, validator: Pubkey) -> u64 {
+    let account = context
+        .banks_client
+        .get_account(ValidatorStakeVaultV1::find_pda(&validator).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let rent = context.banks_client.get_rent().await.unwrap();
+    account.lamports - rent.minimum_balance(account.data.len())
+}
@@ -0,0 +1,487 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_validation::{
+    accounts::{
+        AgentValidationV1, AgentValidationV2, ValidationRequestV1, ValidatorAttesterV1,
+        ValidatorHistoryV1, ValidatorProfileV1,
+    },
+    attestation::attestation_message,
+    errors::MplAgentValidationError,
+    events::MplAgentValidationEvent,
+    instructions::{
+        AddValidatorAttesterV1Builder, RemoveValidatorAttesterV1Builder,
+        RequestValidationV1Builder, RespondValidationAttestationV1Builder,
+    },
+    summary::ValidationSummaryV1,
+    types::{EvidenceType, Key, ValidationStatus},
+};
+use setup::{
+    fetch_request, fetch_validator_profile, process, process_ixs, process_signed,
+    register_validator, setup_agent_in_collection,
+};
+use solana_ed25519_program::new_ed25519_instruction_with_signature;
+use solana_program::instruction::{Instruction, InstructionError};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+    transaction::TransactionError,
+};
+
+const WORK_HASH: [u8; 32] = [7u8; 32];
+
+const EVIDENCE_HASH: [u8; 32] = [5u8; 32];
+
+fn add_attester_ix(
+    context: &ProgramTestContext,
+    validator: Pubkey,
+    attester: Pubkey,
+) -> Instruction {
+    AddValidatorAttesterV1Builder::new()
+        .validator_attester(ValidatorAttesterV1::find_pda(&validator, &attester).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .validator(validator)
+        .payer(context.payer.pubkey())
+        .attester(attester)
+        .instruction()
+}
+
+fn remove_attester_ix(
+    context: &ProgramTestContext,
+    validator: Pubkey,
+    attester: Pubkey,
+) -> Instruction {
+    RemoveValidatorAttesterV1Builder::new()
+        .validator_attester(ValidatorAttesterV1::find_pda(&validator, &attester).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .validator(validator)
+        .destination(context.payer.pubkey())
+        .instruction()
+}
+
+/// Add `attester` to `validator`, signed by both.
+async fn add_attester(context: &mut ProgramTestContext, validator: &Keypair, attester: &Keypair) {
+    let ix = add_attester_ix(context, validator.pubkey(), attester.pubkey());
+    process_signed(context, &[ix], &[validator, attester])
+        .await
+        .unwrap();
+}
+
+fn request_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    requester: Pubkey,
+    validator: Pubkey,
+    nonce: u64,
+) -> Instruction {
+    RequestValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, nonce).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .asset(asset)
+        .requester(requester)
+        .payer(context.payer.pubkey())
+        .nonce(nonce)
+        .work_hash(WORK_HASH)
+        .validator(validator)
+        .instruction()
+}
+
+/// An Ed25519 program instruction verifying the signature of `attester`
+/// over the statement for the response `result` of `validator` to the
+/// request at `nonce`.
+fn attestation_ix(
+    asset: Pubkey,
+    nonce: u64,
+    validator: Pubkey,
+    attester: &Keypair,
+    result: u8,
+) -> Instruction {
+    let validation_request = ValidationRequestV1::find_pda(&asset, nonce).0;
+    let message = attestation_message(&validation_request, &validator, &WORK_HASH, result);
+    let signature: [u8; 64] = attester.sign_message(&message).into();
+    new_ed25519_instruction_with_signature(&message, &signature, &attester.pubkey().to_bytes())
+}
+
+fn respond_ix(
+    context: &ProgramTestContext,
+    collection: Pubkey,
+    asset: Pubkey,
+    validator: Pubkey,
+    attester: Pubkey,
+    result: u8,
+) -> Instruction {
+    RespondValidationAttestationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, 0).0)
+        .agent_validation(AgentValidationV2::find_pda(&asset).0)
+        .validator_history(ValidatorHistoryV1::find_pda(&asset, &validator).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .validator_attester(ValidatorAttesterV1::find_pda(&validator, &attester).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .validator(validator)
+        .payer(context.payer.pubkey())
+        .result(result)
+        .evidence_hash(EVIDENCE_HASH)
+        .instruction()
+}
+
+/// A registered agent with a pending request at nonce 0, from the agent to
+/// the returned validator, which has the returned attester key. Returns the
+/// collection and the asset first.
+async fn setup_attested_request(
+    context: &mut ProgramTestContext,
+) -> (Pubkey, Pubkey, Keypair, Keypair) {
+    let (collection, asset) = setup_agent_in_collection(context).await;
+    // The payer owns the agent asset.
+    let requester = context.payer.insecure_clone();
+    let validator = register_validator(context, 0).await;
+    let attester = Keypair::new();
+    add_attester(context, &validator, &attester).await;
+
+    let ix = request_ix(context, asset, requester.pubkey(), validator.pubkey(), 0);
+    process(context, ix, &requester).await.unwrap();
+
+    (collection, asset, validator, attester)
+}
+
+#[tokio::test]
+async fn validator_can_add_and_remove_an_attester() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = register_validator(&mut context, 0).await;
+    let attester_keypair = Keypair::new();
+    let attester = attester_keypair.pubkey();
+
+    let ix = add_attester_ix(&context, validator.pubkey(), attester);
+    let events = process_signed(&mut context, &[ix], &[&validator, &attester_keypair])
+        .await
+        .unwrap();
+
+    let (address, bump) = ValidatorAttesterV1::find_pda(&validator.pubkey(), &attester);
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+    let validator_attester = ValidatorAttesterV1::from_bytes(&account.data).unwrap();
+    assert_eq!(validator_attester.key, Key::ValidatorAttesterV1);
+    assert_eq!(validator_attester.bump, bump);
+    assert_eq!(validator_attester.validator, validator.pubkey());
+    assert_eq!(validator_attester.attester, attester);
+
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert_eq!(profile.attester_count, 1);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorAttesterAddedV1 {
+            validator: validator.pubkey(),
+            attester,
+            attester_count: 1,
+        }]
+    );
+
+    let ix = remove_attester_ix(&context, validator.pubkey(), attester);
+    let events = process(&mut context, ix, &validator).await.unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .is_none());
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert_eq!(profile.attester_count, 0);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorAttesterRemovedV1 {
+            validator: validator.pubkey(),
+            attester,
+            attester_count: 0,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn cannot_add_an_attester_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = register_validator(&mut context, 0).await;
+    let attester = Keypair::new();
+    add_attester(&mut context, &validator, &attester).await;
+
+    context.get_new_latest_blockhash().await.unwrap();
+    let ix = add_attester_ix(&context, validator.pubkey(), attester.pubkey());
+    let err = process_signed(&mut context, &[ix], &[&validator, &attester])
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::AttesterAlreadyAdded as u32);
+}
+
+#[tokio::test]
+async fn cannot_add_an_attester_without_its_signature() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = register_validator(&mut context, 0).await;
+
+    // The key of another validator's attester, claimed without its signature.
+    let mut ix = add_attester_ix(&context, validator.pubkey(), Pubkey::new_unique());
+    ix.accounts[3].is_signer = false;
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    assert_eq!(
+        err.unwrap(),
+        TransactionError::InstructionError(0, InstructionError::MissingRequiredSignature)
+    );
+}
+
+#[tokio::test]
+async fn unregistered_validator_cannot_add_an_attester() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = Keypair::new();
+    let attester = Keypair::new();
+
+    let ix = add_attester_ix(&context, validator.pubkey(), attester.pubkey());
+    let err = process_signed(&mut context, &[ix], &[&validator, &attester])
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::ValidatorNotRegistered as u32);
+}
+
+#[tokio::test]
+async fn validator_can_respond_with_an_attestation() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, attester) = setup_attested_request(&mut context).await;
+
+    let ixs = [
+        attestation_ix(asset, 0, validator.pubkey(), &attester, 90),
+        respond_ix(
+            &context,
+            collection,
+            asset,
+            validator.pubkey(),
+            attester.pubkey(),
+            90,
+        ),
+    ];
+    let events = process_ixs(&mut context, &ixs, &validator).await.unwrap();
+
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.status, ValidationStatus::Responded);
+    assert_eq!(request.result, 90);
+    assert_eq!(request.evidence_type, EvidenceType::Attestation);
+    assert_eq!(request.evidence_hash, EVIDENCE_HASH);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidationAttestedV1 {
+            asset,
+            nonce: 0,
+            validator: validator.pubkey(),
+            attester: attester.pubkey(),
+            result: 90,
+            evidence_hash: EVIDENCE_HASH,
+        }]
+    );
+
+    // The response counts in the aggregates like any other.
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    let summary = ValidationSummaryV1::from_asset_data(&asset, &account.data).unwrap();
+    assert_eq!(summary.validation_count, 1);
+    assert_eq!(summary.pass_count, 1);
+    assert_eq!(summary.top_validator, validator.pubkey());
+}
+
+#[tokio::test]
+async fn cannot_respond_without_an_ed25519_instruction() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, attester) = setup_attested_request(&mut context).await;
+
+    let ix = respond_ix(
+        &context,
+        collection,
+        asset,
+        validator.pubkey(),
+        attester.pubkey(),
+        90,
+    );
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::MissingAttestation as u32);
+
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.status, ValidationStatus::Pending);
+}
+
+#[tokio::test]
+async fn cannot_respond_with_another_result_than_attested() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, attester) = setup_attested_request(&mut context).await;
+
+    // The attester signed a failing result, the validator reports a pass.
+    let ixs = [
+        attestation_ix(asset, 0, validator.pubkey(), &attester, 10),
+        respond_ix(
+            &context,
+            collection,
+            asset,
+            validator.pubkey(),
+            attester.pubkey(),
+            90,
+        ),
+    ];
+    let err = process_ixs(&mut context, &ixs, &validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidAttestation as u32);
+}
+
+#[tokio::test]
+async fn cannot_respond_with_an_attestation_of_another_key() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, attester) = setup_attested_request(&mut context).await;
+
+    // A valid signature, but by a key the validator never added.
+    let impostor = Keypair::new();
+    let ixs = [
+        attestation_ix(asset, 0, validator.pubkey(), &impostor, 90),
+        respond_ix(
+            &context,
+            collection,
+            asset,
+            validator.pubkey(),
+            attester.pubkey(),
+            90,
+        ),
+    ];
+    let err = process_ixs(&mut context, &ixs, &validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidAttestation as u32);
+}
+
+#[tokio::test]
+async fn cannot_respond_with_a_removed_attester() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, attester) = setup_attested_request(&mut context).await;
+
+    let ix = remove_attester_ix(&context, validator.pubkey(), attester.pubkey());
+    process(&mut context, ix, &validator).await.unwrap();
+
+    let ixs = [
+        attestation_ix(asset, 0, validator.pubkey(), &attester, 90),
+        respond_ix(
+            &context,
+            collection,
+            asset,
+            validator.pubkey(),
+            attester.pubkey(),
+            90,
+        ),
+    ];
+    let err = process_ixs(&mut context, &ixs, &validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InvalidValidatorAttester as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_respond_with_the_attester_of_another_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, _) = setup_attested_request(&mut context).await;
+
+    // Another validator's attester entry, passed by the designated validator.
+    let other = register_validator(&mut context, 0).await;
+    let other_attester = Keypair::new();
+    add_attester(&mut context, &other, &other_attester).await;
+
+    let mut ix = respond_ix(
+        &context,
+        collection,
+        asset,
+        validator.pubkey(),
+        other_attester.pubkey(),
+        90,
+    );
+    ix.accounts[3].pubkey =
+        ValidatorAttesterV1::find_pda(&other.pubkey(), &other_attester.pubkey()).0;
+    let ixs = [
+        attestation_ix(asset, 0, validator.pubkey(), &other_attester, 90),
+        ix,
+    ];
+    let err = process_ixs(&mut context, &ixs, &validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InvalidValidatorAttester as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_replay_an_attestation_for_another_request() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, attester) = setup_attested_request(&mut context).await;
+
+    // A second request for the same work to the same validator.
+    let requester = Keypair::new();
+    let ix = request_ix(&context, asset, requester.pubkey(), validator.pubkey(), 1);
+    process(&mut context, ix, &requester).await.unwrap();
+
+    // The attestation of the first request, copied onto the second.
+    let mut ix = respond_ix(
+        &context,
+        collection,
+        asset,
+        validator.pubkey(),
+        attester.pubkey(),
+        90,
+    );
+    ix.accounts[0].pubkey = ValidationRequestV1::find_pda(&asset, 1).0;
+    let ixs = [
+        attestation_ix(asset, 0, validator.pubkey(), &attester, 90),
+        ix,
+    ];
+    let err = process_ixs(&mut context, &ixs, &validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidAttestation as u32);
+}
+
+#[tokio::test]
+async fn cannot_replay_an_attestation_of_another_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator, attester) = setup_attested_request(&mut context).await;
+
+    // Another validator sharing the attester, requested for the same work.
+    let other = register_validator(&mut context, 0).await;
+    add_attester(&mut context, &other, &attester).await;
+    let requester = Keypair::new();
+    let ix = request_ix(&context, asset, requester.pubkey(), other.pubkey(), 1);
+    process(&mut context, ix, &requester).await.unwrap();
+
+    // The attestation for the first validator, replayed by the other one.
+    let mut ix = respond_ix(
+        &context,
+        collection,
+        asset,
+        other.pubkey(),
+        attester.pubkey(),
+        90,
+    );
+    ix.accounts[0].pubkey = ValidationRequestV1::find_pda(&asset, 1).0;
+    let ixs = [
+        attestation_ix(asset, 0, validator.pubkey(), &attester, 90),
+        ix,
+    ];
+    let err = process_ixs(&mut context, &ixs, &other).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidAttestation as u32);
+}
@@ -0,0 +1,131 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_validation::{
+    accounts::ValidationConfigV1, errors::MplAgentValidationError, events::MplAgentValidationEvent,
+    instructions::UpdateValidationConfigV1Builder, types::Key,
+};
+use setup::{initialize_config_ix, process, start};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+async fn fetch_config(context: &mut ProgramTestContext) -> ValidationConfigV1 {
+    let account = context
+        .banks_client
+        .get_account(ValidationConfigV1::find_pda().0)
+        .await
+        .unwrap()
+        .unwrap();
+    ValidationConfigV1::from_bytes(&account.data).unwrap()
+}
+
+#[tokio::test]
+async fn upgrade_authority_can_initialize_config() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let arbiter = Pubkey::new_unique();
+    let ix = initialize_config_ix(
+        &context,
+        program_data,
+        upgrade_authority.pubkey(),
+        arbiter,
+        1_000,
+    );
+    let events = process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    let config = fetch_config(&mut context).await;
+    assert_eq!(config.key, Key::ValidationConfigV1);
+    assert_eq!(config.authority, upgrade_authority.pubkey());
+    assert_eq!(config.arbiter, arbiter);
+    assert_eq!(config.unbonding_slots, 1_000);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidationConfigUpdatedV1 {
+            authority: upgrade_authority.pubkey(),
+            arbiter,
+            unbonding_slots: 1_000,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn only_upgrade_authority_can_initialize_config() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let stranger = Keypair::new();
+    let ix = initialize_config_ix(
+        &context,
+        program_data,
+        stranger.pubkey(),
+        stranger.pubkey(),
+        1_000,
+    );
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidConfigAuthority as u32);
+}
+
+#[tokio::test]
+async fn cannot_initialize_config_with_zero_unbonding_period() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+
+    let ix = initialize_config_ix(
+        &context,
+        program_data,
+        upgrade_authority.pubkey(),
+        Pubkey::new_unique(),
+        0,
+    );
+    let err = process(&mut context, ix, &upgrade_authority)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidUnbondingPeriod as u32);
+}
+
+#[tokio::test]
+async fn config_authority_can_replace_arbiter_and_hand_over_config() {
+    let upgrade_authority = Keypair::new();
+    let (mut context, program_data) = start(&upgrade_authority).await;
+    let ix = initialize_config_ix(
+        &context,
+        program_data,
+        upgrade_authority.pubkey(),
+        Pubkey::new_unique(),
+        1_000,
+    );
+    process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    let new_authority = Keypair::new();
+    let new_arbiter = Pubkey::new_unique();
+    let ix = UpdateValidationConfigV1Builder::new()
+        .validation_config(ValidationConfigV1::find_pda().0)
+        .authority(upgrade_authority.pubkey())
+        .new_authority(Some(new_authority.pubkey()))
+        .new_arbiter(Some(new_arbiter))
+        .unbonding_slots(2_000)
+        .instruction();
+    process(&mut context, ix, &upgrade_authority).await.unwrap();
+
+    let config = fetch_config(&mut context).await;
+    assert_eq!(config.authority, new_authority.pubkey());
+    assert_eq!(config.arbiter, new_arbiter);
+    assert_eq!(config.unbonding_slots, 2_000);
+
+    // The former authority no longer controls the config.
+    let ix = UpdateValidationConfigV1Builder::new()
+        .validation_config(ValidationConfigV1::find_pda().0)
+        .authority(upgrade_authority.pubkey())
+        .unbonding_slots(3_000)
+        .instruction();
+    let err = process(&mut context, ix, &upgrade_authority)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidConfigAuthority as u32);
+}
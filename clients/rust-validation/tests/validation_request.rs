@@ -0,0 +1,566 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_validation::{
+    accounts::{
+        AgentValidationV1, AgentValidationV2, ValidationConfigV1, ValidationRequestV1,
+        ValidatorHistoryV1, ValidatorProfileV1,
+    },
+    errors::MplAgentValidationError,
+    events::MplAgentValidationEvent,
+    instructions::{
+        RequestValidationV1Builder, RespondValidationV1Builder, UnbondValidatorStakeV1Builder,
+    },
+    summary::ValidationSummaryV1,
+    types::{EvidenceType, Key, ValidationStatus},
+};
+use setup::{
+    fetch_request, process, register_validator, setup_agent, setup_agent_in_collection,
+    EVIDENCE_URI,
+};
+use solana_program::instruction::Instruction;
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const WORK_HASH: [u8; 32] = [7u8; 32];
+
+const EVIDENCE_HASH: [u8; 32] = [9u8; 32];
+
+fn request_validation_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    requester: Pubkey,
+    validator: Pubkey,
+    nonce: u64,
+) -> Instruction {
+    RequestValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, nonce).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .asset(asset)
+        .requester(requester)
+        .payer(context.payer.pubkey())
+        .nonce(nonce)
+        .work_hash(WORK_HASH)
+        .validator(validator)
+        .instruction()
+}
+
+fn respond_validation_ix(
+    context: &ProgramTestContext,
+    collection: Pubkey,
+    asset: Pubkey,
+    nonce: u64,
+    validator: Pubkey,
+    result: u8,
+) -> Instruction {
+    RespondValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, nonce).0)
+        .agent_validation(AgentValidationV2::find_pda(&asset).0)
+        .validator_history(ValidatorHistoryV1::find_pda(&asset, &validator).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .validator(validator)
+        .payer(context.payer.pubkey())
+        .result(result)
+        .evidence_hash(EVIDENCE_HASH)
+        .evidence_uri(EVIDENCE_URI.to_string())
+        .instruction()
+}
+
+async fn fetch_agent_validation(
+    context: &mut ProgramTestContext,
+    asset: Pubkey,
+) -> AgentValidationV2 {
+    let account = context
+        .banks_client
+        .get_account(AgentValidationV2::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), AgentValidationV2::LEN);
+    AgentValidationV2::from_bytes(&account.data).unwrap()
+}
+
+async fn fetch_summary(context: &mut ProgramTestContext, asset: Pubkey) -> ValidationSummaryV1 {
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    ValidationSummaryV1::from_asset_data(&asset, &account.data).unwrap()
+}
+
+/// A registered agent with a pending request at nonce 0, from the agent to
+/// the returned validator. Returns the collection and the asset first.
+async fn setup_request(context: &mut ProgramTestContext) -> (Pubkey, Pubkey, Keypair) {
+    let (collection, asset) = setup_agent_in_collection(context).await;
+    // The payer owns the agent asset.
+    let requester = context.payer.insecure_clone();
+    let validator = register_validator(context, 0).await;
+
+    let ix = request_validation_ix(context, asset, requester.pubkey(), validator.pubkey(), 0);
+    process(context, ix, &requester).await.unwrap();
+
+    (collection, asset, validator)
+}
+
+#[tokio::test]
+async fn client_can_request_validation() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+    let requester = Keypair::new();
+    let validator = register_validator(&mut context, 0).await.pubkey();
+
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), validator, 42);
+    let events = process(&mut context, ix, &requester).await.unwrap();
+
+    let (_, expected_bump) = ValidationRequestV1::find_pda(&asset, 42);
+    let request = fetch_request(&mut context, asset, 42).await;
+    assert_eq!(request.key, Key::ValidationRequestV1);
+    assert_eq!(request.bump, expected_bump);
+    assert_eq!(request.status, ValidationStatus::Pending);
+    assert_eq!(request.result, 0);
+    assert_eq!(request.asset, asset);
+    assert_eq!(request.requester, requester.pubkey());
+    assert_eq!(request.validator, validator);
+    assert_eq!(request.nonce, 42);
+    assert!(request.request_slot > 0);
+    assert_eq!(request.response_slot, 0);
+    assert_eq!(request.work_hash, WORK_HASH);
+    assert_eq!(request.evidence_hash, [0u8; 32]);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidationRequestedV1 {
+            asset,
+            nonce: 42,
+            requester: requester.pubkey(),
+            validator,
+            work_hash: WORK_HASH,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn agent_can_request_validation() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+
+    // The payer owns the agent asset.
+    let owner = context.payer.insecure_clone();
+    let validator = register_validator(&mut context, 0).await;
+    let ix = request_validation_ix(&context, asset, owner.pubkey(), validator.pubkey(), 0);
+    process(&mut context, ix, &owner).await.unwrap();
+
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.requester, owner.pubkey());
+}
+
+#[tokio::test]
+async fn validator_can_respond() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator) = setup_request(&mut context).await;
+
+    let ix = respond_validation_ix(&context, collection, asset, 0, validator.pubkey(), 87);
+    let events = process(&mut context, ix, &validator).await.unwrap();
+
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.status, ValidationStatus::Responded);
+    assert_eq!(request.result, 87);
+    assert_eq!(request.evidence_type, EvidenceType::Uri);
+    assert!(request.response_slot >= request.request_slot);
+    assert_eq!(request.evidence_hash, EVIDENCE_HASH);
+    assert_eq!(request.work_hash, WORK_HASH);
+    assert_eq!(request.counted, 1);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidationRespondedV1 {
+            asset,
+            nonce: 0,
+            validator: validator.pubkey(),
+            result: 87,
+            evidence_uri: EVIDENCE_URI.to_string(),
+            evidence_hash: EVIDENCE_HASH,
+        }]
+    );
+
+    // The agent validation is upgraded and holds the aggregates.
+    let agent_validation = fetch_agent_validation(&mut context, asset).await;
+    assert_eq!(agent_validation.key, Key::AgentValidationV2);
+    assert_eq!(agent_validation.asset, asset);
+    assert_eq!(agent_validation.validation_count, 1);
+    assert_eq!(agent_validation.pass_count, 1);
+    assert_eq!(agent_validation.last_validated_slot, request.response_slot);
+    assert_eq!(agent_validation.top_validator, validator.pubkey());
+    assert_eq!(agent_validation.top_validator_count, 1);
+
+    // And the summary is written to the AppData plugin.
+    assert_eq!(
+        fetch_summary(&mut context, asset).await,
+        ValidationSummaryV1 {
+            pass_rate_bps: 10_000,
+            validation_count: 1,
+            pass_count: 1,
+            last_validated_slot: request.response_slot,
+            top_validator: validator.pubkey(),
+            top_validator_count: 1,
+        }
+    );
+}
+
+#[tokio::test]
+async fn summary_tracks_pass_rate_and_top_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, first) = setup_request(&mut context).await;
+
+    // A second validator is requested twice.
+    let second = register_validator(&mut context, 0).await;
+    let requester = context.payer.insecure_clone();
+    for nonce in [1, 2] {
+        let ix = request_validation_ix(&context, asset, requester.pubkey(), second.pubkey(), nonce);
+        process(&mut context, ix, &requester).await.unwrap();
+    }
+
+    // The first validator passes the work, the second fails it once and
+    // passes it once.
+    let ix = respond_validation_ix(&context, collection, asset, 0, first.pubkey(), 87);
+    process(&mut context, ix, &first).await.unwrap();
+    let ix = respond_validation_ix(&context, collection, asset, 1, second.pubkey(), 20);
+    process(&mut context, ix, &second).await.unwrap();
+
+    // Ties keep the validator that reached the count first.
+    let summary = fetch_summary(&mut context, asset).await;
+    assert_eq!(summary.top_validator, first.pubkey());
+    assert_eq!(summary.pass_rate_bps, 5_000);
+
+    let ix = respond_validation_ix(&context, collection, asset, 2, second.pubkey(), 60);
+    process(&mut context, ix, &second).await.unwrap();
+
+    let summary = fetch_summary(&mut context, asset).await;
+    assert_eq!(summary.validation_count, 3);
+    assert_eq!(summary.pass_count, 2);
+    assert_eq!(summary.pass_rate_bps, 6_666);
+    assert_eq!(summary.top_validator, second.pubkey());
+    assert_eq!(summary.top_validator_count, 2);
+    assert_eq!(
+        summary.last_validated_slot,
+        fetch_request(&mut context, asset, 2).await.response_slot
+    );
+
+    let account = context
+        .banks_client
+        .get_account(ValidatorHistoryV1::find_pda(&asset, &second.pubkey()).0)
+        .await
+        .unwrap()
+        .unwrap();
+    let validator_history = ValidatorHistoryV1::from_bytes(&account.data).unwrap();
+    assert_eq!(validator_history.key, Key::ValidatorHistoryV1);
+    assert_eq!(validator_history.asset, asset);
+    assert_eq!(validator_history.validator, second.pubkey());
+    assert_eq!(validator_history.validation_count, 2);
+}
+
+#[tokio::test]
+async fn response_to_a_client_is_not_counted() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset) = setup_agent_in_collection(&mut context).await;
+    let requester = Keypair::new();
+    let validator = register_validator(&mut context, 0).await;
+
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), validator.pubkey(), 0);
+    process(&mut context, ix, &requester).await.unwrap();
+    let ix = respond_validation_ix(&context, collection, asset, 0, validator.pubkey(), 87);
+    process(&mut context, ix, &validator).await.unwrap();
+
+    // The response is recorded in the request only.
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.status, ValidationStatus::Responded);
+    assert_eq!(request.result, 87);
+    assert_eq!(request.counted, 0);
+
+    let account = context
+        .banks_client
+        .get_account(AgentValidationV2::find_pda(&asset).0)
+        .await
+        .unwrap()
+        .unwrap();
+    assert_eq!(account.data.len(), AgentValidationV1::LEN);
+    assert!(context
+        .banks_client
+        .get_account(ValidatorHistoryV1::find_pda(&asset, &validator.pubkey()).0)
+        .await
+        .unwrap()
+        .is_none());
+    let account = context
+        .banks_client
+        .get_account(asset)
+        .await
+        .unwrap()
+        .unwrap();
+    assert!(ValidationSummaryV1::from_asset_data(&asset, &account.data).is_none());
+}
+
+#[tokio::test]
+async fn cannot_respond_with_another_asset() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset, validator) = setup_request(&mut context).await;
+    let (collection, other_asset) = setup_agent_in_collection(&mut context).await;
+
+    let ix = RespondValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, 0).0)
+        .agent_validation(AgentValidationV2::find_pda(&other_asset).0)
+        .validator_history(ValidatorHistoryV1::find_pda(&other_asset, &validator.pubkey()).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator.pubkey()).0)
+        .asset(other_asset)
+        .collection(Some(collection))
+        .validator(validator.pubkey())
+        .payer(context.payer.pubkey())
+        .result(87)
+        .evidence_hash(EVIDENCE_HASH)
+        .evidence_uri(EVIDENCE_URI.to_string())
+        .instruction();
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::AssetMismatch as u32);
+}
+
+#[tokio::test]
+async fn validator_cannot_respond_below_its_requested_stake() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset) = setup_agent_in_collection(&mut context).await;
+    let requester = Keypair::new();
+    let validator = register_validator(&mut context, 1_000_000_000).await;
+
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), validator.pubkey(), 0);
+    process(&mut context, ix, &requester).await.unwrap();
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.validator_stake, 1_000_000_000);
+
+    // The validator starts unbonding its stake before responding.
+    let ix = UnbondValidatorStakeV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator.pubkey()).0)
+        .validation_config(ValidationConfigV1::find_pda().0)
+        .validator(validator.pubkey())
+        .amount(1)
+        .instruction();
+    process(&mut context, ix, &validator).await.unwrap();
+
+    let ix = respond_validation_ix(&context, collection, asset, 0, validator.pubkey(), 87);
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InsufficientValidatorStake as u32,
+    );
+
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.status, ValidationStatus::Pending);
+}
+
+#[tokio::test]
+async fn cannot_respond_with_the_profile_of_another_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator) = setup_request(&mut context).await;
+    let other = register_validator(&mut context, 0).await;
+
+    let mut ix = respond_validation_ix(&context, collection, asset, 0, validator.pubkey(), 87);
+    ix.accounts[9].pubkey = ValidatorProfileV1::find_pda(&other.pubkey()).0;
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidValidatorProfile as u32);
+}
+
+#[tokio::test]
+async fn requests_are_keyed_on_nonce() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator) = setup_request(&mut context).await;
+
+    let requester = Keypair::new();
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), validator.pubkey(), 1);
+    process(&mut context, ix, &requester).await.unwrap();
+
+    let ix = respond_validation_ix(&context, collection, asset, 1, validator.pubkey(), 20);
+    process(&mut context, ix, &validator).await.unwrap();
+
+    assert_eq!(
+        fetch_request(&mut context, asset, 0).await.status,
+        ValidationStatus::Pending
+    );
+    assert_eq!(fetch_request(&mut context, asset, 1).await.result, 20);
+}
+
+#[tokio::test]
+async fn cannot_request_validation_for_unregistered_asset() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset) = setup::create_collection_and_asset(&mut context).await;
+
+    let requester = Keypair::new();
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), Pubkey::new_unique(), 0);
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::AgentValidationNotRegistered as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_reuse_a_nonce() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset, _) = setup_request(&mut context).await;
+
+    let requester = Keypair::new();
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), Pubkey::new_unique(), 0);
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidationRequestAlreadyExists as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_request_validation_of_empty_work_hash() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+
+    let requester = Keypair::new();
+    let mut builder = RequestValidationV1Builder::new();
+    builder
+        .validation_request(ValidationRequestV1::find_pda(&asset, 0).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .validator_profile(Pubkey::new_unique())
+        .asset(asset)
+        .requester(requester.pubkey())
+        .payer(context.payer.pubkey())
+        .nonce(0)
+        .work_hash([0u8; 32])
+        .validator(Pubkey::new_unique());
+    let err = process(&mut context, builder.instruction(), &requester)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::EmptyWorkHash as u32);
+}
+
+#[tokio::test]
+async fn cannot_request_unregistered_validator() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+
+    let requester = Keypair::new();
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), Pubkey::new_unique(), 0);
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::ValidatorNotRegistered as u32);
+}
+
+#[tokio::test]
+async fn requester_cannot_validate_their_own_request() {
+    let mut context = setup::setup().start_with_context().await;
+    let asset = setup_agent(&mut context).await;
+
+    let requester = Keypair::new();
+    let ix = request_validation_ix(&context, asset, requester.pubkey(), requester.pubkey(), 0);
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::RequesterCannotValidate as u32);
+}
+
+#[tokio::test]
+async fn only_designated_validator_can_respond() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, _) = setup_request(&mut context).await;
+
+    let impostor = Keypair::new();
+    let ix = respond_validation_ix(&context, collection, asset, 0, impostor.pubkey(), 87);
+    let err = process(&mut context, ix, &impostor).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::OnlyDesignatedValidatorCanRespond as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_respond_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator) = setup_request(&mut context).await;
+
+    let ix = respond_validation_ix(&context, collection, asset, 0, validator.pubkey(), 87);
+    process(&mut context, ix, &validator).await.unwrap();
+
+    let ix = respond_validation_ix(&context, collection, asset, 0, validator.pubkey(), 12);
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidationAlreadyResponded as u32,
+    );
+    assert_eq!(fetch_request(&mut context, asset, 0).await.result, 87);
+}
+
+#[tokio::test]
+async fn cannot_respond_above_max_result() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator) = setup_request(&mut context).await;
+
+    let ix = respond_validation_ix(&context, collection, asset, 0, validator.pubkey(), 101);
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidValidationResult as u32);
+}
+
+#[tokio::test]
+async fn cannot_respond_with_invalid_evidence_uri() {
+    let mut context = setup::setup().start_with_context().await;
+    let (collection, asset, validator) = setup_request(&mut context).await;
+
+    for (uri, error) in [
+        ("", MplAgentValidationError::EvidenceUriEmpty),
+        (
+            "http://example.com/evidence.json",
+            MplAgentValidationError::EvidenceUriSchemeNotAllowed,
+        ),
+    ] {
+        let ix = RespondValidationV1Builder::new()
+            .validation_request(ValidationRequestV1::find_pda(&asset, 0).0)
+            .agent_validation(AgentValidationV2::find_pda(&asset).0)
+            .validator_history(ValidatorHistoryV1::find_pda(&asset, &validator.pubkey()).0)
+            .validator_profile(ValidatorProfileV1::find_pda(&validator.pubkey()).0)
+            .asset(asset)
+            .collection(Some(collection))
+            .validator(validator.pubkey())
+            .payer(context.payer.pubkey())
+            .result(50)
+            .evidence_hash(EVIDENCE_HASH)
+            .evidence_uri(uri.to_string())
+            .instruction();
+        let err = process(&mut context, ix, &validator).await.unwrap_err();
+        setup::assert_custom_error(err, error as u32);
+    }
+}
+
+#[test]
+fn decode_summary() {
+    let top_validator = Pubkey::new_unique();
+    let mut data = vec![ValidationSummaryV1::VERSION, 0];
+    data.extend_from_slice(&7_500u16.to_le_bytes());
+    data.extend_from_slice(&[0u8; 4]);
+    for field in [4u64, 3, 42] {
+        data.extend_from_slice(&field.to_le_bytes());
+    }
+    data.extend_from_slice(top_validator.as_ref());
+    data.extend_from_slice(&3u64.to_le_bytes());
+
+    let summary = ValidationSummaryV1::from_bytes(&data).unwrap();
+    assert_eq!(summary.pass_rate_bps, 7_500);
+    assert_eq!(summary.validation_count, 4);
+    assert_eq!(summary.pass_count, 3);
+    assert_eq!(summary.last_validated_slot, 42);
+    assert_eq!(summary.top_validator, top_validator);
+    assert_eq!(summary.top_validator_count, 3);
+    assert_eq!(summary.pass_rate(), Some(0.75));
+
+    // Truncated data and unknown versions are rejected.
+    assert_eq!(ValidationSummaryV1::from_bytes(&data[..71]), None);
+    data[0] = 2;
+    assert_eq!(ValidationSummaryV1::from_bytes(&data), None);
+}
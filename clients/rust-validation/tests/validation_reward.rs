@@ -0,0 +1,516 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_validation::{
+    accounts::{
+        AgentValidationV1, AgentValidationV2, ValidationRequestV1, ValidatorHistoryV1,
+        ValidatorProfileV1,
+    },
+    errors::MplAgentValidationError,
+    events::MplAgentValidationEvent,
+    instructions::{
+        ReclaimValidationRequestV1Builder, RequestValidationV1Builder, RespondValidationV1Builder,
+    },
+    types::ValidationStatus,
+};
+use setup::{
+    fetch_request, fund, process, register_validator, setup_agent_in_collection, EVIDENCE_URI,
+};
+use solana_program::{clock::Clock, instruction::Instruction};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    account::AccountSharedData,
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+// Mirrors `DEFAULT_VALIDATION_EXPIRY_SLOTS` and `MAX_VALIDATION_EXPIRY_SLOTS`
+// of the program.
+const DEFAULT_VALIDATION_EXPIRY_SLOTS: u64 = 216_000;
+const MAX_VALIDATION_EXPIRY_SLOTS: u64 = 6_480_000;
+
+const WORK_HASH: [u8; 32] = [7u8; 32];
+
+const REWARD: u64 = 50_000_000;
+
+fn request_ix(
+    context: &ProgramTestContext,
+    asset: Pubkey,
+    requester: Pubkey,
+    validator: Pubkey,
+    expiry_slot: u64,
+) -> Instruction {
+    RequestValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, 0).0)
+        .agent_validation(AgentValidationV1::find_pda(&asset).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .asset(asset)
+        .requester(requester)
+        .payer(context.payer.pubkey())
+        .nonce(0)
+        .work_hash(WORK_HASH)
+        .validator(validator)
+        .reward(REWARD)
+        .expiry_slot(expiry_slot)
+        .instruction()
+}
+
+fn respond_ix(
+    context: &ProgramTestContext,
+    collection: Pubkey,
+    asset: Pubkey,
+    validator: Pubkey,
+) -> Instruction {
+    RespondValidationV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, 0).0)
+        .agent_validation(AgentValidationV2::find_pda(&asset).0)
+        .validator_history(ValidatorHistoryV1::find_pda(&asset, &validator).0)
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .asset(asset)
+        .collection(Some(collection))
+        .validator(validator)
+        .payer(context.payer.pubkey())
+        .result(80)
+        .evidence_hash([9u8; 32])
+        .evidence_uri(EVIDENCE_URI.to_string())
+        .instruction()
+}
+
+fn reclaim_ix(asset: Pubkey, requester: Pubkey, destination: Pubkey) -> Instruction {
+    ReclaimValidationRequestV1Builder::new()
+        .validation_request(ValidationRequestV1::find_pda(&asset, 0).0)
+        .requester(requester)
+        .destination(destination)
+        .instruction()
+}
+
+async fn current_slot(context: &mut ProgramTestContext) -> u64 {
+    let clock: Clock = context.banks_client.get_sysvar().await.unwrap();
+    clock.slot
+}
+
+async fn balance(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context.banks_client.get_balance(address).await.unwrap()
+}
+
+struct RewardedRequest {
+    collection: Pubkey,
+    asset: Pubkey,
+    requester: Keypair,
+    validator: Keypair,
+    expiry_slot: u64,
+}
+
+/// A registered agent with a pending request at nonce 0 escrowing `REWARD`
+/// and expiring 100 slots from now.
+async fn setup_rewarded_request(context: &mut ProgramTestContext) -> RewardedRequest {
+    let (collection, asset) = setup_agent_in_collection(context).await;
+    let requester = Keypair::new();
+    fund(context, requester.pubkey(), 1_000_000_000).await;
+    let validator = register_validator(context, 0).await;
+
+    let expiry_slot = current_slot(context).await + 100;
+    let ix = request_ix(
+        context,
+        asset,
+        requester.pubkey(),
+        validator.pubkey(),
+        expiry_slot,
+    );
+    process(context, ix, &requester).await.unwrap();
+
+    RewardedRequest {
+        collection,
+        asset,
+        requester,
+        validator,
+        expiry_slot,
+    }
+}
+
+/// Rewrite a pending request as one made before requests escrowed a reward
+/// and expired: zero reward and expiry slot, funded only for its rent.
+async fn downgrade_to_legacy(context: &mut ProgramTestContext, asset: Pubkey) {
+    let address = ValidationRequestV1::find_pda(&asset, 0).0;
+    let account = context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .unwrap();
+
+    // The reward and expiry slot follow the evidence hash at offset 160.
+    let mut data = account.data.clone();
+    data[192..208].fill(0);
+
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let mut account_data = AccountSharedData::new(
+        rent.minimum_balance(ValidationRequestV1::LEN),
+        ValidationRequestV1::LEN,
+        &mpl_agent_validation::ID,
+    );
+    account_data.set_data_from_slice(&data);
+    context.set_account(&address, &account_data);
+}
+
+#[tokio::test]
+async fn requester_escrows_the_reward() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset) = setup_agent_in_collection(&mut context).await;
+    let requester = Keypair::new();
+    fund(&mut context, requester.pubkey(), 1_000_000_000).await;
+    let validator = register_validator(&mut context, 0).await.pubkey();
+
+    let expiry_slot = current_slot(&mut context).await + 1_000;
+    let ix = request_ix(&context, asset, requester.pubkey(), validator, expiry_slot);
+    let events = process(&mut context, ix, &requester).await.unwrap();
+
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(request.reward, REWARD);
+    assert_eq!(request.expiry_slot, expiry_slot);
+
+    // The request holds the reward on top of its rent, paid by the payer.
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let address = ValidationRequestV1::find_pda(&asset, 0).0;
+    assert_eq!(
+        balance(&mut context, address).await,
+        rent.minimum_balance(ValidationRequestV1::LEN) + REWARD
+    );
+    assert_eq!(
+        balance(&mut context, requester.pubkey()).await,
+        1_000_000_000 - REWARD
+    );
+
+    assert_eq!(
+        events,
+        vec![
+            MplAgentValidationEvent::ValidationRequestedV1 {
+                asset,
+                nonce: 0,
+                requester: requester.pubkey(),
+                validator,
+                work_hash: WORK_HASH,
+            },
+            MplAgentValidationEvent::ValidationRewardEscrowedV1 {
+                asset,
+                nonce: 0,
+                requester: requester.pubkey(),
+                reward: REWARD,
+                expiry_slot,
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn request_expires_after_the_default_expiry() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset) = setup_agent_in_collection(&mut context).await;
+    let requester = Keypair::new();
+    fund(&mut context, requester.pubkey(), 1_000_000_000).await;
+    let validator = register_validator(&mut context, 0).await.pubkey();
+
+    let ix = request_ix(&context, asset, requester.pubkey(), validator, 0);
+    process(&mut context, ix, &requester).await.unwrap();
+
+    let request = fetch_request(&mut context, asset, 0).await;
+    assert_eq!(
+        request.expiry_slot,
+        request.request_slot + DEFAULT_VALIDATION_EXPIRY_SLOTS
+    );
+}
+
+#[tokio::test]
+async fn cannot_request_with_an_invalid_expiry() {
+    let mut context = setup::setup().start_with_context().await;
+    let (_, asset) = setup_agent_in_collection(&mut context).await;
+    let requester = Keypair::new();
+    fund(&mut context, requester.pubkey(), 1_000_000_000).await;
+    let validator = register_validator(&mut context, 0).await.pubkey();
+    let slot = current_slot(&mut context).await;
+
+    // Already expired.
+    let ix = request_ix(&context, asset, requester.pubkey(), validator, slot);
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidExpirySlot as u32);
+
+    // Beyond the maximum expiry.
+    let ix = request_ix(
+        &context,
+        asset,
+        requester.pubkey(),
+        validator,
+        slot + MAX_VALIDATION_EXPIRY_SLOTS + 1,
+    );
+    let err = process(&mut context, ix, &requester).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidExpirySlot as u32);
+}
+
+#[tokio::test]
+async fn validator_claims_the_reward_by_responding() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+    let validator = request.validator.pubkey();
+    let before = balance(&mut context, validator).await;
+
+    let ix = respond_ix(&context, request.collection, request.asset, validator);
+    let events = process(&mut context, ix, &request.validator).await.unwrap();
+
+    // The payer pays the fees, so the validator receives the whole reward.
+    assert_eq!(balance(&mut context, validator).await, before + REWARD);
+    let rent = context.banks_client.get_rent().await.unwrap();
+    let address = ValidationRequestV1::find_pda(&request.asset, 0).0;
+    assert_eq!(
+        balance(&mut context, address).await,
+        rent.minimum_balance(ValidationRequestV1::LEN)
+    );
+
+    assert_eq!(
+        events[0],
+        MplAgentValidationEvent::ValidationRewardClaimedV1 {
+            asset: request.asset,
+            nonce: 0,
+            validator,
+            reward: REWARD,
+        }
+    );
+}
+
+#[tokio::test]
+async fn validator_can_respond_until_the_expiry_slot() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+
+    context.warp_to_slot(request.expiry_slot - 1).unwrap();
+    let ix = respond_ix(
+        &context,
+        request.collection,
+        request.asset,
+        request.validator.pubkey(),
+    );
+    process(&mut context, ix, &request.validator).await.unwrap();
+
+    let fetched = fetch_request(&mut context, request.asset, 0).await;
+    assert_eq!(fetched.status, ValidationStatus::Responded);
+}
+
+#[tokio::test]
+async fn requester_reclaims_an_expired_request() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+    let address = ValidationRequestV1::find_pda(&request.asset, 0).0;
+    let escrowed = balance(&mut context, address).await;
+
+    // Once expired, the validator can no longer respond.
+    context.warp_to_slot(request.expiry_slot).unwrap();
+    let ix = respond_ix(
+        &context,
+        request.collection,
+        request.asset,
+        request.validator.pubkey(),
+    );
+    let err = process(&mut context, ix, &request.validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidationRequestExpired as u32,
+    );
+
+    // And the requester gets the reward and the rent back.
+    let destination = Pubkey::new_unique();
+    let ix = reclaim_ix(request.asset, request.requester.pubkey(), destination);
+    let events = process(&mut context, ix, &request.requester).await.unwrap();
+
+    assert!(context
+        .banks_client
+        .get_account(address)
+        .await
+        .unwrap()
+        .is_none());
+    assert_eq!(balance(&mut context, destination).await, escrowed);
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidationRequestReclaimedV1 {
+            asset: request.asset,
+            nonce: 0,
+            requester: request.requester.pubkey(),
+            reward: REWARD,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn cannot_reclaim_before_expiry() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+
+    let ix = reclaim_ix(
+        request.asset,
+        request.requester.pubkey(),
+        request.requester.pubkey(),
+    );
+    let err = process(&mut context, ix, &request.requester)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidationRequestNotExpired as u32,
+    );
+
+    // The validator still claims the reward.
+    let validator = request.validator.pubkey();
+    let before = balance(&mut context, validator).await;
+    let ix = respond_ix(&context, request.collection, request.asset, validator);
+    process(&mut context, ix, &request.validator).await.unwrap();
+    assert_eq!(balance(&mut context, validator).await, before + REWARD);
+}
+
+#[tokio::test]
+async fn cannot_reclaim_a_responded_request() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+
+    let ix = respond_ix(
+        &context,
+        request.collection,
+        request.asset,
+        request.validator.pubkey(),
+    );
+    process(&mut context, ix, &request.validator).await.unwrap();
+
+    // Even after expiry, the reward stays with the validator.
+    context.warp_to_slot(request.expiry_slot).unwrap();
+    let ix = reclaim_ix(
+        request.asset,
+        request.requester.pubkey(),
+        request.requester.pubkey(),
+    );
+    let err = process(&mut context, ix, &request.requester)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidationAlreadyResponded as u32,
+    );
+
+    let fetched = fetch_request(&mut context, request.asset, 0).await;
+    assert_eq!(fetched.status, ValidationStatus::Responded);
+}
+
+#[tokio::test]
+async fn cannot_respond_to_a_reclaimed_request() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+
+    context.warp_to_slot(request.expiry_slot).unwrap();
+    let ix = reclaim_ix(
+        request.asset,
+        request.requester.pubkey(),
+        request.requester.pubkey(),
+    );
+    process(&mut context, ix, &request.requester).await.unwrap();
+
+    let ix = respond_ix(
+        &context,
+        request.collection,
+        request.asset,
+        request.validator.pubkey(),
+    );
+    let err = process(&mut context, ix, &request.validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InvalidValidationRequest as u32,
+    );
+}
+
+#[tokio::test]
+async fn only_the_requester_can_reclaim() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+
+    context.warp_to_slot(request.expiry_slot).unwrap();
+    let stranger = Keypair::new();
+    let ix = reclaim_ix(request.asset, stranger.pubkey(), stranger.pubkey());
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::OnlyRequesterCanReclaim as u32);
+}
+
+#[tokio::test]
+async fn legacy_request_expires_after_the_default_expiry() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+    downgrade_to_legacy(&mut context, request.asset).await;
+    let legacy = fetch_request(&mut context, request.asset, 0).await;
+    assert_eq!(legacy.expiry_slot, 0);
+
+    // Not yet reclaimable.
+    let ix = reclaim_ix(
+        request.asset,
+        request.requester.pubkey(),
+        request.requester.pubkey(),
+    );
+    let err = process(&mut context, ix, &request.requester)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidationRequestNotExpired as u32,
+    );
+
+    // And the validator can still respond, without a reward.
+    let validator = request.validator.pubkey();
+    let before = balance(&mut context, validator).await;
+    let ix = respond_ix(&context, request.collection, request.asset, validator);
+    let events = process(&mut context, ix, &request.validator).await.unwrap();
+    assert_eq!(balance(&mut context, validator).await, before);
+    assert!(matches!(
+        events[0],
+        MplAgentValidationEvent::ValidationRespondedV1 { .. }
+    ));
+}
+
+#[tokio::test]
+async fn legacy_request_is_reclaimable_after_the_default_expiry() {
+    let mut context = setup::setup().start_with_context().await;
+    let request = setup_rewarded_request(&mut context).await;
+    downgrade_to_legacy(&mut context, request.asset).await;
+    let legacy = fetch_request(&mut context, request.asset, 0).await;
+
+    context
+        .warp_to_slot(legacy.request_slot + DEFAULT_VALIDATION_EXPIRY_SLOTS)
+        .unwrap();
+    let ix = respond_ix(
+        &context,
+        request.collection,
+        request.asset,
+        request.validator.pubkey(),
+    );
+    let err = process(&mut context, ix, &request.validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidationRequestExpired as u32,
+    );
+
+    let ix = reclaim_ix(
+        request.asset,
+        request.requester.pubkey(),
+        request.requester.pubkey(),
+    );
+    let events = process(&mut context, ix, &request.requester).await.unwrap();
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidationRequestReclaimedV1 {
+            asset: request.asset,
+            nonce: 0,
+            requester: request.requester.pubkey(),
+            reward: 0,
+        }]
+    );
+}
@@ -0,0 +1,388 @@
+#![cfg(feature = "test-sbf")]
+
+mod setup;
+
+use mpl_agent_validation::{
+    accounts::{ValidationConfigV1, ValidatorProfileV1, ValidatorStakeVaultV1},
+    errors::MplAgentValidationError,
+    events::MplAgentValidationEvent,
+    instructions::{
+        RegisterValidatorV1Builder, SlashValidatorV1Builder, UnbondValidatorStakeV1Builder,
+        WithdrawValidatorStakeV1Builder,
+    },
+    types::Key,
+};
+use setup::{fetch_validator_profile, process, register_validator, start_with_config, vault_stake};
+use solana_program::{clock::Clock, instruction::Instruction, native_token::LAMPORTS_PER_SOL};
+use solana_program_test::{tokio, ProgramTestContext};
+use solana_sdk::{
+    pubkey::Pubkey,
+    signature::{Keypair, Signer},
+};
+
+const STAKE: u64 = 10 * LAMPORTS_PER_SOL;
+
+const UNBONDING_SLOTS: u64 = 1_000;
+
+// Unbonding period the program falls back to while the config is not initialized.
+const DEFAULT_UNBONDING_SLOTS: u64 = 1_512_000;
+
+fn unbond_ix(validator: Pubkey, amount: u64) -> Instruction {
+    UnbondValidatorStakeV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .validation_config(ValidationConfigV1::find_pda().0)
+        .validator(validator)
+        .amount(amount)
+        .instruction()
+}
+
+fn withdraw_ix(validator: Pubkey) -> Instruction {
+    WithdrawValidatorStakeV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .validator_stake_vault(ValidatorStakeVaultV1::find_pda(&validator).0)
+        .validator(validator)
+        .instruction()
+}
+
+fn slash_ix(validator: Pubkey, arbiter: Pubkey, destination: Pubkey, amount: u64) -> Instruction {
+    SlashValidatorV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator).0)
+        .validator_stake_vault(ValidatorStakeVaultV1::find_pda(&validator).0)
+        .validation_config(ValidationConfigV1::find_pda().0)
+        .arbiter(arbiter)
+        .destination(destination)
+        .amount(amount)
+        .instruction()
+}
+
+async fn current_slot(context: &mut ProgramTestContext) -> u64 {
+    context
+        .banks_client
+        .get_sysvar::<Clock>()
+        .await
+        .unwrap()
+        .slot
+}
+
+async fn balance(context: &mut ProgramTestContext, address: Pubkey) -> u64 {
+    context.banks_client.get_balance(address).await.unwrap()
+}
+
+#[tokio::test]
+async fn validator_can_register_with_stake() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = Keypair::new();
+    setup::fund(&mut context, validator.pubkey(), STAKE + LAMPORTS_PER_SOL).await;
+
+    let ix = RegisterValidatorV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator.pubkey()).0)
+        .validator_stake_vault(ValidatorStakeVaultV1::find_pda(&validator.pubkey()).0)
+        .validator(validator.pubkey())
+        .payer(context.payer.pubkey())
+        .stake(STAKE)
+        .instruction();
+    let events = process(&mut context, ix, &validator).await.unwrap();
+
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert_eq!(profile.key, Key::ValidatorProfileV1);
+    assert_eq!(
+        profile.bump,
+        ValidatorProfileV1::find_pda(&validator.pubkey()).1
+    );
+    assert_eq!(profile.authority, validator.pubkey());
+    assert_eq!(profile.stake, STAKE);
+    assert_eq!(profile.unbonding_stake, 0);
+    assert_eq!(profile.unbonding_slot, 0);
+    assert_eq!(profile.slashed_stake, 0);
+    assert!(profile.registered_slot > 0);
+
+    assert_eq!(vault_stake(&mut context, validator.pubkey()).await, STAKE);
+    assert_eq!(
+        balance(&mut context, validator.pubkey()).await,
+        LAMPORTS_PER_SOL
+    );
+
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorRegisteredV1 {
+            validator: validator.pubkey(),
+            stake: STAKE,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn validator_can_register_without_stake() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = register_validator(&mut context, 0).await;
+
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert_eq!(profile.stake, 0);
+    assert_eq!(vault_stake(&mut context, validator.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn cannot_register_twice() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = register_validator(&mut context, 0).await;
+
+    let ix = RegisterValidatorV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator.pubkey()).0)
+        .validator_stake_vault(ValidatorStakeVaultV1::find_pda(&validator.pubkey()).0)
+        .validator(validator.pubkey())
+        .payer(context.payer.pubkey())
+        .stake(STAKE)
+        .instruction();
+    let err = process(&mut context, ix, &validator).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::ValidatorAlreadyRegistered as u32,
+    );
+}
+
+#[tokio::test]
+async fn validator_can_withdraw_stake_after_unbonding() {
+    let (mut context, _) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+
+    // Given a validator unbonding part of its stake.
+    let events = process(
+        &mut context,
+        unbond_ix(validator.pubkey(), 4 * LAMPORTS_PER_SOL),
+        &validator,
+    )
+    .await
+    .unwrap();
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert_eq!(profile.stake, 6 * LAMPORTS_PER_SOL);
+    assert_eq!(profile.unbonding_stake, 4 * LAMPORTS_PER_SOL);
+    assert!(profile.unbonding_slot >= profile.registered_slot + UNBONDING_SLOTS);
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorStakeUnbondingV1 {
+            validator: validator.pubkey(),
+            amount: 4 * LAMPORTS_PER_SOL,
+            unbonding_stake: 4 * LAMPORTS_PER_SOL,
+            unbonding_slot: profile.unbonding_slot,
+        }]
+    );
+
+    // When the unbonding period is over and the validator withdraws.
+    context.warp_to_slot(profile.unbonding_slot).unwrap();
+    let before = balance(&mut context, validator.pubkey()).await;
+    let events = process(&mut context, withdraw_ix(validator.pubkey()), &validator)
+        .await
+        .unwrap();
+
+    // Then the unbonded stake is paid out and the rest stays bonded.
+    assert_eq!(
+        balance(&mut context, validator.pubkey()).await,
+        before + 4 * LAMPORTS_PER_SOL
+    );
+    assert_eq!(
+        vault_stake(&mut context, validator.pubkey()).await,
+        6 * LAMPORTS_PER_SOL
+    );
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert_eq!(profile.stake, 6 * LAMPORTS_PER_SOL);
+    assert_eq!(profile.unbonding_stake, 0);
+    assert_eq!(profile.unbonding_slot, 0);
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorStakeWithdrawnV1 {
+            validator: validator.pubkey(),
+            amount: 4 * LAMPORTS_PER_SOL,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn unbonding_uses_default_period_without_config() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = register_validator(&mut context, STAKE).await;
+
+    let slot = current_slot(&mut context).await;
+    process(
+        &mut context,
+        unbond_ix(validator.pubkey(), STAKE),
+        &validator,
+    )
+    .await
+    .unwrap();
+
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert!(profile.unbonding_slot >= slot + DEFAULT_UNBONDING_SLOTS);
+}
+
+#[tokio::test]
+async fn cannot_withdraw_while_unbonding() {
+    let (mut context, _) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+    process(
+        &mut context,
+        unbond_ix(validator.pubkey(), STAKE),
+        &validator,
+    )
+    .await
+    .unwrap();
+
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    context.warp_to_slot(profile.unbonding_slot - 1).unwrap();
+    let err = process(&mut context, withdraw_ix(validator.pubkey()), &validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::StakeStillUnbonding as u32);
+}
+
+#[tokio::test]
+async fn cannot_withdraw_bonded_stake() {
+    let (mut context, _) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+
+    let err = process(&mut context, withdraw_ix(validator.pubkey()), &validator)
+        .await
+        .unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::NoUnbondingStake as u32);
+}
+
+#[tokio::test]
+async fn cannot_unbond_more_than_stake() {
+    let (mut context, _) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+
+    let err = process(
+        &mut context,
+        unbond_ix(validator.pubkey(), STAKE + 1),
+        &validator,
+    )
+    .await
+    .unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InsufficientValidatorStake as u32,
+    );
+}
+
+#[tokio::test]
+async fn arbiter_can_slash_bonded_and_unbonding_stake() {
+    let (mut context, arbiter) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+    process(
+        &mut context,
+        unbond_ix(validator.pubkey(), 4 * LAMPORTS_PER_SOL),
+        &validator,
+    )
+    .await
+    .unwrap();
+
+    // Slashing more than the bonded stake dips into the unbonding stake.
+    let destination = Pubkey::new_unique();
+    let ix = slash_ix(
+        validator.pubkey(),
+        arbiter.pubkey(),
+        destination,
+        8 * LAMPORTS_PER_SOL,
+    );
+    let events = process(&mut context, ix, &arbiter).await.unwrap();
+
+    let profile = fetch_validator_profile(&mut context, validator.pubkey()).await;
+    assert_eq!(profile.stake, 0);
+    assert_eq!(profile.unbonding_stake, 2 * LAMPORTS_PER_SOL);
+    assert_eq!(profile.slashed_stake, 8 * LAMPORTS_PER_SOL);
+    assert_eq!(
+        balance(&mut context, destination).await,
+        8 * LAMPORTS_PER_SOL
+    );
+    assert_eq!(
+        vault_stake(&mut context, validator.pubkey()).await,
+        2 * LAMPORTS_PER_SOL
+    );
+    assert_eq!(
+        events,
+        vec![MplAgentValidationEvent::ValidatorSlashedV1 {
+            validator: validator.pubkey(),
+            arbiter: arbiter.pubkey(),
+            amount: 8 * LAMPORTS_PER_SOL,
+            stake: 0,
+            unbonding_stake: 2 * LAMPORTS_PER_SOL,
+        }]
+    );
+
+    // The validator withdraws what is left once unbonded.
+    context.warp_to_slot(profile.unbonding_slot).unwrap();
+    process(&mut context, withdraw_ix(validator.pubkey()), &validator)
+        .await
+        .unwrap();
+    assert_eq!(vault_stake(&mut context, validator.pubkey()).await, 0);
+}
+
+#[tokio::test]
+async fn only_arbiter_can_slash() {
+    let (mut context, _) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+
+    let stranger = Keypair::new();
+    let ix = slash_ix(
+        validator.pubkey(),
+        stranger.pubkey(),
+        stranger.pubkey(),
+        STAKE,
+    );
+    let err = process(&mut context, ix, &stranger).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidArbiter as u32);
+}
+
+#[tokio::test]
+async fn cannot_slash_without_config() {
+    let mut context = setup::setup().start_with_context().await;
+    let validator = register_validator(&mut context, STAKE).await;
+
+    let arbiter = Keypair::new();
+    let ix = slash_ix(
+        validator.pubkey(),
+        arbiter.pubkey(),
+        arbiter.pubkey(),
+        STAKE,
+    );
+    let err = process(&mut context, ix, &arbiter).await.unwrap_err();
+    setup::assert_custom_error(err, MplAgentValidationError::InvalidValidationConfig as u32);
+}
+
+#[tokio::test]
+async fn cannot_slash_more_than_stake() {
+    let (mut context, arbiter) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+
+    let ix = slash_ix(
+        validator.pubkey(),
+        arbiter.pubkey(),
+        Pubkey::new_unique(),
+        STAKE + 1,
+    );
+    let err = process(&mut context, ix, &arbiter).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InsufficientValidatorStake as u32,
+    );
+}
+
+#[tokio::test]
+async fn cannot_slash_from_another_vault() {
+    let (mut context, arbiter) = start_with_config(UNBONDING_SLOTS).await;
+    let validator = register_validator(&mut context, STAKE).await;
+    let other = register_validator(&mut context, STAKE).await;
+
+    let ix = SlashValidatorV1Builder::new()
+        .validator_profile(ValidatorProfileV1::find_pda(&validator.pubkey()).0)
+        .validator_stake_vault(ValidatorStakeVaultV1::find_pda(&other.pubkey()).0)
+        .validation_config(ValidationConfigV1::find_pda().0)
+        .arbiter(arbiter.pubkey())
+        .destination(Pubkey::new_unique())
+        .amount(STAKE)
+        .instruction();
+    let err = process(&mut context, ix, &arbiter).await.unwrap_err();
+    setup::assert_custom_error(
+        err,
+        MplAgentValidationError::InvalidValidatorStakeVault as u32,
+    );
+}
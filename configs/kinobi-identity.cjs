@@ -32,6 +32,18 @@ kinobi.update(
         k.variablePdaSeedNode("asset", k.publicKeyTypeNode(), "The address of the asset"),
       ],
     },
+    collectionIdentityV1: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("agent_identity"),
+        k.variablePdaSeedNode("collection", k.publicKeyTypeNode(), "The address of the collection"),
+      ],
+    },
+    agentTokenChangeV1: {
+      seeds: [
+        k.constantPdaSeedNodeFromString("agent_token_change"),
+        k.variablePdaSeedNode("asset", k.publicKeyTypeNode(), "The address of the asset"),
+      ],
+    },
   })
 );
 
@@ -42,12 +54,79 @@ kinobi.update(
       accounts: {
         agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
       },
+      arguments: {
+        lifecycleChecks: {defaultValue: k.numberValueNode(0)},
+      },
     },
     setAgentTokenV1: {
       accounts: {
         agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
       },
     },
+    updateAgentRegistrationUriV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+      },
+    },
+    deregisterIdentityV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+      },
+    },
+    migrateIdentityV1ToV2: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+      },
+    },
+    setAgentTokenFromMintV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+      },
+    },
+    setAgentTokenTimelockV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+      },
+    },
+    clearAgentTokenV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+        agentTokenChange: {defaultValue: k.pdaValueNode("agentTokenChangeV1")},
+      },
+    },
+    replaceAgentTokenV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+        agentTokenChange: {defaultValue: k.pdaValueNode("agentTokenChangeV1")},
+      },
+    },
+    updateIdentityLifecycleChecksV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+      },
+      arguments: {
+        lifecycleChecks: {defaultValue: k.numberValueNode(0)},
+      },
+    },
+    registerCollectionIdentityV1: {
+      accounts: {
+        collectionIdentity: {defaultValue: k.pdaValueNode("collectionIdentityV1")},
+      },
+      arguments: {
+        lifecycleChecks: {defaultValue: k.numberValueNode(0)},
+      },
+    },
+    inheritCollectionIdentityV1: {
+      accounts: {
+        agentIdentity: {defaultValue: k.pdaValueNode("agentIdentityV2")},
+        collectionIdentity: {defaultValue: k.pdaValueNode("collectionIdentityV1")},
+      },
+    },
+    registerIdentitiesV1: {
+      arguments: {
+        lifecycleChecks: {defaultValue: k.numberValueNode(0)},
+      },
+    },
   })
 );
 
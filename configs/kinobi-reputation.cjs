@@ -30,9 +30,108 @@ kinobi.update(
                 ),
             ],
         },
+        agentReputationV2: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("agent_reputation"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+            ],
+        },
+        receiptNullifierV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("receipt_nullifier"),
+                k.variablePdaSeedNode(
+                    "treeIndex",
+                    k.numberTypeNode("u64"),
+                    "Index of the receipts tree",
+                ),
+                k.variablePdaSeedNode(
+                    "nonce",
+                    k.numberTypeNode("u64"),
+                    "Nonce of the receipt leaf",
+                ),
+            ],
+        },
+        feedbackV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("feedback"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+                k.variablePdaSeedNode(
+                    "reviewer",
+                    k.publicKeyTypeNode(),
+                    "The address of the reviewer",
+                ),
+                k.variablePdaSeedNode(
+                    "index",
+                    k.numberTypeNode("u64"),
+                    "Index of the feedback among the reviewer's feedback for the asset",
+                ),
+            ],
+        },
+        reputationConfigV1: {
+            seeds: [k.constantPdaSeedNodeFromString("reputation_config")],
+        },
+        weightingPolicyV1: {
+            seeds: [k.constantPdaSeedNodeFromString("weighting_policy")],
+        },
+        reviewerHistoryV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("reviewer_history"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+                k.variablePdaSeedNode(
+                    "reviewer",
+                    k.publicKeyTypeNode(),
+                    "The address of the reviewer",
+                ),
+            ],
+        },
+        reputationSnapshotV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("reputation_snapshot"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+                k.variablePdaSeedNode(
+                    "epoch",
+                    k.numberTypeNode("u64"),
+                    "Epoch the snapshot was taken in",
+                ),
+            ],
+        },
+        tokenLockV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("token_lock"),
+                k.variablePdaSeedNode(
+                    "reviewer",
+                    k.publicKeyTypeNode(),
+                    "The address of the reviewer",
+                ),
+                k.variablePdaSeedNode(
+                    "mint",
+                    k.publicKeyTypeNode(),
+                    "The mint of the locked tokens",
+                ),
+            ],
+        },
     }),
 );
 
+// Well-known program IDs we want to default in the generated client.
+const COMPRESSION_ID = "mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW";
+
 // Update instructions.
 kinobi.update(
     new k.updateInstructionsVisitor({
@@ -43,6 +142,122 @@ kinobi.update(
                 },
             },
         },
+        submitFeedbackV1: {
+            accounts: {
+                agentReputation: {
+                    defaultValue: k.pdaValueNode("agentReputationV2"),
+                },
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+                weightingPolicy: {
+                    defaultValue: k.pdaValueNode("weightingPolicyV1"),
+                },
+                reviewerHistory: {
+                    defaultValue: k.pdaValueNode("reviewerHistoryV1", [
+                        k.pdaSeedValueNode("asset", k.accountValueNode("asset")),
+                        k.pdaSeedValueNode(
+                            "reviewer",
+                            k.accountValueNode("reviewer"),
+                        ),
+                    ]),
+                },
+                compressionProgram: {
+                    defaultValue: k.publicKeyValueNode(
+                        COMPRESSION_ID,
+                        "mplAccountCompression",
+                    ),
+                },
+            },
+        },
+        disputeFeedbackV1: {
+            accounts: {
+                agentReputation: {
+                    defaultValue: k.pdaValueNode("agentReputationV2"),
+                },
+            },
+        },
+        revokeFeedbackV1: {
+            accounts: {
+                agentReputation: {
+                    defaultValue: k.pdaValueNode("agentReputationV2"),
+                },
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+            },
+        },
+        initializeReputationConfigV1: {
+            accounts: {
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+            },
+        },
+        updateReputationConfigV1: {
+            accounts: {
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+            },
+        },
+        refreshReputationV1: {
+            accounts: {
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+            },
+        },
+        registerReputationAsOwnerV1: {
+            accounts: {
+                agentReputation: {
+                    defaultValue: k.pdaValueNode("agentReputationV1"),
+                },
+            },
+        },
+        getAgentProfileV1: {
+            accounts: {
+                agentReputation: {
+                    defaultValue: k.pdaValueNode("agentReputationV2"),
+                },
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+            },
+        },
+        setWeightingPolicyV1: {
+            accounts: {
+                weightingPolicy: {
+                    defaultValue: k.pdaValueNode("weightingPolicyV1"),
+                },
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+            },
+        },
+        snapshotReputationV1: {
+            accounts: {
+                agentReputation: {
+                    defaultValue: k.pdaValueNode("agentReputationV2"),
+                },
+                reputationConfig: {
+                    defaultValue: k.pdaValueNode("reputationConfigV1"),
+                },
+            },
+        },
+        unlockTokensV1: {
+            accounts: {
+                tokenLock: {
+                    defaultValue: k.pdaValueNode("tokenLockV1", [
+                        k.pdaSeedValueNode(
+                            "reviewer",
+                            k.accountValueNode("reviewer"),
+                        ),
+                        k.pdaSeedValueNode("mint", k.accountValueNode("mint")),
+                    ]),
+                },
+            },
+        },
     }),
 );
 
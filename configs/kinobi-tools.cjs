@@ -44,6 +44,36 @@ kinobi.update(
                 ),
             ],
         },
+        executionDelegateCountV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("execution_delegate_count"),
+                k.variablePdaSeedNode(
+                    "agentAsset",
+                    k.publicKeyTypeNode(),
+                    "The address of the agent asset",
+                ),
+            ],
+        },
+        executionDelegateMigrationV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("execution_delegate_migration"),
+            ],
+        },
+        receiptReviewV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("receipt_review"),
+                k.variablePdaSeedNode(
+                    "receiptsTreeIndex",
+                    k.numberTypeNode("u64"),
+                    "Index of the receipts tree",
+                ),
+                k.variablePdaSeedNode(
+                    "receiptNonce",
+                    k.numberTypeNode("u64"),
+                    "Nonce of the receipt leaf",
+                ),
+            ],
+        },
     }),
 );
 
@@ -66,6 +96,20 @@ kinobi.update(
                     "The receipts tree index",
                 ),
             ]),
+            k.pdaNode("reviewsCollection", [
+                k.constantPdaSeedNodeFromString("reviews_collection"),
+            ]),
+            k.pdaNode("reviewsAuthority", [
+                k.constantPdaSeedNodeFromString("reviews_authority"),
+            ]),
+            k.pdaNode("reviewsTree", [
+                k.constantPdaSeedNodeFromString("reviews_tree"),
+                k.variablePdaSeedNode(
+                    "treeIndex",
+                    k.numberTypeNode("u64"),
+                    "The reviews tree index",
+                ),
+            ]),
         ],
     }),
 );
@@ -105,9 +149,32 @@ kinobi.update(
                 executionDelegateRecord: {
                     defaultValue: k.pdaValueNode("executionDelegateRecordV1"),
                 },
+                executionDelegateCount: {
+                    defaultValue: k.pdaValueNode("executionDelegateCountV1"),
+                },
+            },
+        },
+        revokeExecutionV1: {
+            accounts: {
+                executionDelegateCount: {
+                    defaultValue: k.pdaValueNode("executionDelegateCountV1"),
+                },
+            },
+        },
+        countExecutionDelegateV1: {
+            accounts: {
+                executionDelegateCount: {
+                    defaultValue: k.pdaValueNode("executionDelegateCountV1"),
+                },
+            },
+        },
+        completeExecutionDelegateMigrationV1: {
+            accounts: {
+                executionDelegateMigration: {
+                    defaultValue: k.pdaValueNode("executionDelegateMigrationV1"),
+                },
             },
         },
-        revokeExecutionV1: {},
         mintWorkReceiptV1: {
             accounts: {
                 authority: {
@@ -199,6 +266,69 @@ kinobi.update(
                 },
             },
         },
+        createReviewsCollectionV1: {
+            accounts: {
+                collection: {
+                    defaultValue: k.pdaValueNode("reviewsCollection"),
+                },
+                authority: {
+                    defaultValue: k.pdaValueNode("reviewsAuthority"),
+                },
+                mplCoreProgram: {
+                    defaultValue: k.publicKeyValueNode(MPL_CORE_ID, "mplCore"),
+                },
+            },
+        },
+        registerReviewsTreeV1: {
+            accounts: {
+                authority: {
+                    defaultValue: k.pdaValueNode("reviewsAuthority"),
+                },
+                bubblegumProgram: {
+                    defaultValue: k.publicKeyValueNode(
+                        BUBBLEGUM_ID,
+                        "mplBubblegum",
+                    ),
+                },
+                logWrapper: {
+                    defaultValue: k.publicKeyValueNode(MPL_NOOP_ID, "mplNoop"),
+                },
+                compressionProgram: {
+                    defaultValue: k.publicKeyValueNode(
+                        COMPRESSION_ID,
+                        "mplAccountCompression",
+                    ),
+                },
+            },
+        },
+        mintReviewV1: {
+            accounts: {
+                authority: {
+                    defaultValue: k.pdaValueNode("reviewsAuthority"),
+                },
+                coreCollection: {
+                    defaultValue: k.pdaValueNode("reviewsCollection"),
+                },
+                mplCoreProgram: {
+                    defaultValue: k.publicKeyValueNode(MPL_CORE_ID, "mplCore"),
+                },
+                bubblegumProgram: {
+                    defaultValue: k.publicKeyValueNode(
+                        BUBBLEGUM_ID,
+                        "mplBubblegum",
+                    ),
+                },
+                logWrapper: {
+                    defaultValue: k.publicKeyValueNode(MPL_NOOP_ID, "mplNoop"),
+                },
+                compressionProgram: {
+                    defaultValue: k.publicKeyValueNode(
+                        COMPRESSION_ID,
+                        "mplAccountCompression",
+                    ),
+                },
+            },
+        },
     }),
 );
 
@@ -270,6 +400,32 @@ export function findReceiptsTreePda(
     u64().serialize(seeds.treeIndex),
   ]);
 }
+
+export function findReviewsCollectionPda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  return pda(context, [
+    string({ size: 'variable' }).serialize('reviews_collection'),
+  ]);
+}
+
+export function findReviewsAuthorityPda(
+  context: Pick<Context, 'eddsa' | 'programs'>
+): Pda {
+  return pda(context, [
+    string({ size: 'variable' }).serialize('reviews_authority'),
+  ]);
+}
+
+export function findReviewsTreePda(
+  context: Pick<Context, 'eddsa' | 'programs'>,
+  seeds: { treeIndex: number | bigint }
+): Pda {
+  return pda(context, [
+    string({ size: 'variable' }).serialize('reviews_tree'),
+    u64().serialize(seeds.treeIndex),
+  ]);
+}
 `
 );
 
@@ -30,6 +30,99 @@ kinobi.update(
                 ),
             ],
         },
+        validationRequestV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("validation_request"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+                k.variablePdaSeedNode(
+                    "nonce",
+                    k.numberTypeNode("u64"),
+                    "Nonce of the request among the requests for the asset",
+                ),
+            ],
+        },
+        validationConfigV1: {
+            seeds: [k.constantPdaSeedNodeFromString("validation_config")],
+        },
+        validatorProfileV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("validator_profile"),
+                k.variablePdaSeedNode(
+                    "validator",
+                    k.publicKeyTypeNode(),
+                    "The address of the validator authority",
+                ),
+            ],
+        },
+        validatorStakeVaultV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("validator_stake_vault"),
+                k.variablePdaSeedNode(
+                    "validator",
+                    k.publicKeyTypeNode(),
+                    "The address of the validator authority",
+                ),
+            ],
+        },
+        acceptedValidatorV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("accepted_validator"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+                k.variablePdaSeedNode(
+                    "validator",
+                    k.publicKeyTypeNode(),
+                    "The address of the validator authority",
+                ),
+            ],
+        },
+        agentValidationV2: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("agent_validation"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+            ],
+        },
+        validatorHistoryV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("validator_history"),
+                k.variablePdaSeedNode(
+                    "asset",
+                    k.publicKeyTypeNode(),
+                    "The address of the asset",
+                ),
+                k.variablePdaSeedNode(
+                    "validator",
+                    k.publicKeyTypeNode(),
+                    "The address of the validator authority",
+                ),
+            ],
+        },
+        validatorAttesterV1: {
+            seeds: [
+                k.constantPdaSeedNodeFromString("validator_attester"),
+                k.variablePdaSeedNode(
+                    "validator",
+                    k.publicKeyTypeNode(),
+                    "The address of the validator authority",
+                ),
+                k.variablePdaSeedNode(
+                    "attester",
+                    k.publicKeyTypeNode(),
+                    "The ed25519 attester key",
+                ),
+            ],
+        },
     }),
 );
 
@@ -43,6 +136,143 @@ kinobi.update(
                 },
             },
         },
+        requestValidationV1: {
+            accounts: {
+                agentValidation: {
+                    defaultValue: k.pdaValueNode("agentValidationV1"),
+                },
+            },
+            arguments: {
+                reward: { defaultValue: k.numberValueNode(0) },
+                expirySlot: { defaultValue: k.numberValueNode(0) },
+            },
+        },
+        respondValidationV1: {
+            accounts: {
+                agentValidation: {
+                    defaultValue: k.pdaValueNode("agentValidationV2"),
+                },
+                validatorHistory: {
+                    defaultValue: k.pdaValueNode("validatorHistoryV1", [
+                        k.pdaSeedValueNode("asset", k.accountValueNode("asset")),
+                        k.pdaSeedValueNode(
+                            "validator",
+                            k.accountValueNode("validator"),
+                        ),
+                    ]),
+                },
+                validatorProfile: {
+                    defaultValue: k.pdaValueNode("validatorProfileV1"),
+                },
+            },
+        },
+        initializeValidationConfigV1: {
+            accounts: {
+                validationConfig: {
+                    defaultValue: k.pdaValueNode("validationConfigV1"),
+                },
+            },
+        },
+        updateValidationConfigV1: {
+            accounts: {
+                validationConfig: {
+                    defaultValue: k.pdaValueNode("validationConfigV1"),
+                },
+            },
+        },
+        registerValidatorV1: {
+            accounts: {
+                validatorProfile: {
+                    defaultValue: k.pdaValueNode("validatorProfileV1"),
+                },
+                validatorStakeVault: {
+                    defaultValue: k.pdaValueNode("validatorStakeVaultV1"),
+                },
+            },
+        },
+        unbondValidatorStakeV1: {
+            accounts: {
+                validatorProfile: {
+                    defaultValue: k.pdaValueNode("validatorProfileV1"),
+                },
+                validationConfig: {
+                    defaultValue: k.pdaValueNode("validationConfigV1"),
+                },
+            },
+        },
+        withdrawValidatorStakeV1: {
+            accounts: {
+                validatorProfile: {
+                    defaultValue: k.pdaValueNode("validatorProfileV1"),
+                },
+                validatorStakeVault: {
+                    defaultValue: k.pdaValueNode("validatorStakeVaultV1"),
+                },
+            },
+        },
+        slashValidatorV1: {
+            accounts: {
+                validationConfig: {
+                    defaultValue: k.pdaValueNode("validationConfigV1"),
+                },
+            },
+        },
+        addAcceptedValidatorV1: {
+            accounts: {
+                agentValidation: {
+                    defaultValue: k.pdaValueNode("agentValidationV1"),
+                },
+            },
+        },
+        removeAcceptedValidatorV1: {
+            accounts: {
+                agentValidation: {
+                    defaultValue: k.pdaValueNode("agentValidationV1"),
+                },
+            },
+        },
+        addValidatorAttesterV1: {
+            accounts: {
+                validatorAttester: {
+                    defaultValue: k.pdaValueNode("validatorAttesterV1"),
+                },
+                validatorProfile: {
+                    defaultValue: k.pdaValueNode("validatorProfileV1"),
+                },
+            },
+        },
+        removeValidatorAttesterV1: {
+            accounts: {
+                validatorProfile: {
+                    defaultValue: k.pdaValueNode("validatorProfileV1"),
+                },
+            },
+        },
+        respondValidationAttestationV1: {
+            accounts: {
+                agentValidation: {
+                    defaultValue: k.pdaValueNode("agentValidationV2"),
+                },
+                validatorHistory: {
+                    defaultValue: k.pdaValueNode("validatorHistoryV1", [
+                        k.pdaSeedValueNode("asset", k.accountValueNode("asset")),
+                        k.pdaSeedValueNode(
+                            "validator",
+                            k.accountValueNode("validator"),
+                        ),
+                    ]),
+                },
+                instructionsSysvar: {
+                    defaultValue: k.publicKeyValueNode(
+                        "Sysvar1nstructions1111111111111111111111111",
+                        "sysvarInstructions",
+                    ),
+                },
+                validatorProfile: {
+                    defaultValue: k.pdaValueNode("validatorProfileV1"),
+                },
+            },
+        },
     }),
 );
 
@@ -51,6 +51,106 @@ pub enum MplAgentIdentityError {
     /// 11 - Genesis account is not mint-funded
     #[error("Genesis account is not mint-funded")]
     GenesisNotMintFunded,
+
+    /// 12 - Only the asset owner or Asset Signer can update the registration URI
+    #[error("Only the asset owner or Asset Signer can update the registration URI")]
+    OnlyAssetOwnerOrSignerCanUpdateUri,
+
+    /// 13 - Registration URI is empty
+    #[error("Registration URI is empty")]
+    RegistrationUriEmpty,
+
+    /// 14 - Registration URI exceeds the maximum length
+    #[error("Registration URI exceeds the maximum length")]
+    RegistrationUriTooLong,
+
+    /// 15 - Registration URI is not valid UTF-8
+    #[error("Registration URI is not valid UTF-8")]
+    RegistrationUriNotUtf8,
+
+    /// 16 - Registration URI scheme is not allowed
+    #[error("Registration URI scheme is not allowed")]
+    RegistrationUriSchemeNotAllowed,
+
+    /// 17 - Only the asset owner can deregister the agent identity
+    #[error("Only the asset owner can deregister the agent identity")]
+    OnlyAssetOwnerCanDeregister,
+
+    /// 18 - Invalid Execution Delegate Count account
+    #[error("Invalid Execution Delegate Count account")]
+    InvalidExecutionDelegateCount,
+
+    /// 19 - Agent still has active execution delegates
+    #[error("Agent still has active execution delegates")]
+    ExecutionDelegatesStillActive,
+
+    /// 20 - Agent Identity is already an AgentIdentityV2
+    #[error("Agent Identity is already an AgentIdentityV2")]
+    AgentIdentityAlreadyMigrated,
+
+    /// 21 - Invalid SPL Token or Token-2022 mint
+    #[error("Invalid SPL Token or Token-2022 mint")]
+    InvalidMint,
+
+    /// 22 - Mint authority must be the asset signer or sign the instruction
+    #[error("Mint authority must be the asset signer or sign the instruction")]
+    MintAuthorityMismatch,
+
+    /// 23 - Agent token is not set
+    #[error("Agent token is not set")]
+    AgentTokenNotSet,
+
+    /// 24 - Agent token change is still time-locked
+    #[error("Agent token change is still time-locked")]
+    AgentTokenChangeLocked,
+
+    /// 25 - Agent token timelock cannot be shortened while an agent token is set
+    #[error("Agent token timelock cannot be shortened while an agent token is set")]
+    AgentTokenTimelockDecrease,
+
+    /// 26 - Invalid lifecycle checks
+    #[error("Invalid lifecycle checks")]
+    InvalidLifecycleChecks,
+
+    /// 27 - Only the asset owner or Asset Signer can update the lifecycle checks
+    #[error("Only the asset owner or Asset Signer can update the lifecycle checks")]
+    OnlyAssetOwnerOrSignerCanUpdateLifecycleChecks,
+
+    /// 28 - Invalid Core Collection
+    #[error("Invalid Core Collection")]
+    InvalidCoreCollection,
+
+    /// 29 - Collection identity is not registered
+    #[error("Collection identity is not registered")]
+    CollectionIdentityNotRegistered,
+
+    /// 30 - Asset does not belong to the collection
+    #[error("Asset does not belong to the collection")]
+    AssetNotInCollection,
+
+    /// 31 - Only the asset owner or Asset Signer can inherit the collection identity
+    #[error("Only the asset owner or Asset Signer can inherit the collection identity")]
+    OnlyAssetOwnerOrSignerCanInheritIdentity,
+
+    /// 32 - Agent identity is inherited from the collection
+    #[error("Agent identity is inherited from the collection")]
+    AgentIdentityInheritedFromCollection,
+
+    /// 33 - Batch accounts must be non-empty (agent identity, asset) pairs
+    #[error("Batch accounts must be non-empty (agent identity, asset) pairs")]
+    InvalidBatchAccounts,
+
+    /// 34 - Registration URI table must have one URI or one per asset
+    #[error("Registration URI table must have one URI or one per asset")]
+    InvalidRegistrationUriTable,
+
+    /// 35 - Execution delegates are not all counted yet
+    #[error("Execution delegates are not all counted yet")]
+    ExecutionDelegateMigrationIncomplete,
+
+    /// 36 - Invalid Agent Token Change account
+    #[error("Invalid Agent Token Change account")]
+    InvalidAgentTokenChange,
 }
 
 impl From<MplAgentIdentityError> for ProgramError {
@@ -0,0 +1,79 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+use crate::state::{AgentTokenChange, AgentTokenSource};
+
+/// Version of the event encoding. Emitted as the first byte of every event so
+/// indexers can reject layouts they don't understand.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Events emitted by the program via `sol_log_data`. Each log entry is a
+/// single data field: `[EVENT_VERSION, borsh(MplAgentIdentityEvent)...]`.
+///
+/// New variants must be appended to keep existing variant indices stable.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MplAgentIdentityEvent {
+    /// An agent identity was registered for an asset.
+    IdentityRegisteredV1 {
+        asset: Pubkey,
+        collection: Option<Pubkey>,
+        registration_uri: String,
+    },
+    /// The agent registration URI was updated.
+    RegistrationUriUpdatedV1 {
+        asset: Pubkey,
+        registration_uri: String,
+    },
+    /// An agent identity was deregistered and its PDA closed.
+    IdentityDeregisteredV1 { asset: Pubkey },
+    /// An AgentIdentityV1 account was migrated to an AgentIdentityV2.
+    IdentityMigratedV1 { asset: Pubkey },
+    /// An agent token was set on an identity that had none.
+    AgentTokenSetV1 {
+        asset: Pubkey,
+        agent_token: Pubkey,
+        source: AgentTokenSource,
+    },
+    /// The agent token timelock was set.
+    AgentTokenTimelockSetV1 { asset: Pubkey, timelock: u32 },
+    /// A time-locked agent token change was scheduled.
+    AgentTokenChangeScheduledV1 {
+        asset: Pubkey,
+        agent_token: Pubkey,
+        change: AgentTokenChange,
+        new_agent_token: Option<Pubkey>,
+        unlock_at: i64,
+        expires_at: i64,
+    },
+    /// The agent token was cleared.
+    AgentTokenClearedV1 { asset: Pubkey, agent_token: Pubkey },
+    /// The agent token was replaced.
+    AgentTokenReplacedV1 {
+        asset: Pubkey,
+        old_agent_token: Pubkey,
+        new_agent_token: Pubkey,
+        source: AgentTokenSource,
+    },
+    /// The lifecycle checks on the Agent Identity plugin were updated.
+    LifecycleChecksUpdatedV1 {
+        asset: Pubkey,
+        lifecycle_checks: u32,
+    },
+    /// An agent identity was registered for a collection.
+    CollectionIdentityRegisteredV1 {
+        collection: Pubkey,
+        registration_uri: String,
+    },
+    /// An asset registered an agent identity inherited from its collection.
+    CollectionIdentityInheritedV1 { asset: Pubkey, collection: Pubkey },
+}
+
+impl MplAgentIdentityEvent {
+    /// Serialize the event and write it to the program log.
+    pub fn emit(&self) -> ProgramResult {
+        let mut data = vec![EVENT_VERSION];
+        borsh::to_writer(&mut data, self)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+}
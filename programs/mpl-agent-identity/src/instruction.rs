@@ -1,6 +1,12 @@
 use shank::{ShankContext, ShankInstruction};
 
-use crate::processor::{RegisterIdentityV1Args, SetAgentTokenV1Args};
+use crate::processor::{
+    ClearAgentTokenV1Args, DeregisterIdentityV1Args, InheritCollectionIdentityV1Args,
+    MigrateIdentityV1ToV2Args, RegisterCollectionIdentityV1Args, RegisterIdentitiesV1Args,
+    RegisterIdentityV1Args, ReplaceAgentTokenV1Args, SetAgentTokenFromMintV1Args,
+    SetAgentTokenTimelockV1Args, SetAgentTokenV1Args, UpdateAgentRegistrationUriV1Args,
+    UpdateIdentityLifecycleChecksV1Args,
+};
 
 /// Instruction discriminants for routing.
 /// The first byte of instruction data determines which instruction to execute.
@@ -9,6 +15,17 @@ use crate::processor::{RegisterIdentityV1Args, SetAgentTokenV1Args};
 pub enum MplAgentIdentityInstructionDiscriminant {
     RegisterIdentityV1 = 0,
     SetAgentTokenV1 = 1,
+    UpdateAgentRegistrationUriV1 = 2,
+    DeregisterIdentityV1 = 3,
+    MigrateIdentityV1ToV2 = 4,
+    SetAgentTokenFromMintV1 = 5,
+    SetAgentTokenTimelockV1 = 6,
+    ClearAgentTokenV1 = 7,
+    ReplaceAgentTokenV1 = 8,
+    UpdateIdentityLifecycleChecksV1 = 9,
+    RegisterCollectionIdentityV1 = 10,
+    InheritCollectionIdentityV1 = 11,
+    RegisterIdentitiesV1 = 12,
 }
 
 impl TryFrom<u8> for MplAgentIdentityInstructionDiscriminant {
@@ -18,6 +35,17 @@ impl TryFrom<u8> for MplAgentIdentityInstructionDiscriminant {
         match value {
             0 => Ok(MplAgentIdentityInstructionDiscriminant::RegisterIdentityV1),
             1 => Ok(MplAgentIdentityInstructionDiscriminant::SetAgentTokenV1),
+            2 => Ok(MplAgentIdentityInstructionDiscriminant::UpdateAgentRegistrationUriV1),
+            3 => Ok(MplAgentIdentityInstructionDiscriminant::DeregisterIdentityV1),
+            4 => Ok(MplAgentIdentityInstructionDiscriminant::MigrateIdentityV1ToV2),
+            5 => Ok(MplAgentIdentityInstructionDiscriminant::SetAgentTokenFromMintV1),
+            6 => Ok(MplAgentIdentityInstructionDiscriminant::SetAgentTokenTimelockV1),
+            7 => Ok(MplAgentIdentityInstructionDiscriminant::ClearAgentTokenV1),
+            8 => Ok(MplAgentIdentityInstructionDiscriminant::ReplaceAgentTokenV1),
+            9 => Ok(MplAgentIdentityInstructionDiscriminant::UpdateIdentityLifecycleChecksV1),
+            10 => Ok(MplAgentIdentityInstructionDiscriminant::RegisterCollectionIdentityV1),
+            11 => Ok(MplAgentIdentityInstructionDiscriminant::InheritCollectionIdentityV1),
+            12 => Ok(MplAgentIdentityInstructionDiscriminant::RegisterIdentitiesV1),
             _ => Err(()),
         }
     }
@@ -47,4 +75,109 @@ pub enum MplAgentIdentityInstruction {
     #[account(4, optional, signer, name="authority", desc = "Authority must be the asset signer. If not provided, the payer will be used.")]
     #[account(5, name="system_program", desc = "The system program")]
     SetAgentTokenV1(SetAgentTokenV1Args),
+
+    /// Update the Agent Registration URI stored in the asset's Agent Identity plugin.
+    #[account(0, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.")]
+    #[account(1, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(4, optional, signer, name="authority", desc = "Authority must be the asset owner or the asset signer. If not provided, the payer will be used.")]
+    #[account(5, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(6, name="system_program", desc = "The system program")]
+    UpdateAgentRegistrationUriV1(UpdateAgentRegistrationUriV1Args),
+
+    /// Deregister an Agent Identity. Removes the Agent Identity plugin from the asset and closes the agent identity PDA. Fails while the asset still has execution delegates in mpl-agent-tools, and until mpl-agent-tools has counted every execution delegate.
+    #[account(0, writable, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.")]
+    #[account(1, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(3, name="execution_delegate_count", desc = "The mpl-agent-tools execution delegate count PDA for the asset")]
+    #[account(4, name="execution_delegate_migration", desc = "The mpl-agent-tools execution delegate migration PDA")]
+    #[account(5, writable, name="destination", desc = "The destination for the reclaimed rent")]
+    #[account(6, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(7, optional, signer, name="authority", desc = "Authority must be the asset owner. If not provided, the payer will be used.")]
+    #[account(8, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(9, name="system_program", desc = "The system program")]
+    DeregisterIdentityV1(DeregisterIdentityV1Args),
+
+    /// Migrate an AgentIdentityV1 account to an AgentIdentityV2 in place. Permissionless: the payer only funds the additional rent.
+    #[account(0, writable, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV1.")]
+    #[account(1, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(3, name="system_program", desc = "The system program")]
+    MigrateIdentityV1ToV2(MigrateIdentityV1ToV2Args),
+
+    /// Set an existing SPL Token or Token-2022 mint as the agent token if it is not already set. The mint authority must either be the asset signer or co-sign as `mint_authority`. If the account is an AgentIdentityV1, it will be upgraded to an AgentIdentityV2.
+    #[account(0, writable, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.")]
+    #[account(1, name="asset", desc = "The address of the Core asset")]
+    #[account(2, name="mint", desc = "The SPL Token or Token-2022 mint of the agent token")]
+    #[account(3, optional, signer, name="mint_authority", desc = "The mint authority of the mint. Not required if the asset signer is the mint authority.")]
+    #[account(4, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(5, optional, signer, name="authority", desc = "Authority must be the asset signer. If not provided, the payer will be used.")]
+    #[account(6, name="system_program", desc = "The system program")]
+    SetAgentTokenFromMintV1(SetAgentTokenFromMintV1Args),
+
+    /// Set the notice period that must elapse before the agent token can be cleared or replaced. The timelock can only be shortened while no agent token is set. If the account is an AgentIdentityV1, it will be upgraded to an AgentIdentityV2.
+    #[account(0, writable, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.")]
+    #[account(1, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(3, optional, signer, name="authority", desc = "Authority must be the asset signer. If not provided, the payer will be used.")]
+    #[account(4, name="system_program", desc = "The system program")]
+    SetAgentTokenTimelockV1(SetAgentTokenTimelockV1Args),
+
+    /// Clear the agent token. If a timelock is set, the first call schedules the clear and a second call after the notice period applies it. A scheduled change expires a week after unlocking, and scheduling another change replaces it.
+    #[account(0, writable, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV2.")]
+    #[account(1, writable, name="agent_token_change", desc = "The agent token change PDA holding the scheduled change")]
+    #[account(2, name="asset", desc = "The address of the Core asset")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for the agent token change account")]
+    #[account(4, optional, signer, name="authority", desc = "Authority must be the asset signer. If not provided, the payer will be used.")]
+    #[account(5, name="system_program", desc = "The system program")]
+    ClearAgentTokenV1(ClearAgentTokenV1Args),
+
+    /// Replace the agent token with an existing SPL Token or Token-2022 mint, under the same mint authority rules as SetAgentTokenFromMintV1. If a timelock is set, the first call schedules the replacement with this mint and a second call with the same mint after the notice period applies it. A scheduled change expires a week after unlocking, and scheduling another change replaces it.
+    #[account(0, writable, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV2.")]
+    #[account(1, writable, name="agent_token_change", desc = "The agent token change PDA holding the scheduled change")]
+    #[account(2, name="asset", desc = "The address of the Core asset")]
+    #[account(3, name="mint", desc = "The SPL Token or Token-2022 mint of the new agent token")]
+    #[account(4, optional, signer, name="mint_authority", desc = "The mint authority of the mint. Not required if the asset signer is the mint authority.")]
+    #[account(5, writable, signer, name="payer", desc = "The payer for the agent token change account")]
+    #[account(6, optional, signer, name="authority", desc = "Authority must be the asset signer. If not provided, the payer will be used.")]
+    #[account(7, name="system_program", desc = "The system program")]
+    ReplaceAgentTokenV1(ReplaceAgentTokenV1Args),
+
+    /// Replace the lifecycle checks registered on the asset's Agent Identity plugin.
+    #[account(0, name="agent_identity", desc = "The agent identity PDA. Must be of type AgentIdentityV1 or AgentIdentityV2.")]
+    #[account(1, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(4, optional, signer, name="authority", desc = "Authority must be the asset owner or the asset signer. If not provided, the payer will be used.")]
+    #[account(5, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(6, name="system_program", desc = "The system program")]
+    UpdateIdentityLifecycleChecksV1(UpdateIdentityLifecycleChecksV1Args),
+
+    /// Register an Agent Identity for a Core collection. The Agent Identity plugin is added to the collection, whose update authority must sign.
+    #[account(0, writable, name="collection_identity", desc = "The collection identity PDA")]
+    #[account(1, writable, name="collection", desc = "The address of the Core collection")]
+    #[account(2, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(3, optional, signer, name="authority", desc = "Update authority of the collection. If not provided, the payer will be used.")]
+    #[account(4, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(5, name="system_program", desc = "The system program")]
+    RegisterCollectionIdentityV1(RegisterCollectionIdentityV1Args),
+
+    /// Register an Agent Identity for an asset that inherits the Agent Identity of its collection instead of carrying its own plugin.
+    #[account(0, writable, name="agent_identity", desc = "The agent identity PDA")]
+    #[account(1, name="asset", desc = "The address of the Core asset")]
+    #[account(2, name="collection", desc = "The collection of the asset")]
+    #[account(3, name="collection_identity", desc = "The collection identity PDA")]
+    #[account(4, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(5, optional, signer, name="authority", desc = "Authority must be the asset owner or the asset signer. If not provided, the payer will be used.")]
+    #[account(6, name="system_program", desc = "The system program")]
+    InheritCollectionIdentityV1(InheritCollectionIdentityV1Args),
+
+    /// Register Agent Identities for several assets of a collection under a single collection authority signature. The remaining accounts are (agent identity PDA, asset) pairs, both writable.
+    #[account(0, writable, name="collection", desc = "The address of the collection of every asset")]
+    #[account(1, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(2, optional, signer, name="authority", desc = "Update authority of the collection. If not provided, the payer will be used.")]
+    #[account(3, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(4, name="system_program", desc = "The system program")]
+    RegisterIdentitiesV1(RegisterIdentitiesV1Args),
 }
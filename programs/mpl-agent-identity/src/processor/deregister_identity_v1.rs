@@ -0,0 +1,206 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_agent_tools::{
+    accounts::{ExecutionDelegateCountV1, ExecutionDelegateMigrationV1},
+    types::Key as MplAgentToolsKey,
+};
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::instructions::{
+    RemoveExternalPluginAdapterV1Cpi, RemoveExternalPluginAdapterV1InstructionArgs,
+};
+use mpl_core::types::{ExternalPluginAdapterKey, Key as MplCoreKey};
+use mpl_utils::{assert_signer, close_account_raw};
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentIdentityEvent;
+use crate::instruction::accounts::DeregisterIdentityV1Accounts;
+use crate::state::Key;
+use crate::{error::MplAgentIdentityError, state::AgentIdentityV2};
+
+impl<'a> DeregisterIdentityV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            agent_identity,
+            asset,
+            collection: _,
+            execution_delegate_count,
+            execution_delegate_migration,
+            destination: _,
+            payer,
+            authority,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Agent Identity
+        {
+            let agent_identity_data = agent_identity.try_borrow_data()?;
+            if agent_identity.owner != &crate::ID
+                || agent_identity_data.len() == 0
+                || (agent_identity_data[0] != Key::AgentIdentityV1 as u8
+                    && agent_identity_data[0] != Key::AgentIdentityV2 as u8)
+            {
+                return Err(MplAgentIdentityError::InvalidAgentIdentity.into());
+            }
+        }
+
+        let agent_identity_bump =
+            AgentIdentityV2::check_pda_derivation(agent_identity, self.asset.key)?;
+
+        // Asset
+        // Assert that the asset exists and is a Core asset.
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentIdentityError::InvalidCoreAsset.into());
+        }
+
+        // Collection
+        // SAFE: Checked by the Core program.
+
+        // Execution Delegate Migration
+        // The counts only cover every execution delegate record once the
+        // tools program has backfilled the records delegated before them.
+        let (execution_delegate_migration_pda, _) = ExecutionDelegateMigrationV1::find_pda();
+        if *execution_delegate_migration.key != execution_delegate_migration_pda {
+            return Err(MplAgentIdentityError::ExecutionDelegateMigrationIncomplete.into());
+        }
+
+        if execution_delegate_migration.owner != &mpl_agent_tools::ID
+            || execution_delegate_migration.data_len() == 0
+            || execution_delegate_migration.try_borrow_data()?[0]
+                != MplAgentToolsKey::ExecutionDelegateMigrationV1 as u8
+        {
+            return Err(MplAgentIdentityError::ExecutionDelegateMigrationIncomplete.into());
+        }
+
+        // Execution Delegate Count
+        // The tools program counts the execution delegate records per asset.
+        // The account only exists once the asset has been delegated.
+        let (execution_delegate_count_pda, _) = ExecutionDelegateCountV1::find_pda(asset.key);
+        if *execution_delegate_count.key != execution_delegate_count_pda {
+            return Err(MplAgentIdentityError::InvalidExecutionDelegateCount.into());
+        }
+
+        if execution_delegate_count.data_len() > 0 {
+            let count_data = execution_delegate_count.try_borrow_data()?;
+            if execution_delegate_count.owner != &mpl_agent_tools::ID
+                || count_data[0] != MplAgentToolsKey::ExecutionDelegateCountV1 as u8
+            {
+                return Err(MplAgentIdentityError::InvalidExecutionDelegateCount.into());
+            }
+
+            let count = ExecutionDelegateCountV1::from_bytes(&count_data)
+                .map_err(|_| MplAgentIdentityError::InvalidExecutionDelegateCount)?
+                .count;
+            if count > 0 {
+                return Err(MplAgentIdentityError::ExecutionDelegatesStillActive.into());
+            }
+        }
+
+        // Destination
+        // SAFE: Any account may receive the reclaimed rent.
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentIdentityError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(agent_identity_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct DeregisterIdentityV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<DeregisterIdentityV1Args>() == 8);
+
+pub fn deregister_identity_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = DeregisterIdentityV1Accounts::context(accounts)?;
+    let agent_identity_bump = ctx.accounts.validate()?;
+
+    // Only the asset owner can deregister the agent identity.
+    let signer = ctx.accounts.authority.unwrap_or(ctx.accounts.payer);
+    let asset = BaseAssetV1::try_from(ctx.accounts.asset)?;
+
+    if asset.owner != *signer.key {
+        return Err(MplAgentIdentityError::OnlyAssetOwnerCanDeregister.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Remove the Agent Identity External Plugin Adapter from the asset, unless
+    // the identity was inherited from the collection and never added one.
+    let inherited = AgentIdentityV2::inherits_collection_identity_from_bytes(
+        &ctx.accounts.agent_identity.try_borrow_data()?,
+    );
+    if !inherited {
+        RemoveExternalPluginAdapterV1Cpi {
+            __program: ctx.accounts.mpl_core_program,
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            payer: ctx.accounts.payer,
+            authority: ctx.accounts.authority,
+            system_program: ctx.accounts.system_program,
+            log_wrapper: None,
+            __args: RemoveExternalPluginAdapterV1InstructionArgs {
+                key: ExternalPluginAdapterKey::AgentIdentity,
+            },
+        }
+        // The agent_identity PDA is signed for via `invoke_signed` just like in
+        // `register_identity_v1`, so is_signer = true, is_writable = false.
+        .invoke_signed_with_remaining_accounts(
+            &[&[
+                AgentIdentityV2::PREFIX,
+                ctx.accounts.asset.key.as_ref(),
+                &[agent_identity_bump],
+            ]],
+            &[(ctx.accounts.agent_identity, false, true)],
+        )?;
+    }
+
+    // Close the agent identity account and send the rent to the destination.
+    close_account_raw(ctx.accounts.destination, ctx.accounts.agent_identity)?;
+
+    msg!("Agent identity deregistered: {}", ctx.accounts.asset.key);
+
+    MplAgentIdentityEvent::IdentityDeregisteredV1 {
+        asset: *ctx.accounts.asset.key,
+    }
+    .emit()
+}
@@ -0,0 +1,148 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::accounts::{AssetSigner, BaseAssetV1};
+use mpl_core::types::{Key as MplCoreKey, UpdateAuthority};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentIdentityEvent;
+use crate::state::{CollectionIdentityV1, Key};
+use crate::{
+    error::MplAgentIdentityError, instruction::accounts::InheritCollectionIdentityV1Accounts,
+    state::AgentIdentityV2,
+};
+
+impl<'a> InheritCollectionIdentityV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            agent_identity,
+            asset,
+            collection,
+            collection_identity,
+            payer,
+            authority,
+            system_program,
+        } = self;
+
+        // Agent Identity
+        let agent_identity_bump = AgentIdentityV2::check_pda_derivation(agent_identity, asset.key)?;
+
+        if agent_identity.data_len() != 0 || *agent_identity.owner != system_program::id() {
+            return Err(MplAgentIdentityError::AgentIdentityAlreadyRegistered.into());
+        }
+
+        // Asset
+        // Assert that the asset exists and is a Core asset.
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentIdentityError::InvalidCoreAsset.into());
+        }
+
+        // Collection
+        // SAFE: Checked against the asset's update authority by the processor.
+
+        // Collection Identity
+        {
+            let collection_identity_data = collection_identity.try_borrow_data()?;
+            if collection_identity.owner != &crate::ID
+                || collection_identity_data.first() != Some(&(Key::CollectionIdentityV1 as u8))
+            {
+                return Err(MplAgentIdentityError::CollectionIdentityNotRegistered.into());
+            }
+        }
+
+        let _ = CollectionIdentityV1::check_pda_derivation(collection_identity, collection.key)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(agent_identity_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct InheritCollectionIdentityV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<InheritCollectionIdentityV1Args>() == 8);
+
+pub fn inherit_collection_identity_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = InheritCollectionIdentityV1Accounts::context(accounts)?;
+    let agent_identity_bump = ctx.accounts.validate()?;
+
+    // The authority must be the asset owner or the asset signer PDA.
+    let signer = ctx.accounts.authority.unwrap_or(ctx.accounts.payer);
+    let asset = BaseAssetV1::try_from(ctx.accounts.asset)?;
+    let (asset_signer_pda, _) = AssetSigner::find_pda(ctx.accounts.asset.key);
+
+    if asset.owner != *signer.key && asset_signer_pda != *signer.key {
+        return Err(MplAgentIdentityError::OnlyAssetOwnerOrSignerCanInheritIdentity.into());
+    }
+
+    // The asset must belong to the collection whose identity it inherits.
+    if asset.update_authority != UpdateAuthority::Collection(*ctx.accounts.collection.key) {
+        return Err(MplAgentIdentityError::AssetNotInCollection.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Create the agent identity account. No plugin is added to the asset:
+    // Core applies the collection's Agent Identity plugin to its assets.
+    AgentIdentityV2::create_account(
+        ctx.accounts.agent_identity,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset.key,
+        agent_identity_bump,
+    )?;
+
+    let mut data = ctx.accounts.agent_identity.try_borrow_mut_data()?;
+    let agent_identity: &mut AgentIdentityV2 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentIdentityV2>()]);
+
+    agent_identity.initialize(agent_identity_bump, ctx.accounts.asset.key);
+    agent_identity.inherits_collection_identity = 1;
+
+    msg!(
+        "Agent identity inherited from collection: {}",
+        ctx.accounts.collection.key
+    );
+
+    MplAgentIdentityEvent::CollectionIdentityInheritedV1 {
+        asset: *ctx.accounts.asset.key,
+        collection: *ctx.accounts.collection.key,
+    }
+    .emit()
+}
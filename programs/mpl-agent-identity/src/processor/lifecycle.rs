@@ -0,0 +1,85 @@
+//! Encoding and validation of the AgentIdentity lifecycle check policy
+//! carried as a bitmask in identity instructions.
+//!
+//! Each hookable lifecycle event owns three bits at `3 * event`, laid out as
+//! `[listen, approve, reject]`, so the mask mirrors the order of MPL Core's
+//! `HookableLifecycleEvent` enum.
+
+use mpl_core::types::{ExternalCheckResult, HookableLifecycleEvent};
+use mpl_core::ExternalCheckResultBits;
+use solana_program::program_error::ProgramError;
+
+use crate::error::MplAgentIdentityError;
+
+/// The plugin is notified of the lifecycle event.
+pub const LIFECYCLE_CHECK_LISTEN: u32 = 1 << 0;
+
+/// The plugin may approve the lifecycle event.
+pub const LIFECYCLE_CHECK_APPROVE: u32 = 1 << 1;
+
+/// The plugin may reject the lifecycle event.
+pub const LIFECYCLE_CHECK_REJECT: u32 = 1 << 2;
+
+/// All checks for a single lifecycle event.
+pub const LIFECYCLE_CHECK_ALL: u32 =
+    LIFECYCLE_CHECK_LISTEN | LIFECYCLE_CHECK_APPROVE | LIFECYCLE_CHECK_REJECT;
+
+/// Bit offsets of each configurable lifecycle event in the mask. `Create` is
+/// not configurable since the plugin is always added after the asset exists.
+pub const LIFECYCLE_TRANSFER_SHIFT: u32 = 3;
+pub const LIFECYCLE_BURN_SHIFT: u32 = 6;
+pub const LIFECYCLE_UPDATE_SHIFT: u32 = 9;
+pub const LIFECYCLE_EXECUTE_SHIFT: u32 = 12;
+
+/// Every bit that may be set in a lifecycle check mask.
+pub const VALID_LIFECYCLE_CHECKS: u32 = (LIFECYCLE_CHECK_ALL << LIFECYCLE_TRANSFER_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_BURN_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_UPDATE_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_EXECUTE_SHIFT);
+
+/// The policy used when no mask is provided: full checks on transfer, update
+/// and execute. This matches the checks registered before the policy became
+/// configurable.
+pub const DEFAULT_LIFECYCLE_CHECKS: u32 = (LIFECYCLE_CHECK_ALL << LIFECYCLE_TRANSFER_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_UPDATE_SHIFT)
+    | (LIFECYCLE_CHECK_ALL << LIFECYCLE_EXECUTE_SHIFT);
+
+/// Validate a lifecycle check mask and convert it to the list of checks
+/// expected by MPL Core. A mask of `0` selects `DEFAULT_LIFECYCLE_CHECKS`.
+pub fn lifecycle_checks_from_mask(
+    mask: u32,
+) -> Result<(u32, Vec<(HookableLifecycleEvent, ExternalCheckResult)>), ProgramError> {
+    let mask = if mask == 0 {
+        DEFAULT_LIFECYCLE_CHECKS
+    } else {
+        mask
+    };
+
+    if mask & !VALID_LIFECYCLE_CHECKS != 0 {
+        return Err(MplAgentIdentityError::InvalidLifecycleChecks.into());
+    }
+
+    let checks = [
+        (HookableLifecycleEvent::Transfer, LIFECYCLE_TRANSFER_SHIFT),
+        (HookableLifecycleEvent::Burn, LIFECYCLE_BURN_SHIFT),
+        (HookableLifecycleEvent::Update, LIFECYCLE_UPDATE_SHIFT),
+        (HookableLifecycleEvent::Execute, LIFECYCLE_EXECUTE_SHIFT),
+    ]
+    .into_iter()
+    .filter_map(|(event, shift)| {
+        let bits = (mask >> shift) & LIFECYCLE_CHECK_ALL;
+        (bits != 0).then(|| {
+            (
+                event,
+                ExternalCheckResultBits::new()
+                    .with_can_listen(bits & LIFECYCLE_CHECK_LISTEN != 0)
+                    .with_can_approve(bits & LIFECYCLE_CHECK_APPROVE != 0)
+                    .with_can_reject(bits & LIFECYCLE_CHECK_REJECT != 0)
+                    .into(),
+            )
+        })
+    })
+    .collect();
+
+    Ok((mask, checks))
+}
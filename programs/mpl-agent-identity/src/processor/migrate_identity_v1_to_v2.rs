@@ -0,0 +1,100 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentIdentityEvent;
+use crate::instruction::accounts::MigrateIdentityV1ToV2Accounts;
+use crate::state::Key;
+use crate::{error::MplAgentIdentityError, state::AgentIdentityV2};
+
+impl<'a> MigrateIdentityV1ToV2Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            agent_identity,
+            asset,
+            payer,
+            system_program,
+        } = self;
+
+        // Agent Identity
+        {
+            let agent_identity_data = agent_identity.try_borrow_data()?;
+            if agent_identity.owner != &crate::ID || agent_identity_data.len() == 0 {
+                return Err(MplAgentIdentityError::InvalidAgentIdentity.into());
+            }
+
+            if agent_identity_data[0] == Key::AgentIdentityV2 as u8 {
+                return Err(MplAgentIdentityError::AgentIdentityAlreadyMigrated.into());
+            }
+
+            if agent_identity_data[0] != Key::AgentIdentityV1 as u8 {
+                return Err(MplAgentIdentityError::InvalidAgentIdentity.into());
+            }
+        }
+
+        // Asset
+        // SAFE: Only used to check the PDA derivation of the agent identity.
+        let _ = AgentIdentityV2::check_pda_derivation(agent_identity, asset.key)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct MigrateIdentityV1ToV2Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<MigrateIdentityV1ToV2Args>() == 8);
+
+pub fn migrate_identity_v1_to_v2<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = MigrateIdentityV1ToV2Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Anyone may pay to migrate: the account contents are unchanged apart from
+    // the discriminator and the zeroed V2 fields.
+    AgentIdentityV2::migrate_from_v1(
+        ctx.accounts.agent_identity,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )?;
+
+    msg!("Agent identity migrated to V2: {}", ctx.accounts.asset.key);
+
+    MplAgentIdentityEvent::IdentityMigratedV1 {
+        asset: *ctx.accounts.asset.key,
+    }
+    .emit()
+}
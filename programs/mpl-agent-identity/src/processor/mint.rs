@@ -0,0 +1,74 @@
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{error::MplAgentIdentityError, state::AgentTokenSource};
+
+/// The SPL Token program ID.
+const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// The SPL Token-2022 program ID.
+const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Size of the base SPL Token mint layout.
+const MINT_SIZE: usize = 82;
+
+/// Byte offset of `mint_authority` (COption<Pubkey>: u32 tag + Pubkey) in the
+/// mint layout.
+const MINT_AUTHORITY_OFFSET: usize = 0;
+
+/// Byte offset of `is_initialized` (bool) in the mint layout.
+const MINT_IS_INITIALIZED_OFFSET: usize = 45;
+
+/// Token-2022 accounts with extensions are padded to the size of a token
+/// account; the account type byte follows the padding.
+const TOKEN_2022_ACCOUNT_TYPE_OFFSET: usize = 165;
+
+/// Token-2022 `AccountType::Mint` value.
+const TOKEN_2022_ACCOUNT_TYPE_MINT: u8 = 1;
+
+/// Assert that the account is an initialized SPL Token or Token-2022 mint.
+pub(crate) fn assert_mint(mint: &AccountInfo) -> Result<(), ProgramError> {
+    let mint_data = mint.try_borrow_data()?;
+    let is_mint_layout = mint_data.len() == MINT_SIZE
+        || (mint.owner == &SPL_TOKEN_2022_PROGRAM_ID
+            && mint_data.len() > TOKEN_2022_ACCOUNT_TYPE_OFFSET
+            && mint_data[TOKEN_2022_ACCOUNT_TYPE_OFFSET] == TOKEN_2022_ACCOUNT_TYPE_MINT);
+
+    if (mint.owner != &SPL_TOKEN_PROGRAM_ID && mint.owner != &SPL_TOKEN_2022_PROGRAM_ID)
+        || !is_mint_layout
+        || mint_data[MINT_IS_INITIALIZED_OFFSET] != 1
+    {
+        return Err(MplAgentIdentityError::InvalidMint.into());
+    }
+
+    Ok(())
+}
+
+/// Determine how the agent proves control of the mint: either the asset
+/// signer holds the mint authority, or the mint authority co-signs.
+pub(crate) fn resolve_agent_token_source(
+    mint: &AccountInfo,
+    asset_signer: &Pubkey,
+    mint_authority: Option<&AccountInfo>,
+) -> Result<AgentTokenSource, ProgramError> {
+    // Read the mint authority. A mint without one can't prove provenance.
+    let mint_data = mint.try_borrow_data()?;
+    if mint_data[MINT_AUTHORITY_OFFSET..MINT_AUTHORITY_OFFSET + 4] != [1, 0, 0, 0] {
+        return Err(MplAgentIdentityError::MintAuthorityMismatch.into());
+    }
+    let current_mint_authority = Pubkey::from(
+        <[u8; 32]>::try_from(&mint_data[MINT_AUTHORITY_OFFSET + 4..MINT_AUTHORITY_OFFSET + 36])
+            .unwrap(),
+    );
+
+    if current_mint_authority == *asset_signer {
+        Ok(AgentTokenSource::AssetSigner)
+    } else if mint_authority.is_some_and(|mint_authority| {
+        mint_authority.is_signer && *mint_authority.key == current_mint_authority
+    }) {
+        Ok(AgentTokenSource::MintAuthority)
+    } else {
+        Err(MplAgentIdentityError::MintAuthorityMismatch.into())
+    }
+}
@@ -1,8 +1,41 @@
+mod clear_agent_token_v1;
+mod deregister_identity_v1;
+mod inherit_collection_identity_v1;
+mod lifecycle;
+mod migrate_identity_v1_to_v2;
+mod mint;
+mod register_collection_identity_v1;
+mod register_identities_v1;
 mod register_identity_v1;
+mod replace_agent_token_v1;
+mod set_agent_token_from_mint_v1;
+mod set_agent_token_timelock_v1;
 mod set_agent_token_v1;
+mod update_agent_registration_uri_v1;
+mod update_identity_lifecycle_checks_v1;
+mod uri;
 
+pub use clear_agent_token_v1::{clear_agent_token_v1, ClearAgentTokenV1Args};
+pub use deregister_identity_v1::{deregister_identity_v1, DeregisterIdentityV1Args};
+pub use inherit_collection_identity_v1::{
+    inherit_collection_identity_v1, InheritCollectionIdentityV1Args,
+};
+pub use migrate_identity_v1_to_v2::{migrate_identity_v1_to_v2, MigrateIdentityV1ToV2Args};
+pub use register_collection_identity_v1::{
+    register_collection_identity_v1, RegisterCollectionIdentityV1Args,
+};
+pub use register_identities_v1::{register_identities_v1, RegisterIdentitiesV1Args};
 pub use register_identity_v1::{register_identity_v1, RegisterIdentityV1Args};
+pub use replace_agent_token_v1::{replace_agent_token_v1, ReplaceAgentTokenV1Args};
+pub use set_agent_token_from_mint_v1::{set_agent_token_from_mint_v1, SetAgentTokenFromMintV1Args};
+pub use set_agent_token_timelock_v1::{set_agent_token_timelock_v1, SetAgentTokenTimelockV1Args};
 pub use set_agent_token_v1::{set_agent_token_v1, SetAgentTokenV1Args};
+pub use update_agent_registration_uri_v1::{
+    update_agent_registration_uri_v1, UpdateAgentRegistrationUriV1Args,
+};
+pub use update_identity_lifecycle_checks_v1::{
+    update_identity_lifecycle_checks_v1, UpdateIdentityLifecycleChecksV1Args,
+};
 
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
@@ -30,6 +63,50 @@ pub fn process_instruction<'a>(
             msg!("Instruction: SetAgentTokenV1");
             set_agent_token_v1(accounts, instruction_data)
         }
+        Ok(MplAgentIdentityInstructionDiscriminant::UpdateAgentRegistrationUriV1) => {
+            msg!("Instruction: UpdateAgentRegistrationUriV1");
+            update_agent_registration_uri_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::DeregisterIdentityV1) => {
+            msg!("Instruction: DeregisterIdentityV1");
+            deregister_identity_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::MigrateIdentityV1ToV2) => {
+            msg!("Instruction: MigrateIdentityV1ToV2");
+            migrate_identity_v1_to_v2(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::SetAgentTokenFromMintV1) => {
+            msg!("Instruction: SetAgentTokenFromMintV1");
+            set_agent_token_from_mint_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::SetAgentTokenTimelockV1) => {
+            msg!("Instruction: SetAgentTokenTimelockV1");
+            set_agent_token_timelock_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::ClearAgentTokenV1) => {
+            msg!("Instruction: ClearAgentTokenV1");
+            clear_agent_token_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::ReplaceAgentTokenV1) => {
+            msg!("Instruction: ReplaceAgentTokenV1");
+            replace_agent_token_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::UpdateIdentityLifecycleChecksV1) => {
+            msg!("Instruction: UpdateIdentityLifecycleChecksV1");
+            update_identity_lifecycle_checks_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::RegisterCollectionIdentityV1) => {
+            msg!("Instruction: RegisterCollectionIdentityV1");
+            register_collection_identity_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::InheritCollectionIdentityV1) => {
+            msg!("Instruction: InheritCollectionIdentityV1");
+            inherit_collection_identity_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentIdentityInstructionDiscriminant::RegisterIdentitiesV1) => {
+            msg!("Instruction: RegisterIdentitiesV1");
+            register_identities_v1(accounts, instruction_data)
+        }
         Err(_) => Err(MplAgentIdentityError::InvalidInstructionData.into()),
     }
 }
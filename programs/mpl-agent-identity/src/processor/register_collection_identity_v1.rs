@@ -0,0 +1,168 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::instructions::{
+    AddCollectionExternalPluginAdapterV1Cpi, AddCollectionExternalPluginAdapterV1InstructionArgs,
+};
+use mpl_core::types::{AgentIdentityInitInfo, ExternalPluginAdapterInitInfo, Key as MplCoreKey};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use super::lifecycle::lifecycle_checks_from_mask;
+use super::uri::read_registration_uri;
+use crate::events::MplAgentIdentityEvent;
+use crate::{
+    error::MplAgentIdentityError, instruction::accounts::RegisterCollectionIdentityV1Accounts,
+    state::CollectionIdentityV1,
+};
+
+impl<'a> RegisterCollectionIdentityV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            collection_identity,
+            collection,
+            payer,
+            authority,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Collection Identity
+        let collection_identity_bump =
+            CollectionIdentityV1::check_pda_derivation(collection_identity, collection.key)?;
+
+        if collection_identity.data_len() != 0 || *collection_identity.owner != system_program::id()
+        {
+            return Err(MplAgentIdentityError::AgentIdentityAlreadyRegistered.into());
+        }
+
+        // Collection
+        // Assert that the collection exists and is a Core collection.
+        if collection.owner != &mpl_core::ID
+            || collection.try_borrow_data()?[0] != MplCoreKey::CollectionV1 as u8
+        {
+            return Err(MplAgentIdentityError::InvalidCoreCollection.into());
+        }
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentIdentityError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(collection_identity_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RegisterCollectionIdentityV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 3],
+    /// Bitmask of the lifecycle checks to register on the Agent Identity
+    /// plugin. `0` selects the default policy.
+    pub lifecycle_checks: u32,
+    /// The URI of the Agent Registration JSON file.
+    /// We parse this manually from a string representation in the IDL.
+    #[idl_type("String")]
+    agent_registration_uri: [u8; 0],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RegisterCollectionIdentityV1Args>() == 8);
+
+pub fn register_collection_identity_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args_data = instruction_data
+        .get(..core::mem::size_of::<RegisterCollectionIdentityV1Args>())
+        .ok_or(MplAgentIdentityError::InvalidInstructionData)?;
+    let args: RegisterCollectionIdentityV1Args = bytemuck::pod_read_unaligned(args_data);
+
+    let string_data = &instruction_data[core::mem::size_of::<RegisterCollectionIdentityV1Args>()..];
+    let uri = read_registration_uri(string_data)?;
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RegisterCollectionIdentityV1Accounts::context(accounts)?;
+    let collection_identity_bump = ctx.accounts.validate()?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    let (_, lifecycle_checks) = lifecycle_checks_from_mask(args.lifecycle_checks)?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Create the collection identity account.
+    CollectionIdentityV1::create_account(
+        ctx.accounts.collection_identity,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.collection.key,
+        collection_identity_bump,
+    )?;
+
+    let mut data = ctx.accounts.collection_identity.try_borrow_mut_data()?;
+    let collection_identity: &mut CollectionIdentityV1 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<CollectionIdentityV1>()]);
+
+    collection_identity.initialize(collection_identity_bump, ctx.accounts.collection.key);
+
+    // Drop the collection identity account data.
+    drop(data);
+
+    // Add the Agent Identity External Plugin Adapter to the collection. The
+    // collection update authority must sign, which Core checks.
+    AddCollectionExternalPluginAdapterV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        collection: ctx.accounts.collection,
+        payer: ctx.accounts.payer,
+        authority: ctx.accounts.authority,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: AddCollectionExternalPluginAdapterV1InstructionArgs {
+            init_info: ExternalPluginAdapterInitInfo::AgentIdentity(AgentIdentityInitInfo {
+                uri: uri.clone(),
+                init_plugin_authority: None,
+                lifecycle_checks,
+            }),
+        },
+    }
+    // The collection_identity PDA is signed for via `invoke_signed` just like
+    // the agent_identity PDA in `register_identity_v1`.
+    .invoke_signed_with_remaining_accounts(
+        &[&[
+            CollectionIdentityV1::PREFIX,
+            ctx.accounts.collection.key.as_ref(),
+            &[collection_identity_bump],
+        ]],
+        &[(ctx.accounts.collection_identity, false, true)],
+    )?;
+
+    MplAgentIdentityEvent::CollectionIdentityRegisteredV1 {
+        collection: *ctx.accounts.collection.key,
+        registration_uri: uri,
+    }
+    .emit()
+}
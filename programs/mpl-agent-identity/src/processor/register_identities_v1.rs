@@ -0,0 +1,143 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use super::lifecycle::lifecycle_checks_from_mask;
+use super::register_identity_v1::{register_identity, validate_registration};
+use super::uri::read_registration_uri_table;
+use crate::events::MplAgentIdentityEvent;
+use crate::{error::MplAgentIdentityError, instruction::accounts::RegisterIdentitiesV1Accounts};
+
+impl<'a> RegisterIdentitiesV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            collection: _,
+            payer,
+            authority,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Collection
+        // SAFE: Checked by the Core program for every asset.
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentIdentityError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RegisterIdentitiesV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 3],
+    /// Bitmask of the lifecycle checks to register on every Agent Identity
+    /// plugin. `0` selects the default policy.
+    pub lifecycle_checks: u32,
+    /// The URIs of the Agent Registration JSON files. A single URI is shared
+    /// by every asset, otherwise there must be one URI per asset.
+    /// We parse this manually from a vector of strings in the IDL.
+    #[idl_type("Vec<String>")]
+    agent_registration_uris: [u8; 0],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RegisterIdentitiesV1Args>() == 8);
+
+pub fn register_identities_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args_data = instruction_data
+        .get(..core::mem::size_of::<RegisterIdentitiesV1Args>())
+        .ok_or(MplAgentIdentityError::InvalidInstructionData)?;
+    let args: RegisterIdentitiesV1Args = bytemuck::pod_read_unaligned(args_data);
+
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RegisterIdentitiesV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    // The remaining accounts are (agent identity, asset) pairs.
+    if ctx.remaining_accounts.is_empty() || ctx.remaining_accounts.len() % 2 != 0 {
+        return Err(MplAgentIdentityError::InvalidBatchAccounts.into());
+    }
+    let entries = ctx.remaining_accounts.len() / 2;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    let uris = read_registration_uri_table(
+        &instruction_data[core::mem::size_of::<RegisterIdentitiesV1Args>()..],
+        entries,
+    )?;
+    let (_, lifecycle_checks) = lifecycle_checks_from_mask(args.lifecycle_checks)?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    for (index, pair) in ctx.remaining_accounts.chunks_exact(2).enumerate() {
+        let (agent_identity, asset) = (&pair[0], &pair[1]);
+        let uri = uris.get(index).unwrap_or(&uris[0]);
+
+        validate_registration(agent_identity, asset)
+            .and_then(|agent_identity_bump| {
+                register_identity(
+                    agent_identity,
+                    asset,
+                    Some(ctx.accounts.collection),
+                    ctx.accounts.payer,
+                    ctx.accounts.authority,
+                    ctx.accounts.mpl_core_program,
+                    ctx.accounts.system_program,
+                    agent_identity_bump,
+                    uri.clone(),
+                    lifecycle_checks.clone(),
+                )
+            })
+            .inspect_err(|_| {
+                msg!(
+                    "Failed to register identity at index {}: {}",
+                    index,
+                    asset.key
+                );
+            })?;
+
+        MplAgentIdentityEvent::IdentityRegisteredV1 {
+            asset: *asset.key,
+            collection: Some(*ctx.accounts.collection.key),
+            registration_uri: uri.clone(),
+        }
+        .emit()?;
+    }
+
+    msg!("Registered {} agent identities", entries);
+
+    Ok(())
+}
@@ -3,20 +3,45 @@ use mpl_core::instructions::{
     AddExternalPluginAdapterV1Cpi, AddExternalPluginAdapterV1InstructionArgs,
 };
 use mpl_core::types::{
-    AgentIdentityInitInfo, ExternalPluginAdapterInitInfo, HookableLifecycleEvent, Key as MplCoreKey,
+    AgentIdentityInitInfo, ExternalCheckResult, ExternalPluginAdapterInitInfo,
+    HookableLifecycleEvent, Key as MplCoreKey,
 };
-use mpl_core::ExternalCheckResultBits;
 use mpl_utils::assert_signer;
 use shank::ShankType;
 use solana_program::program_error::ProgramError;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 use solana_system_interface::program as system_program;
 
+use super::lifecycle::lifecycle_checks_from_mask;
+use super::uri::read_registration_uri;
+use crate::events::MplAgentIdentityEvent;
 use crate::{
     error::MplAgentIdentityError, instruction::accounts::RegisterIdentityV1Accounts,
     state::AgentIdentityV2,
 };
 
+/// Check that `agent_identity` is the unregistered agent identity PDA of
+/// `asset` and that `asset` is a Core asset. Returns the PDA bump.
+pub(crate) fn validate_registration(
+    agent_identity: &AccountInfo,
+    asset: &AccountInfo,
+) -> Result<u8, ProgramError> {
+    // Agent Identity
+    let agent_identity_bump = AgentIdentityV2::check_pda_derivation(agent_identity, asset.key)?;
+
+    if agent_identity.data_len() != 0 || *agent_identity.owner != system_program::id() {
+        return Err(MplAgentIdentityError::AgentIdentityAlreadyRegistered.into());
+    }
+
+    // Asset
+    // Assert that the asset exists and is a Core asset.
+    if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8 {
+        return Err(MplAgentIdentityError::InvalidCoreAsset.into());
+    }
+
+    Ok(agent_identity_bump)
+}
+
 impl<'a> RegisterIdentityV1Accounts<'a> {
     pub fn validate(&self) -> Result<u8, ProgramError> {
         let Self {
@@ -29,20 +54,7 @@ impl<'a> RegisterIdentityV1Accounts<'a> {
             system_program,
         } = self;
 
-        // Agent Identity
-        let agent_identity_bump =
-            AgentIdentityV2::check_pda_derivation(agent_identity, self.asset.key)?;
-
-        if agent_identity.data_len() != 0 || *agent_identity.owner != system_program::id() {
-            return Err(MplAgentIdentityError::AgentIdentityAlreadyRegistered.into());
-        }
-
-        // Asset
-        // Assert that the asset exists and is a Core asset.
-        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
-        {
-            return Err(MplAgentIdentityError::InvalidCoreAsset.into());
-        }
+        let agent_identity_bump = validate_registration(agent_identity, asset)?;
 
         // Collection
         // SAFE: Checked by the Core program.
@@ -77,7 +89,10 @@ pub struct RegisterIdentityV1Args {
     pub discriminator: u8,
     /// Padding for alignment.
     #[padding]
-    pub _padding: [u8; 7],
+    pub _padding: [u8; 3],
+    /// Bitmask of the lifecycle checks to register on the Agent Identity
+    /// plugin. `0` selects the default policy.
+    pub lifecycle_checks: u32,
     /// The URI of the Agent Registration JSON file.
     /// We parse this manually from a string representation in the IDL.
     #[idl_type("String")]
@@ -91,11 +106,13 @@ pub fn register_identity_v1<'a>(
     accounts: &'a [AccountInfo<'a>],
     instruction_data: &[u8],
 ) -> ProgramResult {
-    let (_, string_data) =
-        instruction_data.split_at(core::mem::size_of::<RegisterIdentityV1Args>());
+    let args_data = instruction_data
+        .get(..core::mem::size_of::<RegisterIdentityV1Args>())
+        .ok_or(MplAgentIdentityError::InvalidInstructionData)?;
+    let args: RegisterIdentityV1Args = bytemuck::pod_read_unaligned(args_data);
 
-    let uri_length: u32 = u32::from_le_bytes(string_data[..4].try_into().unwrap());
-    let uri = String::from_utf8(string_data[4..4 + uri_length as usize].to_vec()).unwrap();
+    let string_data = &instruction_data[core::mem::size_of::<RegisterIdentityV1Args>()..];
+    let uri = read_registration_uri(string_data)?;
     /****************************************************/
     /****************** Account Setup *******************/
     /****************************************************/
@@ -106,63 +123,82 @@ pub fn register_identity_v1<'a>(
     /****************************************************/
     /***************** Argument Guards ******************/
     /****************************************************/
+    let (_, lifecycle_checks) = lifecycle_checks_from_mask(args.lifecycle_checks)?;
 
     /****************************************************/
     /********************* Actions **********************/
     /****************************************************/
+    register_identity(
+        ctx.accounts.agent_identity,
+        ctx.accounts.asset,
+        ctx.accounts.collection,
+        ctx.accounts.payer,
+        ctx.accounts.authority,
+        ctx.accounts.mpl_core_program,
+        ctx.accounts.system_program,
+        agent_identity_bump,
+        uri.clone(),
+        lifecycle_checks,
+    )?;
+
+    MplAgentIdentityEvent::IdentityRegisteredV1 {
+        asset: *ctx.accounts.asset.key,
+        collection: ctx.accounts.collection.map(|collection| *collection.key),
+        registration_uri: uri,
+    }
+    .emit()
+}
+
+/// Create the agent identity PDA of `asset` and add the Agent Identity plugin
+/// to the asset. The accounts must have been checked with
+/// `validate_registration`.
+#[allow(clippy::too_many_arguments)]
+pub(crate) fn register_identity<'a>(
+    agent_identity: &AccountInfo<'a>,
+    asset: &AccountInfo<'a>,
+    collection: Option<&AccountInfo<'a>>,
+    payer: &AccountInfo<'a>,
+    authority: Option<&AccountInfo<'a>>,
+    mpl_core_program: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    agent_identity_bump: u8,
+    uri: String,
+    lifecycle_checks: Vec<(HookableLifecycleEvent, ExternalCheckResult)>,
+) -> ProgramResult {
     // Create the agent identity account.
-    AgentIdentityV2::create_account(&ctx.accounts, agent_identity_bump)?;
+    AgentIdentityV2::create_account(
+        agent_identity,
+        payer,
+        system_program,
+        asset.key,
+        agent_identity_bump,
+    )?;
 
     // Initialize the account using zero-copy.
     // Borrow the account data mutably and cast to our struct.
-    let mut data = ctx.accounts.agent_identity.try_borrow_mut_data()?;
-    let agent_identity: &mut AgentIdentityV2 =
+    let mut data = agent_identity.try_borrow_mut_data()?;
+    let identity: &mut AgentIdentityV2 =
         bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentIdentityV2>()]);
 
-    agent_identity.initialize(agent_identity_bump, ctx.accounts.asset.key);
+    identity.initialize(agent_identity_bump, asset.key);
 
     // Drop the agent identity account data.
     drop(data);
 
     // Add the Agent Identity External Plugin Adapter to the asset.
     AddExternalPluginAdapterV1Cpi {
-        __program: ctx.accounts.mpl_core_program,
-        asset: ctx.accounts.asset,
-        collection: ctx.accounts.collection,
-        payer: ctx.accounts.payer,
-        authority: ctx.accounts.authority,
-        system_program: ctx.accounts.system_program,
+        __program: mpl_core_program,
+        asset,
+        collection,
+        payer,
+        authority,
+        system_program,
         log_wrapper: None,
         __args: AddExternalPluginAdapterV1InstructionArgs {
             init_info: ExternalPluginAdapterInitInfo::AgentIdentity(AgentIdentityInitInfo {
                 uri,
                 init_plugin_authority: None,
-                lifecycle_checks: vec![
-                    (
-                        HookableLifecycleEvent::Transfer,
-                        ExternalCheckResultBits::new()
-                            .with_can_approve(true)
-                            .with_can_listen(true)
-                            .with_can_reject(true)
-                            .into(),
-                    ),
-                    (
-                        HookableLifecycleEvent::Update,
-                        ExternalCheckResultBits::new()
-                            .with_can_approve(true)
-                            .with_can_listen(true)
-                            .with_can_reject(true)
-                            .into(),
-                    ),
-                    (
-                        HookableLifecycleEvent::Execute,
-                        ExternalCheckResultBits::new()
-                            .with_can_approve(true)
-                            .with_can_listen(true)
-                            .with_can_reject(true)
-                            .into(),
-                    ),
-                ],
+                lifecycle_checks,
             }),
         },
     }
@@ -172,10 +208,10 @@ pub fn register_identity_v1<'a>(
     .invoke_signed_with_remaining_accounts(
         &[&[
             AgentIdentityV2::PREFIX,
-            ctx.accounts.asset.key.as_ref(),
+            asset.key.as_ref(),
             &[agent_identity_bump],
         ]],
-        &[(ctx.accounts.agent_identity, false, true)],
+        &[(agent_identity, false, true)],
     )?;
 
     Ok(())
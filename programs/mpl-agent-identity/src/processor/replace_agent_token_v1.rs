@@ -0,0 +1,185 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::accounts::AssetSigner;
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use podded::pod::OptionalPubkey;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::{clock::Clock, Sysvar};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use super::mint::{assert_mint, resolve_agent_token_source};
+use crate::events::MplAgentIdentityEvent;
+use crate::instruction::accounts::ReplaceAgentTokenV1Accounts;
+use crate::state::{AgentTokenChange, AgentTokenChangeV1, Key};
+use crate::{error::MplAgentIdentityError, state::AgentIdentityV2};
+
+impl<'a> ReplaceAgentTokenV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            agent_identity,
+            agent_token_change,
+            asset,
+            mint,
+            mint_authority,
+            payer,
+            authority,
+            system_program,
+        } = self;
+
+        // Agent Identity
+        {
+            let agent_identity_data = agent_identity.try_borrow_data()?;
+            if agent_identity.owner != &crate::ID
+                || agent_identity_data.len() == 0
+                || (agent_identity_data[0] != Key::AgentIdentityV1 as u8
+                    && agent_identity_data[0] != Key::AgentIdentityV2 as u8)
+            {
+                return Err(MplAgentIdentityError::InvalidAgentIdentity.into());
+            }
+
+            // An AgentIdentityV1 can't have an agent token.
+            if agent_identity_data[0] == Key::AgentIdentityV1 as u8 {
+                return Err(MplAgentIdentityError::AgentTokenNotSet.into());
+            }
+        }
+
+        let _ = AgentIdentityV2::check_pda_derivation(agent_identity, asset.key)?;
+
+        // Agent Token Change
+        // SAFE: The bump is derived again when the account is created.
+        let _ = AgentTokenChangeV1::check(agent_token_change, asset.key)?;
+
+        // Asset
+        // Assert that the asset exists and is a Core asset.
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentIdentityError::InvalidCoreAsset.into());
+        }
+
+        // Mint
+        assert_mint(mint)?;
+
+        // Mint Authority
+        if mint_authority.is_some() {
+            assert_signer(mint_authority.unwrap())?;
+        }
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct ReplaceAgentTokenV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<ReplaceAgentTokenV1Args>() == 8);
+
+pub fn replace_agent_token_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = ReplaceAgentTokenV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    // The agent itself must consent, so the authority must be the asset signer PDA.
+    let asset_signer_pda = AssetSigner::find_pda(ctx.accounts.asset.key).0;
+    if asset_signer_pda != *ctx.accounts.authority.unwrap_or(ctx.accounts.payer).key {
+        return Err(MplAgentIdentityError::OnlyAssetSignerCanSetAgentToken.into());
+    }
+
+    // The new mint must satisfy the same provenance rules as SetAgentTokenFromMintV1.
+    let source = resolve_agent_token_source(
+        ctx.accounts.mint,
+        &asset_signer_pda,
+        ctx.accounts.mint_authority,
+    )?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+
+    let mut agent_identity_data = ctx.accounts.agent_identity.try_borrow_mut_data()?;
+    let agent_identity: &mut AgentIdentityV2 = bytemuck::from_bytes_mut(
+        &mut agent_identity_data[..core::mem::size_of::<AgentIdentityV2>()],
+    );
+
+    let current_agent_token = match agent_identity.agent_token.value() {
+        Some(agent_token) => *agent_token,
+        None => return Err(MplAgentIdentityError::AgentTokenNotSet.into()),
+    };
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let now = Clock::get()?.unix_timestamp;
+    let bump = AgentTokenChangeV1::check(ctx.accounts.agent_token_change, ctx.accounts.asset.key)?;
+    if let Some(pending) = AgentTokenChangeV1::consume(
+        ctx.accounts.agent_token_change,
+        ctx.accounts.asset.key,
+        bump,
+        agent_identity.agent_token_timelock,
+        AgentTokenChange::Replace,
+        Some(ctx.accounts.mint.key),
+        now,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+    )? {
+        msg!(
+            "Agent token change scheduled, unlocks at {}",
+            pending.unlock_at
+        );
+        return MplAgentIdentityEvent::AgentTokenChangeScheduledV1 {
+            asset: *ctx.accounts.asset.key,
+            agent_token: current_agent_token,
+            change: AgentTokenChange::Replace,
+            new_agent_token: Some(*ctx.accounts.mint.key),
+            unlock_at: pending.unlock_at,
+            expires_at: pending.expires_at(),
+        }
+        .emit();
+    }
+
+    agent_identity.agent_token = OptionalPubkey::new(*ctx.accounts.mint.key);
+    agent_identity.agent_token_source = source as u8;
+
+    msg!(
+        "Agent token replaced: {} -> {} ({:?})",
+        current_agent_token,
+        ctx.accounts.mint.key,
+        source
+    );
+    MplAgentIdentityEvent::AgentTokenReplacedV1 {
+        asset: *ctx.accounts.asset.key,
+        old_agent_token: current_agent_token,
+        new_agent_token: *ctx.accounts.mint.key,
+        source,
+    }
+    .emit()
+}
@@ -0,0 +1,154 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::accounts::AssetSigner;
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use podded::pod::{Nullable, OptionalPubkey};
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use super::mint::{assert_mint, resolve_agent_token_source};
+use crate::events::MplAgentIdentityEvent;
+use crate::instruction::accounts::SetAgentTokenFromMintV1Accounts;
+use crate::state::Key;
+use crate::{error::MplAgentIdentityError, state::AgentIdentityV2};
+
+impl<'a> SetAgentTokenFromMintV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            agent_identity,
+            asset,
+            mint,
+            mint_authority,
+            payer,
+            authority,
+            system_program,
+        } = self;
+
+        // Agent Identity
+        let agent_identity_data = agent_identity.try_borrow_data()?;
+        if agent_identity.owner != &crate::ID
+            || agent_identity_data.len() == 0
+            || (agent_identity_data[0] != Key::AgentIdentityV1 as u8
+                && agent_identity_data[0] != Key::AgentIdentityV2 as u8)
+        {
+            return Err(MplAgentIdentityError::InvalidAgentIdentity.into());
+        }
+
+        let _ = AgentIdentityV2::check_pda_derivation(agent_identity, self.asset.key)?;
+
+        // Asset
+        // Assert that the asset exists and is a Core asset.
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentIdentityError::InvalidCoreAsset.into());
+        }
+
+        // Mint
+        assert_mint(mint)?;
+
+        // Mint Authority
+        if mint_authority.is_some() {
+            assert_signer(mint_authority.unwrap())?;
+        }
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct SetAgentTokenFromMintV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<SetAgentTokenFromMintV1Args>() == 8);
+
+pub fn set_agent_token_from_mint_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _instruction_data: &[u8],
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = SetAgentTokenFromMintV1Accounts::context(accounts)?;
+
+    ctx.accounts.validate()?;
+
+    // The agent itself must consent, so the authority must be the asset signer PDA.
+    let asset_signer_pda = AssetSigner::find_pda(ctx.accounts.asset.key).0;
+    if asset_signer_pda != *ctx.accounts.authority.unwrap_or(ctx.accounts.payer).key {
+        return Err(MplAgentIdentityError::OnlyAssetSignerCanSetAgentToken.into());
+    }
+
+    // Either the asset signer holds the mint authority, or the mint authority
+    // co-signs this instruction.
+    let source = resolve_agent_token_source(
+        ctx.accounts.mint,
+        &asset_signer_pda,
+        ctx.accounts.mint_authority,
+    )?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // If agent_identity is an AgentIdentityV1, we need to upgrade it to an AgentIdentityV2.
+    if ctx.accounts.agent_identity.try_borrow_data()?[0] == Key::AgentIdentityV1 as u8 {
+        AgentIdentityV2::migrate_from_v1(
+            ctx.accounts.agent_identity,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+
+    let mut agent_identity_data = ctx.accounts.agent_identity.try_borrow_mut_data()?;
+    let agent_identity: &mut AgentIdentityV2 = bytemuck::from_bytes_mut(
+        &mut agent_identity_data[..core::mem::size_of::<AgentIdentityV2>()],
+    );
+
+    // You can only set the agent token if it is not already set.
+    if agent_identity.agent_token.is_some() {
+        return Err(MplAgentIdentityError::AgentTokenAlreadySet.into());
+    }
+
+    agent_identity.agent_token = OptionalPubkey::new(*ctx.accounts.mint.key);
+    agent_identity.agent_token_source = source as u8;
+
+    msg!(
+        "Agent token set from mint: {} ({:?})",
+        ctx.accounts.mint.key,
+        source
+    );
+
+    MplAgentIdentityEvent::AgentTokenSetV1 {
+        asset: *ctx.accounts.asset.key,
+        agent_token: *ctx.accounts.mint.key,
+        source,
+    }
+    .emit()
+}
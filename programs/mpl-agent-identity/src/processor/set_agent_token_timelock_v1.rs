@@ -0,0 +1,134 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::accounts::AssetSigner;
+use mpl_utils::assert_signer;
+use podded::pod::Nullable;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentIdentityEvent;
+use crate::instruction::accounts::SetAgentTokenTimelockV1Accounts;
+use crate::state::Key;
+use crate::{error::MplAgentIdentityError, state::AgentIdentityV2};
+
+impl<'a> SetAgentTokenTimelockV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            agent_identity,
+            asset,
+            payer,
+            authority,
+            system_program,
+        } = self;
+
+        // Agent Identity
+        {
+            let agent_identity_data = agent_identity.try_borrow_data()?;
+            if agent_identity.owner != &crate::ID
+                || agent_identity_data.len() == 0
+                || (agent_identity_data[0] != Key::AgentIdentityV1 as u8
+                    && agent_identity_data[0] != Key::AgentIdentityV2 as u8)
+            {
+                return Err(MplAgentIdentityError::InvalidAgentIdentity.into());
+            }
+        }
+
+        // Asset
+        // SAFE: Only used to check the PDA derivation of the agent identity.
+        let _ = AgentIdentityV2::check_pda_derivation(agent_identity, asset.key)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct SetAgentTokenTimelockV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 3],
+    /// Notice period in seconds before the agent token can be cleared or
+    /// replaced. Zero disables the timelock.
+    pub timelock: u32,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<SetAgentTokenTimelockV1Args>() == 8);
+
+pub fn set_agent_token_timelock_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = SetAgentTokenTimelockV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    // The agent itself must consent, so the authority must be the asset signer PDA.
+    let asset_signer_pda = AssetSigner::find_pda(ctx.accounts.asset.key).0;
+    if asset_signer_pda != *ctx.accounts.authority.unwrap_or(ctx.accounts.payer).key {
+        return Err(MplAgentIdentityError::OnlyAssetSignerCanSetAgentToken.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+
+    if instruction_data.len() < core::mem::size_of::<SetAgentTokenTimelockV1Args>() {
+        return Err(MplAgentIdentityError::InvalidInstructionData.into());
+    }
+    let args: SetAgentTokenTimelockV1Args = bytemuck::pod_read_unaligned(
+        &instruction_data[..core::mem::size_of::<SetAgentTokenTimelockV1Args>()],
+    );
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // If agent_identity is an AgentIdentityV1, we need to upgrade it to an AgentIdentityV2.
+    if ctx.accounts.agent_identity.try_borrow_data()?[0] == Key::AgentIdentityV1 as u8 {
+        AgentIdentityV2::migrate_from_v1(
+            ctx.accounts.agent_identity,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+
+    let mut agent_identity_data = ctx.accounts.agent_identity.try_borrow_mut_data()?;
+    let agent_identity: &mut AgentIdentityV2 = bytemuck::from_bytes_mut(
+        &mut agent_identity_data[..core::mem::size_of::<AgentIdentityV2>()],
+    );
+
+    // Token holders rely on the notice period, so it can only be shortened
+    // while no agent token is attached.
+    if agent_identity.agent_token.is_some() && args.timelock < agent_identity.agent_token_timelock {
+        return Err(MplAgentIdentityError::AgentTokenTimelockDecrease.into());
+    }
+
+    agent_identity.agent_token_timelock = args.timelock;
+
+    msg!("Agent token timelock set: {}s", args.timelock);
+    MplAgentIdentityEvent::AgentTokenTimelockSetV1 {
+        asset: *ctx.accounts.asset.key,
+        timelock: args.timelock,
+    }
+    .emit()
+}
@@ -1,7 +1,7 @@
 use bytemuck::{Pod, Zeroable};
 use mpl_core::accounts::AssetSigner;
 use mpl_core::types::Key as MplCoreKey;
-use mpl_utils::{assert_signer, resize_or_reallocate_account_raw};
+use mpl_utils::assert_signer;
 use podded::pod::{Nullable, OptionalPubkey};
 use shank::ShankType;
 use solana_program::program_error::ProgramError;
@@ -9,8 +9,9 @@ use solana_program::pubkey::Pubkey;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 use solana_system_interface::program as system_program;
 
+use crate::events::MplAgentIdentityEvent;
 use crate::instruction::accounts::SetAgentTokenV1Accounts;
-use crate::state::Key;
+use crate::state::{AgentTokenSource, Key};
 use crate::{error::MplAgentIdentityError, state::AgentIdentityV2};
 
 /// Genesis program ID.
@@ -143,18 +144,11 @@ pub fn set_agent_token_v1<'a>(
     /****************************************************/
     // If agent_identity is an AgentIdentityV1, we need to upgrade it to an AgentIdentityV2.
     if ctx.accounts.agent_identity.try_borrow_data()?[0] == Key::AgentIdentityV1 as u8 {
-        // Resize the account
-        resize_or_reallocate_account_raw(
+        AgentIdentityV2::migrate_from_v1(
             ctx.accounts.agent_identity,
             ctx.accounts.payer,
             ctx.accounts.system_program,
-            core::mem::size_of::<AgentIdentityV2>(),
         )?;
-
-        // Set the discriminator to AgentIdentityV2
-        ctx.accounts.agent_identity.try_borrow_mut_data()?[0] = Key::AgentIdentityV2 as u8;
-
-        // The new bytes are zeroed so the new fields will be valid.
     }
 
     let mut agent_identity_data = ctx.accounts.agent_identity.try_borrow_mut_data()?;
@@ -177,6 +171,12 @@ pub fn set_agent_token_v1<'a>(
     );
 
     agent_identity.agent_token = OptionalPubkey::new(base_mint);
+    agent_identity.agent_token_source = AgentTokenSource::Genesis as u8;
 
-    Ok(())
+    MplAgentIdentityEvent::AgentTokenSetV1 {
+        asset: *ctx.accounts.asset.key,
+        agent_token: base_mint,
+        source: AgentTokenSource::Genesis,
+    }
+    .emit()
 }
@@ -0,0 +1,180 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::accounts::{AssetSigner, BaseAssetV1};
+use mpl_core::fetch_wrapped_external_plugin_adapter;
+use mpl_core::instructions::{
+    UpdateExternalPluginAdapterV1Cpi, UpdateExternalPluginAdapterV1InstructionArgs,
+};
+use mpl_core::types::{
+    AgentIdentityUpdateInfo, ExternalPluginAdapter, ExternalPluginAdapterKey,
+    ExternalPluginAdapterUpdateInfo, Key as MplCoreKey,
+};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use super::uri::read_registration_uri;
+use crate::events::MplAgentIdentityEvent;
+use crate::instruction::accounts::UpdateAgentRegistrationUriV1Accounts;
+use crate::state::Key;
+use crate::{error::MplAgentIdentityError, state::AgentIdentityV2};
+
+impl<'a> UpdateAgentRegistrationUriV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            agent_identity,
+            asset,
+            collection: _,
+            payer,
+            authority,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Agent Identity
+        let agent_identity_data = agent_identity.try_borrow_data()?;
+        if agent_identity.owner != &crate::ID
+            || agent_identity_data.len() == 0
+            || (agent_identity_data[0] != Key::AgentIdentityV1 as u8
+                && agent_identity_data[0] != Key::AgentIdentityV2 as u8)
+        {
+            return Err(MplAgentIdentityError::InvalidAgentIdentity.into());
+        }
+
+        // An inherited identity has no plugin on the asset to update.
+        if AgentIdentityV2::inherits_collection_identity_from_bytes(&agent_identity_data) {
+            return Err(MplAgentIdentityError::AgentIdentityInheritedFromCollection.into());
+        }
+
+        let agent_identity_bump =
+            AgentIdentityV2::check_pda_derivation(agent_identity, self.asset.key)?;
+
+        // Asset
+        // Assert that the asset exists and is a Core asset.
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentIdentityError::InvalidCoreAsset.into());
+        }
+
+        // Collection
+        // SAFE: Checked by the Core program.
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if authority.is_some() {
+            assert_signer(authority.unwrap())?;
+        }
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentIdentityError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentIdentityError::InvalidSystemProgram.into());
+        }
+
+        Ok(agent_identity_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct UpdateAgentRegistrationUriV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// The new URI of the Agent Registration JSON file.
+    /// We parse this manually from a string representation in the IDL.
+    #[idl_type("String")]
+    agent_registration_uri: [u8; 0],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<UpdateAgentRegistrationUriV1Args>() == 8);
+
+pub fn update_agent_registration_uri_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let string_data = instruction_data
+        .get(core::mem::size_of::<UpdateAgentRegistrationUriV1Args>()..)
+        .ok_or(MplAgentIdentityError::InvalidInstructionData)?;
+
+    let uri = read_registration_uri(string_data)?;
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = UpdateAgentRegistrationUriV1Accounts::context(accounts)?;
+    let agent_identity_bump = ctx.accounts.validate()?;
+
+    // The authority must be the asset owner or the asset signer PDA.
+    let signer = ctx.accounts.authority.unwrap_or(ctx.accounts.payer);
+    let asset = BaseAssetV1::try_from(ctx.accounts.asset)?;
+    let (asset_signer_pda, _) = AssetSigner::find_pda(ctx.accounts.asset.key);
+
+    if asset.owner != *signer.key && asset_signer_pda != *signer.key {
+        return Err(MplAgentIdentityError::OnlyAssetOwnerOrSignerCanUpdateUri.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Read the current URI from the asset's Agent Identity plugin.
+    let old_uri = match fetch_wrapped_external_plugin_adapter::<BaseAssetV1>(
+        ctx.accounts.asset,
+        None,
+        &ExternalPluginAdapterKey::AgentIdentity,
+    ) {
+        Ok((_, ExternalPluginAdapter::AgentIdentity(agent_identity))) => agent_identity.uri,
+        _ => return Err(MplAgentIdentityError::InvalidCoreAsset.into()),
+    };
+
+    // Rewrite the URI on the Agent Identity External Plugin Adapter.
+    UpdateExternalPluginAdapterV1Cpi {
+        __program: ctx.accounts.mpl_core_program,
+        asset: ctx.accounts.asset,
+        collection: ctx.accounts.collection,
+        payer: ctx.accounts.payer,
+        authority: ctx.accounts.authority,
+        system_program: ctx.accounts.system_program,
+        log_wrapper: None,
+        __args: UpdateExternalPluginAdapterV1InstructionArgs {
+            key: ExternalPluginAdapterKey::AgentIdentity,
+            update_info: ExternalPluginAdapterUpdateInfo::AgentIdentity(AgentIdentityUpdateInfo {
+                uri: Some(uri.clone()),
+                lifecycle_checks: None,
+            }),
+        },
+    }
+    // The agent_identity PDA is signed for via `invoke_signed` just like in
+    // `register_identity_v1`, so is_signer = true, is_writable = false.
+    .invoke_signed_with_remaining_accounts(
+        &[&[
+            AgentIdentityV2::PREFIX,
+            ctx.accounts.asset.key.as_ref(),
+            &[agent_identity_bump],
+        ]],
+        &[(ctx.accounts.agent_identity, false, true)],
+    )?;
+
+    msg!("Agent registration URI updated: {} -> {}", old_uri, uri);
+
+    MplAgentIdentityEvent::RegistrationUriUpdatedV1 {
+        asset: *ctx.accounts.asset.key,
+        registration_uri: uri,
+    }
+    .emit()
+}
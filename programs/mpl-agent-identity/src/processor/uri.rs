@@ -0,0 +1,82 @@
+//! Parsing and validation of the Agent Registration URI carried as a
+//! trailing Borsh string in identity instructions.
+
+use solana_program::program_error::ProgramError;
+
+use crate::error::MplAgentIdentityError;
+
+/// Maximum length in bytes of an Agent Registration URI.
+pub const MAX_REGISTRATION_URI_LEN: usize = 200;
+
+/// URI schemes an Agent Registration JSON file may be hosted under.
+pub const ALLOWED_REGISTRATION_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+/// Parse a Borsh-style length-prefixed UTF-8 string from `data`:
+/// `[u32 LE length][bytes...]`. Rejects if the buffer is too short, the
+/// string is empty, the declared length exceeds `max_len`, or the bytes
+/// aren't valid UTF-8.
+pub fn read_length_prefixed_string(data: &[u8], max_len: usize) -> Result<String, ProgramError> {
+    let len_bytes: [u8; 4] = data
+        .get(..4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(MplAgentIdentityError::InvalidInstructionData)?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+
+    if len == 0 {
+        return Err(MplAgentIdentityError::RegistrationUriEmpty.into());
+    }
+    if len > max_len {
+        return Err(MplAgentIdentityError::RegistrationUriTooLong.into());
+    }
+
+    let bytes = data
+        .get(4..4 + len)
+        .ok_or(MplAgentIdentityError::InvalidInstructionData)?;
+
+    String::from_utf8(bytes.to_vec())
+        .map_err(|_| MplAgentIdentityError::RegistrationUriNotUtf8.into())
+}
+
+/// Read the Agent Registration URI that follows the fixed-size args and
+/// check that it uses one of the allowed schemes.
+pub fn read_registration_uri(data: &[u8]) -> Result<String, ProgramError> {
+    let uri = read_length_prefixed_string(data, MAX_REGISTRATION_URI_LEN)?;
+
+    if !ALLOWED_REGISTRATION_URI_SCHEMES
+        .iter()
+        .any(|scheme| uri.starts_with(scheme) && uri.len() > scheme.len())
+    {
+        return Err(MplAgentIdentityError::RegistrationUriSchemeNotAllowed.into());
+    }
+
+    Ok(uri)
+}
+
+/// Read a Borsh-style vector of Agent Registration URIs:
+/// `[u32 LE count][string...]`. Each URI is checked as in
+/// `read_registration_uri`. The table must hold either a single URI shared by
+/// every entry or exactly `entries` URIs.
+pub fn read_registration_uri_table(
+    data: &[u8],
+    entries: usize,
+) -> Result<Vec<String>, ProgramError> {
+    let count_bytes: [u8; 4] = data
+        .get(..4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(MplAgentIdentityError::InvalidInstructionData)?;
+    let count = u32::from_le_bytes(count_bytes) as usize;
+
+    if count != 1 && count != entries {
+        return Err(MplAgentIdentityError::InvalidRegistrationUriTable.into());
+    }
+
+    let mut offset = 4;
+    let mut uris = Vec::with_capacity(count);
+    for _ in 0..count {
+        let uri = read_registration_uri(&data[offset..])?;
+        offset += 4 + uri.len();
+        uris.push(uri);
+    }
+
+    Ok(uris)
+}
@@ -1,5 +1,7 @@
 use bytemuck::{Pod, Zeroable};
-use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use mpl_utils::{
+    assert_derivation, create_or_allocate_account_raw, resize_or_reallocate_account_raw,
+};
 use podded::pod::OptionalPubkey;
 use shank::ShankAccount;
 use solana_program::{
@@ -7,7 +9,7 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::{error::MplAgentIdentityError, instruction::accounts::RegisterIdentityV1Accounts};
+use crate::error::MplAgentIdentityError;
 
 use super::Key;
 
@@ -26,9 +28,23 @@ pub struct AgentIdentityV2 {
     pub asset: Pubkey,
     /// The address of the agent token.
     pub agent_token: OptionalPubkey,
+    /// How the agent token was linked. Only meaningful when `agent_token` is set.
+    #[idl_type(AgentTokenSource)]
+    pub agent_token_source: u8,
+    /// Padding for 4-byte alignment.
+    #[padding]
+    pub _padding_1: [u8; 3],
+    /// Notice period in seconds before the agent token can be cleared or
+    /// replaced. Zero means changes take effect immediately. Scheduled
+    /// changes are held in the asset's `AgentTokenChangeV1`.
+    pub agent_token_timelock: u32,
+    /// Whether the asset takes its Agent Identity plugin from its collection
+    /// instead of carrying its own.
+    #[idl_type("bool")]
+    pub inherits_collection_identity: u8,
     // Reserved for future use.
     #[padding]
-    pub _reserved: [u8; 32],
+    pub _reserved: [u8; 23],
 }
 
 // Compile-time assertion to ensure struct is 8-byte aligned.
@@ -49,18 +65,59 @@ impl AgentIdentityV2 {
         )
     }
 
-    pub fn create_account(accounts: &RegisterIdentityV1Accounts, bump: u8) -> ProgramResult {
+    pub fn create_account<'a>(
+        agent_identity: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
         solana_program::msg!("Creating agent identity account");
         create_or_allocate_account_raw(
             crate::ID,
-            accounts.agent_identity,
-            accounts.system_program,
-            accounts.payer,
+            agent_identity,
+            system_program,
+            payer,
             core::mem::size_of::<AgentIdentityV2>(),
-            &[Self::PREFIX, accounts.asset.key.as_ref(), &[bump]],
+            &[Self::PREFIX, asset.as_ref(), &[bump]],
         )
     }
 
+    /// Returns `true` if `data` is an `AgentIdentityV2` that inherits its
+    /// Agent Identity plugin from the asset's collection. `AgentIdentityV1`
+    /// accounts always carry their own plugin.
+    pub fn inherits_collection_identity_from_bytes(data: &[u8]) -> bool {
+        data.first() == Some(&(Key::AgentIdentityV2 as u8))
+            && data
+                .get(core::mem::offset_of!(
+                    AgentIdentityV2,
+                    inherits_collection_identity
+                ))
+                .is_some_and(|inherits| *inherits != 0)
+    }
+
+    /// Upgrade an `AgentIdentityV1` account to an `AgentIdentityV2` in place.
+    /// The account is grown to the V2 size (the payer funds the extra rent)
+    /// and the discriminator rewritten. The new bytes are zeroed so the new
+    /// fields are valid.
+    pub fn migrate_from_v1<'a>(
+        agent_identity: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        solana_program::msg!("Migrating agent identity account to V2");
+        resize_or_reallocate_account_raw(
+            agent_identity,
+            payer,
+            system_program,
+            core::mem::size_of::<AgentIdentityV2>(),
+        )?;
+
+        agent_identity.try_borrow_mut_data()?[0] = Key::AgentIdentityV2 as u8;
+
+        Ok(())
+    }
+
     /// Initialize the account with the given bump seed.
     #[inline]
     pub fn initialize(&mut self, bump: u8, asset: &Pubkey) {
@@ -0,0 +1,164 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use podded::pod::OptionalPubkey;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::error::MplAgentIdentityError;
+
+use super::{AgentTokenChange, Key};
+
+/// Seconds after unlocking during which a scheduled agent token change can
+/// be executed. It must be scheduled again once expired.
+pub const AGENT_TOKEN_CHANGE_WINDOW: i64 = 7 * 24 * 60 * 60;
+
+/// Agent token change scheduled under the timelock of an agent identity. It
+/// is created on the first scheduled change of the asset and reused by the
+/// following ones.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct AgentTokenChangeV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// The scheduled agent token change.
+    #[idl_type(AgentTokenChange)]
+    pub change: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 5],
+    /// The address of the asset.
+    pub asset: Pubkey,
+    /// The mint a scheduled replacement sets. Unset for a scheduled clear.
+    pub new_agent_token: OptionalPubkey,
+    /// Unix timestamp after which the scheduled change can be executed. Zero
+    /// when no change is scheduled.
+    pub unlock_at: i64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 32],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<AgentTokenChangeV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<AgentTokenChangeV1>() == 112);
+
+impl AgentTokenChangeV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"agent_token_change";
+
+    /// Check the account is the agent token change of the asset, either
+    /// initialized or not yet created. Returns the PDA bump seed.
+    pub fn check(address: &AccountInfo, asset: &Pubkey) -> Result<u8, ProgramError> {
+        let bump = assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, asset.as_ref()],
+            MplAgentIdentityError::InvalidAgentTokenChange,
+        )?;
+
+        if address.data_len() > 0
+            && (address.owner != &crate::ID
+                || address.data_len() != core::mem::size_of::<AgentTokenChangeV1>()
+                || address.try_borrow_data()?[0] != Key::AgentTokenChangeV1 as u8)
+        {
+            return Err(MplAgentIdentityError::InvalidAgentTokenChange.into());
+        }
+
+        Ok(bump)
+    }
+
+    /// Check whether the agent token can be changed now, scheduling the change
+    /// if `timelock` is set and the same change, with the same new mint, is
+    /// not already scheduled. Scheduling replaces any other or expired change.
+    ///
+    /// Returns `None` if the change should be applied, or the scheduled change
+    /// if it was only scheduled. The pending schedule is consumed when the
+    /// change is applied.
+    #[allow(clippy::too_many_arguments)]
+    pub fn consume<'a>(
+        agent_token_change: &AccountInfo<'a>,
+        asset: &Pubkey,
+        bump: u8,
+        timelock: u32,
+        change: AgentTokenChange,
+        new_agent_token: Option<&Pubkey>,
+        now: i64,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> Result<Option<AgentTokenChangeV1>, ProgramError> {
+        if timelock == 0 && agent_token_change.data_is_empty() {
+            return Ok(None);
+        }
+
+        if agent_token_change.data_is_empty() {
+            create_or_allocate_account_raw(
+                crate::ID,
+                agent_token_change,
+                system_program,
+                payer,
+                core::mem::size_of::<AgentTokenChangeV1>(),
+                &[Self::PREFIX, asset.as_ref(), &[bump]],
+            )?;
+
+            let mut data = agent_token_change.try_borrow_mut_data()?;
+            let pending: &mut AgentTokenChangeV1 =
+                bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentTokenChangeV1>()]);
+            pending.initialize(bump, asset);
+        }
+
+        let mut data = agent_token_change.try_borrow_mut_data()?;
+        let pending: &mut AgentTokenChangeV1 =
+            bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentTokenChangeV1>()]);
+
+        if timelock == 0 {
+            pending.clear();
+            return Ok(None);
+        }
+
+        let new_agent_token = new_agent_token
+            .map(|new_agent_token| OptionalPubkey::new(*new_agent_token))
+            .unwrap_or_default();
+
+        let scheduled = pending.unlock_at != 0
+            && pending.change == change as u8
+            && pending.new_agent_token == new_agent_token;
+        if scheduled {
+            if now < pending.unlock_at {
+                return Err(MplAgentIdentityError::AgentTokenChangeLocked.into());
+            }
+
+            if now < pending.expires_at() {
+                pending.clear();
+                return Ok(None);
+            }
+        }
+
+        pending.unlock_at = now.saturating_add(timelock as i64);
+        pending.change = change as u8;
+        pending.new_agent_token = new_agent_token;
+        Ok(Some(*pending))
+    }
+
+    /// Unix timestamp at which the scheduled change expires.
+    pub fn expires_at(&self) -> i64 {
+        self.unlock_at.saturating_add(AGENT_TOKEN_CHANGE_WINDOW)
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, asset: &Pubkey) {
+        self.key = Key::AgentTokenChangeV1 as u8;
+        self.bump = bump;
+        self.asset = *asset;
+        self.clear();
+    }
+
+    fn clear(&mut self) {
+        self.change = AgentTokenChange::None as u8;
+        self.new_agent_token = OptionalPubkey::default();
+        self.unlock_at = 0;
+    }
+}
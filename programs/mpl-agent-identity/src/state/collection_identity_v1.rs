@@ -0,0 +1,80 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentIdentityError;
+
+use super::Key;
+
+/// Agent identity of a Core collection. Shares the `agent_identity` seed
+/// prefix with `AgentIdentityV2`, keyed by the collection address instead of
+/// an asset address.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct CollectionIdentityV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the collection.
+    pub collection: Pubkey,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 16],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<CollectionIdentityV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<CollectionIdentityV1>() == 56);
+
+impl CollectionIdentityV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"agent_identity";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        collection: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, collection.as_ref()],
+            MplAgentIdentityError::InvalidAccountData,
+        )
+    }
+
+    pub fn create_account<'a>(
+        collection_identity: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        collection: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        solana_program::msg!("Creating collection identity account");
+        create_or_allocate_account_raw(
+            crate::ID,
+            collection_identity,
+            system_program,
+            payer,
+            core::mem::size_of::<CollectionIdentityV1>(),
+            &[Self::PREFIX, collection.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, collection: &Pubkey) {
+        self.key = Key::CollectionIdentityV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.collection = *collection;
+    }
+}
@@ -1,9 +1,14 @@
 mod agent_identity_v1;
 mod agent_identity_v2;
+mod agent_token_change_v1;
+mod collection_identity_v1;
 
 pub use agent_identity_v1::*;
 pub use agent_identity_v2::*;
+pub use agent_token_change_v1::*;
+pub use collection_identity_v1::*;
 
+use borsh::{BorshDeserialize, BorshSerialize};
 use shank::ShankType;
 
 /// Account discriminator enum.
@@ -14,6 +19,8 @@ pub enum Key {
     Uninitialized,
     AgentIdentityV1,
     AgentIdentityV2,
+    CollectionIdentityV1,
+    AgentTokenChangeV1,
 }
 
 impl From<u8> for Key {
@@ -22,6 +29,8 @@ impl From<u8> for Key {
             0 => Key::Uninitialized,
             1 => Key::AgentIdentityV1,
             2 => Key::AgentIdentityV2,
+            3 => Key::CollectionIdentityV1,
+            4 => Key::AgentTokenChangeV1,
             _ => Key::Uninitialized,
         }
     }
@@ -32,3 +41,31 @@ impl From<Key> for u8 {
         value as u8
     }
 }
+
+/// How an agent token was linked to an agent identity.
+/// Tokens linked before this was recorded all came from Genesis launches,
+/// which is why `Genesis` is the zero value.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ShankType, BorshSerialize, BorshDeserialize)]
+#[borsh(use_discriminant = true)]
+pub enum AgentTokenSource {
+    /// Base mint of a mint-funded Genesis launch.
+    Genesis,
+    /// Existing mint, proven by its mint authority signing.
+    MintAuthority,
+    /// Existing mint whose mint authority is the asset signer.
+    AssetSigner,
+}
+
+/// A time-locked change of the agent token.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ShankType, BorshSerialize, BorshDeserialize)]
+#[borsh(use_discriminant = true)]
+pub enum AgentTokenChange {
+    /// No change is scheduled.
+    None,
+    /// Clear the agent token.
+    Clear,
+    /// Replace the agent token with another mint.
+    Replace,
+}
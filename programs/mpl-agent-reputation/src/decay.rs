@@ -0,0 +1,172 @@
+//! Deterministic fixed-point exponential decay.
+//!
+//! Decayed quantities are unsigned Q32.32 fixed-point numbers. After
+//! `elapsed` slots a quantity is multiplied by `2^(-elapsed / half_life)`,
+//! computed with integer arithmetic only so that every validator reaches the
+//! same result.
+
+/// Number of fractional bits of a Q32.32 number.
+pub const FRAC_BITS: u32 = 32;
+
+/// `1.0` as Q32.32.
+pub const ONE: u64 = 1 << FRAC_BITS;
+
+/// `1.0` as Q1.63, the precision the decay factor is computed at.
+const FACTOR_ONE: u128 = 1 << 63;
+
+/// `2^(-2^-i)` as Q1.63 for `i` in `1..=32`, rounded to nearest.
+const HALVING_ROOTS: [u64; FRAC_BITS as usize] = [
+    6521908912666391106,
+    7755900482342532474,
+    8457869449776733335,
+    8832331321595618838,
+    9025734193507008925,
+    9124017994966720698,
+    9173560510430823462,
+    9198432556164277331,
+    9210893855724328809,
+    9217130834664616070,
+    9220250907674776491,
+    9221811340221203999,
+    9222591655524303666,
+    9222981837935769002,
+    9223176935331786073,
+    9223274485577403901,
+    9223323261087119913,
+    9223347648938705290,
+    9223359842888679895,
+    9223365939869712687,
+    9223368988361740456,
+    9223370512608132184,
+    9223371274731422509,
+    9223371655793091287,
+    9223371846323931579,
+    9223371941589353202,
+    9223371989222064382,
+    9223372013038420064,
+    9223372024946597928,
+    9223372030900686866,
+    9223372033877731337,
+    9223372035366253572,
+];
+
+/// Decay the Q32.32 `value` over `elapsed` slots with the given half-life,
+/// rounding down. A zero half-life disables decay.
+pub fn decay(value: u64, elapsed: u64, half_life: u64) -> u64 {
+    if half_life == 0 {
+        return value;
+    }
+
+    let halvings = elapsed / half_life;
+    if halvings >= u64::BITS as u64 {
+        return 0;
+    }
+
+    // Remaining fraction of a half-life, as Q0.32.
+    let fraction = (((elapsed % half_life) as u128) << FRAC_BITS) / half_life as u128;
+
+    // 2^(-fraction) is the product of 2^(-2^-i) over the set bits of the
+    // fraction, most significant first.
+    let mut factor = FACTOR_ONE;
+    for (i, root) in HALVING_ROOTS.iter().enumerate() {
+        if fraction & (1 << (FRAC_BITS as usize - 1 - i)) != 0 {
+            factor = (factor * *root as u128) >> 63;
+        }
+    }
+
+    (((value as u128 * factor) >> 63) >> halvings) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use proptest::prelude::*;
+
+    const HALF_LIFE: core::ops::RangeInclusive<u64> = 1..=100_000_000;
+
+    fn reference(value: u64, elapsed: u64, half_life: u64) -> f64 {
+        value as f64 * (-(elapsed as f64) / half_life as f64).exp2()
+    }
+
+    #[test]
+    fn halving_roots_are_decreasing_towards_one() {
+        assert!(HALVING_ROOTS.windows(2).all(|pair| pair[0] < pair[1]));
+        assert!(HALVING_ROOTS
+            .iter()
+            .all(|root| (*root as u128) < FACTOR_ONE));
+    }
+
+    #[test]
+    fn decays_by_half_every_half_life() {
+        assert_eq!(decay(ONE, 0, 100), ONE);
+        assert_eq!(decay(ONE, 100, 100), ONE / 2);
+        assert_eq!(decay(ONE, 300, 100), ONE / 8);
+        assert_eq!(decay(ONE, 64 * 100, 100), 0);
+        assert_eq!(decay(u64::MAX, u64::MAX, 1), 0);
+    }
+
+    #[test]
+    fn zero_half_life_disables_decay() {
+        assert_eq!(decay(ONE, 1_000, 0), ONE);
+    }
+
+    proptest! {
+        #[test]
+        fn never_grows(value: u64, elapsed: u64, half_life in HALF_LIFE) {
+            prop_assert!(decay(value, elapsed, half_life) <= value);
+        }
+
+        #[test]
+        fn whole_half_lives_halve_exactly(
+            value: u64,
+            halvings in 0u64..64,
+            half_life in HALF_LIFE,
+        ) {
+            prop_assert_eq!(
+                decay(value, halvings * half_life, half_life),
+                value >> halvings,
+            );
+        }
+
+        #[test]
+        fn is_monotonic_in_elapsed(
+            value: u64,
+            elapsed in 0u64..1 << 40,
+            extra in 0u64..1 << 40,
+            half_life in HALF_LIFE,
+        ) {
+            prop_assert!(
+                decay(value, elapsed + extra, half_life) <= decay(value, elapsed, half_life)
+            );
+        }
+
+        #[test]
+        fn matches_floating_point(
+            value: u64,
+            elapsed in 0u64..1 << 40,
+            half_life in HALF_LIFE,
+        ) {
+            let expected = reference(value, elapsed, half_life);
+            let actual = decay(value, elapsed, half_life) as f64;
+            prop_assert!(
+                (actual - expected).abs() <= expected * 1e-9 + 2.0,
+                "decay({value}, {elapsed}, {half_life}) = {actual}, expected {expected}",
+            );
+        }
+
+        #[test]
+        fn composes_over_consecutive_periods(
+            value: u64,
+            first in 0u64..1 << 40,
+            second in 0u64..1 << 40,
+            half_life in HALF_LIFE,
+        ) {
+            let stepwise = decay(decay(value, first, half_life), second, half_life) as f64;
+            let direct = decay(value, first + second, half_life) as f64;
+            prop_assert!(
+                (stepwise - direct).abs() <= direct * 1e-9 + 2.0,
+                "stepwise {stepwise}, direct {direct}",
+            );
+        }
+    }
+}
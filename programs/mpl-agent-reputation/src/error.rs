@@ -27,6 +27,162 @@ pub enum MplAgentReputationError {
     /// 5 - Agent Reputation already registered
     #[error("Agent Reputation already registered")]
     AgentReputationAlreadyRegistered,
+
+    /// 6 - Agent Reputation is not registered
+    #[error("Agent Reputation is not registered")]
+    AgentReputationNotRegistered,
+
+    /// 7 - Feedback score must be between 0 and 100
+    #[error("Feedback score must be between 0 and 100")]
+    InvalidFeedbackScore,
+
+    /// 8 - Feedback URI is empty
+    #[error("Feedback URI is empty")]
+    FeedbackUriEmpty,
+
+    /// 9 - Feedback URI exceeds the maximum length
+    #[error("Feedback URI exceeds the maximum length")]
+    FeedbackUriTooLong,
+
+    /// 10 - Feedback URI is not valid UTF-8
+    #[error("Feedback URI is not valid UTF-8")]
+    FeedbackUriNotUtf8,
+
+    /// 11 - Feedback URI scheme is not allowed
+    #[error("Feedback URI scheme is not allowed")]
+    FeedbackUriSchemeNotAllowed,
+
+    /// 12 - Numerical overflow
+    #[error("Numerical overflow")]
+    NumericalOverflow,
+
+    /// 13 - Invalid MPL Account Compression Program
+    #[error("Invalid MPL Account Compression Program")]
+    InvalidCompressionProgram,
+
+    /// 14 - Invalid receipts tree
+    #[error("Invalid receipts tree")]
+    InvalidReceiptsTree,
+
+    /// 15 - Work receipt was already reviewed
+    #[error("Work receipt was already reviewed")]
+    ReceiptAlreadyReviewed,
+
+    /// 16 - Invalid feedback account
+    #[error("Invalid feedback account")]
+    InvalidFeedback,
+
+    /// 17 - Feedback index is already used
+    #[error("Feedback index is already used")]
+    FeedbackAlreadyExists,
+
+    /// 18 - Authority must be the asset owner or an execution delegate
+    #[error("Authority must be the asset owner or an execution delegate")]
+    InvalidResponseAuthority,
+
+    /// 19 - Invalid execution delegate record
+    #[error("Invalid execution delegate record")]
+    InvalidExecutionDelegateRecord,
+
+    /// 20 - Response URI is empty
+    #[error("Response URI is empty")]
+    ResponseUriEmpty,
+
+    /// 21 - Response URI exceeds the maximum length
+    #[error("Response URI exceeds the maximum length")]
+    ResponseUriTooLong,
+
+    /// 22 - Response URI is not valid UTF-8
+    #[error("Response URI is not valid UTF-8")]
+    ResponseUriNotUtf8,
+
+    /// 23 - Response URI scheme is not allowed
+    #[error("Response URI scheme is not allowed")]
+    ResponseUriSchemeNotAllowed,
+
+    /// 24 - Feedback has been revoked
+    #[error("Feedback has been revoked")]
+    FeedbackRevoked,
+
+    /// 25 - Feedback is already disputed
+    #[error("Feedback is already disputed")]
+    FeedbackAlreadyDisputed,
+
+    /// 26 - Only the reviewer may revoke their feedback
+    #[error("Only the reviewer may revoke their feedback")]
+    InvalidReviewer,
+
+    /// 27 - Only the asset owner or an execution delegate may dispute feedback
+    #[error("Only the asset owner or an execution delegate may dispute feedback")]
+    InvalidDisputeAuthority,
+
+    /// 28 - Invalid reputation config account
+    #[error("Invalid reputation config account")]
+    InvalidReputationConfig,
+
+    /// 29 - Reputation config is already initialized
+    #[error("Reputation config is already initialized")]
+    ReputationConfigAlreadyInitialized,
+
+    /// 30 - Half-life must be non-zero
+    #[error("Half-life must be non-zero")]
+    InvalidHalfLife,
+
+    /// 31 - Invalid program data account
+    #[error("Invalid program data account")]
+    InvalidProgramData,
+
+    /// 32 - Authority does not match the config authority
+    #[error("Authority does not match the config authority")]
+    InvalidConfigAuthority,
+
+    /// 33 - Invalid agent identity account
+    #[error("Invalid agent identity account")]
+    InvalidAgentIdentity,
+
+    /// 34 - Only the asset owner or its asset signer can register reputation
+    #[error("Only the asset owner or its asset signer can register reputation")]
+    OnlyAssetOwnerOrSignerCanRegisterReputation,
+
+    /// 35 - Invalid weighting policy
+    #[error("Invalid weighting policy")]
+    InvalidWeightingPolicy,
+
+    /// 36 - Invalid reviewer history account
+    #[error("Invalid reviewer history account")]
+    InvalidReviewerHistory,
+
+    /// 37 - Reviewer asset and identity do not prove a registered reviewer
+    #[error("Reviewer asset and identity do not prove a registered reviewer")]
+    InvalidReviewerIdentity,
+
+    /// 38 - Reviewer token account does not hold the weighting token
+    #[error("Reviewer token account does not hold the weighting token")]
+    InvalidReviewerTokenAccount,
+
+    /// 39 - Invalid reputation snapshot account
+    #[error("Invalid reputation snapshot account")]
+    InvalidReputationSnapshot,
+
+    /// 40 - Reputation was already snapshotted this epoch
+    #[error("Reputation was already snapshotted this epoch")]
+    ReputationAlreadySnapshotted,
+
+    /// 41 - Invalid token lock account
+    #[error("Invalid token lock account")]
+    InvalidTokenLock,
+
+    /// 42 - Tokens are locked until a later slot
+    #[error("Tokens are locked until a later slot")]
+    TokensLocked,
+
+    /// 43 - Invalid token program
+    #[error("Invalid token program")]
+    InvalidTokenProgram,
+
+    /// 44 - Reviewer reputation does not prove an established reviewer
+    #[error("Reviewer reputation does not prove an established reviewer")]
+    InvalidReviewerReputation,
 }
 
 impl From<MplAgentReputationError> for ProgramError {
@@ -0,0 +1,107 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+/// Version of the event encoding. Emitted as the first byte of every event so
+/// indexers can reject layouts they don't understand.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Events emitted by the program via `sol_log_data`. Each log entry is a
+/// single data field: `[EVENT_VERSION, borsh(MplAgentReputationEvent)...]`.
+///
+/// New variants must be appended to keep existing variant indices stable.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MplAgentReputationEvent {
+    /// Feedback was submitted for an agent asset.
+    FeedbackSubmittedV1 {
+        asset: Pubkey,
+        reviewer: Pubkey,
+        score: u8,
+        tag1: [u8; 32],
+        tag2: [u8; 32],
+        feedback_uri: String,
+    },
+    /// The agent responded to a feedback entry.
+    ResponseAppendedV1 {
+        asset: Pubkey,
+        reviewer: Pubkey,
+        feedback_index: u64,
+        responder: Pubkey,
+        response_uri: String,
+        response_hash: [u8; 32],
+    },
+    /// The reviewer revoked a feedback entry, removing it from the
+    /// aggregates.
+    FeedbackRevokedV1 {
+        asset: Pubkey,
+        reviewer: Pubkey,
+        feedback_index: u64,
+        score: u8,
+    },
+    /// The agent disputed a feedback entry.
+    FeedbackDisputedV1 {
+        asset: Pubkey,
+        reviewer: Pubkey,
+        feedback_index: u64,
+        disputer: Pubkey,
+    },
+    /// The reputation config was initialized or updated.
+    ReputationConfigUpdatedV1 {
+        authority: Pubkey,
+        half_life_slots: u64,
+    },
+    /// The decayed score of an agent was brought up to date. Scores are
+    /// Q32.32 fixed-point numbers.
+    ReputationRefreshedV1 {
+        asset: Pubkey,
+        decayed_score: u64,
+        decayed_count: u64,
+        slot: u64,
+    },
+    /// The weighting policy was set or updated. Weights are listed in the
+    /// order of `WeightingPolicyV1`.
+    WeightingPolicyUpdatedV1 {
+        base_weight: u32,
+        receipt_weight: u32,
+        max_receipts: u32,
+        identity_weight: u32,
+        token_weight: u32,
+        token_mint: Pubkey,
+        min_token_balance: u64,
+        min_identity_feedback: u64,
+        token_lock_slots: u64,
+    },
+    /// Feedback was weighted in the weighted aggregates. Follows the
+    /// `FeedbackSubmittedV1` event of the feedback.
+    FeedbackWeightedV1 {
+        asset: Pubkey,
+        reviewer: Pubkey,
+        feedback_index: u64,
+        weight: u32,
+    },
+    /// The aggregates of an agent reputation were snapshotted for `epoch`.
+    ReputationSnapshottedV1 {
+        asset: Pubkey,
+        epoch: u64,
+        slot: u64,
+        feedback_count: u64,
+        score_sum: u64,
+        decayed_score: u64,
+        decayed_count: u64,
+    },
+    /// A reviewer withdrew tokens from their token lock.
+    TokensUnlockedV1 {
+        reviewer: Pubkey,
+        mint: Pubkey,
+        amount: u64,
+    },
+}
+
+impl MplAgentReputationEvent {
+    /// Serialize the event and write it to the program log.
+    pub fn emit(&self) -> ProgramResult {
+        let mut data = vec![EVENT_VERSION];
+        borsh::to_writer(&mut data, self)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+}
@@ -1,6 +1,12 @@
 use shank::{ShankContext, ShankInstruction};
 
-use crate::processor::RegisterReputationV1Args;
+use crate::processor::{
+    AppendResponseV1Args, DisputeFeedbackV1Args, GetAgentProfileV1Args,
+    InitializeReputationConfigV1Args, RefreshReputationV1Args, RegisterReputationAsOwnerV1Args,
+    RegisterReputationV1Args, RevokeFeedbackV1Args, SetWeightingPolicyV1Args,
+    SnapshotReputationV1Args, SubmitFeedbackV1Args, UnlockTokensV1Args,
+    UpdateReputationConfigV1Args,
+};
 
 /// Instruction discriminants for routing.
 /// The first byte of instruction data determines which instruction to execute.
@@ -8,6 +14,18 @@ use crate::processor::RegisterReputationV1Args;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MplAgentReputationInstructionDiscriminant {
     RegisterReputationV1 = 0,
+    SubmitFeedbackV1 = 1,
+    AppendResponseV1 = 2,
+    RevokeFeedbackV1 = 3,
+    DisputeFeedbackV1 = 4,
+    InitializeReputationConfigV1 = 5,
+    UpdateReputationConfigV1 = 6,
+    RefreshReputationV1 = 7,
+    GetAgentProfileV1 = 8,
+    RegisterReputationAsOwnerV1 = 9,
+    SetWeightingPolicyV1 = 10,
+    SnapshotReputationV1 = 11,
+    UnlockTokensV1 = 12,
 }
 
 impl TryFrom<u8> for MplAgentReputationInstructionDiscriminant {
@@ -16,6 +34,18 @@ impl TryFrom<u8> for MplAgentReputationInstructionDiscriminant {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(MplAgentReputationInstructionDiscriminant::RegisterReputationV1),
+            1 => Ok(MplAgentReputationInstructionDiscriminant::SubmitFeedbackV1),
+            2 => Ok(MplAgentReputationInstructionDiscriminant::AppendResponseV1),
+            3 => Ok(MplAgentReputationInstructionDiscriminant::RevokeFeedbackV1),
+            4 => Ok(MplAgentReputationInstructionDiscriminant::DisputeFeedbackV1),
+            5 => Ok(MplAgentReputationInstructionDiscriminant::InitializeReputationConfigV1),
+            6 => Ok(MplAgentReputationInstructionDiscriminant::UpdateReputationConfigV1),
+            7 => Ok(MplAgentReputationInstructionDiscriminant::RefreshReputationV1),
+            8 => Ok(MplAgentReputationInstructionDiscriminant::GetAgentProfileV1),
+            9 => Ok(MplAgentReputationInstructionDiscriminant::RegisterReputationAsOwnerV1),
+            10 => Ok(MplAgentReputationInstructionDiscriminant::SetWeightingPolicyV1),
+            11 => Ok(MplAgentReputationInstructionDiscriminant::SnapshotReputationV1),
+            12 => Ok(MplAgentReputationInstructionDiscriminant::UnlockTokensV1),
             _ => Err(()),
         }
     }
@@ -27,7 +57,7 @@ impl TryFrom<u8> for MplAgentReputationInstructionDiscriminant {
 #[derive(Clone, Debug, ShankContext, ShankInstruction)]
 #[rustfmt::skip]
 pub enum MplAgentReputationInstruction {
-    /// Register an Agent Reputation.
+    /// Register an Agent Reputation. Authorized by the update authority of the asset, which adds an AppData plugin to the asset for the reputation summary. Also attaches the plugin to an Agent Reputation registered with RegisterReputationAsOwnerV1.
     #[account(0, writable, name="agent_reputation", desc = "The agent reputation PDA")]
     #[account(1, writable, name="asset", desc = "The address of the Core asset")]
     #[account(2, writable, optional, name="collection", desc = "The address of the collection")]
@@ -36,4 +66,117 @@ pub enum MplAgentReputationInstruction {
     #[account(5, name="mpl_core_program", desc = "The MPL Core program")]
     #[account(6, name="system_program", desc = "The system program")]
     RegisterReputationV1(RegisterReputationV1Args),
+
+    /// Submit feedback about an agent. The reviewer must prove ownership of a work receipt issued by the agent, and each receipt backs at most one feedback. Records the feedback in a feedback PDA keyed on the asset, the reviewer and a reviewer-chosen index. Weights the feedback according to the weighting policy, from the receipts the reviewer proved with the agent and, optionally, an asset with an agent identity and enough feedback the reviewer owns or the tokens the reviewer locked, which then stay locked for the policy's lock period. Updates the raw and weighted aggregates and the decayed score on the agent reputation PDA, upgrading an AgentReputationV1 to an AgentReputationV2, and writes a summary to the asset's AppData plugin. The merkle proof of the receipt is passed as remaining accounts.
+    #[account(0, writable, name="agent_reputation", desc = "The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2.")]
+    #[account(1, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(3, signer, name="reviewer", desc = "The author of the feedback and owner of the work receipt")]
+    #[account(4, writable, name="receipt_nullifier", desc = "The receipt nullifier PDA at [\"receipt_nullifier\", tree_index_le, nonce_le]")]
+    #[account(5, writable, name="feedback", desc = "The feedback PDA at [\"feedback\", asset, reviewer, feedback_index_le]")]
+    #[account(6, name="reputation_config", desc = "The reputation config PDA at [\"reputation_config\"], initialized or not")]
+    #[account(7, name="weighting_policy", desc = "The weighting policy PDA at [\"weighting_policy\"], set or not")]
+    #[account(8, writable, name="reviewer_history", desc = "The reviewer history PDA at [\"reviewer_history\", asset, reviewer]")]
+    #[account(9, optional, name="reviewer_asset", desc = "A Core asset owned by the reviewer, proving with its agent identity and reputation that the reviewer is established")]
+    #[account(10, optional, name="reviewer_identity", desc = "The mpl-agent-identity agent identity PDA of the reviewer asset")]
+    #[account(11, optional, name="reviewer_reputation", desc = "The agent reputation PDA of the reviewer asset")]
+    #[account(12, optional, writable, name="reviewer_token_lock", desc = "The token lock PDA at [\"token_lock\", reviewer, token_mint], created on first use")]
+    #[account(13, optional, name="reviewer_token_vault", desc = "A token account of the token lock PDA holding the weighting policy token")]
+    #[account(14, name="merkle_tree", desc = "The mpl-agent-tools receipts merkle tree holding the work receipt")]
+    #[account(15, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(16, name="compression_program", desc = "The MPL Account Compression program")]
+    #[account(17, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(18, name="system_program", desc = "The system program")]
+    SubmitFeedbackV1(SubmitFeedbackV1Args),
+
+    /// Append the agent's response to a feedback entry. Authorized by the owner of the asset or by the authority of an mpl-agent-tools execution delegate record for the asset. Stores the hash of the response on the feedback PDA.
+    #[account(0, writable, name="feedback", desc = "The feedback PDA being responded to")]
+    #[account(1, name="asset", desc = "The address of the Core asset")]
+    #[account(2, signer, name="authority", desc = "The asset owner or the authority of the execution delegate record")]
+    #[account(3, optional, name="execution_delegate_record", desc = "The mpl-agent-tools execution delegate record, if the authority is not the asset owner")]
+    AppendResponseV1(AppendResponseV1Args),
+
+    /// Revoke feedback. Only the reviewer may revoke their feedback. Removes its score from the aggregates, and from the disputed aggregates if it was disputed, and what is left of it from the decayed score on the agent reputation PDA and rewrites the summary in the asset's AppData plugin. The feedback PDA is kept with a revoked status, so neither its index nor its work receipt can be reused.
+    #[account(0, writable, name="agent_reputation", desc = "The agent reputation PDA")]
+    #[account(1, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(3, writable, name="feedback", desc = "The feedback PDA being revoked")]
+    #[account(4, name="reputation_config", desc = "The reputation config PDA at [\"reputation_config\"], initialized or not")]
+    #[account(5, signer, name="reviewer", desc = "The author of the feedback")]
+    #[account(6, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(7, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(8, name="system_program", desc = "The system program")]
+    RevokeFeedbackV1(RevokeFeedbackV1Args),
+
+    /// Dispute feedback. Authorized by the owner of the asset or by the authority of an mpl-agent-tools execution delegate record for the asset. Sets the disputed status on the feedback PDA, adds the feedback to the disputed aggregates on the agent reputation PDA and rewrites the summary in the asset's AppData plugin. The feedback stays in the other aggregates.
+    #[account(0, writable, name="agent_reputation", desc = "The agent reputation PDA")]
+    #[account(1, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(3, writable, name="feedback", desc = "The feedback PDA being disputed")]
+    #[account(4, signer, name="authority", desc = "The asset owner or the authority of the execution delegate record")]
+    #[account(5, optional, name="execution_delegate_record", desc = "The mpl-agent-tools execution delegate record, if the authority is not the asset owner")]
+    #[account(6, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(7, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(8, name="system_program", desc = "The system program")]
+    DisputeFeedbackV1(DisputeFeedbackV1Args),
+
+    /// Initialize the reputation config. Only the upgrade authority of the program may initialize it, and becomes the config authority.
+    #[account(0, writable, name="reputation_config", desc = "The reputation config PDA at [\"reputation_config\"]")]
+    #[account(1, name="program_data", desc = "The program data account of this program")]
+    #[account(2, signer, name="authority", desc = "The upgrade authority of this program")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(4, name="system_program", desc = "The system program")]
+    InitializeReputationConfigV1(InitializeReputationConfigV1Args),
+
+    /// Update the reputation config, optionally handing it to a new authority.
+    #[account(0, writable, name="reputation_config", desc = "The reputation config PDA at [\"reputation_config\"]")]
+    #[account(1, signer, name="authority", desc = "The config authority")]
+    #[account(2, optional, name="new_authority", desc = "The new config authority, if it changes")]
+    UpdateReputationConfigV1(UpdateReputationConfigV1Args),
+
+    /// Bring the decayed score of an agent up to the current slot. Permissionless.
+    #[account(0, writable, name="agent_reputation", desc = "The agent reputation PDA. Must be of type AgentReputationV2.")]
+    #[account(1, name="reputation_config", desc = "The reputation config PDA at [\"reputation_config\"], initialized or not")]
+    RefreshReputationV1(RefreshReputationV1Args),
+
+    /// Return the profile of an agent as return data: whether it has an agent identity and an agent reputation, its agent token, its feedback aggregates and its decayed score as of the current slot. Writes nothing, so other programs can invoke it to check an agent.
+    #[account(0, name="asset", desc = "The address of the Core asset")]
+    #[account(1, name="agent_identity", desc = "The mpl-agent-identity agent identity PDA, initialized or not")]
+    #[account(2, name="agent_reputation", desc = "The agent reputation PDA, initialized or not")]
+    #[account(3, name="reputation_config", desc = "The reputation config PDA at [\"reputation_config\"], initialized or not")]
+    GetAgentProfileV1(GetAgentProfileV1Args),
+
+    /// Register an Agent Reputation as the owner of the asset, without the collection authority. No AppData plugin is added to the asset, so no summary is published until its update authority attaches one with RegisterReputationV1.
+    #[account(0, writable, name="agent_reputation", desc = "The agent reputation PDA")]
+    #[account(1, name="asset", desc = "The address of the Core asset")]
+    #[account(2, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(3, optional, signer, name="authority", desc = "The asset owner or the asset signer PDA. If not provided, the payer will be used.")]
+    #[account(4, name="system_program", desc = "The system program")]
+    RegisterReputationAsOwnerV1(RegisterReputationAsOwnerV1Args),
+
+    /// Set the weighting policy, creating it the first time. Only the config authority may set it. Feedback already submitted keeps its weight.
+    #[account(0, writable, name="weighting_policy", desc = "The weighting policy PDA at [\"weighting_policy\"]")]
+    #[account(1, name="reputation_config", desc = "The initialized reputation config PDA at [\"reputation_config\"]")]
+    #[account(2, signer, name="authority", desc = "The config authority")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(4, name="system_program", desc = "The system program")]
+    SetWeightingPolicyV1(SetWeightingPolicyV1Args),
+
+    /// Snapshot the aggregates of an agent, decayed up to the current slot, for the current epoch. Permissionless, once per epoch. Upgrades an AgentReputationV1 to an AgentReputationV2.
+    #[account(0, writable, name="reputation_snapshot", desc = "The reputation snapshot PDA at [\"reputation_snapshot\", asset, epoch_le] for the current epoch")]
+    #[account(1, writable, name="agent_reputation", desc = "The agent reputation PDA. Must be of type AgentReputationV1 or AgentReputationV2.")]
+    #[account(2, name="asset", desc = "The address of the Core asset")]
+    #[account(3, name="reputation_config", desc = "The reputation config PDA at [\"reputation_config\"], initialized or not")]
+    #[account(4, writable, signer, name="payer", desc = "The payer for the snapshot rent")]
+    #[account(5, name="system_program", desc = "The system program")]
+    SnapshotReputationV1(SnapshotReputationV1Args),
+
+    /// Withdraw tokens from a token lock once its lock period is over. Only the reviewer owning the lock may withdraw.
+    #[account(0, name="token_lock", desc = "The token lock PDA at [\"token_lock\", reviewer, mint]")]
+    #[account(1, writable, name="token_vault", desc = "The token account of the token lock PDA holding the tokens")]
+    #[account(2, writable, name="destination", desc = "The token account receiving the tokens")]
+    #[account(3, name="mint", desc = "The mint of the locked tokens")]
+    #[account(4, signer, name="reviewer", desc = "The reviewer owning the lock")]
+    #[account(5, name="token_program", desc = "The SPL Token or Token 2022 program owning the token accounts")]
+    UnlockTokensV1(UnlockTokensV1Args),
 }
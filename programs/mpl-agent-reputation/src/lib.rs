@@ -1,5 +1,7 @@
+pub mod decay;
 pub mod entrypoint;
 pub mod error;
+pub mod events;
 pub mod instruction;
 pub mod processor;
 pub mod state;
@@ -0,0 +1,123 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+
+use super::authority::assert_agent_authority;
+use super::uri::read_response_uri;
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::AppendResponseV1Accounts,
+    state::{FeedbackStatus, FeedbackV1, Key},
+};
+
+impl<'a> AppendResponseV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            feedback,
+            asset,
+            authority,
+            execution_delegate_record,
+        } = self;
+
+        // Feedback
+        if feedback.owner != &crate::ID
+            || feedback.data_len() != core::mem::size_of::<FeedbackV1>()
+            || feedback.try_borrow_data()?[0] != Key::FeedbackV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidFeedback.into());
+        }
+
+        // Asset
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidCoreAsset.into());
+        }
+
+        // Authority
+        assert_signer(authority)?;
+
+        // The asset owner or an execution delegate may respond.
+        assert_agent_authority(
+            asset,
+            authority,
+            *execution_delegate_record,
+            MplAgentReputationError::InvalidResponseAuthority,
+        )
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct AppendResponseV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Hash of the off-chain response file.
+    pub response_hash: [u8; 32],
+    /// The URI of the off-chain response file.
+    /// We parse this manually from a string representation in the IDL.
+    #[idl_type("String")]
+    response_uri: [u8; 0],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<AppendResponseV1Args>() == 40);
+
+pub fn append_response_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args_data = instruction_data
+        .get(..core::mem::size_of::<AppendResponseV1Args>())
+        .ok_or(MplAgentReputationError::InvalidInstructionData)?;
+    let args: AppendResponseV1Args = bytemuck::pod_read_unaligned(args_data);
+
+    let response_uri =
+        read_response_uri(&instruction_data[core::mem::size_of::<AppendResponseV1Args>()..])?;
+
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = AppendResponseV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let mut data = ctx.accounts.feedback.try_borrow_mut_data()?;
+    let feedback: &mut FeedbackV1 = bytemuck::from_bytes_mut(&mut data);
+
+    // The feedback must be about the asset the authority answers for.
+    if feedback.asset != *ctx.accounts.asset.key {
+        return Err(MplAgentReputationError::InvalidFeedback.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    // Revoked feedback is no longer part of the reputation.
+    if feedback.status() == FeedbackStatus::Revoked {
+        return Err(MplAgentReputationError::FeedbackRevoked.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    feedback.record_response(args.response_hash, Clock::get()?.slot)?;
+
+    MplAgentReputationEvent::ResponseAppendedV1 {
+        asset: feedback.asset,
+        reviewer: feedback.reviewer,
+        feedback_index: feedback.index,
+        responder: *ctx.accounts.authority.key,
+        response_uri,
+        response_hash: args.response_hash,
+    }
+    .emit()
+}
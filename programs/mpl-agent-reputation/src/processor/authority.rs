@@ -0,0 +1,65 @@
+//! Authorization of the agent side of a reputation: the owner of the agent
+//! asset, or an executive it delegated execution to in mpl-agent-tools.
+
+use mpl_agent_tools::{accounts::ExecutionDelegateRecordV1, types::Key as MplAgentToolsKey};
+use mpl_core::accounts::BaseAssetV1;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+/// Check that `authority` acts for `asset`: either it owns the asset, or it
+/// is the authority of `execution_delegate_record`. The asset must already
+/// be known to be a Core asset and `authority` to have signed.
+/// `invalid_authority` is reported when neither holds.
+pub fn assert_agent_authority(
+    asset: &AccountInfo,
+    authority: &AccountInfo,
+    execution_delegate_record: Option<&AccountInfo>,
+    invalid_authority: MplAgentReputationError,
+) -> ProgramResult {
+    // The asset owner may always act for the agent.
+    if BaseAssetV1::try_from(asset)?.owner == *authority.key {
+        return Ok(());
+    }
+
+    match execution_delegate_record {
+        Some(execution_delegate_record)
+            if execution_delegate_authority(asset, execution_delegate_record)?
+                == *authority.key =>
+        {
+            Ok(())
+        }
+        _ => Err(invalid_authority.into()),
+    }
+}
+
+/// The authority of the executive `execution_delegate_record` delegates
+/// execution of `asset` to.
+fn execution_delegate_authority(
+    asset: &AccountInfo,
+    execution_delegate_record: &AccountInfo,
+) -> Result<Pubkey, ProgramError> {
+    // Execution Delegate Record
+    if execution_delegate_record.owner != &mpl_agent_tools::ID
+        || execution_delegate_record.data_len() < ExecutionDelegateRecordV1::LEN
+        || execution_delegate_record.try_borrow_data()?[0]
+            != MplAgentToolsKey::ExecutionDelegateRecordV1 as u8
+    {
+        return Err(MplAgentReputationError::InvalidExecutionDelegateRecord.into());
+    }
+
+    let record = ExecutionDelegateRecordV1::from_bytes(
+        &execution_delegate_record.try_borrow_data()?[..ExecutionDelegateRecordV1::LEN],
+    )
+    .map_err(|_| MplAgentReputationError::InvalidExecutionDelegateRecord)?;
+
+    let (record_pda, _) = ExecutionDelegateRecordV1::find_pda(&record.executive_profile, asset.key);
+    if *execution_delegate_record.key != record_pda || record.agent_asset != *asset.key {
+        return Err(MplAgentReputationError::InvalidExecutionDelegateRecord.into());
+    }
+
+    Ok(record.authority)
+}
@@ -0,0 +1,162 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use super::authority::assert_agent_authority;
+use super::summary::{write_summary, SummaryAccounts};
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::DisputeFeedbackV1Accounts,
+    state::{AgentReputationV2, FeedbackV1, Key, ReputationSummaryV1},
+};
+
+impl<'a> DisputeFeedbackV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            agent_reputation,
+            asset,
+            collection: _,
+            feedback,
+            authority,
+            execution_delegate_record,
+            payer,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Agent Reputation
+        // Feedback was recorded, so the account was upgraded to V2.
+        if agent_reputation.owner != &crate::ID
+            || agent_reputation.data_len() < core::mem::size_of::<AgentReputationV2>()
+            || agent_reputation.try_borrow_data()?[0] != Key::AgentReputationV2 as u8
+        {
+            return Err(MplAgentReputationError::InvalidAccountData.into());
+        }
+
+        let agent_reputation_bump =
+            AgentReputationV2::check_pda_derivation(agent_reputation, asset.key)?;
+
+        // Asset
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidCoreAsset.into());
+        }
+
+        // Collection
+        // SAFE: Checked by the Core program.
+
+        // Feedback
+        if feedback.owner != &crate::ID
+            || feedback.data_len() != core::mem::size_of::<FeedbackV1>()
+            || feedback.try_borrow_data()?[0] != Key::FeedbackV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidFeedback.into());
+        }
+
+        // Authority
+        assert_signer(authority)?;
+
+        // The asset owner or an execution delegate may dispute.
+        assert_agent_authority(
+            asset,
+            authority,
+            *execution_delegate_record,
+            MplAgentReputationError::InvalidDisputeAuthority,
+        )?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentReputationError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentReputationError::InvalidSystemProgram.into());
+        }
+
+        Ok(agent_reputation_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct DisputeFeedbackV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<DisputeFeedbackV1Args>() == 8);
+
+pub fn dispute_feedback_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &DisputeFeedbackV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = DisputeFeedbackV1Accounts::context(accounts)?;
+    let agent_reputation_bump = ctx.accounts.validate()?;
+
+    let mut data = ctx.accounts.feedback.try_borrow_mut_data()?;
+    let feedback: &mut FeedbackV1 = bytemuck::from_bytes_mut(&mut data);
+
+    // The feedback must be about the asset the authority answers for.
+    if feedback.asset != *ctx.accounts.asset.key {
+        return Err(MplAgentReputationError::InvalidFeedback.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // The feedback stays in the aggregates and is added to the disputed ones,
+    // so consumers decide whether to count it.
+    feedback.dispute()?;
+    let feedback = *feedback;
+    drop(data);
+
+    let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
+    let agent_reputation: &mut AgentReputationV2 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV2>()]);
+
+    agent_reputation.record_dispute(feedback.score, feedback.weight)?;
+    let summary = ReputationSummaryV1::from(&*agent_reputation);
+
+    // Drop the agent reputation account data before the CPI.
+    drop(data);
+
+    // Write the summary to the AppData plugin.
+    write_summary(
+        &SummaryAccounts {
+            mpl_core_program: ctx.accounts.mpl_core_program,
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            payer: ctx.accounts.payer,
+            agent_reputation: ctx.accounts.agent_reputation,
+            system_program: ctx.accounts.system_program,
+        },
+        &summary,
+        agent_reputation_bump,
+    )?;
+
+    MplAgentReputationEvent::FeedbackDisputedV1 {
+        asset: feedback.asset,
+        reviewer: feedback.reviewer,
+        feedback_index: feedback.index,
+        disputer: *ctx.accounts.authority.key,
+    }
+    .emit()
+}
@@ -0,0 +1,144 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_agent_identity::{accounts::AgentIdentityV2, types::Key as MplAgentIdentityKey};
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, program::set_return_data,
+    program_error::ProgramError, sysvar::Sysvar,
+};
+
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::GetAgentProfileV1Accounts,
+    state::{AgentProfileV1, AgentReputationV2, Key, ReputationConfigV1},
+};
+
+impl<'a> GetAgentProfileV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            asset,
+            agent_identity,
+            agent_reputation,
+            reputation_config: _,
+        } = self;
+
+        // Asset
+        // SAFE: Only used to derive the PDAs.
+
+        // Agent Identity
+        // Uninitialized when the asset has no agent identity.
+        let (agent_identity_pda, _) = AgentIdentityV2::find_pda(asset.key);
+        if *agent_identity.key != agent_identity_pda {
+            return Err(MplAgentReputationError::InvalidAgentIdentity.into());
+        }
+
+        if agent_identity.data_len() > 0 {
+            let data = agent_identity.try_borrow_data()?;
+            if agent_identity.owner != &mpl_agent_identity::ID
+                || (data[0] != MplAgentIdentityKey::AgentIdentityV1 as u8
+                    && data[0] != MplAgentIdentityKey::AgentIdentityV2 as u8)
+            {
+                return Err(MplAgentReputationError::InvalidAgentIdentity.into());
+            }
+        }
+
+        // Agent Reputation
+        // Uninitialized when the asset has no agent reputation.
+        AgentReputationV2::check_pda_derivation(agent_reputation, asset.key)?;
+
+        if agent_reputation.data_len() > 0 {
+            let data = agent_reputation.try_borrow_data()?;
+            if agent_reputation.owner != &crate::ID
+                || (data[0] != Key::AgentReputationV1 as u8
+                    && data[0] != Key::AgentReputationV2 as u8)
+            {
+                return Err(MplAgentReputationError::InvalidAccountData.into());
+            }
+        }
+
+        // Reputation Config
+        // SAFE: Checked when reading the half-life.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct GetAgentProfileV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<GetAgentProfileV1Args>() == 8);
+
+/// Return the profile of an agent as an `AgentProfileV1` in the return data.
+/// Writes nothing, so other programs can invoke it to check an agent.
+pub fn get_agent_profile_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &GetAgentProfileV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = GetAgentProfileV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let slot = Clock::get()?.slot;
+    let mut profile = AgentProfileV1 {
+        version: AgentProfileV1::VERSION,
+        asset: *ctx.accounts.asset.key,
+        slot,
+        ..AgentProfileV1::zeroed()
+    };
+
+    let agent_identity = ctx.accounts.agent_identity.try_borrow_data()?;
+    if !agent_identity.is_empty() {
+        profile.identity_registered = 1;
+    }
+    if agent_identity.first() == Some(&(MplAgentIdentityKey::AgentIdentityV2 as u8)) {
+        let identity = AgentIdentityV2::from_bytes(&agent_identity)
+            .map_err(|_| MplAgentReputationError::InvalidAgentIdentity)?;
+        if let Some(agent_token) = identity.agent_token {
+            profile.agent_token = agent_token;
+            profile.has_agent_token = 1;
+        }
+    }
+
+    let agent_reputation = ctx.accounts.agent_reputation.try_borrow_data()?;
+    if !agent_reputation.is_empty() {
+        profile.reputation_registered = 1;
+    }
+    if agent_reputation.first() == Some(&(Key::AgentReputationV2 as u8)) {
+        // Decay a copy, leaving the account as it is.
+        let mut reputation: AgentReputationV2 =
+            *bytemuck::from_bytes(&agent_reputation[..core::mem::size_of::<AgentReputationV2>()]);
+        let half_life = ReputationConfigV1::half_life_slots(ctx.accounts.reputation_config)?;
+        reputation.apply_decay(slot, half_life);
+
+        profile.feedback_count = reputation.feedback_count;
+        profile.score_sum = reputation.score_sum;
+        profile.score_sum_squares = reputation.score_sum_squares;
+        profile.last_feedback_slot = reputation.last_feedback_slot;
+        profile.decayed_score = reputation.decayed_score;
+        profile.decayed_count = reputation.decayed_count;
+        profile.weight_sum = reputation.weight_sum;
+        profile.weighted_score_sum = reputation.weighted_score_sum;
+        profile.disputed_count = reputation.disputed_count;
+        profile.disputed_score_sum = reputation.disputed_score_sum;
+        profile.disputed_weight_sum = reputation.disputed_weight_sum;
+        profile.disputed_weighted_score_sum = reputation.disputed_weighted_score_sum;
+    }
+
+    set_return_data(bytemuck::bytes_of(&profile));
+
+    Ok(())
+}
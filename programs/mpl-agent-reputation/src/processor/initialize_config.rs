@@ -0,0 +1,141 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError, instruction::accounts::InitializeReputationConfigV1Accounts,
+    state::ReputationConfigV1,
+};
+
+/// The BPF upgradeable loader, which owns the program data account holding
+/// the upgrade authority of the program.
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_program::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Discriminant of the `ProgramData` variant of the upgradeable loader state.
+const PROGRAM_DATA_DISCRIMINANT: u32 = 3;
+
+/// Read the upgrade authority of this program from its program data account,
+/// laid out as `[u32 variant][u64 slot][u8 option][32-byte authority]`.
+fn upgrade_authority(program_data: &AccountInfo) -> Result<Option<Pubkey>, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    if *program_data.key != program_data_address || *program_data.owner != BPF_LOADER_UPGRADEABLE_ID
+    {
+        return Err(MplAgentReputationError::InvalidProgramData.into());
+    }
+
+    let data = program_data.try_borrow_data()?;
+    let header = data
+        .get(..45)
+        .ok_or(MplAgentReputationError::InvalidProgramData)?;
+    if u32::from_le_bytes(header[..4].try_into().unwrap()) != PROGRAM_DATA_DISCRIMINANT {
+        return Err(MplAgentReputationError::InvalidProgramData.into());
+    }
+
+    Ok(match header[12] {
+        0 => None,
+        _ => Some(Pubkey::new_from_array(header[13..45].try_into().unwrap())),
+    })
+}
+
+impl<'a> InitializeReputationConfigV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            reputation_config,
+            program_data,
+            authority,
+            payer,
+            system_program,
+        } = self;
+
+        // Reputation Config
+        let reputation_config_bump = ReputationConfigV1::check_pda_derivation(reputation_config)?;
+        if !reputation_config.data_is_empty() || *reputation_config.owner != system_program::id() {
+            return Err(MplAgentReputationError::ReputationConfigAlreadyInitialized.into());
+        }
+
+        // Authority
+        // Only the upgrade authority of the program may set it up.
+        assert_signer(authority)?;
+        if upgrade_authority(program_data)? != Some(*authority.key) {
+            return Err(MplAgentReputationError::InvalidConfigAuthority.into());
+        }
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentReputationError::InvalidSystemProgram.into());
+        }
+
+        Ok(reputation_config_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct InitializeReputationConfigV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Number of slots after which a feedback counts half as much in the
+    /// decayed score.
+    pub half_life_slots: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<InitializeReputationConfigV1Args>() == 16);
+
+pub fn initialize_reputation_config_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &InitializeReputationConfigV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = InitializeReputationConfigV1Accounts::context(accounts)?;
+    let reputation_config_bump = ctx.accounts.validate()?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.half_life_slots == 0 {
+        return Err(MplAgentReputationError::InvalidHalfLife.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    ReputationConfigV1::create_account(
+        ctx.accounts.reputation_config,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        reputation_config_bump,
+    )?;
+
+    let mut data = ctx.accounts.reputation_config.try_borrow_mut_data()?;
+    let reputation_config: &mut ReputationConfigV1 = bytemuck::from_bytes_mut(&mut data);
+    reputation_config.initialize(
+        reputation_config_bump,
+        ctx.accounts.authority.key,
+        args.half_life_slots,
+    );
+
+    MplAgentReputationEvent::ReputationConfigUpdatedV1 {
+        authority: *ctx.accounts.authority.key,
+        half_life_slots: args.half_life_slots,
+    }
+    .emit()
+}
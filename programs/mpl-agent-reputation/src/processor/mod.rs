@@ -1,4 +1,21 @@
+mod append_response;
+mod authority;
+mod dispute_feedback;
+mod get_agent_profile;
+mod initialize_config;
+mod receipt;
+mod refresh_reputation;
 mod register;
+mod register_as_owner;
+mod revoke_feedback;
+mod set_weighting_policy;
+mod snapshot_reputation;
+mod submit_feedback;
+mod summary;
+mod unlock_tokens;
+mod update_config;
+mod uri;
+mod weight;
 
 use bytemuck::try_from_bytes;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
@@ -6,7 +23,21 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg,
 use crate::error::MplAgentReputationError;
 use crate::instruction::MplAgentReputationInstructionDiscriminant;
 
+pub use append_response::{append_response_v1, AppendResponseV1Args};
+pub use dispute_feedback::{dispute_feedback_v1, DisputeFeedbackV1Args};
+pub use get_agent_profile::{get_agent_profile_v1, GetAgentProfileV1Args};
+pub use initialize_config::{
+    initialize_reputation_config_v1, InitializeReputationConfigV1Args, BPF_LOADER_UPGRADEABLE_ID,
+};
+pub use refresh_reputation::{refresh_reputation_v1, RefreshReputationV1Args};
 pub use register::{register_reputation_v1, RegisterReputationV1Args};
+pub use register_as_owner::{register_reputation_as_owner_v1, RegisterReputationAsOwnerV1Args};
+pub use revoke_feedback::{revoke_feedback_v1, RevokeFeedbackV1Args};
+pub use set_weighting_policy::{set_weighting_policy_v1, SetWeightingPolicyV1Args};
+pub use snapshot_reputation::{snapshot_reputation_v1, SnapshotReputationV1Args};
+pub use submit_feedback::{submit_feedback_v1, SubmitFeedbackV1Args, MAX_FEEDBACK_SCORE};
+pub use unlock_tokens::{unlock_tokens_v1, UnlockTokensV1Args};
+pub use update_config::{update_reputation_config_v1, UpdateReputationConfigV1Args};
 
 /// Process incoming instructions.
 ///
@@ -35,6 +66,94 @@ pub fn process_instruction<'a>(
                     .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
             )
         }
+        Ok(MplAgentReputationInstructionDiscriminant::SubmitFeedbackV1) => {
+            msg!("Instruction: SubmitFeedbackV1");
+            submit_feedback_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::AppendResponseV1) => {
+            msg!("Instruction: AppendResponseV1");
+            append_response_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::RevokeFeedbackV1) => {
+            msg!("Instruction: RevokeFeedbackV1");
+            revoke_feedback_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::DisputeFeedbackV1) => {
+            msg!("Instruction: DisputeFeedbackV1");
+            dispute_feedback_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::InitializeReputationConfigV1) => {
+            msg!("Instruction: InitializeReputationConfigV1");
+            initialize_reputation_config_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::UpdateReputationConfigV1) => {
+            msg!("Instruction: UpdateReputationConfigV1");
+            update_reputation_config_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::RefreshReputationV1) => {
+            msg!("Instruction: RefreshReputationV1");
+            refresh_reputation_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::GetAgentProfileV1) => {
+            msg!("Instruction: GetAgentProfileV1");
+            get_agent_profile_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::RegisterReputationAsOwnerV1) => {
+            msg!("Instruction: RegisterReputationAsOwnerV1");
+            register_reputation_as_owner_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::SetWeightingPolicyV1) => {
+            msg!("Instruction: SetWeightingPolicyV1");
+            set_weighting_policy_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::SnapshotReputationV1) => {
+            msg!("Instruction: SnapshotReputationV1");
+            snapshot_reputation_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentReputationInstructionDiscriminant::UnlockTokensV1) => {
+            msg!("Instruction: UnlockTokensV1");
+            unlock_tokens_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentReputationError::InvalidInstructionData)?,
+            )
+        }
         Err(_) => Err(MplAgentReputationError::InvalidInstructionData.into()),
     }
 }
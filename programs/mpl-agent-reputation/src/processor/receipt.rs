@@ -0,0 +1,39 @@
+//! Proof of holding a work receipt minted by mpl-agent-tools.
+//!
+//! Receipts are Bubblegum V2 leaves in the tools receipts trees. The leaf a
+//! receipt for `(agent, reviewer)` must hash to is rebuilt and verified
+//! against the tree root by `mpl_agent_tools::receipts`, the same code the
+//! tools program uses for reviews.
+
+use mpl_agent_tools::receipts::{find_receipts_tree_pda, ReceiptProof};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::error::MplAgentReputationError;
+
+pub(crate) use mpl_agent_tools::receipts::MPL_ACCOUNT_COMPRESSION_ID;
+
+/// Verify `merkle_tree` is the tools receipts tree at `tree_index` holding a
+/// receipt owned by `leaf_owner` whose first creator is `agent`. The
+/// remaining accounts are the merkle proof.
+pub(crate) fn verify_receipt<'a>(
+    merkle_tree: &AccountInfo<'a>,
+    compression_program: &AccountInfo<'a>,
+    proof_accounts: &[AccountInfo<'a>],
+    agent: &Pubkey,
+    leaf_owner: &Pubkey,
+    tree_index: u64,
+    proof: &ReceiptProof,
+) -> ProgramResult {
+    if *merkle_tree.key != find_receipts_tree_pda(tree_index).0 {
+        return Err(MplAgentReputationError::InvalidReceiptsTree.into());
+    }
+
+    mpl_agent_tools::receipts::verify_receipt(
+        merkle_tree,
+        compression_program,
+        proof_accounts,
+        agent,
+        leaf_owner,
+        proof,
+    )
+}
@@ -0,0 +1,83 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::RefreshReputationV1Accounts,
+    state::{AgentReputationV2, Key, ReputationConfigV1},
+};
+
+impl<'a> RefreshReputationV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            agent_reputation,
+            reputation_config: _,
+        } = self;
+
+        // Agent Reputation
+        // Only V2 accounts carry a decayed score.
+        if agent_reputation.owner != &crate::ID
+            || agent_reputation.data_len() < core::mem::size_of::<AgentReputationV2>()
+            || agent_reputation.try_borrow_data()?[0] != Key::AgentReputationV2 as u8
+        {
+            return Err(MplAgentReputationError::InvalidAccountData.into());
+        }
+
+        // Reputation Config
+        // SAFE: Checked when reading the half-life.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RefreshReputationV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RefreshReputationV1Args>() == 8);
+
+/// Permissionless crank bringing the decayed score of an agent up to the
+/// current slot.
+pub fn refresh_reputation_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &RefreshReputationV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RefreshReputationV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let half_life = ReputationConfigV1::half_life_slots(ctx.accounts.reputation_config)?;
+
+    let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
+    let agent_reputation: &mut AgentReputationV2 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV2>()]);
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let slot = Clock::get()?.slot;
+    agent_reputation.apply_decay(slot, half_life);
+
+    MplAgentReputationEvent::ReputationRefreshedV1 {
+        asset: agent_reputation.asset,
+        decayed_score: agent_reputation.decayed_score,
+        decayed_count: agent_reputation.decayed_count,
+        slot,
+    }
+    .emit()
+}
@@ -13,9 +13,11 @@ use shank::ShankType;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 use solana_system_interface::program as system_program;
 
+use super::summary::{write_summary, SummaryAccounts};
 use crate::{
-    error::MplAgentReputationError, instruction::accounts::RegisterReputationV1Accounts,
-    state::AgentReputationV1,
+    error::MplAgentReputationError,
+    instruction::accounts::RegisterReputationV1Accounts,
+    state::{AgentReputationV1, AgentReputationV2, Key, ReputationSummaryV1},
 };
 
 /// Arguments for the RegisterReputationV1 instruction.
@@ -71,13 +73,18 @@ pub fn register_reputation_v1<'a>(
         ctx.accounts.asset.key,
     )?;
 
-    // Agent reputation PDA must not already be initialized. System CreateAccount
-    // would fail anyway, but checking up front gives a clear error and
-    // defends against any future change in the downstream helper.
-    if ctx.accounts.agent_reputation.data_len() != 0
-        || *ctx.accounts.agent_reputation.owner != system_program::id()
-    {
-        return Err(MplAgentReputationError::AgentReputationAlreadyRegistered.into());
+    // An agent reputation registered by the asset owner has no AppData
+    // plugin, which the collection authority may attach later. Any other
+    // initialized agent reputation is already fully registered.
+    let agent_reputation_registered = ctx.accounts.agent_reputation.data_len() != 0
+        || *ctx.accounts.agent_reputation.owner != system_program::id();
+    if agent_reputation_registered {
+        let key = ctx.accounts.agent_reputation.try_borrow_data()?[0];
+        if ctx.accounts.agent_reputation.owner != &crate::ID
+            || (key != Key::AgentReputationV1 as u8 && key != Key::AgentReputationV2 as u8)
+        {
+            return Err(MplAgentReputationError::AgentReputationAlreadyRegistered.into());
+        }
     }
 
     // Assert that the asset exists and is a Core asset.
@@ -119,17 +126,6 @@ pub fn register_reputation_v1<'a>(
     /****************************************************/
     /********************* Actions **********************/
     /****************************************************/
-    // Create the agent identity account.
-    AgentReputationV1::create_account(&ctx.accounts, agent_reputation_bump)?;
-
-    // Initialize the account using zero-copy.
-    // Borrow the account data mutably and cast to our struct.
-    let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
-    let agent_reputation: &mut AgentReputationV1 =
-        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV1>()]);
-
-    agent_reputation.initialize(agent_reputation_bump, ctx.accounts.asset.key);
-
     // Check if the asset already has a AppData plugin.
     let result = fetch_wrapped_external_plugin_adapter::<BaseAssetV1>(
         ctx.accounts.asset,
@@ -139,6 +135,30 @@ pub fn register_reputation_v1<'a>(
         }),
     );
 
+    if agent_reputation_registered {
+        // Only the AppData plugin is missing.
+        if result.is_ok() {
+            return Err(MplAgentReputationError::AgentReputationAlreadyRegistered.into());
+        }
+    } else {
+        // Create the agent reputation account.
+        AgentReputationV1::create_account(
+            ctx.accounts.agent_reputation,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            ctx.accounts.asset.key,
+            agent_reputation_bump,
+        )?;
+
+        // Initialize the account using zero-copy.
+        // Borrow the account data mutably and cast to our struct.
+        let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
+        let agent_reputation: &mut AgentReputationV1 =
+            bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV1>()]);
+
+        agent_reputation.initialize(agent_reputation_bump, ctx.accounts.asset.key);
+    }
+
     // If the asset already has a AppData plugin, move on, otherwise create it.
     if result.is_err() {
         AddExternalPluginAdapterV1Cpi {
@@ -162,5 +182,29 @@ pub fn register_reputation_v1<'a>(
         .invoke()?;
     }
 
+    // Publish the aggregates of feedback submitted before the plugin existed.
+    if agent_reputation_registered
+        && ctx.accounts.agent_reputation.try_borrow_data()?[0] == Key::AgentReputationV2 as u8
+    {
+        let summary = {
+            let data = ctx.accounts.agent_reputation.try_borrow_data()?;
+            ReputationSummaryV1::from(bytemuck::from_bytes::<AgentReputationV2>(
+                &data[..core::mem::size_of::<AgentReputationV2>()],
+            ))
+        };
+        write_summary(
+            &SummaryAccounts {
+                mpl_core_program: ctx.accounts.mpl_core_program,
+                asset: ctx.accounts.asset,
+                collection: ctx.accounts.collection,
+                payer: ctx.accounts.payer,
+                agent_reputation: ctx.accounts.agent_reputation,
+                system_program: ctx.accounts.system_program,
+            },
+            &summary,
+            agent_reputation_bump,
+        )?;
+    }
+
     Ok(())
 }
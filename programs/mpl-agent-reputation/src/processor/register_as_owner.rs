@@ -0,0 +1,111 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::accounts::{AssetSigner, BaseAssetV1};
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use crate::{
+    error::MplAgentReputationError, instruction::accounts::RegisterReputationAsOwnerV1Accounts,
+    state::AgentReputationV1,
+};
+
+impl<'a> RegisterReputationAsOwnerV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            agent_reputation,
+            asset,
+            payer,
+            authority,
+            system_program,
+        } = self;
+
+        // Agent Reputation
+        let agent_reputation_bump =
+            AgentReputationV1::check_pda_derivation(agent_reputation, asset.key)?;
+
+        if agent_reputation.data_len() != 0 || *agent_reputation.owner != system_program::id() {
+            return Err(MplAgentReputationError::AgentReputationAlreadyRegistered.into());
+        }
+
+        // Asset
+        // Assert that the asset exists and is a Core asset.
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidCoreAsset.into());
+        }
+
+        // Payer
+        assert_signer(payer)?;
+
+        // Authority
+        if let Some(authority) = authority {
+            assert_signer(authority)?;
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentReputationError::InvalidSystemProgram.into());
+        }
+
+        Ok(agent_reputation_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RegisterReputationAsOwnerV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RegisterReputationAsOwnerV1Args>() == 8);
+
+pub fn register_reputation_as_owner_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &RegisterReputationAsOwnerV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RegisterReputationAsOwnerV1Accounts::context(accounts)?;
+    let agent_reputation_bump = ctx.accounts.validate()?;
+
+    // The authority must be the asset owner or the asset signer PDA.
+    let signer = ctx.accounts.authority.unwrap_or(ctx.accounts.payer);
+    let asset = BaseAssetV1::try_from(ctx.accounts.asset)?;
+    let (asset_signer_pda, _) = AssetSigner::find_pda(ctx.accounts.asset.key);
+
+    if asset.owner != *signer.key && asset_signer_pda != *signer.key {
+        return Err(MplAgentReputationError::OnlyAssetOwnerOrSignerCanRegisterReputation.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Create the agent reputation account. No AppData plugin is added to the
+    // asset: only its update authority may add one, with RegisterReputationV1.
+    AgentReputationV1::create_account(
+        ctx.accounts.agent_reputation,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset.key,
+        agent_reputation_bump,
+    )?;
+
+    let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
+    let agent_reputation: &mut AgentReputationV1 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV1>()]);
+
+    agent_reputation.initialize(agent_reputation_bump, ctx.accounts.asset.key);
+
+    Ok(())
+}
@@ -0,0 +1,178 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use super::summary::{write_summary, SummaryAccounts};
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::RevokeFeedbackV1Accounts,
+    state::{
+        AgentReputationV2, FeedbackStatus, FeedbackV1, Key, ReputationConfigV1, ReputationSummaryV1,
+    },
+};
+
+impl<'a> RevokeFeedbackV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            agent_reputation,
+            asset,
+            collection: _,
+            feedback,
+            reputation_config: _,
+            reviewer,
+            payer,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Agent Reputation
+        // Feedback was recorded, so the account was upgraded to V2.
+        if agent_reputation.owner != &crate::ID
+            || agent_reputation.data_len() < core::mem::size_of::<AgentReputationV2>()
+            || agent_reputation.try_borrow_data()?[0] != Key::AgentReputationV2 as u8
+        {
+            return Err(MplAgentReputationError::InvalidAccountData.into());
+        }
+
+        let agent_reputation_bump =
+            AgentReputationV2::check_pda_derivation(agent_reputation, asset.key)?;
+
+        // Asset
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidCoreAsset.into());
+        }
+
+        // Collection
+        // SAFE: Checked by the Core program.
+
+        // Feedback
+        if feedback.owner != &crate::ID
+            || feedback.data_len() != core::mem::size_of::<FeedbackV1>()
+            || feedback.try_borrow_data()?[0] != Key::FeedbackV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidFeedback.into());
+        }
+
+        // Reputation Config
+        // SAFE: Checked when reading the half-life.
+
+        // Reviewer
+        assert_signer(reviewer)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentReputationError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentReputationError::InvalidSystemProgram.into());
+        }
+
+        Ok(agent_reputation_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RevokeFeedbackV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RevokeFeedbackV1Args>() == 8);
+
+pub fn revoke_feedback_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &RevokeFeedbackV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RevokeFeedbackV1Accounts::context(accounts)?;
+    let agent_reputation_bump = ctx.accounts.validate()?;
+
+    let half_life = ReputationConfigV1::half_life_slots(ctx.accounts.reputation_config)?;
+
+    let mut data = ctx.accounts.feedback.try_borrow_mut_data()?;
+    let feedback: &mut FeedbackV1 = bytemuck::from_bytes_mut(&mut data);
+
+    // The feedback must be about the asset whose aggregates are adjusted.
+    if feedback.asset != *ctx.accounts.asset.key {
+        return Err(MplAgentReputationError::InvalidFeedback.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if feedback.reviewer != *ctx.accounts.reviewer.key {
+        return Err(MplAgentReputationError::InvalidReviewer.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // The record is kept, so the feedback index and the work receipt stay
+    // used.
+    let disputed = feedback.status() == FeedbackStatus::Disputed;
+    feedback.revoke()?;
+    let feedback = *feedback;
+    drop(data);
+
+    let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
+    let agent_reputation: &mut AgentReputationV2 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV2>()]);
+
+    agent_reputation.remove_feedback(
+        feedback.score,
+        feedback.weight,
+        feedback.slot,
+        Clock::get()?.slot,
+        half_life,
+    )?;
+    if disputed {
+        agent_reputation.remove_dispute(feedback.score, feedback.weight)?;
+    }
+    let summary = ReputationSummaryV1::from(&*agent_reputation);
+
+    // Drop the agent reputation account data before the CPI.
+    drop(data);
+
+    // Write the summary to the AppData plugin.
+    write_summary(
+        &SummaryAccounts {
+            mpl_core_program: ctx.accounts.mpl_core_program,
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            payer: ctx.accounts.payer,
+            agent_reputation: ctx.accounts.agent_reputation,
+            system_program: ctx.accounts.system_program,
+        },
+        &summary,
+        agent_reputation_bump,
+    )?;
+
+    MplAgentReputationEvent::FeedbackRevokedV1 {
+        asset: feedback.asset,
+        reviewer: feedback.reviewer,
+        feedback_index: feedback.index,
+        score: feedback.score,
+    }
+    .emit()
+}
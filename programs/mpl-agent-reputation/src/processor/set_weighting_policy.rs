@@ -0,0 +1,160 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::SetWeightingPolicyV1Accounts,
+    state::{Key, ReputationConfigV1, WeightingPolicyV1},
+};
+
+impl<'a> SetWeightingPolicyV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            weighting_policy,
+            reputation_config,
+            authority,
+            payer,
+            system_program,
+        } = self;
+
+        // Weighting Policy
+        // Created the first time the policy is set.
+        let weighting_policy_bump = WeightingPolicyV1::check_pda_derivation(weighting_policy)?;
+        if !weighting_policy.data_is_empty()
+            && (weighting_policy.owner != &crate::ID
+                || weighting_policy.try_borrow_data()?[0] != Key::WeightingPolicyV1 as u8)
+        {
+            return Err(MplAgentReputationError::InvalidWeightingPolicy.into());
+        }
+
+        // Reputation Config
+        ReputationConfigV1::check_pda_derivation(reputation_config)?;
+        ReputationConfigV1::check_initialized(reputation_config)?;
+
+        // Authority
+        // Only the config authority may set the policy.
+        assert_signer(authority)?;
+        let data = reputation_config.try_borrow_data()?;
+        if bytemuck::from_bytes::<ReputationConfigV1>(&data).authority != *authority.key {
+            return Err(MplAgentReputationError::InvalidConfigAuthority.into());
+        }
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentReputationError::InvalidSystemProgram.into());
+        }
+
+        Ok(weighting_policy_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct SetWeightingPolicyV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Weight of every feedback. Must be non-zero.
+    pub base_weight: u32,
+    /// Weight added per work receipt the reviewer proved with the agent.
+    pub receipt_weight: u32,
+    /// Maximum number of receipts counted.
+    pub max_receipts: u32,
+    /// Weight added if the reviewer owns an asset with an agent identity and
+    /// enough feedback.
+    pub identity_weight: u32,
+    /// Weight added if the reviewer locked enough of `token_mint`.
+    pub token_weight: u32,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding_1: [u8; 4],
+    /// Mint of the SPL token whose balance adds weight, or the default
+    /// pubkey to disable token weighting.
+    pub token_mint: Pubkey,
+    /// Balance of `token_mint` the reviewer must lock for the token weight.
+    pub min_token_balance: u64,
+    /// Undisputed feedback entries the reviewer's asset must have received
+    /// for the identity weight. Must be non-zero if the identity weight is.
+    pub min_identity_feedback: u64,
+    /// Slots the locked tokens stay locked after adding weight to feedback.
+    /// Must be non-zero if the token weight is.
+    pub token_lock_slots: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<SetWeightingPolicyV1Args>() == 88);
+
+pub fn set_weighting_policy_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &SetWeightingPolicyV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = SetWeightingPolicyV1Accounts::context(accounts)?;
+    let weighting_policy_bump = ctx.accounts.validate()?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    let policy = WeightingPolicyV1 {
+        key: Key::WeightingPolicyV1 as u8,
+        bump: weighting_policy_bump,
+        _padding: [0u8; 6],
+        base_weight: args.base_weight,
+        receipt_weight: args.receipt_weight,
+        max_receipts: args.max_receipts,
+        identity_weight: args.identity_weight,
+        token_weight: args.token_weight,
+        _padding_1: [0u8; 4],
+        token_mint: args.token_mint,
+        min_token_balance: args.min_token_balance,
+        min_identity_feedback: args.min_identity_feedback,
+        token_lock_slots: args.token_lock_slots,
+        _reserved: [0u8; 24],
+    };
+    policy.check()?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Feedback already submitted keeps the weight it was given.
+    if ctx.accounts.weighting_policy.data_is_empty() {
+        WeightingPolicyV1::create_account(
+            ctx.accounts.weighting_policy,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            weighting_policy_bump,
+        )?;
+    }
+
+    let mut data = ctx.accounts.weighting_policy.try_borrow_mut_data()?;
+    *bytemuck::from_bytes_mut::<WeightingPolicyV1>(&mut data) = policy;
+
+    MplAgentReputationEvent::WeightingPolicyUpdatedV1 {
+        base_weight: policy.base_weight,
+        receipt_weight: policy.receipt_weight,
+        max_receipts: policy.max_receipts,
+        identity_weight: policy.identity_weight,
+        token_weight: policy.token_weight,
+        token_mint: policy.token_mint,
+        min_token_balance: policy.min_token_balance,
+        min_identity_feedback: policy.min_identity_feedback,
+        token_lock_slots: policy.token_lock_slots,
+    }
+    .emit()
+}
@@ -0,0 +1,144 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::SnapshotReputationV1Accounts,
+    state::{AgentReputationV2, Key, ReputationConfigV1, ReputationSnapshotV1},
+};
+
+impl<'a> SnapshotReputationV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            reputation_snapshot,
+            agent_reputation,
+            asset,
+            reputation_config: _,
+            payer,
+            system_program,
+        } = self;
+
+        // Reputation Snapshot
+        // One snapshot per epoch.
+        if !reputation_snapshot.data_is_empty() {
+            return Err(MplAgentReputationError::ReputationAlreadySnapshotted.into());
+        }
+
+        // Agent Reputation
+        if agent_reputation.owner != &crate::ID {
+            return Err(MplAgentReputationError::InvalidAccountData.into());
+        }
+        let key = agent_reputation.try_borrow_data()?[0];
+        if key != Key::AgentReputationV1 as u8 && key != Key::AgentReputationV2 as u8 {
+            return Err(MplAgentReputationError::InvalidAccountData.into());
+        }
+        AgentReputationV2::check_pda_derivation(agent_reputation, asset.key)?;
+
+        // Asset
+        // SAFE: Bound to the agent reputation by its PDA derivation.
+
+        // Reputation Config
+        // SAFE: Checked when reading the half-life.
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentReputationError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct SnapshotReputationV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<SnapshotReputationV1Args>() == 8);
+
+/// Permissionless crank copying the aggregates of an agent, decayed up to
+/// the current slot, into the snapshot PDA of the current epoch.
+pub fn snapshot_reputation_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &SnapshotReputationV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = SnapshotReputationV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let clock = Clock::get()?;
+    let reputation_snapshot_bump = ReputationSnapshotV1::check_pda_derivation(
+        ctx.accounts.reputation_snapshot,
+        ctx.accounts.asset.key,
+        clock.epoch,
+    )?;
+
+    let half_life = ReputationConfigV1::half_life_slots(ctx.accounts.reputation_config)?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Upgrade the account in place so it holds the aggregates.
+    if ctx.accounts.agent_reputation.try_borrow_data()?[0] == Key::AgentReputationV1 as u8 {
+        AgentReputationV2::migrate_from_v1(
+            ctx.accounts.agent_reputation,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+
+    let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
+    let agent_reputation: &mut AgentReputationV2 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV2>()]);
+    agent_reputation.apply_decay(clock.slot, half_life);
+    let agent_reputation = *agent_reputation;
+    drop(data);
+
+    ReputationSnapshotV1::create_account(
+        ctx.accounts.reputation_snapshot,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset.key,
+        clock.epoch,
+        reputation_snapshot_bump,
+    )?;
+
+    let mut data = ctx.accounts.reputation_snapshot.try_borrow_mut_data()?;
+    let reputation_snapshot: &mut ReputationSnapshotV1 = bytemuck::from_bytes_mut(&mut data);
+    reputation_snapshot.initialize(
+        reputation_snapshot_bump,
+        clock.epoch,
+        clock.slot,
+        &agent_reputation,
+    );
+
+    MplAgentReputationEvent::ReputationSnapshottedV1 {
+        asset: agent_reputation.asset,
+        epoch: clock.epoch,
+        slot: clock.slot,
+        feedback_count: agent_reputation.feedback_count,
+        score_sum: agent_reputation.score_sum,
+        decayed_score: agent_reputation.decayed_score,
+        decayed_count: agent_reputation.decayed_count,
+    }
+    .emit()
+}
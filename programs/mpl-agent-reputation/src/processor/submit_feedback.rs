@@ -0,0 +1,461 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult, pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use super::receipt::{verify_receipt, MPL_ACCOUNT_COMPRESSION_ID};
+use super::summary::{write_summary, SummaryAccounts};
+use super::uri::read_feedback_uri;
+use super::weight::{holds_tokens, is_established_reviewer};
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::SubmitFeedbackV1Accounts,
+    state::{
+        AgentReputationV2, FeedbackV1, Key, ReceiptNullifierV1, ReputationConfigV1,
+        ReputationSummaryV1, ReviewerHistoryV1, TokenLockV1, WeightingPolicyV1,
+    },
+};
+use mpl_agent_tools::receipts::ReceiptProof;
+
+/// Highest score a feedback may carry.
+pub const MAX_FEEDBACK_SCORE: u8 = 100;
+
+impl<'a> SubmitFeedbackV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            agent_reputation,
+            asset,
+            collection: _,
+            reviewer,
+            receipt_nullifier,
+            feedback,
+            reputation_config: _,
+            weighting_policy: _,
+            reviewer_history,
+            reviewer_asset: _,
+            reviewer_identity: _,
+            reviewer_reputation: _,
+            reviewer_token_lock: _,
+            reviewer_token_vault: _,
+            merkle_tree: _,
+            payer,
+            compression_program,
+            mpl_core_program,
+            system_program,
+        } = self;
+
+        // Agent Reputation
+        if agent_reputation.owner != &crate::ID || agent_reputation.data_is_empty() {
+            return Err(MplAgentReputationError::AgentReputationNotRegistered.into());
+        }
+
+        let key = agent_reputation.try_borrow_data()?[0];
+        if key != Key::AgentReputationV1 as u8 && key != Key::AgentReputationV2 as u8 {
+            return Err(MplAgentReputationError::InvalidAccountData.into());
+        }
+
+        let agent_reputation_bump =
+            AgentReputationV2::check_pda_derivation(agent_reputation, asset.key)?;
+
+        // Asset
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidCoreAsset.into());
+        }
+
+        // Collection
+        // SAFE: Checked by the Core program.
+
+        // Reviewer
+        assert_signer(reviewer)?;
+
+        // Receipt Nullifier
+        // An existing nullifier means the receipt already backs a review.
+        if !receipt_nullifier.data_is_empty() || *receipt_nullifier.owner != system_program::id() {
+            return Err(MplAgentReputationError::ReceiptAlreadyReviewed.into());
+        }
+
+        // Feedback
+        if !feedback.data_is_empty() || *feedback.owner != system_program::id() {
+            return Err(MplAgentReputationError::FeedbackAlreadyExists.into());
+        }
+
+        // Reputation Config
+        // SAFE: Checked when reading the half-life.
+
+        // Weighting Policy
+        // SAFE: Checked when reading the policy.
+
+        // Reviewer History
+        // Created on the reviewer's first feedback for the asset.
+        if !reviewer_history.data_is_empty()
+            && (reviewer_history.owner != &crate::ID
+                || reviewer_history.try_borrow_data()?[0] != Key::ReviewerHistoryV1 as u8)
+        {
+            return Err(MplAgentReputationError::InvalidReviewerHistory.into());
+        }
+
+        // Reviewer Asset, Reviewer Identity, Reviewer Reputation, Reviewer
+        // Token Lock and Reviewer Token Vault
+        // SAFE: Checked when weighting the feedback.
+
+        // Merkle Tree
+        // SAFE: Checked by the receipt verification.
+
+        // Payer
+        assert_signer(payer)?;
+
+        // MPL Account Compression Program
+        if *compression_program.key != MPL_ACCOUNT_COMPRESSION_ID {
+            return Err(MplAgentReputationError::InvalidCompressionProgram.into());
+        }
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentReputationError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentReputationError::InvalidSystemProgram.into());
+        }
+
+        Ok(agent_reputation_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct SubmitFeedbackV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// The feedback score, from 0 to 100.
+    pub score: u8,
+    /// Flags of the work receipt leaf.
+    pub flags: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 1],
+    /// Index of the work receipt leaf within its tree.
+    pub index: u32,
+    /// Index of the receipts tree the work receipt lives in.
+    pub tree_index: u64,
+    /// Nonce of the work receipt leaf within its tree.
+    pub nonce: u64,
+    /// Index of the feedback among the reviewer's feedback for the asset.
+    pub feedback_index: u64,
+    /// Current root of the receipts merkle tree.
+    pub root: [u8; 32],
+    /// Hash of the work receipt's metadata.
+    pub data_hash: [u8; 32],
+    /// Hash of the work receipt's `asset_data` blob.
+    pub asset_data_hash: [u8; 32],
+    /// First free-form tag categorizing the feedback.
+    pub tag1: [u8; 32],
+    /// Second free-form tag categorizing the feedback.
+    pub tag2: [u8; 32],
+    /// The URI of the off-chain feedback file.
+    /// We parse this manually from a string representation in the IDL.
+    #[idl_type("String")]
+    feedback_uri: [u8; 0],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<SubmitFeedbackV1Args>() == 192);
+
+pub fn submit_feedback_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args_data = instruction_data
+        .get(..core::mem::size_of::<SubmitFeedbackV1Args>())
+        .ok_or(MplAgentReputationError::InvalidInstructionData)?;
+    let args: SubmitFeedbackV1Args = bytemuck::pod_read_unaligned(args_data);
+
+    let feedback_uri =
+        read_feedback_uri(&instruction_data[core::mem::size_of::<SubmitFeedbackV1Args>()..])?;
+
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = SubmitFeedbackV1Accounts::context(accounts)?;
+    let agent_reputation_bump = ctx.accounts.validate()?;
+
+    let receipt_nullifier_bump = ReceiptNullifierV1::check_pda_derivation(
+        ctx.accounts.receipt_nullifier,
+        args.tree_index,
+        args.nonce,
+    )?;
+
+    let feedback_bump = FeedbackV1::check_pda_derivation(
+        ctx.accounts.feedback,
+        ctx.accounts.asset.key,
+        ctx.accounts.reviewer.key,
+        args.feedback_index,
+    )?;
+
+    let reviewer_history_bump = ReviewerHistoryV1::check_pda_derivation(
+        ctx.accounts.reviewer_history,
+        ctx.accounts.asset.key,
+        ctx.accounts.reviewer.key,
+    )?;
+
+    let half_life = ReputationConfigV1::half_life_slots(ctx.accounts.reputation_config)?;
+    let weighting_policy = WeightingPolicyV1::load(ctx.accounts.weighting_policy)?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.score > MAX_FEEDBACK_SCORE {
+        return Err(MplAgentReputationError::InvalidFeedbackScore.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // The reviewer must hold a work receipt issued by the agent.
+    verify_receipt(
+        ctx.accounts.merkle_tree,
+        ctx.accounts.compression_program,
+        ctx.remaining_accounts,
+        ctx.accounts.asset.key,
+        ctx.accounts.reviewer.key,
+        args.tree_index,
+        &ReceiptProof {
+            nonce: args.nonce,
+            index: args.index,
+            root: args.root,
+            data_hash: args.data_hash,
+            asset_data_hash: args.asset_data_hash,
+            flags: args.flags,
+        },
+    )?;
+
+    // Nullify the receipt so it cannot back another review.
+    ReceiptNullifierV1::create_account(
+        ctx.accounts.receipt_nullifier,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        args.tree_index,
+        args.nonce,
+        receipt_nullifier_bump,
+    )?;
+
+    let mut data = ctx.accounts.receipt_nullifier.try_borrow_mut_data()?;
+    let receipt_nullifier: &mut ReceiptNullifierV1 = bytemuck::from_bytes_mut(&mut data);
+    receipt_nullifier.initialize(
+        receipt_nullifier_bump,
+        args.tree_index,
+        args.nonce,
+        ctx.accounts.asset.key,
+        ctx.accounts.reviewer.key,
+    );
+    drop(data);
+
+    // Count the receipt in the reviewer's history with the agent.
+    if ctx.accounts.reviewer_history.data_is_empty() {
+        ReviewerHistoryV1::create_account(
+            ctx.accounts.reviewer_history,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+            ctx.accounts.asset.key,
+            ctx.accounts.reviewer.key,
+            reviewer_history_bump,
+        )?;
+
+        let mut data = ctx.accounts.reviewer_history.try_borrow_mut_data()?;
+        let reviewer_history: &mut ReviewerHistoryV1 = bytemuck::from_bytes_mut(&mut data);
+        reviewer_history.initialize(
+            reviewer_history_bump,
+            ctx.accounts.asset.key,
+            ctx.accounts.reviewer.key,
+        );
+    }
+
+    let mut data = ctx.accounts.reviewer_history.try_borrow_mut_data()?;
+    let reviewer_history: &mut ReviewerHistoryV1 = bytemuck::from_bytes_mut(&mut data);
+    reviewer_history.receipt_count = reviewer_history
+        .receipt_count
+        .checked_add(1)
+        .ok_or(MplAgentReputationError::NumericalOverflow)?;
+    let receipt_count = reviewer_history.receipt_count;
+    drop(data);
+
+    let slot = Clock::get()?.slot;
+
+    // Weight the feedback by what the reviewer proves about themselves.
+    let has_identity = is_established_reviewer(
+        ctx.accounts.reviewer.key,
+        ctx.accounts.asset.key,
+        weighting_policy.min_identity_feedback,
+        ctx.accounts.reviewer_asset,
+        ctx.accounts.reviewer_identity,
+        ctx.accounts.reviewer_reputation,
+    )?;
+    let holds_tokens = match (
+        ctx.accounts.reviewer_token_lock,
+        ctx.accounts.reviewer_token_vault,
+    ) {
+        (None, None) => false,
+        (Some(token_lock), Some(token_vault)) => {
+            weighting_policy.weighs_tokens()
+                && lock_tokens(
+                    token_lock,
+                    token_vault,
+                    ctx.accounts.reviewer.key,
+                    &weighting_policy,
+                    ctx.accounts.payer,
+                    ctx.accounts.system_program,
+                    slot,
+                )?
+        }
+        _ => return Err(MplAgentReputationError::InvalidTokenLock.into()),
+    };
+    let weight = weighting_policy.weight(receipt_count, has_identity, holds_tokens);
+
+    // Record the feedback so the agent can respond to it.
+    FeedbackV1::create_account(
+        ctx.accounts.feedback,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset.key,
+        ctx.accounts.reviewer.key,
+        args.feedback_index,
+        feedback_bump,
+    )?;
+
+    let mut data = ctx.accounts.feedback.try_borrow_mut_data()?;
+    let feedback: &mut FeedbackV1 = bytemuck::from_bytes_mut(&mut data);
+    feedback.initialize(
+        feedback_bump,
+        args.score,
+        weight,
+        ctx.accounts.asset.key,
+        ctx.accounts.reviewer.key,
+        args.feedback_index,
+        args.tree_index,
+        args.nonce,
+        slot,
+    );
+    drop(data);
+
+    // Upgrade the account in place so it can hold the aggregates.
+    if ctx.accounts.agent_reputation.try_borrow_data()?[0] == Key::AgentReputationV1 as u8 {
+        AgentReputationV2::migrate_from_v1(
+            ctx.accounts.agent_reputation,
+            ctx.accounts.payer,
+            ctx.accounts.system_program,
+        )?;
+    }
+
+    let mut data = ctx.accounts.agent_reputation.try_borrow_mut_data()?;
+    let agent_reputation: &mut AgentReputationV2 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentReputationV2>()]);
+
+    agent_reputation.record_feedback(args.score, weight, slot, half_life)?;
+    let summary = ReputationSummaryV1::from(&*agent_reputation);
+
+    // Drop the agent reputation account data before the CPI.
+    drop(data);
+
+    // Write the summary to the AppData plugin.
+    write_summary(
+        &SummaryAccounts {
+            mpl_core_program: ctx.accounts.mpl_core_program,
+            asset: ctx.accounts.asset,
+            collection: ctx.accounts.collection,
+            payer: ctx.accounts.payer,
+            agent_reputation: ctx.accounts.agent_reputation,
+            system_program: ctx.accounts.system_program,
+        },
+        &summary,
+        agent_reputation_bump,
+    )?;
+
+    MplAgentReputationEvent::FeedbackSubmittedV1 {
+        asset: *ctx.accounts.asset.key,
+        reviewer: *ctx.accounts.reviewer.key,
+        score: args.score,
+        tag1: args.tag1,
+        tag2: args.tag2,
+        feedback_uri,
+    }
+    .emit()?;
+
+    MplAgentReputationEvent::FeedbackWeightedV1 {
+        asset: *ctx.accounts.asset.key,
+        reviewer: *ctx.accounts.reviewer.key,
+        feedback_index: args.feedback_index,
+        weight,
+    }
+    .emit()
+}
+
+/// Whether the reviewer locked enough of the weighting policy token in
+/// `token_vault`, a token account of their token lock. If so, the tokens stay
+/// locked for the policy's lock period from `slot`, the token lock being
+/// created on first use.
+fn lock_tokens<'a>(
+    token_lock: &AccountInfo<'a>,
+    token_vault: &AccountInfo<'a>,
+    reviewer: &Pubkey,
+    weighting_policy: &WeightingPolicyV1,
+    payer: &AccountInfo<'a>,
+    system_program: &AccountInfo<'a>,
+    slot: u64,
+) -> Result<bool, ProgramError> {
+    let mint = &weighting_policy.token_mint;
+    let token_lock_bump = TokenLockV1::check_pda_derivation(token_lock, reviewer, mint)?;
+    if !token_lock.data_is_empty()
+        && (token_lock.owner != &crate::ID
+            || token_lock.data_len() != core::mem::size_of::<TokenLockV1>()
+            || token_lock.try_borrow_data()?[0] != Key::TokenLockV1 as u8)
+    {
+        return Err(MplAgentReputationError::InvalidTokenLock.into());
+    }
+
+    if !holds_tokens(
+        token_lock.key,
+        mint,
+        weighting_policy.min_token_balance,
+        token_vault,
+    )? {
+        return Ok(false);
+    }
+
+    if token_lock.data_is_empty() {
+        TokenLockV1::create_account(
+            token_lock,
+            payer,
+            system_program,
+            reviewer,
+            mint,
+            token_lock_bump,
+        )?;
+
+        let mut data = token_lock.try_borrow_mut_data()?;
+        bytemuck::from_bytes_mut::<TokenLockV1>(&mut data).initialize(
+            token_lock_bump,
+            reviewer,
+            mint,
+        );
+    }
+
+    let mut data = token_lock.try_borrow_mut_data()?;
+    let token_lock: &mut TokenLockV1 = bytemuck::from_bytes_mut(&mut data);
+    let unlock_slot = slot
+        .checked_add(weighting_policy.token_lock_slots)
+        .ok_or(MplAgentReputationError::NumericalOverflow)?;
+    token_lock.unlock_slot = token_lock.unlock_slot.max(unlock_slot);
+
+    Ok(true)
+}
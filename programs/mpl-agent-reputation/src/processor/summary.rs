@@ -0,0 +1,58 @@
+//! Publishing of the reputation summary to the asset's AppData plugin.
+
+use mpl_core::accounts::BaseAssetV1;
+use mpl_core::fetch_wrapped_external_plugin_adapter;
+use mpl_core::instructions::{
+    WriteExternalPluginAdapterDataV1Cpi, WriteExternalPluginAdapterDataV1InstructionArgs,
+};
+use mpl_core::types::{ExternalPluginAdapterKey, PluginAuthority};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::state::{AgentReputationV2, ReputationSummaryV1};
+
+/// Accounts involved in writing the summary to the AppData plugin.
+pub struct SummaryAccounts<'a, 'b> {
+    pub mpl_core_program: &'b AccountInfo<'a>,
+    pub asset: &'b AccountInfo<'a>,
+    pub collection: Option<&'b AccountInfo<'a>>,
+    pub payer: &'b AccountInfo<'a>,
+    pub agent_reputation: &'b AccountInfo<'a>,
+    pub system_program: &'b AccountInfo<'a>,
+}
+
+/// Write `summary` to the AppData plugin of the asset, of which the agent
+/// reputation PDA is the data authority. Does nothing if the asset has no
+/// such plugin, as when the asset owner registered the reputation. The agent
+/// reputation account data must not be borrowed.
+pub fn write_summary(
+    accounts: &SummaryAccounts,
+    summary: &ReputationSummaryV1,
+    agent_reputation_bump: u8,
+) -> ProgramResult {
+    let key = ExternalPluginAdapterKey::AppData(PluginAuthority::Address {
+        address: *accounts.agent_reputation.key,
+    });
+    if fetch_wrapped_external_plugin_adapter::<BaseAssetV1>(accounts.asset, None, &key).is_err() {
+        return Ok(());
+    }
+
+    WriteExternalPluginAdapterDataV1Cpi {
+        __program: accounts.mpl_core_program,
+        asset: accounts.asset,
+        collection: accounts.collection,
+        payer: accounts.payer,
+        authority: Some(accounts.agent_reputation),
+        buffer: None,
+        system_program: accounts.system_program,
+        log_wrapper: None,
+        __args: WriteExternalPluginAdapterDataV1InstructionArgs {
+            key,
+            data: Some(bytemuck::bytes_of(summary).to_vec()),
+        },
+    }
+    .invoke_signed(&[&[
+        AgentReputationV2::PREFIX,
+        accounts.asset.key.as_ref(),
+        &[agent_reputation_bump],
+    ]])
+}
@@ -0,0 +1,156 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::program::invoke_signed;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+
+use super::weight::{SPL_TOKEN_2022_PROGRAM_ID, SPL_TOKEN_PROGRAM_ID};
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError,
+    instruction::accounts::UnlockTokensV1Accounts,
+    state::{Key, TokenLockV1},
+};
+
+/// Discriminator of the token program's `TransferChecked` instruction.
+const TRANSFER_CHECKED_DISCRIMINATOR: u8 = 12;
+
+/// Byte offset of `decimals` in the mint layout.
+const MINT_DECIMALS_OFFSET: usize = 44;
+
+impl<'a> UnlockTokensV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            token_lock,
+            token_vault: _,
+            destination: _,
+            mint,
+            reviewer,
+            token_program,
+        } = self;
+
+        // Token Lock
+        if token_lock.owner != &crate::ID
+            || token_lock.data_len() != core::mem::size_of::<TokenLockV1>()
+            || token_lock.try_borrow_data()?[0] != Key::TokenLockV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidTokenLock.into());
+        }
+
+        // Token Vault and Destination
+        // SAFE: Checked by the token program.
+
+        // Mint
+        if mint.owner != token_program.key {
+            return Err(MplAgentReputationError::InvalidTokenLock.into());
+        }
+
+        // Reviewer
+        assert_signer(reviewer)?;
+
+        // Token Program
+        if *token_program.key != SPL_TOKEN_PROGRAM_ID
+            && *token_program.key != SPL_TOKEN_2022_PROGRAM_ID
+        {
+            return Err(MplAgentReputationError::InvalidTokenProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct UnlockTokensV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Amount of tokens to withdraw.
+    pub amount: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<UnlockTokensV1Args>() == 16);
+
+pub fn unlock_tokens_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &UnlockTokensV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = UnlockTokensV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let token_lock: TokenLockV1 =
+        *bytemuck::from_bytes(&ctx.accounts.token_lock.try_borrow_data()?);
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if token_lock.reviewer != *ctx.accounts.reviewer.key {
+        return Err(MplAgentReputationError::InvalidReviewer.into());
+    }
+
+    if token_lock.mint != *ctx.accounts.mint.key {
+        return Err(MplAgentReputationError::InvalidTokenLock.into());
+    }
+
+    if Clock::get()?.slot < token_lock.unlock_slot {
+        return Err(MplAgentReputationError::TokensLocked.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let decimals = *ctx
+        .accounts
+        .mint
+        .try_borrow_data()?
+        .get(MINT_DECIMALS_OFFSET)
+        .ok_or(MplAgentReputationError::InvalidTokenLock)?;
+
+    let mut data = vec![TRANSFER_CHECKED_DISCRIMINATOR];
+    data.extend_from_slice(&args.amount.to_le_bytes());
+    data.push(decimals);
+
+    // The token lock PDA is the owner of the vault.
+    invoke_signed(
+        &Instruction {
+            program_id: *ctx.accounts.token_program.key,
+            accounts: vec![
+                AccountMeta::new(*ctx.accounts.token_vault.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.mint.key, false),
+                AccountMeta::new(*ctx.accounts.destination.key, false),
+                AccountMeta::new_readonly(*ctx.accounts.token_lock.key, true),
+            ],
+            data,
+        },
+        &[
+            ctx.accounts.token_vault.clone(),
+            ctx.accounts.mint.clone(),
+            ctx.accounts.destination.clone(),
+            ctx.accounts.token_lock.clone(),
+        ],
+        &[&[
+            TokenLockV1::PREFIX,
+            token_lock.reviewer.as_ref(),
+            token_lock.mint.as_ref(),
+            &[token_lock.bump],
+        ]],
+    )?;
+
+    MplAgentReputationEvent::TokensUnlockedV1 {
+        reviewer: token_lock.reviewer,
+        mint: token_lock.mint,
+        amount: args.amount,
+    }
+    .emit()
+}
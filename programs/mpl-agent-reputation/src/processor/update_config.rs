@@ -0,0 +1,93 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::events::MplAgentReputationEvent;
+use crate::{
+    error::MplAgentReputationError, instruction::accounts::UpdateReputationConfigV1Accounts,
+    state::ReputationConfigV1,
+};
+
+impl<'a> UpdateReputationConfigV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            reputation_config,
+            authority,
+            new_authority: _,
+        } = self;
+
+        // Reputation Config
+        ReputationConfigV1::check_pda_derivation(reputation_config)?;
+        ReputationConfigV1::check_initialized(reputation_config)?;
+
+        // Authority
+        assert_signer(authority)?;
+
+        // New Authority
+        // SAFE: Any address may be handed the config.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct UpdateReputationConfigV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Number of slots after which a feedback counts half as much in the
+    /// decayed score.
+    pub half_life_slots: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<UpdateReputationConfigV1Args>() == 16);
+
+pub fn update_reputation_config_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &UpdateReputationConfigV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = UpdateReputationConfigV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let mut data = ctx.accounts.reputation_config.try_borrow_mut_data()?;
+    let reputation_config: &mut ReputationConfigV1 = bytemuck::from_bytes_mut(&mut data);
+
+    if reputation_config.authority != *ctx.accounts.authority.key {
+        return Err(MplAgentReputationError::InvalidConfigAuthority.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.half_life_slots == 0 {
+        return Err(MplAgentReputationError::InvalidHalfLife.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Decayed aggregates pick up the new half-life the next time they are
+    // brought up to date.
+    reputation_config.half_life_slots = args.half_life_slots;
+    if let Some(new_authority) = ctx.accounts.new_authority {
+        reputation_config.authority = *new_authority.key;
+    }
+
+    MplAgentReputationEvent::ReputationConfigUpdatedV1 {
+        authority: reputation_config.authority,
+        half_life_slots: reputation_config.half_life_slots,
+    }
+    .emit()
+}
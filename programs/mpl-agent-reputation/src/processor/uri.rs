@@ -0,0 +1,79 @@
+//! Parsing and validation of the feedback and response URIs carried as
+//! trailing Borsh strings in reputation instructions.
+
+use solana_program::program_error::ProgramError;
+
+use crate::error::MplAgentReputationError;
+
+/// Maximum length in bytes of a feedback URI.
+pub const MAX_FEEDBACK_URI_LEN: usize = 200;
+
+/// URI schemes a feedback file may be hosted under.
+pub const ALLOWED_FEEDBACK_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+/// Maximum length in bytes of a response URI.
+pub const MAX_RESPONSE_URI_LEN: usize = MAX_FEEDBACK_URI_LEN;
+
+/// Errors reported for a URI that is empty, too long, not UTF-8 or hosted
+/// under a scheme that is not allowed, in that order.
+type UriErrors = [MplAgentReputationError; 4];
+
+/// Parse a Borsh-style `[u32 LE length][bytes...]` feedback URI from `data`
+/// and check that it uses one of the allowed schemes.
+pub fn read_feedback_uri(data: &[u8]) -> Result<String, ProgramError> {
+    read_uri(
+        data,
+        MAX_FEEDBACK_URI_LEN,
+        [
+            MplAgentReputationError::FeedbackUriEmpty,
+            MplAgentReputationError::FeedbackUriTooLong,
+            MplAgentReputationError::FeedbackUriNotUtf8,
+            MplAgentReputationError::FeedbackUriSchemeNotAllowed,
+        ],
+    )
+}
+
+/// Parse a response URI from `data`, with the same rules as feedback URIs.
+pub fn read_response_uri(data: &[u8]) -> Result<String, ProgramError> {
+    read_uri(
+        data,
+        MAX_RESPONSE_URI_LEN,
+        [
+            MplAgentReputationError::ResponseUriEmpty,
+            MplAgentReputationError::ResponseUriTooLong,
+            MplAgentReputationError::ResponseUriNotUtf8,
+            MplAgentReputationError::ResponseUriSchemeNotAllowed,
+        ],
+    )
+}
+
+fn read_uri(data: &[u8], max_len: usize, errors: UriErrors) -> Result<String, ProgramError> {
+    let [empty, too_long, not_utf8, scheme_not_allowed] = errors;
+
+    let len_bytes: [u8; 4] = data
+        .get(..4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(MplAgentReputationError::InvalidInstructionData)?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+
+    if len == 0 {
+        return Err(empty.into());
+    }
+    if len > max_len {
+        return Err(too_long.into());
+    }
+
+    let bytes = data
+        .get(4..4 + len)
+        .ok_or(MplAgentReputationError::InvalidInstructionData)?;
+    let uri = String::from_utf8(bytes.to_vec()).map_err(|_| not_utf8)?;
+
+    if !ALLOWED_FEEDBACK_URI_SCHEMES
+        .iter()
+        .any(|scheme| uri.starts_with(scheme) && uri.len() > scheme.len())
+    {
+        return Err(scheme_not_allowed.into());
+    }
+
+    Ok(uri)
+}
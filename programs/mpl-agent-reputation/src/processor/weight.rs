@@ -0,0 +1,122 @@
+//! Verification of the facts about a reviewer that weight their feedback.
+
+use mpl_agent_identity::{accounts::AgentIdentityV2, types::Key as MplAgentIdentityKey};
+use mpl_core::{accounts::BaseAssetV1, types::Key as MplCoreKey};
+use solana_program::{account_info::AccountInfo, program_error::ProgramError, pubkey::Pubkey};
+
+use crate::{
+    error::MplAgentReputationError,
+    state::{AgentReputationV2, Key},
+};
+
+/// The SPL Token program ID.
+pub(crate) const SPL_TOKEN_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenkegQfeZyiNwAJbNbGKPFXCWuBvf9Ss623VQ5DA");
+
+/// The SPL Token-2022 program ID.
+pub(crate) const SPL_TOKEN_2022_PROGRAM_ID: Pubkey =
+    solana_program::pubkey!("TokenzQdBNbLqP5VEhdkAS6EPFLC1PHnBqCXEpPxuEb");
+
+/// Size of the base SPL Token account layout.
+const TOKEN_ACCOUNT_SIZE: usize = 165;
+
+/// Byte offset of `state` (0 = uninitialized) in the token account layout.
+const TOKEN_ACCOUNT_STATE_OFFSET: usize = 108;
+
+/// Token-2022 accounts with extensions carry their account type right after
+/// the base token account layout.
+const TOKEN_2022_ACCOUNT_TYPE_OFFSET: usize = 165;
+
+/// Token-2022 `AccountType::Account` value.
+const TOKEN_2022_ACCOUNT_TYPE_ACCOUNT: u8 = 2;
+
+/// Whether `reviewer` owns an asset with an agent identity that received at
+/// least `min_feedback` undisputed feedback entries, as proven by
+/// `reviewer_asset`, its agent identity PDA and its agent reputation PDA.
+/// Registering an identity is cheap, so the feedback makes it costly. The
+/// reviewed asset does not count. The accounts must be provided together.
+pub(crate) fn is_established_reviewer(
+    reviewer: &Pubkey,
+    asset: &Pubkey,
+    min_feedback: u64,
+    reviewer_asset: Option<&AccountInfo>,
+    reviewer_identity: Option<&AccountInfo>,
+    reviewer_reputation: Option<&AccountInfo>,
+) -> Result<bool, ProgramError> {
+    let (reviewer_asset, reviewer_identity, reviewer_reputation) =
+        match (reviewer_asset, reviewer_identity, reviewer_reputation) {
+            (None, None, None) => return Ok(false),
+            (Some(reviewer_asset), Some(reviewer_identity), Some(reviewer_reputation)) => {
+                (reviewer_asset, reviewer_identity, reviewer_reputation)
+            }
+            _ => return Err(MplAgentReputationError::InvalidReviewerIdentity.into()),
+        };
+
+    if reviewer_asset.key == asset
+        || reviewer_asset.owner != &mpl_core::ID
+        || reviewer_asset.try_borrow_data()?.first() != Some(&(MplCoreKey::AssetV1 as u8))
+        || BaseAssetV1::try_from(reviewer_asset)?.owner != *reviewer
+    {
+        return Err(MplAgentReputationError::InvalidReviewerIdentity.into());
+    }
+
+    let data = reviewer_identity.try_borrow_data()?;
+    if *reviewer_identity.key != AgentIdentityV2::find_pda(reviewer_asset.key).0
+        || reviewer_identity.owner != &mpl_agent_identity::ID
+        || (data.first() != Some(&(MplAgentIdentityKey::AgentIdentityV1 as u8))
+            && data.first() != Some(&(MplAgentIdentityKey::AgentIdentityV2 as u8)))
+    {
+        return Err(MplAgentReputationError::InvalidReviewerIdentity.into());
+    }
+    drop(data);
+
+    // The agent reputation of the reviewer asset, registered or not, and
+    // upgraded to V2 on its first feedback.
+    AgentReputationV2::check_pda_derivation(reviewer_reputation, reviewer_asset.key)?;
+    let data = reviewer_reputation.try_borrow_data()?;
+    if !data.is_empty() && reviewer_reputation.owner != &crate::ID {
+        return Err(MplAgentReputationError::InvalidReviewerReputation.into());
+    }
+    let undisputed = match data.first().copied().map(Key::from) {
+        None | Some(Key::AgentReputationV1) => 0,
+        Some(Key::AgentReputationV2) if data.len() >= core::mem::size_of::<AgentReputationV2>() => {
+            let reputation: &AgentReputationV2 =
+                bytemuck::from_bytes(&data[..core::mem::size_of::<AgentReputationV2>()]);
+            reputation
+                .feedback_count
+                .saturating_sub(reputation.disputed_count)
+        }
+        _ => return Err(MplAgentReputationError::InvalidReviewerReputation.into()),
+    };
+
+    Ok(undisputed >= min_feedback)
+}
+
+/// Whether `token_vault`, an SPL Token or Token 2022 account of `owner`,
+/// holds at least `min_balance` of `mint`.
+pub(crate) fn holds_tokens(
+    owner: &Pubkey,
+    mint: &Pubkey,
+    min_balance: u64,
+    token_vault: &AccountInfo,
+) -> Result<bool, ProgramError> {
+    // Layout: [mint: 32][owner: 32][amount: 8]...[state: 1 at 108].
+    let data = token_vault.try_borrow_data()?;
+    let is_token_account_layout = data.len() == TOKEN_ACCOUNT_SIZE
+        || (token_vault.owner == &SPL_TOKEN_2022_PROGRAM_ID
+            && data.len() > TOKEN_2022_ACCOUNT_TYPE_OFFSET
+            && data[TOKEN_2022_ACCOUNT_TYPE_OFFSET] == TOKEN_2022_ACCOUNT_TYPE_ACCOUNT);
+
+    if (token_vault.owner != &SPL_TOKEN_PROGRAM_ID
+        && token_vault.owner != &SPL_TOKEN_2022_PROGRAM_ID)
+        || !is_token_account_layout
+        || data[TOKEN_ACCOUNT_STATE_OFFSET] == 0
+        || data[..32] != mint.to_bytes()
+        || data[32..64] != owner.to_bytes()
+    {
+        return Err(MplAgentReputationError::InvalidReviewerTokenAccount.into());
+    }
+
+    let amount = u64::from_le_bytes(data[64..72].try_into().unwrap());
+    Ok(amount >= min_balance)
+}
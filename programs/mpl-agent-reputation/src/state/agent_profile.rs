@@ -0,0 +1,80 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+/// Profile of an agent returned by `GetAgentProfileV1` as return data, for
+/// other programs to check in-transaction. All fields are little-endian.
+///
+/// # Layout
+/// - version: 1 byte
+/// - identity_registered: 1 byte
+/// - reputation_registered: 1 byte
+/// - has_agent_token: 1 byte
+/// - _padding: 4 bytes
+/// - asset: 32 bytes
+/// - agent_token: 32 bytes
+/// - feedback_count: 8 bytes
+/// - score_sum: 8 bytes
+/// - score_sum_squares: 8 bytes
+/// - last_feedback_slot: 8 bytes
+/// - decayed_score: 8 bytes
+/// - decayed_count: 8 bytes
+/// - slot: 8 bytes
+/// - weight_sum: 8 bytes
+/// - weighted_score_sum: 8 bytes
+/// - disputed_count: 8 bytes
+/// - disputed_score_sum: 8 bytes
+/// - disputed_weight_sum: 8 bytes
+/// - disputed_weighted_score_sum: 8 bytes
+///
+/// Total: 176 bytes
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable)]
+pub struct AgentProfileV1 {
+    /// Layout version, `AgentProfileV1::VERSION`.
+    pub version: u8,
+    /// Whether the asset has an agent identity.
+    pub identity_registered: u8,
+    /// Whether the asset has an agent reputation.
+    pub reputation_registered: u8,
+    /// Whether the agent identity links an agent token.
+    pub has_agent_token: u8,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 4],
+    /// The address of the asset.
+    pub asset: Pubkey,
+    /// The address of the agent token, or the default pubkey if none.
+    pub agent_token: Pubkey,
+    /// Number of feedback entries in the aggregates.
+    pub feedback_count: u64,
+    /// Sum of the feedback scores.
+    pub score_sum: u64,
+    /// Sum of the squared feedback scores.
+    pub score_sum_squares: u64,
+    /// Slot of the last feedback.
+    pub last_feedback_slot: u64,
+    /// Decayed score as of `slot`, as Q32.32.
+    pub decayed_score: u64,
+    /// Decayed feedback count as of `slot`, as Q32.32.
+    pub decayed_count: u64,
+    /// Slot the profile was read at.
+    pub slot: u64,
+    /// Sum of the feedback weights.
+    pub weight_sum: u64,
+    /// Sum of the feedback scores, each multiplied by its weight.
+    pub weighted_score_sum: u64,
+    /// Number of disputed feedback entries, included in the aggregates.
+    pub disputed_count: u64,
+    /// Sum of the disputed feedback scores.
+    pub disputed_score_sum: u64,
+    /// Sum of the disputed feedback weights.
+    pub disputed_weight_sum: u64,
+    /// Sum of the disputed feedback scores, each multiplied by its weight.
+    pub disputed_weighted_score_sum: u64,
+}
+
+const _: () = assert!(core::mem::size_of::<AgentProfileV1>() == 176);
+
+impl AgentProfileV1 {
+    /// Version byte of this layout.
+    pub const VERSION: u8 = 1;
+}
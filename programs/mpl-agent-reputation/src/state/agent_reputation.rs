@@ -6,7 +6,7 @@ use solana_program::{
     pubkey::Pubkey,
 };
 
-use crate::{error::MplAgentReputationError, instruction::accounts::RegisterReputationV1Accounts};
+use crate::error::MplAgentReputationError;
 
 use super::Key;
 
@@ -51,15 +51,21 @@ impl AgentReputationV1 {
         )
     }
 
-    pub fn create_account(accounts: &RegisterReputationV1Accounts, bump: u8) -> ProgramResult {
+    pub fn create_account<'a>(
+        agent_reputation: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
         solana_program::msg!("Creating agent reputation account");
         create_or_allocate_account_raw(
             crate::ID,
-            accounts.agent_reputation,
-            accounts.system_program,
-            accounts.payer,
+            agent_reputation,
+            system_program,
+            payer,
             core::mem::size_of::<AgentReputationV1>(),
-            &[Self::PREFIX, accounts.asset.key.as_ref(), &[bump]],
+            &[Self::PREFIX, asset.as_ref(), &[bump]],
         )
     }
 
@@ -0,0 +1,250 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, resize_or_reallocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{
+    decay::{decay, ONE},
+    error::MplAgentReputationError,
+};
+
+use super::Key;
+
+/// Agent reputation with feedback aggregates. Shares its PDA with
+/// `AgentReputationV1`, which is upgraded in place on the first feedback.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct AgentReputationV2 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the asset.
+    pub asset: Pubkey,
+    /// Number of feedback entries in the aggregates.
+    pub feedback_count: u64,
+    /// Sum of the feedback scores.
+    pub score_sum: u64,
+    /// Sum of the squared feedback scores.
+    pub score_sum_squares: u64,
+    /// Slot of the last feedback.
+    pub last_feedback_slot: u64,
+    /// Exponentially decayed score: the sum of the feedback scores, each
+    /// halved for every half-life elapsed since it was submitted, as Q32.32.
+    pub decayed_score: u64,
+    /// Number of feedback entries, decayed like the score, as Q32.32.
+    /// Dividing the decayed score by it gives a recency-weighted average.
+    pub decayed_count: u64,
+    /// Slot the decayed aggregates were last brought up to date at.
+    pub decay_slot: u64,
+    /// Sum of the feedback weights.
+    pub weight_sum: u64,
+    /// Sum of the feedback scores, each multiplied by its weight. Dividing
+    /// it by the weight sum gives the weighted average.
+    pub weighted_score_sum: u64,
+    /// Number of disputed feedback entries. Disputed feedback stays in the
+    /// aggregates above; these let consumers leave it out.
+    pub disputed_count: u64,
+    /// Sum of the disputed feedback scores.
+    pub disputed_score_sum: u64,
+    /// Sum of the disputed feedback weights.
+    pub disputed_weight_sum: u64,
+    /// Sum of the disputed feedback scores, each multiplied by its weight.
+    pub disputed_weighted_score_sum: u64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 24],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<AgentReputationV2>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<AgentReputationV2>() == 168);
+
+impl AgentReputationV2 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"agent_reputation";
+
+    pub fn check_pda_derivation(address: &AccountInfo, asset: &Pubkey) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, asset.as_ref()],
+            MplAgentReputationError::InvalidAccountData,
+        )
+    }
+
+    /// Grow an `AgentReputationV1` account to the V2 size and update its
+    /// discriminator. The new fields are zero-initialized.
+    pub fn migrate_from_v1<'a>(
+        agent_reputation: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        solana_program::msg!("Migrating agent reputation account to V2");
+        resize_or_reallocate_account_raw(
+            agent_reputation,
+            payer,
+            system_program,
+            core::mem::size_of::<AgentReputationV2>(),
+        )?;
+
+        agent_reputation.try_borrow_mut_data()?[0] = Key::AgentReputationV2 as u8;
+
+        Ok(())
+    }
+
+    /// Decay the decayed aggregates up to `slot`.
+    pub fn apply_decay(&mut self, slot: u64, half_life: u64) {
+        let elapsed = slot.saturating_sub(self.decay_slot);
+        self.decayed_score = decay(self.decayed_score, elapsed, half_life);
+        self.decayed_count = decay(self.decayed_count, elapsed, half_life);
+        self.decay_slot = self.decay_slot.max(slot);
+    }
+
+    /// Add a feedback score of weight `weight` submitted at `slot` to the
+    /// aggregates.
+    pub fn record_feedback(
+        &mut self,
+        score: u8,
+        weight: u32,
+        slot: u64,
+        half_life: u64,
+    ) -> ProgramResult {
+        let score = score as u64;
+        self.feedback_count = self
+            .feedback_count
+            .checked_add(1)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.score_sum = self
+            .score_sum
+            .checked_add(score)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.score_sum_squares = self
+            .score_sum_squares
+            .checked_add(score * score)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.weight_sum = self
+            .weight_sum
+            .checked_add(weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.weighted_score_sum = self
+            .weighted_score_sum
+            .checked_add(score * weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.last_feedback_slot = slot;
+
+        self.apply_decay(slot, half_life);
+        self.decayed_score = self
+            .decayed_score
+            .checked_add(score * ONE)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.decayed_count = self
+            .decayed_count
+            .checked_add(ONE)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+
+        Ok(())
+    }
+
+    /// Add a disputed feedback score of weight `weight` to the disputed
+    /// aggregates.
+    pub fn record_dispute(&mut self, score: u8, weight: u32) -> ProgramResult {
+        let score = score as u64;
+        self.disputed_count = self
+            .disputed_count
+            .checked_add(1)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.disputed_score_sum = self
+            .disputed_score_sum
+            .checked_add(score)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.disputed_weight_sum = self
+            .disputed_weight_sum
+            .checked_add(weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.disputed_weighted_score_sum = self
+            .disputed_weighted_score_sum
+            .checked_add(score * weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+
+        Ok(())
+    }
+
+    /// Remove a disputed feedback score of weight `weight` from the disputed
+    /// aggregates.
+    pub fn remove_dispute(&mut self, score: u8, weight: u32) -> ProgramResult {
+        let score = score as u64;
+        self.disputed_count = self
+            .disputed_count
+            .checked_sub(1)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.disputed_score_sum = self
+            .disputed_score_sum
+            .checked_sub(score)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.disputed_weight_sum = self
+            .disputed_weight_sum
+            .checked_sub(weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.disputed_weighted_score_sum = self
+            .disputed_weighted_score_sum
+            .checked_sub(score * weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+
+        Ok(())
+    }
+
+    /// Remove a feedback score of weight `weight` submitted at
+    /// `feedback_slot` from the aggregates at `slot`.
+    pub fn remove_feedback(
+        &mut self,
+        score: u8,
+        weight: u32,
+        feedback_slot: u64,
+        slot: u64,
+        half_life: u64,
+    ) -> ProgramResult {
+        let score = score as u64;
+        self.feedback_count = self
+            .feedback_count
+            .checked_sub(1)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.score_sum = self
+            .score_sum
+            .checked_sub(score)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.score_sum_squares = self
+            .score_sum_squares
+            .checked_sub(score * score)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.weight_sum = self
+            .weight_sum
+            .checked_sub(weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.weighted_score_sum = self
+            .weighted_score_sum
+            .checked_sub(score * weight as u64)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+
+        // The feedback's share of the decayed aggregates is what is left of
+        // it today. Rounding, or a half-life change since the feedback was
+        // submitted, can make it exceed the aggregates, hence the saturation.
+        self.apply_decay(slot, half_life);
+        let age = slot.saturating_sub(feedback_slot);
+        self.decayed_score = self
+            .decayed_score
+            .saturating_sub(decay(score * ONE, age, half_life));
+        self.decayed_count = self
+            .decayed_count
+            .saturating_sub(decay(ONE, age, half_life));
+
+        Ok(())
+    }
+}
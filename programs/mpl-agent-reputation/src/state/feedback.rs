@@ -0,0 +1,200 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::{ShankAccount, ShankType};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+use super::Key;
+
+/// Lifecycle of a feedback entry.
+/// Stored as a u8 in account data but represented as an enum for type safety.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ShankType)]
+pub enum FeedbackStatus {
+    /// Counted in the aggregates.
+    Active,
+    /// Challenged by the agent. Still counted in the aggregates, so
+    /// consumers that want to exclude it must subtract its score.
+    Disputed,
+    /// Withdrawn by the reviewer and removed from the aggregates.
+    Revoked,
+}
+
+impl From<u8> for FeedbackStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => FeedbackStatus::Disputed,
+            2 => FeedbackStatus::Revoked,
+            _ => FeedbackStatus::Active,
+        }
+    }
+}
+
+/// Record of a single feedback entry, keyed on the reviewed asset, the
+/// reviewer and a per-reviewer index. Holds the status of the feedback and
+/// the latest response of the agent to it.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct FeedbackV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// The feedback score, from 0 to 100.
+    pub score: u8,
+    /// Status of the feedback.
+    #[idl_type(FeedbackStatus)]
+    pub status: u8,
+    /// Weight of the feedback in the weighted aggregates.
+    pub weight: u32,
+    /// The address of the reviewed asset.
+    pub asset: Pubkey,
+    /// The author of the feedback.
+    pub reviewer: Pubkey,
+    /// Index of the feedback among the reviewer's feedback for the asset.
+    pub index: u64,
+    /// Index of the receipts tree the work receipt backing the feedback
+    /// lives in.
+    pub tree_index: u64,
+    /// Nonce of the work receipt leaf within its tree.
+    pub nonce: u64,
+    /// Slot the feedback was submitted at.
+    pub slot: u64,
+    /// Number of responses appended to the feedback.
+    pub response_count: u64,
+    /// Slot of the latest response.
+    pub response_slot: u64,
+    /// Hash of the latest response file.
+    pub response_hash: [u8; 32],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<FeedbackV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<FeedbackV1>() == 152);
+
+impl FeedbackV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"feedback";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        asset: &Pubkey,
+        reviewer: &Pubkey,
+        index: u64,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[
+                Self::PREFIX,
+                asset.as_ref(),
+                reviewer.as_ref(),
+                &index.to_le_bytes(),
+            ],
+            MplAgentReputationError::InvalidFeedback,
+        )
+    }
+
+    pub fn create_account<'a>(
+        feedback: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        reviewer: &Pubkey,
+        index: u64,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            feedback,
+            system_program,
+            payer,
+            core::mem::size_of::<FeedbackV1>(),
+            &[
+                Self::PREFIX,
+                asset.as_ref(),
+                reviewer.as_ref(),
+                &index.to_le_bytes(),
+                &[bump],
+            ],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[allow(clippy::too_many_arguments)]
+    #[inline]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        score: u8,
+        weight: u32,
+        asset: &Pubkey,
+        reviewer: &Pubkey,
+        index: u64,
+        tree_index: u64,
+        nonce: u64,
+        slot: u64,
+    ) {
+        self.key = Key::FeedbackV1 as u8;
+        self.bump = bump;
+        self.score = score;
+        self.status = FeedbackStatus::Active as u8;
+        self.weight = weight;
+        self.asset = *asset;
+        self.reviewer = *reviewer;
+        self.index = index;
+        self.tree_index = tree_index;
+        self.nonce = nonce;
+        self.slot = slot;
+        self.response_count = 0;
+        self.response_slot = 0;
+        self.response_hash = [0u8; 32];
+    }
+
+    /// The status of the feedback.
+    #[inline]
+    pub fn status(&self) -> FeedbackStatus {
+        FeedbackStatus::from(self.status)
+    }
+
+    /// Record a response to the feedback.
+    pub fn record_response(&mut self, response_hash: [u8; 32], slot: u64) -> ProgramResult {
+        self.response_count = self
+            .response_count
+            .checked_add(1)
+            .ok_or(MplAgentReputationError::NumericalOverflow)?;
+        self.response_slot = slot;
+        self.response_hash = response_hash;
+
+        Ok(())
+    }
+
+    /// Mark the feedback as revoked by its reviewer.
+    pub fn revoke(&mut self) -> ProgramResult {
+        if self.status() == FeedbackStatus::Revoked {
+            return Err(MplAgentReputationError::FeedbackRevoked.into());
+        }
+        self.status = FeedbackStatus::Revoked as u8;
+
+        Ok(())
+    }
+
+    /// Mark the feedback as disputed by the agent.
+    pub fn dispute(&mut self) -> ProgramResult {
+        match self.status() {
+            FeedbackStatus::Active => {
+                self.status = FeedbackStatus::Disputed as u8;
+                Ok(())
+            }
+            FeedbackStatus::Disputed => {
+                Err(MplAgentReputationError::FeedbackAlreadyDisputed.into())
+            }
+            FeedbackStatus::Revoked => Err(MplAgentReputationError::FeedbackRevoked.into()),
+        }
+    }
+}
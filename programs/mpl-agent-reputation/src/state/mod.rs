@@ -1,6 +1,26 @@
+mod agent_profile;
 mod agent_reputation;
+mod agent_reputation_v2;
+mod feedback;
+mod receipt_nullifier;
+mod reputation_config;
+mod reputation_snapshot;
+mod reputation_summary;
+mod reviewer_history;
+mod token_lock;
+mod weighting_policy;
 
+pub use agent_profile::*;
 pub use agent_reputation::*;
+pub use agent_reputation_v2::*;
+pub use feedback::*;
+pub use receipt_nullifier::*;
+pub use reputation_config::*;
+pub use reputation_snapshot::*;
+pub use reputation_summary::*;
+pub use reviewer_history::*;
+pub use token_lock::*;
+pub use weighting_policy::*;
 
 use shank::ShankType;
 
@@ -11,6 +31,14 @@ use shank::ShankType;
 pub enum Key {
     Uninitialized,
     AgentReputationV1,
+    AgentReputationV2,
+    ReceiptNullifierV1,
+    FeedbackV1,
+    ReputationConfigV1,
+    WeightingPolicyV1,
+    ReviewerHistoryV1,
+    ReputationSnapshotV1,
+    TokenLockV1,
 }
 
 impl From<u8> for Key {
@@ -18,6 +46,14 @@ impl From<u8> for Key {
         match value {
             0 => Key::Uninitialized,
             1 => Key::AgentReputationV1,
+            2 => Key::AgentReputationV2,
+            3 => Key::ReceiptNullifierV1,
+            4 => Key::FeedbackV1,
+            5 => Key::ReputationConfigV1,
+            6 => Key::WeightingPolicyV1,
+            7 => Key::ReviewerHistoryV1,
+            8 => Key::ReputationSnapshotV1,
+            9 => Key::TokenLockV1,
             _ => Key::Uninitialized,
         }
     }
@@ -0,0 +1,102 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+use super::Key;
+
+/// Marks a work receipt as reviewed. Created alongside the feedback the
+/// receipt backs, so every receipt can back at most one review.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ReceiptNullifierV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// Index of the receipts tree the receipt lives in.
+    pub tree_index: u64,
+    /// Nonce of the receipt leaf within its tree.
+    pub nonce: u64,
+    /// The address of the reviewed agent asset.
+    pub asset: Pubkey,
+    /// The author of the feedback.
+    pub reviewer: Pubkey,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ReceiptNullifierV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ReceiptNullifierV1>() == 88);
+
+impl ReceiptNullifierV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"receipt_nullifier";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        tree_index: u64,
+        nonce: u64,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[
+                Self::PREFIX,
+                &tree_index.to_le_bytes(),
+                &nonce.to_le_bytes(),
+            ],
+            MplAgentReputationError::InvalidAccountData,
+        )
+    }
+
+    pub fn create_account<'a>(
+        receipt_nullifier: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        tree_index: u64,
+        nonce: u64,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            receipt_nullifier,
+            system_program,
+            payer,
+            core::mem::size_of::<ReceiptNullifierV1>(),
+            &[
+                Self::PREFIX,
+                &tree_index.to_le_bytes(),
+                &nonce.to_le_bytes(),
+                &[bump],
+            ],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        tree_index: u64,
+        nonce: u64,
+        asset: &Pubkey,
+        reviewer: &Pubkey,
+    ) {
+        self.key = Key::ReceiptNullifierV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.tree_index = tree_index;
+        self.nonce = nonce;
+        self.asset = *asset;
+        self.reviewer = *reviewer;
+    }
+}
@@ -0,0 +1,109 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+use super::Key;
+
+/// Program-wide reputation settings. Until the config is initialized, the
+/// defaults below apply.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ReputationConfigV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The authority allowed to update the config.
+    pub authority: Pubkey,
+    /// Number of slots after which a feedback counts half as much in the
+    /// decayed score.
+    pub half_life_slots: u64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 64],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ReputationConfigV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ReputationConfigV1>() == 112);
+
+impl ReputationConfigV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"reputation_config";
+
+    /// Half-life used before the config is initialized: about 30 days of
+    /// 400ms slots.
+    pub const DEFAULT_HALF_LIFE_SLOTS: u64 = 6_480_000;
+
+    pub fn check_pda_derivation(address: &AccountInfo) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX],
+            MplAgentReputationError::InvalidReputationConfig,
+        )
+    }
+
+    pub fn create_account<'a>(
+        reputation_config: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            reputation_config,
+            system_program,
+            payer,
+            core::mem::size_of::<ReputationConfigV1>(),
+            &[Self::PREFIX, &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, authority: &Pubkey, half_life_slots: u64) {
+        self.key = Key::ReputationConfigV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.authority = *authority;
+        self.half_life_slots = half_life_slots;
+        self._reserved = [0u8; 64];
+    }
+
+    /// Check that `reputation_config` is an initialized config account.
+    pub fn check_initialized(reputation_config: &AccountInfo) -> ProgramResult {
+        if reputation_config.owner != &crate::ID
+            || reputation_config.data_len() != core::mem::size_of::<ReputationConfigV1>()
+            || reputation_config.try_borrow_data()?[0] != Key::ReputationConfigV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidReputationConfig.into());
+        }
+
+        Ok(())
+    }
+
+    /// Read the half-life from the config PDA, falling back to the default
+    /// while the config is not initialized.
+    pub fn half_life_slots(reputation_config: &AccountInfo) -> Result<u64, ProgramError> {
+        Self::check_pda_derivation(reputation_config)?;
+
+        if reputation_config.data_is_empty() {
+            return Ok(Self::DEFAULT_HALF_LIFE_SLOTS);
+        }
+
+        Self::check_initialized(reputation_config)?;
+        let data = reputation_config.try_borrow_data()?;
+        Ok(bytemuck::from_bytes::<ReputationConfigV1>(&data).half_life_slots)
+    }
+}
@@ -0,0 +1,126 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+use super::{AgentReputationV2, Key};
+
+/// Copy of the aggregates of an agent reputation taken once per epoch, so
+/// the reputation of an agent at a past epoch can be queried.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ReputationSnapshotV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the asset.
+    pub asset: Pubkey,
+    /// Epoch the snapshot was taken in.
+    pub epoch: u64,
+    /// Slot the snapshot was taken at.
+    pub slot: u64,
+    /// Number of feedback entries in the aggregates.
+    pub feedback_count: u64,
+    /// Sum of the feedback scores.
+    pub score_sum: u64,
+    /// Sum of the squared feedback scores.
+    pub score_sum_squares: u64,
+    /// Slot of the last feedback.
+    pub last_feedback_slot: u64,
+    /// Decayed score as of `slot`, as Q32.32.
+    pub decayed_score: u64,
+    /// Decayed feedback count as of `slot`, as Q32.32.
+    pub decayed_count: u64,
+    /// Sum of the feedback weights.
+    pub weight_sum: u64,
+    /// Sum of the feedback scores, each multiplied by its weight.
+    pub weighted_score_sum: u64,
+    /// Number of disputed feedback entries, included in the aggregates.
+    pub disputed_count: u64,
+    /// Sum of the disputed feedback scores.
+    pub disputed_score_sum: u64,
+    /// Sum of the disputed feedback weights.
+    pub disputed_weight_sum: u64,
+    /// Sum of the disputed feedback scores, each multiplied by its weight.
+    pub disputed_weighted_score_sum: u64,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ReputationSnapshotV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ReputationSnapshotV1>() == 152);
+
+impl ReputationSnapshotV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"reputation_snapshot";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        asset: &Pubkey,
+        epoch: u64,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, asset.as_ref(), &epoch.to_le_bytes()],
+            MplAgentReputationError::InvalidReputationSnapshot,
+        )
+    }
+
+    pub fn create_account<'a>(
+        reputation_snapshot: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        epoch: u64,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            reputation_snapshot,
+            system_program,
+            payer,
+            core::mem::size_of::<ReputationSnapshotV1>(),
+            &[Self::PREFIX, asset.as_ref(), &epoch.to_le_bytes(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the aggregates of `agent_reputation`,
+    /// decayed up to `slot`.
+    #[inline]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        epoch: u64,
+        slot: u64,
+        agent_reputation: &AgentReputationV2,
+    ) {
+        self.key = Key::ReputationSnapshotV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.asset = agent_reputation.asset;
+        self.epoch = epoch;
+        self.slot = slot;
+        self.feedback_count = agent_reputation.feedback_count;
+        self.score_sum = agent_reputation.score_sum;
+        self.score_sum_squares = agent_reputation.score_sum_squares;
+        self.last_feedback_slot = agent_reputation.last_feedback_slot;
+        self.decayed_score = agent_reputation.decayed_score;
+        self.decayed_count = agent_reputation.decayed_count;
+        self.weight_sum = agent_reputation.weight_sum;
+        self.weighted_score_sum = agent_reputation.weighted_score_sum;
+        self.disputed_count = agent_reputation.disputed_count;
+        self.disputed_score_sum = agent_reputation.disputed_score_sum;
+        self.disputed_weight_sum = agent_reputation.disputed_weight_sum;
+        self.disputed_weighted_score_sum = agent_reputation.disputed_weighted_score_sum;
+    }
+}
@@ -0,0 +1,60 @@
+use bytemuck::{Pod, Zeroable};
+
+use super::AgentReputationV2;
+
+/// Compact summary of the feedback aggregates written to the asset's AppData
+/// plugin. All fields are little-endian.
+///
+/// # Layout
+/// - version: 1 byte
+/// - _padding: 7 bytes
+/// - feedback_count: 8 bytes
+/// - score_sum: 8 bytes
+/// - score_sum_squares: 8 bytes
+/// - last_feedback_slot: 8 bytes
+/// - disputed_count: 8 bytes
+/// - disputed_score_sum: 8 bytes
+///
+/// Total: 56 bytes
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable)]
+pub struct ReputationSummaryV1 {
+    /// Layout version, `ReputationSummaryV1::VERSION`.
+    pub version: u8,
+    /// Padding for 8-byte alignment.
+    pub _padding: [u8; 7],
+    /// Number of feedback entries in the aggregates.
+    pub feedback_count: u64,
+    /// Sum of the feedback scores.
+    pub score_sum: u64,
+    /// Sum of the squared feedback scores.
+    pub score_sum_squares: u64,
+    /// Slot of the last feedback.
+    pub last_feedback_slot: u64,
+    /// Number of disputed feedback entries, included in the aggregates.
+    pub disputed_count: u64,
+    /// Sum of the disputed feedback scores.
+    pub disputed_score_sum: u64,
+}
+
+const _: () = assert!(core::mem::size_of::<ReputationSummaryV1>() == 56);
+
+impl ReputationSummaryV1 {
+    /// Version byte of this layout.
+    pub const VERSION: u8 = 1;
+}
+
+impl From<&AgentReputationV2> for ReputationSummaryV1 {
+    fn from(reputation: &AgentReputationV2) -> Self {
+        Self {
+            version: Self::VERSION,
+            _padding: [0u8; 7],
+            feedback_count: reputation.feedback_count,
+            score_sum: reputation.score_sum,
+            score_sum_squares: reputation.score_sum_squares,
+            last_feedback_slot: reputation.last_feedback_slot,
+            disputed_count: reputation.disputed_count,
+            disputed_score_sum: reputation.disputed_score_sum,
+        }
+    }
+}
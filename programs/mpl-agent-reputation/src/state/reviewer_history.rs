@@ -0,0 +1,85 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+use super::Key;
+
+/// History of a reviewer with an agent: the number of distinct work receipts
+/// the reviewer proved with the agent by submitting feedback. Counts towards
+/// the weight of the reviewer's feedback.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ReviewerHistoryV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the reviewed asset.
+    pub asset: Pubkey,
+    /// The reviewer.
+    pub reviewer: Pubkey,
+    /// Number of work receipts the reviewer proved with the agent. Revoking
+    /// feedback does not decrease it: its receipt stays nullified.
+    pub receipt_count: u64,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ReviewerHistoryV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ReviewerHistoryV1>() == 80);
+
+impl ReviewerHistoryV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"reviewer_history";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        asset: &Pubkey,
+        reviewer: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, asset.as_ref(), reviewer.as_ref()],
+            MplAgentReputationError::InvalidReviewerHistory,
+        )
+    }
+
+    pub fn create_account<'a>(
+        reviewer_history: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        reviewer: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            reviewer_history,
+            system_program,
+            payer,
+            core::mem::size_of::<ReviewerHistoryV1>(),
+            &[Self::PREFIX, asset.as_ref(), reviewer.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, asset: &Pubkey, reviewer: &Pubkey) {
+        self.key = Key::ReviewerHistoryV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.asset = *asset;
+        self.reviewer = *reviewer;
+        self.receipt_count = 0;
+    }
+}
@@ -0,0 +1,87 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+use super::Key;
+
+/// Lock of a reviewer's tokens of a mint. The tokens are held in a token
+/// account whose owner is this PDA, usually its associated token account, so
+/// only the reviewer can withdraw them, with `UnlockTokensV1`. Each feedback
+/// the tokens add weight to keeps them locked for the weighting policy's lock
+/// period, so the same tokens cannot be moved to another wallet to weigh its
+/// feedback in the meantime.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct TokenLockV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The reviewer owning the locked tokens.
+    pub reviewer: Pubkey,
+    /// The mint of the locked tokens.
+    pub mint: Pubkey,
+    /// Slot from which the tokens can be withdrawn.
+    pub unlock_slot: u64,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<TokenLockV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<TokenLockV1>() == 80);
+
+impl TokenLockV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"token_lock";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        reviewer: &Pubkey,
+        mint: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, reviewer.as_ref(), mint.as_ref()],
+            MplAgentReputationError::InvalidTokenLock,
+        )
+    }
+
+    pub fn create_account<'a>(
+        token_lock: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        reviewer: &Pubkey,
+        mint: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            token_lock,
+            system_program,
+            payer,
+            core::mem::size_of::<TokenLockV1>(),
+            &[Self::PREFIX, reviewer.as_ref(), mint.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, reviewer: &Pubkey, mint: &Pubkey) {
+        self.key = Key::TokenLockV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.reviewer = *reviewer;
+        self.mint = *mint;
+        self.unlock_slot = 0;
+    }
+}
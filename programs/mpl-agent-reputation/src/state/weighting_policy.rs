@@ -0,0 +1,175 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentReputationError;
+
+use super::Key;
+
+/// Program-wide policy weighting each feedback by facts about its reviewer
+/// that the program verifies on submission. The weight of a feedback is
+///
+/// `base_weight`
+/// `+ receipt_weight * min(receipts proven by the reviewer, max_receipts)`
+/// `+ identity_weight` if the reviewer owns an asset with an agent identity
+///   whose own reputation holds `min_identity_feedback` undisputed feedback
+/// `+ token_weight` if the reviewer locked `min_token_balance` of `token_mint`.
+///
+/// Until the policy is set, every feedback weighs `base_weight = 1`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct WeightingPolicyV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// Weight of every feedback.
+    pub base_weight: u32,
+    /// Weight added per work receipt the reviewer proved with the agent,
+    /// including the one backing the feedback.
+    pub receipt_weight: u32,
+    /// Maximum number of receipts counted.
+    pub max_receipts: u32,
+    /// Weight added if the reviewer owns an asset with an agent identity and
+    /// enough feedback.
+    pub identity_weight: u32,
+    /// Weight added if the reviewer locked enough of `token_mint`.
+    pub token_weight: u32,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding_1: [u8; 4],
+    /// Mint of the SPL token whose balance adds weight. The default pubkey
+    /// disables token weighting.
+    pub token_mint: Pubkey,
+    /// Balance of `token_mint` the reviewer must lock for the token weight.
+    pub min_token_balance: u64,
+    /// Undisputed feedback entries the reviewer's asset must have received
+    /// for the identity weight, so identities cannot be registered for it.
+    pub min_identity_feedback: u64,
+    /// Slots the locked tokens stay locked after adding weight to feedback.
+    pub token_lock_slots: u64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 24],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<WeightingPolicyV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<WeightingPolicyV1>() == 112);
+
+impl WeightingPolicyV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"weighting_policy";
+
+    /// Policy applied before one is set: every feedback weighs 1.
+    pub const DEFAULT: Self = Self {
+        key: Key::Uninitialized as u8,
+        bump: 0,
+        _padding: [0u8; 6],
+        base_weight: 1,
+        receipt_weight: 0,
+        max_receipts: 0,
+        identity_weight: 0,
+        token_weight: 0,
+        _padding_1: [0u8; 4],
+        token_mint: Pubkey::new_from_array([0u8; 32]),
+        min_token_balance: 0,
+        min_identity_feedback: 0,
+        token_lock_slots: 0,
+        _reserved: [0u8; 24],
+    };
+
+    pub fn check_pda_derivation(address: &AccountInfo) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX],
+            MplAgentReputationError::InvalidWeightingPolicy,
+        )
+    }
+
+    pub fn create_account<'a>(
+        weighting_policy: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            weighting_policy,
+            system_program,
+            payer,
+            core::mem::size_of::<WeightingPolicyV1>(),
+            &[Self::PREFIX, &[bump]],
+        )
+    }
+
+    /// Read the policy from the weighting policy PDA, falling back to the
+    /// default while no policy is set.
+    pub fn load(weighting_policy: &AccountInfo) -> Result<Self, ProgramError> {
+        Self::check_pda_derivation(weighting_policy)?;
+
+        if weighting_policy.data_is_empty() {
+            return Ok(Self::DEFAULT);
+        }
+
+        if weighting_policy.owner != &crate::ID
+            || weighting_policy.data_len() != core::mem::size_of::<WeightingPolicyV1>()
+            || weighting_policy.try_borrow_data()?[0] != Key::WeightingPolicyV1 as u8
+        {
+            return Err(MplAgentReputationError::InvalidWeightingPolicy.into());
+        }
+
+        Ok(*bytemuck::from_bytes(&weighting_policy.try_borrow_data()?))
+    }
+
+    /// Whether locked tokens add weight.
+    #[inline]
+    pub fn weighs_tokens(&self) -> bool {
+        self.token_mint != Pubkey::default() && self.token_weight > 0
+    }
+
+    /// Weight of a feedback whose reviewer proved `receipt_count` receipts
+    /// with the agent.
+    pub fn weight(&self, receipt_count: u64, has_identity: bool, holds_tokens: bool) -> u32 {
+        let receipts = receipt_count.min(self.max_receipts as u64);
+        let mut weight = self.base_weight as u64 + self.receipt_weight as u64 * receipts;
+        if has_identity {
+            weight += self.identity_weight as u64;
+        }
+        if holds_tokens && self.weighs_tokens() {
+            weight += self.token_weight as u64;
+        }
+
+        // Bounded by `check`.
+        weight as u32
+    }
+
+    /// Check that the policy weighs every feedback, that no weight overflows
+    /// a `u32`, and that the identity and token weights cannot be had for
+    /// free: the identity weight requires feedback and the token weight a
+    /// lock period.
+    pub fn check(&self) -> ProgramResult {
+        let max_weight = self.base_weight as u64
+            + self.receipt_weight as u64 * self.max_receipts as u64
+            + self.identity_weight as u64
+            + self.token_weight as u64;
+        if self.base_weight == 0
+            || max_weight > u32::MAX as u64
+            || (self.identity_weight > 0 && self.min_identity_feedback == 0)
+            || (self.weighs_tokens() && self.token_lock_slots == 0)
+        {
+            return Err(MplAgentReputationError::InvalidWeightingPolicy.into());
+        }
+
+        Ok(())
+    }
+}
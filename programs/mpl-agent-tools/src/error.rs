@@ -95,6 +95,66 @@ pub enum MplAgentToolsError {
     /// 22 - Invalid MPL Account Compression Program
     #[error("Invalid MPL Account Compression Program")]
     InvalidCompressionProgram,
+
+    /// 23 - Invalid Execution Delegate Count Derivation
+    #[error("Invalid Execution Delegate Count Derivation")]
+    InvalidExecutionDelegateCountDerivation,
+
+    /// 24 - Invalid Execution Delegate Count
+    #[error("Invalid Execution Delegate Count")]
+    InvalidExecutionDelegateCount,
+
+    /// 25 - Invalid reviews collection PDA / address
+    #[error("Supplied collection is not the canonical reviews collection PDA")]
+    InvalidReviewsCollection,
+
+    /// 26 - Invalid reviews authority PDA
+    #[error("Supplied authority is not the canonical reviews authority PDA")]
+    InvalidReviewsAuthority,
+
+    /// 27 - Reviews collection already initialized
+    #[error("Reviews collection already exists")]
+    ReviewsCollectionAlreadyInitialized,
+
+    /// 28 - Invalid reviews tree PDA derivation
+    #[error("Invalid reviews tree PDA derivation")]
+    InvalidReviewsTreeDerivation,
+
+    /// 29 - Review URI missing or too long
+    #[error("Review URI must be non-empty and within size limits")]
+    ReviewUriInvalid,
+
+    /// 30 - Invalid Receipt Review Derivation
+    #[error("Invalid Receipt Review Derivation")]
+    InvalidReceiptReviewDerivation,
+
+    /// 31 - Work receipt already reviewed
+    #[error("Work receipt was already reviewed")]
+    ReceiptAlreadyReviewed,
+
+    /// 32 - Missing Execution Delegate Count
+    #[error("The execution delegate count is required for a counted delegate record")]
+    MissingExecutionDelegateCount,
+
+    /// 33 - Execution delegate record already counted
+    #[error("Execution delegate record is already counted")]
+    ExecutionDelegateAlreadyCounted,
+
+    /// 34 - Invalid Execution Delegate Migration
+    #[error("Invalid execution delegate migration account")]
+    InvalidExecutionDelegateMigration,
+
+    /// 35 - Execution delegate migration already completed
+    #[error("The execution delegate migration is already completed")]
+    ExecutionDelegateMigrationAlreadyCompleted,
+
+    /// 36 - Invalid Program Data
+    #[error("Invalid program data account")]
+    InvalidProgramData,
+
+    /// 37 - Invalid Migration Authority
+    #[error("Only the upgrade authority of the program can complete the migration")]
+    InvalidMigrationAuthority,
 }
 
 impl From<MplAgentToolsError> for ProgramError {
@@ -0,0 +1,94 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+/// Version of the event encoding. Emitted as the first byte of every event so
+/// indexers can reject layouts they don't understand.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Events emitted by the program via `sol_log_data`. Each log entry is a
+/// single data field: `[EVENT_VERSION, borsh(MplAgentToolsEvent)...]`.
+///
+/// New variants must be appended to keep existing variant indices stable.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MplAgentToolsEvent {
+    /// An executive profile was registered.
+    ExecutiveRegisteredV1 {
+        executive_profile: Pubkey,
+        authority: Pubkey,
+    },
+    /// An executive was delegated execution for an agent asset.
+    ExecutionDelegatedV1 {
+        agent_asset: Pubkey,
+        executive_profile: Pubkey,
+        execution_delegate_record: Pubkey,
+        authority: Pubkey,
+    },
+    /// An execution delegation was revoked and its record closed.
+    ExecutionRevokedV1 {
+        agent_asset: Pubkey,
+        executive_profile: Pubkey,
+        execution_delegate_record: Pubkey,
+    },
+    /// The canonical receipts collection was created.
+    ReceiptsCollectionCreatedV1 { collection: Pubkey },
+    /// A receipts merkle tree was registered.
+    ReceiptsTreeRegisteredV1 {
+        tree_index: u64,
+        merkle_tree: Pubkey,
+        max_depth: u32,
+        max_buffer_size: u32,
+    },
+    /// A work receipt cNFT was minted to a client.
+    WorkReceiptMintedV1 {
+        agent_asset: Pubkey,
+        client: Pubkey,
+        executive_authority: Pubkey,
+        tree_index: u64,
+        receipt_uri: String,
+    },
+    /// A work receipt cNFT was closed (burned) by its owner.
+    WorkReceiptClosedV1 {
+        leaf_owner: Pubkey,
+        tree_index: u64,
+        nonce: u64,
+        index: u32,
+    },
+    /// The canonical reviews collection was created.
+    ReviewsCollectionCreatedV1 { collection: Pubkey },
+    /// A reviews merkle tree was registered.
+    ReviewsTreeRegisteredV1 {
+        tree_index: u64,
+        merkle_tree: Pubkey,
+        max_depth: u32,
+        max_buffer_size: u32,
+    },
+    /// A review cNFT was minted to the owner of a work receipt.
+    ReviewMintedV1 {
+        agent_asset: Pubkey,
+        reviewer: Pubkey,
+        receipt: Pubkey,
+        tree_index: u64,
+        nonce: u64,
+        review_uri: String,
+    },
+    /// Every execution delegate record was counted, so the counts can be
+    /// relied on.
+    ExecutionDelegateMigrationCompletedV1 { authority: Pubkey },
+    /// An execution delegate record made before delegations were counted was
+    /// counted.
+    ExecutionDelegateCountedV1 {
+        agent_asset: Pubkey,
+        executive_profile: Pubkey,
+        count: u64,
+    },
+}
+
+impl MplAgentToolsEvent {
+    /// Serialize the event and write it to the program log.
+    pub fn emit(&self) -> ProgramResult {
+        let mut data = vec![EVENT_VERSION];
+        borsh::to_writer(&mut data, self)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+}
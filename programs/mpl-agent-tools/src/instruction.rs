@@ -1,9 +1,10 @@
 use shank::{ShankContext, ShankInstruction};
 
 use crate::processor::{
-    CloseWorkReceiptV1Args, CreateReceiptsCollectionV1Args, DelegateExecutionV1Args,
-    MintWorkReceiptV1Args, RegisterExecutiveV1Args, RegisterReceiptsTreeV1Args,
-    RevokeExecutionV1Args,
+    CloseWorkReceiptV1Args, CompleteExecutionDelegateMigrationV1Args, CountExecutionDelegateV1Args,
+    CreateReceiptsCollectionV1Args, CreateReviewsCollectionV1Args, DelegateExecutionV1Args,
+    MintReviewV1Args, MintWorkReceiptV1Args, RegisterExecutiveV1Args, RegisterReceiptsTreeV1Args,
+    RegisterReviewsTreeV1Args, RevokeExecutionV1Args,
 };
 
 /// Instruction discriminants for routing.
@@ -17,6 +18,11 @@ pub enum MplAgentToolsInstructionDiscriminant {
     CreateReceiptsCollectionV1 = 4,
     RegisterReceiptsTreeV1 = 5,
     CloseWorkReceiptV1 = 6,
+    CreateReviewsCollectionV1 = 7,
+    RegisterReviewsTreeV1 = 8,
+    MintReviewV1 = 9,
+    CountExecutionDelegateV1 = 10,
+    CompleteExecutionDelegateMigrationV1 = 11,
 }
 
 impl TryFrom<u8> for MplAgentToolsInstructionDiscriminant {
@@ -31,6 +37,11 @@ impl TryFrom<u8> for MplAgentToolsInstructionDiscriminant {
             4 => Ok(MplAgentToolsInstructionDiscriminant::CreateReceiptsCollectionV1),
             5 => Ok(MplAgentToolsInstructionDiscriminant::RegisterReceiptsTreeV1),
             6 => Ok(MplAgentToolsInstructionDiscriminant::CloseWorkReceiptV1),
+            7 => Ok(MplAgentToolsInstructionDiscriminant::CreateReviewsCollectionV1),
+            8 => Ok(MplAgentToolsInstructionDiscriminant::RegisterReviewsTreeV1),
+            9 => Ok(MplAgentToolsInstructionDiscriminant::MintReviewV1),
+            10 => Ok(MplAgentToolsInstructionDiscriminant::CountExecutionDelegateV1),
+            11 => Ok(MplAgentToolsInstructionDiscriminant::CompleteExecutionDelegateMigrationV1),
             _ => Err(()),
         }
     }
@@ -47,6 +58,8 @@ pub enum MplAgentToolsInstruction {
     RegisterExecutiveV1(RegisterExecutiveV1Args),
 
     /// Delegate Executive Permission for an Agent Asset.
+    /// Every delegation is counted for the agent asset, which keeps its
+    /// identity from being deregistered until the delegation is revoked.
     #[account(0, name="executive_profile", desc = "The executive profile")]
     #[account(1, name="agent_asset", desc = "The agent asset")]
     #[account(2, name="agent_identity", desc = "The agent identity")]
@@ -54,6 +67,7 @@ pub enum MplAgentToolsInstruction {
     #[account(4, writable, signer, name="payer", desc = "The payer for additional rent")]
     #[account(5, optional, signer, name="authority", desc = "Authority the executive signs with when executing agent actions")]
     #[account(6, name="system_program", desc = "The system program")]
+    #[account(7, writable, name="execution_delegate_count", desc = "The execution delegate count for the agent asset")]
     DelegateExecutionV1(DelegateExecutionV1Args),
 
     /// Revoke an Execution Delegate for an Agent Asset.
@@ -63,6 +77,7 @@ pub enum MplAgentToolsInstruction {
     #[account(3, writable, signer, name="payer", desc = "The payer")]
     #[account(4, optional, signer, name="authority", desc = "Authority — must be asset owner or executive authority")]
     #[account(5, name="system_program", desc = "The system program")]
+    #[account(6, writable, name="execution_delegate_count", desc = "The execution delegate count for the agent asset")]
     RevokeExecutionV1(RevokeExecutionV1Args),
 
     /// Mint a work receipt cNFT to a client wallet on behalf of an agent.
@@ -139,4 +154,73 @@ pub enum MplAgentToolsInstruction {
     #[account(10, name="bubblegum_program", desc = "The MPL Bubblegum program")]
     #[account(11, name="system_program", desc = "The system program")]
     CloseWorkReceiptV1(CloseWorkReceiptV1Args),
+
+    /// Permissionless idempotent bootstrap: create the canonical reviews
+    /// collection at [\"reviews_collection\"] PDA with update_authority =
+    /// [\"reviews_authority\"] PDA. The collection is soulbound through a
+    /// PermanentFreezeDelegate, so review cNFTs stay with their authors.
+    #[account(0, writable, signer, name="payer", desc = "Funds the collection's rent")]
+    #[account(1, writable, name="collection", desc = "Reviews collection PDA at [\"reviews_collection\"]")]
+    #[account(2, name="authority", desc = "Reviews authority PDA at [\"reviews_authority\"] — becomes the collection's update_authority")]
+    #[account(3, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(4, name="system_program", desc = "The system program")]
+    CreateReviewsCollectionV1(CreateReviewsCollectionV1Args),
+
+    /// Permissionless tree registration for review cNFTs. Mirrors
+    /// RegisterReceiptsTreeV1: the tree is created at PDA
+    /// [\"reviews_tree\", tree_index_le] with `tree_creator =
+    /// [\"reviews_authority\"]` PDA so MintReviewV1 can sign every mint.
+    #[account(0, writable, signer, name="payer", desc = "Funds the tree rent")]
+    #[account(1, name="authority", desc = "Reviews authority PDA at [\"reviews_authority\"] — set as tree_creator")]
+    #[account(2, writable, name="merkle_tree", desc = "Reviews merkle tree PDA at [\"reviews_tree\", tree_index_le]")]
+    #[account(3, writable, name="tree_config", desc = "Bubblegum tree config PDA (derived from merkle_tree)")]
+    #[account(4, name="log_wrapper", desc = "MPL Noop / log wrapper program")]
+    #[account(5, name="compression_program", desc = "MPL Account Compression program")]
+    #[account(6, name="bubblegum_program", desc = "The MPL Bubblegum program")]
+    #[account(7, name="system_program", desc = "The system program")]
+    RegisterReviewsTreeV1(RegisterReviewsTreeV1Args),
+
+    /// Mint a soulbound review cNFT to the owner of a work receipt. The
+    /// reviewer proves they hold the receipt with a merkle proof against
+    /// the receipts tree; the review links the agent, the reviewer and the
+    /// receipt's asset id through its creators. A receipt review record at
+    /// [\"receipt_review\", receipts_tree_index_le, receipt_nonce_le]
+    /// limits each receipt to one review. Any accounts beyond
+    /// `system_program` are treated as the receipt's merkle proof path.
+    #[account(0, writable, signer, name="payer", desc = "Pays for the review record and the cNFT mint")]
+    #[account(1, signer, name="reviewer", desc = "Owner of the work receipt and recipient of the review")]
+    #[account(2, name="agent_asset", desc = "The Core asset representing the reviewed agent")]
+    #[account(3, writable, name="receipt_review", desc = "Receipt review record PDA")]
+    #[account(4, name="receipts_merkle_tree", desc = "Receipts merkle tree holding the work receipt")]
+    #[account(5, name="authority", desc = "Reviews authority PDA at [\"reviews_authority\"] — signs CPI via invoke_signed")]
+    #[account(6, writable, name="tree_config", desc = "Bubblegum tree config PDA for the reviews tree")]
+    #[account(7, writable, name="merkle_tree", desc = "Reviews merkle tree at PDA [\"reviews_tree\", tree_index_le]")]
+    #[account(8, writable, name="core_collection", desc = "Canonical reviews collection PDA at [\"reviews_collection\"]")]
+    #[account(9, name="mpl_core_cpi_signer", desc = "Bubblegum's mpl-core CPI signer PDA")]
+    #[account(10, name="log_wrapper", desc = "MPL Noop / log wrapper program")]
+    #[account(11, name="compression_program", desc = "MPL Account Compression program")]
+    #[account(12, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(13, name="bubblegum_program", desc = "The MPL Bubblegum program")]
+    #[account(14, name="system_program", desc = "The system program")]
+    MintReviewV1(MintReviewV1Args),
+
+    /// Permissionless backfill: count an execution delegate record
+    /// delegated before delegations were counted.
+    #[account(0, writable, name="execution_delegate_record", desc = "The uncounted execution delegate record")]
+    #[account(1, writable, name="execution_delegate_count", desc = "The execution delegate count for the agent asset")]
+    #[account(2, name="agent_asset", desc = "The agent asset")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(4, name="system_program", desc = "The system program")]
+    CountExecutionDelegateV1(CountExecutionDelegateV1Args),
+
+    /// Mark the backfill of execution delegate records as complete, so the
+    /// execution delegate counts cover every record. Only the upgrade
+    /// authority of the program can call it, once, after counting every
+    /// record delegated before delegations were counted.
+    #[account(0, writable, name="execution_delegate_migration", desc = "Execution delegate migration PDA at [\"execution_delegate_migration\"]")]
+    #[account(1, name="program_data", desc = "The program data account of this program")]
+    #[account(2, signer, name="authority", desc = "The upgrade authority of this program")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for the migration account")]
+    #[account(4, name="system_program", desc = "The system program")]
+    CompleteExecutionDelegateMigrationV1(CompleteExecutionDelegateMigrationV1Args),
 }
@@ -7,6 +7,7 @@ use solana_program::{
 };
 use solana_system_interface::program as system_program;
 
+use crate::events::MplAgentToolsEvent;
 use crate::{
     error::MplAgentToolsError,
     instruction::accounts::CloseWorkReceiptV1Accounts,
@@ -136,7 +137,13 @@ pub fn close_work_receipt_v1<'a>(
     }
     burn.invoke_signed(&[authority_seeds])?;
 
-    Ok(())
+    MplAgentToolsEvent::WorkReceiptClosedV1 {
+        leaf_owner: *ctx.accounts.leaf_owner.key,
+        tree_index: args.tree_index,
+        nonce: args.nonce,
+        index: args.index,
+    }
+    .emit()
 }
 
 pub fn cast_close_work_receipt_args(data: &[u8]) -> Result<&CloseWorkReceiptV1Args, ProgramError> {
@@ -0,0 +1,125 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult,
+    program_error::ProgramError, pubkey::Pubkey, sysvar::Sysvar,
+};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentToolsEvent;
+use crate::{
+    error::MplAgentToolsError, instruction::accounts::CompleteExecutionDelegateMigrationV1Accounts,
+    state::ExecutionDelegateMigrationV1,
+};
+
+/// The BPF upgradeable loader, which owns the program data account holding
+/// the upgrade authority of the program.
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_program::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Discriminant of the `ProgramData` variant of the upgradeable loader state.
+const PROGRAM_DATA_DISCRIMINANT: u32 = 3;
+
+/// Read the upgrade authority of this program from its program data account,
+/// laid out as `[u32 variant][u64 slot][u8 option][32-byte authority]`.
+fn upgrade_authority(program_data: &AccountInfo) -> Result<Option<Pubkey>, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    if *program_data.key != program_data_address || *program_data.owner != BPF_LOADER_UPGRADEABLE_ID
+    {
+        return Err(MplAgentToolsError::InvalidProgramData.into());
+    }
+
+    let data = program_data.try_borrow_data()?;
+    let header = data
+        .get(..45)
+        .ok_or(MplAgentToolsError::InvalidProgramData)?;
+    if u32::from_le_bytes(header[..4].try_into().unwrap()) != PROGRAM_DATA_DISCRIMINANT {
+        return Err(MplAgentToolsError::InvalidProgramData.into());
+    }
+
+    Ok(match header[12] {
+        0 => None,
+        _ => Some(Pubkey::new_from_array(header[13..45].try_into().unwrap())),
+    })
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct CompleteExecutionDelegateMigrationV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<CompleteExecutionDelegateMigrationV1Args>() == 8);
+
+/// Mark every execution delegate record as counted. The upgrade authority
+/// calls it once it has backfilled, with `CountExecutionDelegateV1`, the
+/// records delegated before delegations were counted.
+pub fn complete_execution_delegate_migration_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &CompleteExecutionDelegateMigrationV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = CompleteExecutionDelegateMigrationV1Accounts::context(accounts)?;
+
+    /****************************************************/
+    /****************** Account Guards ******************/
+    /****************************************************/
+    // Payer must sign.
+    assert_signer(ctx.accounts.payer)?;
+
+    // Only the upgrade authority of the program may complete the migration.
+    assert_signer(ctx.accounts.authority)?;
+    if upgrade_authority(ctx.accounts.program_data)? != Some(*ctx.accounts.authority.key) {
+        return Err(MplAgentToolsError::InvalidMigrationAuthority.into());
+    }
+
+    // The migration can only be completed once.
+    let bump = ExecutionDelegateMigrationV1::check_pda_derivation(
+        ctx.accounts.execution_delegate_migration,
+    )?;
+    if ctx.accounts.execution_delegate_migration.owner != &system_program::ID
+        || ctx.accounts.execution_delegate_migration.data_len() > 0
+    {
+        return Err(MplAgentToolsError::ExecutionDelegateMigrationAlreadyCompleted.into());
+    }
+
+    // Validate system program.
+    if *ctx.accounts.system_program.key != system_program::id() {
+        return Err(MplAgentToolsError::InvalidSystemProgram.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    ExecutionDelegateMigrationV1::create_account(
+        ctx.accounts.execution_delegate_migration,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        bump,
+    )?;
+
+    let mut data = ctx
+        .accounts
+        .execution_delegate_migration
+        .try_borrow_mut_data()?;
+    let migration: &mut ExecutionDelegateMigrationV1 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<ExecutionDelegateMigrationV1>()]);
+    migration.initialize(bump, ctx.accounts.authority.key, Clock::get()?.slot);
+    drop(data);
+
+    MplAgentToolsEvent::ExecutionDelegateMigrationCompletedV1 {
+        authority: *ctx.accounts.authority.key,
+    }
+    .emit()
+}
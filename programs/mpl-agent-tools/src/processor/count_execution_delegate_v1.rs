@@ -0,0 +1,109 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use crate::{
+    error::MplAgentToolsError,
+    events::MplAgentToolsEvent,
+    instruction::accounts::CountExecutionDelegateV1Accounts,
+    state::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, Key},
+};
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct CountExecutionDelegateV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<CountExecutionDelegateV1Args>() == 8);
+
+pub fn count_execution_delegate_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &CountExecutionDelegateV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = CountExecutionDelegateV1Accounts::context(accounts)?;
+
+    /****************************************************/
+    /****************** Account Guards ******************/
+    /****************************************************/
+    // Payer must sign.
+    assert_signer(ctx.accounts.payer)?;
+
+    // Assert that the execution delegate record is initialized and owned by our program.
+    if ctx.accounts.execution_delegate_record.owner != &crate::ID
+        || ctx.accounts.execution_delegate_record.data_len() == 0
+        || ctx.accounts.execution_delegate_record.try_borrow_data()?[0]
+            != Key::ExecutionDelegateRecordV1 as u8
+    {
+        return Err(MplAgentToolsError::ExecutionDelegateRecordMustBeInitialized.into());
+    }
+
+    let mut record_data = ctx
+        .accounts
+        .execution_delegate_record
+        .try_borrow_mut_data()?;
+    let record: &mut ExecutionDelegateRecordV1 = bytemuck::from_bytes_mut(
+        &mut record_data[..core::mem::size_of::<ExecutionDelegateRecordV1>()],
+    );
+
+    // Verify the record's agent_asset matches the passed agent_asset account.
+    if record.agent_asset != *ctx.accounts.agent_asset.key {
+        return Err(MplAgentToolsError::InvalidExecutionDelegateRecordDerivation.into());
+    }
+
+    // Check PDA derivation.
+    ExecutionDelegateRecordV1::check_pda_derivation(
+        ctx.accounts.execution_delegate_record,
+        &record.executive_profile,
+        ctx.accounts.agent_asset.key,
+    )?;
+
+    if record.counted != 0 {
+        return Err(MplAgentToolsError::ExecutionDelegateAlreadyCounted.into());
+    }
+
+    // Check the delegate count, which is created on the first counted record.
+    let count_bump = ExecutionDelegateCountV1::check(
+        ctx.accounts.execution_delegate_count,
+        ctx.accounts.agent_asset.key,
+    )?;
+
+    // Validate system program.
+    if *ctx.accounts.system_program.key != system_program::id() {
+        return Err(MplAgentToolsError::InvalidSystemProgram.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    record.counted = 1;
+    let executive_profile = record.executive_profile;
+    drop(record_data);
+
+    let count = ExecutionDelegateCountV1::increment(
+        ctx.accounts.execution_delegate_count,
+        ctx.accounts.agent_asset.key,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        count_bump,
+    )?;
+
+    MplAgentToolsEvent::ExecutionDelegateCountedV1 {
+        agent_asset: *ctx.accounts.agent_asset.key,
+        executive_profile,
+        count,
+    }
+    .emit()
+}
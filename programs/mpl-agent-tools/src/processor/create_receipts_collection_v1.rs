@@ -13,6 +13,7 @@ use solana_program::{
 };
 use solana_system_interface::program as system_program;
 
+use crate::events::MplAgentToolsEvent;
 use crate::{
     error::MplAgentToolsError,
     instruction::accounts::CreateReceiptsCollectionV1Accounts,
@@ -99,7 +100,10 @@ pub fn create_receipts_collection_v1<'a>(
         ])
         .invoke_signed(&[collection_signer_seeds])?;
 
-    Ok(())
+    MplAgentToolsEvent::ReceiptsCollectionCreatedV1 {
+        collection: *ctx.accounts.collection.key,
+    }
+    .emit()
 }
 
 pub fn cast_create_receipts_collection_args(
@@ -8,10 +8,11 @@ use shank::ShankType;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 use solana_system_interface::program as system_program;
 
+use crate::events::MplAgentToolsEvent;
 use crate::{
     error::MplAgentToolsError,
     instruction::accounts::DelegateExecutionV1Accounts,
-    state::{ExecutionDelegateRecordV1, ExecutiveProfileV1, Key},
+    state::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, ExecutiveProfileV1, Key},
 };
 
 #[repr(C)]
@@ -113,6 +114,13 @@ pub fn delegate_execution_v1<'a>(
         return Err(MplAgentToolsError::ExecutionDelegateRecordMustBeUninitialized.into());
     }
 
+    // Check the delegate count account. It is created on the first
+    // delegation for the agent asset.
+    let count_bump = ExecutionDelegateCountV1::check(
+        ctx.accounts.execution_delegate_count,
+        ctx.accounts.agent_asset.key,
+    )?;
+
     // Validate system program.
     if *ctx.accounts.system_program.key != system_program::id() {
         return Err(MplAgentToolsError::InvalidSystemProgram.into());
@@ -146,5 +154,22 @@ pub fn delegate_execution_v1<'a>(
         &executive_profile.authority,
     );
 
-    Ok(())
+    drop(data);
+
+    // Track the new delegate record.
+    ExecutionDelegateCountV1::increment(
+        ctx.accounts.execution_delegate_count,
+        ctx.accounts.agent_asset.key,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        count_bump,
+    )?;
+
+    MplAgentToolsEvent::ExecutionDelegatedV1 {
+        agent_asset: *ctx.accounts.agent_asset.key,
+        executive_profile: *ctx.accounts.executive_profile.key,
+        execution_delegate_record: *ctx.accounts.execution_delegate_record.key,
+        authority: executive_profile.authority,
+    }
+    .emit()
 }
@@ -0,0 +1,261 @@
+use bytemuck::{from_bytes, Pod, Zeroable};
+use mpl_agent_tools::receipts::{verify_receipt, ReceiptProof};
+use mpl_bubblegum::{
+    accounts::TreeConfig,
+    instructions::MintV2CpiBuilder,
+    types::{Creator, MetadataArgsV2, TokenStandard},
+    utils::get_asset_id,
+    ID as BUBBLEGUM_ID,
+};
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
+use solana_system_interface::program as system_program;
+
+use super::uri::read_length_prefixed_string;
+use crate::events::MplAgentToolsEvent;
+use crate::{
+    error::MplAgentToolsError,
+    instruction::accounts::MintReviewV1Accounts,
+    state::{
+        check_receipts_tree_pda, check_reviews_authority_pda, check_reviews_collection_pda,
+        check_reviews_tree_pda, ReceiptReviewV1, REVIEWS_AUTHORITY_PREFIX,
+    },
+};
+
+/// Maximum length of the off-chain review URI, in bytes.
+pub const MAX_REVIEW_URI_LEN: usize = 200;
+
+/// MPL Account Compression program id — pinned to defeat compression-
+/// program spoofing.
+const MPL_ACCOUNT_COMPRESSION_ID: solana_program::pubkey::Pubkey =
+    solana_program::pubkey!("mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW");
+
+/// Number of named accounts in `MintReviewV1` (everything before the
+/// receipt's merkle proof remaining accounts). Must stay in sync with the
+/// `#[account]` list in `instruction.rs`.
+const MINT_REVIEW_NAMED_ACCOUNTS: usize = 15;
+
+/// Fixed-head Pod args for `MintReviewV1`. The receipt fields are the leaf
+/// data of the work receipt being reviewed, verified against the receipts
+/// tree root. The `review_uri` string is a zero-sized sentinel followed by
+/// a length-prefixed UTF-8 tail, as in `MintWorkReceiptV1Args`.
+#[repr(C)]
+#[derive(Pod, Zeroable, PartialEq, Eq, Debug, Clone, Copy, ShankType)]
+pub struct MintReviewV1Args {
+    #[skip]
+    pub discriminator: u8,
+    /// Receipt leaf flags.
+    pub receipt_flags: u8,
+    /// Padding to align the `u32` field.
+    #[padding]
+    pub _pad: [u8; 2],
+    /// Receipt leaf's index within its tree.
+    pub receipt_index: u32,
+    /// Index of the receipts tree the receipt lives in.
+    pub receipts_tree_index: u64,
+    /// Receipt leaf's nonce within its tree.
+    pub receipt_nonce: u64,
+    /// Index of the reviews tree the review is minted into.
+    /// Must match the tree PDA seeds (`["reviews_tree", index_le]`).
+    pub reviews_tree_index: u64,
+    /// Current root of the receipts merkle tree.
+    pub root: [u8; 32],
+    /// Hash of the receipt's metadata.
+    pub receipt_data_hash: [u8; 32],
+    /// Hash of the receipt's `asset_data` blob.
+    pub receipt_asset_data_hash: [u8; 32],
+    /// URI of the off-chain review JSON. Stored as a length-prefixed
+    /// UTF-8 string immediately after this struct in instruction data.
+    #[idl_type("String")]
+    pub review_uri: [u8; 0],
+}
+const _: () = assert!(core::mem::size_of::<MintReviewV1Args>() == 128);
+const _: () = assert!(core::mem::align_of::<MintReviewV1Args>() == 8);
+
+pub fn mint_review_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let ctx = MintReviewV1Accounts::context(accounts)?;
+    let proof_accounts = if accounts.len() > MINT_REVIEW_NAMED_ACCOUNTS {
+        &accounts[MINT_REVIEW_NAMED_ACCOUNTS..]
+    } else {
+        &[][..]
+    };
+
+    if instruction_data.len() < core::mem::size_of::<MintReviewV1Args>() {
+        return Err(MplAgentToolsError::InvalidInstructionData.into());
+    }
+    let (args_bytes, tail) = instruction_data.split_at(core::mem::size_of::<MintReviewV1Args>());
+    let args: &MintReviewV1Args = from_bytes(args_bytes);
+
+    let review_uri = read_length_prefixed_string(
+        tail,
+        MAX_REVIEW_URI_LEN,
+        MplAgentToolsError::ReviewUriInvalid,
+    )?;
+
+    // The reviewer signs: unlike receipts, a review is the holder's own
+    // statement, and it is minted to the same wallet.
+    assert_signer(ctx.accounts.payer)?;
+    assert_signer(ctx.accounts.reviewer)?;
+
+    // Agent asset = Core AssetV1.
+    if ctx.accounts.agent_asset.owner != &mpl_core::ID
+        || ctx.accounts.agent_asset.data_len() == 0
+        || ctx.accounts.agent_asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+    {
+        return Err(MplAgentToolsError::InvalidCoreAsset.into());
+    }
+
+    let authority_bump = check_reviews_authority_pda(ctx.accounts.authority)?;
+    check_reviews_tree_pda(ctx.accounts.merkle_tree, args.reviews_tree_index)?;
+    check_reviews_collection_pda(ctx.accounts.core_collection)?;
+    check_receipts_tree_pda(ctx.accounts.receipts_merkle_tree, args.receipts_tree_index)?;
+
+    if *ctx.accounts.mpl_core_program.key != mpl_core::ID {
+        return Err(MplAgentToolsError::InvalidMplCoreProgram.into());
+    }
+    if *ctx.accounts.bubblegum_program.key != BUBBLEGUM_ID {
+        return Err(MplAgentToolsError::InvalidBubblegumProgram.into());
+    }
+    if *ctx.accounts.compression_program.key != MPL_ACCOUNT_COMPRESSION_ID {
+        return Err(MplAgentToolsError::InvalidCompressionProgram.into());
+    }
+    if *ctx.accounts.system_program.key != system_program::id() {
+        return Err(MplAgentToolsError::InvalidSystemProgram.into());
+    }
+
+    // One review per receipt.
+    let review_bump = ReceiptReviewV1::check_pda_derivation(
+        ctx.accounts.receipt_review,
+        args.receipts_tree_index,
+        args.receipt_nonce,
+    )?;
+    if ctx.accounts.receipt_review.data_len() != 0 {
+        return Err(MplAgentToolsError::ReceiptAlreadyReviewed.into());
+    }
+
+    // Argument guard.
+    if review_uri.is_empty() {
+        return Err(MplAgentToolsError::ReviewUriInvalid.into());
+    }
+
+    // The receipt is rebuilt the way `MintWorkReceiptV1` mints it, with the
+    // reviewer as the client.
+    verify_receipt(
+        ctx.accounts.receipts_merkle_tree,
+        ctx.accounts.compression_program,
+        proof_accounts,
+        ctx.accounts.agent_asset.key,
+        ctx.accounts.reviewer.key,
+        &ReceiptProof {
+            nonce: args.receipt_nonce,
+            index: args.receipt_index,
+            root: args.root,
+            data_hash: args.receipt_data_hash,
+            asset_data_hash: args.receipt_asset_data_hash,
+            flags: args.receipt_flags,
+        },
+    )?;
+
+    let receipt = get_asset_id(ctx.accounts.receipts_merkle_tree.key, args.receipt_nonce);
+
+    // Bubblegum assigns the next leaf `num_minted` as its nonce.
+    if *ctx.accounts.tree_config.owner != BUBBLEGUM_ID {
+        return Err(MplAgentToolsError::InvalidAccountData.into());
+    }
+    let review_nonce = TreeConfig::from_bytes(&ctx.accounts.tree_config.try_borrow_data()?)
+        .map_err(|_| MplAgentToolsError::InvalidAccountData)?
+        .num_minted;
+
+    ReceiptReviewV1::create_account(
+        &ctx.accounts,
+        args.receipts_tree_index,
+        args.receipt_nonce,
+        review_bump,
+    )?;
+    {
+        let mut data = ctx.accounts.receipt_review.try_borrow_mut_data()?;
+        let receipt_review: &mut ReceiptReviewV1 = bytemuck::from_bytes_mut(&mut data);
+        receipt_review.initialize(
+            review_bump,
+            ctx.accounts.agent_asset.key,
+            ctx.accounts.reviewer.key,
+            &receipt,
+            args.reviews_tree_index,
+            review_nonce,
+        );
+    }
+
+    msg!(
+        "Review agent={} reviewer={} receipt={} tree_index={}",
+        ctx.accounts.agent_asset.key,
+        ctx.accounts.reviewer.key,
+        receipt,
+        args.reviews_tree_index,
+    );
+
+    // Creators link the review to the agent, its author and the reviewed
+    // receipt. None are verified: the leaf is only ever minted here.
+    let metadata = MetadataArgsV2 {
+        name: "Agent Review".to_string(),
+        symbol: "AGENTRVW".to_string(),
+        uri: review_uri.clone(),
+        seller_fee_basis_points: 0,
+        primary_sale_happened: false,
+        is_mutable: false,
+        token_standard: Some(TokenStandard::NonFungible),
+        creators: vec![
+            Creator {
+                address: *ctx.accounts.agent_asset.key,
+                verified: false,
+                share: 100,
+            },
+            Creator {
+                address: *ctx.accounts.reviewer.key,
+                verified: false,
+                share: 0,
+            },
+            Creator {
+                address: receipt,
+                verified: false,
+                share: 0,
+            },
+        ],
+        collection: Some(*ctx.accounts.core_collection.key),
+    };
+
+    // The reviews_authority PDA is both the reviews tree creator and the
+    // reviews collection's update_authority.
+    let authority_seeds: &[&[u8]] = &[REVIEWS_AUTHORITY_PREFIX, &[authority_bump]];
+
+    MintV2CpiBuilder::new(ctx.accounts.bubblegum_program)
+        .tree_config(ctx.accounts.tree_config)
+        .payer(ctx.accounts.payer)
+        .tree_creator_or_delegate(Some(ctx.accounts.authority))
+        .collection_authority(None)
+        .leaf_owner(ctx.accounts.reviewer)
+        .leaf_delegate(None)
+        .merkle_tree(ctx.accounts.merkle_tree)
+        .core_collection(Some(ctx.accounts.core_collection))
+        .mpl_core_cpi_signer(Some(ctx.accounts.mpl_core_cpi_signer))
+        .log_wrapper(ctx.accounts.log_wrapper)
+        .compression_program(ctx.accounts.compression_program)
+        .mpl_core_program(ctx.accounts.mpl_core_program)
+        .system_program(ctx.accounts.system_program)
+        .metadata(metadata)
+        .invoke_signed(&[authority_seeds])?;
+
+    MplAgentToolsEvent::ReviewMintedV1 {
+        agent_asset: *ctx.accounts.agent_asset.key,
+        reviewer: *ctx.accounts.reviewer.key,
+        receipt,
+        tree_index: args.reviews_tree_index,
+        nonce: review_nonce,
+        review_uri,
+    }
+    .emit()
+}
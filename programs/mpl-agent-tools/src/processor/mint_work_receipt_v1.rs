@@ -7,11 +7,11 @@ use mpl_bubblegum::{
 use mpl_core::types::Key as MplCoreKey;
 use mpl_utils::assert_signer;
 use shank::ShankType;
-use solana_program::{
-    account_info::AccountInfo, entrypoint::ProgramResult, msg, program_error::ProgramError,
-};
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg};
 use solana_system_interface::program as system_program;
 
+use super::uri::read_length_prefixed_string;
+use crate::events::MplAgentToolsEvent;
 use crate::{
     error::MplAgentToolsError,
     instruction::accounts::MintWorkReceiptV1Accounts,
@@ -69,7 +69,11 @@ pub fn mint_work_receipt_v1<'a>(
         instruction_data.split_at(core::mem::size_of::<MintWorkReceiptV1Args>());
     let args: &MintWorkReceiptV1Args = from_bytes(args_bytes);
 
-    let receipt_uri = read_length_prefixed_string(tail, MAX_RECEIPT_URI_LEN)?;
+    let receipt_uri = read_length_prefixed_string(
+        tail,
+        MAX_RECEIPT_URI_LEN,
+        MplAgentToolsError::ReceiptUriInvalid,
+    )?;
 
     // Signers. The client is intentionally NOT a signer — coordinating a
     // real-time handshake with the receiving wallet is impractical. The
@@ -149,7 +153,7 @@ pub fn mint_work_receipt_v1<'a>(
     let metadata = MetadataArgsV2 {
         name: "Agent Work Receipt".to_string(),
         symbol: "AGENTRCPT".to_string(),
-        uri: receipt_uri,
+        uri: receipt_uri.clone(),
         seller_fee_basis_points: 0,
         primary_sale_happened: false,
         is_mutable: false,
@@ -194,24 +198,12 @@ pub fn mint_work_receipt_v1<'a>(
         .metadata(metadata)
         .invoke_signed(&[authority_seeds])?;
 
-    Ok(())
-}
-
-/// Parse a Borsh-style length-prefixed UTF-8 string from `data`:
-/// `[u32 LE length][bytes...]`. Rejects if the buffer is too short, the
-/// declared length exceeds `max_len`, or the bytes aren't valid UTF-8.
-fn read_length_prefixed_string(data: &[u8], max_len: usize) -> Result<String, ProgramError> {
-    if data.len() < 4 {
-        return Err(MplAgentToolsError::InvalidInstructionData.into());
-    }
-    let len = u32::from_le_bytes(
-        data[..4]
-            .try_into()
-            .map_err(|_| MplAgentToolsError::InvalidInstructionData)?,
-    ) as usize;
-    if len > max_len || data.len() < 4 + len {
-        return Err(MplAgentToolsError::ReceiptUriInvalid.into());
+    MplAgentToolsEvent::WorkReceiptMintedV1 {
+        agent_asset: *ctx.accounts.agent_asset.key,
+        client: *ctx.accounts.client.key,
+        executive_authority: *ctx.accounts.executive_authority.key,
+        tree_index: args.tree_index,
+        receipt_uri,
     }
-    String::from_utf8(data[4..4 + len].to_vec())
-        .map_err(|_| MplAgentToolsError::InvalidInstructionData.into())
+    .emit()
 }
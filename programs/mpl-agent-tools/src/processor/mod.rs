@@ -1,10 +1,16 @@
 mod close_work_receipt_v1;
+mod complete_execution_delegate_migration_v1;
+mod count_execution_delegate_v1;
 mod create_receipts_collection_v1;
+mod create_reviews_collection_v1;
 mod delegate_execution_v1;
+mod mint_review_v1;
 mod mint_work_receipt_v1;
 mod register_executive_v1;
 mod register_receipts_tree_v1;
+mod register_reviews_tree_v1;
 mod revoke_execution_v1;
+mod uri;
 
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
 
@@ -14,16 +20,29 @@ use crate::instruction::MplAgentToolsInstructionDiscriminant;
 pub use close_work_receipt_v1::{
     cast_close_work_receipt_args, close_work_receipt_v1, CloseWorkReceiptV1Args,
 };
+pub use complete_execution_delegate_migration_v1::{
+    complete_execution_delegate_migration_v1, CompleteExecutionDelegateMigrationV1Args,
+    BPF_LOADER_UPGRADEABLE_ID,
+};
+pub use count_execution_delegate_v1::{count_execution_delegate_v1, CountExecutionDelegateV1Args};
 pub use create_receipts_collection_v1::{
     cast_create_receipts_collection_args, create_receipts_collection_v1,
     CreateReceiptsCollectionV1Args,
 };
+pub use create_reviews_collection_v1::{
+    cast_create_reviews_collection_args, create_reviews_collection_v1,
+    CreateReviewsCollectionV1Args,
+};
 pub use delegate_execution_v1::{delegate_execution_v1, DelegateExecutionV1Args};
+pub use mint_review_v1::{mint_review_v1, MintReviewV1Args, MAX_REVIEW_URI_LEN};
 pub use mint_work_receipt_v1::{mint_work_receipt_v1, MintWorkReceiptV1Args, MAX_RECEIPT_URI_LEN};
 pub use register_executive_v1::{register_executive_v1, RegisterExecutiveV1Args};
 pub use register_receipts_tree_v1::{
     cast_register_receipts_tree_args, register_receipts_tree_v1, RegisterReceiptsTreeV1Args,
 };
+pub use register_reviews_tree_v1::{
+    cast_register_reviews_tree_args, register_reviews_tree_v1, RegisterReviewsTreeV1Args,
+};
 pub use revoke_execution_v1::{revoke_execution_v1, RevokeExecutionV1Args};
 
 /// Process incoming instructions.
@@ -78,6 +97,35 @@ pub fn process_instruction<'a>(
             let args = cast_close_work_receipt_args(instruction_data)?;
             close_work_receipt_v1(accounts, args)
         }
+        Ok(MplAgentToolsInstructionDiscriminant::CreateReviewsCollectionV1) => {
+            msg!("Instruction: CreateReviewsCollectionV1");
+            let args = cast_create_reviews_collection_args(instruction_data)?;
+            create_reviews_collection_v1(accounts, args)
+        }
+        Ok(MplAgentToolsInstructionDiscriminant::RegisterReviewsTreeV1) => {
+            msg!("Instruction: RegisterReviewsTreeV1");
+            let args = cast_register_reviews_tree_args(instruction_data)?;
+            register_reviews_tree_v1(accounts, args)
+        }
+        Ok(MplAgentToolsInstructionDiscriminant::MintReviewV1) => {
+            msg!("Instruction: MintReviewV1");
+            mint_review_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentToolsInstructionDiscriminant::CountExecutionDelegateV1) => {
+            msg!("Instruction: CountExecutionDelegateV1");
+            let args: &CountExecutionDelegateV1Args = bytemuck::from_bytes(
+                &instruction_data[..core::mem::size_of::<CountExecutionDelegateV1Args>()],
+            );
+            count_execution_delegate_v1(accounts, args)
+        }
+        Ok(MplAgentToolsInstructionDiscriminant::CompleteExecutionDelegateMigrationV1) => {
+            msg!("Instruction: CompleteExecutionDelegateMigrationV1");
+            let args: &CompleteExecutionDelegateMigrationV1Args = bytemuck::from_bytes(
+                &instruction_data
+                    [..core::mem::size_of::<CompleteExecutionDelegateMigrationV1Args>()],
+            );
+            complete_execution_delegate_migration_v1(accounts, args)
+        }
         Err(_) => Err(MplAgentToolsError::InvalidInstructionData.into()),
     }
 }
@@ -4,6 +4,7 @@ use shank::ShankType;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
 use solana_system_interface::program as system_program;
 
+use crate::events::MplAgentToolsEvent;
 use crate::{
     error::MplAgentToolsError, instruction::accounts::RegisterExecutiveV1Accounts,
     state::ExecutiveProfileV1,
@@ -82,7 +83,12 @@ pub fn register_executive_v1<'a>(
     let executive_profile: &mut ExecutiveProfileV1 =
         bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<ExecutiveProfileV1>()]);
 
-    executive_profile.initialize(ctx.accounts.authority.unwrap_or(ctx.accounts.payer).key);
+    let authority = *ctx.accounts.authority.unwrap_or(ctx.accounts.payer).key;
+    executive_profile.initialize(&authority);
 
-    Ok(())
+    MplAgentToolsEvent::ExecutiveRegisteredV1 {
+        executive_profile: *ctx.accounts.executive_profile.key,
+        authority,
+    }
+    .emit()
 }
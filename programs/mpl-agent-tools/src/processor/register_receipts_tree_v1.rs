@@ -8,6 +8,7 @@ use solana_program::{
 };
 use solana_system_interface::{instruction as system_instruction, program as system_program};
 
+use crate::events::MplAgentToolsEvent;
 use crate::{
     error::MplAgentToolsError,
     instruction::accounts::RegisterReceiptsTreeV1Accounts,
@@ -128,13 +129,19 @@ pub fn register_receipts_tree_v1<'a>(
         ctx.accounts.merkle_tree.key,
     );
 
-    Ok(())
+    MplAgentToolsEvent::ReceiptsTreeRegisteredV1 {
+        tree_index: args.tree_index,
+        merkle_tree: *ctx.accounts.merkle_tree.key,
+        max_depth: args.max_depth,
+        max_buffer_size: args.max_buffer_size,
+    }
+    .emit()
 }
 
 /// Compute the merkle tree account size in bytes, matching
 /// spl-concurrent-merkle-tree's struct layout. `Path<MAX_DEPTH>` has no
 /// leaf field — verified empirically against Bubblegum's canopy check.
-fn merkle_tree_account_size(
+pub(crate) fn merkle_tree_account_size(
     max_depth: usize,
     max_buffer_size: usize,
     canopy_depth: usize,
@@ -0,0 +1,139 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_bubblegum::{instructions::CreateTreeConfigV2CpiBuilder, ID as BUBBLEGUM_ID};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, msg, program::invoke_signed,
+    program_error::ProgramError, rent::Rent, sysvar::Sysvar,
+};
+use solana_system_interface::{instruction as system_instruction, program as system_program};
+
+use super::register_receipts_tree_v1::merkle_tree_account_size;
+use crate::events::MplAgentToolsEvent;
+use crate::{
+    error::MplAgentToolsError,
+    instruction::accounts::RegisterReviewsTreeV1Accounts,
+    state::{
+        check_reviews_authority_pda, check_reviews_tree_pda, REVIEWS_AUTHORITY_PREFIX,
+        REVIEWS_TREE_PREFIX,
+    },
+};
+
+/// MPL Account Compression program id (owner of the merkle tree account).
+const MPL_ACCOUNT_COMPRESSION_ID: solana_program::pubkey::Pubkey =
+    solana_program::pubkey!("mcmt6YrQEMKw8Mw43FmpRLmf7BqRnFMKmAcbxE3xkAW");
+
+/// Permissionless review tree registration. Same rules as
+/// `RegisterReceiptsTreeV1`: the caller picks an unused `tree_index` and
+/// pays the rent, the tree is created at PDA `["reviews_tree", index_le]`
+/// and Bubblegum is configured with `tree_creator = ["reviews_authority"]`
+/// PDA.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RegisterReviewsTreeV1Args {
+    #[skip]
+    pub discriminator: u8,
+    /// Padding to align the `u64` field.
+    #[padding]
+    pub _pad: [u8; 7],
+    pub tree_index: u64,
+    pub max_depth: u32,
+    pub max_buffer_size: u32,
+    pub canopy_depth: u32,
+    #[padding]
+    pub _pad2: [u8; 4],
+}
+const _: () = assert!(core::mem::size_of::<RegisterReviewsTreeV1Args>() == 32);
+
+pub fn register_reviews_tree_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &RegisterReviewsTreeV1Args,
+) -> ProgramResult {
+    let ctx = RegisterReviewsTreeV1Accounts::context(accounts)?;
+
+    assert_signer(ctx.accounts.payer)?;
+
+    if *ctx.accounts.bubblegum_program.key != BUBBLEGUM_ID {
+        return Err(MplAgentToolsError::InvalidBubblegumProgram.into());
+    }
+    if *ctx.accounts.compression_program.key != MPL_ACCOUNT_COMPRESSION_ID {
+        return Err(MplAgentToolsError::InvalidCompressionProgram.into());
+    }
+    if *ctx.accounts.system_program.key != system_program::id() {
+        return Err(MplAgentToolsError::InvalidSystemProgram.into());
+    }
+
+    let authority_bump = check_reviews_authority_pda(ctx.accounts.authority)?;
+
+    let tree_bump = check_reviews_tree_pda(ctx.accounts.merkle_tree, args.tree_index)?;
+    if ctx.accounts.merkle_tree.data_len() != 0
+        || *ctx.accounts.merkle_tree.owner != system_program::id()
+    {
+        return Err(MplAgentToolsError::InvalidAccountData.into());
+    }
+
+    let size = merkle_tree_account_size(
+        args.max_depth as usize,
+        args.max_buffer_size as usize,
+        args.canopy_depth as usize,
+    )?;
+    let rent_lamports = Rent::get()?.minimum_balance(size);
+
+    let index_bytes = args.tree_index.to_le_bytes();
+    let tree_seeds: &[&[u8]] = &[REVIEWS_TREE_PREFIX, &index_bytes, &[tree_bump]];
+    let authority_seeds: &[&[u8]] = &[REVIEWS_AUTHORITY_PREFIX, &[authority_bump]];
+
+    invoke_signed(
+        &system_instruction::create_account(
+            ctx.accounts.payer.key,
+            ctx.accounts.merkle_tree.key,
+            rent_lamports,
+            size as u64,
+            &MPL_ACCOUNT_COMPRESSION_ID,
+        ),
+        &[
+            ctx.accounts.payer.clone(),
+            ctx.accounts.merkle_tree.clone(),
+            ctx.accounts.system_program.clone(),
+        ],
+        &[tree_seeds],
+    )?;
+
+    CreateTreeConfigV2CpiBuilder::new(ctx.accounts.bubblegum_program)
+        .tree_config(ctx.accounts.tree_config)
+        .merkle_tree(ctx.accounts.merkle_tree)
+        .payer(ctx.accounts.payer)
+        .tree_creator(Some(ctx.accounts.authority))
+        .log_wrapper(ctx.accounts.log_wrapper)
+        .compression_program(ctx.accounts.compression_program)
+        .system_program(ctx.accounts.system_program)
+        .max_depth(args.max_depth)
+        .max_buffer_size(args.max_buffer_size)
+        .public(false)
+        .invoke_signed(&[authority_seeds])?;
+
+    msg!(
+        "Registered reviews tree #{} at {}",
+        args.tree_index,
+        ctx.accounts.merkle_tree.key,
+    );
+
+    MplAgentToolsEvent::ReviewsTreeRegisteredV1 {
+        tree_index: args.tree_index,
+        merkle_tree: *ctx.accounts.merkle_tree.key,
+        max_depth: args.max_depth,
+        max_buffer_size: args.max_buffer_size,
+    }
+    .emit()
+}
+
+pub fn cast_register_reviews_tree_args(
+    data: &[u8],
+) -> Result<&RegisterReviewsTreeV1Args, ProgramError> {
+    if data.len() < core::mem::size_of::<RegisterReviewsTreeV1Args>() {
+        return Err(MplAgentToolsError::InvalidInstructionData.into());
+    }
+    Ok(bytemuck::from_bytes(
+        &data[..core::mem::size_of::<RegisterReviewsTreeV1Args>()],
+    ))
+}
@@ -5,10 +5,11 @@ use shank::ShankType;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
 use solana_system_interface::program as system_program;
 
+use crate::events::MplAgentToolsEvent;
 use crate::{
     error::MplAgentToolsError,
     instruction::accounts::RevokeExecutionV1Accounts,
-    state::{ExecutionDelegateRecordV1, Key},
+    state::{ExecutionDelegateCountV1, ExecutionDelegateRecordV1, Key},
 };
 
 #[repr(C)]
@@ -50,7 +51,12 @@ pub fn revoke_execution_v1<'a>(
 
     // Read the delegate record via bytemuck and extract needed values.
     // We must drop the borrow before calling close_account which needs mut access.
-    let (record_executive_profile, record_authority, record_agent_asset): (Pubkey, Pubkey, Pubkey) = {
+    let (record_executive_profile, record_authority, record_agent_asset, record_counted): (
+        Pubkey,
+        Pubkey,
+        Pubkey,
+        bool,
+    ) = {
         let record_data = ctx.accounts.execution_delegate_record.try_borrow_data()?;
         let record: &ExecutionDelegateRecordV1 =
             from_bytes(&record_data[..core::mem::size_of::<ExecutionDelegateRecordV1>()]);
@@ -58,6 +64,7 @@ pub fn revoke_execution_v1<'a>(
             record.executive_profile,
             record.authority,
             record.agent_asset,
+            record.counted != 0,
         )
     };
 
@@ -73,6 +80,12 @@ pub fn revoke_execution_v1<'a>(
         ctx.accounts.agent_asset.key,
     )?;
 
+    // Check the delegate count of the agent asset.
+    ExecutionDelegateCountV1::check(
+        ctx.accounts.execution_delegate_count,
+        ctx.accounts.agent_asset.key,
+    )?;
+
     // Assert that the agent asset is a valid MPL Core asset.
     if ctx.accounts.agent_asset.owner != &mpl_core::ID
         || ctx.accounts.agent_asset.data_len() == 0
@@ -112,11 +125,22 @@ pub fn revoke_execution_v1<'a>(
     /********************* Actions **********************/
     /****************************************************/
 
+    // Stop tracking the delegate record. Records delegated before
+    // delegations were counted are only in the count once backfilled.
+    if record_counted {
+        ExecutionDelegateCountV1::decrement(ctx.accounts.execution_delegate_count)?;
+    }
+
     // Close the execution delegate record and refund rent to destination.
     close_account_raw(
         ctx.accounts.destination,
         ctx.accounts.execution_delegate_record,
     )?;
 
-    Ok(())
+    MplAgentToolsEvent::ExecutionRevokedV1 {
+        agent_asset: record_agent_asset,
+        executive_profile: record_executive_profile,
+        execution_delegate_record: *ctx.accounts.execution_delegate_record.key,
+    }
+    .emit()
 }
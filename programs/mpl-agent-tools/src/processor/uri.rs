@@ -0,0 +1,31 @@
+//! Parsing of the URIs carried as trailing Borsh strings in receipt and
+//! review instructions.
+
+use solana_program::program_error::ProgramError;
+
+use crate::error::MplAgentToolsError;
+
+/// Parse a Borsh-style length-prefixed UTF-8 string from `data`:
+/// `[u32 LE length][bytes...]`. Rejects with `invalid` if the declared
+/// length exceeds `max_len` or overruns the buffer, and with
+/// `InvalidInstructionData` if the buffer is too short or the bytes aren't
+/// valid UTF-8.
+pub fn read_length_prefixed_string(
+    data: &[u8],
+    max_len: usize,
+    invalid: MplAgentToolsError,
+) -> Result<String, ProgramError> {
+    if data.len() < 4 {
+        return Err(MplAgentToolsError::InvalidInstructionData.into());
+    }
+    let len = u32::from_le_bytes(
+        data[..4]
+            .try_into()
+            .map_err(|_| MplAgentToolsError::InvalidInstructionData)?,
+    ) as usize;
+    if len > max_len || data.len() < 4 + len {
+        return Err(invalid.into());
+    }
+    String::from_utf8(data[4..4 + len].to_vec())
+        .map_err(|_| MplAgentToolsError::InvalidInstructionData.into())
+}
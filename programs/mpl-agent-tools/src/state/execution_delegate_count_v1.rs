@@ -0,0 +1,127 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{error::MplAgentToolsError, state::Key};
+
+/// Number of counted `ExecutionDelegateRecordV1` accounts for an agent asset.
+/// Other programs (e.g. mpl-agent-identity) read `count` to check whether an
+/// agent still has active execution delegates.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ExecutionDelegateCountV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the agent asset.
+    pub agent_asset: Pubkey,
+    /// The number of open execution delegate records for the agent asset.
+    pub count: u64,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ExecutionDelegateCountV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ExecutionDelegateCountV1>() == 48);
+
+impl ExecutionDelegateCountV1 {
+    pub const PREFIX: &[u8] = b"execution_delegate_count";
+
+    // Check the PDA derivation.
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        agent_asset: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, agent_asset.as_ref()],
+            MplAgentToolsError::InvalidExecutionDelegateCountDerivation,
+        )
+    }
+
+    /// Check the account is the count of the agent asset, either initialized
+    /// or not yet created. Returns the PDA bump seed.
+    pub fn check(address: &AccountInfo, agent_asset: &Pubkey) -> Result<u8, ProgramError> {
+        let bump = Self::check_pda_derivation(address, agent_asset)?;
+
+        if address.data_len() > 0
+            && (address.owner != &crate::ID
+                || address.try_borrow_data()?[0] != Key::ExecutionDelegateCountV1 as u8)
+        {
+            return Err(MplAgentToolsError::InvalidExecutionDelegateCount.into());
+        }
+
+        Ok(bump)
+    }
+
+    /// Count a delegate record, creating the account on the first one.
+    /// Returns the new count.
+    pub fn increment<'a>(
+        execution_delegate_count: &AccountInfo<'a>,
+        agent_asset: &Pubkey,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        bump: u8,
+    ) -> Result<u64, ProgramError> {
+        if execution_delegate_count.data_len() == 0 {
+            create_or_allocate_account_raw(
+                crate::ID,
+                execution_delegate_count,
+                system_program,
+                payer,
+                core::mem::size_of::<ExecutionDelegateCountV1>(),
+                &[Self::PREFIX, agent_asset.as_ref(), &[bump]],
+            )?;
+
+            let mut data = execution_delegate_count.try_borrow_mut_data()?;
+            let count: &mut ExecutionDelegateCountV1 = bytemuck::from_bytes_mut(
+                &mut data[..core::mem::size_of::<ExecutionDelegateCountV1>()],
+            );
+            count.initialize(bump, agent_asset);
+        }
+
+        let mut data = execution_delegate_count.try_borrow_mut_data()?;
+        let count: &mut ExecutionDelegateCountV1 =
+            bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<ExecutionDelegateCountV1>()]);
+        count.count = count.count.saturating_add(1);
+
+        Ok(count.count)
+    }
+
+    /// Stop counting a counted delegate record.
+    pub fn decrement(execution_delegate_count: &AccountInfo) -> ProgramResult {
+        if execution_delegate_count.data_len() == 0 {
+            return Err(MplAgentToolsError::InvalidExecutionDelegateCount.into());
+        }
+
+        let mut data = execution_delegate_count.try_borrow_mut_data()?;
+        let count: &mut ExecutionDelegateCountV1 =
+            bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<ExecutionDelegateCountV1>()]);
+        count.count = count
+            .count
+            .checked_sub(1)
+            .ok_or(MplAgentToolsError::InvalidExecutionDelegateCount)?;
+
+        Ok(())
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, agent_asset: &Pubkey) {
+        solana_program::msg!("Initializing execution delegate count account");
+        self.key = Key::ExecutionDelegateCountV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.agent_asset = *agent_asset;
+        self.count = 0;
+    }
+}
@@ -0,0 +1,75 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{error::MplAgentToolsError, state::Key};
+
+/// Marks the backfill of execution delegate records made before delegations
+/// were counted as complete. Once it exists, every `ExecutionDelegateRecordV1`
+/// is counted in its agent asset's `ExecutionDelegateCountV1`, so other
+/// programs (e.g. mpl-agent-identity) can rely on the count.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ExecutionDelegateMigrationV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The upgrade authority that completed the migration.
+    pub authority: Pubkey,
+    /// Slot at which the migration was completed.
+    pub slot: u64,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ExecutionDelegateMigrationV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ExecutionDelegateMigrationV1>() == 48);
+
+impl ExecutionDelegateMigrationV1 {
+    pub const PREFIX: &[u8] = b"execution_delegate_migration";
+
+    // Check the PDA derivation.
+    pub fn check_pda_derivation(address: &AccountInfo) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX],
+            MplAgentToolsError::InvalidExecutionDelegateMigration,
+        )
+    }
+
+    // Create the account.
+    pub fn create_account<'a>(
+        execution_delegate_migration: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            execution_delegate_migration,
+            system_program,
+            payer,
+            core::mem::size_of::<ExecutionDelegateMigrationV1>(),
+            &[Self::PREFIX, &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, authority: &Pubkey, slot: u64) {
+        self.key = Key::ExecutionDelegateMigrationV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.authority = *authority;
+        self.slot = slot;
+    }
+}
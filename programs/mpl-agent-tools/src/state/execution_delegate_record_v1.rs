@@ -18,9 +18,13 @@ pub struct ExecutionDelegateRecordV1 {
     pub key: u8,
     /// PDA bump seed.
     pub bump: u8,
+    /// Whether the record is counted in the agent asset's
+    /// `ExecutionDelegateCountV1`. Records delegated before delegations were
+    /// counted are not counted until backfilled.
+    pub counted: u8,
     /// Padding for 8-byte alignment.
     #[padding]
-    pub _padding: [u8; 6],
+    pub _padding: [u8; 5],
     /// The address of the executive profile.
     pub executive_profile: Pubkey,
     /// The address of the authority signer for the executive.
@@ -83,7 +87,8 @@ impl ExecutionDelegateRecordV1 {
         solana_program::msg!("Initializing execution delegate record account");
         self.key = Key::ExecutionDelegateRecordV1 as u8;
         self.bump = bump;
-        self._padding = [0u8; 6];
+        self.counted = 1;
+        self._padding = [0u8; 5];
         self.executive_profile = *executive_profile;
         self.agent_asset = *agent_asset;
         self.authority = *authority;
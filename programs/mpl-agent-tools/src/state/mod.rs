@@ -1,22 +1,31 @@
+mod execution_delegate_count_v1;
+mod execution_delegate_migration_v1;
 mod execution_delegate_record_v1;
 mod executive_profile_v1;
+mod receipt_review_v1;
 mod seeds;
 
+pub use execution_delegate_count_v1::*;
+pub use execution_delegate_migration_v1::*;
 pub use execution_delegate_record_v1::*;
 pub use executive_profile_v1::*;
+pub use receipt_review_v1::*;
 pub use seeds::*;
 
 use shank::ShankType;
 
-/// Account discriminator enum for the two on-chain record accounts in
-/// this program. Receipts collection/authority/tree are stateless PDAs
-/// without their own discriminator.
+/// Account discriminator enum for the on-chain record accounts in this
+/// program. Receipts and reviews collection/authority/tree are stateless
+/// PDAs without their own discriminator.
 #[repr(u8)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, ShankType)]
 pub enum Key {
     Uninitialized,
     ExecutiveProfileV1,
     ExecutionDelegateRecordV1,
+    ExecutionDelegateCountV1,
+    ReceiptReviewV1,
+    ExecutionDelegateMigrationV1,
 }
 
 impl From<u8> for Key {
@@ -25,6 +34,9 @@ impl From<u8> for Key {
             0 => Key::Uninitialized,
             1 => Key::ExecutiveProfileV1,
             2 => Key::ExecutionDelegateRecordV1,
+            3 => Key::ExecutionDelegateCountV1,
+            4 => Key::ReceiptReviewV1,
+            5 => Key::ExecutionDelegateMigrationV1,
             _ => Key::Uninitialized,
         }
     }
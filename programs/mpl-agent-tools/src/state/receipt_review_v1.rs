@@ -0,0 +1,103 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{error::MplAgentToolsError, instruction::accounts::MintReviewV1Accounts, state::Key};
+
+/// Links a work receipt to the review cNFT minted for it. Keyed on the
+/// receipt's `(tree_index, nonce)`, so a receipt can be reviewed once.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ReceiptReviewV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the reviewed agent asset.
+    pub agent_asset: Pubkey,
+    /// The author of the review and owner of the receipt.
+    pub reviewer: Pubkey,
+    /// Bubblegum asset id of the reviewed work receipt.
+    pub receipt: Pubkey,
+    /// Index of the reviews tree the review lives in.
+    pub review_tree_index: u64,
+    /// Review leaf's nonce within its tree.
+    pub review_nonce: u64,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ReceiptReviewV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ReceiptReviewV1>() == 120);
+
+impl ReceiptReviewV1 {
+    pub const PREFIX: &[u8] = b"receipt_review";
+
+    // Check the PDA derivation.
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        receipts_tree_index: u64,
+        receipt_nonce: u64,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[
+                Self::PREFIX,
+                &receipts_tree_index.to_le_bytes(),
+                &receipt_nonce.to_le_bytes(),
+            ],
+            MplAgentToolsError::InvalidReceiptReviewDerivation,
+        )
+    }
+
+    // Create the account.
+    pub fn create_account(
+        accounts: &MintReviewV1Accounts,
+        receipts_tree_index: u64,
+        receipt_nonce: u64,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            accounts.receipt_review,
+            accounts.system_program,
+            accounts.payer,
+            core::mem::size_of::<ReceiptReviewV1>(),
+            &[
+                Self::PREFIX,
+                &receipts_tree_index.to_le_bytes(),
+                &receipt_nonce.to_le_bytes(),
+                &[bump],
+            ],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        agent_asset: &Pubkey,
+        reviewer: &Pubkey,
+        receipt: &Pubkey,
+        review_tree_index: u64,
+        review_nonce: u64,
+    ) {
+        self.key = Key::ReceiptReviewV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.agent_asset = *agent_asset;
+        self.reviewer = *reviewer;
+        self.receipt = *receipt;
+        self.review_tree_index = review_tree_index;
+        self.review_nonce = review_nonce;
+    }
+}
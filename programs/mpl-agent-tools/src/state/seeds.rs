@@ -1,5 +1,5 @@
-//! Stateless PDA derivations for the receipts collection, its program
-//! signing authority, and the per-tree merkle accounts.
+//! Stateless PDA derivations for the receipts and reviews collections,
+//! their program signing authorities, and the per-tree merkle accounts.
 //!
 //! Nothing in this module corresponds to a stored account — these are
 //! all derivable from the program id and constant seeds. The program
@@ -54,3 +54,47 @@ pub fn check_receipts_tree_pda(address: &AccountInfo, index: u64) -> Result<u8,
         MplAgentToolsError::InvalidReceiptsTreeDerivation,
     )
 }
+
+/// Seeds for the canonical reviews collection (the MPL Core collection
+/// asset every review cNFT lives in).
+pub const REVIEWS_COLLECTION_PREFIX: &[u8] = b"reviews_collection";
+
+/// Seeds for the program's reviews signing authority. Plays the same role
+/// for the reviews collection and trees as `receipts_authority` does for
+/// receipts.
+pub const REVIEWS_AUTHORITY_PREFIX: &[u8] = b"reviews_authority";
+
+/// Seeds for per-tree review merkle accounts: `["reviews_tree", index_le]`.
+pub const REVIEWS_TREE_PREFIX: &[u8] = b"reviews_tree";
+
+/// Verify the supplied account is at the canonical reviews collection
+/// PDA and return its bump.
+pub fn check_reviews_collection_pda(address: &AccountInfo) -> Result<u8, ProgramError> {
+    assert_derivation(
+        &crate::ID,
+        address,
+        &[REVIEWS_COLLECTION_PREFIX],
+        MplAgentToolsError::InvalidReviewsCollection,
+    )
+}
+
+/// Verify the supplied account is at the canonical reviews authority
+/// PDA and return its bump.
+pub fn check_reviews_authority_pda(address: &AccountInfo) -> Result<u8, ProgramError> {
+    assert_derivation(
+        &crate::ID,
+        address,
+        &[REVIEWS_AUTHORITY_PREFIX],
+        MplAgentToolsError::InvalidReviewsAuthority,
+    )
+}
+
+/// Verify the supplied account is the reviews tree PDA for `index`.
+pub fn check_reviews_tree_pda(address: &AccountInfo, index: u64) -> Result<u8, ProgramError> {
+    assert_derivation(
+        &crate::ID,
+        address,
+        &[REVIEWS_TREE_PREFIX, &index.to_le_bytes()],
+        MplAgentToolsError::InvalidReviewsTreeDerivation,
+    )
+}
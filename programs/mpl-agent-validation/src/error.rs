@@ -27,6 +27,174 @@ pub enum MplAgentValidationError {
     /// 5 - Agent Validation already registered
     #[error("Agent Validation already registered")]
     AgentValidationAlreadyRegistered,
+
+    /// 6 - Asset has no agent validation
+    #[error("Asset has no agent validation")]
+    AgentValidationNotRegistered,
+
+    /// 7 - Invalid validation request account
+    #[error("Invalid validation request account")]
+    InvalidValidationRequest,
+
+    /// 8 - Validation request already exists
+    #[error("Validation request already exists")]
+    ValidationRequestAlreadyExists,
+
+    /// 9 - Work hash must be non-zero
+    #[error("Work hash must be non-zero")]
+    EmptyWorkHash,
+
+    /// 10 - Requester cannot validate their own request
+    #[error("Requester cannot validate their own request")]
+    RequesterCannotValidate,
+
+    /// 11 - Only the designated validator can respond
+    #[error("Only the designated validator can respond")]
+    OnlyDesignatedValidatorCanRespond,
+
+    /// 12 - Validation was already responded to
+    #[error("Validation was already responded to")]
+    ValidationAlreadyResponded,
+
+    /// 13 - Validation result exceeds the maximum of 100
+    #[error("Validation result exceeds the maximum of 100")]
+    InvalidValidationResult,
+
+    /// 14 - Evidence URI must not be empty
+    #[error("Evidence URI must not be empty")]
+    EvidenceUriEmpty,
+
+    /// 15 - Evidence URI exceeds the maximum length
+    #[error("Evidence URI exceeds the maximum length")]
+    EvidenceUriTooLong,
+
+    /// 16 - Evidence URI is not valid UTF-8
+    #[error("Evidence URI is not valid UTF-8")]
+    EvidenceUriNotUtf8,
+
+    /// 17 - Evidence URI scheme is not allowed
+    #[error("Evidence URI scheme is not allowed")]
+    EvidenceUriSchemeNotAllowed,
+
+    /// 18 - Invalid validation config account
+    #[error("Invalid validation config account")]
+    InvalidValidationConfig,
+
+    /// 19 - Validation config already initialized
+    #[error("Validation config already initialized")]
+    ValidationConfigAlreadyInitialized,
+
+    /// 20 - Invalid program data account
+    #[error("Invalid program data account")]
+    InvalidProgramData,
+
+    /// 21 - Signer is not the config authority
+    #[error("Signer is not the config authority")]
+    InvalidConfigAuthority,
+
+    /// 22 - Unbonding period must be non-zero
+    #[error("Unbonding period must be non-zero")]
+    InvalidUnbondingPeriod,
+
+    /// 23 - Invalid validator profile account
+    #[error("Invalid validator profile account")]
+    InvalidValidatorProfile,
+
+    /// 24 - Validator already registered
+    #[error("Validator already registered")]
+    ValidatorAlreadyRegistered,
+
+    /// 25 - Validator is not registered
+    #[error("Validator is not registered")]
+    ValidatorNotRegistered,
+
+    /// 26 - Invalid validator stake vault account
+    #[error("Invalid validator stake vault account")]
+    InvalidValidatorStakeVault,
+
+    /// 27 - Stake amount must be non-zero
+    #[error("Stake amount must be non-zero")]
+    InvalidStakeAmount,
+
+    /// 28 - Validator does not have enough stake
+    #[error("Validator does not have enough stake")]
+    InsufficientValidatorStake,
+
+    /// 29 - Validator has no unbonding stake
+    #[error("Validator has no unbonding stake")]
+    NoUnbondingStake,
+
+    /// 30 - Stake is still unbonding
+    #[error("Stake is still unbonding")]
+    StakeStillUnbonding,
+
+    /// 31 - Signer is not the arbiter
+    #[error("Signer is not the arbiter")]
+    InvalidArbiter,
+
+    /// 32 - Numerical overflow
+    #[error("Numerical overflow")]
+    NumericalOverflow,
+
+    /// 33 - Invalid accepted validator account
+    #[error("Invalid accepted validator account")]
+    InvalidAcceptedValidator,
+
+    /// 34 - Validator already accepted
+    #[error("Validator already accepted")]
+    ValidatorAlreadyAccepted,
+
+    /// 35 - Validator is not accepted by the agent
+    #[error("Validator is not accepted by the agent")]
+    ValidatorNotAccepted,
+
+    /// 36 - Signer does not own the agent asset
+    #[error("Signer does not own the agent asset")]
+    InvalidAgentAuthority,
+
+    /// 37 - Invalid validator history account
+    #[error("Invalid validator history account")]
+    InvalidValidatorHistory,
+
+    /// 38 - Asset does not match the validation request
+    #[error("Asset does not match the validation request")]
+    AssetMismatch,
+
+    /// 39 - Invalid validator attester account
+    #[error("Invalid validator attester account")]
+    InvalidValidatorAttester,
+
+    /// 40 - Attester already added
+    #[error("Attester already added")]
+    AttesterAlreadyAdded,
+
+    /// 41 - Invalid instructions sysvar
+    #[error("Invalid instructions sysvar")]
+    InvalidInstructionsSysvar,
+
+    /// 42 - Attestation must be verified by an Ed25519 program instruction right before
+    #[error("Attestation must be verified by an Ed25519 program instruction right before")]
+    MissingAttestation,
+
+    /// 43 - Attestation does not match the attester or the response
+    #[error("Attestation does not match the attester or the response")]
+    InvalidAttestation,
+
+    /// 44 - Validation request is expired
+    #[error("Validation request is expired")]
+    ValidationRequestExpired,
+
+    /// 45 - Validation request is not expired yet
+    #[error("Validation request is not expired yet")]
+    ValidationRequestNotExpired,
+
+    /// 46 - Expiry slot must be in the future and within the maximum expiry
+    #[error("Expiry slot must be in the future and within the maximum expiry")]
+    InvalidExpirySlot,
+
+    /// 47 - Only the requester can reclaim the validation request
+    #[error("Only the requester can reclaim the validation request")]
+    OnlyRequesterCanReclaim,
 }
 
 impl From<MplAgentValidationError> for ProgramError {
@@ -0,0 +1,122 @@
+use borsh::{BorshDeserialize, BorshSerialize};
+use solana_program::{entrypoint::ProgramResult, log::sol_log_data, pubkey::Pubkey};
+
+/// Version of the event encoding. Emitted as the first byte of every event so
+/// indexers can reject layouts they don't understand.
+pub const EVENT_VERSION: u8 = 1;
+
+/// Events emitted by the program via `sol_log_data`. Each log entry is a
+/// single data field: `[EVENT_VERSION, borsh(MplAgentValidationEvent)...]`.
+///
+/// New variants must be appended to keep existing variant indices stable.
+#[derive(Clone, Debug, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub enum MplAgentValidationEvent {
+    /// A validation of a piece of agent work was requested.
+    ValidationRequestedV1 {
+        asset: Pubkey,
+        nonce: u64,
+        requester: Pubkey,
+        validator: Pubkey,
+        work_hash: [u8; 32],
+    },
+    /// The designated validator recorded the result of a validation.
+    ValidationRespondedV1 {
+        asset: Pubkey,
+        nonce: u64,
+        validator: Pubkey,
+        result: u8,
+        evidence_uri: String,
+        evidence_hash: [u8; 32],
+    },
+    /// The validation config was initialized or updated.
+    ValidationConfigUpdatedV1 {
+        authority: Pubkey,
+        arbiter: Pubkey,
+        unbonding_slots: u64,
+    },
+    /// A validator registered, escrowing `stake` lamports.
+    ValidatorRegisteredV1 { validator: Pubkey, stake: u64 },
+    /// A validator started unbonding `amount` lamports of stake.
+    ValidatorStakeUnbondingV1 {
+        validator: Pubkey,
+        amount: u64,
+        unbonding_stake: u64,
+        unbonding_slot: u64,
+    },
+    /// A validator withdrew its unbonded stake.
+    ValidatorStakeWithdrawnV1 { validator: Pubkey, amount: u64 },
+    /// The arbiter slashed `amount` lamports of validator stake.
+    ValidatorSlashedV1 {
+        validator: Pubkey,
+        arbiter: Pubkey,
+        amount: u64,
+        stake: u64,
+        unbonding_stake: u64,
+    },
+    /// An agent accepted a validator.
+    ValidatorAcceptedV1 {
+        asset: Pubkey,
+        validator: Pubkey,
+        accepted_validator_count: u32,
+    },
+    /// An agent removed a validator from the validators it accepts.
+    ValidatorRemovedV1 {
+        asset: Pubkey,
+        validator: Pubkey,
+        accepted_validator_count: u32,
+    },
+    /// A validator added an attester key.
+    ValidatorAttesterAddedV1 {
+        validator: Pubkey,
+        attester: Pubkey,
+        attester_count: u64,
+    },
+    /// A validator removed an attester key.
+    ValidatorAttesterRemovedV1 {
+        validator: Pubkey,
+        attester: Pubkey,
+        attester_count: u64,
+    },
+    /// The designated validator recorded the result of a validation backed
+    /// by an attestation of one of its attester keys.
+    ValidationAttestedV1 {
+        asset: Pubkey,
+        nonce: u64,
+        validator: Pubkey,
+        attester: Pubkey,
+        result: u8,
+        evidence_hash: [u8; 32],
+    },
+    /// A requester escrowed a reward for the validator of a request.
+    ValidationRewardEscrowedV1 {
+        asset: Pubkey,
+        nonce: u64,
+        requester: Pubkey,
+        reward: u64,
+        expiry_slot: u64,
+    },
+    /// The validator of a request claimed its reward by responding.
+    ValidationRewardClaimedV1 {
+        asset: Pubkey,
+        nonce: u64,
+        validator: Pubkey,
+        reward: u64,
+    },
+    /// The requester closed an expired request, reclaiming its reward.
+    ValidationRequestReclaimedV1 {
+        asset: Pubkey,
+        nonce: u64,
+        requester: Pubkey,
+        reward: u64,
+    },
+}
+
+impl MplAgentValidationEvent {
+    /// Serialize the event and write it to the program log.
+    pub fn emit(&self) -> ProgramResult {
+        let mut data = vec![EVENT_VERSION];
+        borsh::to_writer(&mut data, self)?;
+        sol_log_data(&[&data]);
+        Ok(())
+    }
+}
@@ -1,6 +1,12 @@
 use shank::{ShankContext, ShankInstruction};
 
-use crate::processor::RegisterValidationV1Args;
+use crate::processor::{
+    AddAcceptedValidatorV1Args, AddValidatorAttesterV1Args, InitializeValidationConfigV1Args,
+    ReclaimValidationRequestV1Args, RegisterValidationV1Args, RegisterValidatorV1Args,
+    RemoveAcceptedValidatorV1Args, RemoveValidatorAttesterV1Args, RequestValidationV1Args,
+    RespondValidationAttestationV1Args, RespondValidationV1Args, SlashValidatorV1Args,
+    UnbondValidatorStakeV1Args, UpdateValidationConfigV1Args, WithdrawValidatorStakeV1Args,
+};
 
 /// Instruction discriminants for routing.
 /// The first byte of instruction data determines which instruction to execute.
@@ -8,6 +14,20 @@ use crate::processor::RegisterValidationV1Args;
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub enum MplAgentValidationInstructionDiscriminant {
     RegisterValidationV1 = 0,
+    RequestValidationV1 = 1,
+    RespondValidationV1 = 2,
+    InitializeValidationConfigV1 = 3,
+    UpdateValidationConfigV1 = 4,
+    RegisterValidatorV1 = 5,
+    UnbondValidatorStakeV1 = 6,
+    WithdrawValidatorStakeV1 = 7,
+    SlashValidatorV1 = 8,
+    AddAcceptedValidatorV1 = 9,
+    RemoveAcceptedValidatorV1 = 10,
+    AddValidatorAttesterV1 = 11,
+    RemoveValidatorAttesterV1 = 12,
+    RespondValidationAttestationV1 = 13,
+    ReclaimValidationRequestV1 = 14,
 }
 
 impl TryFrom<u8> for MplAgentValidationInstructionDiscriminant {
@@ -16,6 +36,20 @@ impl TryFrom<u8> for MplAgentValidationInstructionDiscriminant {
     fn try_from(value: u8) -> Result<Self, Self::Error> {
         match value {
             0 => Ok(MplAgentValidationInstructionDiscriminant::RegisterValidationV1),
+            1 => Ok(MplAgentValidationInstructionDiscriminant::RequestValidationV1),
+            2 => Ok(MplAgentValidationInstructionDiscriminant::RespondValidationV1),
+            3 => Ok(MplAgentValidationInstructionDiscriminant::InitializeValidationConfigV1),
+            4 => Ok(MplAgentValidationInstructionDiscriminant::UpdateValidationConfigV1),
+            5 => Ok(MplAgentValidationInstructionDiscriminant::RegisterValidatorV1),
+            6 => Ok(MplAgentValidationInstructionDiscriminant::UnbondValidatorStakeV1),
+            7 => Ok(MplAgentValidationInstructionDiscriminant::WithdrawValidatorStakeV1),
+            8 => Ok(MplAgentValidationInstructionDiscriminant::SlashValidatorV1),
+            9 => Ok(MplAgentValidationInstructionDiscriminant::AddAcceptedValidatorV1),
+            10 => Ok(MplAgentValidationInstructionDiscriminant::RemoveAcceptedValidatorV1),
+            11 => Ok(MplAgentValidationInstructionDiscriminant::AddValidatorAttesterV1),
+            12 => Ok(MplAgentValidationInstructionDiscriminant::RemoveValidatorAttesterV1),
+            13 => Ok(MplAgentValidationInstructionDiscriminant::RespondValidationAttestationV1),
+            14 => Ok(MplAgentValidationInstructionDiscriminant::ReclaimValidationRequestV1),
             _ => Err(()),
         }
     }
@@ -36,4 +70,128 @@ pub enum MplAgentValidationInstruction {
     #[account(5, name="mpl_core_program", desc = "The MPL Core program")]
     #[account(6, name="system_program", desc = "The system program")]
     RegisterValidationV1(RegisterValidationV1Args),
+
+    /// Request a registered validator to independently verify a piece of agent work. The agent or any of its clients may request. If the agent whitelists validators, the validator must be one of them. Creates a validation request PDA keyed on the asset and a requester-chosen nonce, escrowing the reward of the validator until it responds or the request expires.
+    #[account(0, writable, name="validation_request", desc = "The validation request PDA at [\"validation_request\", asset, nonce_le]")]
+    #[account(1, name="agent_validation", desc = "The agent validation PDA of the asset")]
+    #[account(2, name="validator_profile", desc = "The validator profile PDA of the requested validator")]
+    #[account(3, optional, name="accepted_validator", desc = "The accepted validator PDA of the requested validator, if the agent whitelists validators")]
+    #[account(4, name="asset", desc = "The address of the Core asset")]
+    #[account(5, writable, signer, name="requester", desc = "The agent or client requesting the validation, funding the reward")]
+    #[account(6, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(7, name="system_program", desc = "The system program")]
+    RequestValidationV1(RequestValidationV1Args),
+
+    /// Record the result of a validation. Only the validator designated in the request may respond, once, before the request expires, while it is still registered with the stake it had when requested and, if the agent accepts only some validators, still accepted. Pays the validator the reward escrowed in the request. If the agent requested the validation or accepts the validator, adds the result to the agent's validation aggregates and writes their summary to the asset's AppData plugin.
+    #[account(0, writable, name="validation_request", desc = "The validation request PDA")]
+    #[account(1, writable, name="agent_validation", desc = "The agent validation PDA of the asset")]
+    #[account(2, writable, name="validator_history", desc = "The validator history PDA at [\"validator_history\", asset, validator]")]
+    #[account(3, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(4, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(5, writable, signer, name="validator", desc = "The validator designated in the request, receiving the reward")]
+    #[account(6, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(7, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(8, name="system_program", desc = "The system program")]
+    #[account(9, name="validator_profile", desc = "The validator profile PDA of the validator")]
+    #[account(10, optional, name="accepted_validator", desc = "The accepted validator PDA at [\"accepted_validator\", asset, validator], required if the agent accepts any validator")]
+    RespondValidationV1(RespondValidationV1Args),
+
+    /// Initialize the validation config. Only the upgrade authority of the program may initialize it, and becomes the config authority.
+    #[account(0, writable, name="validation_config", desc = "The validation config PDA at [\"validation_config\"]")]
+    #[account(1, name="program_data", desc = "The program data account of this program")]
+    #[account(2, signer, name="authority", desc = "The upgrade authority of this program")]
+    #[account(3, name="arbiter", desc = "The authority allowed to slash validator stake")]
+    #[account(4, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(5, name="system_program", desc = "The system program")]
+    InitializeValidationConfigV1(InitializeValidationConfigV1Args),
+
+    /// Update the validation config, optionally handing it to a new authority or the slashing to a new arbiter.
+    #[account(0, writable, name="validation_config", desc = "The validation config PDA at [\"validation_config\"]")]
+    #[account(1, signer, name="authority", desc = "The config authority")]
+    #[account(2, optional, name="new_authority", desc = "The new config authority, if it changes")]
+    #[account(3, optional, name="new_arbiter", desc = "The new arbiter, if it changes")]
+    UpdateValidationConfigV1(UpdateValidationConfigV1Args),
+
+    /// Register a validator, escrowing optional stake in its stake vault.
+    #[account(0, writable, name="validator_profile", desc = "The validator profile PDA at [\"validator_profile\", validator]")]
+    #[account(1, writable, name="validator_stake_vault", desc = "The validator stake vault PDA at [\"validator_stake_vault\", validator]")]
+    #[account(2, writable, signer, name="validator", desc = "The validator authority, funding the stake")]
+    #[account(3, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(4, name="system_program", desc = "The system program")]
+    RegisterValidatorV1(RegisterValidatorV1Args),
+
+    /// Start unbonding validator stake. The stake stays slashable until the unbonding period is over, and each unbonding restarts the period.
+    #[account(0, writable, name="validator_profile", desc = "The validator profile PDA")]
+    #[account(1, name="validation_config", desc = "The validation config PDA at [\"validation_config\"], initialized or not")]
+    #[account(2, signer, name="validator", desc = "The validator authority")]
+    UnbondValidatorStakeV1(UnbondValidatorStakeV1Args),
+
+    /// Withdraw the unbonding stake of a validator once the unbonding period is over.
+    #[account(0, writable, name="validator_profile", desc = "The validator profile PDA")]
+    #[account(1, writable, name="validator_stake_vault", desc = "The validator stake vault PDA")]
+    #[account(2, writable, signer, name="validator", desc = "The validator authority, receiving the stake")]
+    WithdrawValidatorStakeV1(WithdrawValidatorStakeV1Args),
+
+    /// Slash validator stake, bonded first and unbonding after. Only the arbiter of the validation config may slash.
+    #[account(0, writable, name="validator_profile", desc = "The validator profile PDA")]
+    #[account(1, writable, name="validator_stake_vault", desc = "The validator stake vault PDA")]
+    #[account(2, name="validation_config", desc = "The validation config PDA at [\"validation_config\"]")]
+    #[account(3, signer, name="arbiter", desc = "The arbiter of the validation config")]
+    #[account(4, writable, name="destination", desc = "The account receiving the slashed stake")]
+    SlashValidatorV1(SlashValidatorV1Args),
+
+    /// Whitelist a validator for an agent. Once an agent accepts any validator, validation can only be requested from the validators it accepts. Only the asset owner may update the whitelist.
+    #[account(0, writable, name="accepted_validator", desc = "The accepted validator PDA at [\"accepted_validator\", asset, validator]")]
+    #[account(1, writable, name="agent_validation", desc = "The agent validation PDA of the asset")]
+    #[account(2, name="asset", desc = "The address of the Core asset")]
+    #[account(3, signer, name="authority", desc = "The owner of the asset")]
+    #[account(4, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(5, name="system_program", desc = "The system program")]
+    AddAcceptedValidatorV1(AddAcceptedValidatorV1Args),
+
+    /// Remove a validator from the whitelist of an agent. Removing the last one opens the agent to any registered validator again.
+    #[account(0, writable, name="accepted_validator", desc = "The accepted validator PDA")]
+    #[account(1, writable, name="agent_validation", desc = "The agent validation PDA of the asset")]
+    #[account(2, name="asset", desc = "The address of the Core asset")]
+    #[account(3, signer, name="authority", desc = "The owner of the asset")]
+    #[account(4, writable, name="destination", desc = "The account receiving the rent")]
+    RemoveAcceptedValidatorV1(RemoveAcceptedValidatorV1Args),
+
+    /// Add an ed25519 attester key, such as the key of a TEE enclave, whose attestations the validator may respond with. The attester must sign to prove possession of the key.
+    #[account(0, writable, name="validator_attester", desc = "The validator attester PDA at [\"validator_attester\", validator, attester]")]
+    #[account(1, writable, name="validator_profile", desc = "The validator profile PDA")]
+    #[account(2, signer, name="validator", desc = "The validator authority")]
+    #[account(3, signer, name="attester", desc = "The ed25519 attester key, signing as proof of possession")]
+    #[account(4, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(5, name="system_program", desc = "The system program")]
+    AddValidatorAttesterV1(AddValidatorAttesterV1Args),
+
+    /// Remove an attester key of a validator.
+    #[account(0, writable, name="validator_attester", desc = "The validator attester PDA")]
+    #[account(1, writable, name="validator_profile", desc = "The validator profile PDA")]
+    #[account(2, signer, name="validator", desc = "The validator authority")]
+    #[account(3, writable, name="destination", desc = "The account receiving the rent")]
+    RemoveValidatorAttesterV1(RemoveValidatorAttesterV1Args),
+
+    /// Record the result of a validation backed by an attestation: a statement over the request, the validator, the work hash of the request and the result, signed by an attester key of the validator. The signature must be verified by an Ed25519 program instruction right before this one. Otherwise behaves as RespondValidationV1.
+    #[account(0, writable, name="validation_request", desc = "The validation request PDA")]
+    #[account(1, writable, name="agent_validation", desc = "The agent validation PDA of the asset")]
+    #[account(2, writable, name="validator_history", desc = "The validator history PDA at [\"validator_history\", asset, validator]")]
+    #[account(3, name="validator_attester", desc = "The validator attester PDA of the key that signed the attestation")]
+    #[account(4, writable, name="asset", desc = "The address of the Core asset")]
+    #[account(5, writable, optional, name="collection", desc = "The address of the collection")]
+    #[account(6, writable, signer, name="validator", desc = "The validator designated in the request, receiving the reward")]
+    #[account(7, writable, signer, name="payer", desc = "The payer for additional rent")]
+    #[account(8, name="instructions_sysvar", desc = "The instructions sysvar")]
+    #[account(9, name="mpl_core_program", desc = "The MPL Core program")]
+    #[account(10, name="system_program", desc = "The system program")]
+    #[account(11, name="validator_profile", desc = "The validator profile PDA of the validator")]
+    #[account(12, optional, name="accepted_validator", desc = "The accepted validator PDA at [\"accepted_validator\", asset, validator], required if the agent accepts any validator")]
+    RespondValidationAttestationV1(RespondValidationAttestationV1Args),
+
+    /// Close an expired validation request the validator did not respond to, returning the escrowed reward and the rent. Only the requester may reclaim.
+    #[account(0, writable, name="validation_request", desc = "The validation request PDA")]
+    #[account(1, signer, name="requester", desc = "The requester of the validation")]
+    #[account(2, writable, name="destination", desc = "The account receiving the reward and the rent")]
+    ReclaimValidationRequestV1(ReclaimValidationRequestV1Args),
 }
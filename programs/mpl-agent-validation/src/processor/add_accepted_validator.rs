@@ -0,0 +1,123 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use super::authority::assert_asset_owner;
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::AddAcceptedValidatorV1Accounts,
+    state::{AcceptedValidatorV1, AgentValidationV1},
+};
+
+impl<'a> AddAcceptedValidatorV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            accepted_validator,
+            agent_validation,
+            asset,
+            authority,
+            payer,
+            system_program,
+        } = self;
+
+        // Accepted Validator
+        if !accepted_validator.data_is_empty() {
+            return Err(MplAgentValidationError::ValidatorAlreadyAccepted.into());
+        }
+
+        // Agent Validation
+        AgentValidationV1::check_pda_derivation(agent_validation, asset.key)?;
+        AgentValidationV1::check_initialized(agent_validation)?;
+
+        // Authority
+        // Only the asset owner decides which validators the agent accepts.
+        assert_signer(authority)?;
+        assert_asset_owner(asset, authority)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentValidationError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct AddAcceptedValidatorV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// The validator authority to accept.
+    pub validator: Pubkey,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<AddAcceptedValidatorV1Args>() == 40);
+
+pub fn add_accepted_validator_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &AddAcceptedValidatorV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = AddAcceptedValidatorV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let accepted_validator_bump = AcceptedValidatorV1::check_pda_derivation(
+        ctx.accounts.accepted_validator,
+        ctx.accounts.asset.key,
+        &args.validator,
+    )?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    AcceptedValidatorV1::create_account(
+        ctx.accounts.accepted_validator,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset.key,
+        &args.validator,
+        accepted_validator_bump,
+    )?;
+
+    {
+        let mut data = ctx.accounts.accepted_validator.try_borrow_mut_data()?;
+        let accepted_validator: &mut AcceptedValidatorV1 = bytemuck::from_bytes_mut(&mut data);
+        accepted_validator.initialize(
+            accepted_validator_bump,
+            ctx.accounts.asset.key,
+            &args.validator,
+        );
+    }
+
+    let mut data = ctx.accounts.agent_validation.try_borrow_mut_data()?;
+    let agent_validation: &mut AgentValidationV1 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentValidationV1>()]);
+    agent_validation.accepted_validator_count = agent_validation
+        .accepted_validator_count
+        .checked_add(1)
+        .ok_or(MplAgentValidationError::NumericalOverflow)?;
+
+    MplAgentValidationEvent::ValidatorAcceptedV1 {
+        asset: *ctx.accounts.asset.key,
+        validator: args.validator,
+        accepted_validator_count: agent_validation.accepted_validator_count,
+    }
+    .emit()
+}
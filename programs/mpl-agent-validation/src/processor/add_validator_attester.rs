@@ -0,0 +1,121 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::AddValidatorAttesterV1Accounts,
+    state::{ValidatorAttesterV1, ValidatorProfileV1},
+};
+
+impl<'a> AddValidatorAttesterV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validator_attester,
+            validator_profile,
+            validator,
+            attester,
+            payer,
+            system_program,
+        } = self;
+
+        // Validator Attester
+        if !validator_attester.data_is_empty() {
+            return Err(MplAgentValidationError::AttesterAlreadyAdded.into());
+        }
+
+        // Validator Profile
+        ValidatorProfileV1::check_pda_derivation(validator_profile, validator.key)?;
+        ValidatorProfileV1::check_initialized(validator_profile)?;
+
+        // Validator
+        assert_signer(validator)?;
+
+        // Attester
+        // Signs as proof of possession, so a validator cannot claim the key
+        // of another validator's attester.
+        assert_signer(attester)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentValidationError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct AddValidatorAttesterV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<AddValidatorAttesterV1Args>() == 8);
+
+pub fn add_validator_attester_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &AddValidatorAttesterV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = AddValidatorAttesterV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let validator_attester_bump = ValidatorAttesterV1::check_pda_derivation(
+        ctx.accounts.validator_attester,
+        ctx.accounts.validator.key,
+        ctx.accounts.attester.key,
+    )?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    ValidatorAttesterV1::create_account(
+        ctx.accounts.validator_attester,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.validator.key,
+        ctx.accounts.attester.key,
+        validator_attester_bump,
+    )?;
+
+    {
+        let mut data = ctx.accounts.validator_attester.try_borrow_mut_data()?;
+        let validator_attester: &mut ValidatorAttesterV1 = bytemuck::from_bytes_mut(&mut data);
+        validator_attester.initialize(
+            validator_attester_bump,
+            ctx.accounts.validator.key,
+            ctx.accounts.attester.key,
+        );
+    }
+
+    let mut data = ctx.accounts.validator_profile.try_borrow_mut_data()?;
+    let validator_profile: &mut ValidatorProfileV1 = bytemuck::from_bytes_mut(&mut data);
+    validator_profile.attester_count = validator_profile
+        .attester_count
+        .checked_add(1)
+        .ok_or(MplAgentValidationError::NumericalOverflow)?;
+
+    MplAgentValidationEvent::ValidatorAttesterAddedV1 {
+        validator: *ctx.accounts.validator.key,
+        attester: *ctx.accounts.attester.key,
+        attester_count: validator_profile.attester_count,
+    }
+    .emit()
+}
@@ -0,0 +1,92 @@
+//! Verification of the attestation carried by `RespondValidationAttestationV1`.
+//!
+//! The program does not verify the ed25519 signature itself. The transaction
+//! must carry an Ed25519 program instruction right before the response, and
+//! the runtime rejects the transaction unless that instruction's signature is
+//! valid. The response then checks that the instruction verified exactly one
+//! signature, by the expected attester, over the expected statement.
+
+use solana_instructions_sysvar::get_instruction_relative;
+use solana_program::{
+    account_info::AccountInfo, ed25519_program, entrypoint::ProgramResult, pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+/// Size of an attestation statement:
+/// `[validation request (32)][validator (32)][work hash of the request (32)][result (1)]`.
+///
+/// The request address commits to the asset and the nonce, so a statement
+/// cannot be replayed against another request or by another validator.
+pub const ATTESTATION_MESSAGE_LEN: usize = 97;
+
+/// Offset of the signature offsets in Ed25519 program instruction data,
+/// after the `u8` signature count and a byte of padding.
+const SIGNATURE_OFFSETS_START: usize = 2;
+
+/// Size of the signature offsets of one signature: seven `u16` fields.
+const SIGNATURE_OFFSETS_SIZE: usize = 14;
+
+/// Instruction index meaning the data is in the Ed25519 instruction itself.
+const CURRENT_INSTRUCTION: u16 = u16::MAX;
+
+/// Build the statement an attester signs for a validation response.
+pub fn attestation_message(
+    validation_request: &Pubkey,
+    validator: &Pubkey,
+    work_hash: &[u8; 32],
+    result: u8,
+) -> [u8; ATTESTATION_MESSAGE_LEN] {
+    let mut message = [0u8; ATTESTATION_MESSAGE_LEN];
+    message[..32].copy_from_slice(validation_request.as_ref());
+    message[32..64].copy_from_slice(validator.as_ref());
+    message[64..96].copy_from_slice(work_hash);
+    message[96] = result;
+    message
+}
+
+/// Check that the instruction before the current one is an Ed25519 program
+/// instruction verifying the signature of `attester` over `message`.
+pub fn assert_attestation(
+    instructions_sysvar: &AccountInfo,
+    attester: &Pubkey,
+    message: &[u8],
+) -> ProgramResult {
+    let instruction = get_instruction_relative(-1, instructions_sysvar)
+        .map_err(|_| MplAgentValidationError::MissingAttestation)?;
+    if instruction.program_id != ed25519_program::ID {
+        return Err(MplAgentValidationError::MissingAttestation.into());
+    }
+
+    let data = instruction.data.as_slice();
+    if data.first() != Some(&1) {
+        return Err(MplAgentValidationError::InvalidAttestation.into());
+    }
+
+    let offsets = data
+        .get(SIGNATURE_OFFSETS_START..SIGNATURE_OFFSETS_START + SIGNATURE_OFFSETS_SIZE)
+        .ok_or(MplAgentValidationError::InvalidAttestation)?;
+    let field = |index: usize| u16::from_le_bytes([offsets[index * 2], offsets[index * 2 + 1]]);
+    let signature_instruction_index = field(1);
+    let public_key_offset = field(2) as usize;
+    let public_key_instruction_index = field(3);
+    let message_data_offset = field(4) as usize;
+    let message_data_size = field(5) as usize;
+    let message_instruction_index = field(6);
+
+    // Data taken from other instructions would not be the data compared below.
+    if signature_instruction_index != CURRENT_INSTRUCTION
+        || public_key_instruction_index != CURRENT_INSTRUCTION
+        || message_instruction_index != CURRENT_INSTRUCTION
+    {
+        return Err(MplAgentValidationError::InvalidAttestation.into());
+    }
+
+    let public_key = data.get(public_key_offset..public_key_offset + 32);
+    let signed_message = data.get(message_data_offset..message_data_offset + message_data_size);
+    if public_key != Some(attester.as_ref()) || signed_message != Some(message) {
+        return Err(MplAgentValidationError::InvalidAttestation.into());
+    }
+
+    Ok(())
+}
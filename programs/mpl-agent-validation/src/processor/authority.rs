@@ -0,0 +1,56 @@
+//! Authorization of the agent side of a validation: the owner of the agent
+//! asset.
+
+use mpl_core::{
+    accounts::{BaseAssetV1, BaseCollectionV1},
+    types::{Key as MplCoreKey, UpdateAuthority},
+};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+/// Check that `asset` is a Core asset owned by `authority`. `authority` must
+/// already be known to have signed.
+pub fn assert_asset_owner(asset: &AccountInfo, authority: &AccountInfo) -> ProgramResult {
+    if asset.owner != &mpl_core::ID
+        || asset.data_is_empty()
+        || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+    {
+        return Err(MplAgentValidationError::InvalidCoreAsset.into());
+    }
+
+    if BaseAssetV1::try_from(asset)?.owner != *authority.key {
+        return Err(MplAgentValidationError::InvalidAgentAuthority.into());
+    }
+
+    Ok(())
+}
+
+/// Whether `key` is the owner or the update authority of the Core `asset`.
+/// The update authority of an asset in a collection is the update authority
+/// of the collection, which must then be passed as `collection`. `asset`
+/// must already be known to be a Core asset.
+pub fn is_agent_authority(
+    asset: &AccountInfo,
+    collection: Option<&AccountInfo>,
+    key: &Pubkey,
+) -> Result<bool, ProgramError> {
+    let asset = BaseAssetV1::try_from(asset)?;
+    if asset.owner == *key {
+        return Ok(true);
+    }
+
+    match asset.update_authority {
+        UpdateAuthority::Address(address) => Ok(address == *key),
+        UpdateAuthority::Collection(address) => match collection {
+            Some(collection) if *collection.key == address && collection.owner == &mpl_core::ID => {
+                Ok(BaseCollectionV1::try_from(collection)?.update_authority == *key)
+            }
+            _ => Ok(false),
+        },
+        UpdateAuthority::None => Ok(false),
+    }
+}
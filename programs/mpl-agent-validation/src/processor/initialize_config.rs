@@ -0,0 +1,147 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+use solana_system_interface::program as system_program;
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError, instruction::accounts::InitializeValidationConfigV1Accounts,
+    state::ValidationConfigV1,
+};
+
+/// The BPF upgradeable loader, which owns the program data account holding
+/// the upgrade authority of the program.
+pub const BPF_LOADER_UPGRADEABLE_ID: Pubkey =
+    solana_program::pubkey!("BPFLoaderUpgradeab1e11111111111111111111111");
+
+/// Discriminant of the `ProgramData` variant of the upgradeable loader state.
+const PROGRAM_DATA_DISCRIMINANT: u32 = 3;
+
+/// Read the upgrade authority of this program from its program data account,
+/// laid out as `[u32 variant][u64 slot][u8 option][32-byte authority]`.
+fn upgrade_authority(program_data: &AccountInfo) -> Result<Option<Pubkey>, ProgramError> {
+    let (program_data_address, _) =
+        Pubkey::find_program_address(&[crate::ID.as_ref()], &BPF_LOADER_UPGRADEABLE_ID);
+    if *program_data.key != program_data_address || *program_data.owner != BPF_LOADER_UPGRADEABLE_ID
+    {
+        return Err(MplAgentValidationError::InvalidProgramData.into());
+    }
+
+    let data = program_data.try_borrow_data()?;
+    let header = data
+        .get(..45)
+        .ok_or(MplAgentValidationError::InvalidProgramData)?;
+    if u32::from_le_bytes(header[..4].try_into().unwrap()) != PROGRAM_DATA_DISCRIMINANT {
+        return Err(MplAgentValidationError::InvalidProgramData.into());
+    }
+
+    Ok(match header[12] {
+        0 => None,
+        _ => Some(Pubkey::new_from_array(header[13..45].try_into().unwrap())),
+    })
+}
+
+impl<'a> InitializeValidationConfigV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            validation_config,
+            program_data,
+            authority,
+            arbiter: _,
+            payer,
+            system_program,
+        } = self;
+
+        // Validation Config
+        let validation_config_bump = ValidationConfigV1::check_pda_derivation(validation_config)?;
+        if !validation_config.data_is_empty() || *validation_config.owner != system_program::id() {
+            return Err(MplAgentValidationError::ValidationConfigAlreadyInitialized.into());
+        }
+
+        // Authority
+        // Only the upgrade authority of the program may set it up.
+        assert_signer(authority)?;
+        if upgrade_authority(program_data)? != Some(*authority.key) {
+            return Err(MplAgentValidationError::InvalidConfigAuthority.into());
+        }
+
+        // Arbiter
+        // SAFE: Any address may be made the arbiter.
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentValidationError::InvalidSystemProgram.into());
+        }
+
+        Ok(validation_config_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct InitializeValidationConfigV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Number of slots unbonding stake stays slashable before it can be
+    /// withdrawn.
+    pub unbonding_slots: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<InitializeValidationConfigV1Args>() == 16);
+
+pub fn initialize_validation_config_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &InitializeValidationConfigV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = InitializeValidationConfigV1Accounts::context(accounts)?;
+    let validation_config_bump = ctx.accounts.validate()?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.unbonding_slots == 0 {
+        return Err(MplAgentValidationError::InvalidUnbondingPeriod.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    ValidationConfigV1::create_account(
+        ctx.accounts.validation_config,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        validation_config_bump,
+    )?;
+
+    let mut data = ctx.accounts.validation_config.try_borrow_mut_data()?;
+    let validation_config: &mut ValidationConfigV1 = bytemuck::from_bytes_mut(&mut data);
+    validation_config.initialize(
+        validation_config_bump,
+        ctx.accounts.authority.key,
+        ctx.accounts.arbiter.key,
+        args.unbonding_slots,
+    );
+
+    MplAgentValidationEvent::ValidationConfigUpdatedV1 {
+        authority: *ctx.accounts.authority.key,
+        arbiter: *ctx.accounts.arbiter.key,
+        unbonding_slots: args.unbonding_slots,
+    }
+    .emit()
+}
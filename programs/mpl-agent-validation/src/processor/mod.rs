@@ -1,4 +1,22 @@
+mod add_accepted_validator;
+mod add_validator_attester;
+mod attestation;
+mod authority;
+mod initialize_config;
+mod reclaim_validation_request;
 mod register;
+mod register_validator;
+mod remove_accepted_validator;
+mod remove_validator_attester;
+mod request_validation;
+mod respond_validation;
+mod respond_validation_attestation;
+mod slash_validator;
+mod summary;
+mod unbond_validator_stake;
+mod update_config;
+mod uri;
+mod withdraw_validator_stake;
 
 use bytemuck::try_from_bytes;
 use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg, pubkey::Pubkey};
@@ -6,7 +24,34 @@ use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, msg,
 use crate::error::MplAgentValidationError;
 use crate::instruction::MplAgentValidationInstructionDiscriminant;
 
+pub use add_accepted_validator::{add_accepted_validator_v1, AddAcceptedValidatorV1Args};
+pub use add_validator_attester::{add_validator_attester_v1, AddValidatorAttesterV1Args};
+pub use attestation::{attestation_message, ATTESTATION_MESSAGE_LEN};
+pub use initialize_config::{
+    initialize_validation_config_v1, InitializeValidationConfigV1Args, BPF_LOADER_UPGRADEABLE_ID,
+};
+pub use reclaim_validation_request::{
+    reclaim_validation_request_v1, ReclaimValidationRequestV1Args,
+};
 pub use register::{register_validation_v1, RegisterValidationV1Args};
+pub use register_validator::{register_validator_v1, RegisterValidatorV1Args};
+pub use remove_accepted_validator::{remove_accepted_validator_v1, RemoveAcceptedValidatorV1Args};
+pub use remove_validator_attester::{remove_validator_attester_v1, RemoveValidatorAttesterV1Args};
+pub use request_validation::{
+    request_validation_v1, RequestValidationV1Args, DEFAULT_VALIDATION_EXPIRY_SLOTS,
+    MAX_VALIDATION_EXPIRY_SLOTS,
+};
+pub use respond_validation::{
+    respond_validation_v1, RespondValidationV1Args, MAX_VALIDATION_RESULT,
+    PASSING_VALIDATION_RESULT,
+};
+pub use respond_validation_attestation::{
+    respond_validation_attestation_v1, RespondValidationAttestationV1Args,
+};
+pub use slash_validator::{slash_validator_v1, SlashValidatorV1Args};
+pub use unbond_validator_stake::{unbond_validator_stake_v1, UnbondValidatorStakeV1Args};
+pub use update_config::{update_validation_config_v1, UpdateValidationConfigV1Args};
+pub use withdraw_validator_stake::{withdraw_validator_stake_v1, WithdrawValidatorStakeV1Args};
 
 /// Process incoming instructions.
 ///
@@ -35,6 +80,114 @@ pub fn process_instruction<'a>(
                     .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
             )
         }
+        Ok(MplAgentValidationInstructionDiscriminant::RequestValidationV1) => {
+            msg!("Instruction: RequestValidationV1");
+            request_validation_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::RespondValidationV1) => {
+            msg!("Instruction: RespondValidationV1");
+            respond_validation_v1(accounts, instruction_data)
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::InitializeValidationConfigV1) => {
+            msg!("Instruction: InitializeValidationConfigV1");
+            initialize_validation_config_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::UpdateValidationConfigV1) => {
+            msg!("Instruction: UpdateValidationConfigV1");
+            update_validation_config_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::RegisterValidatorV1) => {
+            msg!("Instruction: RegisterValidatorV1");
+            register_validator_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::UnbondValidatorStakeV1) => {
+            msg!("Instruction: UnbondValidatorStakeV1");
+            unbond_validator_stake_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::WithdrawValidatorStakeV1) => {
+            msg!("Instruction: WithdrawValidatorStakeV1");
+            withdraw_validator_stake_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::SlashValidatorV1) => {
+            msg!("Instruction: SlashValidatorV1");
+            slash_validator_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::AddAcceptedValidatorV1) => {
+            msg!("Instruction: AddAcceptedValidatorV1");
+            add_accepted_validator_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::RemoveAcceptedValidatorV1) => {
+            msg!("Instruction: RemoveAcceptedValidatorV1");
+            remove_accepted_validator_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::AddValidatorAttesterV1) => {
+            msg!("Instruction: AddValidatorAttesterV1");
+            add_validator_attester_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::RemoveValidatorAttesterV1) => {
+            msg!("Instruction: RemoveValidatorAttesterV1");
+            remove_validator_attester_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::RespondValidationAttestationV1) => {
+            msg!("Instruction: RespondValidationAttestationV1");
+            respond_validation_attestation_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
+        Ok(MplAgentValidationInstructionDiscriminant::ReclaimValidationRequestV1) => {
+            msg!("Instruction: ReclaimValidationRequestV1");
+            reclaim_validation_request_v1(
+                accounts,
+                try_from_bytes(instruction_data)
+                    .map_err(|_| MplAgentValidationError::InvalidInstructionData)?,
+            )
+        }
         Err(_) => Err(MplAgentValidationError::InvalidInstructionData.into()),
     }
 }
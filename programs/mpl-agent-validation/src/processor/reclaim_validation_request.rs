@@ -0,0 +1,101 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_signer, close_account_raw};
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::ReclaimValidationRequestV1Accounts,
+    state::{Key, ValidationRequestV1, ValidationStatus},
+};
+
+impl<'a> ReclaimValidationRequestV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validation_request,
+            requester,
+            destination: _,
+        } = self;
+
+        // Validation Request
+        if validation_request.owner != &crate::ID
+            || validation_request.data_len() != core::mem::size_of::<ValidationRequestV1>()
+            || validation_request.try_borrow_data()?[0] != Key::ValidationRequestV1 as u8
+        {
+            return Err(MplAgentValidationError::InvalidValidationRequest.into());
+        }
+
+        // Requester
+        // Checked against the request below.
+        assert_signer(requester)?;
+
+        // Destination
+        // SAFE: The requester may send the reward and the rent anywhere.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct ReclaimValidationRequestV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<ReclaimValidationRequestV1Args>() == 8);
+
+pub fn reclaim_validation_request_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &ReclaimValidationRequestV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = ReclaimValidationRequestV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let validation_request = {
+        let data = ctx.accounts.validation_request.try_borrow_data()?;
+        *bytemuck::from_bytes::<ValidationRequestV1>(&data)
+    };
+
+    if validation_request.requester != *ctx.accounts.requester.key {
+        return Err(MplAgentValidationError::OnlyRequesterCanReclaim.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    // A responded request paid its reward out and stays as the record of the
+    // validation.
+    if validation_request.status != ValidationStatus::Pending as u8 {
+        return Err(MplAgentValidationError::ValidationAlreadyResponded.into());
+    }
+
+    if !validation_request.is_expired(Clock::get()?.slot) {
+        return Err(MplAgentValidationError::ValidationRequestNotExpired.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    close_account_raw(ctx.accounts.destination, ctx.accounts.validation_request)?;
+
+    MplAgentValidationEvent::ValidationRequestReclaimedV1 {
+        asset: validation_request.asset,
+        nonce: validation_request.nonce,
+        requester: validation_request.requester,
+        reward: validation_request.reward,
+    }
+    .emit()
+}
@@ -0,0 +1,145 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+use solana_system_interface::{instruction as system_instruction, program as system_program};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::RegisterValidatorV1Accounts,
+    state::{ValidatorProfileV1, ValidatorStakeVaultV1},
+};
+
+impl<'a> RegisterValidatorV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(u8, u8), ProgramError> {
+        let Self {
+            validator_profile,
+            validator_stake_vault,
+            validator,
+            payer,
+            system_program,
+        } = self;
+
+        // Validator Profile
+        let validator_profile_bump =
+            ValidatorProfileV1::check_pda_derivation(validator_profile, validator.key)?;
+        if !validator_profile.data_is_empty() || *validator_profile.owner != system_program::id() {
+            return Err(MplAgentValidationError::ValidatorAlreadyRegistered.into());
+        }
+
+        // Validator Stake Vault
+        // Created along with the profile, so it must not exist either.
+        let validator_stake_vault_bump =
+            ValidatorStakeVaultV1::check_pda_derivation(validator_stake_vault, validator.key)?;
+        if !validator_stake_vault.data_is_empty()
+            || *validator_stake_vault.owner != system_program::id()
+        {
+            return Err(MplAgentValidationError::ValidatorAlreadyRegistered.into());
+        }
+
+        // Validator
+        // Funds the stake.
+        assert_signer(validator)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentValidationError::InvalidSystemProgram.into());
+        }
+
+        Ok((validator_profile_bump, validator_stake_vault_bump))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RegisterValidatorV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Lamports to escrow as stake. May be zero.
+    pub stake: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RegisterValidatorV1Args>() == 16);
+
+pub fn register_validator_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &RegisterValidatorV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RegisterValidatorV1Accounts::context(accounts)?;
+    let (validator_profile_bump, validator_stake_vault_bump) = ctx.accounts.validate()?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    ValidatorProfileV1::create_account(
+        ctx.accounts.validator_profile,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.validator.key,
+        validator_profile_bump,
+    )?;
+
+    {
+        let mut data = ctx.accounts.validator_profile.try_borrow_mut_data()?;
+        let validator_profile: &mut ValidatorProfileV1 = bytemuck::from_bytes_mut(&mut data);
+        validator_profile.initialize(
+            validator_profile_bump,
+            ctx.accounts.validator.key,
+            args.stake,
+            Clock::get()?.slot,
+        );
+    }
+
+    // The vault is created even without stake, so the profile always has
+    // one to be slashed from.
+    ValidatorStakeVaultV1::create_account(
+        ctx.accounts.validator_stake_vault,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.validator.key,
+        validator_stake_vault_bump,
+    )?;
+
+    {
+        let mut data = ctx.accounts.validator_stake_vault.try_borrow_mut_data()?;
+        let validator_stake_vault: &mut ValidatorStakeVaultV1 = bytemuck::from_bytes_mut(&mut data);
+        validator_stake_vault.initialize(validator_stake_vault_bump, ctx.accounts.validator.key);
+    }
+
+    if args.stake > 0 {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.validator.key,
+                ctx.accounts.validator_stake_vault.key,
+                args.stake,
+            ),
+            &[
+                ctx.accounts.validator.clone(),
+                ctx.accounts.validator_stake_vault.clone(),
+                ctx.accounts.system_program.clone(),
+            ],
+        )?;
+    }
+
+    MplAgentValidationEvent::ValidatorRegisteredV1 {
+        validator: *ctx.accounts.validator.key,
+        stake: args.stake,
+    }
+    .emit()
+}
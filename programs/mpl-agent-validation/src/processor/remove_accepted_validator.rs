@@ -0,0 +1,101 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_signer, close_account_raw};
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::authority::assert_asset_owner;
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::RemoveAcceptedValidatorV1Accounts,
+    state::{AcceptedValidatorV1, AgentValidationV1, Key},
+};
+
+impl<'a> RemoveAcceptedValidatorV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            accepted_validator,
+            agent_validation,
+            asset,
+            authority,
+            destination: _,
+        } = self;
+
+        // Accepted Validator
+        // Derivation checked against its own validator below.
+        if accepted_validator.owner != &crate::ID
+            || accepted_validator.data_len() != core::mem::size_of::<AcceptedValidatorV1>()
+            || accepted_validator.try_borrow_data()?[0] != Key::AcceptedValidatorV1 as u8
+        {
+            return Err(MplAgentValidationError::InvalidAcceptedValidator.into());
+        }
+
+        // Agent Validation
+        AgentValidationV1::check_pda_derivation(agent_validation, asset.key)?;
+        AgentValidationV1::check_initialized(agent_validation)?;
+
+        // Authority
+        assert_signer(authority)?;
+        assert_asset_owner(asset, authority)?;
+
+        // Destination
+        // SAFE: Any account may receive the rent of the entry.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RemoveAcceptedValidatorV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RemoveAcceptedValidatorV1Args>() == 8);
+
+pub fn remove_accepted_validator_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &RemoveAcceptedValidatorV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RemoveAcceptedValidatorV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let validator = {
+        let data = ctx.accounts.accepted_validator.try_borrow_data()?;
+        bytemuck::from_bytes::<AcceptedValidatorV1>(&data).validator
+    };
+    AcceptedValidatorV1::check_pda_derivation(
+        ctx.accounts.accepted_validator,
+        ctx.accounts.asset.key,
+        &validator,
+    )?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    close_account_raw(ctx.accounts.destination, ctx.accounts.accepted_validator)?;
+
+    let mut data = ctx.accounts.agent_validation.try_borrow_mut_data()?;
+    let agent_validation: &mut AgentValidationV1 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentValidationV1>()]);
+    agent_validation.accepted_validator_count =
+        agent_validation.accepted_validator_count.saturating_sub(1);
+
+    MplAgentValidationEvent::ValidatorRemovedV1 {
+        asset: *ctx.accounts.asset.key,
+        validator,
+        accepted_validator_count: agent_validation.accepted_validator_count,
+    }
+    .emit()
+}
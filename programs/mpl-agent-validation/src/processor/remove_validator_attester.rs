@@ -0,0 +1,90 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_signer, close_account_raw};
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    instruction::accounts::RemoveValidatorAttesterV1Accounts,
+    state::{ValidatorAttesterV1, ValidatorProfileV1},
+};
+
+impl<'a> RemoveValidatorAttesterV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validator_attester,
+            validator_profile,
+            validator,
+            destination: _,
+        } = self;
+
+        // Validator Attester
+        // Derivation checked against its own attester below.
+        ValidatorAttesterV1::check_initialized(validator_attester)?;
+
+        // Validator Profile
+        ValidatorProfileV1::check_pda_derivation(validator_profile, validator.key)?;
+        ValidatorProfileV1::check_initialized(validator_profile)?;
+
+        // Validator
+        assert_signer(validator)?;
+
+        // Destination
+        // SAFE: Any account may receive the rent of the entry.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RemoveValidatorAttesterV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RemoveValidatorAttesterV1Args>() == 8);
+
+pub fn remove_validator_attester_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &RemoveValidatorAttesterV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RemoveValidatorAttesterV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let attester = {
+        let data = ctx.accounts.validator_attester.try_borrow_data()?;
+        bytemuck::from_bytes::<ValidatorAttesterV1>(&data).attester
+    };
+    ValidatorAttesterV1::check_pda_derivation(
+        ctx.accounts.validator_attester,
+        ctx.accounts.validator.key,
+        &attester,
+    )?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    close_account_raw(ctx.accounts.destination, ctx.accounts.validator_attester)?;
+
+    let mut data = ctx.accounts.validator_profile.try_borrow_mut_data()?;
+    let validator_profile: &mut ValidatorProfileV1 = bytemuck::from_bytes_mut(&mut data);
+    validator_profile.attester_count = validator_profile.attester_count.saturating_sub(1);
+
+    MplAgentValidationEvent::ValidatorAttesterRemovedV1 {
+        validator: *ctx.accounts.validator.key,
+        attester,
+        attester_count: validator_profile.attester_count,
+    }
+    .emit()
+}
@@ -0,0 +1,220 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program::invoke;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+use solana_system_interface::{instruction as system_instruction, program as system_program};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::RequestValidationV1Accounts,
+    state::{AcceptedValidatorV1, AgentValidationV1, ValidationRequestV1, ValidatorProfileV1},
+};
+
+/// Slots until a request expires when the requester leaves the expiry to the
+/// program, about a day.
+pub const DEFAULT_VALIDATION_EXPIRY_SLOTS: u64 = 216_000;
+
+/// Maximum number of slots until a request expires, about 30 days.
+pub const MAX_VALIDATION_EXPIRY_SLOTS: u64 = 6_480_000;
+
+impl<'a> RequestValidationV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validation_request,
+            agent_validation,
+            validator_profile: _,
+            accepted_validator: _,
+            asset,
+            requester,
+            payer,
+            system_program,
+        } = self;
+
+        // Validation Request
+        if !validation_request.data_is_empty() {
+            return Err(MplAgentValidationError::ValidationRequestAlreadyExists.into());
+        }
+
+        // Agent Validation
+        // Only assets registered for validation can be validated.
+        AgentValidationV1::check_pda_derivation(agent_validation, asset.key)?;
+        AgentValidationV1::check_initialized(agent_validation)?;
+
+        // Validator Profile
+        // Checked against the validator argument below.
+
+        // Accepted Validator
+        // Checked against the validator argument below, if the agent
+        // restricts its validators.
+
+        // Asset
+        // SAFE: Bound to the agent validation by its PDA derivation.
+
+        // Requester
+        // Anyone, the agent or one of its clients, may request a validation.
+        // Funds the reward.
+        assert_signer(requester)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentValidationError::InvalidSystemProgram.into());
+        }
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RequestValidationV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Nonce of the request among the requests for the asset.
+    pub nonce: u64,
+    /// Hash of the work to validate.
+    pub work_hash: [u8; 32],
+    /// The validator designated to respond.
+    pub validator: Pubkey,
+    /// Reward of the validator in lamports, escrowed in the request.
+    pub reward: u64,
+    /// Slot the request expires at. Zero expires it after
+    /// `DEFAULT_VALIDATION_EXPIRY_SLOTS`.
+    pub expiry_slot: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RequestValidationV1Args>() == 96);
+
+pub fn request_validation_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &RequestValidationV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RequestValidationV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let validation_request_bump = ValidationRequestV1::check_pda_derivation(
+        ctx.accounts.validation_request,
+        ctx.accounts.asset.key,
+        args.nonce,
+    )?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.work_hash == [0u8; 32] {
+        return Err(MplAgentValidationError::EmptyWorkHash.into());
+    }
+
+    let slot = Clock::get()?.slot;
+    let expiry_slot = match args.expiry_slot {
+        0 => slot.saturating_add(DEFAULT_VALIDATION_EXPIRY_SLOTS),
+        expiry_slot => expiry_slot,
+    };
+    if expiry_slot <= slot || expiry_slot - slot > MAX_VALIDATION_EXPIRY_SLOTS {
+        return Err(MplAgentValidationError::InvalidExpirySlot.into());
+    }
+
+    // The validation must be independent of the requester.
+    if args.validator == *ctx.accounts.requester.key {
+        return Err(MplAgentValidationError::RequesterCannotValidate.into());
+    }
+
+    // Only registered validators can be requested.
+    ValidatorProfileV1::check_pda_derivation(ctx.accounts.validator_profile, &args.validator)?;
+    ValidatorProfileV1::check_initialized(ctx.accounts.validator_profile)?;
+
+    // Once the agent accepts any validator, it only accepts those.
+    AcceptedValidatorV1::check_accepted(
+        ctx.accounts.agent_validation,
+        ctx.accounts.accepted_validator,
+        ctx.accounts.asset.key,
+        &args.validator,
+    )?;
+
+    // The validator must keep this stake until it responds.
+    let validator_stake = {
+        let data = ctx.accounts.validator_profile.try_borrow_data()?;
+        bytemuck::from_bytes::<ValidatorProfileV1>(&data).stake
+    };
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    ValidationRequestV1::create_account(
+        ctx.accounts.validation_request,
+        ctx.accounts.payer,
+        ctx.accounts.system_program,
+        ctx.accounts.asset.key,
+        args.nonce,
+        validation_request_bump,
+    )?;
+
+    {
+        let mut data = ctx.accounts.validation_request.try_borrow_mut_data()?;
+        let validation_request: &mut ValidationRequestV1 = bytemuck::from_bytes_mut(&mut data);
+        validation_request.initialize(
+            validation_request_bump,
+            ctx.accounts.asset.key,
+            ctx.accounts.requester.key,
+            &args.validator,
+            args.nonce,
+            args.work_hash,
+            validator_stake,
+            args.reward,
+            expiry_slot,
+            slot,
+        );
+    }
+
+    MplAgentValidationEvent::ValidationRequestedV1 {
+        asset: *ctx.accounts.asset.key,
+        nonce: args.nonce,
+        requester: *ctx.accounts.requester.key,
+        validator: args.validator,
+        work_hash: args.work_hash,
+    }
+    .emit()?;
+
+    // Escrow the reward in the request, on top of its rent.
+    if args.reward > 0 {
+        invoke(
+            &system_instruction::transfer(
+                ctx.accounts.requester.key,
+                ctx.accounts.validation_request.key,
+                args.reward,
+            ),
+            &[
+                ctx.accounts.requester.clone(),
+                ctx.accounts.validation_request.clone(),
+                ctx.accounts.system_program.clone(),
+            ],
+        )?;
+
+        MplAgentValidationEvent::ValidationRewardEscrowedV1 {
+            asset: *ctx.accounts.asset.key,
+            nonce: args.nonce,
+            requester: *ctx.accounts.requester.key,
+            reward: args.reward,
+            expiry_slot,
+        }
+        .emit()?;
+    }
+
+    Ok(())
+}
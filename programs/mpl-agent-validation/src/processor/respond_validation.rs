@@ -0,0 +1,329 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_core::types::Key as MplCoreKey;
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+use solana_system_interface::program as system_program;
+
+use super::authority::is_agent_authority;
+use super::summary::{write_summary, SummaryAccounts};
+use super::uri::read_evidence_uri;
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::RespondValidationV1Accounts,
+    state::{
+        AcceptedValidatorV1, AgentValidationV1, AgentValidationV2, EvidenceType, Key,
+        ValidationRequestV1, ValidationSummaryV1, ValidatorHistoryV1, ValidatorProfileV1,
+    },
+};
+
+/// Maximum validation result.
+pub const MAX_VALIDATION_RESULT: u8 = 100;
+
+/// Minimum validation result counted as a pass in the validation aggregates.
+pub const PASSING_VALIDATION_RESULT: u8 = 50;
+
+impl<'a> RespondValidationV1Accounts<'a> {
+    pub fn validate(&self) -> Result<u8, ProgramError> {
+        let Self {
+            validation_request,
+            agent_validation,
+            validator_history,
+            asset,
+            collection: _,
+            validator,
+            payer,
+            mpl_core_program,
+            system_program,
+            validator_profile,
+            accepted_validator: _,
+        } = self;
+
+        // Validation Request
+        if validation_request.owner != &crate::ID
+            || validation_request.data_len() != core::mem::size_of::<ValidationRequestV1>()
+            || validation_request.try_borrow_data()?[0] != Key::ValidationRequestV1 as u8
+        {
+            return Err(MplAgentValidationError::InvalidValidationRequest.into());
+        }
+
+        // Agent Validation
+        AgentValidationV1::check_initialized(agent_validation)?;
+        let agent_validation_bump =
+            AgentValidationV1::check_pda_derivation(agent_validation, asset.key)?;
+
+        // Validator History
+        // Created on the validator's first response for the asset.
+        if !validator_history.data_is_empty()
+            && (validator_history.owner != &crate::ID
+                || validator_history.try_borrow_data()?[0] != Key::ValidatorHistoryV1 as u8)
+        {
+            return Err(MplAgentValidationError::InvalidValidatorHistory.into());
+        }
+
+        // Asset
+        // Checked against the request below.
+        if asset.owner != &mpl_core::ID || asset.try_borrow_data()?[0] != MplCoreKey::AssetV1 as u8
+        {
+            return Err(MplAgentValidationError::InvalidCoreAsset.into());
+        }
+
+        // Collection
+        // SAFE: Checked by the Core program.
+
+        // Validator
+        // Checked against the request below.
+        assert_signer(validator)?;
+
+        // Payer
+        assert_signer(payer)?;
+
+        // MPL Core Program
+        if *mpl_core_program.key != mpl_core::ID {
+            return Err(MplAgentValidationError::InvalidMplCoreProgram.into());
+        }
+
+        // System Program
+        if *system_program.key != system_program::id() {
+            return Err(MplAgentValidationError::InvalidSystemProgram.into());
+        }
+
+        // Validator Profile
+        // The validator must still be registered when it responds.
+        ValidatorProfileV1::check_pda_derivation(validator_profile, validator.key)?;
+        ValidatorProfileV1::check_initialized(validator_profile)?;
+
+        // Accepted Validator
+        // Checked against the agent's accepted validators below.
+
+        Ok(agent_validation_bump)
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RespondValidationV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// The validation result, from 0 to 100.
+    pub result: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// Hash of the off-chain evidence file.
+    pub evidence_hash: [u8; 32],
+    /// The URI of the off-chain evidence file.
+    /// We parse this manually from a string representation in the IDL.
+    #[idl_type("String")]
+    evidence_uri: [u8; 0],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RespondValidationV1Args>() == 40);
+
+pub fn respond_validation_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    instruction_data: &[u8],
+) -> ProgramResult {
+    let args_data = instruction_data
+        .get(..core::mem::size_of::<RespondValidationV1Args>())
+        .ok_or(MplAgentValidationError::InvalidInstructionData)?;
+    let args: RespondValidationV1Args = bytemuck::pod_read_unaligned(args_data);
+
+    let evidence_uri =
+        read_evidence_uri(&instruction_data[core::mem::size_of::<RespondValidationV1Args>()..])?;
+
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RespondValidationV1Accounts::context(accounts)?;
+    let agent_validation_bump = ctx.accounts.validate()?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.result > MAX_VALIDATION_RESULT {
+        return Err(MplAgentValidationError::InvalidValidationResult.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let nonce = record_response(
+        &ctx.accounts,
+        agent_validation_bump,
+        args.result,
+        EvidenceType::Uri,
+        args.evidence_hash,
+    )?;
+
+    MplAgentValidationEvent::ValidationRespondedV1 {
+        asset: *ctx.accounts.asset.key,
+        nonce,
+        validator: *ctx.accounts.validator.key,
+        result: args.result,
+        evidence_uri,
+        evidence_hash: args.evidence_hash,
+    }
+    .emit()
+}
+
+/// Record the response of the designated validator to a validation request
+/// and pay the validator its reward. If the agent vouches for the response,
+/// add it to the agent's validation aggregates and write their summary to the
+/// asset's AppData plugin. Returns the nonce of the request. `result` must
+/// already be checked against `MAX_VALIDATION_RESULT`.
+pub(super) fn record_response<'a>(
+    accounts: &RespondValidationV1Accounts<'a>,
+    agent_validation_bump: u8,
+    result: u8,
+    evidence_type: EvidenceType,
+    evidence_hash: [u8; 32],
+) -> Result<u64, ProgramError> {
+    let mut data = accounts.validation_request.try_borrow_mut_data()?;
+    let validation_request: &mut ValidationRequestV1 = bytemuck::from_bytes_mut(&mut data);
+
+    if validation_request.validator != *accounts.validator.key {
+        return Err(MplAgentValidationError::OnlyDesignatedValidatorCanRespond.into());
+    }
+
+    if validation_request.asset != *accounts.asset.key {
+        return Err(MplAgentValidationError::AssetMismatch.into());
+    }
+
+    let validator_history_bump = ValidatorHistoryV1::check_pda_derivation(
+        accounts.validator_history,
+        accounts.asset.key,
+        accounts.validator.key,
+    )?;
+
+    // The validator must not have unbonded or been slashed below the stake
+    // it had when requested.
+    let validator_stake = {
+        let data = accounts.validator_profile.try_borrow_data()?;
+        bytemuck::from_bytes::<ValidatorProfileV1>(&data).stake
+    };
+    if validator_stake < validation_request.validator_stake {
+        return Err(MplAgentValidationError::InsufficientValidatorStake.into());
+    }
+
+    // The agent may have stopped accepting the validator since the request.
+    let restricted = AcceptedValidatorV1::check_accepted(
+        accounts.agent_validation,
+        accounts.accepted_validator,
+        accounts.asset.key,
+        accounts.validator.key,
+    )?;
+
+    // Anyone may request a validation from any registered validator, so the
+    // aggregates only count responses the agent vouches for: to its own
+    // requests, or from validators it accepts.
+    let counted = restricted
+        || is_agent_authority(
+            accounts.asset,
+            accounts.collection,
+            &validation_request.requester,
+        )?;
+
+    let slot = Clock::get()?.slot;
+    validation_request.record_response(result, evidence_type, evidence_hash, counted, slot)?;
+    let nonce = validation_request.nonce;
+    let reward = validation_request.reward;
+    drop(data);
+
+    // The validator claims the reward escrowed in the request.
+    if reward > 0 {
+        ValidationRequestV1::pay_out_reward(
+            accounts.validation_request,
+            accounts.validator,
+            reward,
+        )?;
+
+        MplAgentValidationEvent::ValidationRewardClaimedV1 {
+            asset: *accounts.asset.key,
+            nonce,
+            validator: *accounts.validator.key,
+            reward,
+        }
+        .emit()?;
+    }
+
+    // Other responses are only recorded in the request.
+    if !counted {
+        return Ok(nonce);
+    }
+
+    // Count the response in the validator's history with the agent.
+    if accounts.validator_history.data_is_empty() {
+        ValidatorHistoryV1::create_account(
+            accounts.validator_history,
+            accounts.payer,
+            accounts.system_program,
+            accounts.asset.key,
+            accounts.validator.key,
+            validator_history_bump,
+        )?;
+
+        let mut data = accounts.validator_history.try_borrow_mut_data()?;
+        let validator_history: &mut ValidatorHistoryV1 = bytemuck::from_bytes_mut(&mut data);
+        validator_history.initialize(
+            validator_history_bump,
+            accounts.asset.key,
+            accounts.validator.key,
+        );
+    }
+
+    let mut data = accounts.validator_history.try_borrow_mut_data()?;
+    let validator_history: &mut ValidatorHistoryV1 = bytemuck::from_bytes_mut(&mut data);
+    validator_history.validation_count = validator_history
+        .validation_count
+        .checked_add(1)
+        .ok_or(MplAgentValidationError::NumericalOverflow)?;
+    let validator_count = validator_history.validation_count;
+    drop(data);
+
+    // Upgrade the account in place so it can hold the aggregates.
+    if accounts.agent_validation.try_borrow_data()?[0] == Key::AgentValidationV1 as u8 {
+        AgentValidationV2::migrate_from_v1(
+            accounts.agent_validation,
+            accounts.payer,
+            accounts.system_program,
+        )?;
+    }
+
+    let mut data = accounts.agent_validation.try_borrow_mut_data()?;
+    let agent_validation: &mut AgentValidationV2 =
+        bytemuck::from_bytes_mut(&mut data[..core::mem::size_of::<AgentValidationV2>()]);
+
+    agent_validation.record_validation(
+        result >= PASSING_VALIDATION_RESULT,
+        accounts.validator.key,
+        validator_count,
+        slot,
+    )?;
+    let summary = ValidationSummaryV1::from(&*agent_validation);
+
+    // Drop the agent validation account data before the CPI.
+    drop(data);
+
+    // Write the summary to the AppData plugin.
+    write_summary(
+        &SummaryAccounts {
+            mpl_core_program: accounts.mpl_core_program,
+            asset: accounts.asset,
+            collection: accounts.collection,
+            payer: accounts.payer,
+            agent_validation: accounts.agent_validation,
+            system_program: accounts.system_program,
+        },
+        &summary,
+        agent_validation_bump,
+    )?;
+
+    Ok(nonce)
+}
@@ -0,0 +1,135 @@
+use bytemuck::{Pod, Zeroable};
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::pubkey::Pubkey;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use super::attestation::{assert_attestation, attestation_message};
+use super::respond_validation::{record_response, MAX_VALIDATION_RESULT};
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::{RespondValidationAttestationV1Accounts, RespondValidationV1Accounts},
+    state::{EvidenceType, ValidationRequestV1, ValidatorAttesterV1},
+};
+
+impl<'a> RespondValidationAttestationV1Accounts<'a> {
+    /// The accounts of the response, shared with `RespondValidationV1`.
+    fn response_accounts(&self) -> RespondValidationV1Accounts<'a> {
+        RespondValidationV1Accounts {
+            validation_request: self.validation_request,
+            agent_validation: self.agent_validation,
+            validator_history: self.validator_history,
+            asset: self.asset,
+            collection: self.collection,
+            validator: self.validator,
+            payer: self.payer,
+            mpl_core_program: self.mpl_core_program,
+            system_program: self.system_program,
+            validator_profile: self.validator_profile,
+            accepted_validator: self.accepted_validator,
+        }
+    }
+
+    pub fn validate(&self) -> Result<(u8, Pubkey), ProgramError> {
+        let Self {
+            validator_attester,
+            validator,
+            instructions_sysvar,
+            ..
+        } = self;
+
+        // Validation Request, Agent Validation, Validator History, Asset,
+        // Collection, Validator, Payer, MPL Core Program, System Program,
+        // Validator Profile, Accepted Validator
+        let agent_validation_bump = self.response_accounts().validate()?;
+
+        // Validator Attester
+        let attester = ValidatorAttesterV1::check_initialized(validator_attester)?;
+        ValidatorAttesterV1::check_pda_derivation(validator_attester, validator.key, &attester)?;
+
+        // Instructions Sysvar
+        if !solana_instructions_sysvar::check_id(instructions_sysvar.key) {
+            return Err(MplAgentValidationError::InvalidInstructionsSysvar.into());
+        }
+
+        Ok((agent_validation_bump, attester))
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct RespondValidationAttestationV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// The validation result, from 0 to 100.
+    pub result: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// Hash of the attestation report backing the statement, such as a TEE
+    /// quote.
+    pub evidence_hash: [u8; 32],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<RespondValidationAttestationV1Args>() == 40);
+
+pub fn respond_validation_attestation_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &RespondValidationAttestationV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = RespondValidationAttestationV1Accounts::context(accounts)?;
+    let (agent_validation_bump, attester) = ctx.accounts.validate()?;
+
+    let work_hash = {
+        let data = ctx.accounts.validation_request.try_borrow_data()?;
+        bytemuck::from_bytes::<ValidationRequestV1>(&data).work_hash
+    };
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.result > MAX_VALIDATION_RESULT {
+        return Err(MplAgentValidationError::InvalidValidationResult.into());
+    }
+
+    // The attester must have signed this request, this validator, the work
+    // and this result.
+    assert_attestation(
+        ctx.accounts.instructions_sysvar,
+        &attester,
+        &attestation_message(
+            ctx.accounts.validation_request.key,
+            ctx.accounts.validator.key,
+            &work_hash,
+            args.result,
+        ),
+    )?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let nonce = record_response(
+        &ctx.accounts.response_accounts(),
+        agent_validation_bump,
+        args.result,
+        EvidenceType::Attestation,
+        args.evidence_hash,
+    )?;
+
+    MplAgentValidationEvent::ValidationAttestedV1 {
+        asset: *ctx.accounts.asset.key,
+        nonce,
+        validator: *ctx.accounts.validator.key,
+        attester,
+        result: args.result,
+        evidence_hash: args.evidence_hash,
+    }
+    .emit()
+}
@@ -0,0 +1,113 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::SlashValidatorV1Accounts,
+    state::{ValidationConfigV1, ValidatorProfileV1, ValidatorStakeVaultV1},
+};
+
+impl<'a> SlashValidatorV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validator_profile,
+            validator_stake_vault: _,
+            validation_config,
+            arbiter,
+            destination: _,
+        } = self;
+
+        // Validator Profile
+        ValidatorProfileV1::check_initialized(validator_profile)?;
+
+        // Validator Stake Vault
+        // Checked against the validator of the profile below.
+
+        // Validation Config
+        // There is no arbiter until the config is initialized.
+        ValidationConfigV1::check_pda_derivation(validation_config)?;
+        ValidationConfigV1::check_initialized(validation_config)?;
+
+        // Arbiter
+        // Must be the arbiter of the config.
+        assert_signer(arbiter)?;
+        let data = validation_config.try_borrow_data()?;
+        if bytemuck::from_bytes::<ValidationConfigV1>(&data).arbiter != *arbiter.key {
+            return Err(MplAgentValidationError::InvalidArbiter.into());
+        }
+
+        // Destination
+        // SAFE: The arbiter decides where slashed stake goes.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct SlashValidatorV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Lamports of stake to slash.
+    pub amount: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<SlashValidatorV1Args>() == 16);
+
+pub fn slash_validator_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &SlashValidatorV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = SlashValidatorV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.amount == 0 {
+        return Err(MplAgentValidationError::InvalidStakeAmount.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let (validator, stake, unbonding_stake) = {
+        let mut data = ctx.accounts.validator_profile.try_borrow_mut_data()?;
+        let validator_profile: &mut ValidatorProfileV1 = bytemuck::from_bytes_mut(&mut data);
+        validator_profile.slash(args.amount)?;
+        (
+            validator_profile.authority,
+            validator_profile.stake,
+            validator_profile.unbonding_stake,
+        )
+    };
+
+    ValidatorStakeVaultV1::check_initialized(ctx.accounts.validator_stake_vault, &validator)?;
+    ValidatorStakeVaultV1::pay_out(
+        ctx.accounts.validator_stake_vault,
+        ctx.accounts.destination,
+        args.amount,
+    )?;
+
+    MplAgentValidationEvent::ValidatorSlashedV1 {
+        validator,
+        arbiter: *ctx.accounts.arbiter.key,
+        amount: args.amount,
+        stake,
+        unbonding_stake,
+    }
+    .emit()
+}
@@ -0,0 +1,89 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError,
+    instruction::accounts::UnbondValidatorStakeV1Accounts,
+    state::{ValidationConfigV1, ValidatorProfileV1},
+};
+
+impl<'a> UnbondValidatorStakeV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validator_profile,
+            validation_config: _,
+            validator,
+        } = self;
+
+        // Validator Profile
+        ValidatorProfileV1::check_pda_derivation(validator_profile, validator.key)?;
+        ValidatorProfileV1::check_initialized(validator_profile)?;
+
+        // Validation Config
+        // SAFE: Checked when the unbonding period is read.
+
+        // Validator
+        assert_signer(validator)?;
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct UnbondValidatorStakeV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Lamports of bonded stake to unbond.
+    pub amount: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<UnbondValidatorStakeV1Args>() == 16);
+
+pub fn unbond_validator_stake_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &UnbondValidatorStakeV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = UnbondValidatorStakeV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let unbonding_slots = ValidationConfigV1::unbonding_slots(ctx.accounts.validation_config)?;
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.amount == 0 {
+        return Err(MplAgentValidationError::InvalidStakeAmount.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let unbonding_slot = Clock::get()?.slot.saturating_add(unbonding_slots);
+
+    let mut data = ctx.accounts.validator_profile.try_borrow_mut_data()?;
+    let validator_profile: &mut ValidatorProfileV1 = bytemuck::from_bytes_mut(&mut data);
+    validator_profile.unbond(args.amount, unbonding_slot)?;
+
+    MplAgentValidationEvent::ValidatorStakeUnbondingV1 {
+        validator: *ctx.accounts.validator.key,
+        amount: args.amount,
+        unbonding_stake: validator_profile.unbonding_stake,
+        unbonding_slot,
+    }
+    .emit()
+}
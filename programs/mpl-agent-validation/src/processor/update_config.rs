@@ -0,0 +1,100 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    error::MplAgentValidationError, instruction::accounts::UpdateValidationConfigV1Accounts,
+    state::ValidationConfigV1,
+};
+
+impl<'a> UpdateValidationConfigV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validation_config,
+            authority,
+            new_authority: _,
+            new_arbiter: _,
+        } = self;
+
+        // Validation Config
+        ValidationConfigV1::check_pda_derivation(validation_config)?;
+        ValidationConfigV1::check_initialized(validation_config)?;
+
+        // Authority
+        assert_signer(authority)?;
+
+        // New Authority
+        // SAFE: Any address may be handed the config.
+
+        // New Arbiter
+        // SAFE: Any address may be made the arbiter.
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct UpdateValidationConfigV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+    /// Number of slots unbonding stake stays slashable before it can be
+    /// withdrawn.
+    pub unbonding_slots: u64,
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<UpdateValidationConfigV1Args>() == 16);
+
+pub fn update_validation_config_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    args: &UpdateValidationConfigV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = UpdateValidationConfigV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    let mut data = ctx.accounts.validation_config.try_borrow_mut_data()?;
+    let validation_config: &mut ValidationConfigV1 = bytemuck::from_bytes_mut(&mut data);
+
+    if validation_config.authority != *ctx.accounts.authority.key {
+        return Err(MplAgentValidationError::InvalidConfigAuthority.into());
+    }
+
+    /****************************************************/
+    /***************** Argument Guards ******************/
+    /****************************************************/
+    if args.unbonding_slots == 0 {
+        return Err(MplAgentValidationError::InvalidUnbondingPeriod.into());
+    }
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    // Stake already unbonding keeps the withdrawal slot it was given.
+    validation_config.unbonding_slots = args.unbonding_slots;
+    if let Some(new_authority) = ctx.accounts.new_authority {
+        validation_config.authority = *new_authority.key;
+    }
+    if let Some(new_arbiter) = ctx.accounts.new_arbiter {
+        validation_config.arbiter = *new_arbiter.key;
+    }
+
+    MplAgentValidationEvent::ValidationConfigUpdatedV1 {
+        authority: validation_config.authority,
+        arbiter: validation_config.arbiter,
+        unbonding_slots: validation_config.unbonding_slots,
+    }
+    .emit()
+}
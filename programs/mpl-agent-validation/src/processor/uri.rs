@@ -0,0 +1,44 @@
+//! Parsing and validation of the evidence URI carried as a trailing Borsh
+//! string in `RespondValidationV1`.
+
+use solana_program::program_error::ProgramError;
+
+use crate::error::MplAgentValidationError;
+
+/// Maximum length in bytes of an evidence URI.
+pub const MAX_EVIDENCE_URI_LEN: usize = 200;
+
+/// URI schemes an evidence file may be hosted under.
+pub const ALLOWED_EVIDENCE_URI_SCHEMES: [&str; 3] = ["https://", "ipfs://", "ar://"];
+
+/// Parse a Borsh-style `[u32 LE length][bytes...]` evidence URI from `data`
+/// and check that it uses one of the allowed schemes.
+pub fn read_evidence_uri(data: &[u8]) -> Result<String, ProgramError> {
+    let len_bytes: [u8; 4] = data
+        .get(..4)
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(MplAgentValidationError::InvalidInstructionData)?;
+    let len = u32::from_le_bytes(len_bytes) as usize;
+
+    if len == 0 {
+        return Err(MplAgentValidationError::EvidenceUriEmpty.into());
+    }
+    if len > MAX_EVIDENCE_URI_LEN {
+        return Err(MplAgentValidationError::EvidenceUriTooLong.into());
+    }
+
+    let bytes = data
+        .get(4..4 + len)
+        .ok_or(MplAgentValidationError::InvalidInstructionData)?;
+    let uri = String::from_utf8(bytes.to_vec())
+        .map_err(|_| MplAgentValidationError::EvidenceUriNotUtf8)?;
+
+    if !ALLOWED_EVIDENCE_URI_SCHEMES
+        .iter()
+        .any(|scheme| uri.starts_with(scheme) && uri.len() > scheme.len())
+    {
+        return Err(MplAgentValidationError::EvidenceUriSchemeNotAllowed.into());
+    }
+
+    Ok(uri)
+}
@@ -0,0 +1,82 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::assert_signer;
+use shank::ShankType;
+use solana_program::program_error::ProgramError;
+use solana_program::sysvar::Sysvar;
+use solana_program::{account_info::AccountInfo, clock::Clock, entrypoint::ProgramResult};
+
+use crate::events::MplAgentValidationEvent;
+use crate::{
+    instruction::accounts::WithdrawValidatorStakeV1Accounts,
+    state::{ValidatorProfileV1, ValidatorStakeVaultV1},
+};
+
+impl<'a> WithdrawValidatorStakeV1Accounts<'a> {
+    pub fn validate(&self) -> Result<(), ProgramError> {
+        let Self {
+            validator_profile,
+            validator_stake_vault,
+            validator,
+        } = self;
+
+        // Validator Profile
+        ValidatorProfileV1::check_pda_derivation(validator_profile, validator.key)?;
+        ValidatorProfileV1::check_initialized(validator_profile)?;
+
+        // Validator Stake Vault
+        ValidatorStakeVaultV1::check_initialized(validator_stake_vault, validator.key)?;
+
+        // Validator
+        // Receives the stake.
+        assert_signer(validator)?;
+
+        Ok(())
+    }
+}
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankType)]
+pub struct WithdrawValidatorStakeV1Args {
+    /// Instruction discriminator (not included in IDL).
+    #[skip]
+    pub discriminator: u8,
+    /// Padding for alignment.
+    #[padding]
+    pub _padding: [u8; 7],
+}
+
+// Compile-time assertion to ensure struct is properly sized.
+const _: () = assert!(core::mem::size_of::<WithdrawValidatorStakeV1Args>() == 8);
+
+pub fn withdraw_validator_stake_v1<'a>(
+    accounts: &'a [AccountInfo<'a>],
+    _args: &WithdrawValidatorStakeV1Args,
+) -> ProgramResult {
+    /****************************************************/
+    /****************** Account Setup *******************/
+    /****************************************************/
+
+    let ctx = WithdrawValidatorStakeV1Accounts::context(accounts)?;
+    ctx.accounts.validate()?;
+
+    /****************************************************/
+    /********************* Actions **********************/
+    /****************************************************/
+    let amount = {
+        let mut data = ctx.accounts.validator_profile.try_borrow_mut_data()?;
+        let validator_profile: &mut ValidatorProfileV1 = bytemuck::from_bytes_mut(&mut data);
+        validator_profile.withdraw(Clock::get()?.slot)?
+    };
+
+    ValidatorStakeVaultV1::pay_out(
+        ctx.accounts.validator_stake_vault,
+        ctx.accounts.validator,
+        amount,
+    )?;
+
+    MplAgentValidationEvent::ValidatorStakeWithdrawnV1 {
+        validator: *ctx.accounts.validator.key,
+        amount,
+    }
+    .emit()
+}
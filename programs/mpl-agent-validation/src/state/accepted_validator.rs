@@ -0,0 +1,129 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+use super::{AgentValidationV1, Key};
+
+/// Whitelist entry of a validator accepted by an agent. Once an agent
+/// accepts any validator, validation can only be requested from the
+/// validators it accepts.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct AcceptedValidatorV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the agent asset.
+    pub asset: Pubkey,
+    /// The accepted validator authority.
+    pub validator: Pubkey,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<AcceptedValidatorV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<AcceptedValidatorV1>() == 72);
+
+impl AcceptedValidatorV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"accepted_validator";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        asset: &Pubkey,
+        validator: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, asset.as_ref(), validator.as_ref()],
+            MplAgentValidationError::InvalidAcceptedValidator,
+        )
+    }
+
+    pub fn create_account<'a>(
+        accepted_validator: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        validator: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            accepted_validator,
+            system_program,
+            payer,
+            core::mem::size_of::<AcceptedValidatorV1>(),
+            &[Self::PREFIX, asset.as_ref(), validator.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, asset: &Pubkey, validator: &Pubkey) {
+        self.key = Key::AcceptedValidatorV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.asset = *asset;
+        self.validator = *validator;
+    }
+
+    /// Check that `accepted_validator` is the initialized whitelist entry of
+    /// `validator` for `asset`.
+    pub fn check_initialized(
+        accepted_validator: &AccountInfo,
+        asset: &Pubkey,
+        validator: &Pubkey,
+    ) -> ProgramResult {
+        Self::check_pda_derivation(accepted_validator, asset, validator)?;
+        if accepted_validator.owner != &crate::ID
+            || accepted_validator.data_len() != core::mem::size_of::<AcceptedValidatorV1>()
+            || accepted_validator.try_borrow_data()?[0] != Key::AcceptedValidatorV1 as u8
+        {
+            return Err(MplAgentValidationError::ValidatorNotAccepted.into());
+        }
+
+        Ok(())
+    }
+
+    /// Check that `validator` may validate `asset`: once the agent accepts
+    /// any validator, `accepted_validator` must be the whitelist entry of
+    /// `validator`. `agent_validation` must already be known to be the
+    /// initialized agent validation of `asset`. Returns whether the agent
+    /// restricts its validators.
+    pub fn check_accepted(
+        agent_validation: &AccountInfo,
+        accepted_validator: Option<&AccountInfo>,
+        asset: &Pubkey,
+        validator: &Pubkey,
+    ) -> Result<bool, ProgramError> {
+        // V2 keeps the V1 layout as a prefix.
+        let accepted_validator_count = {
+            let data = agent_validation.try_borrow_data()?;
+            bytemuck::from_bytes::<AgentValidationV1>(
+                &data[..core::mem::size_of::<AgentValidationV1>()],
+            )
+            .accepted_validator_count
+        };
+        if accepted_validator_count == 0 {
+            return Ok(false);
+        }
+
+        let accepted_validator =
+            accepted_validator.ok_or(MplAgentValidationError::ValidatorNotAccepted)?;
+        Self::check_initialized(accepted_validator, asset, validator)?;
+
+        Ok(true)
+    }
+}
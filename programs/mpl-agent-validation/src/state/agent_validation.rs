@@ -15,9 +15,11 @@ use super::Key;
 /// # Layout
 /// - key: 1 byte (account discriminator)
 /// - bump: 1 byte (PDA bump seed)
-/// - _padding: 6 bytes (alignment to 8 bytes)
+/// - _padding: 2 bytes (alignment to 4 bytes)
+/// - accepted_validator_count: 4 bytes (number of whitelisted validators)
+/// - asset: 32 bytes (the address of the asset)
 ///
-/// Total: 8 bytes (8-byte aligned)
+/// Total: 40 bytes (8-byte aligned)
 #[repr(C)]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
 pub struct AgentValidationV1 {
@@ -26,9 +28,12 @@ pub struct AgentValidationV1 {
     pub key: u8,
     /// PDA bump seed.
     pub bump: u8,
-    /// Padding for 8-byte alignment.
+    /// Padding for 4-byte alignment.
     #[padding]
-    pub _padding: [u8; 6],
+    pub _padding: [u8; 2],
+    /// Number of validators the agent accepts. Zero means any registered
+    /// validator may be requested.
+    pub accepted_validator_count: u32,
     /// The address of the asset.
     pub asset: Pubkey,
 }
@@ -63,13 +68,32 @@ impl AgentValidationV1 {
         )
     }
 
+    /// Check that `agent_validation` is an initialized agent validation
+    /// account, i.e. that its asset is registered for validation. Accepts
+    /// `AgentValidationV2` accounts, which keep the V1 layout as a prefix.
+    pub fn check_initialized(agent_validation: &AccountInfo) -> ProgramResult {
+        if agent_validation.owner != &crate::ID
+            || agent_validation.data_len() < core::mem::size_of::<AgentValidationV1>()
+        {
+            return Err(MplAgentValidationError::AgentValidationNotRegistered.into());
+        }
+
+        let key = agent_validation.try_borrow_data()?[0];
+        if key != Key::AgentValidationV1 as u8 && key != Key::AgentValidationV2 as u8 {
+            return Err(MplAgentValidationError::AgentValidationNotRegistered.into());
+        }
+
+        Ok(())
+    }
+
     /// Initialize the account with the given bump seed.
     #[inline]
     pub fn initialize(&mut self, bump: u8, asset: &Pubkey) {
         solana_program::msg!("Initializing agent validation account");
         self.key = Key::AgentValidationV1 as u8;
         self.bump = bump;
-        self._padding = [0u8; 6];
+        self._padding = [0u8; 2];
+        self.accepted_validator_count = 0;
         self.asset = *asset;
     }
 }
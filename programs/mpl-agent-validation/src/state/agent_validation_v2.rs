@@ -0,0 +1,102 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::resize_or_reallocate_account_raw;
+use shank::ShankAccount;
+use solana_program::{account_info::AccountInfo, entrypoint::ProgramResult, pubkey::Pubkey};
+
+use crate::error::MplAgentValidationError;
+
+use super::Key;
+
+/// Agent validation with validation aggregates. Shares its PDA with
+/// `AgentValidationV1`, which is upgraded in place on the first response,
+/// and keeps its layout as a prefix.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct AgentValidationV2 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 4-byte alignment.
+    #[padding]
+    pub _padding: [u8; 2],
+    /// Number of validators the agent accepts. Zero means any registered
+    /// validator may be requested.
+    pub accepted_validator_count: u32,
+    /// The address of the asset.
+    pub asset: Pubkey,
+    /// Number of validation responses.
+    pub validation_count: u64,
+    /// Number of validation responses with a passing result.
+    pub pass_count: u64,
+    /// Slot of the last validation response.
+    pub last_validated_slot: u64,
+    /// The validator with the most responses for the agent. Ties keep the
+    /// validator that reached the count first.
+    pub top_validator: Pubkey,
+    /// Number of responses of the top validator.
+    pub top_validator_count: u64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 32],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<AgentValidationV2>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<AgentValidationV2>() == 136);
+
+impl AgentValidationV2 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"agent_validation";
+
+    /// Grow an `AgentValidationV1` account to the V2 size and update its
+    /// discriminator. The new fields are zero-initialized.
+    pub fn migrate_from_v1<'a>(
+        agent_validation: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+    ) -> ProgramResult {
+        solana_program::msg!("Migrating agent validation account to V2");
+        resize_or_reallocate_account_raw(
+            agent_validation,
+            payer,
+            system_program,
+            core::mem::size_of::<AgentValidationV2>(),
+        )?;
+
+        agent_validation.try_borrow_mut_data()?[0] = Key::AgentValidationV2 as u8;
+
+        Ok(())
+    }
+
+    /// Add a response of `validator` at `slot` to the aggregates.
+    /// `validator_count` is the number of responses of the validator for the
+    /// agent, this one included.
+    pub fn record_validation(
+        &mut self,
+        passed: bool,
+        validator: &Pubkey,
+        validator_count: u64,
+        slot: u64,
+    ) -> ProgramResult {
+        self.validation_count = self
+            .validation_count
+            .checked_add(1)
+            .ok_or(MplAgentValidationError::NumericalOverflow)?;
+        if passed {
+            self.pass_count = self
+                .pass_count
+                .checked_add(1)
+                .ok_or(MplAgentValidationError::NumericalOverflow)?;
+        }
+        self.last_validated_slot = slot;
+
+        if validator_count > self.top_validator_count {
+            self.top_validator = *validator;
+            self.top_validator_count = validator_count;
+        }
+
+        Ok(())
+    }
+}
@@ -1,6 +1,24 @@
+mod accepted_validator;
 mod agent_validation;
+mod agent_validation_v2;
+mod validation_config;
+mod validation_request;
+mod validation_summary;
+mod validator_attester;
+mod validator_history;
+mod validator_profile;
+mod validator_stake_vault;
 
+pub use accepted_validator::*;
 pub use agent_validation::*;
+pub use agent_validation_v2::*;
+pub use validation_config::*;
+pub use validation_request::*;
+pub use validation_summary::*;
+pub use validator_attester::*;
+pub use validator_history::*;
+pub use validator_profile::*;
+pub use validator_stake_vault::*;
 
 use shank::ShankType;
 
@@ -11,6 +29,14 @@ use shank::ShankType;
 pub enum Key {
     Uninitialized,
     AgentValidationV1,
+    ValidationRequestV1,
+    ValidationConfigV1,
+    ValidatorProfileV1,
+    ValidatorStakeVaultV1,
+    AcceptedValidatorV1,
+    AgentValidationV2,
+    ValidatorHistoryV1,
+    ValidatorAttesterV1,
 }
 
 impl From<u8> for Key {
@@ -18,6 +44,14 @@ impl From<u8> for Key {
         match value {
             0 => Key::Uninitialized,
             1 => Key::AgentValidationV1,
+            2 => Key::ValidationRequestV1,
+            3 => Key::ValidationConfigV1,
+            4 => Key::ValidatorProfileV1,
+            5 => Key::ValidatorStakeVaultV1,
+            6 => Key::AcceptedValidatorV1,
+            7 => Key::AgentValidationV2,
+            8 => Key::ValidatorHistoryV1,
+            9 => Key::ValidatorAttesterV1,
             _ => Key::Uninitialized,
         }
     }
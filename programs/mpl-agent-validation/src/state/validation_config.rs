@@ -0,0 +1,118 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+use super::Key;
+
+/// Program-wide validator registry settings. Until the config is initialized,
+/// the default unbonding period applies and no validator can be slashed.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ValidationConfigV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The authority allowed to update the config.
+    pub authority: Pubkey,
+    /// The authority allowed to slash validator stake.
+    pub arbiter: Pubkey,
+    /// Number of slots unbonding stake stays slashable before it can be
+    /// withdrawn.
+    pub unbonding_slots: u64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 64],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ValidationConfigV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ValidationConfigV1>() == 144);
+
+impl ValidationConfigV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"validation_config";
+
+    /// Unbonding period used before the config is initialized: about 7 days
+    /// of 400ms slots.
+    pub const DEFAULT_UNBONDING_SLOTS: u64 = 1_512_000;
+
+    pub fn check_pda_derivation(address: &AccountInfo) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX],
+            MplAgentValidationError::InvalidValidationConfig,
+        )
+    }
+
+    pub fn create_account<'a>(
+        validation_config: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            validation_config,
+            system_program,
+            payer,
+            core::mem::size_of::<ValidationConfigV1>(),
+            &[Self::PREFIX, &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        authority: &Pubkey,
+        arbiter: &Pubkey,
+        unbonding_slots: u64,
+    ) {
+        self.key = Key::ValidationConfigV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.authority = *authority;
+        self.arbiter = *arbiter;
+        self.unbonding_slots = unbonding_slots;
+        self._reserved = [0u8; 64];
+    }
+
+    /// Check that `validation_config` is an initialized config account.
+    pub fn check_initialized(validation_config: &AccountInfo) -> ProgramResult {
+        if validation_config.owner != &crate::ID
+            || validation_config.data_len() != core::mem::size_of::<ValidationConfigV1>()
+            || validation_config.try_borrow_data()?[0] != Key::ValidationConfigV1 as u8
+        {
+            return Err(MplAgentValidationError::InvalidValidationConfig.into());
+        }
+
+        Ok(())
+    }
+
+    /// Read the unbonding period from the config PDA, falling back to the
+    /// default while the config is not initialized.
+    pub fn unbonding_slots(validation_config: &AccountInfo) -> Result<u64, ProgramError> {
+        Self::check_pda_derivation(validation_config)?;
+
+        if validation_config.data_is_empty() {
+            return Ok(Self::DEFAULT_UNBONDING_SLOTS);
+        }
+
+        Self::check_initialized(validation_config)?;
+        let data = validation_config.try_borrow_data()?;
+        Ok(bytemuck::from_bytes::<ValidationConfigV1>(&data).unbonding_slots)
+    }
+}
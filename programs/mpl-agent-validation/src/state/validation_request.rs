@@ -0,0 +1,253 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::{ShankAccount, ShankType};
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::{error::MplAgentValidationError, processor::DEFAULT_VALIDATION_EXPIRY_SLOTS};
+
+use super::Key;
+
+/// Lifecycle of a validation request.
+/// Stored as a u8 in account data but represented as an enum for type safety.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ShankType)]
+pub enum ValidationStatus {
+    /// Waiting for the validator to respond.
+    Pending,
+    /// The validator recorded a result.
+    Responded,
+}
+
+impl From<u8> for ValidationStatus {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => ValidationStatus::Responded,
+            _ => ValidationStatus::Pending,
+        }
+    }
+}
+
+/// Kind of evidence backing a validation result.
+/// Stored as a u8 in account data but represented as an enum for type safety.
+#[repr(u8)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, ShankType)]
+pub enum EvidenceType {
+    /// An off-chain evidence file at the URI of the response.
+    Uri,
+    /// A statement over the request, validator, work hash and result signed
+    /// by an attester key of the validator, verified by the Ed25519 program.
+    Attestation,
+}
+
+impl From<u8> for EvidenceType {
+    fn from(value: u8) -> Self {
+        match value {
+            1 => EvidenceType::Attestation,
+            _ => EvidenceType::Uri,
+        }
+    }
+}
+
+/// Request for a validator to independently verify a piece of agent work,
+/// keyed on the agent asset and a requester-chosen nonce. Holds the result
+/// once the validator responds, and escrows the reward of the validator
+/// until then.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ValidationRequestV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Status of the request.
+    #[idl_type(ValidationStatus)]
+    pub status: u8,
+    /// The validation result, from 0 to 100. Zero while pending.
+    pub result: u8,
+    /// Kind of evidence backing the result. Meaningless while pending.
+    #[idl_type(EvidenceType)]
+    pub evidence_type: u8,
+    /// Whether the response counts in the agent's validation aggregates,
+    /// which only count requests of the agent and responses of validators it
+    /// accepts. Meaningless while pending.
+    pub counted: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 2],
+    /// The address of the agent asset whose work is validated.
+    pub asset: Pubkey,
+    /// The agent or client that requested the validation.
+    pub requester: Pubkey,
+    /// The validator designated to respond.
+    pub validator: Pubkey,
+    /// Nonce of the request among the requests for the asset.
+    pub nonce: u64,
+    /// Slot the validation was requested at.
+    pub request_slot: u64,
+    /// Slot the validator responded at. Zero while pending.
+    pub response_slot: u64,
+    /// Hash of the validated work.
+    pub work_hash: [u8; 32],
+    /// Hash of the evidence file backing the result. Zero while pending.
+    pub evidence_hash: [u8; 32],
+    /// Reward of the validator in lamports, held by the request account on
+    /// top of its rent until the validator responds.
+    pub reward: u64,
+    /// Slot from which the validator can no longer respond and the requester
+    /// can reclaim the request. Zero for requests made before requests
+    /// expired, which expire `DEFAULT_VALIDATION_EXPIRY_SLOTS` after their
+    /// request slot.
+    pub expiry_slot: u64,
+    /// Bonded stake of the validator when the validation was requested,
+    /// which it must still have to respond. Zero for requests made before
+    /// stake was recorded.
+    pub validator_stake: u64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 8],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ValidationRequestV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ValidationRequestV1>() == 224);
+
+impl ValidationRequestV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"validation_request";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        asset: &Pubkey,
+        nonce: u64,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, asset.as_ref(), &nonce.to_le_bytes()],
+            MplAgentValidationError::InvalidValidationRequest,
+        )
+    }
+
+    pub fn create_account<'a>(
+        validation_request: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        nonce: u64,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            validation_request,
+            system_program,
+            payer,
+            core::mem::size_of::<ValidationRequestV1>(),
+            &[Self::PREFIX, asset.as_ref(), &nonce.to_le_bytes(), &[bump]],
+        )
+    }
+
+    /// Initialize the account as a pending request.
+    #[inline]
+    #[allow(clippy::too_many_arguments)]
+    pub fn initialize(
+        &mut self,
+        bump: u8,
+        asset: &Pubkey,
+        requester: &Pubkey,
+        validator: &Pubkey,
+        nonce: u64,
+        work_hash: [u8; 32],
+        validator_stake: u64,
+        reward: u64,
+        expiry_slot: u64,
+        slot: u64,
+    ) {
+        self.key = Key::ValidationRequestV1 as u8;
+        self.bump = bump;
+        self.status = ValidationStatus::Pending as u8;
+        self.result = 0;
+        self.evidence_type = EvidenceType::Uri as u8;
+        self.counted = 0;
+        self._padding = [0u8; 2];
+        self.asset = *asset;
+        self.requester = *requester;
+        self.validator = *validator;
+        self.nonce = nonce;
+        self.request_slot = slot;
+        self.response_slot = 0;
+        self.work_hash = work_hash;
+        self.evidence_hash = [0u8; 32];
+        self.reward = reward;
+        self.expiry_slot = expiry_slot;
+        self.validator_stake = validator_stake;
+        self._reserved = [0u8; 8];
+    }
+
+    /// Slot the request expires at, defaulting the expiry of requests made
+    /// before requests expired.
+    #[inline]
+    pub fn expires_at(&self) -> u64 {
+        match self.expiry_slot {
+            0 => self
+                .request_slot
+                .saturating_add(DEFAULT_VALIDATION_EXPIRY_SLOTS),
+            expiry_slot => expiry_slot,
+        }
+    }
+
+    /// Whether the request is expired at `slot`.
+    #[inline]
+    pub fn is_expired(&self, slot: u64) -> bool {
+        slot >= self.expires_at()
+    }
+
+    /// Record the validator's result.
+    pub fn record_response(
+        &mut self,
+        result: u8,
+        evidence_type: EvidenceType,
+        evidence_hash: [u8; 32],
+        counted: bool,
+        slot: u64,
+    ) -> ProgramResult {
+        if self.status != ValidationStatus::Pending as u8 {
+            return Err(MplAgentValidationError::ValidationAlreadyResponded.into());
+        }
+        if self.is_expired(slot) {
+            return Err(MplAgentValidationError::ValidationRequestExpired.into());
+        }
+
+        self.status = ValidationStatus::Responded as u8;
+        self.result = result;
+        self.evidence_type = evidence_type as u8;
+        self.counted = counted as u8;
+        self.response_slot = slot;
+        self.evidence_hash = evidence_hash;
+
+        Ok(())
+    }
+
+    /// Pay the escrowed reward out of the request. The program owns the
+    /// request, so the lamports are moved directly.
+    pub fn pay_out_reward(
+        validation_request: &AccountInfo,
+        destination: &AccountInfo,
+        reward: u64,
+    ) -> ProgramResult {
+        let mut request_lamports = validation_request.try_borrow_mut_lamports()?;
+        **request_lamports = request_lamports
+            .checked_sub(reward)
+            .ok_or(MplAgentValidationError::NumericalOverflow)?;
+
+        let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+        **destination_lamports = destination_lamports
+            .checked_add(reward)
+            .ok_or(MplAgentValidationError::NumericalOverflow)?;
+
+        Ok(())
+    }
+}
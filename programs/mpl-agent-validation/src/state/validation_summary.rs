@@ -0,0 +1,69 @@
+use bytemuck::{Pod, Zeroable};
+use solana_program::pubkey::Pubkey;
+
+use super::AgentValidationV2;
+
+/// Compact summary of the validation aggregates written to the asset's
+/// AppData plugin. All fields are little-endian.
+///
+/// # Layout
+/// - version: 1 byte
+/// - _padding: 1 byte
+/// - pass_rate_bps: 2 bytes
+/// - _reserved: 4 bytes
+/// - validation_count: 8 bytes
+/// - pass_count: 8 bytes
+/// - last_validated_slot: 8 bytes
+/// - top_validator: 32 bytes
+/// - top_validator_count: 8 bytes
+///
+/// Total: 72 bytes
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable)]
+pub struct ValidationSummaryV1 {
+    /// Layout version, `ValidationSummaryV1::VERSION`.
+    pub version: u8,
+    /// Padding for 2-byte alignment.
+    pub _padding: u8,
+    /// Share of passing validations, in basis points.
+    pub pass_rate_bps: u16,
+    /// Reserved for future use.
+    pub _reserved: [u8; 4],
+    /// Number of validation responses.
+    pub validation_count: u64,
+    /// Number of validation responses with a passing result.
+    pub pass_count: u64,
+    /// Slot of the last validation response.
+    pub last_validated_slot: u64,
+    /// The validator with the most responses for the agent.
+    pub top_validator: Pubkey,
+    /// Number of responses of the top validator.
+    pub top_validator_count: u64,
+}
+
+const _: () = assert!(core::mem::size_of::<ValidationSummaryV1>() == 72);
+
+impl ValidationSummaryV1 {
+    /// Version byte of this layout.
+    pub const VERSION: u8 = 1;
+}
+
+impl From<&AgentValidationV2> for ValidationSummaryV1 {
+    fn from(agent_validation: &AgentValidationV2) -> Self {
+        let pass_rate_bps = (agent_validation.pass_count as u128 * 10_000)
+            .checked_div(agent_validation.validation_count as u128)
+            .unwrap_or(0) as u16;
+
+        Self {
+            version: Self::VERSION,
+            _padding: 0,
+            pass_rate_bps,
+            _reserved: [0u8; 4],
+            validation_count: agent_validation.validation_count,
+            pass_count: agent_validation.pass_count,
+            last_validated_slot: agent_validation.last_validated_slot,
+            top_validator: agent_validation.top_validator,
+            top_validator_count: agent_validation.top_validator_count,
+        }
+    }
+}
@@ -0,0 +1,95 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+use super::Key;
+
+/// Attester key of a validator, such as the key of a TEE enclave. An
+/// attestation signed by the key is accepted as the validator's evidence.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ValidatorAttesterV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The validator authority.
+    pub validator: Pubkey,
+    /// The ed25519 attester key.
+    pub attester: Pubkey,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ValidatorAttesterV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ValidatorAttesterV1>() == 72);
+
+impl ValidatorAttesterV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"validator_attester";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        validator: &Pubkey,
+        attester: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, validator.as_ref(), attester.as_ref()],
+            MplAgentValidationError::InvalidValidatorAttester,
+        )
+    }
+
+    pub fn create_account<'a>(
+        validator_attester: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        validator: &Pubkey,
+        attester: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            validator_attester,
+            system_program,
+            payer,
+            core::mem::size_of::<ValidatorAttesterV1>(),
+            &[Self::PREFIX, validator.as_ref(), attester.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, validator: &Pubkey, attester: &Pubkey) {
+        self.key = Key::ValidatorAttesterV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.validator = *validator;
+        self.attester = *attester;
+    }
+
+    /// Check that `validator_attester` is an initialized attester key of a
+    /// validator, returning the attester key. The derivation is left to the
+    /// caller.
+    pub fn check_initialized(validator_attester: &AccountInfo) -> Result<Pubkey, ProgramError> {
+        if validator_attester.owner != &crate::ID
+            || validator_attester.data_len() != core::mem::size_of::<ValidatorAttesterV1>()
+            || validator_attester.try_borrow_data()?[0] != Key::ValidatorAttesterV1 as u8
+        {
+            return Err(MplAgentValidationError::InvalidValidatorAttester.into());
+        }
+
+        let data = validator_attester.try_borrow_data()?;
+        Ok(bytemuck::from_bytes::<ValidatorAttesterV1>(&data).attester)
+    }
+}
@@ -0,0 +1,84 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+use super::Key;
+
+/// History of a validator with an agent: the number of validations the
+/// validator responded to for the agent. Ranks the validator for the top
+/// validator of the agent.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ValidatorHistoryV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The address of the validated asset.
+    pub asset: Pubkey,
+    /// The validator authority.
+    pub validator: Pubkey,
+    /// Number of validations the validator responded to for the agent.
+    pub validation_count: u64,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ValidatorHistoryV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ValidatorHistoryV1>() == 80);
+
+impl ValidatorHistoryV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"validator_history";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        asset: &Pubkey,
+        validator: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, asset.as_ref(), validator.as_ref()],
+            MplAgentValidationError::InvalidValidatorHistory,
+        )
+    }
+
+    pub fn create_account<'a>(
+        validator_history: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        asset: &Pubkey,
+        validator: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            validator_history,
+            system_program,
+            payer,
+            core::mem::size_of::<ValidatorHistoryV1>(),
+            &[Self::PREFIX, asset.as_ref(), validator.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, asset: &Pubkey, validator: &Pubkey) {
+        self.key = Key::ValidatorHistoryV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.asset = *asset;
+        self.validator = *validator;
+        self.validation_count = 0;
+    }
+}
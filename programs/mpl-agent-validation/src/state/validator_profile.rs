@@ -0,0 +1,163 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+use super::Key;
+
+/// Registry entry of a validator, keyed on its authority. Tracks the stake
+/// escrowed in its `ValidatorStakeVaultV1`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ValidatorProfileV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The validator authority, which signs validation responses.
+    pub authority: Pubkey,
+    /// Bonded stake, in lamports.
+    pub stake: u64,
+    /// Stake waiting out the unbonding period, in lamports. Still slashable.
+    pub unbonding_stake: u64,
+    /// Slot from which the unbonding stake can be withdrawn. Zero when
+    /// nothing is unbonding.
+    pub unbonding_slot: u64,
+    /// Total stake slashed by the arbiter, in lamports.
+    pub slashed_stake: u64,
+    /// Slot the validator registered at.
+    pub registered_slot: u64,
+    /// Number of attester keys of the validator.
+    pub attester_count: u64,
+    // Reserved for future use.
+    #[padding]
+    pub _reserved: [u8; 56],
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ValidatorProfileV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ValidatorProfileV1>() == 144);
+
+impl ValidatorProfileV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"validator_profile";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        validator: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, validator.as_ref()],
+            MplAgentValidationError::InvalidValidatorProfile,
+        )
+    }
+
+    pub fn create_account<'a>(
+        validator_profile: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        validator: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            validator_profile,
+            system_program,
+            payer,
+            core::mem::size_of::<ValidatorProfileV1>(),
+            &[Self::PREFIX, validator.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, authority: &Pubkey, stake: u64, slot: u64) {
+        self.key = Key::ValidatorProfileV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.authority = *authority;
+        self.stake = stake;
+        self.unbonding_stake = 0;
+        self.unbonding_slot = 0;
+        self.slashed_stake = 0;
+        self.registered_slot = slot;
+        self.attester_count = 0;
+        self._reserved = [0u8; 56];
+    }
+
+    /// Check that `validator_profile` is an initialized validator profile.
+    pub fn check_initialized(validator_profile: &AccountInfo) -> ProgramResult {
+        if validator_profile.owner != &crate::ID
+            || validator_profile.data_len() != core::mem::size_of::<ValidatorProfileV1>()
+            || validator_profile.try_borrow_data()?[0] != Key::ValidatorProfileV1 as u8
+        {
+            return Err(MplAgentValidationError::ValidatorNotRegistered.into());
+        }
+
+        Ok(())
+    }
+
+    /// Move `amount` of bonded stake to unbonding. The unbonding period
+    /// restarts for all of the unbonding stake.
+    pub fn unbond(&mut self, amount: u64, unbonding_slot: u64) -> ProgramResult {
+        if amount > self.stake {
+            return Err(MplAgentValidationError::InsufficientValidatorStake.into());
+        }
+
+        self.stake -= amount;
+        self.unbonding_stake = self
+            .unbonding_stake
+            .checked_add(amount)
+            .ok_or(MplAgentValidationError::NumericalOverflow)?;
+        self.unbonding_slot = unbonding_slot;
+
+        Ok(())
+    }
+
+    /// Release the unbonding stake once the unbonding period is over,
+    /// returning the amount to withdraw.
+    pub fn withdraw(&mut self, slot: u64) -> Result<u64, ProgramError> {
+        if self.unbonding_stake == 0 {
+            return Err(MplAgentValidationError::NoUnbondingStake.into());
+        }
+        if slot < self.unbonding_slot {
+            return Err(MplAgentValidationError::StakeStillUnbonding.into());
+        }
+
+        let amount = self.unbonding_stake;
+        self.unbonding_stake = 0;
+        self.unbonding_slot = 0;
+
+        Ok(amount)
+    }
+
+    /// Slash `amount` of stake, taken from the bonded stake first and the
+    /// unbonding stake after.
+    pub fn slash(&mut self, amount: u64) -> ProgramResult {
+        let from_stake = amount.min(self.stake);
+        let from_unbonding = amount - from_stake;
+        if from_unbonding > self.unbonding_stake {
+            return Err(MplAgentValidationError::InsufficientValidatorStake.into());
+        }
+
+        self.stake -= from_stake;
+        self.unbonding_stake -= from_unbonding;
+        if self.unbonding_stake == 0 {
+            self.unbonding_slot = 0;
+        }
+        self.slashed_stake = self.slashed_stake.saturating_add(amount);
+
+        Ok(())
+    }
+}
@@ -0,0 +1,113 @@
+use bytemuck::{Pod, Zeroable};
+use mpl_utils::{assert_derivation, create_or_allocate_account_raw};
+use shank::ShankAccount;
+use solana_program::{
+    account_info::AccountInfo, entrypoint::ProgramResult, program_error::ProgramError,
+    pubkey::Pubkey,
+};
+
+use crate::error::MplAgentValidationError;
+
+use super::Key;
+
+/// Program-owned vault escrowing the stake of a validator. Every lamport
+/// above the rent-exempt minimum is stake accounted for in the
+/// `ValidatorProfileV1`.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Pod, Zeroable, ShankAccount)]
+pub struct ValidatorStakeVaultV1 {
+    /// Account discriminator.
+    #[idl_type(Key)]
+    pub key: u8,
+    /// PDA bump seed.
+    pub bump: u8,
+    /// Padding for 8-byte alignment.
+    #[padding]
+    pub _padding: [u8; 6],
+    /// The validator authority the stake belongs to.
+    pub validator: Pubkey,
+}
+
+// Compile-time assertion to ensure struct is 8-byte aligned.
+const _: () = assert!(core::mem::size_of::<ValidatorStakeVaultV1>() % 8 == 0);
+const _: () = assert!(core::mem::size_of::<ValidatorStakeVaultV1>() == 40);
+
+impl ValidatorStakeVaultV1 {
+    /// PDA seed prefix for this account type.
+    pub const PREFIX: &'static [u8] = b"validator_stake_vault";
+
+    pub fn check_pda_derivation(
+        address: &AccountInfo,
+        validator: &Pubkey,
+    ) -> Result<u8, ProgramError> {
+        assert_derivation(
+            &crate::ID,
+            address,
+            &[Self::PREFIX, validator.as_ref()],
+            MplAgentValidationError::InvalidValidatorStakeVault,
+        )
+    }
+
+    pub fn create_account<'a>(
+        validator_stake_vault: &AccountInfo<'a>,
+        payer: &AccountInfo<'a>,
+        system_program: &AccountInfo<'a>,
+        validator: &Pubkey,
+        bump: u8,
+    ) -> ProgramResult {
+        create_or_allocate_account_raw(
+            crate::ID,
+            validator_stake_vault,
+            system_program,
+            payer,
+            core::mem::size_of::<ValidatorStakeVaultV1>(),
+            &[Self::PREFIX, validator.as_ref(), &[bump]],
+        )
+    }
+
+    /// Initialize the account with the given bump seed.
+    #[inline]
+    pub fn initialize(&mut self, bump: u8, validator: &Pubkey) {
+        self.key = Key::ValidatorStakeVaultV1 as u8;
+        self.bump = bump;
+        self._padding = [0u8; 6];
+        self.validator = *validator;
+    }
+
+    /// Check that `validator_stake_vault` is the initialized vault of
+    /// `validator`.
+    pub fn check_initialized(
+        validator_stake_vault: &AccountInfo,
+        validator: &Pubkey,
+    ) -> ProgramResult {
+        Self::check_pda_derivation(validator_stake_vault, validator)?;
+        if validator_stake_vault.owner != &crate::ID
+            || validator_stake_vault.data_len() != core::mem::size_of::<ValidatorStakeVaultV1>()
+            || validator_stake_vault.try_borrow_data()?[0] != Key::ValidatorStakeVaultV1 as u8
+        {
+            return Err(MplAgentValidationError::InvalidValidatorStakeVault.into());
+        }
+
+        Ok(())
+    }
+
+    /// Pay `amount` of stake out of the vault. The program owns the vault, so
+    /// the lamports are moved directly.
+    pub fn pay_out(
+        validator_stake_vault: &AccountInfo,
+        destination: &AccountInfo,
+        amount: u64,
+    ) -> ProgramResult {
+        let mut vault_lamports = validator_stake_vault.try_borrow_mut_lamports()?;
+        **vault_lamports = vault_lamports
+            .checked_sub(amount)
+            .ok_or(MplAgentValidationError::InsufficientValidatorStake)?;
+
+        let mut destination_lamports = destination.try_borrow_mut_lamports()?;
+        **destination_lamports = destination_lamports
+            .checked_add(amount)
+            .ok_or(MplAgentValidationError::NumericalOverflow)?;
+
+        Ok(())
+    }
+}